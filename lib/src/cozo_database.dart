@@ -1,5 +1,7 @@
 import 'dart:convert';
 
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+
 import 'cozo_exception.dart';
 import 'cozo_result.dart';
 import 'rust/api/simple.dart' as bridge;
@@ -11,7 +13,14 @@ enum CozoEngine {
   memory('mem'),
 
   /// SQLite-backed persistent database. Recommended for mobile.
-  sqlite('sqlite');
+  sqlite('sqlite'),
+
+  /// RocksDB-backed persistent database. Only available when the native
+  /// library is built with the `rocksdb` cargo feature.
+  rocksdb('rocksdb'),
+
+  /// A remote cozo-server. The path is the server URL.
+  http('http');
 
   final String value;
   const CozoEngine(this.value);
@@ -70,8 +79,31 @@ class CozoDatabase {
   }
 
   /// Open a SQLite-backed database at the given path.
-  static Future<CozoDatabase> openSqlite(String path) async {
-    return open(engine: CozoEngine.sqlite, path: path);
+  ///
+  /// With [readOnly], the file must already exist, every query runs in
+  /// read-only mode and writes throw, so the file can be shared with another
+  /// process that owns it.
+  static Future<CozoDatabase> openSqlite(
+    String path, {
+    bool readOnly = false,
+  }) async {
+    return open(
+      engine: CozoEngine.sqlite,
+      path: path,
+      options: jsonEncode({'read_only': readOnly}),
+    );
+  }
+
+  /// Connect to a cozo-server at [url], e.g. `https://host:9070`.
+  ///
+  /// [auth] is the server's auth token. Queries, export and import go to the
+  /// server; backups and the other local-only calls throw.
+  static Future<CozoDatabase> openRemote(String url, {String? auth}) async {
+    return open(
+      engine: CozoEngine.http,
+      path: url,
+      options: jsonEncode({if (auth != null) 'auth': auth}),
+    );
   }
 
   void _ensureOpen() {
@@ -266,6 +298,8 @@ class CozoDatabase {
         immutable: false,
       );
       return CozoResult.fromJson(resultJson);
+    } on AnyhowException catch (e) {
+      throw CozoQueryException(message: e.message);
     } catch (e) {
       if (e is CozoQueryException) rethrow;
       throw CozoDatabaseException('Query execution failed: $e');
@@ -287,6 +321,8 @@ class CozoDatabase {
         immutable: true,
       );
       return CozoResult.fromJson(resultJson);
+    } on AnyhowException catch (e) {
+      throw CozoQueryException(message: e.message);
     } catch (e) {
       if (e is CozoQueryException) rethrow;
       throw CozoDatabaseException('Query execution failed: $e');
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `copy_asset`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// Open a database shipped as a Flutter asset.
+///
+/// - `bytes`: contents of a sqlite-engine database file or a backup made with
+///   `cozo_backup`, which use the same format
+/// - `mode`: see [`AssetOpenMode`]
+/// - `path`: destination file for [`AssetOpenMode::CopyOnFirstOpen`], e.g. in
+///   the app documents directory; ignored for [`AssetOpenMode::Memory`]
+///
+/// Returns an opaque CozoDb handle.
+Future<CozoDb>  cozoOpenFromAssetBytes({required List<int> bytes , required AssetOpenMode mode , required String path }) => RustLib.instance.api.crateApiAssetCozoOpenFromAssetBytes(bytes: bytes, mode: mode, path: path);
+
+            /// How [`cozo_open_from_asset_bytes`] uses the bundled database.
+enum AssetOpenMode {
+                    /// Load the data into a fresh in-memory database. Writes are not saved.
+memory,
+/// Write the asset to `path` if no file exists there yet, then open that
+/// file with the sqlite engine. Later opens keep the user's changes.
+copyOnFirstOpen,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,124 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cdc.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `ensure_log`, `last_seq`, `log_exists`, `row_changes`, `stop`, `values`, `write_entries`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Change`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Record who changed what and when for every row put into or removed from
+/// `relations`, in the append-only `cozo_audit` relation read with
+/// [`cozo_read_audit`].
+///
+/// - `actor_provider`: returns the user or service to record for a change,
+///   e.g. the id of the signed-in user
+///
+/// Changes are recorded from change callbacks just after they commit, one
+/// actor call per write statement, so the actor is the one current then.
+/// Each entry holds the row before and after the change. Writes that do not
+/// run callbacks, such as `cozo_import_relations` and restores, are not
+/// recorded. The log survives restarts but auditing does not: enable it
+/// again after opening the database. Enabling again replaces the audited
+/// relations and the actor provider.
+Future<void>  cozoEnableAudit({required CozoDb db , required List<String> relations , required FutureOr<String> Function() actorProvider }) => RustLib.instance.api.crateApiAuditCozoEnableAudit(db: db, relations: relations, actorProvider: actorProvider);
+
+/// Stop auditing. Returns once the changes already made are recorded; the
+/// log itself is kept.
+Future<void>  cozoDisableAudit({required CozoDb db }) => RustLib.instance.api.crateApiAuditCozoDisableAudit(db: db);
+
+/// Read audit entries in the order they were recorded.
+///
+/// Returns at most `query.limit` entries matching `query`.
+Future<List<AuditEntry>>  cozoReadAudit({required CozoDb db , required AuditQuery query }) => RustLib.instance.api.crateApiAuditCozoReadAudit(db: db, query: query);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Auditor>>
+                abstract class Auditor implements RustOpaqueInterface {
+                    static Future<Auditor>  default_()=>RustLib.instance.api.crateApiAuditAuditorDefault();
+
+
+
+                    
+                }
+                
+
+/// One row changed, as recorded by the audit log.
+class AuditEntry  {
+                /// Position in the log, increasing with every entry.
+final PlatformInt64 seq;
+/// Unix time in seconds the change was recorded.
+final double at;
+/// What the actor provider returned for the change.
+final String actor;
+final String relation;
+final CdcOp op;
+/// Values of the key columns of the row.
+final List<CozoValue> key;
+/// The row before the change, `None` if it was inserted.
+final List<CozoValue>? old;
+/// The row after the change, `None` if it was removed.
+final List<CozoValue>? new_;
+
+                const AuditEntry({required this.seq ,required this.at ,required this.actor ,required this.relation ,required this.op ,required this.key ,this.old ,this.new_ ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => seq.hashCode^at.hashCode^actor.hashCode^relation.hashCode^op.hashCode^key.hashCode^old.hashCode^new_.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuditEntry &&
+                runtimeType == other.runtimeType
+                && seq == other.seq&& at == other.at&& actor == other.actor&& relation == other.relation&& op == other.op&& key == other.key&& old == other.old&& new_ == other.new_;
+        
+            }
+
+/// Which entries [`cozo_read_audit`] returns. Unset fields match everything.
+class AuditQuery  {
+                final String? relation;
+final String? actor;
+/// Unix time in seconds of the earliest entry returned.
+final double? since;
+/// Unix time in seconds the entries returned are older than.
+final double? until;
+/// First `seq` returned; pass the last `seq` read plus one to continue
+/// where the previous call stopped.
+final PlatformInt64 fromSeq;
+/// Maximum number of entries returned.
+final int limit;
+
+                const AuditQuery({this.relation ,this.actor ,this.since ,this.until ,required this.fromSeq ,required this.limit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^actor.hashCode^since.hashCode^until.hashCode^fromSeq.hashCode^limit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuditQuery &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& actor == other.actor&& since == other.since&& until == other.until&& fromSeq == other.fromSeq&& limit == other.limit;
+        
+            }
+            
\ No newline at end of file
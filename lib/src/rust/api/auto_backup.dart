@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `backup_and_rotate`, `is_idle`, `run_schedule`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Back up the database in the background at a fixed interval, keeping the
+/// newest `keep_n` backups.
+///
+/// Each backup is written under a temporary name and renamed when complete,
+/// so the directory never holds a partial backup under a backup name.
+/// Enabling again replaces the previous schedule. The schedule ends with
+/// [`cozo_disable_auto_backup`], when the database is closed, or when the
+/// Dart side cancels its subscription.
+///
+/// Each event is a JSON string, one per scheduled backup:
+/// `{"stage": "done", "path": p, "bytes": n, "removed": [paths], "elapsed": secs}`,
+/// `{"stage": "failed", "error": message, "elapsed": secs}` or
+/// `{"stage": "skipped", "reason": "busy"}`.
+Stream<String>  cozoEnableAutoBackup({required CozoDb db , required BackupPolicy policy }) => RustLib.instance.api.crateApiAutoBackupCozoEnableAutoBackup(db: db, policy: policy);
+
+/// Stop the schedule started by [`cozo_enable_auto_backup`], if any. A
+/// backup already being written is finished first.
+///
+/// Returns whether a schedule was running.
+bool  cozoDisableAutoBackup({required CozoDb db }) => RustLib.instance.api.crateApiAutoBackupCozoDisableAutoBackup(db: db);
+
+            /// Settings of [`cozo_enable_auto_backup`].
+class BackupPolicy  {
+                /// Seconds between backups, at least 1. The first backup is taken one
+/// interval after enabling.
+final BigInt intervalSecs;
+/// Directory the backups are written to, created if missing. Use a
+/// directory of its own: files named like backups are rotated out.
+final String dir;
+/// Number of backups kept; older ones are deleted after each backup.
+/// 0 keeps them all.
+final int keepN;
+/// Wait until no query is running before taking a backup. A backup that
+/// cannot start before the next one is due is skipped.
+final bool onlyOnIdle;
+
+                const BackupPolicy({required this.intervalSecs ,required this.dir ,required this.keepN ,required this.onlyOnIdle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => intervalSecs.hashCode^dir.hashCode^keepN.hashCode^onlyOnIdle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BackupPolicy &&
+                runtimeType == other.runtimeType
+                && intervalSecs == other.intervalSecs&& dir == other.dir&& keepN == other.keepN&& onlyOnIdle == other.onlyOnIdle;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,131 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `backup_cipher`, `copy_into`, `file_size`, `path`, `private`, `relation_count`, `with_progress`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TempFile`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`
+
+
+            /// Backup the database to a file path, reporting progress through a stream.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "bytes": n, "relations": n, "elapsed": secs}`,
+/// where `bytes` is the size of the backup file written so far and
+/// `relations` the number of stored relations being copied. The stream closes
+/// after the `done` event, or fails with the backup error.
+Stream<String>  cozoBackupWithProgress({required CozoDb db , required String path }) => RustLib.instance.api.crateApiBackupCozoBackupWithProgress(db: db, path: path);
+
+/// Restore the database from a backup file, reporting progress through a stream.
+///
+/// Events have the same shape as [`cozo_backup_with_progress`]. `bytes` is the
+/// size of the backup file, and `relations` is the number of stored relations
+/// restored, reported with the `done` event. Cozo restores in one transaction,
+/// so `progress` events only carry the elapsed time. A compressed backup is
+/// decompressed before the `started` event.
+Stream<String>  cozoRestoreWithProgress({required CozoDb db , required String path }) => RustLib.instance.api.crateApiBackupCozoRestoreWithProgress(db: db, path: path);
+
+/// Backup the database into memory instead of a file.
+///
+/// Returns the backup file contents, suitable for [`cozo_restore_from_bytes`]
+/// or for handing to a cloud storage SDK.
+Future<Uint8List>  cozoBackupToBytes({required CozoDb db }) => RustLib.instance.api.crateApiBackupCozoBackupToBytes(db: db);
+
+/// Restore the database from backup contents produced by [`cozo_backup_to_bytes`],
+/// or the contents of a compressed backup file.
+///
+/// Like [`crate::api::simple::cozo_restore`], the database must be empty.
+Future<void>  cozoRestoreFromBytes({required CozoDb db , required List<int> data }) => RustLib.instance.api.crateApiBackupCozoRestoreFromBytes(db: db, data: data);
+
+/// Copy the whole database into a new in-memory database.
+///
+/// Schemas, indices and data are copied, so scratch edits on the clone
+/// (tests, "what-if" flows) never reach the original.
+///
+/// Returns a handle to the clone, which is always writable.
+Future<CozoDb>  cozoCloneToMem({required CozoDb db }) => RustLib.instance.api.crateApiBackupCozoCloneToMem(db: db);
+
+/// Copy the whole database into a new database on another engine, e.g. to
+/// move from mem to sqlite or from sqlite to rocksdb.
+///
+/// - `dst_engine`, `dst_path`, `dst_options`: as for `cozo_open_db`
+///
+/// Schemas, indices and data are copied. The destination must be empty, so
+/// `dst_path` should not point at an existing database.
+///
+/// Returns a handle to the destination database.
+Future<CozoDb>  cozoCopyDatabase({required CozoDb src , required String dstEngine , required String dstPath , required String dstOptions }) => RustLib.instance.api.crateApiBackupCozoCopyDatabase(src: src, dstEngine: dstEngine, dstPath: dstPath, dstOptions: dstOptions);
+
+/// Backup the database to a file encrypted with a passphrase.
+///
+/// The key is derived from `passphrase` with Argon2id and a random salt, and
+/// the backup is sealed with XChaCha20-Poly1305. Restore it with
+/// [`cozo_restore_encrypted`].
+///
+/// Cozo only writes backups to a file, so the plaintext backup is written
+/// first to a file only the app can read, with a random name in its private
+/// cache directory, and overwritten and removed once it has been read,
+/// whether or not the backup succeeds.
+Future<void>  cozoBackupEncrypted({required CozoDb db , required String path , required String passphrase }) => RustLib.instance.api.crateApiBackupCozoBackupEncrypted(db: db, path: path, passphrase: passphrase);
+
+/// Restore the database from a file written by [`cozo_backup_encrypted`].
+///
+/// Fails without touching the database if the passphrase is wrong or the
+/// file has been modified. The decrypted backup goes through a private file
+/// as for [`cozo_backup_encrypted`].
+Future<void>  cozoRestoreEncrypted({required CozoDb db , required String path , required String passphrase }) => RustLib.instance.api.crateApiBackupCozoRestoreEncrypted(db: db, path: path, passphrase: passphrase);
+
+/// Check a backup file before offering it for restore, e.g. in a restore
+/// picker.
+///
+/// The file is checked with `PRAGMA integrity_check` and then read in full
+/// into a scratch in-memory database, so this takes about as long as a
+/// restore and needs memory for the whole backup. Files written by
+/// [`cozo_backup_encrypted`] cannot be checked without the passphrase and
+/// are rejected. Compressed backups are decompressed to a temporary file
+/// first.
+///
+/// Fails if the file cannot be read as a backup at all.
+Future<BackupInfo>  cozoVerifyBackup({required String path }) => RustLib.instance.api.crateApiBackupCozoVerifyBackup(path: path);
+
+            /// What [`cozo_verify_backup`] found in a backup file.
+class BackupInfo  {
+                /// Stored relations in the backup, not counting indices.
+final List<String> relations;
+/// Number of rows of each relation, in the order of `relations`.
+final Uint64List rowCounts;
+/// Unix time in seconds the backup was taken, from the manifest of
+/// `cozo_backup_incremental` if the file is listed in one, else the time
+/// the file was last modified.
+final double? createdAt;
+/// Whether the file passed SQLite's integrity check and, for a full
+/// backup listed in a manifest, every relation matches the checksum
+/// recorded when it was taken.
+final bool checksumOk;
+
+                const BackupInfo({required this.relations ,required this.rowCounts ,this.createdAt ,required this.checksumOk ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relations.hashCode^rowCounts.hashCode^createdAt.hashCode^checksumOk.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BackupInfo &&
+                runtimeType == other.runtimeType
+                && relations == other.relations&& rowCounts == other.rowCounts&& createdAt == other.createdAt&& checksumOk == other.checksumOk;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `run_atomic`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Run several scripts in one call across the bridge.
+///
+/// - `atomic`: if true, all scripts share one write transaction that is only
+///   committed if every script succeeds. Each script must then be a single
+///   query, without `{ ... }` blocks or `::` system ops.
+///
+/// Returns one [`QueryResult`] per script, in order. Without `atomic`, every
+/// script runs on its own and failures are reported in their entry. With
+/// `atomic`, the first failure aborts the transaction and is returned as an
+/// error naming the failing script.
+Future<List<QueryResult>>  cozoRunBatch({required CozoDb db , required List<ScriptWithParams> scripts , required bool atomic }) => RustLib.instance.api.crateApiBatchCozoRunBatch(db: db, scripts: scripts, atomic: atomic);
+
+            /// One entry of a [`cozo_run_batch`] call.
+class ScriptWithParams  {
+                final String script;
+final Map<String, CozoValue> params;
+
+                const ScriptWithParams({required this.script ,required this.params ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => script.hashCode^params.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScriptWithParams &&
+                runtimeType == other.runtimeType
+                && script == other.script&& params == other.params;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `delay`, `is_busy`, `retry_busy`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Run scripts that fail because another connection holds SQLite's write
+/// lock again after a backoff, e.g. while a background isolate with its own
+/// handle is writing, instead of failing the call. `None` turns retrying
+/// off, which is the default.
+///
+/// Applies to `cozo_run_query` and its variants, batches, prepared queries,
+/// the row calls such as `cozo_put_rows`, and system ops the binding runs.
+/// A failed script has written nothing, so running it again is safe; calls
+/// made inside a `CozoTx` are not retried, since the transaction holds its
+/// earlier writes. Takes effect for this handle and the handles sharing its
+/// database, including queries prepared before.
+void  cozoSetBusyRetry({required CozoDb db , BusyRetryPolicy? policy }) => RustLib.instance.api.crateApiBusyRetryCozoSetBusyRetry(db: db, policy: policy);
+
+            /// How scripts failing with SQLite's "database is locked" are retried, see
+/// [`cozo_set_busy_retry`].
+class BusyRetryPolicy  {
+                /// Runs of a script in total, including the first; 1 disables retries.
+final int maxAttempts;
+/// Wait before the first retry.
+final BigInt initialDelayMs;
+/// Factor each wait is multiplied by for the next retry: 2.0 doubles
+/// it, 1.0 keeps it constant.
+final double multiplier;
+/// Upper bound of a single wait.
+final BigInt maxDelayMs;
+
+                const BusyRetryPolicy({required this.maxAttempts ,required this.initialDelayMs ,required this.multiplier ,required this.maxDelayMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxAttempts.hashCode^initialDelayMs.hashCode^multiplier.hashCode^maxDelayMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BusyRetryPolicy &&
+                runtimeType == other.runtimeType
+                && maxAttempts == other.maxAttempts&& initialDelayMs == other.initialDelayMs&& multiplier == other.multiplier&& maxDelayMs == other.maxDelayMs;
+        
+            }
+            
\ No newline at end of file
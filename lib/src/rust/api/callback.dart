@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `change`, `new`, `new`, `reads_relation`, `row_objects`, `rows`, `watch`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Visible`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Subscribe to changes committed to a stored relation.
+///
+/// - `relation`: name of the stored relation to watch
+///
+/// Each event is a [`RelationChange`] with typed values, one per commit that
+/// wrote to or removed rows from the relation.
+/// Rows hidden by the relation's row policy are left out, and an event with
+/// no row left is not sent; encrypted columns are decrypted.
+/// The stream stays open until [`cozo_unregister_callbacks`] is called for the
+/// relation or the Dart side cancels its subscription.
+Stream<RelationChange>  cozoRegisterCallback({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiCallbackCozoRegisterCallback(db: db, relation: relation);
+
+/// Subscribe to changes committed to several stored relations through one
+/// stream, e.g. to run a Dart handler whenever an order row is inserted.
+///
+/// - `relations`: names of the stored relations to watch
+///
+/// Each event is a JSON string
+/// `{"relation": name, "op": "Put" | "Rm", "new": [{column: value}], "old": [{column: value}]}`,
+/// with `new` and `old` as for [`cozo_register_callback`] but as one object
+/// per row, keyed by column name, ready to decode into a model class. Events
+/// of one relation arrive in commit order. The stream stays open until
+/// [`cozo_unregister_callbacks`] is called for every relation or the Dart
+/// side cancels its subscription, which stops watching all of them. Row
+/// policies and encrypted columns apply as for [`cozo_register_callback`].
+Stream<String>  cozoWatchRelations({required CozoDb db , required List<String> relations }) => RustLib.instance.api.crateApiCallbackCozoWatchRelations(db: db, relations: relations);
+
+/// Run a read-only query and run it again whenever a stored relation it
+/// reads changes, e.g. to drive a `StreamBuilder` without polling.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Each event is the query result as a JSON string, as returned by
+/// `cozo_run_query`. The first is sent right away, and later ones only when
+/// the rows differ from the last sent; changes committed while the query
+/// runs again are folded into one run. The relations read are those the
+/// script names with `*relation` or `~relation:index`. Each run applies the
+/// row policies in effect at the time. If running the query
+/// again fails, e.g. because a relation was dropped, the last event is
+/// `{"ok": false, "error": message}`. The stream stays open until
+/// [`cozo_unregister_callbacks`] is called for the relations read or the
+/// Dart side cancels its subscription.
+///
+/// Fails without opening the stream if the query fails the first time.
+Stream<String>  cozoWatchQuery({required CozoDb db , required String script , required String paramsJson }) => RustLib.instance.api.crateApiCallbackCozoWatchQuery(db: db, script: script, paramsJson: paramsJson);
+
+/// Close every change stream registered for `relation` through this handle.
+///
+/// Returns the number of streams closed.
+Future<int>  cozoUnregisterCallbacks({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiCallbackCozoUnregisterCallbacks(db: db, relation: relation);
+
+            /// Kind of a change to a stored relation.
+enum ChangeOp {
+                    put,
+rm,
+                    ;
+                    
+                }
+
+/// Rows a commit wrote to or removed from a stored relation, see
+/// [`cozo_register_callback`].
+class RelationChange  {
+                final String relation;
+final ChangeOp op;
+/// Column names, in the order of the values of each row.
+final List<String> headers;
+/// The rows written by a `Put`; empty for an `Rm`.
+final List<List<CozoValue>> newRows;
+/// The rows a `Put` replaced, or those an `Rm` removed.
+final List<List<CozoValue>> oldRows;
+
+                const RelationChange({required this.relation ,required this.op ,required this.headers ,required this.newRows ,required this.oldRows ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^op.hashCode^headers.hashCode^newRows.hashCode^oldRows.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RelationChange &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& op == other.op&& headers == other.headers&& newRows == other.newRows&& oldRows == other.oldRows;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,93 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `ensure_log`, `is_cdc_trigger`, `log_exists`, `trim`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Record every row put into or removed from `relations` in an internal log,
+/// read with [`cozo_cdc_read`] and trimmed with [`cozo_cdc_trim`], e.g. to
+/// feed sync, audit trails or cache invalidation.
+///
+/// Changes are recorded by triggers in the transaction that makes them, so
+/// the log survives restarts and never holds a change that was rolled back.
+/// Writes that do not run triggers, such as `cozo_import_relations` and
+/// restores, are not recorded. Triggers set on the relation later with
+/// `cozo_set_triggers` must keep the capture triggers, which
+/// `cozo_list_triggers` returns along with the others. Enabling a relation
+/// again does nothing.
+Future<void>  cozoCdcEnable({required CozoDb db , required List<String> relations }) => RustLib.instance.api.crateApiCdcCozoCdcEnable(db: db, relations: relations);
+
+/// Stop recording changes to `relation`. Changes already recorded stay in
+/// the log.
+Future<void>  cozoCdcDisable({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiCdcCozoCdcDisable(db: db, relation: relation);
+
+/// Read recorded changes in the order they were made.
+///
+/// - `from_tx`: first change id to return; pass the last `tx` read plus one
+///   to continue where the previous call stopped
+/// - `limit`: maximum number of entries returned
+///
+/// Returns at most `limit` entries with `tx >= from_tx`, every row of a
+/// change id together unless `limit` cuts it short.
+Future<List<CdcEntry>>  cozoCdcRead({required CozoDb db , required PlatformInt64 fromTx , required int limit }) => RustLib.instance.api.crateApiCdcCozoCdcRead(db: db, fromTx: fromTx, limit: limit);
+
+/// Delete recorded changes with a change id below `before_tx`, e.g. once
+/// every consumer has read them.
+///
+/// Returns the number of entries deleted.
+Future<BigInt>  cozoCdcTrim({required CozoDb db , required PlatformInt64 beforeTx }) => RustLib.instance.api.crateApiCdcCozoCdcTrim(db: db, beforeTx: beforeTx);
+
+/// Delete recorded changes older than `max_age_secs` seconds.
+///
+/// Returns the number of entries deleted.
+Future<BigInt>  cozoCdcTrimOlderThan({required CozoDb db , required double maxAgeSecs }) => RustLib.instance.api.crateApiCdcCozoCdcTrimOlderThan(db: db, maxAgeSecs: maxAgeSecs);
+
+            /// One row changed, as recorded by change data capture.
+class CdcEntry  {
+                /// Change id, increasing with every write statement to a captured
+/// relation. Rows written by one statement share it.
+final PlatformInt64 tx;
+final String relation;
+final CdcOp op;
+/// Values of the key columns of the row.
+final List<CozoValue> key;
+/// Unix time in seconds of the change.
+final double at;
+
+                const CdcEntry({required this.tx ,required this.relation ,required this.op ,required this.key ,required this.at ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tx.hashCode^relation.hashCode^op.hashCode^key.hashCode^at.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CdcEntry &&
+                runtimeType == other.runtimeType
+                && tx == other.tx&& relation == other.relation&& op == other.op&& key == other.key&& at == other.at;
+        
+            }
+
+/// Kind of a captured change.
+enum CdcOp {
+                    put,
+rm,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,98 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cipher_from_options`, `cipher`, `decrypt_rows`, `decrypt`, `encrypt_rows`, `encrypt`, `encrypted_columns`, `ensure_settings`, `is_encrypted`, `location`, `parse_key`, `rekey`, `rewrite_column`, `settings_exist`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Encrypt the values of a column with the `column_key` the database was
+/// opened with, so that sensitive fields stay unreadable in the file, its
+/// backups and exports without encrypting the whole database.
+///
+/// - `column`: a non-key `Bytes` or `Any` column, nullable or not
+///
+/// The values already in the column are encrypted now. From then on the
+/// row calls (`cozo_put_rows`, `cozo_upsert_rows`) encrypt the column on
+/// write, and every query result is decrypted on read, wherever the values
+/// end up in it: results of the query calls, in every encoding, rows from
+/// `cozo_get_row`, search hits and change stream events alike. Relation
+/// exports keep the ciphertext, so that they import back as they were.
+/// Scripts writing the column themselves must bind values from
+/// [`cozo_encrypt_value`]: plaintext they write is stored as it is. Nulls
+/// stay null. Each value gets a random nonce, so scripts cannot compare or
+/// filter on encrypted values, only pass them through.
+///
+/// Each value is bound to its relation, column and row key, which are
+/// authenticated with it. Query results are decrypted wherever the value
+/// sits in them, since a script may move it anywhere; rewriting the column
+/// (this call, [`cozo_decrypt_column`], [`cozo_rekey`]) fails on a value
+/// stored in another row or column than the one it was encrypted for.
+///
+/// Returns the number of values encrypted.
+Future<BigInt>  cozoEncryptColumn({required CozoDb db , required String relation , required String column }) => RustLib.instance.api.crateApiColumnCryptoCozoEncryptColumn(db: db, relation: relation, column: column);
+
+/// Store the values of an encrypted column in plaintext again.
+///
+/// Returns the number of values decrypted.
+Future<BigInt>  cozoDecryptColumn({required CozoDb db , required String relation , required String column }) => RustLib.instance.api.crateApiColumnCryptoCozoDecryptColumn(db: db, relation: relation, column: column);
+
+/// The encrypted columns of all relations.
+Future<List<EncryptedColumn>>  cozoListEncryptedColumns({required CozoDb db }) => RustLib.instance.api.crateApiColumnCryptoCozoListEncryptedColumns(db: db);
+
+/// Re-encrypt every encrypted column with `new_key`, 64 hex digits, and use
+/// it on this handle and the handles sharing its database from then on.
+///
+/// The storage itself is never encrypted (see `cozo_open_db`), so this
+/// rotates the `column_key`. Values are decrypted and encrypted again one at
+/// a time in memory, never written in plaintext, and all of them are
+/// rewritten in one transaction, so if the call fails, e.g. because the
+/// handle's key does not decrypt them, the stored values keep the old key.
+/// Queries decrypting values wait until it finishes; writes of encrypted
+/// columns racing with it may keep the old key, so run it while the app is
+/// not writing them. Open the database with `new_key` afterwards.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "values": n, "total": n, "elapsed": secs}`,
+/// where `values` is the number of values re-encrypted so far out of
+/// `total`. The stream closes after the `done` event, or fails with the
+/// error.
+Stream<String>  cozoRekey({required CozoDb db , required String newKey }) => RustLib.instance.api.crateApiColumnCryptoCozoRekey(db: db, newKey: newKey);
+
+/// `value` encrypted with the `column_key`, to bind as a parameter in a
+/// script writing it to `column` of `relation`, in the row with the key
+/// columns `key`, in schema order.
+CozoValue  cozoEncryptValue({required CozoDb db , required String relation , required String column , required List<CozoValue> key , required CozoValue value }) => RustLib.instance.api.crateApiColumnCryptoCozoEncryptValue(db: db, relation: relation, column: column, key: key, value: value);
+
+            /// A column whose values are stored encrypted, see [`cozo_encrypt_column`].
+class EncryptedColumn  {
+                final String relation;
+final String column;
+
+                const EncryptedColumn({required this.relation ,required this.column ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^column.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EncryptedColumn &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& column == other.column;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'simple.dart';
+part 'compression.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `check`, `compress_file`, `decompress_file`, `decompressed_backup`, `new`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Context`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `drop`, `eq`, `fmt`
+
+
+            /// Backup the database to a file, compressed as `compression` says.
+///
+/// Backups mostly hold keys and short strings and often shrink to a
+/// fraction of their size, at the cost of CPU time on backup and restore.
+/// The uncompressed backup is written to a temporary file first, so this
+/// needs free space for both files while it runs.
+///
+/// `cozo_restore`, `cozo_restore_with_progress`, `cozo_restore_from_bytes`,
+/// `cozo_import_from_backup` and `cozo_verify_backup` read compressed
+/// backups as they are, decompressing to a temporary file first.
+Future<void>  cozoBackupCompressed({required CozoDb db , required String path , required BackupCompression compression }) => RustLib.instance.api.crateApiCompressionCozoBackupCompressed(db: db, path: path, compression: compression);
+
+            @freezed
+                sealed class BackupCompression with _$BackupCompression  {
+                    const BackupCompression._();
+
+                     /// A plain backup file, as written by `cozo_backup`.
+const factory BackupCompression.none() = BackupCompression_None;
+ /// zstd at `level`: 1 is the fastest, 19 compresses well at a moderate
+/// cost and 22 is the smallest. 0 picks zstd's default of 3, and
+/// negative levels are faster still at the cost of size.
+const factory BackupCompression.zstd({   required int level , }) = BackupCompression_Zstd;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
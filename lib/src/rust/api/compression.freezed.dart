@@ -0,0 +1,333 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'compression.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$BackupCompression {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() none,
+    required TResult Function(int level) zstd,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? none,
+    TResult? Function(int level)? zstd,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? none,
+    TResult Function(int level)? zstd,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BackupCompression_None value) none,
+    required TResult Function(BackupCompression_Zstd value) zstd,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BackupCompression_None value)? none,
+    TResult? Function(BackupCompression_Zstd value)? zstd,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BackupCompression_None value)? none,
+    TResult Function(BackupCompression_Zstd value)? zstd,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BackupCompressionCopyWith<$Res> {
+  factory $BackupCompressionCopyWith(BackupCompression value, $Res Function(BackupCompression) then) =
+      _$BackupCompressionCopyWithImpl<$Res, BackupCompression>;
+}
+
+/// @nodoc
+class _$BackupCompressionCopyWithImpl<$Res, $Val extends BackupCompression>
+    implements $BackupCompressionCopyWith<$Res> {
+  _$BackupCompressionCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of BackupCompression
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$BackupCompression_NoneImplCopyWith<$Res> {
+  factory _$$BackupCompression_NoneImplCopyWith(
+          _$BackupCompression_NoneImpl value, $Res Function(_$BackupCompression_NoneImpl) then) =
+      __$$BackupCompression_NoneImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BackupCompression_NoneImplCopyWithImpl<$Res>
+    extends _$BackupCompressionCopyWithImpl<$Res, _$BackupCompression_NoneImpl>
+    implements _$$BackupCompression_NoneImplCopyWith<$Res> {
+  __$$BackupCompression_NoneImplCopyWithImpl(
+      _$BackupCompression_NoneImpl _value, $Res Function(_$BackupCompression_NoneImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BackupCompression
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$BackupCompression_NoneImpl extends BackupCompression_None {
+  const _$BackupCompression_NoneImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'BackupCompression.none()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BackupCompression_NoneImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() none,
+    required TResult Function(int level) zstd,
+  }) {
+    return none();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? none,
+    TResult? Function(int level)? zstd,
+  }) {
+    return none?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? none,
+    TResult Function(int level)? zstd,
+    required TResult orElse(),
+  }) {
+    if (none != null) {
+      return none();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BackupCompression_None value) none,
+    required TResult Function(BackupCompression_Zstd value) zstd,
+  }) {
+    return none(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BackupCompression_None value)? none,
+    TResult? Function(BackupCompression_Zstd value)? zstd,
+  }) {
+    return none?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BackupCompression_None value)? none,
+    TResult Function(BackupCompression_Zstd value)? zstd,
+    required TResult orElse(),
+  }) {
+    if (none != null) {
+      return none(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BackupCompression_None extends BackupCompression {
+  const factory BackupCompression_None() = _$BackupCompression_NoneImpl;
+  const BackupCompression_None._() : super._();
+}
+
+/// @nodoc
+abstract class _$$BackupCompression_ZstdImplCopyWith<$Res> {
+  factory _$$BackupCompression_ZstdImplCopyWith(
+          _$BackupCompression_ZstdImpl value, $Res Function(_$BackupCompression_ZstdImpl) then) =
+      __$$BackupCompression_ZstdImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int level});
+}
+
+/// @nodoc
+class __$$BackupCompression_ZstdImplCopyWithImpl<$Res>
+    extends _$BackupCompressionCopyWithImpl<$Res, _$BackupCompression_ZstdImpl>
+    implements _$$BackupCompression_ZstdImplCopyWith<$Res> {
+  __$$BackupCompression_ZstdImplCopyWithImpl(
+      _$BackupCompression_ZstdImpl _value, $Res Function(_$BackupCompression_ZstdImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BackupCompression
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? level = null,
+  }) {
+    return _then(_$BackupCompression_ZstdImpl(
+      level: null == level
+          ? _value.level
+          : level // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BackupCompression_ZstdImpl extends BackupCompression_Zstd {
+  const _$BackupCompression_ZstdImpl({required this.level})
+      : super._();
+
+  @override
+  final int level;
+
+  @override
+  String toString() {
+    return 'BackupCompression.zstd(level: $level)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BackupCompression_ZstdImpl &&
+            (identical(other.level, level) || other.level == level));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, level);
+
+  /// Create a copy of BackupCompression
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BackupCompression_ZstdImplCopyWith<_$BackupCompression_ZstdImpl> get copyWith =>
+      __$$BackupCompression_ZstdImplCopyWithImpl<_$BackupCompression_ZstdImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() none,
+    required TResult Function(int level) zstd,
+  }) {
+    return zstd(level);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? none,
+    TResult? Function(int level)? zstd,
+  }) {
+    return zstd?.call(level);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? none,
+    TResult Function(int level)? zstd,
+    required TResult orElse(),
+  }) {
+    if (zstd != null) {
+      return zstd(level);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BackupCompression_None value) none,
+    required TResult Function(BackupCompression_Zstd value) zstd,
+  }) {
+    return zstd(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BackupCompression_None value)? none,
+    TResult? Function(BackupCompression_Zstd value)? zstd,
+  }) {
+    return zstd?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BackupCompression_None value)? none,
+    TResult Function(BackupCompression_Zstd value)? zstd,
+    required TResult orElse(),
+  }) {
+    if (zstd != null) {
+      return zstd(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BackupCompression_Zstd extends BackupCompression {
+  const factory BackupCompression_Zstd({required final int level}) = _$BackupCompression_ZstdImpl;
+  const BackupCompression_Zstd._() : super._();
+
+  int get level;
+
+  /// Create a copy of BackupCompression
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BackupCompression_ZstdImplCopyWith<_$BackupCompression_ZstdImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
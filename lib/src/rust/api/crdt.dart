@@ -0,0 +1,93 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'schema.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `crdt_columns`, `ensure_tombstones`, `local_tombstones`, `merge_row`, `query`, `rows_json`, `tombstone_trigger`, `version_trigger`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Create a stored relation whose rows merge without conflicts between
+/// installs, for offline-first apps.
+///
+/// - `columns`: as for `cozo_create_relation`; names must not start with
+///   `cozo_crdt_`
+///
+/// Every value column is a last-writer-wins register: the relation gets a
+/// hidden `cozo_crdt_<column>` version column per value column, plus
+/// `cozo_crdt_row`, which triggers set to the time of each write. Removed
+/// rows leave a tombstone with the time of removal. Merging keeps, per
+/// column, the value written last; a removal wins over puts made before it.
+/// Ties go to the greater value, so every install converges on the same rows.
+///
+/// Write rows with `:put` or `:rm` naming only the app's columns: the
+/// version columns then take their defaults, which is how the triggers tell
+/// writes of the app from those of a merge. `:update` keeps the old
+/// versions and so is not tracked. Queries binding columns by name do not
+/// see the version columns.
+Future<void>  cozoCreateCrdtRelation({required CozoDb db , required String name , required List<ColumnDef> columns }) => RustLib.instance.api.crateApiCrdtCozoCreateCrdtRelation(db: db, name: name, columns: columns);
+
+/// Export the rows of CRDT relations written since `since`, with their
+/// versions, and their tombstones, for [`cozo_crdt_merge`] on another
+/// install.
+///
+/// - `relations`: relations created with [`cozo_create_crdt_relation`]
+/// - `since`: Unix time in seconds; 0 exports the full state
+///
+/// Merging is idempotent, so a changeset can be applied any number of times
+/// and in any order. Rows merged from other installs keep the times they
+/// were written there, so a changeset since a given time only carries them
+/// if they were written after it; send the full state to bring a new
+/// install up to date.
+///
+/// Returns a JSON changeset.
+Future<String>  cozoCrdtChanges({required CozoDb db , required List<String> relations , required double since }) => RustLib.instance.api.crateApiCrdtCozoCrdtChanges(db: db, relations: relations, since: since);
+
+/// Merge a changeset from [`cozo_crdt_changes`] of another install into
+/// the CRDT relations it holds.
+///
+/// Each value column keeps whichever of the local and remote values was
+/// written last, and rows removed on either side after their last put are
+/// removed. Merged rows keep their versions and do not run triggers, and
+/// all relations are merged in one transaction.
+///
+/// Fails without writing anything if a relation of the changeset is not a
+/// CRDT relation here.
+Future<CrdtMergeReport>  cozoCrdtMerge({required CozoDb db , required String changeset }) => RustLib.instance.api.crateApiCrdtCozoCrdtMerge(db: db, changeset: changeset);
+
+            /// Outcome of [`cozo_crdt_merge`].
+class CrdtMergeReport  {
+                /// Rows inserted or changed by the merge.
+final BigInt upserted;
+/// Rows removed by the merge.
+final BigInt deleted;
+
+                const CrdtMergeReport({required this.upserted ,required this.deleted ,});
+
+                static Future<CrdtMergeReport>  default_()=>RustLib.instance.api.crateApiCrdtCrdtMergeReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => upserted.hashCode^deleted.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CrdtMergeReport &&
+                runtimeType == other.runtimeType
+                && upserted == other.upserted&& deleted == other.deleted;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CursorState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Run a read-only query and open a cursor on its result, to read with
+/// [`cozo_cursor_next`] instead of running the query again with a growing
+/// `:offset`.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+/// - `page_size`: maximum number of rows per page
+Future<CozoCursor>  cozoOpenCursor({required CozoDb db , required String script , required String paramsJson , required int pageSize }) => RustLib.instance.api.crateApiCursorCozoOpenCursor(db: db, script: script, paramsJson: paramsJson, pageSize: pageSize);
+
+/// Read the next page of a cursor. Once the result is exhausted, pages are
+/// empty and `last`.
+Future<CursorPage>  cozoCursorNext({required CozoCursor cursor }) => RustLib.instance.api.crateApiCursorCozoCursorNext(cursor: cursor);
+
+/// Number of rows in the result of a cursor.
+BigInt  cozoCursorLen({required CozoCursor cursor }) => RustLib.instance.api.crateApiCursorCozoCursorLen(cursor: cursor);
+
+/// Free the rows of a cursor. Reading it afterwards fails.
+void  cozoCursorClose({required CozoCursor cursor }) => RustLib.instance.api.crateApiCursorCozoCursorClose(cursor: cursor);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CozoCursor>>
+                abstract class CozoCursor implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// A page of rows read from a [`CozoCursor`].
+class CursorPage  {
+                final List<String> headers;
+/// At most the cursor's page size of rows, one value per header.
+final List<List<CozoValue>> rows;
+/// Index of the first row of the page in the result.
+final BigInt offset;
+/// Whether the page holds the last rows of the result.
+final bool last;
+
+                const CursorPage({required this.headers ,required this.rows ,required this.offset ,required this.last ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => headers.hashCode^rows.hashCode^offset.hashCode^last.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CursorPage &&
+                runtimeType == other.runtimeType
+                && headers == other.headers&& rows == other.rows&& offset == other.offset&& last == other.last;
+        
+            }
+            
\ No newline at end of file
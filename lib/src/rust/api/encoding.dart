@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `encode_json`, `encode_result`, `encode_value`, `head`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Cbor`, `Msgpack`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `array`, `array`, `bool`, `bool`, `bytes`, `bytes`, `float`, `float`, `int`, `int`, `map`, `map`, `nil`, `nil`, `str`, `str`
+
+
+            /// Run a CozoScript query and return the result in a compact binary encoding.
+///
+/// - `encoding`: wire format of the returned bytes
+///
+/// Other arguments and the error behaviour are the same as `cozo_run_query`.
+/// The result has the same shape as the JSON one: a map with `headers`,
+/// `rows`, `next`, `ok` and `took`. In MessagePack and CBOR, bytes values are
+/// binary strings instead of base64 text; values with no native counterpart
+/// (UUIDs, vectors, validities, JSON) are encoded as in the JSON result.
+///
+/// Returns the encoded result.
+Future<Uint8List>  cozoRunQueryEncoded({required CozoDb db , required String script , required String paramsJson , required bool immutable , required ResultEncoding encoding }) => RustLib.instance.api.crateApiEncodingCozoRunQueryEncoded(db: db, script: script, paramsJson: paramsJson, immutable: immutable, encoding: encoding);
+
+            
+                abstract class Encoder {
+                    /// Start an array; the next `len` values are its items.
+ Future<void>  array({required BigInt len });
+
+
+ Future<void>  bool({required bool value });
+
+
+ Future<void>  bytes({required List<int> value });
+
+
+ Future<void>  float({required double value });
+
+
+ Future<void>  int({required PlatformInt64 value });
+
+
+/// Start a map; the next `2 * len` values are its keys and values.
+ Future<void>  map({required BigInt len });
+
+
+ Future<void>  nil();
+
+
+ Future<void>  str({required String value });
+
+
+                }
+                
+
+/// Wire format of a result from [`cozo_run_query_encoded`].
+enum ResultEncoding {
+                    /// UTF-8 JSON text, the same as [`crate::api::simple::cozo_run_query`].
+json,
+/// MessagePack.
+msgpack,
+/// CBOR (RFC 8949).
+cbor,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,131 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `catch_panic`, `failure`, `from_code`, `from_query`, `new`, `with_context`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`
+
+
+            
+
+            /// A failed call, with enough structure for Dart code to branch on.
+///
+/// Its message is the same text the call's error has always carried, e.g.
+/// `Query failed [parser::pest]: ...`, so code matching on messages keeps
+/// working.
+class CozoDartError implements FrbException {
+                final ErrorKind kind;
+final String message;
+/// cozo's diagnostic code, e.g. `eval::killed`, when there is one.
+final String? code;
+/// Where in the script the error was found, for script errors.
+final ErrorSpan? span;
+/// The stored relation the error is about, when cozo names one.
+final String? relation;
+/// For [`ErrorKind::Locked`], the holder of the lock when it is known.
+final LockHolder? lockHolder;
+
+                const CozoDartError({required this.kind ,required this.message ,this.code ,this.span ,this.relation ,this.lockHolder ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^message.hashCode^code.hashCode^span.hashCode^relation.hashCode^lockHolder.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CozoDartError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& message == other.message&& code == other.code&& span == other.span&& relation == other.relation&& lockHolder == other.lockHolder;
+        
+            }
+
+/// What went wrong, for branching on errors in Dart.
+enum ErrorKind {
+                    /// The script does not parse (`parser::*` codes).
+parse,
+/// The script parsed but failed while running.
+eval,
+/// The storage engine, a backup or a database file failed.
+storage,
+/// A write conflicted with the schema, an index or access levels
+/// (`tx::*` codes).
+transact,
+/// Imported data was malformed, or an import or export failed.
+importExport,
+/// The handle was closed with `cozo_close_db`.
+closed,
+/// Another process has the database open for writing; see
+/// `CozoDartError::lock_holder`.
+locked,
+/// The query ran past its timeout.
+timeout,
+/// The query was killed, by `cozo_kill_query` or the heap limit.
+cancelled,
+/// cozo or the binding panicked. The message holds the panic payload.
+internal,
+                    ;
+                    
+                }
+
+/// Byte offsets into the script of the part an error points at.
+class ErrorSpan  {
+                final int start;
+final int end;
+
+                const ErrorSpan({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ErrorSpan &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+/// The process holding a database another process failed to open.
+class LockHolder  {
+                final int pid;
+/// Unix time in seconds it opened the database.
+final double since;
+
+                const LockHolder({required this.pid ,required this.since ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^since.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LockHolder &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& since == other.since;
+        
+            }
+            
\ No newline at end of file
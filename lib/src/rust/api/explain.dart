@@ -0,0 +1,117 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `explain_raw`, `explain_source`, `run_explain`, `script_offset`, `strings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Explain how cozo would evaluate a script, without running it.
+///
+/// - `script`: a single query; imperative scripts and system ops cannot be
+///   explained
+///
+/// Returns the plan as a tree of rules and their steps.
+Future<QueryPlan>  cozoExplain({required CozoDb db , required String script }) => RustLib.instance.api.crateApiExplainCozoExplain(db: db, script: script);
+
+            /// One rule of a [`QueryPlan`]; `?` is the entry rule.
+class PlanRule  {
+                /// Strata are evaluated in increasing order.
+final int stratum;
+/// Position of the rule in the plan.
+final int ruleIdx;
+/// Rule name, with cozo's suffix for its binding pattern, e.g. `r|Mff`.
+final String name;
+/// Steps in evaluation order, ending with the `out` step.
+final List<PlanStep> steps;
+
+                const PlanRule({required this.stratum ,required this.ruleIdx ,required this.name ,required this.steps ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stratum.hashCode^ruleIdx.hashCode^name.hashCode^steps.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlanRule &&
+                runtimeType == other.runtimeType
+                && stratum == other.stratum&& ruleIdx == other.ruleIdx&& name == other.name&& steps == other.steps;
+        
+            }
+
+/// One step of a [`PlanRule`].
+class PlanStep  {
+                final int atomIdx;
+/// Operation, e.g. "load_stored", "stored_prefix_join", "unify" or "out".
+final String op;
+/// Relation or rule the step reads (stored relations start with `:`),
+/// or the variable a `unify` step binds.
+final String? reference;
+/// Join keys as (left, right) variable pairs.
+final List<(String,String)> joinsOn;
+/// Filters applied by the step.
+final List<String> filters;
+/// Expression of a `unify` step.
+final String? expr;
+/// Variables the step outputs.
+final List<String> output;
+
+                const PlanStep({required this.atomIdx ,required this.op ,this.reference ,required this.joinsOn ,required this.filters ,this.expr ,required this.output ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => atomIdx.hashCode^op.hashCode^reference.hashCode^joinsOn.hashCode^filters.hashCode^expr.hashCode^output.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlanStep &&
+                runtimeType == other.runtimeType
+                && atomIdx == other.atomIdx&& op == other.op&& reference == other.reference&& joinsOn == other.joinsOn&& filters == other.filters&& expr == other.expr&& output == other.output;
+        
+            }
+
+/// Query plan of a script, as reported by `::explain`.
+class QueryPlan  {
+                /// Rules in the order cozo lists them, grouped by stratum.
+final List<PlanRule> rules;
+/// Seconds spent parsing and planning the script, which is not run.
+final double took;
+
+                const QueryPlan({required this.rules ,required this.took ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => rules.hashCode^took.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryPlan &&
+                runtimeType == other.runtimeType
+                && rules == other.rules&& took == other.took;
+        
+            }
+            
\ No newline at end of file
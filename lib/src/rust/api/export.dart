@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `csv_field`, `encode_arrow_ipc`, `export_relation`, `write_csv`, `write_line`
+
+
+            /// Export relations to a file, writing one relation at a time so the whole
+/// export is never held in memory as a single string.
+///
+/// - `relations`: names of the stored relations to export
+/// - `path`: destination file, created or truncated
+/// - `format`: only "ndjson" is supported
+///
+/// The NDJSON output has, for each relation, a header line
+/// `{"relation": name, "headers": [..]}` followed by one JSON array per row.
+/// Relations with a row policy only export the rows it allows.
+///
+/// Returns the number of rows written.
+Future<BigInt>  cozoExportRelationsToFile({required CozoDb db , required List<String> relations , required String path , required String format }) => RustLib.instance.api.crateApiExportCozoExportRelationsToFile(db: db, relations: relations, path: path, format: format);
+
+/// Export the rows of a stored relation that match a filter, e.g. a single
+/// user's data for a GDPR export.
+///
+/// - `relation`: name of the stored relation to export
+/// - `filter_script`: conditions on the relation's columns, in CozoScript,
+///   e.g. `user_id == $uid` or `created_at > $since, status != 'draft'`
+/// - `params_json`: JSON object of named parameters, e.g. '{"uid": 42}'
+///
+/// Every column is bound by name, so the filter can use any of them. It
+/// runs as the body of a read-only query,
+/// `?[cols] := *relation{cols}, <filter_script>`.
+///
+/// Returns a JSON string in the same format as `cozo_export_relations`,
+/// `{"ok": true, "data": {relation: {"headers", "rows"}}}`, holding only the
+/// matching rows. Its `data` can be passed to `cozo_import_relations`.
+Future<String>  cozoExportFiltered({required CozoDb db , required String relation , required String filterScript , required String paramsJson }) => RustLib.instance.api.crateApiExportCozoExportFiltered(db: db, relation: relation, filterScript: filterScript, paramsJson: paramsJson);
+
+/// Run a read-only query and save its rows to a file.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+/// - `path`: destination file, created or truncated
+/// - `format`: "csv", or "parquet" (requires the `parquet` crate feature)
+///
+/// CSV output has a header row; nulls are empty fields, strings and numbers
+/// are written as-is and other values as JSON. Parquet columns are typed from
+/// their values as in [`cozo_run_query_arrow`]: all-integer columns are
+/// `Int64`, numeric ones `Float64`,
+/// then `Boolean` and `Binary`; anything else is a JSON or plain `Utf8` string.
+///
+/// Returns the number of rows written.
+Future<BigInt>  cozoQueryToFile({required CozoDb db , required String script , required String paramsJson , required String path , required String format }) => RustLib.instance.api.crateApiExportCozoQueryToFile(db: db, script: script, paramsJson: paramsJson, path: path, format: format);
+
+/// Run a read-only query and return its rows as an Arrow IPC stream, for
+/// analytics screens that would otherwise decode 100k+ rows of JSON.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Requires the `arrow` crate feature. Columns are typed from their values:
+/// all-integer columns are `Int64`, numeric ones `Float64`, then `Boolean`
+/// and `Binary`; anything else is a JSON or plain `Utf8` string. Every
+/// column is nullable and rows come in batches of 8192.
+///
+/// Returns the bytes of an IPC stream (schema message, then one message per
+/// batch), readable by arrow-dart and other Arrow implementations.
+Future<Uint8List>  cozoRunQueryArrow({required CozoDb db , required String script , required String paramsJson }) => RustLib.instance.api.crateApiExportCozoRunQueryArrow(db: db, script: script, paramsJson: paramsJson);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `held`, `lock_database`, `lock_path`, `locked_error`, `process_alive`, `read_holder`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FileLock`, `HeldLock`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
+
+
+            /// Delete the lock file of the database at `path`, e.g. after a crash on a
+/// file system without file locks left a claim `cozo_open_db` keeps
+/// reporting as `Locked`.
+///
+/// Only call it when the process named in the error is really gone: a
+/// process still writing the database keeps doing so, and two writers can
+/// corrupt it. Fails if this process holds the lock; close its handles
+/// instead.
+///
+/// Returns false if there was no lock file.
+bool  cozoForceUnlock({required String path }) => RustLib.instance.api.crateApiFileLockCozoForceUnlock(path: path);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `inputs_to_json`, `options_to_json`
+
+
+            /// Register a Dart function as a custom fixed rule usable from CozoScript.
+///
+/// - `name`: rule name, used as `?[..] <~ Name(input[], option: ..)`
+/// - `arity`: number of columns in every row the rule returns
+/// - `rule`: called with a JSON array of the input relations
+///   (`[{"headers", "rows"}, ..]`) and a JSON object of the rule options;
+///   must complete with a JSON object `{"headers", "rows"}` for the output
+///
+/// The calling query blocks while the Dart function runs, so queries using
+/// the rule must not be issued from a `#[frb(sync)]` call.
+Future<void>  cozoRegisterFixedRule({required CozoDb db , required String name , required BigInt arity , required FutureOr<String> Function(String, String) rule }) => RustLib.instance.api.crateApiFixedRuleCozoRegisterFixedRule(db: db, name: name, arity: arity, rule: rule);
+
+/// Remove a fixed rule registered with [`cozo_register_fixed_rule`].
+///
+/// Returns whether a rule with that name existed.
+Future<bool>  cozoUnregisterFixedRule({required CozoDb db , required String name }) => RustLib.instance.api.crateApiFixedRuleCozoUnregisterFixedRule(db: db, name: name);
+
+            
+            
\ No newline at end of file
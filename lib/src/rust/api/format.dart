@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `block_comment_len`, `break_rule`, `indent`, `is_closer`, `quoted_len`, `raw_string_len`, `render`, `tokenize`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Kind`, `Token`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Pretty-print a CozoScript script.
+///
+/// Only whitespace outside strings and comments is changed, so the result
+/// means the same as the input:
+///
+/// - blocks (`{ ... }` around a query or an imperative statement) and open
+///   brackets are indented by four spaces per level
+/// - rule bodies continued on the next line are indented one level deeper
+///   than their head
+/// - rules longer than 80 columns get one body atom per line
+/// - `:=`, `<-` and `<~` are surrounded by single spaces, commas are
+///   followed by one, and other runs of spaces are collapsed to one
+/// - trailing spaces are removed and blank lines are collapsed to one
+///
+/// Scripts that do not parse are formatted as far as they can be tokenized;
+/// nothing is validated.
+///
+/// Returns the formatted script, with a final newline if the input had one.
+String  cozoFormatScript({required String script }) => RustLib.instance.api.crateApiFormatCozoFormatScript(script: script);
+
+            
+            
\ No newline at end of file
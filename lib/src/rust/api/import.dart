@@ -0,0 +1,200 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `coerce_rows`, `coerce`, `insert_batch`, `named_rows`, `put_script`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Import a CSV file with a header row into a stored relation.
+///
+/// Fields are coerced to the relation's column types: `Int`, `Float`, `Bool`
+/// ("true"/"false", "1"/"0", "yes"/"no"), `String`, `Uuid` and `Json` are
+/// parsed directly, and any other type (lists, vectors, `Any`) is parsed as
+/// JSON, falling back to a string. Empty fields in nullable columns become
+/// null. Columns with a default may be missing from the CSV.
+///
+/// Rows are inserted in batches of `batch_size`, each in its own transaction,
+/// so a failure leaves earlier batches in place.
+///
+/// Returns the number of rows imported.
+Future<BigInt>  cozoImportCsv({required CozoDb db , required String path , required CsvImportConfig config }) => RustLib.instance.api.crateApiImportCozoImportCsv(db: db, path: path, config: config);
+
+/// Import relations into the database, choosing what happens to rows that
+/// already exist, e.g. to restore a partial export into a live database
+/// without overwriting newer local rows.
+///
+/// - `data_json`: JSON string in the same format as export output
+///
+/// Imported values are converted to the column types before they are
+/// compared with local rows, so a row re-imported from an export of the
+/// same data is unchanged and is not written again. All relations are
+/// written in one transaction, and like `cozo_import_relations` this does
+/// not run triggers. Indices cannot be imported into.
+///
+/// Run with `dry_run` first to show the conflicts for review before
+/// importing: a dry run with [`ImportStrategy::FailOnConflict`] returns the
+/// report instead of failing.
+///
+/// Returns what was written, deleted and left alone.
+Future<ImportReport>  cozoImportRelationsWithOptions({required CozoDb db , required String dataJson , required ImportOptions options }) => RustLib.instance.api.crateApiImportCozoImportRelationsWithOptions(db: db, dataJson: dataJson, options: options);
+
+            /// How a CSV file is mapped onto a stored relation.
+class CsvImportConfig  {
+                /// Stored relation to insert into. It must already exist.
+final String relation;
+/// Relation column name to CSV header name. Columns not listed here are
+/// read from the CSV column with the same name.
+final Map<String, String> columnMap;
+/// Field delimiter, a single character such as "," or ";".
+final String delimiter;
+/// Number of rows inserted per transaction.
+final int batchSize;
+/// Treat empty fields as null even for non-nullable columns, so a missing
+/// value is rejected instead of being stored as "".
+final bool emptyAsNull;
+/// Trim whitespace around every field before coercion.
+final bool trim;
+
+                const CsvImportConfig({required this.relation ,required this.columnMap ,required this.delimiter ,required this.batchSize ,required this.emptyAsNull ,required this.trim ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^columnMap.hashCode^delimiter.hashCode^batchSize.hashCode^emptyAsNull.hashCode^trim.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CsvImportConfig &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& columnMap == other.columnMap&& delimiter == other.delimiter&& batchSize == other.batchSize&& emptyAsNull == other.emptyAsNull&& trim == other.trim;
+        
+            }
+
+/// An imported row whose key exists locally with different values.
+class ImportConflict  {
+                final String relation;
+/// Values of the key columns.
+final List<CozoValue> key;
+/// Non-key values of the local row.
+final List<CozoValue> local;
+/// Non-key values of the imported row, converted to the column types.
+final List<CozoValue> incoming;
+
+                const ImportConflict({required this.relation ,required this.key ,required this.local ,required this.incoming ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^key.hashCode^local.hashCode^incoming.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportConflict &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& key == other.key&& local == other.local&& incoming == other.incoming;
+        
+            }
+
+/// Settings of [`cozo_import_relations_with_options`].
+class ImportOptions  {
+                final ImportStrategy strategy;
+/// Work out what the import would do without writing anything.
+final bool dryRun;
+
+                const ImportOptions({required this.strategy ,required this.dryRun ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => strategy.hashCode^dryRun.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportOptions &&
+                runtimeType == other.runtimeType
+                && strategy == other.strategy&& dryRun == other.dryRun;
+        
+            }
+
+/// What [`cozo_import_relations_with_options`] did, or with `dry_run` would
+/// do, summed over all imported relations.
+class ImportReport  {
+                /// Rows whose key did not exist locally.
+final BigInt inserted;
+/// Local rows overwritten with different values.
+final BigInt updated;
+/// Conflicting rows not written because the local row was kept.
+final BigInt skipped;
+/// Rows identical to the local row, which are not written.
+final BigInt unchanged;
+/// Local rows deleted by [`ImportStrategy::Replace`].
+final BigInt deleted;
+/// Number of imported rows whose key exists locally with different
+/// values, whatever the strategy did with them.
+final BigInt conflictCount;
+/// The first conflicts, in relation and key order, at most 100.
+final List<ImportConflict> conflicts;
+
+                const ImportReport({required this.inserted ,required this.updated ,required this.skipped ,required this.unchanged ,required this.deleted ,required this.conflictCount ,required this.conflicts ,});
+
+                static Future<ImportReport>  default_()=>RustLib.instance.api.crateApiImportImportReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => inserted.hashCode^updated.hashCode^skipped.hashCode^unchanged.hashCode^deleted.hashCode^conflictCount.hashCode^conflicts.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportReport &&
+                runtimeType == other.runtimeType
+                && inserted == other.inserted&& updated == other.updated&& skipped == other.skipped&& unchanged == other.unchanged&& deleted == other.deleted&& conflictCount == other.conflictCount&& conflicts == other.conflicts;
+        
+            }
+
+/// How [`cozo_import_relations_with_options`] treats rows whose key already
+/// exists in the database.
+enum ImportStrategy {
+                    /// Make each imported relation hold exactly the imported rows, deleting
+/// local rows whose key is not in the import.
+replace,
+/// Write every imported row, overwriting local rows with the same key
+/// and keeping the others, as `cozo_import_relations` does.
+upsert,
+/// Write only rows whose key does not exist locally, keeping local rows.
+skipExisting,
+/// Fail without writing anything if any imported row has the key of a
+/// local row with different values.
+failOnConflict,
+                    ;
+                    
+                }
+            
\ No newline at end of file
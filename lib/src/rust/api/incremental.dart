@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_increment`, `read_manifest`, `relation_checksum`, `replace_rows`, `scan`, `schema_signature`, `write_manifest`, `write_relations`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Back up the database into `dir`, writing only the relations whose rows
+/// changed since the previous backup in that directory.
+///
+/// - `dir`: a directory of its own for this database's backups, created if
+///   missing, with a `manifest.json` recording every backup in it
+///
+/// The first backup is a full one. Later calls compare a checksum of every
+/// relation's rows with the manifest and write the changed relations, in
+/// full, to a new file. A full backup is written instead when relations or
+/// indices were created, removed or changed since the last full backup, as
+/// increments only carry rows. Checksums are computed by reading every row,
+/// so each call reads the whole database but writes only what changed.
+///
+/// Restore the chain with [`cozo_restore_incremental`]. Files of older
+/// chains can be deleted once a newer full backup exists.
+Future<IncrementalBackupInfo>  cozoBackupIncremental({required CozoDb db , required String dir }) => RustLib.instance.api.crateApiIncrementalCozoBackupIncremental(db: db, dir: dir);
+
+/// Restore the newest backup chain in `dir`: its full backup, then every
+/// later increment in order.
+///
+/// Like `cozo_restore`, the database must be empty. Rows of an increment
+/// replace all rows of their relation, with triggers off, so the result is
+/// the database as it was at the newest backup.
+///
+/// Returns the paths of the files applied, the full backup first.
+Future<List<String>>  cozoRestoreIncremental({required CozoDb db , required String dir }) => RustLib.instance.api.crateApiIncrementalCozoRestoreIncremental(db: db, dir: dir);
+
+            /// A backup written by [`cozo_backup_incremental`].
+class IncrementalBackupInfo  {
+                /// Path of the new backup file.
+final String path;
+/// Whether this is a full backup, starting a new chain.
+final bool full;
+/// Relations stored in the file: every relation for a full backup, the
+/// changed ones for an incremental backup.
+final List<String> relations;
+/// Unix time in seconds.
+final double createdAt;
+
+                const IncrementalBackupInfo({required this.path ,required this.full ,required this.relations ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^full.hashCode^relations.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IncrementalBackupInfo &&
+                runtimeType == other.runtimeType
+                && path == other.path&& full == other.full&& relations == other.relations&& createdAt == other.createdAt;
+        
+            }
+            
\ No newline at end of file
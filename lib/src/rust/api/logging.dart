@@ -0,0 +1,31 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `install`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DartLogger`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `enabled`, `flush`, `log`
+
+
+            /// Forward log records from the binding and cozo to Dart.
+///
+/// - `level`: most verbose level to forward: "error", "warn", "info",
+///   "debug" or "trace"
+///
+/// Each event is a JSON string `{"level", "target", "message"}`, e.g.
+/// `{"level": "WARN", "target": "cozo::runtime::db", "message": "..."}`;
+/// print it or hand it to the app's own logger. Opening a new stream
+/// replaces the previous one.
+///
+/// The forwarding logger replaces the platform console logger that the
+/// bridge would otherwise install, so Rust logs only appear while a stream
+/// is open.
+Stream<String>  cozoLogStream({required String level }) => RustLib.instance.api.crateApiLoggingCozoLogStream(level: level);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `allows`, `new`, `step`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Budget`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Do as much housekeeping as fits in `budget_ms` milliseconds, e.g. from an
+/// iOS `BGProcessingTask` or an Android `WorkManager` job, which the OS
+/// kills once their time is up.
+///
+/// The work is done in small steps, each committed on its own: copying the
+/// write-ahead log into the database file, deleting expired rows (see
+/// `cozo_set_ttl`), then returning free pages to the file system. A step
+/// only starts if the time left is more than twice the longest step so far,
+/// so the call returns before the budget expires rather than in the middle
+/// of a write; report `finished == false` to the OS so that it schedules the
+/// task again.
+///
+/// Free pages are only returned in steps from SQLite files with
+/// `auto_vacuum` set to incremental; other files keep them until
+/// `cozo_compact`, which rewrites the whole file in one go and does not fit
+/// in a budget.
+Future<MaintenanceReport>  cozoBeginMaintenance({required CozoDb db , required BigInt budgetMs }) => RustLib.instance.api.crateApiMaintenanceCozoBeginMaintenance(db: db, budgetMs: budgetMs);
+
+            /// What [`cozo_begin_maintenance`] did.
+class MaintenanceReport  {
+                /// Whether all the work is done; false if the budget ran out first, in
+/// which case the next call continues where this one stopped.
+final bool finished;
+/// Expired rows deleted, see `cozo_set_ttl`.
+final BigInt rowsPurged;
+/// SQLite pages returned to the file system.
+final BigInt pagesFreed;
+final BigInt elapsedMs;
+
+                const MaintenanceReport({required this.finished ,required this.rowsPurged ,required this.pagesFreed ,required this.elapsedMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => finished.hashCode^rowsPurged.hashCode^pagesFreed.hashCode^elapsedMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MaintenanceReport &&
+                runtimeType == other.runtimeType
+                && finished == other.finished&& rowsPurged == other.rowsPurged&& pagesFreed == other.pagesFreed&& elapsedMs == other.elapsedMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,130 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `error_kind`, `percentile`, `record_cache_lookup`, `record_query`, `record_query`, `record_rows_written`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Metrics`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Counters and latency histogram of the queries run on the database, for
+/// feeding app performance dashboards.
+///
+/// Queries run through `cozo_run_query` and the calls built on it, and
+/// through `cozo_run_query_typed`, are counted; counting is in memory and
+/// shared by every handle to the database. See [`CozoMetrics`] for what each
+/// counter covers.
+CozoMetrics  cozoMetrics({required CozoDb db }) => RustLib.instance.api.crateApiMetricsCozoMetrics(db: db);
+
+/// Set every counter of [`cozo_metrics`] back to zero, starting `since` now.
+void  cozoResetMetrics({required CozoDb db }) => RustLib.instance.api.crateApiMetricsCozoResetMetrics(db: db);
+
+            /// Counters of a database since it was opened or [`cozo_reset_metrics`].
+class CozoMetrics  {
+                /// When counting started, in seconds since the Unix epoch.
+final double since;
+/// Queries run, failed ones included.
+final BigInt queries;
+/// Queries whose script contains a mutation option such as `:put` or
+/// `:rm`, the same approximation as `TransactionInfo::write_scripts`.
+final BigInt writeQueries;
+/// Rows returned by queries that did not write.
+final BigInt rowsRead;
+/// Rows written by `cozo_put_rows`, `cozo_upsert_rows` and
+/// `cozo_delete_rows`. cozo does not report rows written by a script.
+final BigInt rowsWritten;
+final BigInt errors;
+/// Failed queries by kind, only kinds seen.
+final List<ErrorCount> errorsByKind;
+/// Latency percentiles as the upper bound of the bucket they fall in,
+/// `None` before any query ran or when it falls in the open bucket.
+final double? latencyP50Ms;
+final double? latencyP99Ms;
+final double? latencyMeanMs;
+final List<LatencyBucket> latencyBuckets;
+/// Results of `cozo_run_query_cached` served from the cache, and those
+/// that ran the query while the cache was enabled.
+final BigInt cacheHits;
+final BigInt cacheMisses;
+/// `cache_hits` over lookups, `None` before any lookup.
+final double? cacheHitRate;
+
+                const CozoMetrics({required this.since ,required this.queries ,required this.writeQueries ,required this.rowsRead ,required this.rowsWritten ,required this.errors ,required this.errorsByKind ,this.latencyP50Ms ,this.latencyP99Ms ,this.latencyMeanMs ,required this.latencyBuckets ,required this.cacheHits ,required this.cacheMisses ,this.cacheHitRate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => since.hashCode^queries.hashCode^writeQueries.hashCode^rowsRead.hashCode^rowsWritten.hashCode^errors.hashCode^errorsByKind.hashCode^latencyP50Ms.hashCode^latencyP99Ms.hashCode^latencyMeanMs.hashCode^latencyBuckets.hashCode^cacheHits.hashCode^cacheMisses.hashCode^cacheHitRate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CozoMetrics &&
+                runtimeType == other.runtimeType
+                && since == other.since&& queries == other.queries&& writeQueries == other.writeQueries&& rowsRead == other.rowsRead&& rowsWritten == other.rowsWritten&& errors == other.errors&& errorsByKind == other.errorsByKind&& latencyP50Ms == other.latencyP50Ms&& latencyP99Ms == other.latencyP99Ms&& latencyMeanMs == other.latencyMeanMs&& latencyBuckets == other.latencyBuckets&& cacheHits == other.cacheHits&& cacheMisses == other.cacheMisses&& cacheHitRate == other.cacheHitRate;
+        
+            }
+
+/// Number of failed queries of one [`ErrorKind`].
+class ErrorCount  {
+                final ErrorKind kind;
+final BigInt count;
+
+                const ErrorCount({required this.kind ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ErrorCount &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& count == other.count;
+        
+            }
+
+/// Queries that took at most `le_ms` and longer than the previous bucket's.
+class LatencyBucket  {
+                /// `None` for the last bucket, which has no upper bound.
+final double? leMs;
+final BigInt count;
+
+                const LatencyBucket({this.leMs ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => leMs.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LatencyBucket &&
+                runtimeType == other.runtimeType
+                && leMs == other.leMs&& count == other.count;
+        
+            }
+            
\ No newline at end of file
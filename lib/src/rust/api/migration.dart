@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `applied_versions`, `migration_script`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Run every migration that has not been applied to this database yet.
+///
+/// - `migrations`: the app's full list of migrations, in any order
+///
+/// Applied versions are recorded in the `cozo_migrations` stored relation.
+/// Pending migrations run in version order, each in one transaction together
+/// with its bookkeeping, so a failed migration leaves no trace and can be
+/// retried. Migrations that ran before the failure stay applied, and the
+/// error names the failing version.
+Future<MigrationReport>  cozoMigrate({required CozoDb db , required List<Migration> migrations }) => RustLib.instance.api.crateApiMigrationCozoMigrate(db: db, migrations: migrations);
+
+            /// One schema change, applied at most once per database.
+class Migration  {
+                /// Unique, ordered version number.
+final PlatformInt64 version;
+/// CozoScript to run: a single query or a chain of `{ ... }` blocks.
+final String script;
+
+                const Migration({required this.version ,required this.script ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => version.hashCode^script.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Migration &&
+                runtimeType == other.runtimeType
+                && version == other.version&& script == other.script;
+        
+            }
+
+/// Outcome of [`cozo_migrate`].
+class MigrationReport  {
+                /// Versions applied by this call, in order.
+final Int64List applied;
+/// Highest applied version after this call, `None` if none ever ran.
+final PlatformInt64? currentVersion;
+
+                const MigrationReport({required this.applied ,this.currentVersion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => applied.hashCode^currentVersion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MigrationReport &&
+                runtimeType == other.runtimeType
+                && applied == other.applied&& currentVersion == other.currentVersion;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `app_name`, `env_dir`, `home`, `platform_dir`, `private_cache_dir`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// The path to open the database `name` with in `location`, creating the
+/// directory it goes in.
+///
+/// - `name`: letters, digits, `-`, `_` and `.`, not starting with `.`
+/// - `engine`: "sqlite" gives the file `<name>.db`, "rocksdb" the directory
+///   `<name>` (created by `cozo_open_db`), "mem" an empty path
+/// - `base_dir`: the directory to use instead of looking it up, e.g. from
+///   `getApplicationSupportDirectory()` in `path_provider`
+///
+/// Without `base_dir` the directory is derived from the process: `$HOME`
+/// in the app container on iOS, `/data/user/<user>/<package>` on Android,
+/// and the app's directories named after the executable on desktop
+/// (`~/Library/Application Support/<app>`, `%APPDATA%\<app>`,
+/// `$XDG_DATA_HOME/<app>`, ...). That matches `path_provider` on mobile;
+/// on desktop, where `path_provider` uses the bundle or application id,
+/// pass `base_dir` to keep files where the rest of the app keeps them.
+Future<String>  cozoDbPath({required String name , required DbLocation location , required String engine , String? baseDir }) => RustLib.instance.api.crateApiPathsCozoDbPath(name: name, location: location, engine: engine, baseDir: baseDir);
+
+            /// Which platform directory [`cozo_db_path`] puts a database in.
+enum DbLocation {
+                    /// Private app data that the user does not manage: Application Support
+/// on iOS and macOS, the app's files directory on Android. The usual
+/// choice.
+appSupport,
+/// User-visible documents: the Documents folder on iOS (shown in the
+/// Files app if the app enables file sharing) and desktop. On Android,
+/// scoped storage keeps apps out of the shared Documents folder, so this
+/// is the app's files directory, as for `AppSupport`.
+documents,
+/// Data that can be rebuilt: the OS may delete it when space runs low,
+/// and it is not backed up.
+cache,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `script_params`
+
+
+            /// Prepare a query for [`cozo_run_prepared`].
+PreparedQuery  cozoPrepare({required CozoDb db , required String script }) => RustLib.instance.api.crateApiPreparedCozoPrepare(db: db, script: script);
+
+/// Run a prepared query.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+/// - `immutable`: if true, the query is run in read-only mode
+///
+/// Fails without running the query if a parameter the script uses has no
+/// value, from `params_json` or the row policy parameters.
+///
+/// Returns the same JSON result as [`crate::api::simple::cozo_run_query`].
+Future<String>  cozoRunPrepared({required PreparedQuery prepared , required String paramsJson , required bool immutable }) => RustLib.instance.api.crateApiPreparedCozoRunPrepared(prepared: prepared, paramsJson: paramsJson, immutable: immutable);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PreparedQuery>>
+                abstract class PreparedQuery implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+            
\ No newline at end of file
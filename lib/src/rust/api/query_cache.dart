@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `clear`, `digest`, `evict`, `invalidate`, `watch`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Entry`, `QueryCache`, `Watch`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Keep the results of queries run with [`cozo_run_query_cached`], so
+/// repeated identical reads, e.g. from several widgets, do not run the
+/// query again.
+///
+/// - `capacity`: number of results kept, least recently used dropped first
+///
+/// A result is dropped as soon as a script commits a write to a stored
+/// relation the query reads, i.e. one it names with `*relation` or
+/// `~relation:index`. Writes that do not go through a script, such as
+/// `cozo_import_relations` and restores, are not noticed: call
+/// [`cozo_clear_query_cache`] after them. Calling this again changes the
+/// capacity and keeps the results that fit.
+Future<void>  cozoEnableQueryCache({required CozoDb db , required int capacity }) => RustLib.instance.api.crateApiQueryCacheCozoEnableQueryCache(db: db, capacity: capacity);
+
+/// Stop caching query results and drop the cached ones.
+void  cozoDisableQueryCache({required CozoDb db }) => RustLib.instance.api.crateApiQueryCacheCozoDisableQueryCache(db: db);
+
+/// Drop every cached query result, keeping the cache enabled.
+void  cozoClearQueryCache({required CozoDb db }) => RustLib.instance.api.crateApiQueryCacheCozoClearQueryCache(db: db);
+
+/// Run a read-only query like `cozo_run_query`, returning the cached result
+/// of the same script with the same `params_json` if there is one.
+///
+/// The script is run immutable. Without [`cozo_enable_query_cache`] every
+/// call runs the query. A cached result is returned exactly as it was
+/// first, including its `took`.
+Future<String>  cozoRunQueryCached({required CozoDb db , required String script , required String paramsJson }) => RustLib.instance.api.crateApiQueryCacheCozoRunQueryCached(db: db, script: script, paramsJson: paramsJson);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,106 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `corruption`, `database_files`, `move_files`, `open_failure_corruption`, `quick_check`, `remove_files`, `salvage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Open a database, replacing it according to `policy` if it is corrupt.
+///
+/// - `engine`, `path`, `options`: as for `cozo_open_db`
+///
+/// A sqlite database is corrupt if `PRAGMA quick_check` finds a problem, and
+/// a rocksdb database if opening it fails with a corruption error. Salvaged relations
+/// keep their columns and triggers, but not column defaults or indices, and
+/// their rows are copied without running triggers. The replacement is built
+/// next to the original and moved into place only when complete; the corrupt
+/// files are renamed to `<path>.corrupt-<unix time>`.
+///
+/// Fails if opening fails for another reason, such as a missing directory
+/// or a locked file, or with the corruption error under
+/// [`RecoveryPolicy::Fail`].
+Future<RecoveredDb>  cozoOpenOrRecover({required String engine , required String path , required String options , required RecoveryPolicy policy }) => RustLib.instance.api.crateApiRecoveryCozoOpenOrRecover(engine: engine, path: path, options: options, policy: policy);
+
+            /// A database opened by [`cozo_open_or_recover`].
+class RecoveredDb  {
+                final CozoDb db;
+final RecoveryReport report;
+
+                const RecoveredDb({required this.db ,required this.report ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => db.hashCode^report.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecoveredDb &&
+                runtimeType == other.runtimeType
+                && db == other.db&& report == other.report;
+        
+            }
+
+/// What [`cozo_open_or_recover`] does with a database that cannot be read.
+enum RecoveryPolicy {
+                    /// Fail with the corruption error, leaving the files untouched.
+fail,
+/// Copy every relation that can still be read into a fresh database,
+/// which replaces the corrupt one.
+salvage,
+/// Replace the corrupt database with an empty one.
+reset,
+                    ;
+                    
+                }
+
+/// What [`cozo_open_or_recover`] found and did.
+class RecoveryReport  {
+                /// Whether the database was corrupt and has been replaced.
+final bool recovered;
+/// Why the database was considered corrupt, `None` if it was not.
+final String? reason;
+/// Where the corrupt database was moved to, kept for manual inspection.
+final String? corruptPath;
+/// Relations copied with all their rows.
+final List<String> relationsRecovered;
+final BigInt rowsRecovered;
+/// Relations whose rows could not be read. They exist in the new
+/// database, but empty.
+final List<String> relationsLost;
+/// Indices that were not recreated, as `relation:index`. Recreate them
+/// with the calls that made them.
+final List<String> indicesDropped;
+
+                const RecoveryReport({required this.recovered ,this.reason ,this.corruptPath ,required this.relationsRecovered ,required this.rowsRecovered ,required this.relationsLost ,required this.indicesDropped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => recovered.hashCode^reason.hashCode^corruptPath.hashCode^relationsRecovered.hashCode^rowsRecovered.hashCode^relationsLost.hashCode^indicesDropped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecoveryReport &&
+                runtimeType == other.runtimeType
+                && recovered == other.recovered&& reason == other.reason&& corruptPath == other.corruptPath&& relationsRecovered == other.relationsRecovered&& rowsRecovered == other.rowsRecovered&& relationsLost == other.relationsLost&& indicesDropped == other.indicesDropped;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `named`, `tokens`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NamedDb`
+
+
+            /// Open a database under a process-wide name, or share it if that name is
+/// already open.
+///
+/// - `name`: registry key, e.g. "main"
+/// - `engine`, `path`, `options`: as for `cozo_open_db`, used only by the
+///   first open of `name`
+///
+/// Every open or [`cozo_get_named`] takes a reference that
+/// [`cozo_close_named`] gives back. All returned handles share one
+/// underlying instance. Opening an existing name with a different engine or
+/// path fails.
+///
+/// Returns a handle to the named database.
+CozoDb  cozoOpenNamed({required String name , required String engine , required String path , required String options }) => RustLib.instance.api.crateApiRegistryCozoOpenNamed(name: name, engine: engine, path: path, options: options);
+
+/// Get another handle to a database opened with [`cozo_open_named`].
+///
+/// Takes a reference like [`cozo_open_named`], to be given back with
+/// [`cozo_close_named`].
+CozoDb  cozoGetNamed({required String name }) => RustLib.instance.api.crateApiRegistryCozoGetNamed(name: name);
+
+/// Give back a reference taken by [`cozo_open_named`] or [`cozo_get_named`].
+///
+/// When the last reference is given back the database is closed, which
+/// closes every handle to it, and the name becomes free.
+///
+/// Returns the number of references still held.
+int  cozoCloseNamed({required String name }) => RustLib.instance.api.crateApiRegistryCozoCloseNamed(name: name);
+
+/// Create a token for the database that can be sent to another isolate.
+///
+/// Opaque handles are bound to the isolate that created them, but the token
+/// is a plain integer: pass it through a `SendPort` or isolate arguments and
+/// call [`cozo_attach`] on the other side. Handles attached from the token
+/// share the same instance and can be used concurrently.
+///
+/// The token stays valid until [`cozo_release_token`]. Closing the database
+/// closes every attached handle too.
+BigInt  cozoShareToken({required CozoDb db }) => RustLib.instance.api.crateApiRegistryCozoShareToken(db: db);
+
+/// Get a handle in the current isolate from a token made by [`cozo_share_token`].
+CozoDb  cozoAttach({required BigInt token }) => RustLib.instance.api.crateApiRegistryCozoAttach(token: token);
+
+/// Invalidate a token. Handles already attached from it keep working.
+///
+/// Returns false if the token was unknown or already released.
+bool  cozoReleaseToken({required BigInt token }) => RustLib.instance.api.crateApiRegistryCozoReleaseToken(token: token);
+
+            
+            
\ No newline at end of file
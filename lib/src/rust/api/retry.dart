@@ -0,0 +1,66 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'batch.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `delay`, `is_conflict`, `with_retry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// Run a script like `cozo_run_query`, running it again with a jittered
+/// backoff while it fails with a write conflict.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// A conflict means another write committed first (RocksDB) or held the
+/// write lock (SQLite); the failed run wrote nothing, so running it again is
+/// safe. Other errors, including failed `:ensure` checks, are returned right
+/// away. After the last attempt the conflict error is returned.
+Future<String>  cozoRunWithRetry({required CozoDb db , required String script , required String paramsJson , required RetryPolicy policy }) => RustLib.instance.api.crateApiRetryCozoRunWithRetry(db: db, script: script, paramsJson: paramsJson, policy: policy);
+
+/// Run `scripts` in one write transaction like an atomic `cozo_run_batch`,
+/// running the whole transaction again with a jittered backoff while it
+/// fails with a write conflict, as in [`cozo_run_with_retry`].
+///
+/// Each attempt starts a fresh transaction, so the scripts are run again
+/// against the data as the conflicting write left it. Returns one
+/// [`QueryResult`] per script, from the attempt that committed.
+Future<List<QueryResult>>  cozoRunTxWithRetry({required CozoDb db , required List<ScriptWithParams> scripts , required RetryPolicy policy }) => RustLib.instance.api.crateApiRetryCozoRunTxWithRetry(db: db, scripts: scripts, policy: policy);
+
+            /// How [`cozo_run_with_retry`] and [`cozo_run_tx_with_retry`] retry.
+class RetryPolicy  {
+                /// Runs in total, including the first, at least 1.
+final int maxAttempts;
+/// Upper bound of the wait before the first retry; it doubles for each
+/// retry after that.
+final BigInt initialDelayMs;
+/// Upper bound of a single wait.
+final BigInt maxDelayMs;
+
+                const RetryPolicy({required this.maxAttempts ,required this.initialDelayMs ,required this.maxDelayMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxAttempts.hashCode^initialDelayMs.hashCode^maxDelayMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetryPolicy &&
+                runtimeType == other.runtimeType
+                && maxAttempts == other.maxAttempts&& initialDelayMs == other.initialDelayMs&& maxDelayMs == other.maxDelayMs;
+        
+            }
+            
\ No newline at end of file
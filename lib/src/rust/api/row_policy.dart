@@ -0,0 +1,101 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `allowed_rows`, `apply_row_policies_cached`, `apply_row_policies`, `blocks`, `filter_returning`, `filter_rows`, `is_comment`, `policy_condition`, `policy_generation`, `policy_rows`, `positional`, `returning_relation`, `rewrite`, `run_on`, `skip_blank`, `skip_comment`, `split_args`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Policy`, `RewrittenScript`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Only let queries see the rows of `relation` for which `predicate` holds,
+/// e.g. `tenant_id == $current_tenant`, so that a query that forgets the
+/// filter cannot read rows of another account.
+///
+/// - `predicate`: a CozoScript condition over the relation's column names;
+///   parameters it uses are set with [`cozo_set_policy_params`]
+///
+/// Every script run through the query calls (`cozo_run_query` and its
+/// variants, including the encoded, Arrow and file ones, batches,
+/// transactions, prepared queries, streams, cursors and watches) has each
+/// `*relation{..}` and `*relation[..]` atom replaced by a rule reading only
+/// the allowed rows. Scripts the rewrite cannot make safe fail instead:
+/// atoms with an `@` clause, direct reads or searches of the relation's
+/// indices, and imperative scripts. System ops other than `::explain` are
+/// run as they are, as are writes: a policy limits which rows are read, not
+/// which are written. What a write's `:returning` gives back is limited to
+/// the rows the policy allows, besides the `requested` keys of a `:rm`.
+/// Watches apply the policies in effect each time they run again, and a
+/// cursor fails to read more pages once the policies or their parameters
+/// change.
+///
+/// The calls that read rows without a script of the app's also keep to the
+/// policy: `cozo_get_row`, the full-text, LSH and vector searches (which
+/// may then return fewer than `k` hits), both relation exports, and change
+/// streams, whose events leave out the rows the policy hides and are not
+/// sent when no row is left.
+///
+/// Policies are kept in memory for the handle and the handles sharing its
+/// database, not in the database. Setting one again replaces it; set it
+/// again after changing the relation's columns. Cached query results are
+/// dropped.
+Future<void>  cozoSetRowPolicy({required CozoDb db , required String relation , required String predicate }) => RustLib.instance.api.crateApiRowPolicyCozoSetRowPolicy(db: db, relation: relation, predicate: predicate);
+
+/// Let queries read every row of `relation` again.
+///
+/// Returns whether the relation had a policy.
+bool  cozoRemoveRowPolicy({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiRowPolicyCozoRemoveRowPolicy(db: db, relation: relation);
+
+/// The row policies in effect, by relation name.
+List<RowPolicy>  cozoListRowPolicies({required CozoDb db }) => RustLib.instance.api.crateApiRowPolicyCozoListRowPolicies(db: db);
+
+/// Set the parameters row policies use, e.g. '{"current_tenant": 42}' when
+/// the user signs in, replacing the previous ones.
+///
+/// They are bound in every script reading a relation with a policy and take
+/// precedence over the script's own parameters of the same name, so a query
+/// cannot widen what it reads by passing them itself. Cached query results
+/// are dropped.
+void  cozoSetPolicyParams({required CozoDb db , required String paramsJson }) => RustLib.instance.api.crateApiRowPolicyCozoSetPolicyParams(db: db, paramsJson: paramsJson);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RowPolicies>>
+                abstract class RowPolicies implements RustOpaqueInterface {
+                    static Future<RowPolicies>  default_()=>RustLib.instance.api.crateApiRowPolicyRowPoliciesDefault();
+
+
+
+                    
+                }
+                
+
+/// A relation's row policy, see [`cozo_set_row_policy`].
+class RowPolicy  {
+                final String relation;
+final String predicate;
+
+                const RowPolicy({required this.relation ,required this.predicate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^predicate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RowPolicy &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& predicate == other.predicate;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `rows_to_data`, `written`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// Write rows straight into a stored relation, without building a script.
+///
+/// - `relation`: name of the stored relation
+/// - `rows`: full rows with one value per column, in the relation's column
+///   order (keys first, as listed by `cozo_list_columns`)
+///
+/// Existing rows with the same key are replaced. Rows are written in chunks
+/// of 1000, each with a `:put` script in its own transaction, so triggers,
+/// change callbacks and indices see them as they would any other write.
+///
+/// Returns the number of rows written.
+Future<BigInt>  cozoPutRows({required CozoDb db , required String relation , required List<List<CozoValue>> rows }) => RustLib.instance.api.crateApiRowsCozoPutRows(db: db, relation: relation, rows: rows);
+
+/// Delete rows from a stored relation by primary key.
+///
+/// - `keys`: one entry per row, holding the key column values in key order
+///
+/// Keys that do not exist are ignored. All deletions run in one transaction.
+/// For a relation with soft deletes enabled, the rows are marked deleted
+/// instead, see `cozo_enable_soft_delete`.
+///
+/// Returns the number of rows actually deleted.
+Future<BigInt>  cozoDeleteRows({required CozoDb db , required String relation , required List<List<CozoValue>> keys }) => RustLib.instance.api.crateApiRowsCozoDeleteRows(db: db, relation: relation, keys: keys);
+
+/// Insert rows into a stored relation, resolving existing keys with `on_conflict`.
+///
+/// - `rows`: full rows with one value per column, in the relation's column
+///   order (keys first)
+///
+/// Unlike [`cozo_put_rows`] this goes through the query engine, so triggers,
+/// callbacks and indices all apply. All rows are written in one transaction.
+///
+/// Returns the number of rows written.
+Future<BigInt>  cozoUpsertRows({required CozoDb db , required String relation , required List<List<CozoValue>> rows , required OnConflict onConflict }) => RustLib.instance.api.crateApiRowsCozoUpsertRows(db: db, relation: relation, rows: rows, onConflict: onConflict);
+
+/// Look up one row of a stored relation by its primary key.
+///
+/// - `key`: the key column values, in key order
+///
+/// The key is bound before the relation is scanned, so this is a direct key
+/// lookup rather than a filter over the whole relation.
+///
+/// Returns the full row in column order, or `None` if the key does not exist
+/// or the row is flagged deleted, see `cozo_enable_soft_delete`.
+Future<List<CozoValue>?>  cozoGetRow({required CozoDb db , required String relation , required List<CozoValue> key }) => RustLib.instance.api.crateApiRowsCozoGetRow(db: db, relation: relation, key: key);
+
+            /// What [`cozo_upsert_rows`] does with a row whose key already exists.
+enum OnConflict {
+                    /// Overwrite the existing row (`:put`).
+replace,
+/// Keep the existing row and drop the new one.
+skip,
+/// Fail the whole call without writing anything (`:insert`).
+error,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,133 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `epoch_secs`, `is_killed`, `kill_evaluation`, `run_tracked`, `start`, `stop`, `watch_heap`, `with_timeout`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HeapWatch`, `RunningGuard`, `RunningQuery`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `fmt`, `fmt`
+
+
+            /// Read-write query options with no timeout and no heap limit, the ones
+/// `cozo_run_query` uses on a handle opened with default options.
+QueryOptions  cozoDefaultQueryOptions() => RustLib.instance.api.crateApiRunningCozoDefaultQueryOptions();
+
+/// Run a CozoScript query under a caller-chosen id so it can be cancelled
+/// with [`cozo_kill_query`].
+///
+/// - `query_id`: any string unique among the queries currently running on
+///   this handle, e.g. a UUID generated in Dart
+///
+/// Other arguments and the result are the same as [`cozo_run_query`],
+/// including the handle's heap limit. A killed query fails with an
+/// `eval::killed` error.
+Future<String>  cozoRunTrackedQuery({required CozoDb db , required String queryId , required String script , required String paramsJson , required bool immutable }) => RustLib.instance.api.crateApiRunningCozoRunTrackedQuery(db: db, queryId: queryId, script: script, paramsJson: paramsJson, immutable: immutable);
+
+/// Run a CozoScript query with extra execution options.
+///
+/// Other arguments and the result are the same as [`cozo_run_query`], but
+/// `heap_limit_bytes` replaces the handle's heap limit. A query that
+/// exceeds `timeout_ms` fails with a `Query failed [timeout]` error; one
+/// during which the heap grows past `heap_limit_bytes` fails with
+/// `Query failed [heap_limit]`.
+///
+/// The timeout is cozo's own `:timeout` option, added to the script (to
+/// each block of a `{ .. }` chain, each getting the full timeout), so only
+/// this query is stopped. System ops and imperative scripts cannot take a
+/// timeout and fail with an error instead.
+///
+/// Cozo checks for cancellation between evaluation steps, so a single long
+/// step, such as expanding a huge `int_range`, finishes (and allocates)
+/// before the query stops.
+Future<String>  cozoRunQueryWithOptions({required CozoDb db , required String script , required String paramsJson , required QueryOptions options }) => RustLib.instance.api.crateApiRunningCozoRunQueryWithOptions(db: db, script: script, paramsJson: paramsJson, options: options);
+
+/// Abort a query started with [`cozo_run_tracked_query`].
+///
+/// cozo 0.7.6 does not say which caller an evaluation listed by `::running`
+/// belongs to, so the query's evaluation is told by its start time: it is
+/// only killed when it is the only evaluation on the database that started
+/// after the tracked query did. Queries that started earlier, tracked or
+/// not, do not get in the way; while another one that started later is
+/// evaluating too, nothing is killed and false is returned, so call again
+/// once it has finished. A query started in the few milliseconds before
+/// the tracked one begins evaluating would still be taken for it, since
+/// cozo gives no way to tell them apart.
+///
+/// Returns true if the query's evaluation was signalled to stop; false if
+/// the id is unknown, the query has not started evaluating yet or its
+/// evaluation cannot be told apart from others.
+Future<bool>  cozoKillQuery({required CozoDb db , required String queryId }) => RustLib.instance.api.crateApiRunningCozoKillQuery(db: db, queryId: queryId);
+
+/// List the queries currently running under [`cozo_run_tracked_query`],
+/// oldest first.
+List<RunningQueryInfo>  cozoListRunning({required CozoDb db }) => RustLib.instance.api.crateApiRunningCozoListRunning(db: db);
+
+            /// Options for [`cozo_run_query_with_options`].
+class QueryOptions  {
+                /// If true, the query is run in read-only mode.
+final bool immutable;
+/// Stop the query once it has run for this many milliseconds.
+final int? timeoutMs;
+/// Stop the query once the process's Rust heap has grown by this many
+/// bytes since it started, `None` for no limit. This caps the whole
+/// process's heap, not the query: allocations made while it runs by
+/// other queries, handles and threads count too, so they can stop it,
+/// and memory a query frees again does not.
+final BigInt? heapLimitBytes;
+
+                const QueryOptions({required this.immutable ,this.timeoutMs ,this.heapLimitBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => immutable.hashCode^timeoutMs.hashCode^heapLimitBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryOptions &&
+                runtimeType == other.runtimeType
+                && immutable == other.immutable&& timeoutMs == other.timeoutMs&& heapLimitBytes == other.heapLimitBytes;
+        
+            }
+
+/// A query currently running under [`cozo_run_tracked_query`].
+class RunningQueryInfo  {
+                /// The caller-chosen query id, usable with [`cozo_kill_query`].
+final String id;
+/// The first characters of the script.
+final String script;
+/// Start time in seconds since the Unix epoch.
+final double startedAt;
+/// Seconds elapsed since the query started.
+final double elapsed;
+
+                const RunningQueryInfo({required this.id ,required this.script ,required this.startedAt ,required this.elapsed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^script.hashCode^startedAt.hashCode^elapsed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunningQueryInfo &&
+                runtimeType == other.runtimeType
+                && id == other.id&& script == other.script&& startedAt == other.startedAt&& elapsed == other.elapsed;
+        
+            }
+            
\ No newline at end of file
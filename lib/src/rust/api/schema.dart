@@ -0,0 +1,329 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'simple.dart';
+import 'value.dart';
+part 'schema.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `check_relation_name`, `column_decl`, `column_names`, `column_spec`, `count`, `create_script`, `indices`, `is_ident`, `relation_columns`, `schema_diff`, `schema_mismatches`, `string`, `system_op`, `type_name`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+
+
+            /// List the stored relations in the database, including indices.
+Future<List<RelationInfo>>  cozoListRelations({required CozoDb db }) => RustLib.instance.api.crateApiSchemaCozoListRelations(db: db);
+
+/// List the columns of a stored relation, keys first.
+Future<List<ColumnInfo>>  cozoListColumns({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiSchemaCozoListColumns(db: db, relation: relation);
+
+/// Create a stored relation.
+///
+/// - `name`: relation name; dotted names such as `app.users` are allowed
+/// - `columns`: at least one key column; the rest are value columns
+///
+/// Names and types are checked before the script is built, and default
+/// values are written as escaped literals, so no input is pasted into the
+/// script verbatim apart from [`ColumnDefault::Expr`] expressions.
+///
+/// Fails if a relation with this name already exists.
+Future<void>  cozoCreateRelation({required CozoDb db , required String name , required List<ColumnDef> columns }) => RustLib.instance.api.crateApiSchemaCozoCreateRelation(db: db, name: name, columns: columns);
+
+/// Remove a stored relation with all its rows, indices and triggers.
+Future<void>  cozoDropRelation({required CozoDb db , required String name }) => RustLib.instance.api.crateApiSchemaCozoDropRelation(db: db, name: name);
+
+/// Rename a stored relation, keeping its rows, indices and triggers.
+///
+/// Fails if `new_name` is already taken.
+Future<void>  cozoRenameRelation({required CozoDb db , required String name , required String newName }) => RustLib.instance.api.crateApiSchemaCozoRenameRelation(db: db, name: name, newName: newName);
+
+/// Create a stored relation unless it exists, and otherwise check that its
+/// columns are the requested ones, e.g. at app startup.
+///
+/// - `columns`: as for [`cozo_create_relation`]
+///
+/// An existing relation is never changed; compare the report against what
+/// the app can work with, or use `cozo_migrate` to change the schema.
+///
+/// Returns whether the relation was created and every difference found.
+Future<EnsureRelationReport>  cozoEnsureRelation({required CozoDb db , required String name , required List<ColumnDef> columns }) => RustLib.instance.api.crateApiSchemaCozoEnsureRelation(db: db, name: name, columns: columns);
+
+/// Compare the schemas of two databases: relations, their columns and
+/// their indices, e.g. to show what a migration or a restore will change
+/// before applying it.
+///
+/// Differences are reported from `a` to `b`: a relation only in `b` is
+/// added. Rows, triggers and column default expressions are not compared,
+/// only whether a column has a default.
+Future<SchemaDiff>  cozoSchemaDiff({required CozoDb a , required CozoDb b }) => RustLib.instance.api.crateApiSchemaCozoSchemaDiff(a: a, b: b);
+
+/// Compare the schema of a database with the schema in a backup file, as
+/// [`cozo_schema_diff`] with the backup as `b`: what restoring it would
+/// change.
+///
+/// The backup is read into a scratch in-memory database, so this needs
+/// memory for the whole backup. Compressed backups are read as they are.
+Future<SchemaDiff>  cozoSchemaDiffBackup({required CozoDb db , required String path }) => RustLib.instance.api.crateApiSchemaCozoSchemaDiffBackup(db: db, path: path);
+
+            /// A column of a relation to create with [`cozo_create_relation`].
+class ColumnDef  {
+                final String name;
+/// Column type without the nullability marker, e.g. "Int", "[String]"
+/// or "<F32; 128>". Empty for `Any`.
+final String columnType;
+/// Whether the column accepts null, i.e. the type is declared with `?`.
+final bool nullable;
+/// Whether the column is part of the primary key. Key columns are placed
+/// first, in the order given.
+final bool isKey;
+/// Value used when a write leaves the column out.
+final ColumnDefault? default_;
+
+                const ColumnDef({required this.name ,required this.columnType ,required this.nullable ,required this.isKey ,this.default_ ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^columnType.hashCode^nullable.hashCode^isKey.hashCode^default_.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ColumnDef &&
+                runtimeType == other.runtimeType
+                && name == other.name&& columnType == other.columnType&& nullable == other.nullable&& isKey == other.isKey&& default_ == other.default_;
+        
+            }
+
+@freezed
+                sealed class ColumnDefault with _$ColumnDefault  {
+                    const ColumnDefault._();
+
+                     /// A fixed value.
+const factory ColumnDefault.value(  CozoValue field0,) = ColumnDefault_Value;
+ /// A CozoScript expression evaluated for each written row, e.g. `now()`
+/// or `rand_uuid_v4()`.
+const factory ColumnDefault.expr(  String field0,) = ColumnDefault_Expr;
+
+                    
+
+                    
+                }
+
+/// A column of a stored relation, as listed by `::columns`.
+class ColumnInfo  {
+                final String name;
+/// Column type without the nullability marker, e.g. "Int" or "<F32; 128>".
+final String columnType;
+/// Whether the type was declared with `?`.
+final bool nullable;
+/// Whether the column is part of the primary key.
+final bool isKey;
+/// Position in the relation. Key columns come first, so for a key column
+/// this is also its position within the key.
+final int index;
+final bool hasDefault;
+
+                const ColumnInfo({required this.name ,required this.columnType ,required this.nullable ,required this.isKey ,required this.index ,required this.hasDefault ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^columnType.hashCode^nullable.hashCode^isKey.hashCode^index.hashCode^hasDefault.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ColumnInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& columnType == other.columnType&& nullable == other.nullable&& isKey == other.isKey&& index == other.index&& hasDefault == other.hasDefault;
+        
+            }
+
+/// Outcome of [`cozo_ensure_relation`].
+class EnsureRelationReport  {
+                /// Whether the relation did not exist and was created by this call.
+final bool created;
+/// How the existing relation differs from the requested columns; empty
+/// if it matches or was just created.
+final List<SchemaMismatch> mismatches;
+
+                const EnsureRelationReport({required this.created ,required this.mismatches ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => created.hashCode^mismatches.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EnsureRelationReport &&
+                runtimeType == other.runtimeType
+                && created == other.created&& mismatches == other.mismatches;
+        
+            }
+
+/// How a relation differs between the two databases of a [`SchemaDiff`].
+class RelationDiff  {
+                final String name;
+/// Column differences, with `expected` from the first database and
+/// `actual` from the second: [`SchemaMismatchKind::Missing`] is a column
+/// that was removed and [`SchemaMismatchKind::Unexpected`] one that was
+/// added.
+final List<SchemaMismatch> columns;
+final List<String> addedIndices;
+final List<String> removedIndices;
+/// Indices in both with a different type, columns or configuration.
+final List<String> changedIndices;
+
+                const RelationDiff({required this.name ,required this.columns ,required this.addedIndices ,required this.removedIndices ,required this.changedIndices ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^columns.hashCode^addedIndices.hashCode^removedIndices.hashCode^changedIndices.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RelationDiff &&
+                runtimeType == other.runtimeType
+                && name == other.name&& columns == other.columns&& addedIndices == other.addedIndices&& removedIndices == other.removedIndices&& changedIndices == other.changedIndices;
+        
+            }
+
+/// A stored relation, as listed by `::relations`.
+class RelationInfo  {
+                final String name;
+/// Total number of columns.
+final int arity;
+/// "normal", "protected", "read_only", "hidden", or "index" for indices.
+final String accessLevel;
+final int nKeys;
+final int nNonKeys;
+final int nPutTriggers;
+final int nRmTriggers;
+final int nReplaceTriggers;
+/// Description set with `::describe`, empty if none.
+final String description;
+
+                const RelationInfo({required this.name ,required this.arity ,required this.accessLevel ,required this.nKeys ,required this.nNonKeys ,required this.nPutTriggers ,required this.nRmTriggers ,required this.nReplaceTriggers ,required this.description ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^arity.hashCode^accessLevel.hashCode^nKeys.hashCode^nNonKeys.hashCode^nPutTriggers.hashCode^nRmTriggers.hashCode^nReplaceTriggers.hashCode^description.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RelationInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& arity == other.arity&& accessLevel == other.accessLevel&& nKeys == other.nKeys&& nNonKeys == other.nNonKeys&& nPutTriggers == other.nPutTriggers&& nRmTriggers == other.nRmTriggers&& nReplaceTriggers == other.nReplaceTriggers&& description == other.description;
+        
+            }
+
+/// Schema changes from one database to another, see [`cozo_schema_diff`].
+class SchemaDiff  {
+                /// Relations only in the second database.
+final List<String> addedRelations;
+/// Relations only in the first database.
+final List<String> removedRelations;
+/// Relations in both whose columns or indices differ.
+final List<RelationDiff> changedRelations;
+
+                const SchemaDiff({required this.addedRelations ,required this.removedRelations ,required this.changedRelations ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => addedRelations.hashCode^removedRelations.hashCode^changedRelations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SchemaDiff &&
+                runtimeType == other.runtimeType
+                && addedRelations == other.addedRelations&& removedRelations == other.removedRelations&& changedRelations == other.changedRelations;
+        
+            }
+
+/// One difference found by [`cozo_ensure_relation`].
+class SchemaMismatch  {
+                final String column;
+final SchemaMismatchKind kind;
+/// The requested property, e.g. "Int?" for a type mismatch or "key" for
+/// a key mismatch. `None` for [`SchemaMismatchKind::Unexpected`].
+final String? expected;
+/// The property found in the database. `None` for
+/// [`SchemaMismatchKind::Missing`].
+final String? actual;
+
+                const SchemaMismatch({required this.column ,required this.kind ,this.expected ,this.actual ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => column.hashCode^kind.hashCode^expected.hashCode^actual.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SchemaMismatch &&
+                runtimeType == other.runtimeType
+                && column == other.column&& kind == other.kind&& expected == other.expected&& actual == other.actual;
+        
+            }
+
+enum SchemaMismatchKind {
+                    /// A requested column does not exist.
+missing,
+/// The relation has a column that was not requested.
+unexpected,
+/// The column has a different type or nullability.
+type,
+/// The column is a key column but was requested as a value, or the
+/// other way round.
+key,
+/// The column is at a different position; for key columns this changes
+/// the key order.
+position,
+/// The column has a default but none was requested, or the other way
+/// round. Default expressions themselves are not compared.
+default_,
+                    ;
+                    
+                }
+            
\ No newline at end of file
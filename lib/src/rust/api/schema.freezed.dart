@@ -0,0 +1,380 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'schema.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$ColumnDefault {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(CozoValue field0) value,
+    required TResult Function(String field0) expr,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue field0)? value,
+    TResult? Function(String field0)? expr,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(CozoValue field0)? value,
+    TResult Function(String field0)? expr,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ColumnDefault_Value value) value,
+    required TResult Function(ColumnDefault_Expr value) expr,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ColumnDefault_Value value)? value,
+    TResult? Function(ColumnDefault_Expr value)? expr,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ColumnDefault_Value value)? value,
+    TResult Function(ColumnDefault_Expr value)? expr,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ColumnDefaultCopyWith<$Res> {
+  factory $ColumnDefaultCopyWith(ColumnDefault value, $Res Function(ColumnDefault) then) =
+      _$ColumnDefaultCopyWithImpl<$Res, ColumnDefault>;
+}
+
+/// @nodoc
+class _$ColumnDefaultCopyWithImpl<$Res, $Val extends ColumnDefault>
+    implements $ColumnDefaultCopyWith<$Res> {
+  _$ColumnDefaultCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$ColumnDefault_ValueImplCopyWith<$Res> {
+  factory _$$ColumnDefault_ValueImplCopyWith(
+          _$ColumnDefault_ValueImpl value, $Res Function(_$ColumnDefault_ValueImpl) then) =
+      __$$ColumnDefault_ValueImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({CozoValue field0});
+
+  $CozoValueCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class __$$ColumnDefault_ValueImplCopyWithImpl<$Res>
+    extends _$ColumnDefaultCopyWithImpl<$Res, _$ColumnDefault_ValueImpl>
+    implements _$$ColumnDefault_ValueImplCopyWith<$Res> {
+  __$$ColumnDefault_ValueImplCopyWithImpl(
+      _$ColumnDefault_ValueImpl _value, $Res Function(_$ColumnDefault_ValueImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$ColumnDefault_ValueImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as CozoValue,
+    ));
+  }
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $CozoValueCopyWith<$Res> get field0 {
+    return $CozoValueCopyWith<$Res>(_value.field0, (value) {
+      return _then(_value.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$ColumnDefault_ValueImpl extends ColumnDefault_Value {
+  const _$ColumnDefault_ValueImpl(this.field0)
+      : super._();
+
+  @override
+  final CozoValue field0;
+
+  @override
+  String toString() {
+    return 'ColumnDefault.value(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ColumnDefault_ValueImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ColumnDefault_ValueImplCopyWith<_$ColumnDefault_ValueImpl> get copyWith =>
+      __$$ColumnDefault_ValueImplCopyWithImpl<_$ColumnDefault_ValueImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(CozoValue field0) value,
+    required TResult Function(String field0) expr,
+  }) {
+    return value(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue field0)? value,
+    TResult? Function(String field0)? expr,
+  }) {
+    return value?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(CozoValue field0)? value,
+    TResult Function(String field0)? expr,
+    required TResult orElse(),
+  }) {
+    if (value != null) {
+      return value(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ColumnDefault_Value value) value,
+    required TResult Function(ColumnDefault_Expr value) expr,
+  }) {
+    return value(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ColumnDefault_Value value)? value,
+    TResult? Function(ColumnDefault_Expr value)? expr,
+  }) {
+    return value?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ColumnDefault_Value value)? value,
+    TResult Function(ColumnDefault_Expr value)? expr,
+    required TResult orElse(),
+  }) {
+    if (value != null) {
+      return value(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ColumnDefault_Value extends ColumnDefault {
+  const factory ColumnDefault_Value(final CozoValue field0) = _$ColumnDefault_ValueImpl;
+  const ColumnDefault_Value._() : super._();
+
+  CozoValue get field0;
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ColumnDefault_ValueImplCopyWith<_$ColumnDefault_ValueImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ColumnDefault_ExprImplCopyWith<$Res> {
+  factory _$$ColumnDefault_ExprImplCopyWith(
+          _$ColumnDefault_ExprImpl value, $Res Function(_$ColumnDefault_ExprImpl) then) =
+      __$$ColumnDefault_ExprImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$ColumnDefault_ExprImplCopyWithImpl<$Res>
+    extends _$ColumnDefaultCopyWithImpl<$Res, _$ColumnDefault_ExprImpl>
+    implements _$$ColumnDefault_ExprImplCopyWith<$Res> {
+  __$$ColumnDefault_ExprImplCopyWithImpl(
+      _$ColumnDefault_ExprImpl _value, $Res Function(_$ColumnDefault_ExprImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$ColumnDefault_ExprImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ColumnDefault_ExprImpl extends ColumnDefault_Expr {
+  const _$ColumnDefault_ExprImpl(this.field0)
+      : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'ColumnDefault.expr(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ColumnDefault_ExprImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ColumnDefault_ExprImplCopyWith<_$ColumnDefault_ExprImpl> get copyWith =>
+      __$$ColumnDefault_ExprImplCopyWithImpl<_$ColumnDefault_ExprImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(CozoValue field0) value,
+    required TResult Function(String field0) expr,
+  }) {
+    return expr(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue field0)? value,
+    TResult? Function(String field0)? expr,
+  }) {
+    return expr?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(CozoValue field0)? value,
+    TResult Function(String field0)? expr,
+    required TResult orElse(),
+  }) {
+    if (expr != null) {
+      return expr(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ColumnDefault_Value value) value,
+    required TResult Function(ColumnDefault_Expr value) expr,
+  }) {
+    return expr(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ColumnDefault_Value value)? value,
+    TResult? Function(ColumnDefault_Expr value)? expr,
+  }) {
+    return expr?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ColumnDefault_Value value)? value,
+    TResult Function(ColumnDefault_Expr value)? expr,
+    required TResult orElse(),
+  }) {
+    if (expr != null) {
+      return expr(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ColumnDefault_Expr extends ColumnDefault {
+  const factory ColumnDefault_Expr(final String field0) = _$ColumnDefault_ExprImpl;
+  const ColumnDefault_Expr._() : super._();
+
+  String get field0;
+
+  /// Create a copy of ColumnDefault
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ColumnDefault_ExprImplCopyWith<_$ColumnDefault_ExprImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,272 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'simple.dart';
+import 'value.dart';
+part 'search.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `extractor`, `filters`, `query_terms`, `run_search`, `snippet`, `tokenizer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Create a full-text search index over text columns of a stored relation.
+///
+/// - `name`: index name, used as `~relation:name{...}` in queries
+/// - `columns`: the indexed text columns; several columns are indexed as
+///   their values joined with `|`, which the `Simple` tokenizer splits on
+///   but `Whitespace` does not. Rows where every column is null are not
+///   indexed.
+///
+/// Existing rows are indexed before this returns, and later writes keep the
+/// index up to date. Drop the index with `cozo_drop_index`.
+Future<void>  cozoCreateFtsIndex({required CozoDb db , required String relation , required String name , required List<String> columns , required FtsConfig config }) => RustLib.instance.api.crateApiSearchCozoCreateFtsIndex(db: db, relation: relation, name: name, columns: columns, config: config);
+
+/// Search a full-text index.
+///
+/// - `relation`, `index`: the indexed relation and the index name
+/// - `query`: cozo full-text query, e.g. `cat AND dog`, `"exact phrase"`
+///   or `prefix*`
+/// - `limit`, `offset`: the page of hits to return
+/// - `snippets`: if set, each hit gets a snippet of its indexed text
+///
+/// Snippets mark the words that start with a query term, ignoring case.
+/// They are built from the text as stored, so a word the index only matched
+/// after stemming or folding may not be marked.
+///
+/// Returns the hits, best first.
+Future<List<FtsHit>>  cozoFtsSearch({required CozoDb db , required String relation , required String index , required String query , required int limit , required int offset , SnippetConfig? snippets }) => RustLib.instance.api.crateApiSearchCozoFtsSearch(db: db, relation: relation, index: index, query: query, limit: limit, offset: offset, snippets: snippets);
+
+/// Create a MinHash-LSH index for finding near-duplicate texts.
+///
+/// - `name`: index name, used as `~relation:name{...}` in queries
+/// - `columns`: the indexed text columns, combined as for
+///   `cozo_create_fts_index`
+///
+/// Existing rows are indexed before this returns, and later writes keep the
+/// index up to date. Drop the index with `cozo_drop_index`.
+Future<void>  cozoCreateLshIndex({required CozoDb db , required String relation , required String name , required List<String> columns , required LshConfig config }) => RustLib.instance.api.crateApiSearchCozoCreateLshIndex(db: db, relation: relation, name: name, columns: columns, config: config);
+
+/// Find the rows whose indexed text is a near duplicate of `text`.
+///
+/// - `relation`, `index`: the indexed relation and the index name
+/// - `text`: the text to compare, e.g. a note being edited
+/// - `k`: maximum number of hits; `None` returns every near duplicate
+/// - `filter`: CozoScript expression over the relation's columns; only rows
+///   for which it is true are returned
+///
+/// To find the duplicates of a stored row, pass its text and skip its own
+/// key in the hits. Hits are candidates estimated from the index, in no
+/// particular order; compare the texts to confirm them.
+Future<List<LshHit>>  cozoLshSearch({required CozoDb db , required String relation , required String index , required String text , int? k , String? filter }) => RustLib.instance.api.crateApiSearchCozoLshSearch(db: db, relation: relation, index: index, text: text, k: k, filter: filter);
+
+            /// Tokenization settings of a full-text index.
+class FtsConfig  {
+                final FtsTokenizer tokenizer;
+/// Applied in order; usually starts with `Lowercase`.
+final List<FtsFilter> filters;
+/// If set, a `Stopwords` filter for this language is applied after
+/// `filters`.
+final String? stopwordsLang;
+
+                const FtsConfig({required this.tokenizer ,required this.filters ,this.stopwordsLang ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tokenizer.hashCode^filters.hashCode^stopwordsLang.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FtsConfig &&
+                runtimeType == other.runtimeType
+                && tokenizer == other.tokenizer&& filters == other.filters&& stopwordsLang == other.stopwordsLang;
+        
+            }
+
+@freezed
+                sealed class FtsFilter with _$FtsFilter  {
+                    const FtsFilter._();
+
+                     const factory FtsFilter.lowercase() = FtsFilter_Lowercase;
+ /// Drop tokens that are not purely alphanumeric.
+const factory FtsFilter.alphaNumOnly() = FtsFilter_AlphaNumOnly;
+ /// Replace accented Latin characters with their ASCII counterparts.
+const factory FtsFilter.asciiFolding() = FtsFilter_AsciiFolding;
+ /// Drop tokens of `limit` bytes or more.
+const factory FtsFilter.removeLong({   required int limit , }) = FtsFilter_RemoveLong;
+ /// Reduce words to their stem, e.g. "running" to "run".
+///
+/// - `language`: e.g. "english" or "french"
+const factory FtsFilter.stemmer({   required String language , }) = FtsFilter_Stemmer;
+ /// Drop common words of a language.
+///
+/// - `language`: ISO 639-1 code, e.g. "en" or "de"
+const factory FtsFilter.stopwords({   required String language , }) = FtsFilter_Stopwords;
+ /// Drop the given words.
+const factory FtsFilter.stopwordList({   required List<String> words , }) = FtsFilter_StopwordList;
+
+                    
+
+                    
+                }
+
+/// A row found by [`cozo_fts_search`].
+class FtsHit  {
+                /// Key column values of the row, in key order.
+final List<CozoValue> key;
+/// Relevance, higher is better.
+final double score;
+/// Indexed text around the first matched word, with matches marked.
+/// `None` if no snippets were asked for.
+final String? snippet;
+
+                const FtsHit({required this.key ,required this.score ,this.snippet ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^score.hashCode^snippet.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FtsHit &&
+                runtimeType == other.runtimeType
+                && key == other.key&& score == other.score&& snippet == other.snippet;
+        
+            }
+
+@freezed
+                sealed class FtsTokenizer with _$FtsTokenizer  {
+                    const FtsTokenizer._();
+
+                     /// The whole text is one token.
+const factory FtsTokenizer.raw() = FtsTokenizer_Raw;
+ /// Split on whitespace and punctuation.
+const factory FtsTokenizer.simple() = FtsTokenizer_Simple;
+ /// Split on whitespace only.
+const factory FtsTokenizer.whitespace() = FtsTokenizer_Whitespace;
+ /// Every run of `min_gram` to `max_gram` characters, for substring
+/// matching; with `prefix_only`, only the runs at the start of the text.
+const factory FtsTokenizer.nGram({   required int minGram ,  required int maxGram ,  required bool prefixOnly , }) = FtsTokenizer_NGram;
+ /// Chinese word segmentation with jieba.
+///
+/// - `kind`: "default", "all", "search" or "unicode"
+/// - `use_hmm`: also find words missing from the dictionary
+const factory FtsTokenizer.cangjie({   required String kind ,  required bool useHmm , }) = FtsTokenizer_Cangjie;
+
+                    
+
+                    
+                }
+
+/// Settings of a MinHash-LSH index.
+class LshConfig  {
+                final FtsTokenizer tokenizer;
+final List<FtsFilter> filters;
+/// Tokens per shingle: 1 compares sets of words, higher values also
+/// take word order into account.
+final int nGram;
+/// Number of hash permutations; more is more accurate but larger and
+/// slower. Cozo defaults to 200.
+final int nPerm;
+/// Jaccard similarity, between 0 and 1, above which two texts count as
+/// near duplicates. Cozo defaults to 0.9.
+final double targetThreshold;
+/// Relative cost of reporting a pair below the threshold; cozo
+/// defaults to 1.
+final double falsePositiveWeight;
+/// Relative cost of missing a pair above the threshold; cozo defaults
+/// to 1.
+final double falseNegativeWeight;
+
+                const LshConfig({required this.tokenizer ,required this.filters ,required this.nGram ,required this.nPerm ,required this.targetThreshold ,required this.falsePositiveWeight ,required this.falseNegativeWeight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tokenizer.hashCode^filters.hashCode^nGram.hashCode^nPerm.hashCode^targetThreshold.hashCode^falsePositiveWeight.hashCode^falseNegativeWeight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LshConfig &&
+                runtimeType == other.runtimeType
+                && tokenizer == other.tokenizer&& filters == other.filters&& nGram == other.nGram&& nPerm == other.nPerm&& targetThreshold == other.targetThreshold&& falsePositiveWeight == other.falsePositiveWeight&& falseNegativeWeight == other.falseNegativeWeight;
+        
+            }
+
+/// A row found by [`cozo_lsh_search`].
+class LshHit  {
+                /// Key column values of the row, in key order.
+final List<CozoValue> key;
+/// The full row, in column order.
+final List<CozoValue> row;
+
+                const LshHit({required this.key ,required this.row ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^row.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LshHit &&
+                runtimeType == other.runtimeType
+                && key == other.key&& row == other.row;
+        
+            }
+
+/// How [`cozo_fts_search`] builds snippets.
+class SnippetConfig  {
+                /// Put before each matched word, e.g. `<b>`.
+final String open;
+/// Put after each matched word, e.g. `</b>`.
+final String close;
+/// Maximum number of words in a snippet.
+final int maxWords;
+
+                const SnippetConfig({required this.open ,required this.close ,required this.maxWords ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => open.hashCode^close.hashCode^maxWords.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnippetConfig &&
+                runtimeType == other.runtimeType
+                && open == other.open&& close == other.close&& maxWords == other.maxWords;
+        
+            }
+            
\ No newline at end of file
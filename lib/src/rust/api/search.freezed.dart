@@ -0,0 +1,2039 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'search.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$FtsFilter {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $FtsFilterCopyWith<$Res> {
+  factory $FtsFilterCopyWith(FtsFilter value, $Res Function(FtsFilter) then) =
+      _$FtsFilterCopyWithImpl<$Res, FtsFilter>;
+}
+
+/// @nodoc
+class _$FtsFilterCopyWithImpl<$Res, $Val extends FtsFilter>
+    implements $FtsFilterCopyWith<$Res> {
+  _$FtsFilterCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$FtsFilter_LowercaseImplCopyWith<$Res> {
+  factory _$$FtsFilter_LowercaseImplCopyWith(
+          _$FtsFilter_LowercaseImpl value, $Res Function(_$FtsFilter_LowercaseImpl) then) =
+      __$$FtsFilter_LowercaseImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FtsFilter_LowercaseImplCopyWithImpl<$Res>
+    extends _$FtsFilterCopyWithImpl<$Res, _$FtsFilter_LowercaseImpl>
+    implements _$$FtsFilter_LowercaseImplCopyWith<$Res> {
+  __$$FtsFilter_LowercaseImplCopyWithImpl(
+      _$FtsFilter_LowercaseImpl _value, $Res Function(_$FtsFilter_LowercaseImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$FtsFilter_LowercaseImpl extends FtsFilter_Lowercase {
+  const _$FtsFilter_LowercaseImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'FtsFilter.lowercase()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsFilter_LowercaseImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) {
+    return lowercase();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) {
+    return lowercase?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (lowercase != null) {
+      return lowercase();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) {
+    return lowercase(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) {
+    return lowercase?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (lowercase != null) {
+      return lowercase(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsFilter_Lowercase extends FtsFilter {
+  const factory FtsFilter_Lowercase() = _$FtsFilter_LowercaseImpl;
+  const FtsFilter_Lowercase._() : super._();
+}
+
+/// @nodoc
+abstract class _$$FtsFilter_AlphaNumOnlyImplCopyWith<$Res> {
+  factory _$$FtsFilter_AlphaNumOnlyImplCopyWith(
+          _$FtsFilter_AlphaNumOnlyImpl value, $Res Function(_$FtsFilter_AlphaNumOnlyImpl) then) =
+      __$$FtsFilter_AlphaNumOnlyImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FtsFilter_AlphaNumOnlyImplCopyWithImpl<$Res>
+    extends _$FtsFilterCopyWithImpl<$Res, _$FtsFilter_AlphaNumOnlyImpl>
+    implements _$$FtsFilter_AlphaNumOnlyImplCopyWith<$Res> {
+  __$$FtsFilter_AlphaNumOnlyImplCopyWithImpl(
+      _$FtsFilter_AlphaNumOnlyImpl _value, $Res Function(_$FtsFilter_AlphaNumOnlyImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$FtsFilter_AlphaNumOnlyImpl extends FtsFilter_AlphaNumOnly {
+  const _$FtsFilter_AlphaNumOnlyImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'FtsFilter.alphaNumOnly()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsFilter_AlphaNumOnlyImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) {
+    return alphaNumOnly();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) {
+    return alphaNumOnly?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (alphaNumOnly != null) {
+      return alphaNumOnly();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) {
+    return alphaNumOnly(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) {
+    return alphaNumOnly?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (alphaNumOnly != null) {
+      return alphaNumOnly(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsFilter_AlphaNumOnly extends FtsFilter {
+  const factory FtsFilter_AlphaNumOnly() = _$FtsFilter_AlphaNumOnlyImpl;
+  const FtsFilter_AlphaNumOnly._() : super._();
+}
+
+/// @nodoc
+abstract class _$$FtsFilter_AsciiFoldingImplCopyWith<$Res> {
+  factory _$$FtsFilter_AsciiFoldingImplCopyWith(
+          _$FtsFilter_AsciiFoldingImpl value, $Res Function(_$FtsFilter_AsciiFoldingImpl) then) =
+      __$$FtsFilter_AsciiFoldingImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FtsFilter_AsciiFoldingImplCopyWithImpl<$Res>
+    extends _$FtsFilterCopyWithImpl<$Res, _$FtsFilter_AsciiFoldingImpl>
+    implements _$$FtsFilter_AsciiFoldingImplCopyWith<$Res> {
+  __$$FtsFilter_AsciiFoldingImplCopyWithImpl(
+      _$FtsFilter_AsciiFoldingImpl _value, $Res Function(_$FtsFilter_AsciiFoldingImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$FtsFilter_AsciiFoldingImpl extends FtsFilter_AsciiFolding {
+  const _$FtsFilter_AsciiFoldingImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'FtsFilter.asciiFolding()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsFilter_AsciiFoldingImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) {
+    return asciiFolding();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) {
+    return asciiFolding?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (asciiFolding != null) {
+      return asciiFolding();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) {
+    return asciiFolding(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) {
+    return asciiFolding?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (asciiFolding != null) {
+      return asciiFolding(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsFilter_AsciiFolding extends FtsFilter {
+  const factory FtsFilter_AsciiFolding() = _$FtsFilter_AsciiFoldingImpl;
+  const FtsFilter_AsciiFolding._() : super._();
+}
+
+/// @nodoc
+abstract class _$$FtsFilter_RemoveLongImplCopyWith<$Res> {
+  factory _$$FtsFilter_RemoveLongImplCopyWith(
+          _$FtsFilter_RemoveLongImpl value, $Res Function(_$FtsFilter_RemoveLongImpl) then) =
+      __$$FtsFilter_RemoveLongImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int limit});
+}
+
+/// @nodoc
+class __$$FtsFilter_RemoveLongImplCopyWithImpl<$Res>
+    extends _$FtsFilterCopyWithImpl<$Res, _$FtsFilter_RemoveLongImpl>
+    implements _$$FtsFilter_RemoveLongImplCopyWith<$Res> {
+  __$$FtsFilter_RemoveLongImplCopyWithImpl(
+      _$FtsFilter_RemoveLongImpl _value, $Res Function(_$FtsFilter_RemoveLongImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? limit = null,
+  }) {
+    return _then(_$FtsFilter_RemoveLongImpl(
+      limit: null == limit
+          ? _value.limit
+          : limit // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$FtsFilter_RemoveLongImpl extends FtsFilter_RemoveLong {
+  const _$FtsFilter_RemoveLongImpl({required this.limit})
+      : super._();
+
+  @override
+  final int limit;
+
+  @override
+  String toString() {
+    return 'FtsFilter.removeLong(limit: $limit)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsFilter_RemoveLongImpl &&
+            (identical(other.limit, limit) || other.limit == limit));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, limit);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FtsFilter_RemoveLongImplCopyWith<_$FtsFilter_RemoveLongImpl> get copyWith =>
+      __$$FtsFilter_RemoveLongImplCopyWithImpl<_$FtsFilter_RemoveLongImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) {
+    return removeLong(limit);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) {
+    return removeLong?.call(limit);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (removeLong != null) {
+      return removeLong(limit);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) {
+    return removeLong(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) {
+    return removeLong?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (removeLong != null) {
+      return removeLong(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsFilter_RemoveLong extends FtsFilter {
+  const factory FtsFilter_RemoveLong({required final int limit}) = _$FtsFilter_RemoveLongImpl;
+  const FtsFilter_RemoveLong._() : super._();
+
+  int get limit;
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FtsFilter_RemoveLongImplCopyWith<_$FtsFilter_RemoveLongImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$FtsFilter_StemmerImplCopyWith<$Res> {
+  factory _$$FtsFilter_StemmerImplCopyWith(
+          _$FtsFilter_StemmerImpl value, $Res Function(_$FtsFilter_StemmerImpl) then) =
+      __$$FtsFilter_StemmerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String language});
+}
+
+/// @nodoc
+class __$$FtsFilter_StemmerImplCopyWithImpl<$Res>
+    extends _$FtsFilterCopyWithImpl<$Res, _$FtsFilter_StemmerImpl>
+    implements _$$FtsFilter_StemmerImplCopyWith<$Res> {
+  __$$FtsFilter_StemmerImplCopyWithImpl(
+      _$FtsFilter_StemmerImpl _value, $Res Function(_$FtsFilter_StemmerImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? language = null,
+  }) {
+    return _then(_$FtsFilter_StemmerImpl(
+      language: null == language
+          ? _value.language
+          : language // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$FtsFilter_StemmerImpl extends FtsFilter_Stemmer {
+  const _$FtsFilter_StemmerImpl({required this.language})
+      : super._();
+
+  @override
+  final String language;
+
+  @override
+  String toString() {
+    return 'FtsFilter.stemmer(language: $language)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsFilter_StemmerImpl &&
+            (identical(other.language, language) || other.language == language));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, language);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FtsFilter_StemmerImplCopyWith<_$FtsFilter_StemmerImpl> get copyWith =>
+      __$$FtsFilter_StemmerImplCopyWithImpl<_$FtsFilter_StemmerImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) {
+    return stemmer(language);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) {
+    return stemmer?.call(language);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (stemmer != null) {
+      return stemmer(language);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) {
+    return stemmer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) {
+    return stemmer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (stemmer != null) {
+      return stemmer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsFilter_Stemmer extends FtsFilter {
+  const factory FtsFilter_Stemmer({required final String language}) = _$FtsFilter_StemmerImpl;
+  const FtsFilter_Stemmer._() : super._();
+
+  String get language;
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FtsFilter_StemmerImplCopyWith<_$FtsFilter_StemmerImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$FtsFilter_StopwordsImplCopyWith<$Res> {
+  factory _$$FtsFilter_StopwordsImplCopyWith(
+          _$FtsFilter_StopwordsImpl value, $Res Function(_$FtsFilter_StopwordsImpl) then) =
+      __$$FtsFilter_StopwordsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String language});
+}
+
+/// @nodoc
+class __$$FtsFilter_StopwordsImplCopyWithImpl<$Res>
+    extends _$FtsFilterCopyWithImpl<$Res, _$FtsFilter_StopwordsImpl>
+    implements _$$FtsFilter_StopwordsImplCopyWith<$Res> {
+  __$$FtsFilter_StopwordsImplCopyWithImpl(
+      _$FtsFilter_StopwordsImpl _value, $Res Function(_$FtsFilter_StopwordsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? language = null,
+  }) {
+    return _then(_$FtsFilter_StopwordsImpl(
+      language: null == language
+          ? _value.language
+          : language // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$FtsFilter_StopwordsImpl extends FtsFilter_Stopwords {
+  const _$FtsFilter_StopwordsImpl({required this.language})
+      : super._();
+
+  @override
+  final String language;
+
+  @override
+  String toString() {
+    return 'FtsFilter.stopwords(language: $language)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsFilter_StopwordsImpl &&
+            (identical(other.language, language) || other.language == language));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, language);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FtsFilter_StopwordsImplCopyWith<_$FtsFilter_StopwordsImpl> get copyWith =>
+      __$$FtsFilter_StopwordsImplCopyWithImpl<_$FtsFilter_StopwordsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) {
+    return stopwords(language);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) {
+    return stopwords?.call(language);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (stopwords != null) {
+      return stopwords(language);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) {
+    return stopwords(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) {
+    return stopwords?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (stopwords != null) {
+      return stopwords(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsFilter_Stopwords extends FtsFilter {
+  const factory FtsFilter_Stopwords({required final String language}) = _$FtsFilter_StopwordsImpl;
+  const FtsFilter_Stopwords._() : super._();
+
+  String get language;
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FtsFilter_StopwordsImplCopyWith<_$FtsFilter_StopwordsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$FtsFilter_StopwordListImplCopyWith<$Res> {
+  factory _$$FtsFilter_StopwordListImplCopyWith(
+          _$FtsFilter_StopwordListImpl value, $Res Function(_$FtsFilter_StopwordListImpl) then) =
+      __$$FtsFilter_StopwordListImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<String> words});
+}
+
+/// @nodoc
+class __$$FtsFilter_StopwordListImplCopyWithImpl<$Res>
+    extends _$FtsFilterCopyWithImpl<$Res, _$FtsFilter_StopwordListImpl>
+    implements _$$FtsFilter_StopwordListImplCopyWith<$Res> {
+  __$$FtsFilter_StopwordListImplCopyWithImpl(
+      _$FtsFilter_StopwordListImpl _value, $Res Function(_$FtsFilter_StopwordListImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? words = null,
+  }) {
+    return _then(_$FtsFilter_StopwordListImpl(
+      words: null == words
+          ? _value._words
+          : words // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$FtsFilter_StopwordListImpl extends FtsFilter_StopwordList {
+  const _$FtsFilter_StopwordListImpl({required final List<String> words})
+      : _words = words,
+        super._();
+
+  final List<String> _words;
+  @override
+  List<String> get words {
+    if (_words is EqualUnmodifiableListView) return _words;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_words);
+  }
+
+  @override
+  String toString() {
+    return 'FtsFilter.stopwordList(words: $words)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsFilter_StopwordListImpl &&
+            const DeepCollectionEquality().equals(other._words, _words));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(_words));
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FtsFilter_StopwordListImplCopyWith<_$FtsFilter_StopwordListImpl> get copyWith =>
+      __$$FtsFilter_StopwordListImplCopyWithImpl<_$FtsFilter_StopwordListImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowercase,
+    required TResult Function() alphaNumOnly,
+    required TResult Function() asciiFolding,
+    required TResult Function(int limit) removeLong,
+    required TResult Function(String language) stemmer,
+    required TResult Function(String language) stopwords,
+    required TResult Function(List<String> words) stopwordList,
+  }) {
+    return stopwordList(words);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowercase,
+    TResult? Function()? alphaNumOnly,
+    TResult? Function()? asciiFolding,
+    TResult? Function(int limit)? removeLong,
+    TResult? Function(String language)? stemmer,
+    TResult? Function(String language)? stopwords,
+    TResult? Function(List<String> words)? stopwordList,
+  }) {
+    return stopwordList?.call(words);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowercase,
+    TResult Function()? alphaNumOnly,
+    TResult Function()? asciiFolding,
+    TResult Function(int limit)? removeLong,
+    TResult Function(String language)? stemmer,
+    TResult Function(String language)? stopwords,
+    TResult Function(List<String> words)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (stopwordList != null) {
+      return stopwordList(words);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsFilter_Lowercase value) lowercase,
+    required TResult Function(FtsFilter_AlphaNumOnly value) alphaNumOnly,
+    required TResult Function(FtsFilter_AsciiFolding value) asciiFolding,
+    required TResult Function(FtsFilter_RemoveLong value) removeLong,
+    required TResult Function(FtsFilter_Stemmer value) stemmer,
+    required TResult Function(FtsFilter_Stopwords value) stopwords,
+    required TResult Function(FtsFilter_StopwordList value) stopwordList,
+  }) {
+    return stopwordList(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsFilter_Lowercase value)? lowercase,
+    TResult? Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult? Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult? Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult? Function(FtsFilter_Stemmer value)? stemmer,
+    TResult? Function(FtsFilter_Stopwords value)? stopwords,
+    TResult? Function(FtsFilter_StopwordList value)? stopwordList,
+  }) {
+    return stopwordList?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsFilter_Lowercase value)? lowercase,
+    TResult Function(FtsFilter_AlphaNumOnly value)? alphaNumOnly,
+    TResult Function(FtsFilter_AsciiFolding value)? asciiFolding,
+    TResult Function(FtsFilter_RemoveLong value)? removeLong,
+    TResult Function(FtsFilter_Stemmer value)? stemmer,
+    TResult Function(FtsFilter_Stopwords value)? stopwords,
+    TResult Function(FtsFilter_StopwordList value)? stopwordList,
+    required TResult orElse(),
+  }) {
+    if (stopwordList != null) {
+      return stopwordList(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsFilter_StopwordList extends FtsFilter {
+  const factory FtsFilter_StopwordList({required final List<String> words}) = _$FtsFilter_StopwordListImpl;
+  const FtsFilter_StopwordList._() : super._();
+
+  List<String> get words;
+
+  /// Create a copy of FtsFilter
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FtsFilter_StopwordListImplCopyWith<_$FtsFilter_StopwordListImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$FtsTokenizer {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() raw,
+    required TResult Function() simple,
+    required TResult Function() whitespace,
+    required TResult Function(int minGram, int maxGram, bool prefixOnly) nGram,
+    required TResult Function(String kind, bool useHmm) cangjie,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? raw,
+    TResult? Function()? simple,
+    TResult? Function()? whitespace,
+    TResult? Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult? Function(String kind, bool useHmm)? cangjie,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? raw,
+    TResult Function()? simple,
+    TResult Function()? whitespace,
+    TResult Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult Function(String kind, bool useHmm)? cangjie,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsTokenizer_Raw value) raw,
+    required TResult Function(FtsTokenizer_Simple value) simple,
+    required TResult Function(FtsTokenizer_Whitespace value) whitespace,
+    required TResult Function(FtsTokenizer_NGram value) nGram,
+    required TResult Function(FtsTokenizer_Cangjie value) cangjie,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsTokenizer_Raw value)? raw,
+    TResult? Function(FtsTokenizer_Simple value)? simple,
+    TResult? Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult? Function(FtsTokenizer_NGram value)? nGram,
+    TResult? Function(FtsTokenizer_Cangjie value)? cangjie,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsTokenizer_Raw value)? raw,
+    TResult Function(FtsTokenizer_Simple value)? simple,
+    TResult Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult Function(FtsTokenizer_NGram value)? nGram,
+    TResult Function(FtsTokenizer_Cangjie value)? cangjie,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $FtsTokenizerCopyWith<$Res> {
+  factory $FtsTokenizerCopyWith(FtsTokenizer value, $Res Function(FtsTokenizer) then) =
+      _$FtsTokenizerCopyWithImpl<$Res, FtsTokenizer>;
+}
+
+/// @nodoc
+class _$FtsTokenizerCopyWithImpl<$Res, $Val extends FtsTokenizer>
+    implements $FtsTokenizerCopyWith<$Res> {
+  _$FtsTokenizerCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$FtsTokenizer_RawImplCopyWith<$Res> {
+  factory _$$FtsTokenizer_RawImplCopyWith(
+          _$FtsTokenizer_RawImpl value, $Res Function(_$FtsTokenizer_RawImpl) then) =
+      __$$FtsTokenizer_RawImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FtsTokenizer_RawImplCopyWithImpl<$Res>
+    extends _$FtsTokenizerCopyWithImpl<$Res, _$FtsTokenizer_RawImpl>
+    implements _$$FtsTokenizer_RawImplCopyWith<$Res> {
+  __$$FtsTokenizer_RawImplCopyWithImpl(
+      _$FtsTokenizer_RawImpl _value, $Res Function(_$FtsTokenizer_RawImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$FtsTokenizer_RawImpl extends FtsTokenizer_Raw {
+  const _$FtsTokenizer_RawImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'FtsTokenizer.raw()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsTokenizer_RawImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() raw,
+    required TResult Function() simple,
+    required TResult Function() whitespace,
+    required TResult Function(int minGram, int maxGram, bool prefixOnly) nGram,
+    required TResult Function(String kind, bool useHmm) cangjie,
+  }) {
+    return raw();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? raw,
+    TResult? Function()? simple,
+    TResult? Function()? whitespace,
+    TResult? Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult? Function(String kind, bool useHmm)? cangjie,
+  }) {
+    return raw?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? raw,
+    TResult Function()? simple,
+    TResult Function()? whitespace,
+    TResult Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult Function(String kind, bool useHmm)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (raw != null) {
+      return raw();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsTokenizer_Raw value) raw,
+    required TResult Function(FtsTokenizer_Simple value) simple,
+    required TResult Function(FtsTokenizer_Whitespace value) whitespace,
+    required TResult Function(FtsTokenizer_NGram value) nGram,
+    required TResult Function(FtsTokenizer_Cangjie value) cangjie,
+  }) {
+    return raw(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsTokenizer_Raw value)? raw,
+    TResult? Function(FtsTokenizer_Simple value)? simple,
+    TResult? Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult? Function(FtsTokenizer_NGram value)? nGram,
+    TResult? Function(FtsTokenizer_Cangjie value)? cangjie,
+  }) {
+    return raw?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsTokenizer_Raw value)? raw,
+    TResult Function(FtsTokenizer_Simple value)? simple,
+    TResult Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult Function(FtsTokenizer_NGram value)? nGram,
+    TResult Function(FtsTokenizer_Cangjie value)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (raw != null) {
+      return raw(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsTokenizer_Raw extends FtsTokenizer {
+  const factory FtsTokenizer_Raw() = _$FtsTokenizer_RawImpl;
+  const FtsTokenizer_Raw._() : super._();
+}
+
+/// @nodoc
+abstract class _$$FtsTokenizer_SimpleImplCopyWith<$Res> {
+  factory _$$FtsTokenizer_SimpleImplCopyWith(
+          _$FtsTokenizer_SimpleImpl value, $Res Function(_$FtsTokenizer_SimpleImpl) then) =
+      __$$FtsTokenizer_SimpleImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FtsTokenizer_SimpleImplCopyWithImpl<$Res>
+    extends _$FtsTokenizerCopyWithImpl<$Res, _$FtsTokenizer_SimpleImpl>
+    implements _$$FtsTokenizer_SimpleImplCopyWith<$Res> {
+  __$$FtsTokenizer_SimpleImplCopyWithImpl(
+      _$FtsTokenizer_SimpleImpl _value, $Res Function(_$FtsTokenizer_SimpleImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$FtsTokenizer_SimpleImpl extends FtsTokenizer_Simple {
+  const _$FtsTokenizer_SimpleImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'FtsTokenizer.simple()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsTokenizer_SimpleImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() raw,
+    required TResult Function() simple,
+    required TResult Function() whitespace,
+    required TResult Function(int minGram, int maxGram, bool prefixOnly) nGram,
+    required TResult Function(String kind, bool useHmm) cangjie,
+  }) {
+    return simple();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? raw,
+    TResult? Function()? simple,
+    TResult? Function()? whitespace,
+    TResult? Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult? Function(String kind, bool useHmm)? cangjie,
+  }) {
+    return simple?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? raw,
+    TResult Function()? simple,
+    TResult Function()? whitespace,
+    TResult Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult Function(String kind, bool useHmm)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (simple != null) {
+      return simple();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsTokenizer_Raw value) raw,
+    required TResult Function(FtsTokenizer_Simple value) simple,
+    required TResult Function(FtsTokenizer_Whitespace value) whitespace,
+    required TResult Function(FtsTokenizer_NGram value) nGram,
+    required TResult Function(FtsTokenizer_Cangjie value) cangjie,
+  }) {
+    return simple(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsTokenizer_Raw value)? raw,
+    TResult? Function(FtsTokenizer_Simple value)? simple,
+    TResult? Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult? Function(FtsTokenizer_NGram value)? nGram,
+    TResult? Function(FtsTokenizer_Cangjie value)? cangjie,
+  }) {
+    return simple?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsTokenizer_Raw value)? raw,
+    TResult Function(FtsTokenizer_Simple value)? simple,
+    TResult Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult Function(FtsTokenizer_NGram value)? nGram,
+    TResult Function(FtsTokenizer_Cangjie value)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (simple != null) {
+      return simple(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsTokenizer_Simple extends FtsTokenizer {
+  const factory FtsTokenizer_Simple() = _$FtsTokenizer_SimpleImpl;
+  const FtsTokenizer_Simple._() : super._();
+}
+
+/// @nodoc
+abstract class _$$FtsTokenizer_WhitespaceImplCopyWith<$Res> {
+  factory _$$FtsTokenizer_WhitespaceImplCopyWith(
+          _$FtsTokenizer_WhitespaceImpl value, $Res Function(_$FtsTokenizer_WhitespaceImpl) then) =
+      __$$FtsTokenizer_WhitespaceImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FtsTokenizer_WhitespaceImplCopyWithImpl<$Res>
+    extends _$FtsTokenizerCopyWithImpl<$Res, _$FtsTokenizer_WhitespaceImpl>
+    implements _$$FtsTokenizer_WhitespaceImplCopyWith<$Res> {
+  __$$FtsTokenizer_WhitespaceImplCopyWithImpl(
+      _$FtsTokenizer_WhitespaceImpl _value, $Res Function(_$FtsTokenizer_WhitespaceImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$FtsTokenizer_WhitespaceImpl extends FtsTokenizer_Whitespace {
+  const _$FtsTokenizer_WhitespaceImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'FtsTokenizer.whitespace()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsTokenizer_WhitespaceImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() raw,
+    required TResult Function() simple,
+    required TResult Function() whitespace,
+    required TResult Function(int minGram, int maxGram, bool prefixOnly) nGram,
+    required TResult Function(String kind, bool useHmm) cangjie,
+  }) {
+    return whitespace();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? raw,
+    TResult? Function()? simple,
+    TResult? Function()? whitespace,
+    TResult? Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult? Function(String kind, bool useHmm)? cangjie,
+  }) {
+    return whitespace?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? raw,
+    TResult Function()? simple,
+    TResult Function()? whitespace,
+    TResult Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult Function(String kind, bool useHmm)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (whitespace != null) {
+      return whitespace();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsTokenizer_Raw value) raw,
+    required TResult Function(FtsTokenizer_Simple value) simple,
+    required TResult Function(FtsTokenizer_Whitespace value) whitespace,
+    required TResult Function(FtsTokenizer_NGram value) nGram,
+    required TResult Function(FtsTokenizer_Cangjie value) cangjie,
+  }) {
+    return whitespace(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsTokenizer_Raw value)? raw,
+    TResult? Function(FtsTokenizer_Simple value)? simple,
+    TResult? Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult? Function(FtsTokenizer_NGram value)? nGram,
+    TResult? Function(FtsTokenizer_Cangjie value)? cangjie,
+  }) {
+    return whitespace?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsTokenizer_Raw value)? raw,
+    TResult Function(FtsTokenizer_Simple value)? simple,
+    TResult Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult Function(FtsTokenizer_NGram value)? nGram,
+    TResult Function(FtsTokenizer_Cangjie value)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (whitespace != null) {
+      return whitespace(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsTokenizer_Whitespace extends FtsTokenizer {
+  const factory FtsTokenizer_Whitespace() = _$FtsTokenizer_WhitespaceImpl;
+  const FtsTokenizer_Whitespace._() : super._();
+}
+
+/// @nodoc
+abstract class _$$FtsTokenizer_NGramImplCopyWith<$Res> {
+  factory _$$FtsTokenizer_NGramImplCopyWith(
+          _$FtsTokenizer_NGramImpl value, $Res Function(_$FtsTokenizer_NGramImpl) then) =
+      __$$FtsTokenizer_NGramImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int minGram, int maxGram, bool prefixOnly});
+}
+
+/// @nodoc
+class __$$FtsTokenizer_NGramImplCopyWithImpl<$Res>
+    extends _$FtsTokenizerCopyWithImpl<$Res, _$FtsTokenizer_NGramImpl>
+    implements _$$FtsTokenizer_NGramImplCopyWith<$Res> {
+  __$$FtsTokenizer_NGramImplCopyWithImpl(
+      _$FtsTokenizer_NGramImpl _value, $Res Function(_$FtsTokenizer_NGramImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? minGram = null,
+    Object? maxGram = null,
+    Object? prefixOnly = null,
+  }) {
+    return _then(_$FtsTokenizer_NGramImpl(
+      minGram: null == minGram
+          ? _value.minGram
+          : minGram // ignore: cast_nullable_to_non_nullable
+              as int,
+      maxGram: null == maxGram
+          ? _value.maxGram
+          : maxGram // ignore: cast_nullable_to_non_nullable
+              as int,
+      prefixOnly: null == prefixOnly
+          ? _value.prefixOnly
+          : prefixOnly // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$FtsTokenizer_NGramImpl extends FtsTokenizer_NGram {
+  const _$FtsTokenizer_NGramImpl({required this.minGram, required this.maxGram, required this.prefixOnly})
+      : super._();
+
+  @override
+  final int minGram;
+
+  @override
+  final int maxGram;
+
+  @override
+  final bool prefixOnly;
+
+  @override
+  String toString() {
+    return 'FtsTokenizer.nGram(minGram: $minGram, maxGram: $maxGram, prefixOnly: $prefixOnly)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsTokenizer_NGramImpl &&
+            (identical(other.minGram, minGram) || other.minGram == minGram) &&
+            (identical(other.maxGram, maxGram) || other.maxGram == maxGram) &&
+            (identical(other.prefixOnly, prefixOnly) || other.prefixOnly == prefixOnly));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, minGram, maxGram, prefixOnly);
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FtsTokenizer_NGramImplCopyWith<_$FtsTokenizer_NGramImpl> get copyWith =>
+      __$$FtsTokenizer_NGramImplCopyWithImpl<_$FtsTokenizer_NGramImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() raw,
+    required TResult Function() simple,
+    required TResult Function() whitespace,
+    required TResult Function(int minGram, int maxGram, bool prefixOnly) nGram,
+    required TResult Function(String kind, bool useHmm) cangjie,
+  }) {
+    return nGram(minGram, maxGram, prefixOnly);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? raw,
+    TResult? Function()? simple,
+    TResult? Function()? whitespace,
+    TResult? Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult? Function(String kind, bool useHmm)? cangjie,
+  }) {
+    return nGram?.call(minGram, maxGram, prefixOnly);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? raw,
+    TResult Function()? simple,
+    TResult Function()? whitespace,
+    TResult Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult Function(String kind, bool useHmm)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (nGram != null) {
+      return nGram(minGram, maxGram, prefixOnly);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsTokenizer_Raw value) raw,
+    required TResult Function(FtsTokenizer_Simple value) simple,
+    required TResult Function(FtsTokenizer_Whitespace value) whitespace,
+    required TResult Function(FtsTokenizer_NGram value) nGram,
+    required TResult Function(FtsTokenizer_Cangjie value) cangjie,
+  }) {
+    return nGram(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsTokenizer_Raw value)? raw,
+    TResult? Function(FtsTokenizer_Simple value)? simple,
+    TResult? Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult? Function(FtsTokenizer_NGram value)? nGram,
+    TResult? Function(FtsTokenizer_Cangjie value)? cangjie,
+  }) {
+    return nGram?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsTokenizer_Raw value)? raw,
+    TResult Function(FtsTokenizer_Simple value)? simple,
+    TResult Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult Function(FtsTokenizer_NGram value)? nGram,
+    TResult Function(FtsTokenizer_Cangjie value)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (nGram != null) {
+      return nGram(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsTokenizer_NGram extends FtsTokenizer {
+  const factory FtsTokenizer_NGram({required final int minGram, required final int maxGram, required final bool prefixOnly}) = _$FtsTokenizer_NGramImpl;
+  const FtsTokenizer_NGram._() : super._();
+
+  int get minGram;
+  int get maxGram;
+  bool get prefixOnly;
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FtsTokenizer_NGramImplCopyWith<_$FtsTokenizer_NGramImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$FtsTokenizer_CangjieImplCopyWith<$Res> {
+  factory _$$FtsTokenizer_CangjieImplCopyWith(
+          _$FtsTokenizer_CangjieImpl value, $Res Function(_$FtsTokenizer_CangjieImpl) then) =
+      __$$FtsTokenizer_CangjieImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String kind, bool useHmm});
+}
+
+/// @nodoc
+class __$$FtsTokenizer_CangjieImplCopyWithImpl<$Res>
+    extends _$FtsTokenizerCopyWithImpl<$Res, _$FtsTokenizer_CangjieImpl>
+    implements _$$FtsTokenizer_CangjieImplCopyWith<$Res> {
+  __$$FtsTokenizer_CangjieImplCopyWithImpl(
+      _$FtsTokenizer_CangjieImpl _value, $Res Function(_$FtsTokenizer_CangjieImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? kind = null,
+    Object? useHmm = null,
+  }) {
+    return _then(_$FtsTokenizer_CangjieImpl(
+      kind: null == kind
+          ? _value.kind
+          : kind // ignore: cast_nullable_to_non_nullable
+              as String,
+      useHmm: null == useHmm
+          ? _value.useHmm
+          : useHmm // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$FtsTokenizer_CangjieImpl extends FtsTokenizer_Cangjie {
+  const _$FtsTokenizer_CangjieImpl({required this.kind, required this.useHmm})
+      : super._();
+
+  @override
+  final String kind;
+
+  @override
+  final bool useHmm;
+
+  @override
+  String toString() {
+    return 'FtsTokenizer.cangjie(kind: $kind, useHmm: $useHmm)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FtsTokenizer_CangjieImpl &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            (identical(other.useHmm, useHmm) || other.useHmm == useHmm));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, kind, useHmm);
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FtsTokenizer_CangjieImplCopyWith<_$FtsTokenizer_CangjieImpl> get copyWith =>
+      __$$FtsTokenizer_CangjieImplCopyWithImpl<_$FtsTokenizer_CangjieImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() raw,
+    required TResult Function() simple,
+    required TResult Function() whitespace,
+    required TResult Function(int minGram, int maxGram, bool prefixOnly) nGram,
+    required TResult Function(String kind, bool useHmm) cangjie,
+  }) {
+    return cangjie(kind, useHmm);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? raw,
+    TResult? Function()? simple,
+    TResult? Function()? whitespace,
+    TResult? Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult? Function(String kind, bool useHmm)? cangjie,
+  }) {
+    return cangjie?.call(kind, useHmm);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? raw,
+    TResult Function()? simple,
+    TResult Function()? whitespace,
+    TResult Function(int minGram, int maxGram, bool prefixOnly)? nGram,
+    TResult Function(String kind, bool useHmm)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (cangjie != null) {
+      return cangjie(kind, useHmm);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FtsTokenizer_Raw value) raw,
+    required TResult Function(FtsTokenizer_Simple value) simple,
+    required TResult Function(FtsTokenizer_Whitespace value) whitespace,
+    required TResult Function(FtsTokenizer_NGram value) nGram,
+    required TResult Function(FtsTokenizer_Cangjie value) cangjie,
+  }) {
+    return cangjie(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FtsTokenizer_Raw value)? raw,
+    TResult? Function(FtsTokenizer_Simple value)? simple,
+    TResult? Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult? Function(FtsTokenizer_NGram value)? nGram,
+    TResult? Function(FtsTokenizer_Cangjie value)? cangjie,
+  }) {
+    return cangjie?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FtsTokenizer_Raw value)? raw,
+    TResult Function(FtsTokenizer_Simple value)? simple,
+    TResult Function(FtsTokenizer_Whitespace value)? whitespace,
+    TResult Function(FtsTokenizer_NGram value)? nGram,
+    TResult Function(FtsTokenizer_Cangjie value)? cangjie,
+    required TResult orElse(),
+  }) {
+    if (cangjie != null) {
+      return cangjie(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FtsTokenizer_Cangjie extends FtsTokenizer {
+  const factory FtsTokenizer_Cangjie({required final String kind, required final bool useHmm}) = _$FtsTokenizer_CangjieImpl;
+  const FtsTokenizer_Cangjie._() : super._();
+
+  String get kind;
+  bool get useHmm;
+
+  /// Create a copy of FtsTokenizer
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FtsTokenizer_CangjieImplCopyWith<_$FtsTokenizer_CangjieImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -4,24 +4,78 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'value.dart';
 
-/// Open a new CozoDB database.
+
+            // These functions are ignored because they are not marked as `pub`: `closed_error`, `from_instance`, `from_remote`, `instance`, `is_remote`, `live_instance`, `mutability`, `mutability`, `parse_params`, `query_error`, `record_rows`, `rows_to_json`, `run_query`, `run_rewritten`, `run_rows`, `share`, `shared`, `with_heap_limit`, `writable`
+
+
+            /// Open a new CozoDB database.
+///
+/// - `engine`: "mem" for in-memory, "sqlite" for persistent SQLite,
+///   "rocksdb" for persistent RocksDB (requires the `rocksdb` crate feature),
+///   "http" for a remote cozo-server
+/// - `path`: file path for sqlite, directory for rocksdb, empty string for
+///   mem, server URL such as `https://host:9070` for http
+/// - `options`: JSON string of engine options, use "{}" for defaults.
+///   `{"read_only": true}` opens an existing database for reading only: all
+///   scripts run immutable and writes fail at the binding layer. SQLite takes
+///   its write lock only while writing, so a read-only handle never holds one
+///   and can share a file owned by another process.
+///
+/// `{"heap_limit_bytes": n}` makes `cozo_run_query` stop a query during
+/// which the process's Rust heap grows by more than `n` bytes, so a runaway
+/// join fails with a `Query failed [heap_limit]` error before the OS kills
+/// the app. The limit is off unless set, and not available on the web. It
+/// caps the growth of the whole process's heap, not the query's own
+/// allocations: memory allocated meanwhile by other queries, handles or
+/// threads counts too and can stop an unrelated query, see
+/// `QueryOptions::heap_limit_bytes`.
+///
+/// At-rest encryption is not available: cozo opens its own pooled SQLite
+/// connections with no hook for a SQLCipher key, so an `encryption_key`
+/// option is rejected rather than silently writing plaintext. Sensitive
+/// columns can be encrypted instead: `{"column_key": hex}`, 64 hex digits
+/// of a 256-bit key kept e.g. in the platform keystore, enables
+/// `cozo_encrypt_column` on the handle.
+///
+/// For the sqlite engine, `{"journal_mode": "wal"}` switches the file to
+/// write-ahead logging, so reads no longer wait for a write to commit; the
+/// mode is stored in the file and kept by later opens. "delete", "truncate"
+/// and "persist" switch back to a rollback journal. The per-connection
+/// PRAGMAs `synchronous`, `cache_size`, `mmap_size` and `busy_timeout` are
+/// rejected for the same reason as `encryption_key`: cozo opens its own
+/// connections with no hook to set them.
 ///
-/// - `engine`: "mem" for in-memory, "sqlite" for persistent SQLite
-/// - `path`: file path for sqlite engine, empty string for mem
-/// - `options`: JSON string of engine options, use "{}" for defaults
+/// A writable sqlite or rocksdb handle claims the database for its process
+/// through the lock file `<path>.lock` until it is closed, so that a second
+/// process opening it, such as an Android widget or receiver process, fails
+/// with an `ErrorKind::Locked` error naming the holder's pid instead of
+/// SQLite's "database is locked" on its first write. Opens within one
+/// process share the claim, and read-only handles take none.
+///
+/// A remote handle sends [`cozo_run_query`], [`cozo_export_relations`] and
+/// [`cozo_import_relations`] to the server, authenticating with the `auth`
+/// option (the server's auth token) and giving up after `timeout_secs`
+/// (default 60). Other calls fail on a remote handle.
+///
+/// On the web (wasm builds) only "mem" is available.
 ///
 /// Returns an opaque CozoDb handle.
-CozoDb cozoOpenDb({
-  required String engine,
-  required String path,
-  required String options,
-}) => RustLib.instance.api.crateApiSimpleCozoOpenDb(
-  engine: engine,
-  path: path,
-  options: options,
-);
+CozoDb  cozoOpenDb({required String engine , required String path , required String options }) => RustLib.instance.api.crateApiSimpleCozoOpenDb(engine: engine, path: path, options: options);
+
+/// Close the database, releasing the storage (and the SQLite file lock) once
+/// queries already in flight have finished.
+///
+/// Change streams registered through this handle are closed, and automatic
+/// backups and auditing stop. Any later call on the handle fails with a
+/// "Database is closed" error. Closing twice is a no-op.
+void  cozoCloseDb({required CozoDb db }) => RustLib.instance.api.crateApiSimpleCozoCloseDb(db: db);
+
+/// Whether [`cozo_close_db`] has been called on this handle.
+bool  cozoIsClosed({required CozoDb db }) => RustLib.instance.api.crateApiSimpleCozoIsClosed(db: db);
 
 /// Run a CozoScript query.
 ///
@@ -30,61 +84,80 @@ CozoDb cozoOpenDb({
 /// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
 /// - `immutable`: if true, the query is run in read-only mode
 ///
-/// Returns a JSON string with the query result.
-Future<String> cozoRunQuery({
-  required CozoDb db,
-  required String script,
-  required String paramsJson,
-  required bool immutable,
-}) => RustLib.instance.api.crateApiSimpleCozoRunQuery(
-  db: db,
-  script: script,
-  paramsJson: paramsJson,
-  immutable: immutable,
-);
+/// This is not marked `#[frb(sync)]`: FRB runs it on its worker thread pool
+/// and Dart receives a `Future`, so long queries never block the UI isolate.
+///
+/// Returns a JSON string with the query result. Query failures are returned
+/// as errors carrying the error kind (e.g. `parser::pest`) and the rendered
+/// display message, so they surface as Dart exceptions. A panic inside cozo
+/// is returned as an `Internal error` rather than unwinding into the bridge.
+/// A query during which the heap grows past the handle's `heap_limit_bytes`
+/// is stopped, see [`cozo_open_db`].
+Future<String>  cozoRunQuery({required CozoDb db , required String script , required String paramsJson , required bool immutable }) => RustLib.instance.api.crateApiSimpleCozoRunQuery(db: db, script: script, paramsJson: paramsJson, immutable: immutable);
+
+/// Run a CozoScript query, failing with a structured [`CozoDartError`].
+///
+/// Arguments and the result are the same as [`cozo_run_query`], and the
+/// error message is the same text, but Dart receives the error's kind,
+/// diagnostic code, script span and relation as fields to branch on.
+Future<String>  cozoRunQueryChecked({required CozoDb db , required String script , required String paramsJson , required bool immutable }) => RustLib.instance.api.crateApiSimpleCozoRunQueryChecked(db: db, script: script, paramsJson: paramsJson, immutable: immutable);
+
+/// Run a CozoScript query with typed parameters and return a typed result.
+///
+/// - `params`: named parameters as [`CozoValue`]s, so bytes, big integers
+///   and vectors are passed without JSON encoding; a `Uint8List` bound as
+///   [`CozoValue::Bytes`] is stored as a `Bytes` value with no base64 step
+///
+/// Other arguments are the same as [`cozo_run_query`], and the query runs
+/// the same way, but the rows come back as [`QueryResult`] with typed
+/// values instead of a JSON string.
+/// This skips the intermediate JSON allocation entirely, which matters for
+/// results with large string or bytes columns.
+/// Failures are reported through `ok` and `error`.
+Future<QueryResult>  cozoRunQueryTyped({required CozoDb db , required String script , required Map<String, CozoValue> params , required bool immutable }) => RustLib.instance.api.crateApiSimpleCozoRunQueryTyped(db: db, script: script, params: params, immutable: immutable);
 
 /// Export relations from the database.
 ///
-/// - `relations_json`: JSON array of relation names, e.g. '["users", "edges"]'
+/// - `relations_json`: JSON array of relation names, e.g. '["users", "edges"]',
+///   or cozo's `{"relations": [..]}`
+///
+/// Relations with a row policy only export the rows it allows, see
+/// `cozo_set_row_policy`.
 ///
 /// Returns JSON string with exported data.
-Future<String> cozoExportRelations({
-  required CozoDb db,
-  required String relationsJson,
-}) => RustLib.instance.api.crateApiSimpleCozoExportRelations(
-  db: db,
-  relationsJson: relationsJson,
-);
+Future<String>  cozoExportRelations({required CozoDb db , required String relationsJson }) => RustLib.instance.api.crateApiSimpleCozoExportRelations(db: db, relationsJson: relationsJson);
 
 /// Import relations into the database.
 ///
 /// - `data_json`: JSON string in the same format as export output.
-Future<void> cozoImportRelations({
-  required CozoDb db,
-  required String dataJson,
-}) => RustLib.instance.api.crateApiSimpleCozoImportRelations(
-  db: db,
-  dataJson: dataJson,
-);
+Future<void>  cozoImportRelations({required CozoDb db , required String dataJson }) => RustLib.instance.api.crateApiSimpleCozoImportRelations(db: db, dataJson: dataJson);
 
 /// Backup the database to a file path.
-Future<void> cozoBackup({required CozoDb db, required String path}) =>
-    RustLib.instance.api.crateApiSimpleCozoBackup(db: db, path: path);
+Future<void>  cozoBackup({required CozoDb db , required String path }) => RustLib.instance.api.crateApiSimpleCozoBackup(db: db, path: path);
 
-/// Restore the database from a backup file.
-Future<void> cozoRestore({required CozoDb db, required String path}) =>
-    RustLib.instance.api.crateApiSimpleCozoRestore(db: db, path: path);
+/// Restore the database from a backup file, compressed or not.
+Future<void>  cozoRestore({required CozoDb db , required String path }) => RustLib.instance.api.crateApiSimpleCozoRestore(db: db, path: path);
 
 /// Import relations from a backup file without fully restoring.
-Future<void> cozoImportFromBackup({
-  required CozoDb db,
-  required String path,
-  required String relationsJson,
-}) => RustLib.instance.api.crateApiSimpleCozoImportFromBackup(
-  db: db,
-  path: path,
-  relationsJson: relationsJson,
-);
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CozoDb>>
-abstract class CozoDb implements RustOpaqueInterface {}
+Future<void>  cozoImportFromBackup({required CozoDb db , required String path , required String relationsJson }) => RustLib.instance.api.crateApiSimpleCozoImportFromBackup(db: db, path: path, relationsJson: relationsJson);
+
+/// Set the number of worker threads that run async (non-`sync`) calls.
+///
+/// - `threads`: pool size, at least 1. Low-end devices can cap parallelism
+///   with a small pool; desktop builds can match the number of cores.
+///
+/// Takes effect immediately. Shrinking the pool lets running calls finish
+/// first; extra threads exit as they become idle.
+///
+/// Returns the previous pool size.
+int  cozoConfigure({required int threads }) => RustLib.instance.api.crateApiSimpleCozoConfigure(threads: threads);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CozoDb>>
+                abstract class CozoDb implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+            
\ No newline at end of file
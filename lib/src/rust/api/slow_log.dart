@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `record`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Configure the slow-query log of the database.
+///
+/// - `threshold_ms`: queries taking at least this long are recorded; `None`
+///   stops recording. New handles use 100 ms.
+/// - `capacity`: number of entries kept, oldest dropped first. New handles
+///   keep 100.
+///
+/// Queries run through `cozo_run_query` and the calls built on it are
+/// recorded. Existing entries beyond the new capacity are dropped.
+void  cozoConfigureSlowLog({required CozoDb db , int? thresholdMs , required int capacity }) => RustLib.instance.api.crateApiSlowLogCozoConfigureSlowLog(db: db, thresholdMs: thresholdMs, capacity: capacity);
+
+/// The recorded slow queries, oldest first.
+List<SlowQuery>  cozoSlowQueries({required CozoDb db }) => RustLib.instance.api.crateApiSlowLogCozoSlowQueries(db: db);
+
+/// Remove every recorded slow query.
+void  cozoClearSlowQueries({required CozoDb db }) => RustLib.instance.api.crateApiSlowLogCozoClearSlowQueries(db: db);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SlowLog>>
+                abstract class SlowLog implements RustOpaqueInterface {
+                    static Future<SlowLog>  default_()=>RustLib.instance.api.crateApiSlowLogSlowLogDefault();
+
+
+
+                    
+                }
+                
+
+/// A query that ran longer than the slow-query threshold.
+class SlowQuery  {
+                final String script;
+/// Hash of the parameters JSON, so queries with the same parameters can
+/// be grouped without recording the values. Only comparable within one
+/// run of the app.
+final String paramsDigest;
+/// Seconds the query took.
+final double took;
+/// Rows returned.
+final BigInt rows;
+/// When the query finished, in seconds since the Unix epoch.
+final double finishedAt;
+
+                const SlowQuery({required this.script ,required this.paramsDigest ,required this.took ,required this.rows ,required this.finishedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => script.hashCode^paramsDigest.hashCode^took.hashCode^rows.hashCode^finishedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SlowQuery &&
+                runtimeType == other.runtimeType
+                && script == other.script&& paramsDigest == other.paramsDigest&& took == other.took&& rows == other.rows&& finishedAt == other.finishedAt;
+        
+            }
+            
\ No newline at end of file
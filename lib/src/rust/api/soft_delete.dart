@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `ensure_settings`, `reassert`, `set_flag`, `settings_exist`, `soft_delete_mode`, `soft_delete_rows`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SoftDelete`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// Make `cozo_delete_rows` keep the rows of `relation` and mark them
+/// deleted instead, so that [`cozo_restore_deleted`] can bring them back.
+///
+/// - `flag_column`: a non-key `Bool` column, typically declared with
+///   `default false`, that deleting sets to true; `cozo_get_row` then treats
+///   flagged rows as missing. `None` for a time travel relation, whose rows
+///   are retracted at the current time instead, as with `cozo_retract_rows`.
+///
+/// Only the helpers see the mode: scripts reading the relation must filter
+/// flagged rows themselves, and `:rm` still removes rows. The setting is
+/// stored in the `cozo_soft_delete` relation and survives restarts.
+Future<void>  cozoEnableSoftDelete({required CozoDb db , required String relation , String? flagColumn }) => RustLib.instance.api.crateApiSoftDeleteCozoEnableSoftDelete(db: db, relation: relation, flagColumn: flagColumn);
+
+/// Make `cozo_delete_rows` remove rows of `relation` again. Rows already
+/// marked deleted stay as they are.
+Future<void>  cozoDisableSoftDelete({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiSoftDeleteCozoDisableSoftDelete(db: db, relation: relation);
+
+/// Bring back rows of `relation` deleted while soft deletes were enabled.
+///
+/// - `keys`: one entry per row, holding the key column values in key order,
+///   leaving out the `Validity` of a time travel relation
+///
+/// A flagged row has its flag cleared. A retracted row is asserted again
+/// now, with the values it had when it was retracted. Keys of rows that are
+/// not deleted are ignored.
+///
+/// Returns the number of rows restored.
+Future<BigInt>  cozoRestoreDeleted({required CozoDb db , required String relation , required List<List<CozoValue>> keys }) => RustLib.instance.api.crateApiSoftDeleteCozoRestoreDeleted(db: db, relation: relation, keys: keys);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,124 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add_dir_sizes`, `encoded_size`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Row count, estimated size and indices of a stored relation, e.g. for a
+/// settings screen showing storage per table.
+///
+/// Scans the whole relation, so the time taken grows with its size.
+Future<RelationStats>  cozoRelationStats({required CozoDb db , required String name }) => RustLib.instance.api.crateApiStatsCozoRelationStats(db: db, name: name);
+
+/// Storage used by the database, e.g. for a "storage used by this app"
+/// screen or to decide when to compact.
+Future<DbStats>  cozoDbStats({required CozoDb db }) => RustLib.instance.api.crateApiStatsCozoDbStats(db: db);
+
+            /// Storage usage of the whole database, see [`cozo_db_stats`].
+class DbStats  {
+                final String engine;
+/// Size of the database file (sqlite) or directory (rocksdb), without
+/// the write-ahead log. 0 for mem.
+final BigInt fileBytes;
+/// Size of the write-ahead log: the `-wal` file for sqlite in WAL mode
+/// (or the rollback `-journal` during a write), the `.log` files for
+/// rocksdb. 0 for mem.
+final BigInt walBytes;
+/// SQLite page size in bytes; `None` for other engines.
+final BigInt? pageSize;
+/// SQLite pages in the file; `None` for other engines.
+final BigInt? pageCount;
+/// SQLite pages that are unused, e.g. after deleting rows, and can be
+/// reclaimed by compacting. `None` for other engines.
+final BigInt? freePages;
+/// Number of stored relations, not counting indices.
+final int relations;
+
+                const DbStats({required this.engine ,required this.fileBytes ,required this.walBytes ,this.pageSize ,this.pageCount ,this.freePages ,required this.relations ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => engine.hashCode^fileBytes.hashCode^walBytes.hashCode^pageSize.hashCode^pageCount.hashCode^freePages.hashCode^relations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DbStats &&
+                runtimeType == other.runtimeType
+                && engine == other.engine&& fileBytes == other.fileBytes&& walBytes == other.walBytes&& pageSize == other.pageSize&& pageCount == other.pageCount&& freePages == other.freePages&& relations == other.relations;
+        
+            }
+
+/// An index of a stored relation, as listed by `::indices`.
+class IndexSummary  {
+                final String name;
+/// "normal", "hnsw", "fts" or "lsh".
+final String indexType;
+
+                const IndexSummary({required this.name ,required this.indexType ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^indexType.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexSummary &&
+                runtimeType == other.runtimeType
+                && name == other.name&& indexType == other.indexType;
+        
+            }
+
+/// Storage usage of one stored relation, see [`cozo_relation_stats`].
+class RelationStats  {
+                final String name;
+final BigInt rows;
+/// Estimated size of the rows in storage, from the encoded size of each
+/// value. Indices and storage-engine overhead are not included.
+final BigInt approxBytes;
+/// Indices on the relation.
+final List<IndexSummary> indices;
+/// Latest `Validity` timestamp in the key, in microseconds, for
+/// relations keyed by validity (time travel). Cozo keeps no modification
+/// time for other relations, so this is `None` for them.
+final PlatformInt64? lastModified;
+
+                const RelationStats({required this.name ,required this.rows ,required this.approxBytes ,required this.indices ,this.lastModified ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^rows.hashCode^approxBytes.hashCode^indices.hashCode^lastModified.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RelationStats &&
+                runtimeType == other.runtimeType
+                && name == other.name&& rows == other.rows&& approxBytes == other.approxBytes&& indices == other.indices&& lastModified == other.lastModified;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,121 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `set_journal_mode`, `sqlite_file_query`, `sqlite_path`, `sqlite_pragma`, `sqlite_query`, `sync_file`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Reclaim the space left behind by deleted rows, reporting progress
+/// through a stream.
+///
+/// For the sqlite engine this runs `VACUUM`, which rewrites the whole file
+/// and needs up to twice its size in free disk space while it runs; writes
+/// from other calls wait until it is done. For rocksdb it compacts all
+/// stored data. For mem it does nothing.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "bytes": n, "reclaimed": n | null, "elapsed": secs}`,
+/// where `bytes` is the size of the database files before compacting, and
+/// after compacting in the `done` event, which also carries the bytes
+/// `reclaimed`. The stream closes after the `done` event, or fails with the
+/// compaction error.
+Stream<String>  cozoCompact({required CozoDb db }) => RustLib.instance.api.crateApiStorageCozoCompact(db: db);
+
+/// Write everything committed so far into the database files and flush them
+/// to disk, e.g. from `didChangeAppLifecycleState` when the state becomes
+/// `AppLifecycleState.paused`.
+///
+/// iOS and Android may kill a paused app without warning. Committed data
+/// survives that on its own, but a device losing power or an OS crash can
+/// still drop writes the OS has not flushed yet; checkpointing on pause
+/// narrows that window and leaves a small `-wal` file for the next start.
+///
+/// For the sqlite engine in WAL mode this runs `PRAGMA wal_checkpoint(TRUNCATE)`,
+/// which copies the write-ahead log into the database file and empties it;
+/// then the files are synced to disk. For rocksdb the files in its directory
+/// are synced. For mem it does nothing. It is cheap when little has been
+/// written, so calling it on every pause is fine.
+///
+/// Returns false if a query still reading the database kept part of the log
+/// from being copied; the synced log keeps that part safe, and the next
+/// checkpoint copies it.
+Future<bool>  cozoCheckpoint({required CozoDb db }) => RustLib.instance.api.crateApiStorageCozoCheckpoint(db: db);
+
+/// Check that the database can be read in full, e.g. after a crash or
+/// before a backup, instead of finding out from a failing query later.
+///
+/// Runs the storage engine's integrity check, then reads the columns and
+/// indices of every stored relation and scans all of its rows. The scan
+/// reads every row, so this takes about as long as exporting the database.
+///
+/// Fails only if the database is closed; problems are listed in the report.
+Future<IntegrityReport>  cozoCheckIntegrity({required CozoDb db }) => RustLib.instance.api.crateApiStorageCozoCheckIntegrity(db: db);
+
+            /// Outcome of [`cozo_check_integrity`].
+class IntegrityReport  {
+                /// Whether no problem was found.
+final bool ok;
+/// Problems found by the storage engine's own check, `PRAGMA
+/// integrity_check` for sqlite. Always empty for mem and rocksdb, which
+/// have no such check.
+final List<String> storageErrors;
+/// Stored relations whose schema, indices or rows could not be read.
+final List<RelationIntegrityError> relationErrors;
+/// Stored relations scanned, not counting indices.
+final int relationsChecked;
+/// Rows read while scanning them.
+final BigInt rowsChecked;
+
+                const IntegrityReport({required this.ok ,required this.storageErrors ,required this.relationErrors ,required this.relationsChecked ,required this.rowsChecked ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ok.hashCode^storageErrors.hashCode^relationErrors.hashCode^relationsChecked.hashCode^rowsChecked.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IntegrityReport &&
+                runtimeType == other.runtimeType
+                && ok == other.ok&& storageErrors == other.storageErrors&& relationErrors == other.relationErrors&& relationsChecked == other.relationsChecked&& rowsChecked == other.rowsChecked;
+        
+            }
+
+/// A stored relation that failed the check in [`cozo_check_integrity`].
+class RelationIntegrityError  {
+                /// The relation, or empty if the list of relations could not be read.
+final String relation;
+final String message;
+
+                const RelationIntegrityError({required this.relation ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RelationIntegrityError &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& message == other.message;
+        
+            }
+            
\ No newline at end of file
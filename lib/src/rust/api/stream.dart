@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            
+
+            /// Run a CozoScript query and deliver its rows in batches.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+/// - `chunk_size`: maximum number of rows per chunk
+/// - `immutable`: if true, the query is run in read-only mode
+///
+/// Each event is a JSON string
+/// `{"headers": [..], "rows": [..], "offset": n, "last": bool}` where `offset`
+/// is the index of the chunk's first row. A query with no rows still sends one
+/// empty, last chunk. The stream closes after the last chunk, or fails with
+/// the query error.
+Stream<String>  cozoRunQueryStream({required CozoDb db , required String script , required String paramsJson , required int chunkSize , required bool immutable }) => RustLib.instance.api.crateApiStreamCozoRunQueryStream(db: db, script: script, paramsJson: paramsJson, chunkSize: chunkSize, immutable: immutable);
+
+/// Run a CozoScript query like `cozo_run_query`, sending heartbeats to
+/// `sink` while it runs, e.g. to show that a long recursion or fixed rule
+/// such as `PageRank` over a large graph is still making progress.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "elapsed": secs, "heap_bytes": n}`,
+/// with a `progress` event every 200 ms. Cozo evaluates a query without
+/// reporting how many rows it has derived so far, so `heap_bytes`, the
+/// growth of the library's heap since the query started, stands in for
+/// the work done; other queries running at the same time count towards it.
+/// The stream closes after the `done` event, which is sent whether or not
+/// the query succeeded.
+///
+/// Returns the same JSON result as `cozo_run_query`.
+Stream<String>  cozoRunQueryWithProgress({required CozoDb db , required String script , required String paramsJson , required bool immutable }) => RustLib.instance.api.crateApiStreamCozoRunQueryWithProgress(db: db, script: script, paramsJson: paramsJson, immutable: immutable);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,148 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_changes`, `base_rows`, `ensure_log`, `is_sync_trigger`, `key_pattern`, `local_versions`, `log_exists`, `log_trigger`, `next_seq`, `query`, `resolve_conflict`, `row_json`, `rows_json`, `tick`, `with_base`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Outcome`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Record changes to stored relations so they can be synced with another
+/// install of the app, with [`cozo_sync_export_changes`] and
+/// [`cozo_sync_apply_changes`].
+///
+/// - `relations`: stored relations to sync; each install must enable the
+///   same ones, with the same columns
+///
+/// Triggers added to each relation record, for every row put or removed,
+/// the time of the change in the `cozo_sync_log` relation, which keeps only
+/// the newest change of each row. Rows already in the relation are recorded
+/// as changed now, so the first export carries them. Triggers set on the
+/// relation later with `cozo_set_triggers` must keep the sync triggers,
+/// which [`cozo_list_triggers`] returns along with the others. Enabling a
+/// relation again does nothing.
+Future<void>  cozoSyncEnable({required CozoDb db , required List<String> relations }) => RustLib.instance.api.crateApiSyncCozoSyncEnable(db: db, relations: relations);
+
+/// Stop recording changes to `relation` and forget the changes recorded and
+/// received so far. Its rows are kept.
+Future<void>  cozoSyncDisable({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiSyncCozoSyncDisable(db: db, relation: relation);
+
+/// Export the changes to synced relations recorded since `since_token`, for
+/// sending to another install over any transport.
+///
+/// - `since_token`: the token of the last export that the other install
+///   applied, or an empty string for every recorded change
+///
+/// Each changed row is exported once, as it is now: its current values, or
+/// its key if it was removed. Keep one token per install synced with, and
+/// store the new token only once that install has applied the payload; an
+/// older token just exports some changes again, which applying skips.
+Future<SyncChangeset>  cozoSyncExportChanges({required CozoDb db , required String sinceToken }) => RustLib.instance.api.crateApiSyncCozoSyncExportChanges(db: db, sinceToken: sinceToken);
+
+/// Apply a payload from [`cozo_sync_export_changes`] of another install.
+///
+/// A remote change made to the row as this install last received or sent
+/// it is written as it is. A remote change conflicts with one made here in
+/// the meantime if both installs changed the row since; conflicts are
+/// resolved per row, last writer wins: the remote change is written unless
+/// the row changed here after it, going by the clocks of the installs that
+/// made the changes. Applied changes are recorded as changes of this
+/// install, so they are passed on to installs this one syncs with. They are
+/// written with `:put` and `:rm` like local changes, so the indices and
+/// triggers of the relations see them too. All changes are written in one
+/// transaction.
+///
+/// Fails without writing anything if a relation of the payload is missing
+/// or a row does not fit its columns.
+Future<SyncReport>  cozoSyncApplyChanges({required CozoDb db , required String payload }) => RustLib.instance.api.crateApiSyncCozoSyncApplyChanges(db: db, payload: payload);
+
+/// Apply a payload from [`cozo_sync_export_changes`] of another install
+/// like [`cozo_sync_apply_changes`], with conflicts settled by `resolve`
+/// instead of by last writer wins.
+///
+/// - `resolve`: called for each conflicting row with a JSON object
+///   `{"relation", "headers", "local", "remote", "base"}`, where `local`
+///   and `remote` are the row here and in the payload and `base` the row
+///   both installs last had, each an array of values in `headers` order or
+///   null if the row is removed or, for `base`, unknown; must complete with
+///   the winning row as such an array, or null to remove it
+///
+/// The winner is recorded as a new change of this install unless it is the
+/// remote row, so it is sent back to the other install on the next export
+/// and replaces the row there without another conflict.
+///
+/// Each call blocks until the Dart function completes, so this must not be
+/// called from a `#[frb(sync)]` call. Fails without writing anything if
+/// `resolve` returns a row that does not fit or changes the key.
+Future<SyncReport>  cozoSyncApplyChangesWithResolver({required CozoDb db , required String payload , required FutureOr<String> Function(String) resolve }) => RustLib.instance.api.crateApiSyncCozoSyncApplyChangesWithResolver(db: db, payload: payload, resolve: resolve);
+
+            /// Changes exported by [`cozo_sync_export_changes`].
+class SyncChangeset  {
+                /// JSON payload to hand to `cozo_sync_apply_changes` on the other
+/// install.
+final String payload;
+/// Token to pass as `since_token` next time, once the other install
+/// has applied this payload.
+final String token;
+/// Number of rows put or removed in the payload.
+final BigInt changes;
+
+                const SyncChangeset({required this.payload ,required this.token ,required this.changes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => payload.hashCode^token.hashCode^changes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SyncChangeset &&
+                runtimeType == other.runtimeType
+                && payload == other.payload&& token == other.token&& changes == other.changes;
+        
+            }
+
+/// Outcome of [`cozo_sync_apply_changes`].
+class SyncReport  {
+                /// Remote changes written to this database.
+final BigInt applied;
+/// Remote changes not written because the row changed later here, or
+/// the change had been applied before.
+final BigInt skipped;
+/// Conflicting changes settled by the resolver passed to
+/// [`cozo_sync_apply_changes_with_resolver`].
+final BigInt resolved;
+
+                const SyncReport({required this.applied ,required this.skipped ,required this.resolved ,});
+
+                static Future<SyncReport>  default_()=>RustLib.instance.api.crateApiSyncSyncReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => applied.hashCode^skipped.hashCode^resolved.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SyncReport &&
+                runtimeType == other.runtimeType
+                && applied == other.applied&& skipped == other.skipped&& resolved == other.resolved;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,93 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `atom_end`, `skip_string`, `temporal_column`, `validity_column`, `validity`, `with_validity`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// The `Validity` value for `time`, to bind as a parameter or write into a
+/// `Validity` column without converting to microseconds by hand.
+CozoValue  cozoValidityFromTime({required ValidityTime time }) => RustLib.instance.api.crateApiTemporalCozoValidityFromTime(time: time);
+
+/// The time and assert flag of a `Validity` value read from a result.
+///
+/// Fails for other values, and for timestamps a `DateTime` cannot hold,
+/// such as the ones `'END'` stands for.
+ValidityTime  cozoValidityToTime({required CozoValue value }) => RustLib.instance.api.crateApiTemporalCozoValidityToTime(value: value);
+
+/// Run a read-only query against time travel relations as they were at
+/// `asof`, without writing the `@` clauses by hand.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Every stored relation atom of the script, `*relation{..}` or
+/// `*relation[..]`, that reads a relation with a `Validity` key column and
+/// has no `@` clause of its own gets `@ $cozo_asof`, bound to `asof` in
+/// microseconds. Other atoms are left as they are.
+///
+/// Returns the same JSON result as `cozo_run_query`.
+Future<String>  cozoQueryAsof({required CozoDb db , required String script , required String paramsJson , required DateTime asof }) => RustLib.instance.api.crateApiTemporalCozoQueryAsof(db: db, script: script, paramsJson: paramsJson, asof: asof);
+
+/// Write rows of a time travel relation, valid from `valid_from` and, if
+/// `valid_to` is given, until then.
+///
+/// - `relation`: a stored relation whose last key column is a `Validity`
+/// - `rows`: rows with one value per column except the `Validity` one, in
+///   the relation's column order (keys first)
+///
+/// Each row is asserted at `valid_from` and, with `valid_to`, retracted at
+/// `valid_to` with the same values, so queries at a time in between see it.
+/// Goes through the query engine, so triggers, callbacks and indices apply.
+/// All rows are written in one transaction.
+///
+/// Returns the number of rows written, counting each retraction.
+Future<BigInt>  cozoPutValid({required CozoDb db , required String relation , required List<List<CozoValue>> rows , required DateTime validFrom , DateTime? validTo }) => RustLib.instance.api.crateApiTemporalCozoPutValid(db: db, relation: relation, rows: rows, validFrom: validFrom, validTo: validTo);
+
+/// Retract rows of a time travel relation at `at`, so queries from then on
+/// no longer see them.
+///
+/// - `keys`: one entry per row, holding the key column values except the
+///   `Validity` one, in key order
+///
+/// A retraction keeps the values the row had at `at`; keys without a row
+/// valid at `at` are ignored. Goes through the query engine like
+/// [`cozo_put_valid`], in one transaction.
+///
+/// Returns the number of rows retracted.
+Future<BigInt>  cozoRetractRows({required CozoDb db , required String relation , required List<List<CozoValue>> keys , required DateTime at }) => RustLib.instance.api.crateApiTemporalCozoRetractRows(db: db, relation: relation, keys: keys, at: at);
+
+            /// A `Validity` value with its timestamp as a `DateTime`.
+class ValidityTime  {
+                /// Time from which the row is asserted or retracted.
+final DateTime validFrom;
+/// Whether the row is asserted, as opposed to retracted, from then on.
+final bool isAssert;
+
+                const ValidityTime({required this.validFrom ,required this.isAssert ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => validFrom.hashCode^isAssert.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ValidityTime &&
+                runtimeType == other.runtimeType
+                && validFrom == other.validFrom&& isAssert == other.isAssert;
+        
+            }
+            
\ No newline at end of file
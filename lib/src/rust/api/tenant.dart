@@ -0,0 +1,63 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `database_path`, `tenant_dir`, `valid_id`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OpenTenant`
+
+
+            /// Manage one isolated database per tenant under `root`, which is created
+/// if missing.
+///
+/// - `engine`: "sqlite" or "rocksdb"
+/// - `options`: JSON string of options for every tenant's database, as for
+///   `cozo_open_db`, without `column_key`
+///
+/// A tenant's database lives in `root/<tenant id>`, so tenants never share
+/// files, and deleting one removes everything it stored.
+TenantManager  cozoTenantManager({required String root , required String engine , required String options }) => RustLib.instance.api.crateApiTenantCozoTenantManager(root: root, engine: engine, options: options);
+
+/// Open the database of `tenant_id`, creating it on first use, or share it
+/// if the manager already has it open.
+///
+/// - `tenant_id`: letters, digits, `-`, `_` and `.`, not starting with `.`
+/// - `column_key`: the tenant's own key for `cozo_encrypt_column`, 64 hex
+///   digits, or `None` for a tenant without encrypted columns
+///
+/// Opening an open tenant with a different key fails.
+CozoDb  cozoOpenTenant({required TenantManager manager , required String tenantId , String? columnKey }) => RustLib.instance.api.crateApiTenantCozoOpenTenant(manager: manager, tenantId: tenantId, columnKey: columnKey);
+
+/// Close the database of `tenant_id`, and every handle the manager gave out
+/// for it, e.g. when the user signs out.
+///
+/// Returns false if the tenant was not open.
+bool  cozoCloseTenant({required TenantManager manager , required String tenantId }) => RustLib.instance.api.crateApiTenantCozoCloseTenant(manager: manager, tenantId: tenantId);
+
+/// Ids of the tenants with a database under the root, sorted.
+List<String>  cozoListTenants({required TenantManager manager }) => RustLib.instance.api.crateApiTenantCozoListTenants(manager: manager);
+
+/// Close the database of `tenant_id` and delete its directory with
+/// everything in it, e.g. when the account is removed from the device.
+///
+/// Handles the manager gave out for the tenant are closed first; a handle
+/// opened on the same files without the manager must be closed by the caller
+/// beforehand.
+///
+/// Returns false if the tenant had no database.
+bool  cozoDeleteTenant({required TenantManager manager , required String tenantId }) => RustLib.instance.api.crateApiTenantCozoDeleteTenant(manager: manager, tenantId: tenantId);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TenantManager>>
+                abstract class TenantManager implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+            
\ No newline at end of file
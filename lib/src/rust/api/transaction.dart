@@ -0,0 +1,140 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `finish_multi`, `finish`, `result_hash`, `run`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TxEntry`, `TxHistory`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `drop`, `eq`, `fmt`, `fmt`
+
+
+            /// Begin a multi-statement transaction.
+///
+/// - `write`: if true, the transaction may modify stored relations
+CozoTx  cozoBeginTx({required CozoDb db , required bool write }) => RustLib.instance.api.crateApiTransactionCozoBeginTx(db: db, write: write);
+
+/// Run a CozoScript query inside the transaction.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Returns a JSON string with the query result, or an error if the query
+/// fails. A failed query does not end the transaction.
+Future<String>  txRunQuery({required CozoTx tx , required String script , required String paramsJson }) => RustLib.instance.api.crateApiTransactionTxRunQuery(tx: tx, script: script, paramsJson: paramsJson);
+
+/// Commit the transaction. The handle cannot be used afterwards.
+Future<void>  txCommit({required CozoTx tx }) => RustLib.instance.api.crateApiTransactionTxCommit(tx: tx);
+
+/// Abort the transaction, discarding its writes. The handle cannot be used afterwards.
+Future<void>  txAbort({required CozoTx tx }) => RustLib.instance.api.crateApiTransactionTxAbort(tx: tx);
+
+/// Mark the current point of the transaction as `name`, to return to with
+/// [`tx_rollback_to`], e.g. before each step of a wizard or each chunk of an
+/// import.
+///
+/// Savepoints nest: a name used again marks a new point, and rolling back
+/// or releasing it goes to the newest one. The handle keeps every script it
+/// has run, with its parameters, until it is finished, to go back with.
+void  txSavepoint({required CozoTx tx , required String name }) => RustLib.instance.api.crateApiTransactionTxSavepoint(tx: tx, name: name);
+
+/// Undo what the transaction wrote since the savepoint `name`, keeping the
+/// savepoint and everything before it. Savepoints set after it are removed.
+///
+/// Cozo transactions cannot undo part of their writes, so this aborts the
+/// storage transaction and runs the scripts from before the savepoint again
+/// in a new one. Savepoints are therefore not isolated: the new transaction
+/// sees what other writers committed in the meantime, and takes as long as
+/// running the scripts did.
+///
+/// - If a script from before the savepoint calls a function whose result
+///   changes on each run, such as `now()`, `rand_uuid_v4()` or the other
+///   `rand_` functions, or uses the `'NOW'` or `'ASSERT'` validity, the
+///   rollback is refused and the transaction is left as it was. The check
+///   looks at the script text, so a string literal containing one counts
+///   too; abort the transaction instead in that case.
+/// - If a script returns something else than it did the first time, e.g.
+///   because another writer committed rows it reads, or if it fails, the
+///   transaction is aborted: the error says so, the handle can no longer be
+///   used, as after [`tx_abort`], and it is no longer listed by
+///   [`cozo_list_transactions`].
+Future<void>  txRollbackTo({required CozoTx tx , required String name }) => RustLib.instance.api.crateApiTransactionTxRollbackTo(tx: tx, name: name);
+
+/// Forget the savepoint `name` and the savepoints set after it, keeping
+/// what was written since.
+void  txRelease({required CozoTx tx , required String name }) => RustLib.instance.api.crateApiTransactionTxRelease(tx: tx, name: name);
+
+/// The transactions open on the database, oldest first, e.g. to find a
+/// write transaction a forgotten `CozoTx` keeps open, which makes every
+/// other write wait.
+///
+/// Lists those begun with `cozo_begin_tx` through this handle or the
+/// handles sharing its database that are neither committed, aborted nor
+/// dropped.
+List<TransactionInfo>  cozoListTransactions({required CozoDb db }) => RustLib.instance.api.crateApiTransactionCozoListTransactions(db: db);
+
+/// Abort the open transaction `id` from [`cozo_list_transactions`],
+/// discarding its writes. Later calls on its `CozoTx` fail as for a
+/// finished transaction.
+///
+/// Waits for a script running in it to finish first. Returns false if no
+/// such transaction is open.
+Future<bool>  cozoAbortTransaction({required CozoDb db , required BigInt id }) => RustLib.instance.api.crateApiTransactionCozoAbortTransaction(db: db, id: id);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CozoTx>>
+                abstract class CozoTx implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// An open transaction, see [`cozo_list_transactions`].
+class TransactionInfo  {
+                /// Id to pass to [`cozo_abort_transaction`].
+final BigInt id;
+final TxMode mode;
+/// Seconds since the transaction began.
+final double ageSecs;
+/// Seconds since a script last ran in it, or since it began.
+final double idleSecs;
+/// Scripts run in it so far.
+final int scriptsRun;
+/// Scripts run that appear to write: those whose text contains a
+/// mutation option such as `:put`, `:rm` or `:create`. An
+/// approximation, since a string literal containing one counts too, and
+/// not a row count: cozo does not report how many rows a script writes.
+final int writeScripts;
+
+                const TransactionInfo({required this.id ,required this.mode ,required this.ageSecs ,required this.idleSecs ,required this.scriptsRun ,required this.writeScripts ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^mode.hashCode^ageSecs.hashCode^idleSecs.hashCode^scriptsRun.hashCode^writeScripts.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TransactionInfo &&
+                runtimeType == other.runtimeType
+                && id == other.id&& mode == other.mode&& ageSecs == other.ageSecs&& idleSecs == other.idleSecs&& scriptsRun == other.scriptsRun&& writeScripts == other.writeScripts;
+        
+            }
+
+/// Whether a transaction may write, see [`cozo_begin_tx`].
+enum TxMode {
+                    read,
+write,
+                    ;
+                    
+                }
+            
\ No newline at end of file
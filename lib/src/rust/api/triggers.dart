@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Set the triggers of a stored relation, replacing all existing ones.
+Future<void>  cozoSetTriggers({required CozoDb db , required String relation , required TriggerSpec spec }) => RustLib.instance.api.crateApiTriggersCozoSetTriggers(db: db, relation: relation, spec: spec);
+
+/// List the triggers of a stored relation.
+Future<TriggerSpec>  cozoListTriggers({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiTriggersCozoListTriggers(db: db, relation: relation);
+
+/// Remove every trigger of a stored relation.
+Future<void>  cozoClearTriggers({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiTriggersCozoClearTriggers(db: db, relation: relation);
+
+            /// The triggers of a stored relation.
+///
+/// Each trigger is a query script, without surrounding braces, run in the
+/// same transaction as the write that fired it. `_new[...]` holds the
+/// written rows and `_old[...]` the rows they replaced or removed, both with
+/// the relation's columns. Triggers of one kind run in order.
+class TriggerSpec  {
+                /// Run after rows are put.
+final List<String> onPut;
+/// Run after rows are removed.
+final List<String> onRm;
+/// Run after the relation is replaced with `:replace`.
+final List<String> onReplace;
+
+                const TriggerSpec({required this.onPut ,required this.onRm ,required this.onReplace ,});
+
+                static Future<TriggerSpec>  default_()=>RustLib.instance.api.crateApiTriggersTriggerSpecDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => onPut.hashCode^onRm.hashCode^onReplace.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TriggerSpec &&
+                runtimeType == other.runtimeType
+                && onPut == other.onPut&& onRm == other.onRm&& onReplace == other.onReplace;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `ensure_settings`, `purge_expired`, `settings_exist`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Make rows of `relation` expire `ttl_secs` seconds after the time in
+/// `ttl_column`, e.g. for caches, sessions and log tables.
+///
+/// - `ttl_column`: an `Int` or `Float` column holding Unix time in seconds,
+///   typically declared with `default now()`
+///
+/// Expired rows are deleted by [`cozo_purge_expired`], so they stay visible
+/// to queries until it runs. The setting is stored in the `cozo_ttl`
+/// relation and survives restarts; setting it again replaces it.
+Future<void>  cozoSetTtl({required CozoDb db , required String relation , required String ttlColumn , required double ttlSecs }) => RustLib.instance.api.crateApiTtlCozoSetTtl(db: db, relation: relation, ttlColumn: ttlColumn, ttlSecs: ttlSecs);
+
+/// Stop rows of `relation` from expiring. Its rows are kept.
+Future<void>  cozoClearTtl({required CozoDb db , required String relation }) => RustLib.instance.api.crateApiTtlCozoClearTtl(db: db, relation: relation);
+
+/// The expiry settings of all relations.
+Future<List<TtlSetting>>  cozoListTtl({required CozoDb db }) => RustLib.instance.api.crateApiTtlCozoListTtl(db: db);
+
+/// Delete the expired rows of every relation with a TTL, e.g. on app start
+/// and then periodically.
+///
+/// Rows are removed with `:rm`, so triggers and change callbacks see them,
+/// one transaction per relation. Settings of relations that no longer exist
+/// are skipped.
+///
+/// Returns the number of rows deleted.
+Future<BigInt>  cozoPurgeExpired({required CozoDb db }) => RustLib.instance.api.crateApiTtlCozoPurgeExpired(db: db);
+
+            /// Expiry setting of a relation, see [`cozo_set_ttl`].
+class TtlSetting  {
+                final String relation;
+/// Column holding the time each row was written or last refreshed.
+final String column;
+/// Seconds a row lives after that time.
+final double ttlSecs;
+
+                const TtlSetting({required this.relation ,required this.column ,required this.ttlSecs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^column.hashCode^ttlSecs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TtlSetting &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& column == other.column&& ttlSecs == other.ttlSecs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,141 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `checkable`, `position`, `push_diagnostics`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Parse and compile a script without running it, for query editors that
+/// validate as the user types.
+///
+/// Compiling checks the syntax, that the stored relations read exist and
+/// that rules and relations are used with the right arity and columns.
+/// Nothing is read from or written to the database.
+ValidationResult  cozoValidateScript({required CozoDb db , required String script }) => RustLib.instance.api.crateApiValidateCozoValidateScript(db: db, script: script);
+
+/// Parse and compile a script without running it, and report what is wrong
+/// with it as positioned diagnostics.
+///
+/// Checks the same things as `cozo_validate_script`, and likewise returns
+/// nothing for system ops and imperative scripts. An error pointing at
+/// several parts of the script gives one diagnostic per part; an error
+/// pointing at none is placed at the start of the script.
+///
+/// Returns the diagnostics, empty if the script compiles.
+List<ScriptDiagnostic>  cozoDiagnoseScript({required CozoDb db , required String script }) => RustLib.instance.api.crateApiValidateCozoDiagnoseScript(db: db, script: script);
+
+            /// How serious a [`ScriptDiagnostic`] is.
+enum DiagnosticSeverity {
+                    error,
+warning,
+advice,
+                    ;
+                    
+                }
+
+/// A problem found in a script, positioned for underlining in an editor.
+class ScriptDiagnostic  {
+                /// What is wrong, without the rendered source snippet.
+final String message;
+final DiagnosticSeverity severity;
+/// cozo's diagnostic code, e.g. `parser::pest`.
+final String? code;
+/// Start of the part the diagnostic points at.
+final TextPosition start;
+/// End of that part, exclusive. Equal to `start` where cozo points at a
+/// position rather than a range, e.g. an unexpected end of input.
+final TextPosition end;
+/// cozo's note on the pointed-at part, when it has one.
+final String? label;
+/// Suggestion on how to fix it, when cozo has one.
+final String? hint;
+
+                const ScriptDiagnostic({required this.message ,required this.severity ,this.code ,required this.start ,required this.end ,this.label ,this.hint ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => message.hashCode^severity.hashCode^code.hashCode^start.hashCode^end.hashCode^label.hashCode^hint.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScriptDiagnostic &&
+                runtimeType == other.runtimeType
+                && message == other.message&& severity == other.severity&& code == other.code&& start == other.start&& end == other.end&& label == other.label&& hint == other.hint;
+        
+            }
+
+/// A position in a script.
+class TextPosition  {
+                /// Byte offset into the UTF-8 script.
+final int offset;
+/// Zero-based line.
+final int line;
+/// Zero-based column in UTF-16 code units, the unit Dart strings and
+/// text fields index by.
+final int column;
+
+                const TextPosition({required this.offset ,required this.line ,required this.column ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => offset.hashCode^line.hashCode^column.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextPosition &&
+                runtimeType == other.runtimeType
+                && offset == other.offset&& line == other.line&& column == other.column;
+        
+            }
+
+/// Outcome of [`cozo_validate_script`].
+class ValidationResult  {
+                /// False if the script failed to parse or compile.
+final bool valid;
+/// Whether the script could be checked at all. System ops (`::...`) and
+/// imperative scripts (`{...}` blocks) cannot be compiled without
+/// running them, so they are reported valid but unchecked.
+final bool checked;
+/// The parse or compile error, with its span relative to the script.
+final CozoDartError? error;
+
+                const ValidationResult({required this.valid ,required this.checked ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => valid.hashCode^checked.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ValidationResult &&
+                runtimeType == other.runtimeType
+                && valid == other.valid&& checked == other.checked&& error == other.error;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'value.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `from_error`, `from_rows`, `literal`, `params_from_values`, `string_literal`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`, `from`, `try_from`
+
+
+            
+
+            @freezed
+                sealed class CozoValue with _$CozoValue  {
+                    const CozoValue._();
+
+                     const factory CozoValue.null_() = CozoValue_Null;
+ const factory CozoValue.bool(  bool field0,) = CozoValue_Bool;
+ const factory CozoValue.int(  PlatformInt64 field0,) = CozoValue_Int;
+ const factory CozoValue.float(  double field0,) = CozoValue_Float;
+ const factory CozoValue.str(  String field0,) = CozoValue_Str;
+ /// Binary blob, a `Uint8List` in Dart. Unlike the JSON API, bytes are
+/// passed as-is rather than base64 encoded, in both parameters and rows.
+const factory CozoValue.bytes(  Uint8List field0,) = CozoValue_Bytes;
+ const factory CozoValue.uuid(  String field0,) = CozoValue_Uuid;
+ const factory CozoValue.list(  List<CozoValue> field0,) = CozoValue_List;
+ /// `<F32; N>` vector, a `Float32List` in Dart.
+const factory CozoValue.f32Vector(  Float32List field0,) = CozoValue_F32Vector;
+ /// `<F64; N>` vector, a `Float64List` in Dart.
+const factory CozoValue.f64Vector(  Float64List field0,) = CozoValue_F64Vector;
+ /// JSON value, encoded as a JSON string.
+const factory CozoValue.json(  String field0,) = CozoValue_Json;
+ /// Validity for time travel: microsecond timestamp and assert/retract flag.
+const factory CozoValue.validity({   required PlatformInt64 timestamp ,  required bool isAssert , }) = CozoValue_Validity;
+
+                    
+
+                    
+                }
+
+/// Typed result of a CozoScript query, mirroring cozo's `NamedRows`.
+///
+/// Prefer this over the JSON result for large text or blob columns. Values
+/// are moved out of cozo's rows, so `Bytes` buffers are handed to the bridge
+/// as they are, and the bridge copies each string or blob once into its
+/// message; Dart reads bytes as a view into that message. The JSON path
+/// instead builds a JSON tree, base64-encodes bytes, renders the tree to a
+/// string and parses it again in Dart.
+class QueryResult  {
+                /// Whether the query succeeded.
+final bool ok;
+/// Column headers.
+final List<String> headers;
+/// Rows of typed values, one entry per header.
+final List<List<CozoValue>> rows;
+/// Time taken by the query in seconds.
+final double took;
+/// Human-readable error message when `ok` is false.
+final String? error;
+
+                const QueryResult({required this.ok ,required this.headers ,required this.rows ,required this.took ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ok.hashCode^headers.hashCode^rows.hashCode^took.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryResult &&
+                runtimeType == other.runtimeType
+                && ok == other.ok&& headers == other.headers&& rows == other.rows&& took == other.took&& error == other.error;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,2559 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'value.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$CozoValue {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $CozoValueCopyWith<$Res> {
+  factory $CozoValueCopyWith(CozoValue value, $Res Function(CozoValue) then) =
+      _$CozoValueCopyWithImpl<$Res, CozoValue>;
+}
+
+/// @nodoc
+class _$CozoValueCopyWithImpl<$Res, $Val extends CozoValue>
+    implements $CozoValueCopyWith<$Res> {
+  _$CozoValueCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$CozoValue_NullImplCopyWith<$Res> {
+  factory _$$CozoValue_NullImplCopyWith(
+          _$CozoValue_NullImpl value, $Res Function(_$CozoValue_NullImpl) then) =
+      __$$CozoValue_NullImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CozoValue_NullImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_NullImpl>
+    implements _$$CozoValue_NullImplCopyWith<$Res> {
+  __$$CozoValue_NullImplCopyWithImpl(
+      _$CozoValue_NullImpl _value, $Res Function(_$CozoValue_NullImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CozoValue_NullImpl extends CozoValue_Null {
+  const _$CozoValue_NullImpl()
+      : super._();
+
+  @override
+  String toString() {
+    return 'CozoValue.null_()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_NullImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return null_();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return null_?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (null_ != null) {
+      return null_();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return null_(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return null_?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (null_ != null) {
+      return null_(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Null extends CozoValue {
+  const factory CozoValue_Null() = _$CozoValue_NullImpl;
+  const CozoValue_Null._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CozoValue_BoolImplCopyWith<$Res> {
+  factory _$$CozoValue_BoolImplCopyWith(
+          _$CozoValue_BoolImpl value, $Res Function(_$CozoValue_BoolImpl) then) =
+      __$$CozoValue_BoolImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool field0});
+}
+
+/// @nodoc
+class __$$CozoValue_BoolImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_BoolImpl>
+    implements _$$CozoValue_BoolImplCopyWith<$Res> {
+  __$$CozoValue_BoolImplCopyWithImpl(
+      _$CozoValue_BoolImpl _value, $Res Function(_$CozoValue_BoolImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_BoolImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_BoolImpl extends CozoValue_Bool {
+  const _$CozoValue_BoolImpl(this.field0)
+      : super._();
+
+  @override
+  final bool field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.bool(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_BoolImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_BoolImplCopyWith<_$CozoValue_BoolImpl> get copyWith =>
+      __$$CozoValue_BoolImplCopyWithImpl<_$CozoValue_BoolImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return bool(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return bool?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return bool(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return bool?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Bool extends CozoValue {
+  const factory CozoValue_Bool(final bool field0) = _$CozoValue_BoolImpl;
+  const CozoValue_Bool._() : super._();
+
+  bool get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_BoolImplCopyWith<_$CozoValue_BoolImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_IntImplCopyWith<$Res> {
+  factory _$$CozoValue_IntImplCopyWith(
+          _$CozoValue_IntImpl value, $Res Function(_$CozoValue_IntImpl) then) =
+      __$$CozoValue_IntImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PlatformInt64 field0});
+}
+
+/// @nodoc
+class __$$CozoValue_IntImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_IntImpl>
+    implements _$$CozoValue_IntImplCopyWith<$Res> {
+  __$$CozoValue_IntImplCopyWithImpl(
+      _$CozoValue_IntImpl _value, $Res Function(_$CozoValue_IntImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_IntImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as PlatformInt64,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_IntImpl extends CozoValue_Int {
+  const _$CozoValue_IntImpl(this.field0)
+      : super._();
+
+  @override
+  final PlatformInt64 field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.int(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_IntImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_IntImplCopyWith<_$CozoValue_IntImpl> get copyWith =>
+      __$$CozoValue_IntImplCopyWithImpl<_$CozoValue_IntImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return int(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return int?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (int != null) {
+      return int(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return int(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return int?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (int != null) {
+      return int(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Int extends CozoValue {
+  const factory CozoValue_Int(final PlatformInt64 field0) = _$CozoValue_IntImpl;
+  const CozoValue_Int._() : super._();
+
+  PlatformInt64 get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_IntImplCopyWith<_$CozoValue_IntImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_FloatImplCopyWith<$Res> {
+  factory _$$CozoValue_FloatImplCopyWith(
+          _$CozoValue_FloatImpl value, $Res Function(_$CozoValue_FloatImpl) then) =
+      __$$CozoValue_FloatImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({double field0});
+}
+
+/// @nodoc
+class __$$CozoValue_FloatImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_FloatImpl>
+    implements _$$CozoValue_FloatImplCopyWith<$Res> {
+  __$$CozoValue_FloatImplCopyWithImpl(
+      _$CozoValue_FloatImpl _value, $Res Function(_$CozoValue_FloatImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_FloatImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as double,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_FloatImpl extends CozoValue_Float {
+  const _$CozoValue_FloatImpl(this.field0)
+      : super._();
+
+  @override
+  final double field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.float(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_FloatImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_FloatImplCopyWith<_$CozoValue_FloatImpl> get copyWith =>
+      __$$CozoValue_FloatImplCopyWithImpl<_$CozoValue_FloatImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return float(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return float?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (float != null) {
+      return float(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return float(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return float?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (float != null) {
+      return float(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Float extends CozoValue {
+  const factory CozoValue_Float(final double field0) = _$CozoValue_FloatImpl;
+  const CozoValue_Float._() : super._();
+
+  double get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_FloatImplCopyWith<_$CozoValue_FloatImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_StrImplCopyWith<$Res> {
+  factory _$$CozoValue_StrImplCopyWith(
+          _$CozoValue_StrImpl value, $Res Function(_$CozoValue_StrImpl) then) =
+      __$$CozoValue_StrImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$CozoValue_StrImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_StrImpl>
+    implements _$$CozoValue_StrImplCopyWith<$Res> {
+  __$$CozoValue_StrImplCopyWithImpl(
+      _$CozoValue_StrImpl _value, $Res Function(_$CozoValue_StrImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_StrImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_StrImpl extends CozoValue_Str {
+  const _$CozoValue_StrImpl(this.field0)
+      : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.str(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_StrImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_StrImplCopyWith<_$CozoValue_StrImpl> get copyWith =>
+      __$$CozoValue_StrImplCopyWithImpl<_$CozoValue_StrImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return str(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return str?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (str != null) {
+      return str(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return str(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return str?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (str != null) {
+      return str(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Str extends CozoValue {
+  const factory CozoValue_Str(final String field0) = _$CozoValue_StrImpl;
+  const CozoValue_Str._() : super._();
+
+  String get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_StrImplCopyWith<_$CozoValue_StrImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_BytesImplCopyWith<$Res> {
+  factory _$$CozoValue_BytesImplCopyWith(
+          _$CozoValue_BytesImpl value, $Res Function(_$CozoValue_BytesImpl) then) =
+      __$$CozoValue_BytesImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List field0});
+}
+
+/// @nodoc
+class __$$CozoValue_BytesImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_BytesImpl>
+    implements _$$CozoValue_BytesImplCopyWith<$Res> {
+  __$$CozoValue_BytesImplCopyWithImpl(
+      _$CozoValue_BytesImpl _value, $Res Function(_$CozoValue_BytesImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_BytesImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_BytesImpl extends CozoValue_Bytes {
+  const _$CozoValue_BytesImpl(this.field0)
+      : super._();
+
+  @override
+  final Uint8List field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.bytes(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_BytesImpl &&
+            const DeepCollectionEquality().equals(other.field0, field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(field0));
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_BytesImplCopyWith<_$CozoValue_BytesImpl> get copyWith =>
+      __$$CozoValue_BytesImplCopyWithImpl<_$CozoValue_BytesImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return bytes(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return bytes?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (bytes != null) {
+      return bytes(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return bytes(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return bytes?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (bytes != null) {
+      return bytes(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Bytes extends CozoValue {
+  const factory CozoValue_Bytes(final Uint8List field0) = _$CozoValue_BytesImpl;
+  const CozoValue_Bytes._() : super._();
+
+  Uint8List get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_BytesImplCopyWith<_$CozoValue_BytesImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_UuidImplCopyWith<$Res> {
+  factory _$$CozoValue_UuidImplCopyWith(
+          _$CozoValue_UuidImpl value, $Res Function(_$CozoValue_UuidImpl) then) =
+      __$$CozoValue_UuidImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$CozoValue_UuidImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_UuidImpl>
+    implements _$$CozoValue_UuidImplCopyWith<$Res> {
+  __$$CozoValue_UuidImplCopyWithImpl(
+      _$CozoValue_UuidImpl _value, $Res Function(_$CozoValue_UuidImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_UuidImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_UuidImpl extends CozoValue_Uuid {
+  const _$CozoValue_UuidImpl(this.field0)
+      : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.uuid(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_UuidImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_UuidImplCopyWith<_$CozoValue_UuidImpl> get copyWith =>
+      __$$CozoValue_UuidImplCopyWithImpl<_$CozoValue_UuidImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return uuid(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return uuid?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (uuid != null) {
+      return uuid(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return uuid(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return uuid?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (uuid != null) {
+      return uuid(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Uuid extends CozoValue {
+  const factory CozoValue_Uuid(final String field0) = _$CozoValue_UuidImpl;
+  const CozoValue_Uuid._() : super._();
+
+  String get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_UuidImplCopyWith<_$CozoValue_UuidImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_ListImplCopyWith<$Res> {
+  factory _$$CozoValue_ListImplCopyWith(
+          _$CozoValue_ListImpl value, $Res Function(_$CozoValue_ListImpl) then) =
+      __$$CozoValue_ListImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<CozoValue> field0});
+}
+
+/// @nodoc
+class __$$CozoValue_ListImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_ListImpl>
+    implements _$$CozoValue_ListImplCopyWith<$Res> {
+  __$$CozoValue_ListImplCopyWithImpl(
+      _$CozoValue_ListImpl _value, $Res Function(_$CozoValue_ListImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_ListImpl(
+      null == field0
+          ? _value._field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as List<CozoValue>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_ListImpl extends CozoValue_List {
+  const _$CozoValue_ListImpl(final List<CozoValue> field0)
+      : _field0 = field0,
+        super._();
+
+  final List<CozoValue> _field0;
+  @override
+  List<CozoValue> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'CozoValue.list(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_ListImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_ListImplCopyWith<_$CozoValue_ListImpl> get copyWith =>
+      __$$CozoValue_ListImplCopyWithImpl<_$CozoValue_ListImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return list(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return list?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (list != null) {
+      return list(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return list(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return list?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (list != null) {
+      return list(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_List extends CozoValue {
+  const factory CozoValue_List(final List<CozoValue> field0) = _$CozoValue_ListImpl;
+  const CozoValue_List._() : super._();
+
+  List<CozoValue> get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_ListImplCopyWith<_$CozoValue_ListImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_F32VectorImplCopyWith<$Res> {
+  factory _$$CozoValue_F32VectorImplCopyWith(
+          _$CozoValue_F32VectorImpl value, $Res Function(_$CozoValue_F32VectorImpl) then) =
+      __$$CozoValue_F32VectorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Float32List field0});
+}
+
+/// @nodoc
+class __$$CozoValue_F32VectorImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_F32VectorImpl>
+    implements _$$CozoValue_F32VectorImplCopyWith<$Res> {
+  __$$CozoValue_F32VectorImplCopyWithImpl(
+      _$CozoValue_F32VectorImpl _value, $Res Function(_$CozoValue_F32VectorImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_F32VectorImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as Float32List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_F32VectorImpl extends CozoValue_F32Vector {
+  const _$CozoValue_F32VectorImpl(this.field0)
+      : super._();
+
+  @override
+  final Float32List field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.f32Vector(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_F32VectorImpl &&
+            const DeepCollectionEquality().equals(other.field0, field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(field0));
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_F32VectorImplCopyWith<_$CozoValue_F32VectorImpl> get copyWith =>
+      __$$CozoValue_F32VectorImplCopyWithImpl<_$CozoValue_F32VectorImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return f32Vector(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return f32Vector?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (f32Vector != null) {
+      return f32Vector(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return f32Vector(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return f32Vector?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (f32Vector != null) {
+      return f32Vector(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_F32Vector extends CozoValue {
+  const factory CozoValue_F32Vector(final Float32List field0) = _$CozoValue_F32VectorImpl;
+  const CozoValue_F32Vector._() : super._();
+
+  Float32List get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_F32VectorImplCopyWith<_$CozoValue_F32VectorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_F64VectorImplCopyWith<$Res> {
+  factory _$$CozoValue_F64VectorImplCopyWith(
+          _$CozoValue_F64VectorImpl value, $Res Function(_$CozoValue_F64VectorImpl) then) =
+      __$$CozoValue_F64VectorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Float64List field0});
+}
+
+/// @nodoc
+class __$$CozoValue_F64VectorImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_F64VectorImpl>
+    implements _$$CozoValue_F64VectorImplCopyWith<$Res> {
+  __$$CozoValue_F64VectorImplCopyWithImpl(
+      _$CozoValue_F64VectorImpl _value, $Res Function(_$CozoValue_F64VectorImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_F64VectorImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as Float64List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_F64VectorImpl extends CozoValue_F64Vector {
+  const _$CozoValue_F64VectorImpl(this.field0)
+      : super._();
+
+  @override
+  final Float64List field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.f64Vector(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_F64VectorImpl &&
+            const DeepCollectionEquality().equals(other.field0, field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(field0));
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_F64VectorImplCopyWith<_$CozoValue_F64VectorImpl> get copyWith =>
+      __$$CozoValue_F64VectorImplCopyWithImpl<_$CozoValue_F64VectorImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return f64Vector(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return f64Vector?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (f64Vector != null) {
+      return f64Vector(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return f64Vector(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return f64Vector?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (f64Vector != null) {
+      return f64Vector(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_F64Vector extends CozoValue {
+  const factory CozoValue_F64Vector(final Float64List field0) = _$CozoValue_F64VectorImpl;
+  const CozoValue_F64Vector._() : super._();
+
+  Float64List get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_F64VectorImplCopyWith<_$CozoValue_F64VectorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_JsonImplCopyWith<$Res> {
+  factory _$$CozoValue_JsonImplCopyWith(
+          _$CozoValue_JsonImpl value, $Res Function(_$CozoValue_JsonImpl) then) =
+      __$$CozoValue_JsonImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$CozoValue_JsonImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_JsonImpl>
+    implements _$$CozoValue_JsonImplCopyWith<$Res> {
+  __$$CozoValue_JsonImplCopyWithImpl(
+      _$CozoValue_JsonImpl _value, $Res Function(_$CozoValue_JsonImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$CozoValue_JsonImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_JsonImpl extends CozoValue_Json {
+  const _$CozoValue_JsonImpl(this.field0)
+      : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'CozoValue.json(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_JsonImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, field0);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_JsonImplCopyWith<_$CozoValue_JsonImpl> get copyWith =>
+      __$$CozoValue_JsonImplCopyWithImpl<_$CozoValue_JsonImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return json(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return json?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (json != null) {
+      return json(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return json(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return json?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (json != null) {
+      return json(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Json extends CozoValue {
+  const factory CozoValue_Json(final String field0) = _$CozoValue_JsonImpl;
+  const CozoValue_Json._() : super._();
+
+  String get field0;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_JsonImplCopyWith<_$CozoValue_JsonImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CozoValue_ValidityImplCopyWith<$Res> {
+  factory _$$CozoValue_ValidityImplCopyWith(
+          _$CozoValue_ValidityImpl value, $Res Function(_$CozoValue_ValidityImpl) then) =
+      __$$CozoValue_ValidityImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PlatformInt64 timestamp, bool isAssert});
+}
+
+/// @nodoc
+class __$$CozoValue_ValidityImplCopyWithImpl<$Res>
+    extends _$CozoValueCopyWithImpl<$Res, _$CozoValue_ValidityImpl>
+    implements _$$CozoValue_ValidityImplCopyWith<$Res> {
+  __$$CozoValue_ValidityImplCopyWithImpl(
+      _$CozoValue_ValidityImpl _value, $Res Function(_$CozoValue_ValidityImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? timestamp = null,
+    Object? isAssert = null,
+  }) {
+    return _then(_$CozoValue_ValidityImpl(
+      timestamp: null == timestamp
+          ? _value.timestamp
+          : timestamp // ignore: cast_nullable_to_non_nullable
+              as PlatformInt64,
+      isAssert: null == isAssert
+          ? _value.isAssert
+          : isAssert // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CozoValue_ValidityImpl extends CozoValue_Validity {
+  const _$CozoValue_ValidityImpl({required this.timestamp, required this.isAssert})
+      : super._();
+
+  @override
+  final PlatformInt64 timestamp;
+
+  @override
+  final bool isAssert;
+
+  @override
+  String toString() {
+    return 'CozoValue.validity(timestamp: $timestamp, isAssert: $isAssert)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CozoValue_ValidityImpl &&
+            (identical(other.timestamp, timestamp) || other.timestamp == timestamp) &&
+            (identical(other.isAssert, isAssert) || other.isAssert == isAssert));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, timestamp, isAssert);
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CozoValue_ValidityImplCopyWith<_$CozoValue_ValidityImpl> get copyWith =>
+      __$$CozoValue_ValidityImplCopyWithImpl<_$CozoValue_ValidityImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) int,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) str,
+    required TResult Function(Uint8List field0) bytes,
+    required TResult Function(String field0) uuid,
+    required TResult Function(List<CozoValue> field0) list,
+    required TResult Function(Float32List field0) f32Vector,
+    required TResult Function(Float64List field0) f64Vector,
+    required TResult Function(String field0) json,
+    required TResult Function(PlatformInt64 timestamp, bool isAssert) validity,
+  }) {
+    return validity(timestamp, isAssert);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? int,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? str,
+    TResult? Function(Uint8List field0)? bytes,
+    TResult? Function(String field0)? uuid,
+    TResult? Function(List<CozoValue> field0)? list,
+    TResult? Function(Float32List field0)? f32Vector,
+    TResult? Function(Float64List field0)? f64Vector,
+    TResult? Function(String field0)? json,
+    TResult? Function(PlatformInt64 timestamp, bool isAssert)? validity,
+  }) {
+    return validity?.call(timestamp, isAssert);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? int,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? str,
+    TResult Function(Uint8List field0)? bytes,
+    TResult Function(String field0)? uuid,
+    TResult Function(List<CozoValue> field0)? list,
+    TResult Function(Float32List field0)? f32Vector,
+    TResult Function(Float64List field0)? f64Vector,
+    TResult Function(String field0)? json,
+    TResult Function(PlatformInt64 timestamp, bool isAssert)? validity,
+    required TResult orElse(),
+  }) {
+    if (validity != null) {
+      return validity(timestamp, isAssert);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CozoValue_Null value) null_,
+    required TResult Function(CozoValue_Bool value) bool,
+    required TResult Function(CozoValue_Int value) int,
+    required TResult Function(CozoValue_Float value) float,
+    required TResult Function(CozoValue_Str value) str,
+    required TResult Function(CozoValue_Bytes value) bytes,
+    required TResult Function(CozoValue_Uuid value) uuid,
+    required TResult Function(CozoValue_List value) list,
+    required TResult Function(CozoValue_F32Vector value) f32Vector,
+    required TResult Function(CozoValue_F64Vector value) f64Vector,
+    required TResult Function(CozoValue_Json value) json,
+    required TResult Function(CozoValue_Validity value) validity,
+  }) {
+    return validity(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CozoValue_Null value)? null_,
+    TResult? Function(CozoValue_Bool value)? bool,
+    TResult? Function(CozoValue_Int value)? int,
+    TResult? Function(CozoValue_Float value)? float,
+    TResult? Function(CozoValue_Str value)? str,
+    TResult? Function(CozoValue_Bytes value)? bytes,
+    TResult? Function(CozoValue_Uuid value)? uuid,
+    TResult? Function(CozoValue_List value)? list,
+    TResult? Function(CozoValue_F32Vector value)? f32Vector,
+    TResult? Function(CozoValue_F64Vector value)? f64Vector,
+    TResult? Function(CozoValue_Json value)? json,
+    TResult? Function(CozoValue_Validity value)? validity,
+  }) {
+    return validity?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CozoValue_Null value)? null_,
+    TResult Function(CozoValue_Bool value)? bool,
+    TResult Function(CozoValue_Int value)? int,
+    TResult Function(CozoValue_Float value)? float,
+    TResult Function(CozoValue_Str value)? str,
+    TResult Function(CozoValue_Bytes value)? bytes,
+    TResult Function(CozoValue_Uuid value)? uuid,
+    TResult Function(CozoValue_List value)? list,
+    TResult Function(CozoValue_F32Vector value)? f32Vector,
+    TResult Function(CozoValue_F64Vector value)? f64Vector,
+    TResult Function(CozoValue_Json value)? json,
+    TResult Function(CozoValue_Validity value)? validity,
+    required TResult orElse(),
+  }) {
+    if (validity != null) {
+      return validity(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CozoValue_Validity extends CozoValue {
+  const factory CozoValue_Validity({required final PlatformInt64 timestamp, required final bool isAssert}) = _$CozoValue_ValidityImpl;
+  const CozoValue_Validity._() : super._();
+
+  PlatformInt64 get timestamp;
+  bool get isAssert;
+
+  /// Create a copy of CozoValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CozoValue_ValidityImplCopyWith<_$CozoValue_ValidityImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
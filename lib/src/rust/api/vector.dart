@@ -0,0 +1,216 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'value.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `embedding_row`, `run_write_op`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Create an HNSW index for nearest-neighbour search over vector columns.
+///
+/// - `relation`: the stored relation holding the vectors
+/// - `name`: index name, used as `~relation:name{...}` in queries
+///
+/// Existing rows are indexed before this returns, and later writes keep the
+/// index up to date.
+Future<void>  cozoCreateHnswIndex({required CozoDb db , required String relation , required String name , required HnswConfig config }) => RustLib.instance.api.crateApiVectorCozoCreateHnswIndex(db: db, relation: relation, name: name, config: config);
+
+/// Drop an index of a stored relation. Works for every kind of index.
+Future<void>  cozoDropIndex({required CozoDb db , required String relation , required String name }) => RustLib.instance.api.crateApiVectorCozoDropIndex(db: db, relation: relation, name: name);
+
+/// List HNSW indices.
+///
+/// - `relation`: only list indices of this stored relation; `None` lists
+///   those of every relation
+Future<List<VectorIndexInfo>>  cozoListVectorIndices({required CozoDb db , String? relation }) => RustLib.instance.api.crateApiVectorCozoListVectorIndices(db: db, relation: relation);
+
+/// Write many vectors into one column of a stored relation, in a single
+/// transaction, reporting progress through a stream.
+///
+/// - `column`: the vector column, of type `<F32; dim>` or `<F64; dim>`
+///
+/// Vectors are passed as binary vectors rather than script text, so no
+/// precision is lost in formatting. They are written in chunks of 1000
+/// through the query engine, so HNSW indices, triggers and change callbacks
+/// see them. If `column` is the only non-key column, rows are created or
+/// replaced; otherwise only the vector of existing rows is updated and a
+/// missing key fails the call. Nothing is written unless every chunk
+/// succeeds.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "written": n, "total": n, "elapsed": secs}`,
+/// with a `progress` event after each chunk. The stream closes after the
+/// `done` event, sent once the transaction is committed, or fails with the error.
+Stream<String>  cozoPutEmbeddings({required CozoDb db , required String relation , required String column , required List<Embedding> embeddings }) => RustLib.instance.api.crateApiVectorCozoPutEmbeddings(db: db, relation: relation, column: column, embeddings: embeddings);
+
+/// Find the rows whose vectors are nearest to `query` with an HNSW index.
+///
+/// - `relation`, `index`: the indexed relation and the index name
+/// - `query`: the query vector; converted to `F64` for `F64` indices
+/// - `k`: maximum number of hits
+/// - `ef`: candidates considered while searching; higher is more accurate
+///   and slower, and should be at least `k`
+/// - `filter`: CozoScript expression over the relation's columns; only rows
+///   for which it is true are returned
+///
+/// Returns the hits, nearest first. Rows indexed on several vector fields
+/// can appear once per field.
+Future<List<VectorHit>>  cozoVectorSearch({required CozoDb db , required String relation , required String index , required List<double> query , required int k , required int ef , String? filter }) => RustLib.instance.api.crateApiVectorCozoVectorSearch(db: db, relation: relation, index: index, query: query, k: k, ef: ef, filter: filter);
+
+            /// A vector to store with [`cozo_put_embeddings`].
+class Embedding  {
+                /// Key column values of the row, in key order.
+final List<CozoValue> key;
+final Float32List vector;
+
+                const Embedding({required this.key ,required this.vector ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^vector.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Embedding &&
+                runtimeType == other.runtimeType
+                && key == other.key&& vector == other.vector;
+        
+            }
+
+/// Settings of an HNSW vector index.
+class HnswConfig  {
+                /// Length of the indexed vectors.
+final int dim;
+final VectorType dtype;
+/// Vector columns to index. Rows with null in a column are skipped.
+final List<String> fields;
+final VectorDistance distance;
+/// Neighbours kept per node; 16 to 50 is typical. Higher is more
+/// accurate but uses more space and slows down writes.
+final int m;
+/// Candidates considered while inserting; higher builds a more accurate
+/// index more slowly.
+final int efConstruction;
+/// CozoScript expression over the columns; only rows for which it is
+/// true are indexed. `None` indexes every row.
+final String? filter;
+final bool extendCandidates;
+final bool keepPrunedConnections;
+
+                const HnswConfig({required this.dim ,required this.dtype ,required this.fields ,required this.distance ,required this.m ,required this.efConstruction ,this.filter ,required this.extendCandidates ,required this.keepPrunedConnections ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dim.hashCode^dtype.hashCode^fields.hashCode^distance.hashCode^m.hashCode^efConstruction.hashCode^filter.hashCode^extendCandidates.hashCode^keepPrunedConnections.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HnswConfig &&
+                runtimeType == other.runtimeType
+                && dim == other.dim&& dtype == other.dtype&& fields == other.fields&& distance == other.distance&& m == other.m&& efConstruction == other.efConstruction&& filter == other.filter&& extendCandidates == other.extendCandidates&& keepPrunedConnections == other.keepPrunedConnections;
+        
+            }
+
+/// How an HNSW index measures the distance between two vectors.
+enum VectorDistance {
+                    /// Euclidean distance, squared.
+l2,
+/// One minus the cosine similarity.
+cosine,
+/// One minus the inner product, for normalized vectors.
+innerProduct,
+                    ;
+                    
+                }
+
+/// A row found by [`cozo_vector_search`].
+class VectorHit  {
+                /// Key column values of the row, in key order.
+final List<CozoValue> key;
+/// Distance to the query vector, as measured by the index.
+final double distance;
+/// The full row, in column order.
+final List<CozoValue> row;
+
+                const VectorHit({required this.key ,required this.distance ,required this.row ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^distance.hashCode^row.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VectorHit &&
+                runtimeType == other.runtimeType
+                && key == other.key&& distance == other.distance&& row == other.row;
+        
+            }
+
+/// An HNSW index, as listed by `::indices`.
+class VectorIndexInfo  {
+                /// The indexed relation.
+final String relation;
+final String name;
+final int dim;
+final VectorType dtype;
+final List<String> fields;
+final VectorDistance distance;
+final int m;
+final int efConstruction;
+final bool extendCandidates;
+final bool keepPrunedConnections;
+
+                const VectorIndexInfo({required this.relation ,required this.name ,required this.dim ,required this.dtype ,required this.fields ,required this.distance ,required this.m ,required this.efConstruction ,required this.extendCandidates ,required this.keepPrunedConnections ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relation.hashCode^name.hashCode^dim.hashCode^dtype.hashCode^fields.hashCode^distance.hashCode^m.hashCode^efConstruction.hashCode^extendCandidates.hashCode^keepPrunedConnections.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VectorIndexInfo &&
+                runtimeType == other.runtimeType
+                && relation == other.relation&& name == other.name&& dim == other.dim&& dtype == other.dtype&& fields == other.fields&& distance == other.distance&& m == other.m&& efConstruction == other.efConstruction&& extendCandidates == other.extendCandidates&& keepPrunedConnections == other.keepPrunedConnections;
+        
+            }
+
+/// Element type of indexed vectors, matching the column type `<F32; dim>`
+/// or `<F64; dim>`.
+enum VectorType {
+                    f32,
+f64,
+                    ;
+                    
+                }
+            
\ No newline at end of file
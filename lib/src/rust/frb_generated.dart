@@ -3,695 +3,8623 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/asset.dart';
+import 'api/audit.dart';
+import 'api/auto_backup.dart';
+import 'api/backup.dart';
+import 'api/batch.dart';
+import 'api/busy_retry.dart';
+import 'api/callback.dart';
+import 'api/cdc.dart';
+import 'api/column_crypto.dart';
+import 'api/compression.dart';
+import 'api/crdt.dart';
+import 'api/cursor.dart';
+import 'api/encoding.dart';
+import 'api/error.dart';
+import 'api/explain.dart';
+import 'api/export.dart';
+import 'api/file_lock.dart';
+import 'api/fixed_rule.dart';
+import 'api/format.dart';
+import 'api/import.dart';
+import 'api/incremental.dart';
+import 'api/logging.dart';
+import 'api/maintenance.dart';
+import 'api/metrics.dart';
+import 'api/migration.dart';
+import 'api/paths.dart';
+import 'api/prepared.dart';
+import 'api/query_cache.dart';
+import 'api/recovery.dart';
+import 'api/registry.dart';
+import 'api/retry.dart';
+import 'api/row_policy.dart';
+import 'api/rows.dart';
+import 'api/running.dart';
+import 'api/schema.dart';
+import 'api/search.dart';
 import 'api/simple.dart';
+import 'api/slow_log.dart';
+import 'api/soft_delete.dart';
+import 'api/stats.dart';
+import 'api/storage.dart';
+import 'api/stream.dart';
+import 'api/sync.dart';
+import 'api/temporal.dart';
+import 'api/tenant.dart';
+import 'api/transaction.dart';
+import 'api/triggers.dart';
+import 'api/ttl.dart';
+import 'api/validate.dart';
+import 'api/value.dart';
+import 'api/vector.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 739979687;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'cozo_dart',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiSimpleCozoBackup({
-    required CozoDb db,
-    required String path,
-  });
-
-  Future<String> crateApiSimpleCozoExportRelations({
-    required CozoDb db,
-    required String relationsJson,
-  });
-
-  Future<void> crateApiSimpleCozoImportFromBackup({
-    required CozoDb db,
-    required String path,
-    required String relationsJson,
-  });
-
-  Future<void> crateApiSimpleCozoImportRelations({
-    required CozoDb db,
-    required String dataJson,
-  });
-
-  CozoDb crateApiSimpleCozoOpenDb({
-    required String engine,
-    required String path,
-    required String options,
-  });
-
-  Future<void> crateApiSimpleCozoRestore({
-    required CozoDb db,
-    required String path,
-  });
-
-  Future<String> crateApiSimpleCozoRunQuery({
-    required CozoDb db,
-    required String script,
-    required String paramsJson,
-    required bool immutable,
-  });
-
-  Future<void> crateApiSimpleInitApp();
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CozoDb;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CozoDb;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CozoDbPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<void> crateApiSimpleCozoBackup({
-    required CozoDb db,
-    required String path,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-            db,
-            serializer,
-          );
-          sse_encode_String(path, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleCozoBackupConstMeta,
-        argValues: [db, path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleCozoBackupConstMeta =>
-      const TaskConstMeta(debugName: "cozo_backup", argNames: ["db", "path"]);
-
-  @override
-  Future<String> crateApiSimpleCozoExportRelations({
-    required CozoDb db,
-    required String relationsJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-            db,
-            serializer,
-          );
-          sse_encode_String(relationsJson, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -421443982;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'cozo_dart',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<Auditor> crateApiAuditAuditorDefault();
+
+Future<RowPolicies> crateApiRowPolicyRowPoliciesDefault();
+
+Future<SlowLog> crateApiSlowLogSlowLogDefault();
+
+Future<bool> crateApiTransactionCozoAbortTransaction({required CozoDb db , required BigInt id });
+
+CozoDb crateApiRegistryCozoAttach({required BigInt token });
+
+Future<void> crateApiSimpleCozoBackup({required CozoDb db , required String path });
+
+Future<void> crateApiCompressionCozoBackupCompressed({required CozoDb db , required String path , required BackupCompression compression });
+
+Future<void> crateApiBackupCozoBackupEncrypted({required CozoDb db , required String path , required String passphrase });
+
+Future<IncrementalBackupInfo> crateApiIncrementalCozoBackupIncremental({required CozoDb db , required String dir });
+
+Future<Uint8List> crateApiBackupCozoBackupToBytes({required CozoDb db });
+
+Stream<String> crateApiBackupCozoBackupWithProgress({required CozoDb db , required String path });
+
+Future<MaintenanceReport> crateApiMaintenanceCozoBeginMaintenance({required CozoDb db , required BigInt budgetMs });
+
+CozoTx crateApiTransactionCozoBeginTx({required CozoDb db , required bool write });
+
+Future<void> crateApiCdcCozoCdcDisable({required CozoDb db , required String relation });
+
+Future<void> crateApiCdcCozoCdcEnable({required CozoDb db , required List<String> relations });
+
+Future<List<CdcEntry>> crateApiCdcCozoCdcRead({required CozoDb db , required PlatformInt64 fromTx , required int limit });
+
+Future<BigInt> crateApiCdcCozoCdcTrim({required CozoDb db , required PlatformInt64 beforeTx });
+
+Future<BigInt> crateApiCdcCozoCdcTrimOlderThan({required CozoDb db , required double maxAgeSecs });
+
+Future<IntegrityReport> crateApiStorageCozoCheckIntegrity({required CozoDb db });
+
+Future<bool> crateApiStorageCozoCheckpoint({required CozoDb db });
+
+void crateApiQueryCacheCozoClearQueryCache({required CozoDb db });
+
+void crateApiSlowLogCozoClearSlowQueries({required CozoDb db });
+
+Future<void> crateApiTriggersCozoClearTriggers({required CozoDb db , required String relation });
+
+Future<void> crateApiTtlCozoClearTtl({required CozoDb db , required String relation });
+
+Future<CozoDb> crateApiBackupCozoCloneToMem({required CozoDb db });
+
+void crateApiSimpleCozoCloseDb({required CozoDb db });
+
+int crateApiRegistryCozoCloseNamed({required String name });
+
+bool crateApiTenantCozoCloseTenant({required TenantManager manager , required String tenantId });
+
+Stream<String> crateApiStorageCozoCompact({required CozoDb db });
+
+int crateApiSimpleCozoConfigure({required int threads });
+
+void crateApiSlowLogCozoConfigureSlowLog({required CozoDb db , int? thresholdMs , required int capacity });
+
+Future<CozoDb> crateApiBackupCozoCopyDatabase({required CozoDb src , required String dstEngine , required String dstPath , required String dstOptions });
+
+Future<String> crateApiCrdtCozoCrdtChanges({required CozoDb db , required List<String> relations , required double since });
+
+Future<CrdtMergeReport> crateApiCrdtCozoCrdtMerge({required CozoDb db , required String changeset });
+
+Future<void> crateApiCrdtCozoCreateCrdtRelation({required CozoDb db , required String name , required List<ColumnDef> columns });
+
+Future<void> crateApiSearchCozoCreateFtsIndex({required CozoDb db , required String relation , required String name , required List<String> columns , required FtsConfig config });
+
+Future<void> crateApiVectorCozoCreateHnswIndex({required CozoDb db , required String relation , required String name , required HnswConfig config });
+
+Future<void> crateApiSearchCozoCreateLshIndex({required CozoDb db , required String relation , required String name , required List<String> columns , required LshConfig config });
+
+Future<void> crateApiSchemaCozoCreateRelation({required CozoDb db , required String name , required List<ColumnDef> columns });
+
+void crateApiCursorCozoCursorClose({required CozoCursor cursor });
+
+BigInt crateApiCursorCozoCursorLen({required CozoCursor cursor });
+
+Future<CursorPage> crateApiCursorCozoCursorNext({required CozoCursor cursor });
+
+Future<String> crateApiPathsCozoDbPath({required String name , required DbLocation location , required String engine , String? baseDir });
+
+Future<DbStats> crateApiStatsCozoDbStats({required CozoDb db });
+
+Future<BigInt> crateApiColumnCryptoCozoDecryptColumn({required CozoDb db , required String relation , required String column });
+
+QueryOptions crateApiRunningCozoDefaultQueryOptions();
+
+Future<BigInt> crateApiRowsCozoDeleteRows({required CozoDb db , required String relation , required List<List<CozoValue>> keys });
+
+bool crateApiTenantCozoDeleteTenant({required TenantManager manager , required String tenantId });
+
+List<ScriptDiagnostic> crateApiValidateCozoDiagnoseScript({required CozoDb db , required String script });
+
+Future<void> crateApiAuditCozoDisableAudit({required CozoDb db });
+
+bool crateApiAutoBackupCozoDisableAutoBackup({required CozoDb db });
+
+void crateApiQueryCacheCozoDisableQueryCache({required CozoDb db });
+
+Future<void> crateApiSoftDeleteCozoDisableSoftDelete({required CozoDb db , required String relation });
+
+Future<void> crateApiVectorCozoDropIndex({required CozoDb db , required String relation , required String name });
+
+Future<void> crateApiSchemaCozoDropRelation({required CozoDb db , required String name });
+
+Future<void> crateApiAuditCozoEnableAudit({required CozoDb db , required List<String> relations , required FutureOr<String> Function() actorProvider });
+
+Stream<String> crateApiAutoBackupCozoEnableAutoBackup({required CozoDb db , required BackupPolicy policy });
+
+Future<void> crateApiQueryCacheCozoEnableQueryCache({required CozoDb db , required int capacity });
+
+Future<void> crateApiSoftDeleteCozoEnableSoftDelete({required CozoDb db , required String relation , String? flagColumn });
+
+Future<BigInt> crateApiColumnCryptoCozoEncryptColumn({required CozoDb db , required String relation , required String column });
+
+CozoValue crateApiColumnCryptoCozoEncryptValue({required CozoDb db , required String relation , required String column , required List<CozoValue> key , required CozoValue value });
+
+Future<EnsureRelationReport> crateApiSchemaCozoEnsureRelation({required CozoDb db , required String name , required List<ColumnDef> columns });
+
+Future<QueryPlan> crateApiExplainCozoExplain({required CozoDb db , required String script });
+
+Future<String> crateApiExportCozoExportFiltered({required CozoDb db , required String relation , required String filterScript , required String paramsJson });
+
+Future<String> crateApiSimpleCozoExportRelations({required CozoDb db , required String relationsJson });
+
+Future<BigInt> crateApiExportCozoExportRelationsToFile({required CozoDb db , required List<String> relations , required String path , required String format });
+
+bool crateApiFileLockCozoForceUnlock({required String path });
+
+String crateApiFormatCozoFormatScript({required String script });
+
+Future<List<FtsHit>> crateApiSearchCozoFtsSearch({required CozoDb db , required String relation , required String index , required String query , required int limit , required int offset , SnippetConfig? snippets });
+
+CozoDb crateApiRegistryCozoGetNamed({required String name });
+
+Future<List<CozoValue>?> crateApiRowsCozoGetRow({required CozoDb db , required String relation , required List<CozoValue> key });
+
+Future<BigInt> crateApiImportCozoImportCsv({required CozoDb db , required String path , required CsvImportConfig config });
+
+Future<void> crateApiSimpleCozoImportFromBackup({required CozoDb db , required String path , required String relationsJson });
+
+Future<void> crateApiSimpleCozoImportRelations({required CozoDb db , required String dataJson });
+
+Future<ImportReport> crateApiImportCozoImportRelationsWithOptions({required CozoDb db , required String dataJson , required ImportOptions options });
+
+bool crateApiSimpleCozoIsClosed({required CozoDb db });
+
+Future<bool> crateApiRunningCozoKillQuery({required CozoDb db , required String queryId });
+
+Future<List<ColumnInfo>> crateApiSchemaCozoListColumns({required CozoDb db , required String relation });
+
+Future<List<EncryptedColumn>> crateApiColumnCryptoCozoListEncryptedColumns({required CozoDb db });
+
+Future<List<RelationInfo>> crateApiSchemaCozoListRelations({required CozoDb db });
+
+List<RowPolicy> crateApiRowPolicyCozoListRowPolicies({required CozoDb db });
+
+List<RunningQueryInfo> crateApiRunningCozoListRunning({required CozoDb db });
+
+List<String> crateApiTenantCozoListTenants({required TenantManager manager });
+
+List<TransactionInfo> crateApiTransactionCozoListTransactions({required CozoDb db });
+
+Future<TriggerSpec> crateApiTriggersCozoListTriggers({required CozoDb db , required String relation });
+
+Future<List<TtlSetting>> crateApiTtlCozoListTtl({required CozoDb db });
+
+Future<List<VectorIndexInfo>> crateApiVectorCozoListVectorIndices({required CozoDb db , String? relation });
+
+Stream<String> crateApiLoggingCozoLogStream({required String level });
+
+Future<List<LshHit>> crateApiSearchCozoLshSearch({required CozoDb db , required String relation , required String index , required String text , int? k , String? filter });
+
+CozoMetrics crateApiMetricsCozoMetrics({required CozoDb db });
+
+Future<MigrationReport> crateApiMigrationCozoMigrate({required CozoDb db , required List<Migration> migrations });
+
+Future<CozoCursor> crateApiCursorCozoOpenCursor({required CozoDb db , required String script , required String paramsJson , required int pageSize });
+
+CozoDb crateApiSimpleCozoOpenDb({required String engine , required String path , required String options });
+
+Future<CozoDb> crateApiAssetCozoOpenFromAssetBytes({required List<int> bytes , required AssetOpenMode mode , required String path });
+
+CozoDb crateApiRegistryCozoOpenNamed({required String name , required String engine , required String path , required String options });
+
+Future<RecoveredDb> crateApiRecoveryCozoOpenOrRecover({required String engine , required String path , required String options , required RecoveryPolicy policy });
+
+CozoDb crateApiTenantCozoOpenTenant({required TenantManager manager , required String tenantId , String? columnKey });
+
+PreparedQuery crateApiPreparedCozoPrepare({required CozoDb db , required String script });
+
+Future<BigInt> crateApiTtlCozoPurgeExpired({required CozoDb db });
+
+Stream<String> crateApiVectorCozoPutEmbeddings({required CozoDb db , required String relation , required String column , required List<Embedding> embeddings });
+
+Future<BigInt> crateApiRowsCozoPutRows({required CozoDb db , required String relation , required List<List<CozoValue>> rows });
+
+Future<BigInt> crateApiTemporalCozoPutValid({required CozoDb db , required String relation , required List<List<CozoValue>> rows , required DateTime validFrom , DateTime? validTo });
+
+Future<String> crateApiTemporalCozoQueryAsof({required CozoDb db , required String script , required String paramsJson , required DateTime asof });
+
+Future<BigInt> crateApiExportCozoQueryToFile({required CozoDb db , required String script , required String paramsJson , required String path , required String format });
+
+Future<List<AuditEntry>> crateApiAuditCozoReadAudit({required CozoDb db , required AuditQuery query });
+
+Stream<RelationChange> crateApiCallbackCozoRegisterCallback({required CozoDb db , required String relation });
+
+Future<void> crateApiFixedRuleCozoRegisterFixedRule({required CozoDb db , required String name , required BigInt arity , required FutureOr<String> Function(String, String) rule });
+
+Stream<String> crateApiColumnCryptoCozoRekey({required CozoDb db , required String newKey });
+
+Future<RelationStats> crateApiStatsCozoRelationStats({required CozoDb db , required String name });
+
+bool crateApiRegistryCozoReleaseToken({required BigInt token });
+
+bool crateApiRowPolicyCozoRemoveRowPolicy({required CozoDb db , required String relation });
+
+Future<void> crateApiSchemaCozoRenameRelation({required CozoDb db , required String name , required String newName });
+
+void crateApiMetricsCozoResetMetrics({required CozoDb db });
+
+Future<void> crateApiSimpleCozoRestore({required CozoDb db , required String path });
+
+Future<BigInt> crateApiSoftDeleteCozoRestoreDeleted({required CozoDb db , required String relation , required List<List<CozoValue>> keys });
+
+Future<void> crateApiBackupCozoRestoreEncrypted({required CozoDb db , required String path , required String passphrase });
+
+Future<void> crateApiBackupCozoRestoreFromBytes({required CozoDb db , required List<int> data });
+
+Future<List<String>> crateApiIncrementalCozoRestoreIncremental({required CozoDb db , required String dir });
+
+Stream<String> crateApiBackupCozoRestoreWithProgress({required CozoDb db , required String path });
+
+Future<BigInt> crateApiTemporalCozoRetractRows({required CozoDb db , required String relation , required List<List<CozoValue>> keys , required DateTime at });
+
+Future<List<QueryResult>> crateApiBatchCozoRunBatch({required CozoDb db , required List<ScriptWithParams> scripts , required bool atomic });
+
+Future<String> crateApiPreparedCozoRunPrepared({required PreparedQuery prepared , required String paramsJson , required bool immutable });
+
+Future<String> crateApiSimpleCozoRunQuery({required CozoDb db , required String script , required String paramsJson , required bool immutable });
+
+Future<Uint8List> crateApiExportCozoRunQueryArrow({required CozoDb db , required String script , required String paramsJson });
+
+Future<String> crateApiQueryCacheCozoRunQueryCached({required CozoDb db , required String script , required String paramsJson });
+
+Future<String> crateApiSimpleCozoRunQueryChecked({required CozoDb db , required String script , required String paramsJson , required bool immutable });
+
+Future<Uint8List> crateApiEncodingCozoRunQueryEncoded({required CozoDb db , required String script , required String paramsJson , required bool immutable , required ResultEncoding encoding });
+
+Stream<String> crateApiStreamCozoRunQueryStream({required CozoDb db , required String script , required String paramsJson , required int chunkSize , required bool immutable });
+
+Future<QueryResult> crateApiSimpleCozoRunQueryTyped({required CozoDb db , required String script , required Map<String, CozoValue> params , required bool immutable });
+
+Future<String> crateApiRunningCozoRunQueryWithOptions({required CozoDb db , required String script , required String paramsJson , required QueryOptions options });
+
+Stream<String> crateApiStreamCozoRunQueryWithProgress({required CozoDb db , required String script , required String paramsJson , required bool immutable });
+
+Future<String> crateApiRunningCozoRunTrackedQuery({required CozoDb db , required String queryId , required String script , required String paramsJson , required bool immutable });
+
+Future<List<QueryResult>> crateApiRetryCozoRunTxWithRetry({required CozoDb db , required List<ScriptWithParams> scripts , required RetryPolicy policy });
+
+Future<String> crateApiRetryCozoRunWithRetry({required CozoDb db , required String script , required String paramsJson , required RetryPolicy policy });
+
+Future<SchemaDiff> crateApiSchemaCozoSchemaDiff({required CozoDb a , required CozoDb b });
+
+Future<SchemaDiff> crateApiSchemaCozoSchemaDiffBackup({required CozoDb db , required String path });
+
+void crateApiBusyRetryCozoSetBusyRetry({required CozoDb db , BusyRetryPolicy? policy });
+
+void crateApiRowPolicyCozoSetPolicyParams({required CozoDb db , required String paramsJson });
+
+Future<void> crateApiRowPolicyCozoSetRowPolicy({required CozoDb db , required String relation , required String predicate });
+
+Future<void> crateApiTriggersCozoSetTriggers({required CozoDb db , required String relation , required TriggerSpec spec });
+
+Future<void> crateApiTtlCozoSetTtl({required CozoDb db , required String relation , required String ttlColumn , required double ttlSecs });
+
+BigInt crateApiRegistryCozoShareToken({required CozoDb db });
+
+List<SlowQuery> crateApiSlowLogCozoSlowQueries({required CozoDb db });
+
+Future<SyncReport> crateApiSyncCozoSyncApplyChanges({required CozoDb db , required String payload });
+
+Future<SyncReport> crateApiSyncCozoSyncApplyChangesWithResolver({required CozoDb db , required String payload , required FutureOr<String> Function(String) resolve });
+
+Future<void> crateApiSyncCozoSyncDisable({required CozoDb db , required String relation });
+
+Future<void> crateApiSyncCozoSyncEnable({required CozoDb db , required List<String> relations });
+
+Future<SyncChangeset> crateApiSyncCozoSyncExportChanges({required CozoDb db , required String sinceToken });
+
+TenantManager crateApiTenantCozoTenantManager({required String root , required String engine , required String options });
+
+Future<int> crateApiCallbackCozoUnregisterCallbacks({required CozoDb db , required String relation });
+
+Future<bool> crateApiFixedRuleCozoUnregisterFixedRule({required CozoDb db , required String name });
+
+Future<BigInt> crateApiRowsCozoUpsertRows({required CozoDb db , required String relation , required List<List<CozoValue>> rows , required OnConflict onConflict });
+
+ValidationResult crateApiValidateCozoValidateScript({required CozoDb db , required String script });
+
+CozoValue crateApiTemporalCozoValidityFromTime({required ValidityTime time });
+
+ValidityTime crateApiTemporalCozoValidityToTime({required CozoValue value });
+
+Future<List<VectorHit>> crateApiVectorCozoVectorSearch({required CozoDb db , required String relation , required String index , required List<double> query , required int k , required int ef , String? filter });
+
+Future<BackupInfo> crateApiBackupCozoVerifyBackup({required String path });
+
+Stream<String> crateApiCallbackCozoWatchQuery({required CozoDb db , required String script , required String paramsJson });
+
+Stream<String> crateApiCallbackCozoWatchRelations({required CozoDb db , required List<String> relations });
+
+Future<CrdtMergeReport> crateApiCrdtCrdtMergeReportDefault();
+
+Future<ImportReport> crateApiImportImportReportDefault();
+
+Future<void> crateApiSimpleInitApp();
+
+Future<SyncReport> crateApiSyncSyncReportDefault();
+
+Future<TriggerSpec> crateApiTriggersTriggerSpecDefault();
+
+Future<void> crateApiTransactionTxAbort({required CozoTx tx });
+
+Future<void> crateApiTransactionTxCommit({required CozoTx tx });
+
+void crateApiTransactionTxRelease({required CozoTx tx , required String name });
+
+Future<void> crateApiTransactionTxRollbackTo({required CozoTx tx , required String name });
+
+Future<String> crateApiTransactionTxRunQuery({required CozoTx tx , required String script , required String paramsJson });
+
+void crateApiTransactionTxSavepoint({required CozoTx tx , required String name });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Auditor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Auditor;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AuditorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CozoCursor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CozoCursor;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CozoCursorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CozoDb;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CozoDb;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CozoDbPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CozoTx;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CozoTx;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CozoTxPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PreparedQuery;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PreparedQuery;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PreparedQueryPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RowPolicies;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RowPolicies;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RowPoliciesPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SlowLog;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SlowLog;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SlowLogPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TenantManager;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TenantManager;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TenantManagerPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<Auditor> crateApiAuditAuditorDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleCozoExportRelationsConstMeta,
-        argValues: [db, relationsJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleCozoExportRelationsConstMeta =>
-      const TaskConstMeta(
-        debugName: "cozo_export_relations",
-        argNames: ["db", "relationsJson"],
-      );
-
-  @override
-  Future<void> crateApiSimpleCozoImportFromBackup({
-    required CozoDb db,
-    required String path,
-    required String relationsJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-            db,
-            serializer,
-          );
-          sse_encode_String(path, serializer);
-          sse_encode_String(relationsJson, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleCozoImportFromBackupConstMeta,
-        argValues: [db, path, relationsJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleCozoImportFromBackupConstMeta =>
-      const TaskConstMeta(
-        debugName: "cozo_import_from_backup",
-        argNames: ["db", "path", "relationsJson"],
-      );
-
-  @override
-  Future<void> crateApiSimpleCozoImportRelations({
-    required CozoDb db,
-    required String dataJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-            db,
-            serializer,
-          );
-          sse_encode_String(dataJson, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleCozoImportRelationsConstMeta,
-        argValues: [db, dataJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleCozoImportRelationsConstMeta =>
-      const TaskConstMeta(
-        debugName: "cozo_import_relations",
-        argNames: ["db", "dataJson"],
-      );
-
-  @override
-  CozoDb crateApiSimpleCozoOpenDb({
-    required String engine,
-    required String path,
-    required String options,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(engine, serializer);
-          sse_encode_String(path, serializer);
-          sse_encode_String(options, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleCozoOpenDbConstMeta,
-        argValues: [engine, path, options],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleCozoOpenDbConstMeta => const TaskConstMeta(
-    debugName: "cozo_open_db",
-    argNames: ["engine", "path", "options"],
-  );
-
-  @override
-  Future<void> crateApiSimpleCozoRestore({
-    required CozoDb db,
-    required String path,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-            db,
-            serializer,
-          );
-          sse_encode_String(path, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleCozoRestoreConstMeta,
-        argValues: [db, path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleCozoRestoreConstMeta =>
-      const TaskConstMeta(debugName: "cozo_restore", argNames: ["db", "path"]);
-
-  @override
-  Future<String> crateApiSimpleCozoRunQuery({
-    required CozoDb db,
-    required String script,
-    required String paramsJson,
-    required bool immutable,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-            db,
-            serializer,
-          );
-          sse_encode_String(script, serializer);
-          sse_encode_String(paramsJson, serializer);
-          sse_encode_bool(immutable, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAuditAuditorDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuditAuditorDefaultConstMeta => const TaskConstMeta(
+            debugName: "Auditor_default",
+            argNames: [],
+        );
+        
+
+@override Future<RowPolicies> crateApiRowPolicyRowPoliciesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleCozoRunQueryConstMeta,
-        argValues: [db, script, paramsJson, immutable],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleCozoRunQueryConstMeta => const TaskConstMeta(
-    debugName: "cozo_run_query",
-    argNames: ["db", "script", "paramsJson", "immutable"],
-  );
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CozoDb => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CozoDb => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  CozoDb
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CozoDbImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CozoDb
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CozoDbImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CozoDb
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CozoDbImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  CozoDb
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CozoDbImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CozoDb
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CozoDbImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CozoDb
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CozoDbImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    CozoDb self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CozoDbImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    CozoDb self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CozoDbImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    CozoDb self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CozoDbImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-}
-
-@sealed
-class CozoDbImpl extends RustOpaque implements CozoDb {
-  // Not to be used by end users
-  CozoDbImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  CozoDbImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_CozoDb,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CozoDb,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CozoDbPtr,
-  );
-}
+        )
+        ,
+            constMeta: kCrateApiRowPolicyRowPoliciesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowPolicyRowPoliciesDefaultConstMeta => const TaskConstMeta(
+            debugName: "RowPolicies_default",
+            argNames: [],
+        );
+        
+
+@override Future<SlowLog> crateApiSlowLogSlowLogDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSlowLogSlowLogDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSlowLogSlowLogDefaultConstMeta => const TaskConstMeta(
+            debugName: "SlowLog_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiTransactionCozoAbortTransaction({required CozoDb db , required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_u_64(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionCozoAbortTransactionConstMeta,
+            argValues: [db, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionCozoAbortTransactionConstMeta => const TaskConstMeta(
+            debugName: "cozo_abort_transaction",
+            argNames: ["db", "id"],
+        );
+        
+
+@override CozoDb crateApiRegistryCozoAttach({required BigInt token })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(token, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRegistryCozoAttachConstMeta,
+            argValues: [token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRegistryCozoAttachConstMeta => const TaskConstMeta(
+            debugName: "cozo_attach",
+            argNames: ["token"],
+        );
+        
+
+@override Future<void> crateApiSimpleCozoBackup({required CozoDb db , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoBackupConstMeta,
+            argValues: [db, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoBackupConstMeta => const TaskConstMeta(
+            debugName: "cozo_backup",
+            argNames: ["db", "path"],
+        );
+        
+
+@override Future<void> crateApiCompressionCozoBackupCompressed({required CozoDb db , required String path , required BackupCompression compression })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+sse_encode_box_autoadd_backup_compression(compression, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCompressionCozoBackupCompressedConstMeta,
+            argValues: [db, path, compression],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionCozoBackupCompressedConstMeta => const TaskConstMeta(
+            debugName: "cozo_backup_compressed",
+            argNames: ["db", "path", "compression"],
+        );
+        
+
+@override Future<void> crateApiBackupCozoBackupEncrypted({required CozoDb db , required String path , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(passphrase, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoBackupEncryptedConstMeta,
+            argValues: [db, path, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackupCozoBackupEncryptedConstMeta => const TaskConstMeta(
+            debugName: "cozo_backup_encrypted",
+            argNames: ["db", "path", "passphrase"],
+        );
+        
+
+@override Future<IncrementalBackupInfo> crateApiIncrementalCozoBackupIncremental({required CozoDb db , required String dir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(dir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_incremental_backup_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiIncrementalCozoBackupIncrementalConstMeta,
+            argValues: [db, dir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalCozoBackupIncrementalConstMeta => const TaskConstMeta(
+            debugName: "cozo_backup_incremental",
+            argNames: ["db", "dir"],
+        );
+        
+
+@override Future<Uint8List> crateApiBackupCozoBackupToBytes({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoBackupToBytesConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackupCozoBackupToBytesConstMeta => const TaskConstMeta(
+            debugName: "cozo_backup_to_bytes",
+            argNames: ["db"],
+        );
+        
+
+@override Stream<String> crateApiBackupCozoBackupWithProgress({required CozoDb db , required String path })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoBackupWithProgressConstMeta,
+            argValues: [db, path, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiBackupCozoBackupWithProgressConstMeta => const TaskConstMeta(
+            debugName: "cozo_backup_with_progress",
+            argNames: ["db", "path", "sink"],
+        );
+        
+
+@override Future<MaintenanceReport> crateApiMaintenanceCozoBeginMaintenance({required CozoDb db , required BigInt budgetMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_u_64(budgetMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_maintenance_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceCozoBeginMaintenanceConstMeta,
+            argValues: [db, budgetMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceCozoBeginMaintenanceConstMeta => const TaskConstMeta(
+            debugName: "cozo_begin_maintenance",
+            argNames: ["db", "budgetMs"],
+        );
+        
+
+@override CozoTx crateApiTransactionCozoBeginTx({required CozoDb db , required bool write })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_bool(write, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionCozoBeginTxConstMeta,
+            argValues: [db, write],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionCozoBeginTxConstMeta => const TaskConstMeta(
+            debugName: "cozo_begin_tx",
+            argNames: ["db", "write"],
+        );
+        
+
+@override Future<void> crateApiCdcCozoCdcDisable({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCdcCozoCdcDisableConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCdcCozoCdcDisableConstMeta => const TaskConstMeta(
+            debugName: "cozo_cdc_disable",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<void> crateApiCdcCozoCdcEnable({required CozoDb db , required List<String> relations })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_String(relations, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCdcCozoCdcEnableConstMeta,
+            argValues: [db, relations],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCdcCozoCdcEnableConstMeta => const TaskConstMeta(
+            debugName: "cozo_cdc_enable",
+            argNames: ["db", "relations"],
+        );
+        
+
+@override Future<List<CdcEntry>> crateApiCdcCozoCdcRead({required CozoDb db , required PlatformInt64 fromTx , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_i_64(fromTx, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_cdc_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCdcCozoCdcReadConstMeta,
+            argValues: [db, fromTx, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCdcCozoCdcReadConstMeta => const TaskConstMeta(
+            debugName: "cozo_cdc_read",
+            argNames: ["db", "fromTx", "limit"],
+        );
+        
+
+@override Future<BigInt> crateApiCdcCozoCdcTrim({required CozoDb db , required PlatformInt64 beforeTx })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_i_64(beforeTx, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCdcCozoCdcTrimConstMeta,
+            argValues: [db, beforeTx],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCdcCozoCdcTrimConstMeta => const TaskConstMeta(
+            debugName: "cozo_cdc_trim",
+            argNames: ["db", "beforeTx"],
+        );
+        
+
+@override Future<BigInt> crateApiCdcCozoCdcTrimOlderThan({required CozoDb db , required double maxAgeSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_f_64(maxAgeSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCdcCozoCdcTrimOlderThanConstMeta,
+            argValues: [db, maxAgeSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCdcCozoCdcTrimOlderThanConstMeta => const TaskConstMeta(
+            debugName: "cozo_cdc_trim_older_than",
+            argNames: ["db", "maxAgeSecs"],
+        );
+        
+
+@override Future<IntegrityReport> crateApiStorageCozoCheckIntegrity({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_integrity_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStorageCozoCheckIntegrityConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStorageCozoCheckIntegrityConstMeta => const TaskConstMeta(
+            debugName: "cozo_check_integrity",
+            argNames: ["db"],
+        );
+        
+
+@override Future<bool> crateApiStorageCozoCheckpoint({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStorageCozoCheckpointConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStorageCozoCheckpointConstMeta => const TaskConstMeta(
+            debugName: "cozo_checkpoint",
+            argNames: ["db"],
+        );
+        
+
+@override void crateApiQueryCacheCozoClearQueryCache({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryCacheCozoClearQueryCacheConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryCacheCozoClearQueryCacheConstMeta => const TaskConstMeta(
+            debugName: "cozo_clear_query_cache",
+            argNames: ["db"],
+        );
+        
+
+@override void crateApiSlowLogCozoClearSlowQueries({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSlowLogCozoClearSlowQueriesConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSlowLogCozoClearSlowQueriesConstMeta => const TaskConstMeta(
+            debugName: "cozo_clear_slow_queries",
+            argNames: ["db"],
+        );
+        
+
+@override Future<void> crateApiTriggersCozoClearTriggers({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTriggersCozoClearTriggersConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTriggersCozoClearTriggersConstMeta => const TaskConstMeta(
+            debugName: "cozo_clear_triggers",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<void> crateApiTtlCozoClearTtl({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTtlCozoClearTtlConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTtlCozoClearTtlConstMeta => const TaskConstMeta(
+            debugName: "cozo_clear_ttl",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<CozoDb> crateApiBackupCozoCloneToMem({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoCloneToMemConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackupCozoCloneToMemConstMeta => const TaskConstMeta(
+            debugName: "cozo_clone_to_mem",
+            argNames: ["db"],
+        );
+        
+
+@override void crateApiSimpleCozoCloseDb({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoCloseDbConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoCloseDbConstMeta => const TaskConstMeta(
+            debugName: "cozo_close_db",
+            argNames: ["db"],
+        );
+        
+
+@override int crateApiRegistryCozoCloseNamed({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRegistryCozoCloseNamedConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRegistryCozoCloseNamedConstMeta => const TaskConstMeta(
+            debugName: "cozo_close_named",
+            argNames: ["name"],
+        );
+        
+
+@override bool crateApiTenantCozoCloseTenant({required TenantManager manager , required String tenantId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(manager, serializer);
+sse_encode_String(tenantId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTenantCozoCloseTenantConstMeta,
+            argValues: [manager, tenantId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantCozoCloseTenantConstMeta => const TaskConstMeta(
+            debugName: "cozo_close_tenant",
+            argNames: ["manager", "tenantId"],
+        );
+        
+
+@override Stream<String> crateApiStorageCozoCompact({required CozoDb db })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStorageCozoCompactConstMeta,
+            argValues: [db, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiStorageCozoCompactConstMeta => const TaskConstMeta(
+            debugName: "cozo_compact",
+            argNames: ["db", "sink"],
+        );
+        
+
+@override int crateApiSimpleCozoConfigure({required int threads })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(threads, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoConfigureConstMeta,
+            argValues: [threads],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoConfigureConstMeta => const TaskConstMeta(
+            debugName: "cozo_configure",
+            argNames: ["threads"],
+        );
+        
+
+@override void crateApiSlowLogCozoConfigureSlowLog({required CozoDb db , int? thresholdMs , required int capacity })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_opt_box_autoadd_u_32(thresholdMs, serializer);
+sse_encode_u_32(capacity, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSlowLogCozoConfigureSlowLogConstMeta,
+            argValues: [db, thresholdMs, capacity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSlowLogCozoConfigureSlowLogConstMeta => const TaskConstMeta(
+            debugName: "cozo_configure_slow_log",
+            argNames: ["db", "thresholdMs", "capacity"],
+        );
+        
+
+@override Future<CozoDb> crateApiBackupCozoCopyDatabase({required CozoDb src , required String dstEngine , required String dstPath , required String dstOptions })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(src, serializer);
+sse_encode_String(dstEngine, serializer);
+sse_encode_String(dstPath, serializer);
+sse_encode_String(dstOptions, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoCopyDatabaseConstMeta,
+            argValues: [src, dstEngine, dstPath, dstOptions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackupCozoCopyDatabaseConstMeta => const TaskConstMeta(
+            debugName: "cozo_copy_database",
+            argNames: ["src", "dstEngine", "dstPath", "dstOptions"],
+        );
+        
+
+@override Future<String> crateApiCrdtCozoCrdtChanges({required CozoDb db , required List<String> relations , required double since })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_String(relations, serializer);
+sse_encode_f_64(since, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCrdtCozoCrdtChangesConstMeta,
+            argValues: [db, relations, since],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrdtCozoCrdtChangesConstMeta => const TaskConstMeta(
+            debugName: "cozo_crdt_changes",
+            argNames: ["db", "relations", "since"],
+        );
+        
+
+@override Future<CrdtMergeReport> crateApiCrdtCozoCrdtMerge({required CozoDb db , required String changeset })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(changeset, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_crdt_merge_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCrdtCozoCrdtMergeConstMeta,
+            argValues: [db, changeset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrdtCozoCrdtMergeConstMeta => const TaskConstMeta(
+            debugName: "cozo_crdt_merge",
+            argNames: ["db", "changeset"],
+        );
+        
+
+@override Future<void> crateApiCrdtCozoCreateCrdtRelation({required CozoDb db , required String name , required List<ColumnDef> columns })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+sse_encode_list_column_def(columns, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCrdtCozoCreateCrdtRelationConstMeta,
+            argValues: [db, name, columns],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrdtCozoCreateCrdtRelationConstMeta => const TaskConstMeta(
+            debugName: "cozo_create_crdt_relation",
+            argNames: ["db", "name", "columns"],
+        );
+        
+
+@override Future<void> crateApiSearchCozoCreateFtsIndex({required CozoDb db , required String relation , required String name , required List<String> columns , required FtsConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(name, serializer);
+sse_encode_list_String(columns, serializer);
+sse_encode_box_autoadd_fts_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSearchCozoCreateFtsIndexConstMeta,
+            argValues: [db, relation, name, columns, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchCozoCreateFtsIndexConstMeta => const TaskConstMeta(
+            debugName: "cozo_create_fts_index",
+            argNames: ["db", "relation", "name", "columns", "config"],
+        );
+        
+
+@override Future<void> crateApiVectorCozoCreateHnswIndex({required CozoDb db , required String relation , required String name , required HnswConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(name, serializer);
+sse_encode_box_autoadd_hnsw_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiVectorCozoCreateHnswIndexConstMeta,
+            argValues: [db, relation, name, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVectorCozoCreateHnswIndexConstMeta => const TaskConstMeta(
+            debugName: "cozo_create_hnsw_index",
+            argNames: ["db", "relation", "name", "config"],
+        );
+        
+
+@override Future<void> crateApiSearchCozoCreateLshIndex({required CozoDb db , required String relation , required String name , required List<String> columns , required LshConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(name, serializer);
+sse_encode_list_String(columns, serializer);
+sse_encode_box_autoadd_lsh_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSearchCozoCreateLshIndexConstMeta,
+            argValues: [db, relation, name, columns, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchCozoCreateLshIndexConstMeta => const TaskConstMeta(
+            debugName: "cozo_create_lsh_index",
+            argNames: ["db", "relation", "name", "columns", "config"],
+        );
+        
+
+@override Future<void> crateApiSchemaCozoCreateRelation({required CozoDb db , required String name , required List<ColumnDef> columns })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+sse_encode_list_column_def(columns, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoCreateRelationConstMeta,
+            argValues: [db, name, columns],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoCreateRelationConstMeta => const TaskConstMeta(
+            debugName: "cozo_create_relation",
+            argNames: ["db", "name", "columns"],
+        );
+        
+
+@override void crateApiCursorCozoCursorClose({required CozoCursor cursor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(cursor, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCursorCozoCursorCloseConstMeta,
+            argValues: [cursor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCursorCozoCursorCloseConstMeta => const TaskConstMeta(
+            debugName: "cozo_cursor_close",
+            argNames: ["cursor"],
+        );
+        
+
+@override BigInt crateApiCursorCozoCursorLen({required CozoCursor cursor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(cursor, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCursorCozoCursorLenConstMeta,
+            argValues: [cursor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCursorCozoCursorLenConstMeta => const TaskConstMeta(
+            debugName: "cozo_cursor_len",
+            argNames: ["cursor"],
+        );
+        
+
+@override Future<CursorPage> crateApiCursorCozoCursorNext({required CozoCursor cursor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(cursor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cursor_page,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCursorCozoCursorNextConstMeta,
+            argValues: [cursor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCursorCozoCursorNextConstMeta => const TaskConstMeta(
+            debugName: "cozo_cursor_next",
+            argNames: ["cursor"],
+        );
+        
+
+@override Future<String> crateApiPathsCozoDbPath({required String name , required DbLocation location , required String engine , String? baseDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_db_location(location, serializer);
+sse_encode_String(engine, serializer);
+sse_encode_opt_String(baseDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPathsCozoDbPathConstMeta,
+            argValues: [name, location, engine, baseDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPathsCozoDbPathConstMeta => const TaskConstMeta(
+            debugName: "cozo_db_path",
+            argNames: ["name", "location", "engine", "baseDir"],
+        );
+        
+
+@override Future<DbStats> crateApiStatsCozoDbStats({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_db_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStatsCozoDbStatsConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatsCozoDbStatsConstMeta => const TaskConstMeta(
+            debugName: "cozo_db_stats",
+            argNames: ["db"],
+        );
+        
+
+@override Future<BigInt> crateApiColumnCryptoCozoDecryptColumn({required CozoDb db , required String relation , required String column })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(column, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiColumnCryptoCozoDecryptColumnConstMeta,
+            argValues: [db, relation, column],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiColumnCryptoCozoDecryptColumnConstMeta => const TaskConstMeta(
+            debugName: "cozo_decrypt_column",
+            argNames: ["db", "relation", "column"],
+        );
+        
+
+@override QueryOptions crateApiRunningCozoDefaultQueryOptions()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_query_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRunningCozoDefaultQueryOptionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRunningCozoDefaultQueryOptionsConstMeta => const TaskConstMeta(
+            debugName: "cozo_default_query_options",
+            argNames: [],
+        );
+        
+
+@override Future<BigInt> crateApiRowsCozoDeleteRows({required CozoDb db , required String relation , required List<List<CozoValue>> keys })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_list_list_cozo_value(keys, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRowsCozoDeleteRowsConstMeta,
+            argValues: [db, relation, keys],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowsCozoDeleteRowsConstMeta => const TaskConstMeta(
+            debugName: "cozo_delete_rows",
+            argNames: ["db", "relation", "keys"],
+        );
+        
+
+@override bool crateApiTenantCozoDeleteTenant({required TenantManager manager , required String tenantId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(manager, serializer);
+sse_encode_String(tenantId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTenantCozoDeleteTenantConstMeta,
+            argValues: [manager, tenantId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantCozoDeleteTenantConstMeta => const TaskConstMeta(
+            debugName: "cozo_delete_tenant",
+            argNames: ["manager", "tenantId"],
+        );
+        
+
+@override List<ScriptDiagnostic> crateApiValidateCozoDiagnoseScript({required CozoDb db , required String script })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_script_diagnostic,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiValidateCozoDiagnoseScriptConstMeta,
+            argValues: [db, script],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiValidateCozoDiagnoseScriptConstMeta => const TaskConstMeta(
+            debugName: "cozo_diagnose_script",
+            argNames: ["db", "script"],
+        );
+        
+
+@override Future<void> crateApiAuditCozoDisableAudit({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAuditCozoDisableAuditConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuditCozoDisableAuditConstMeta => const TaskConstMeta(
+            debugName: "cozo_disable_audit",
+            argNames: ["db"],
+        );
+        
+
+@override bool crateApiAutoBackupCozoDisableAutoBackup({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAutoBackupCozoDisableAutoBackupConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAutoBackupCozoDisableAutoBackupConstMeta => const TaskConstMeta(
+            debugName: "cozo_disable_auto_backup",
+            argNames: ["db"],
+        );
+        
+
+@override void crateApiQueryCacheCozoDisableQueryCache({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryCacheCozoDisableQueryCacheConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryCacheCozoDisableQueryCacheConstMeta => const TaskConstMeta(
+            debugName: "cozo_disable_query_cache",
+            argNames: ["db"],
+        );
+        
+
+@override Future<void> crateApiSoftDeleteCozoDisableSoftDelete({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSoftDeleteCozoDisableSoftDeleteConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSoftDeleteCozoDisableSoftDeleteConstMeta => const TaskConstMeta(
+            debugName: "cozo_disable_soft_delete",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<void> crateApiVectorCozoDropIndex({required CozoDb db , required String relation , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiVectorCozoDropIndexConstMeta,
+            argValues: [db, relation, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVectorCozoDropIndexConstMeta => const TaskConstMeta(
+            debugName: "cozo_drop_index",
+            argNames: ["db", "relation", "name"],
+        );
+        
+
+@override Future<void> crateApiSchemaCozoDropRelation({required CozoDb db , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoDropRelationConstMeta,
+            argValues: [db, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoDropRelationConstMeta => const TaskConstMeta(
+            debugName: "cozo_drop_relation",
+            argNames: ["db", "name"],
+        );
+        
+
+@override Future<void> crateApiAuditCozoEnableAudit({required CozoDb db , required List<String> relations , required FutureOr<String> Function() actorProvider })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_String(relations, serializer);
+sse_encode_DartFn_Inputs__Output_String_AnyhowException(actorProvider, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAuditCozoEnableAuditConstMeta,
+            argValues: [db, relations, actorProvider],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuditCozoEnableAuditConstMeta => const TaskConstMeta(
+            debugName: "cozo_enable_audit",
+            argNames: ["db", "relations", "actorProvider"],
+        );
+        
+
+@override Stream<String> crateApiAutoBackupCozoEnableAutoBackup({required CozoDb db , required BackupPolicy policy })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_box_autoadd_backup_policy(policy, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAutoBackupCozoEnableAutoBackupConstMeta,
+            argValues: [db, policy, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiAutoBackupCozoEnableAutoBackupConstMeta => const TaskConstMeta(
+            debugName: "cozo_enable_auto_backup",
+            argNames: ["db", "policy", "sink"],
+        );
+        
+
+@override Future<void> crateApiQueryCacheCozoEnableQueryCache({required CozoDb db , required int capacity })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_u_32(capacity, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiQueryCacheCozoEnableQueryCacheConstMeta,
+            argValues: [db, capacity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryCacheCozoEnableQueryCacheConstMeta => const TaskConstMeta(
+            debugName: "cozo_enable_query_cache",
+            argNames: ["db", "capacity"],
+        );
+        
+
+@override Future<void> crateApiSoftDeleteCozoEnableSoftDelete({required CozoDb db , required String relation , String? flagColumn })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_opt_String(flagColumn, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSoftDeleteCozoEnableSoftDeleteConstMeta,
+            argValues: [db, relation, flagColumn],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSoftDeleteCozoEnableSoftDeleteConstMeta => const TaskConstMeta(
+            debugName: "cozo_enable_soft_delete",
+            argNames: ["db", "relation", "flagColumn"],
+        );
+        
+
+@override Future<BigInt> crateApiColumnCryptoCozoEncryptColumn({required CozoDb db , required String relation , required String column })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(column, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiColumnCryptoCozoEncryptColumnConstMeta,
+            argValues: [db, relation, column],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiColumnCryptoCozoEncryptColumnConstMeta => const TaskConstMeta(
+            debugName: "cozo_encrypt_column",
+            argNames: ["db", "relation", "column"],
+        );
+        
+
+@override CozoValue crateApiColumnCryptoCozoEncryptValue({required CozoDb db , required String relation , required String column , required List<CozoValue> key , required CozoValue value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(column, serializer);
+sse_encode_list_cozo_value(key, serializer);
+sse_encode_box_autoadd_cozo_value(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cozo_value,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiColumnCryptoCozoEncryptValueConstMeta,
+            argValues: [db, relation, column, key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiColumnCryptoCozoEncryptValueConstMeta => const TaskConstMeta(
+            debugName: "cozo_encrypt_value",
+            argNames: ["db", "relation", "column", "key", "value"],
+        );
+        
+
+@override Future<EnsureRelationReport> crateApiSchemaCozoEnsureRelation({required CozoDb db , required String name , required List<ColumnDef> columns })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+sse_encode_list_column_def(columns, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ensure_relation_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoEnsureRelationConstMeta,
+            argValues: [db, name, columns],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoEnsureRelationConstMeta => const TaskConstMeta(
+            debugName: "cozo_ensure_relation",
+            argNames: ["db", "name", "columns"],
+        );
+        
+
+@override Future<QueryPlan> crateApiExplainCozoExplain({required CozoDb db , required String script })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_query_plan,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiExplainCozoExplainConstMeta,
+            argValues: [db, script],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExplainCozoExplainConstMeta => const TaskConstMeta(
+            debugName: "cozo_explain",
+            argNames: ["db", "script"],
+        );
+        
+
+@override Future<String> crateApiExportCozoExportFiltered({required CozoDb db , required String relation , required String filterScript , required String paramsJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(filterScript, serializer);
+sse_encode_String(paramsJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiExportCozoExportFilteredConstMeta,
+            argValues: [db, relation, filterScript, paramsJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCozoExportFilteredConstMeta => const TaskConstMeta(
+            debugName: "cozo_export_filtered",
+            argNames: ["db", "relation", "filterScript", "paramsJson"],
+        );
+        
+
+@override Future<String> crateApiSimpleCozoExportRelations({required CozoDb db , required String relationsJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relationsJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoExportRelationsConstMeta,
+            argValues: [db, relationsJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoExportRelationsConstMeta => const TaskConstMeta(
+            debugName: "cozo_export_relations",
+            argNames: ["db", "relationsJson"],
+        );
+        
+
+@override Future<BigInt> crateApiExportCozoExportRelationsToFile({required CozoDb db , required List<String> relations , required String path , required String format })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_String(relations, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(format, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiExportCozoExportRelationsToFileConstMeta,
+            argValues: [db, relations, path, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCozoExportRelationsToFileConstMeta => const TaskConstMeta(
+            debugName: "cozo_export_relations_to_file",
+            argNames: ["db", "relations", "path", "format"],
+        );
+        
+
+@override bool crateApiFileLockCozoForceUnlock({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiFileLockCozoForceUnlockConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFileLockCozoForceUnlockConstMeta => const TaskConstMeta(
+            debugName: "cozo_force_unlock",
+            argNames: ["path"],
+        );
+        
+
+@override String crateApiFormatCozoFormatScript({required String script })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(script, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiFormatCozoFormatScriptConstMeta,
+            argValues: [script],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFormatCozoFormatScriptConstMeta => const TaskConstMeta(
+            debugName: "cozo_format_script",
+            argNames: ["script"],
+        );
+        
+
+@override Future<List<FtsHit>> crateApiSearchCozoFtsSearch({required CozoDb db , required String relation , required String index , required String query , required int limit , required int offset , SnippetConfig? snippets })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(index, serializer);
+sse_encode_String(query, serializer);
+sse_encode_u_32(limit, serializer);
+sse_encode_u_32(offset, serializer);
+sse_encode_opt_box_autoadd_snippet_config(snippets, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fts_hit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSearchCozoFtsSearchConstMeta,
+            argValues: [db, relation, index, query, limit, offset, snippets],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchCozoFtsSearchConstMeta => const TaskConstMeta(
+            debugName: "cozo_fts_search",
+            argNames: ["db", "relation", "index", "query", "limit", "offset", "snippets"],
+        );
+        
+
+@override CozoDb crateApiRegistryCozoGetNamed({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRegistryCozoGetNamedConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRegistryCozoGetNamedConstMeta => const TaskConstMeta(
+            debugName: "cozo_get_named",
+            argNames: ["name"],
+        );
+        
+
+@override Future<List<CozoValue>?> crateApiRowsCozoGetRow({required CozoDb db , required String relation , required List<CozoValue> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_list_cozo_value(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_cozo_value,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRowsCozoGetRowConstMeta,
+            argValues: [db, relation, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowsCozoGetRowConstMeta => const TaskConstMeta(
+            debugName: "cozo_get_row",
+            argNames: ["db", "relation", "key"],
+        );
+        
+
+@override Future<BigInt> crateApiImportCozoImportCsv({required CozoDb db , required String path , required CsvImportConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+sse_encode_box_autoadd_csv_import_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiImportCozoImportCsvConstMeta,
+            argValues: [db, path, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportCozoImportCsvConstMeta => const TaskConstMeta(
+            debugName: "cozo_import_csv",
+            argNames: ["db", "path", "config"],
+        );
+        
+
+@override Future<void> crateApiSimpleCozoImportFromBackup({required CozoDb db , required String path , required String relationsJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(relationsJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoImportFromBackupConstMeta,
+            argValues: [db, path, relationsJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoImportFromBackupConstMeta => const TaskConstMeta(
+            debugName: "cozo_import_from_backup",
+            argNames: ["db", "path", "relationsJson"],
+        );
+        
+
+@override Future<void> crateApiSimpleCozoImportRelations({required CozoDb db , required String dataJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(dataJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoImportRelationsConstMeta,
+            argValues: [db, dataJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoImportRelationsConstMeta => const TaskConstMeta(
+            debugName: "cozo_import_relations",
+            argNames: ["db", "dataJson"],
+        );
+        
+
+@override Future<ImportReport> crateApiImportCozoImportRelationsWithOptions({required CozoDb db , required String dataJson , required ImportOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(dataJson, serializer);
+sse_encode_box_autoadd_import_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_import_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiImportCozoImportRelationsWithOptionsConstMeta,
+            argValues: [db, dataJson, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportCozoImportRelationsWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "cozo_import_relations_with_options",
+            argNames: ["db", "dataJson", "options"],
+        );
+        
+
+@override bool crateApiSimpleCozoIsClosed({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoIsClosedConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoIsClosedConstMeta => const TaskConstMeta(
+            debugName: "cozo_is_closed",
+            argNames: ["db"],
+        );
+        
+
+@override Future<bool> crateApiRunningCozoKillQuery({required CozoDb db , required String queryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(queryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRunningCozoKillQueryConstMeta,
+            argValues: [db, queryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRunningCozoKillQueryConstMeta => const TaskConstMeta(
+            debugName: "cozo_kill_query",
+            argNames: ["db", "queryId"],
+        );
+        
+
+@override Future<List<ColumnInfo>> crateApiSchemaCozoListColumns({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_column_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoListColumnsConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoListColumnsConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_columns",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<List<EncryptedColumn>> crateApiColumnCryptoCozoListEncryptedColumns({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_encrypted_column,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiColumnCryptoCozoListEncryptedColumnsConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiColumnCryptoCozoListEncryptedColumnsConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_encrypted_columns",
+            argNames: ["db"],
+        );
+        
+
+@override Future<List<RelationInfo>> crateApiSchemaCozoListRelations({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_relation_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoListRelationsConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoListRelationsConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_relations",
+            argNames: ["db"],
+        );
+        
+
+@override List<RowPolicy> crateApiRowPolicyCozoListRowPolicies({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_row_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRowPolicyCozoListRowPoliciesConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowPolicyCozoListRowPoliciesConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_row_policies",
+            argNames: ["db"],
+        );
+        
+
+@override List<RunningQueryInfo> crateApiRunningCozoListRunning({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_running_query_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRunningCozoListRunningConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRunningCozoListRunningConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_running",
+            argNames: ["db"],
+        );
+        
+
+@override List<String> crateApiTenantCozoListTenants({required TenantManager manager })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(manager, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTenantCozoListTenantsConstMeta,
+            argValues: [manager],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantCozoListTenantsConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_tenants",
+            argNames: ["manager"],
+        );
+        
+
+@override List<TransactionInfo> crateApiTransactionCozoListTransactions({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_transaction_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTransactionCozoListTransactionsConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionCozoListTransactionsConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_transactions",
+            argNames: ["db"],
+        );
+        
+
+@override Future<TriggerSpec> crateApiTriggersCozoListTriggers({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_trigger_spec,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTriggersCozoListTriggersConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTriggersCozoListTriggersConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_triggers",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<List<TtlSetting>> crateApiTtlCozoListTtl({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ttl_setting,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTtlCozoListTtlConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTtlCozoListTtlConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_ttl",
+            argNames: ["db"],
+        );
+        
+
+@override Future<List<VectorIndexInfo>> crateApiVectorCozoListVectorIndices({required CozoDb db , String? relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_opt_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_vector_index_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiVectorCozoListVectorIndicesConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVectorCozoListVectorIndicesConstMeta => const TaskConstMeta(
+            debugName: "cozo_list_vector_indices",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Stream<String> crateApiLoggingCozoLogStream({required String level })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(level, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggingCozoLogStreamConstMeta,
+            argValues: [level, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoggingCozoLogStreamConstMeta => const TaskConstMeta(
+            debugName: "cozo_log_stream",
+            argNames: ["level", "sink"],
+        );
+        
+
+@override Future<List<LshHit>> crateApiSearchCozoLshSearch({required CozoDb db , required String relation , required String index , required String text , int? k , String? filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(index, serializer);
+sse_encode_String(text, serializer);
+sse_encode_opt_box_autoadd_u_32(k, serializer);
+sse_encode_opt_String(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_lsh_hit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSearchCozoLshSearchConstMeta,
+            argValues: [db, relation, index, text, k, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchCozoLshSearchConstMeta => const TaskConstMeta(
+            debugName: "cozo_lsh_search",
+            argNames: ["db", "relation", "index", "text", "k", "filter"],
+        );
+        
+
+@override CozoMetrics crateApiMetricsCozoMetrics({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cozo_metrics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMetricsCozoMetricsConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetricsCozoMetricsConstMeta => const TaskConstMeta(
+            debugName: "cozo_metrics",
+            argNames: ["db"],
+        );
+        
+
+@override Future<MigrationReport> crateApiMigrationCozoMigrate({required CozoDb db , required List<Migration> migrations })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_migration(migrations, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_migration_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiMigrationCozoMigrateConstMeta,
+            argValues: [db, migrations],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMigrationCozoMigrateConstMeta => const TaskConstMeta(
+            debugName: "cozo_migrate",
+            argNames: ["db", "migrations"],
+        );
+        
+
+@override Future<CozoCursor> crateApiCursorCozoOpenCursor({required CozoDb db , required String script , required String paramsJson , required int pageSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_u_32(pageSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCursorCozoOpenCursorConstMeta,
+            argValues: [db, script, paramsJson, pageSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCursorCozoOpenCursorConstMeta => const TaskConstMeta(
+            debugName: "cozo_open_cursor",
+            argNames: ["db", "script", "paramsJson", "pageSize"],
+        );
+        
+
+@override CozoDb crateApiSimpleCozoOpenDb({required String engine , required String path , required String options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(engine, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoOpenDbConstMeta,
+            argValues: [engine, path, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoOpenDbConstMeta => const TaskConstMeta(
+            debugName: "cozo_open_db",
+            argNames: ["engine", "path", "options"],
+        );
+        
+
+@override Future<CozoDb> crateApiAssetCozoOpenFromAssetBytes({required List<int> bytes , required AssetOpenMode mode , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bytes, serializer);
+sse_encode_asset_open_mode(mode, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAssetCozoOpenFromAssetBytesConstMeta,
+            argValues: [bytes, mode, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAssetCozoOpenFromAssetBytesConstMeta => const TaskConstMeta(
+            debugName: "cozo_open_from_asset_bytes",
+            argNames: ["bytes", "mode", "path"],
+        );
+        
+
+@override CozoDb crateApiRegistryCozoOpenNamed({required String name , required String engine , required String path , required String options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_String(engine, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRegistryCozoOpenNamedConstMeta,
+            argValues: [name, engine, path, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRegistryCozoOpenNamedConstMeta => const TaskConstMeta(
+            debugName: "cozo_open_named",
+            argNames: ["name", "engine", "path", "options"],
+        );
+        
+
+@override Future<RecoveredDb> crateApiRecoveryCozoOpenOrRecover({required String engine , required String path , required String options , required RecoveryPolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(engine, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(options, serializer);
+sse_encode_recovery_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_recovered_db,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRecoveryCozoOpenOrRecoverConstMeta,
+            argValues: [engine, path, options, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecoveryCozoOpenOrRecoverConstMeta => const TaskConstMeta(
+            debugName: "cozo_open_or_recover",
+            argNames: ["engine", "path", "options", "policy"],
+        );
+        
+
+@override CozoDb crateApiTenantCozoOpenTenant({required TenantManager manager , required String tenantId , String? columnKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(manager, serializer);
+sse_encode_String(tenantId, serializer);
+sse_encode_opt_String(columnKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTenantCozoOpenTenantConstMeta,
+            argValues: [manager, tenantId, columnKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantCozoOpenTenantConstMeta => const TaskConstMeta(
+            debugName: "cozo_open_tenant",
+            argNames: ["manager", "tenantId", "columnKey"],
+        );
+        
+
+@override PreparedQuery crateApiPreparedCozoPrepare({required CozoDb db , required String script })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPreparedCozoPrepareConstMeta,
+            argValues: [db, script],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPreparedCozoPrepareConstMeta => const TaskConstMeta(
+            debugName: "cozo_prepare",
+            argNames: ["db", "script"],
+        );
+        
+
+@override Future<BigInt> crateApiTtlCozoPurgeExpired({required CozoDb db })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTtlCozoPurgeExpiredConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTtlCozoPurgeExpiredConstMeta => const TaskConstMeta(
+            debugName: "cozo_purge_expired",
+            argNames: ["db"],
+        );
+        
+
+@override Stream<String> crateApiVectorCozoPutEmbeddings({required CozoDb db , required String relation , required String column , required List<Embedding> embeddings })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(column, serializer);
+sse_encode_list_embedding(embeddings, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiVectorCozoPutEmbeddingsConstMeta,
+            argValues: [db, relation, column, embeddings, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiVectorCozoPutEmbeddingsConstMeta => const TaskConstMeta(
+            debugName: "cozo_put_embeddings",
+            argNames: ["db", "relation", "column", "embeddings", "sink"],
+        );
+        
+
+@override Future<BigInt> crateApiRowsCozoPutRows({required CozoDb db , required String relation , required List<List<CozoValue>> rows })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_list_list_cozo_value(rows, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRowsCozoPutRowsConstMeta,
+            argValues: [db, relation, rows],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowsCozoPutRowsConstMeta => const TaskConstMeta(
+            debugName: "cozo_put_rows",
+            argNames: ["db", "relation", "rows"],
+        );
+        
+
+@override Future<BigInt> crateApiTemporalCozoPutValid({required CozoDb db , required String relation , required List<List<CozoValue>> rows , required DateTime validFrom , DateTime? validTo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_list_list_cozo_value(rows, serializer);
+sse_encode_Chrono_Utc(validFrom, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(validTo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTemporalCozoPutValidConstMeta,
+            argValues: [db, relation, rows, validFrom, validTo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTemporalCozoPutValidConstMeta => const TaskConstMeta(
+            debugName: "cozo_put_valid",
+            argNames: ["db", "relation", "rows", "validFrom", "validTo"],
+        );
+        
+
+@override Future<String> crateApiTemporalCozoQueryAsof({required CozoDb db , required String script , required String paramsJson , required DateTime asof })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_Chrono_Utc(asof, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTemporalCozoQueryAsofConstMeta,
+            argValues: [db, script, paramsJson, asof],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTemporalCozoQueryAsofConstMeta => const TaskConstMeta(
+            debugName: "cozo_query_asof",
+            argNames: ["db", "script", "paramsJson", "asof"],
+        );
+        
+
+@override Future<BigInt> crateApiExportCozoQueryToFile({required CozoDb db , required String script , required String paramsJson , required String path , required String format })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(format, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiExportCozoQueryToFileConstMeta,
+            argValues: [db, script, paramsJson, path, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCozoQueryToFileConstMeta => const TaskConstMeta(
+            debugName: "cozo_query_to_file",
+            argNames: ["db", "script", "paramsJson", "path", "format"],
+        );
+        
+
+@override Future<List<AuditEntry>> crateApiAuditCozoReadAudit({required CozoDb db , required AuditQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_box_autoadd_audit_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_audit_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAuditCozoReadAuditConstMeta,
+            argValues: [db, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuditCozoReadAuditConstMeta => const TaskConstMeta(
+            debugName: "cozo_read_audit",
+            argNames: ["db", "query"],
+        );
+        
+
+@override Stream<RelationChange> crateApiCallbackCozoRegisterCallback({required CozoDb db , required String relation })  { 
+            final sink = RustStreamSink<RelationChange>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_StreamSink_relation_change_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCallbackCozoRegisterCallbackConstMeta,
+            argValues: [db, relation, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCallbackCozoRegisterCallbackConstMeta => const TaskConstMeta(
+            debugName: "cozo_register_callback",
+            argNames: ["db", "relation", "sink"],
+        );
+        
+
+@override Future<void> crateApiFixedRuleCozoRegisterFixedRule({required CozoDb db , required String name , required BigInt arity , required FutureOr<String> Function(String, String) rule })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+sse_encode_usize(arity, serializer);
+sse_encode_DartFn_Inputs_String_String_Output_String_AnyhowException(rule, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiFixedRuleCozoRegisterFixedRuleConstMeta,
+            argValues: [db, name, arity, rule],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFixedRuleCozoRegisterFixedRuleConstMeta => const TaskConstMeta(
+            debugName: "cozo_register_fixed_rule",
+            argNames: ["db", "name", "arity", "rule"],
+        );
+        
+
+@override Stream<String> crateApiColumnCryptoCozoRekey({required CozoDb db , required String newKey })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(newKey, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiColumnCryptoCozoRekeyConstMeta,
+            argValues: [db, newKey, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiColumnCryptoCozoRekeyConstMeta => const TaskConstMeta(
+            debugName: "cozo_rekey",
+            argNames: ["db", "newKey", "sink"],
+        );
+        
+
+@override Future<RelationStats> crateApiStatsCozoRelationStats({required CozoDb db , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_relation_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStatsCozoRelationStatsConstMeta,
+            argValues: [db, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStatsCozoRelationStatsConstMeta => const TaskConstMeta(
+            debugName: "cozo_relation_stats",
+            argNames: ["db", "name"],
+        );
+        
+
+@override bool crateApiRegistryCozoReleaseToken({required BigInt token })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(token, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRegistryCozoReleaseTokenConstMeta,
+            argValues: [token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRegistryCozoReleaseTokenConstMeta => const TaskConstMeta(
+            debugName: "cozo_release_token",
+            argNames: ["token"],
+        );
+        
+
+@override bool crateApiRowPolicyCozoRemoveRowPolicy({required CozoDb db , required String relation })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRowPolicyCozoRemoveRowPolicyConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowPolicyCozoRemoveRowPolicyConstMeta => const TaskConstMeta(
+            debugName: "cozo_remove_row_policy",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<void> crateApiSchemaCozoRenameRelation({required CozoDb db , required String name , required String newName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(newName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoRenameRelationConstMeta,
+            argValues: [db, name, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoRenameRelationConstMeta => const TaskConstMeta(
+            debugName: "cozo_rename_relation",
+            argNames: ["db", "name", "newName"],
+        );
+        
+
+@override void crateApiMetricsCozoResetMetrics({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMetricsCozoResetMetricsConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetricsCozoResetMetricsConstMeta => const TaskConstMeta(
+            debugName: "cozo_reset_metrics",
+            argNames: ["db"],
+        );
+        
+
+@override Future<void> crateApiSimpleCozoRestore({required CozoDb db , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoRestoreConstMeta,
+            argValues: [db, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoRestoreConstMeta => const TaskConstMeta(
+            debugName: "cozo_restore",
+            argNames: ["db", "path"],
+        );
+        
+
+@override Future<BigInt> crateApiSoftDeleteCozoRestoreDeleted({required CozoDb db , required String relation , required List<List<CozoValue>> keys })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_list_list_cozo_value(keys, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSoftDeleteCozoRestoreDeletedConstMeta,
+            argValues: [db, relation, keys],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSoftDeleteCozoRestoreDeletedConstMeta => const TaskConstMeta(
+            debugName: "cozo_restore_deleted",
+            argNames: ["db", "relation", "keys"],
+        );
+        
+
+@override Future<void> crateApiBackupCozoRestoreEncrypted({required CozoDb db , required String path , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(passphrase, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoRestoreEncryptedConstMeta,
+            argValues: [db, path, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackupCozoRestoreEncryptedConstMeta => const TaskConstMeta(
+            debugName: "cozo_restore_encrypted",
+            argNames: ["db", "path", "passphrase"],
+        );
+        
+
+@override Future<void> crateApiBackupCozoRestoreFromBytes({required CozoDb db , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoRestoreFromBytesConstMeta,
+            argValues: [db, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackupCozoRestoreFromBytesConstMeta => const TaskConstMeta(
+            debugName: "cozo_restore_from_bytes",
+            argNames: ["db", "data"],
+        );
+        
+
+@override Future<List<String>> crateApiIncrementalCozoRestoreIncremental({required CozoDb db , required String dir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(dir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiIncrementalCozoRestoreIncrementalConstMeta,
+            argValues: [db, dir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalCozoRestoreIncrementalConstMeta => const TaskConstMeta(
+            debugName: "cozo_restore_incremental",
+            argNames: ["db", "dir"],
+        );
+        
+
+@override Stream<String> crateApiBackupCozoRestoreWithProgress({required CozoDb db , required String path })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoRestoreWithProgressConstMeta,
+            argValues: [db, path, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiBackupCozoRestoreWithProgressConstMeta => const TaskConstMeta(
+            debugName: "cozo_restore_with_progress",
+            argNames: ["db", "path", "sink"],
+        );
+        
+
+@override Future<BigInt> crateApiTemporalCozoRetractRows({required CozoDb db , required String relation , required List<List<CozoValue>> keys , required DateTime at })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_list_list_cozo_value(keys, serializer);
+sse_encode_Chrono_Utc(at, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTemporalCozoRetractRowsConstMeta,
+            argValues: [db, relation, keys, at],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTemporalCozoRetractRowsConstMeta => const TaskConstMeta(
+            debugName: "cozo_retract_rows",
+            argNames: ["db", "relation", "keys", "at"],
+        );
+        
+
+@override Future<List<QueryResult>> crateApiBatchCozoRunBatch({required CozoDb db , required List<ScriptWithParams> scripts , required bool atomic })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_script_with_params(scripts, serializer);
+sse_encode_bool(atomic, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_query_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBatchCozoRunBatchConstMeta,
+            argValues: [db, scripts, atomic],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchCozoRunBatchConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_batch",
+            argNames: ["db", "scripts", "atomic"],
+        );
+        
+
+@override Future<String> crateApiPreparedCozoRunPrepared({required PreparedQuery prepared , required String paramsJson , required bool immutable })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(prepared, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_bool(immutable, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPreparedCozoRunPreparedConstMeta,
+            argValues: [prepared, paramsJson, immutable],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPreparedCozoRunPreparedConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_prepared",
+            argNames: ["prepared", "paramsJson", "immutable"],
+        );
+        
+
+@override Future<String> crateApiSimpleCozoRunQuery({required CozoDb db , required String script , required String paramsJson , required bool immutable })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_bool(immutable, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoRunQueryConstMeta,
+            argValues: [db, script, paramsJson, immutable],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoRunQueryConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query",
+            argNames: ["db", "script", "paramsJson", "immutable"],
+        );
+        
+
+@override Future<Uint8List> crateApiExportCozoRunQueryArrow({required CozoDb db , required String script , required String paramsJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiExportCozoRunQueryArrowConstMeta,
+            argValues: [db, script, paramsJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCozoRunQueryArrowConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_arrow",
+            argNames: ["db", "script", "paramsJson"],
+        );
+        
+
+@override Future<String> crateApiQueryCacheCozoRunQueryCached({required CozoDb db , required String script , required String paramsJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiQueryCacheCozoRunQueryCachedConstMeta,
+            argValues: [db, script, paramsJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryCacheCozoRunQueryCachedConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_cached",
+            argNames: ["db", "script", "paramsJson"],
+        );
+        
+
+@override Future<String> crateApiSimpleCozoRunQueryChecked({required CozoDb db , required String script , required String paramsJson , required bool immutable })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_bool(immutable, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cozo_dart_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoRunQueryCheckedConstMeta,
+            argValues: [db, script, paramsJson, immutable],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoRunQueryCheckedConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_checked",
+            argNames: ["db", "script", "paramsJson", "immutable"],
+        );
+        
+
+@override Future<Uint8List> crateApiEncodingCozoRunQueryEncoded({required CozoDb db , required String script , required String paramsJson , required bool immutable , required ResultEncoding encoding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_bool(immutable, serializer);
+sse_encode_result_encoding(encoding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEncodingCozoRunQueryEncodedConstMeta,
+            argValues: [db, script, paramsJson, immutable, encoding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingCozoRunQueryEncodedConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_encoded",
+            argNames: ["db", "script", "paramsJson", "immutable", "encoding"],
+        );
+        
+
+@override Stream<String> crateApiStreamCozoRunQueryStream({required CozoDb db , required String script , required String paramsJson , required int chunkSize , required bool immutable })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_u_32(chunkSize, serializer);
+sse_encode_bool(immutable, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStreamCozoRunQueryStreamConstMeta,
+            argValues: [db, script, paramsJson, chunkSize, immutable, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiStreamCozoRunQueryStreamConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_stream",
+            argNames: ["db", "script", "paramsJson", "chunkSize", "immutable", "sink"],
+        );
+        
+
+@override Future<QueryResult> crateApiSimpleCozoRunQueryTyped({required CozoDb db , required String script , required Map<String, CozoValue> params , required bool immutable })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_Map_String_cozo_value_None(params, serializer);
+sse_encode_bool(immutable, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_query_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleCozoRunQueryTypedConstMeta,
+            argValues: [db, script, params, immutable],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleCozoRunQueryTypedConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_typed",
+            argNames: ["db", "script", "params", "immutable"],
+        );
+        
+
+@override Future<String> crateApiRunningCozoRunQueryWithOptions({required CozoDb db , required String script , required String paramsJson , required QueryOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_box_autoadd_query_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRunningCozoRunQueryWithOptionsConstMeta,
+            argValues: [db, script, paramsJson, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRunningCozoRunQueryWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_with_options",
+            argNames: ["db", "script", "paramsJson", "options"],
+        );
+        
+
+@override Stream<String> crateApiStreamCozoRunQueryWithProgress({required CozoDb db , required String script , required String paramsJson , required bool immutable })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_bool(immutable, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStreamCozoRunQueryWithProgressConstMeta,
+            argValues: [db, script, paramsJson, immutable, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiStreamCozoRunQueryWithProgressConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_query_with_progress",
+            argNames: ["db", "script", "paramsJson", "immutable", "sink"],
+        );
+        
+
+@override Future<String> crateApiRunningCozoRunTrackedQuery({required CozoDb db , required String queryId , required String script , required String paramsJson , required bool immutable })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(queryId, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_bool(immutable, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRunningCozoRunTrackedQueryConstMeta,
+            argValues: [db, queryId, script, paramsJson, immutable],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRunningCozoRunTrackedQueryConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_tracked_query",
+            argNames: ["db", "queryId", "script", "paramsJson", "immutable"],
+        );
+        
+
+@override Future<List<QueryResult>> crateApiRetryCozoRunTxWithRetry({required CozoDb db , required List<ScriptWithParams> scripts , required RetryPolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_script_with_params(scripts, serializer);
+sse_encode_box_autoadd_retry_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_query_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRetryCozoRunTxWithRetryConstMeta,
+            argValues: [db, scripts, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRetryCozoRunTxWithRetryConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_tx_with_retry",
+            argNames: ["db", "scripts", "policy"],
+        );
+        
+
+@override Future<String> crateApiRetryCozoRunWithRetry({required CozoDb db , required String script , required String paramsJson , required RetryPolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_box_autoadd_retry_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRetryCozoRunWithRetryConstMeta,
+            argValues: [db, script, paramsJson, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRetryCozoRunWithRetryConstMeta => const TaskConstMeta(
+            debugName: "cozo_run_with_retry",
+            argNames: ["db", "script", "paramsJson", "policy"],
+        );
+        
+
+@override Future<SchemaDiff> crateApiSchemaCozoSchemaDiff({required CozoDb a , required CozoDb b })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(a, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(b, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_schema_diff,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoSchemaDiffConstMeta,
+            argValues: [a, b],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoSchemaDiffConstMeta => const TaskConstMeta(
+            debugName: "cozo_schema_diff",
+            argNames: ["a", "b"],
+        );
+        
+
+@override Future<SchemaDiff> crateApiSchemaCozoSchemaDiffBackup({required CozoDb db , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_schema_diff,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSchemaCozoSchemaDiffBackupConstMeta,
+            argValues: [db, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSchemaCozoSchemaDiffBackupConstMeta => const TaskConstMeta(
+            debugName: "cozo_schema_diff_backup",
+            argNames: ["db", "path"],
+        );
+        
+
+@override void crateApiBusyRetryCozoSetBusyRetry({required CozoDb db , BusyRetryPolicy? policy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_opt_box_autoadd_busy_retry_policy(policy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBusyRetryCozoSetBusyRetryConstMeta,
+            argValues: [db, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBusyRetryCozoSetBusyRetryConstMeta => const TaskConstMeta(
+            debugName: "cozo_set_busy_retry",
+            argNames: ["db", "policy"],
+        );
+        
+
+@override void crateApiRowPolicyCozoSetPolicyParams({required CozoDb db , required String paramsJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(paramsJson, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRowPolicyCozoSetPolicyParamsConstMeta,
+            argValues: [db, paramsJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowPolicyCozoSetPolicyParamsConstMeta => const TaskConstMeta(
+            debugName: "cozo_set_policy_params",
+            argNames: ["db", "paramsJson"],
+        );
+        
+
+@override Future<void> crateApiRowPolicyCozoSetRowPolicy({required CozoDb db , required String relation , required String predicate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(predicate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRowPolicyCozoSetRowPolicyConstMeta,
+            argValues: [db, relation, predicate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowPolicyCozoSetRowPolicyConstMeta => const TaskConstMeta(
+            debugName: "cozo_set_row_policy",
+            argNames: ["db", "relation", "predicate"],
+        );
+        
+
+@override Future<void> crateApiTriggersCozoSetTriggers({required CozoDb db , required String relation , required TriggerSpec spec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_box_autoadd_trigger_spec(spec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTriggersCozoSetTriggersConstMeta,
+            argValues: [db, relation, spec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTriggersCozoSetTriggersConstMeta => const TaskConstMeta(
+            debugName: "cozo_set_triggers",
+            argNames: ["db", "relation", "spec"],
+        );
+        
+
+@override Future<void> crateApiTtlCozoSetTtl({required CozoDb db , required String relation , required String ttlColumn , required double ttlSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(ttlColumn, serializer);
+sse_encode_f_64(ttlSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTtlCozoSetTtlConstMeta,
+            argValues: [db, relation, ttlColumn, ttlSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTtlCozoSetTtlConstMeta => const TaskConstMeta(
+            debugName: "cozo_set_ttl",
+            argNames: ["db", "relation", "ttlColumn", "ttlSecs"],
+        );
+        
+
+@override BigInt crateApiRegistryCozoShareToken({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRegistryCozoShareTokenConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRegistryCozoShareTokenConstMeta => const TaskConstMeta(
+            debugName: "cozo_share_token",
+            argNames: ["db"],
+        );
+        
+
+@override List<SlowQuery> crateApiSlowLogCozoSlowQueries({required CozoDb db })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_slow_query,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSlowLogCozoSlowQueriesConstMeta,
+            argValues: [db],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSlowLogCozoSlowQueriesConstMeta => const TaskConstMeta(
+            debugName: "cozo_slow_queries",
+            argNames: ["db"],
+        );
+        
+
+@override Future<SyncReport> crateApiSyncCozoSyncApplyChanges({required CozoDb db , required String payload })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(payload, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSyncCozoSyncApplyChangesConstMeta,
+            argValues: [db, payload],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncCozoSyncApplyChangesConstMeta => const TaskConstMeta(
+            debugName: "cozo_sync_apply_changes",
+            argNames: ["db", "payload"],
+        );
+        
+
+@override Future<SyncReport> crateApiSyncCozoSyncApplyChangesWithResolver({required CozoDb db , required String payload , required FutureOr<String> Function(String) resolve })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(payload, serializer);
+sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(resolve, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSyncCozoSyncApplyChangesWithResolverConstMeta,
+            argValues: [db, payload, resolve],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncCozoSyncApplyChangesWithResolverConstMeta => const TaskConstMeta(
+            debugName: "cozo_sync_apply_changes_with_resolver",
+            argNames: ["db", "payload", "resolve"],
+        );
+        
+
+@override Future<void> crateApiSyncCozoSyncDisable({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSyncCozoSyncDisableConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncCozoSyncDisableConstMeta => const TaskConstMeta(
+            debugName: "cozo_sync_disable",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<void> crateApiSyncCozoSyncEnable({required CozoDb db , required List<String> relations })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_String(relations, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSyncCozoSyncEnableConstMeta,
+            argValues: [db, relations],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncCozoSyncEnableConstMeta => const TaskConstMeta(
+            debugName: "cozo_sync_enable",
+            argNames: ["db", "relations"],
+        );
+        
+
+@override Future<SyncChangeset> crateApiSyncCozoSyncExportChanges({required CozoDb db , required String sinceToken })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(sinceToken, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_changeset,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSyncCozoSyncExportChangesConstMeta,
+            argValues: [db, sinceToken],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncCozoSyncExportChangesConstMeta => const TaskConstMeta(
+            debugName: "cozo_sync_export_changes",
+            argNames: ["db", "sinceToken"],
+        );
+        
+
+@override TenantManager crateApiTenantCozoTenantManager({required String root , required String engine , required String options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(root, serializer);
+sse_encode_String(engine, serializer);
+sse_encode_String(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTenantCozoTenantManagerConstMeta,
+            argValues: [root, engine, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantCozoTenantManagerConstMeta => const TaskConstMeta(
+            debugName: "cozo_tenant_manager",
+            argNames: ["root", "engine", "options"],
+        );
+        
+
+@override Future<int> crateApiCallbackCozoUnregisterCallbacks({required CozoDb db , required String relation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCallbackCozoUnregisterCallbacksConstMeta,
+            argValues: [db, relation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCallbackCozoUnregisterCallbacksConstMeta => const TaskConstMeta(
+            debugName: "cozo_unregister_callbacks",
+            argNames: ["db", "relation"],
+        );
+        
+
+@override Future<bool> crateApiFixedRuleCozoUnregisterFixedRule({required CozoDb db , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiFixedRuleCozoUnregisterFixedRuleConstMeta,
+            argValues: [db, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFixedRuleCozoUnregisterFixedRuleConstMeta => const TaskConstMeta(
+            debugName: "cozo_unregister_fixed_rule",
+            argNames: ["db", "name"],
+        );
+        
+
+@override Future<BigInt> crateApiRowsCozoUpsertRows({required CozoDb db , required String relation , required List<List<CozoValue>> rows , required OnConflict onConflict })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_list_list_cozo_value(rows, serializer);
+sse_encode_on_conflict(onConflict, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRowsCozoUpsertRowsConstMeta,
+            argValues: [db, relation, rows, onConflict],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRowsCozoUpsertRowsConstMeta => const TaskConstMeta(
+            debugName: "cozo_upsert_rows",
+            argNames: ["db", "relation", "rows", "onConflict"],
+        );
+        
+
+@override ValidationResult crateApiValidateCozoValidateScript({required CozoDb db , required String script })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_validation_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiValidateCozoValidateScriptConstMeta,
+            argValues: [db, script],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiValidateCozoValidateScriptConstMeta => const TaskConstMeta(
+            debugName: "cozo_validate_script",
+            argNames: ["db", "script"],
+        );
+        
+
+@override CozoValue crateApiTemporalCozoValidityFromTime({required ValidityTime time })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_validity_time(time, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cozo_value,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTemporalCozoValidityFromTimeConstMeta,
+            argValues: [time],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTemporalCozoValidityFromTimeConstMeta => const TaskConstMeta(
+            debugName: "cozo_validity_from_time",
+            argNames: ["time"],
+        );
+        
+
+@override ValidityTime crateApiTemporalCozoValidityToTime({required CozoValue value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_cozo_value(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_validity_time,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTemporalCozoValidityToTimeConstMeta,
+            argValues: [value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTemporalCozoValidityToTimeConstMeta => const TaskConstMeta(
+            debugName: "cozo_validity_to_time",
+            argNames: ["value"],
+        );
+        
+
+@override Future<List<VectorHit>> crateApiVectorCozoVectorSearch({required CozoDb db , required String relation , required String index , required List<double> query , required int k , required int ef , String? filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(relation, serializer);
+sse_encode_String(index, serializer);
+sse_encode_list_prim_f_32_loose(query, serializer);
+sse_encode_u_32(k, serializer);
+sse_encode_u_32(ef, serializer);
+sse_encode_opt_String(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_vector_hit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiVectorCozoVectorSearchConstMeta,
+            argValues: [db, relation, index, query, k, ef, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVectorCozoVectorSearchConstMeta => const TaskConstMeta(
+            debugName: "cozo_vector_search",
+            argNames: ["db", "relation", "index", "query", "k", "ef", "filter"],
+        );
+        
+
+@override Future<BackupInfo> crateApiBackupCozoVerifyBackup({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_backup_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBackupCozoVerifyBackupConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackupCozoVerifyBackupConstMeta => const TaskConstMeta(
+            debugName: "cozo_verify_backup",
+            argNames: ["path"],
+        );
+        
+
+@override Stream<String> crateApiCallbackCozoWatchQuery({required CozoDb db , required String script , required String paramsJson })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCallbackCozoWatchQueryConstMeta,
+            argValues: [db, script, paramsJson, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCallbackCozoWatchQueryConstMeta => const TaskConstMeta(
+            debugName: "cozo_watch_query",
+            argNames: ["db", "script", "paramsJson", "sink"],
+        );
+        
+
+@override Stream<String> crateApiCallbackCozoWatchRelations({required CozoDb db , required List<String> relations })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(db, serializer);
+sse_encode_list_String(relations, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCallbackCozoWatchRelationsConstMeta,
+            argValues: [db, relations, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCallbackCozoWatchRelationsConstMeta => const TaskConstMeta(
+            debugName: "cozo_watch_relations",
+            argNames: ["db", "relations", "sink"],
+        );
+        
+
+@override Future<CrdtMergeReport> crateApiCrdtCrdtMergeReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_crdt_merge_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCrdtCrdtMergeReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrdtCrdtMergeReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "crdt_merge_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<ImportReport> crateApiImportImportReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_import_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiImportImportReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportImportReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "import_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<SyncReport> crateApiSyncSyncReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSyncSyncReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncSyncReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "sync_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<TriggerSpec> crateApiTriggersTriggerSpecDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_trigger_spec,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTriggersTriggerSpecDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTriggersTriggerSpecDefaultConstMeta => const TaskConstMeta(
+            debugName: "trigger_spec_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTransactionTxAbort({required CozoTx tx })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(tx, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionTxAbortConstMeta,
+            argValues: [tx],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionTxAbortConstMeta => const TaskConstMeta(
+            debugName: "tx_abort",
+            argNames: ["tx"],
+        );
+        
+
+@override Future<void> crateApiTransactionTxCommit({required CozoTx tx })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(tx, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionTxCommitConstMeta,
+            argValues: [tx],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionTxCommitConstMeta => const TaskConstMeta(
+            debugName: "tx_commit",
+            argNames: ["tx"],
+        );
+        
+
+@override void crateApiTransactionTxRelease({required CozoTx tx , required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(tx, serializer);
+sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionTxReleaseConstMeta,
+            argValues: [tx, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionTxReleaseConstMeta => const TaskConstMeta(
+            debugName: "tx_release",
+            argNames: ["tx", "name"],
+        );
+        
+
+@override Future<void> crateApiTransactionTxRollbackTo({required CozoTx tx , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(tx, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionTxRollbackToConstMeta,
+            argValues: [tx, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionTxRollbackToConstMeta => const TaskConstMeta(
+            debugName: "tx_rollback_to",
+            argNames: ["tx", "name"],
+        );
+        
+
+@override Future<String> crateApiTransactionTxRunQuery({required CozoTx tx , required String script , required String paramsJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(tx, serializer);
+sse_encode_String(script, serializer);
+sse_encode_String(paramsJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionTxRunQueryConstMeta,
+            argValues: [tx, script, paramsJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionTxRunQueryConstMeta => const TaskConstMeta(
+            debugName: "tx_run_query",
+            argNames: ["tx", "script", "paramsJson"],
+        );
+        
+
+@override void crateApiTransactionTxSavepoint({required CozoTx tx , required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(tx, serializer);
+sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTransactionTxSavepointConstMeta,
+            argValues: [tx, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransactionTxSavepointConstMeta => const TaskConstMeta(
+            debugName: "tx_savepoint",
+            argNames: ["tx", "name"],
+        );
+        
+
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_String_AnyhowException(FutureOr<String> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<String>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_String_String_Output_String_AnyhowException(FutureOr<String> Function(String, String) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_String(rawArg0);
+final arg1 = dco_decode_String(rawArg1);
+
+
+                Box<String>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, )
+                encode_DartFn_Inputs__Output_String_AnyhowException(FutureOr<String> Function() raw) {
+              return (callId, ) async {
+                
+
+                Box<String>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw());
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Auditor => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Auditor => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CozoCursor => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CozoCursor => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CozoDb => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CozoDb => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CozoTx => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CozoTx => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PreparedQuery => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PreparedQuery => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RowPolicies => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RowPolicies => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SlowLog => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SlowLog => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TenantManager => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TenantManager => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected Auditor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AuditorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoCursor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoCursorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoDb dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoDbImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoTx dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoTxImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PreparedQuery dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PreparedQueryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RowPolicies dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RowPoliciesImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SlowLog dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SlowLogImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TenantManager dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TenantManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoCursor dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoCursorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoDb dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoDbImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoTx dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoTxImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PreparedQuery dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PreparedQueryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TenantManager dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TenantManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected DateTime dco_decode_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true); }
+
+@protected FutureOr<String> Function(String) dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<String> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<String> Function() dco_decode_DartFn_Inputs__Output_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
+@protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_string(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, CozoValue> dco_decode_Map_String_cozo_value_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_cozo_value(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Auditor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AuditorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoCursor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoCursorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoDb dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoDbImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CozoTx dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CozoTxImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PreparedQuery dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PreparedQueryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RowPolicies dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RowPoliciesImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SlowLog dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SlowLogImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TenantManager dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TenantManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<RelationChange> dco_decode_StreamSink_relation_change_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected Encoder dco_decode_TraitDef_Encoder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected AssetOpenMode dco_decode_asset_open_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AssetOpenMode.values[raw as int]; }
+
+@protected AuditEntry dco_decode_audit_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return AuditEntry(seq: dco_decode_i_64(arr[0]),
+at: dco_decode_f_64(arr[1]),
+actor: dco_decode_String(arr[2]),
+relation: dco_decode_String(arr[3]),
+op: dco_decode_cdc_op(arr[4]),
+key: dco_decode_list_cozo_value(arr[5]),
+old: dco_decode_opt_list_cozo_value(arr[6]),
+new_: dco_decode_opt_list_cozo_value(arr[7]),); }
+
+@protected AuditQuery dco_decode_audit_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return AuditQuery(relation: dco_decode_opt_String(arr[0]),
+actor: dco_decode_opt_String(arr[1]),
+since: dco_decode_opt_box_autoadd_f_64(arr[2]),
+until: dco_decode_opt_box_autoadd_f_64(arr[3]),
+fromSeq: dco_decode_i_64(arr[4]),
+limit: dco_decode_u_32(arr[5]),); }
+
+@protected BackupCompression dco_decode_backup_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return BackupCompression_None();
+case 1: return BackupCompression_Zstd(level: dco_decode_i_32(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected BackupInfo dco_decode_backup_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BackupInfo(relations: dco_decode_list_String(arr[0]),
+rowCounts: dco_decode_list_prim_u_64_strict(arr[1]),
+createdAt: dco_decode_opt_box_autoadd_f_64(arr[2]),
+checksumOk: dco_decode_bool(arr[3]),); }
+
+@protected BackupPolicy dco_decode_backup_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BackupPolicy(intervalSecs: dco_decode_u_64(arr[0]),
+dir: dco_decode_String(arr[1]),
+keepN: dco_decode_u_32(arr[2]),
+onlyOnIdle: dco_decode_bool(arr[3]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Chrono_Utc(raw); }
+
+@protected AuditQuery dco_decode_box_autoadd_audit_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_audit_query(raw); }
+
+@protected BackupCompression dco_decode_box_autoadd_backup_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_backup_compression(raw); }
+
+@protected BackupPolicy dco_decode_box_autoadd_backup_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_backup_policy(raw); }
+
+@protected BusyRetryPolicy dco_decode_box_autoadd_busy_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_busy_retry_policy(raw); }
+
+@protected ColumnDefault dco_decode_box_autoadd_column_default(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_column_default(raw); }
+
+@protected CozoDartError dco_decode_box_autoadd_cozo_dart_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cozo_dart_error(raw); }
+
+@protected CozoValue dco_decode_box_autoadd_cozo_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cozo_value(raw); }
+
+@protected CsvImportConfig dco_decode_box_autoadd_csv_import_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_csv_import_config(raw); }
+
+@protected ErrorSpan dco_decode_box_autoadd_error_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_error_span(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FtsConfig dco_decode_box_autoadd_fts_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_fts_config(raw); }
+
+@protected HnswConfig dco_decode_box_autoadd_hnsw_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_hnsw_config(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected ImportOptions dco_decode_box_autoadd_import_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_import_options(raw); }
+
+@protected LockHolder dco_decode_box_autoadd_lock_holder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lock_holder(raw); }
+
+@protected LshConfig dco_decode_box_autoadd_lsh_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lsh_config(raw); }
+
+@protected QueryOptions dco_decode_box_autoadd_query_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query_options(raw); }
+
+@protected RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_retry_policy(raw); }
+
+@protected SnippetConfig dco_decode_box_autoadd_snippet_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_snippet_config(raw); }
+
+@protected TriggerSpec dco_decode_box_autoadd_trigger_spec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_trigger_spec(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected ValidityTime dco_decode_box_autoadd_validity_time(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_validity_time(raw); }
+
+@protected BusyRetryPolicy dco_decode_busy_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BusyRetryPolicy(maxAttempts: dco_decode_u_32(arr[0]),
+initialDelayMs: dco_decode_u_64(arr[1]),
+multiplier: dco_decode_f_64(arr[2]),
+maxDelayMs: dco_decode_u_64(arr[3]),); }
+
+@protected CdcEntry dco_decode_cdc_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CdcEntry(tx: dco_decode_i_64(arr[0]),
+relation: dco_decode_String(arr[1]),
+op: dco_decode_cdc_op(arr[2]),
+key: dco_decode_list_cozo_value(arr[3]),
+at: dco_decode_f_64(arr[4]),); }
+
+@protected CdcOp dco_decode_cdc_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CdcOp.values[raw as int]; }
+
+@protected ChangeOp dco_decode_change_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChangeOp.values[raw as int]; }
+
+@protected ColumnDef dco_decode_column_def(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ColumnDef(name: dco_decode_String(arr[0]),
+columnType: dco_decode_String(arr[1]),
+nullable: dco_decode_bool(arr[2]),
+isKey: dco_decode_bool(arr[3]),
+default_: dco_decode_opt_box_autoadd_column_default(arr[4]),); }
+
+@protected ColumnDefault dco_decode_column_default(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ColumnDefault_Value(dco_decode_box_autoadd_cozo_value(raw[1]),);
+case 1: return ColumnDefault_Expr(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ColumnInfo dco_decode_column_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ColumnInfo(name: dco_decode_String(arr[0]),
+columnType: dco_decode_String(arr[1]),
+nullable: dco_decode_bool(arr[2]),
+isKey: dco_decode_bool(arr[3]),
+index: dco_decode_u_32(arr[4]),
+hasDefault: dco_decode_bool(arr[5]),); }
+
+@protected CozoDartError dco_decode_cozo_dart_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CozoDartError(kind: dco_decode_error_kind(arr[0]),
+message: dco_decode_String(arr[1]),
+code: dco_decode_opt_String(arr[2]),
+span: dco_decode_opt_box_autoadd_error_span(arr[3]),
+relation: dco_decode_opt_String(arr[4]),
+lockHolder: dco_decode_opt_box_autoadd_lock_holder(arr[5]),); }
+
+@protected CozoMetrics dco_decode_cozo_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return CozoMetrics(since: dco_decode_f_64(arr[0]),
+queries: dco_decode_u_64(arr[1]),
+writeQueries: dco_decode_u_64(arr[2]),
+rowsRead: dco_decode_u_64(arr[3]),
+rowsWritten: dco_decode_u_64(arr[4]),
+errors: dco_decode_u_64(arr[5]),
+errorsByKind: dco_decode_list_error_count(arr[6]),
+latencyP50Ms: dco_decode_opt_box_autoadd_f_64(arr[7]),
+latencyP99Ms: dco_decode_opt_box_autoadd_f_64(arr[8]),
+latencyMeanMs: dco_decode_opt_box_autoadd_f_64(arr[9]),
+latencyBuckets: dco_decode_list_latency_bucket(arr[10]),
+cacheHits: dco_decode_u_64(arr[11]),
+cacheMisses: dco_decode_u_64(arr[12]),
+cacheHitRate: dco_decode_opt_box_autoadd_f_64(arr[13]),); }
+
+@protected CozoValue dco_decode_cozo_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return CozoValue_Null();
+case 1: return CozoValue_Bool(dco_decode_bool(raw[1]),);
+case 2: return CozoValue_Int(dco_decode_i_64(raw[1]),);
+case 3: return CozoValue_Float(dco_decode_f_64(raw[1]),);
+case 4: return CozoValue_Str(dco_decode_String(raw[1]),);
+case 5: return CozoValue_Bytes(dco_decode_list_prim_u_8_strict(raw[1]),);
+case 6: return CozoValue_Uuid(dco_decode_String(raw[1]),);
+case 7: return CozoValue_List(dco_decode_list_cozo_value(raw[1]),);
+case 8: return CozoValue_F32Vector(dco_decode_list_prim_f_32_strict(raw[1]),);
+case 9: return CozoValue_F64Vector(dco_decode_list_prim_f_64_strict(raw[1]),);
+case 10: return CozoValue_Json(dco_decode_String(raw[1]),);
+case 11: return CozoValue_Validity(timestamp: dco_decode_i_64(raw[1]),isAssert: dco_decode_bool(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected CrdtMergeReport dco_decode_crdt_merge_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CrdtMergeReport(upserted: dco_decode_u_64(arr[0]),
+deleted: dco_decode_u_64(arr[1]),); }
+
+@protected CsvImportConfig dco_decode_csv_import_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CsvImportConfig(relation: dco_decode_String(arr[0]),
+columnMap: dco_decode_Map_String_String_None(arr[1]),
+delimiter: dco_decode_String(arr[2]),
+batchSize: dco_decode_u_32(arr[3]),
+emptyAsNull: dco_decode_bool(arr[4]),
+trim: dco_decode_bool(arr[5]),); }
+
+@protected CursorPage dco_decode_cursor_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CursorPage(headers: dco_decode_list_String(arr[0]),
+rows: dco_decode_list_list_cozo_value(arr[1]),
+offset: dco_decode_u_64(arr[2]),
+last: dco_decode_bool(arr[3]),); }
+
+@protected DbLocation dco_decode_db_location(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DbLocation.values[raw as int]; }
+
+@protected DbStats dco_decode_db_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return DbStats(engine: dco_decode_String(arr[0]),
+fileBytes: dco_decode_u_64(arr[1]),
+walBytes: dco_decode_u_64(arr[2]),
+pageSize: dco_decode_opt_box_autoadd_u_64(arr[3]),
+pageCount: dco_decode_opt_box_autoadd_u_64(arr[4]),
+freePages: dco_decode_opt_box_autoadd_u_64(arr[5]),
+relations: dco_decode_u_32(arr[6]),); }
+
+@protected DiagnosticSeverity dco_decode_diagnostic_severity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DiagnosticSeverity.values[raw as int]; }
+
+@protected Embedding dco_decode_embedding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Embedding(key: dco_decode_list_cozo_value(arr[0]),
+vector: dco_decode_list_prim_f_32_strict(arr[1]),); }
+
+@protected EncryptedColumn dco_decode_encrypted_column(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return EncryptedColumn(relation: dco_decode_String(arr[0]),
+column: dco_decode_String(arr[1]),); }
+
+@protected EnsureRelationReport dco_decode_ensure_relation_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return EnsureRelationReport(created: dco_decode_bool(arr[0]),
+mismatches: dco_decode_list_schema_mismatch(arr[1]),); }
+
+@protected ErrorCount dco_decode_error_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ErrorCount(kind: dco_decode_error_kind(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected ErrorKind dco_decode_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ErrorKind.values[raw as int]; }
+
+@protected ErrorSpan dco_decode_error_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ErrorSpan(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FtsConfig dco_decode_fts_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FtsConfig(tokenizer: dco_decode_fts_tokenizer(arr[0]),
+filters: dco_decode_list_fts_filter(arr[1]),
+stopwordsLang: dco_decode_opt_String(arr[2]),); }
+
+@protected FtsFilter dco_decode_fts_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FtsFilter_Lowercase();
+case 1: return FtsFilter_AlphaNumOnly();
+case 2: return FtsFilter_AsciiFolding();
+case 3: return FtsFilter_RemoveLong(limit: dco_decode_u_32(raw[1]),);
+case 4: return FtsFilter_Stemmer(language: dco_decode_String(raw[1]),);
+case 5: return FtsFilter_Stopwords(language: dco_decode_String(raw[1]),);
+case 6: return FtsFilter_StopwordList(words: dco_decode_list_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected FtsHit dco_decode_fts_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FtsHit(key: dco_decode_list_cozo_value(arr[0]),
+score: dco_decode_f_64(arr[1]),
+snippet: dco_decode_opt_String(arr[2]),); }
+
+@protected FtsTokenizer dco_decode_fts_tokenizer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FtsTokenizer_Raw();
+case 1: return FtsTokenizer_Simple();
+case 2: return FtsTokenizer_Whitespace();
+case 3: return FtsTokenizer_NGram(minGram: dco_decode_u_32(raw[1]),maxGram: dco_decode_u_32(raw[2]),prefixOnly: dco_decode_bool(raw[3]),);
+case 4: return FtsTokenizer_Cangjie(kind: dco_decode_String(raw[1]),useHmm: dco_decode_bool(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected HnswConfig dco_decode_hnsw_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return HnswConfig(dim: dco_decode_u_32(arr[0]),
+dtype: dco_decode_vector_type(arr[1]),
+fields: dco_decode_list_String(arr[2]),
+distance: dco_decode_vector_distance(arr[3]),
+m: dco_decode_u_32(arr[4]),
+efConstruction: dco_decode_u_32(arr[5]),
+filter: dco_decode_opt_String(arr[6]),
+extendCandidates: dco_decode_bool(arr[7]),
+keepPrunedConnections: dco_decode_bool(arr[8]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImportConflict dco_decode_import_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ImportConflict(relation: dco_decode_String(arr[0]),
+key: dco_decode_list_cozo_value(arr[1]),
+local: dco_decode_list_cozo_value(arr[2]),
+incoming: dco_decode_list_cozo_value(arr[3]),); }
+
+@protected ImportOptions dco_decode_import_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ImportOptions(strategy: dco_decode_import_strategy(arr[0]),
+dryRun: dco_decode_bool(arr[1]),); }
+
+@protected ImportReport dco_decode_import_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ImportReport(inserted: dco_decode_u_64(arr[0]),
+updated: dco_decode_u_64(arr[1]),
+skipped: dco_decode_u_64(arr[2]),
+unchanged: dco_decode_u_64(arr[3]),
+deleted: dco_decode_u_64(arr[4]),
+conflictCount: dco_decode_u_64(arr[5]),
+conflicts: dco_decode_list_import_conflict(arr[6]),); }
+
+@protected ImportStrategy dco_decode_import_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ImportStrategy.values[raw as int]; }
+
+@protected IncrementalBackupInfo dco_decode_incremental_backup_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return IncrementalBackupInfo(path: dco_decode_String(arr[0]),
+full: dco_decode_bool(arr[1]),
+relations: dco_decode_list_String(arr[2]),
+createdAt: dco_decode_f_64(arr[3]),); }
+
+@protected IndexSummary dco_decode_index_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IndexSummary(name: dco_decode_String(arr[0]),
+indexType: dco_decode_String(arr[1]),); }
+
+@protected IntegrityReport dco_decode_integrity_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return IntegrityReport(ok: dco_decode_bool(arr[0]),
+storageErrors: dco_decode_list_String(arr[1]),
+relationErrors: dco_decode_list_relation_integrity_error(arr[2]),
+relationsChecked: dco_decode_u_32(arr[3]),
+rowsChecked: dco_decode_u_64(arr[4]),); }
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected LatencyBucket dco_decode_latency_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LatencyBucket(leMs: dco_decode_opt_box_autoadd_f_64(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<AuditEntry> dco_decode_list_audit_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_audit_entry).toList(); }
+
+@protected List<CdcEntry> dco_decode_list_cdc_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cdc_entry).toList(); }
+
+@protected List<ColumnDef> dco_decode_list_column_def(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_column_def).toList(); }
+
+@protected List<ColumnInfo> dco_decode_list_column_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_column_info).toList(); }
+
+@protected List<CozoValue> dco_decode_list_cozo_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cozo_value).toList(); }
+
+@protected List<Embedding> dco_decode_list_embedding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_embedding).toList(); }
+
+@protected List<EncryptedColumn> dco_decode_list_encrypted_column(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_encrypted_column).toList(); }
+
+@protected List<ErrorCount> dco_decode_list_error_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_error_count).toList(); }
+
+@protected List<FtsFilter> dco_decode_list_fts_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_fts_filter).toList(); }
+
+@protected List<FtsHit> dco_decode_list_fts_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_fts_hit).toList(); }
+
+@protected List<ImportConflict> dco_decode_list_import_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_import_conflict).toList(); }
+
+@protected List<IndexSummary> dco_decode_list_index_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_index_summary).toList(); }
+
+@protected List<LatencyBucket> dco_decode_list_latency_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_latency_bucket).toList(); }
+
+@protected List<List<CozoValue>> dco_decode_list_list_cozo_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_cozo_value).toList(); }
+
+@protected List<LshHit> dco_decode_list_lsh_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lsh_hit).toList(); }
+
+@protected List<Migration> dco_decode_list_migration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_migration).toList(); }
+
+@protected List<PlanRule> dco_decode_list_plan_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_plan_rule).toList(); }
+
+@protected List<PlanStep> dco_decode_list_plan_step(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_plan_step).toList(); }
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<double>; }
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float32List; }
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float64List; }
+
+@protected Int64List dco_decode_list_prim_i_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeInt64List(raw); }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeUint64List(raw); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QueryResult> dco_decode_list_query_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_result).toList(); }
+
+@protected List<(String,CozoValue)> dco_decode_list_record_string_cozo_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_cozo_value).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<RelationDiff> dco_decode_list_relation_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_relation_diff).toList(); }
+
+@protected List<RelationInfo> dco_decode_list_relation_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_relation_info).toList(); }
+
+@protected List<RelationIntegrityError> dco_decode_list_relation_integrity_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_relation_integrity_error).toList(); }
+
+@protected List<RowPolicy> dco_decode_list_row_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_row_policy).toList(); }
+
+@protected List<RunningQueryInfo> dco_decode_list_running_query_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_running_query_info).toList(); }
+
+@protected List<SchemaMismatch> dco_decode_list_schema_mismatch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_schema_mismatch).toList(); }
+
+@protected List<ScriptDiagnostic> dco_decode_list_script_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_script_diagnostic).toList(); }
+
+@protected List<ScriptWithParams> dco_decode_list_script_with_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_script_with_params).toList(); }
+
+@protected List<SlowQuery> dco_decode_list_slow_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_slow_query).toList(); }
+
+@protected List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_transaction_info).toList(); }
+
+@protected List<TtlSetting> dco_decode_list_ttl_setting(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ttl_setting).toList(); }
+
+@protected List<VectorHit> dco_decode_list_vector_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_vector_hit).toList(); }
+
+@protected List<VectorIndexInfo> dco_decode_list_vector_index_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_vector_index_info).toList(); }
+
+@protected LockHolder dco_decode_lock_holder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LockHolder(pid: dco_decode_u_32(arr[0]),
+since: dco_decode_f_64(arr[1]),); }
+
+@protected LshConfig dco_decode_lsh_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return LshConfig(tokenizer: dco_decode_fts_tokenizer(arr[0]),
+filters: dco_decode_list_fts_filter(arr[1]),
+nGram: dco_decode_u_32(arr[2]),
+nPerm: dco_decode_u_32(arr[3]),
+targetThreshold: dco_decode_f_64(arr[4]),
+falsePositiveWeight: dco_decode_f_64(arr[5]),
+falseNegativeWeight: dco_decode_f_64(arr[6]),); }
+
+@protected LshHit dco_decode_lsh_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LshHit(key: dco_decode_list_cozo_value(arr[0]),
+row: dco_decode_list_cozo_value(arr[1]),); }
+
+@protected MaintenanceReport dco_decode_maintenance_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MaintenanceReport(finished: dco_decode_bool(arr[0]),
+rowsPurged: dco_decode_u_64(arr[1]),
+pagesFreed: dco_decode_u_64(arr[2]),
+elapsedMs: dco_decode_u_64(arr[3]),); }
+
+@protected Migration dco_decode_migration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Migration(version: dco_decode_i_64(arr[0]),
+script: dco_decode_String(arr[1]),); }
+
+@protected MigrationReport dco_decode_migration_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MigrationReport(applied: dco_decode_list_prim_i_64_strict(arr[0]),
+currentVersion: dco_decode_opt_box_autoadd_i_64(arr[1]),); }
+
+@protected OnConflict dco_decode_on_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OnConflict.values[raw as int]; }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Chrono_Utc(raw); }
+
+@protected BusyRetryPolicy? dco_decode_opt_box_autoadd_busy_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_busy_retry_policy(raw); }
+
+@protected ColumnDefault? dco_decode_opt_box_autoadd_column_default(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_column_default(raw); }
+
+@protected CozoDartError? dco_decode_opt_box_autoadd_cozo_dart_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_cozo_dart_error(raw); }
+
+@protected ErrorSpan? dco_decode_opt_box_autoadd_error_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_error_span(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected LockHolder? dco_decode_opt_box_autoadd_lock_holder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_lock_holder(raw); }
+
+@protected SnippetConfig? dco_decode_opt_box_autoadd_snippet_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_snippet_config(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected List<CozoValue>? dco_decode_opt_list_cozo_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_cozo_value(raw); }
+
+@protected PlanRule dco_decode_plan_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PlanRule(stratum: dco_decode_u_32(arr[0]),
+ruleIdx: dco_decode_u_32(arr[1]),
+name: dco_decode_String(arr[2]),
+steps: dco_decode_list_plan_step(arr[3]),); }
+
+@protected PlanStep dco_decode_plan_step(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PlanStep(atomIdx: dco_decode_u_32(arr[0]),
+op: dco_decode_String(arr[1]),
+reference: dco_decode_opt_String(arr[2]),
+joinsOn: dco_decode_list_record_string_string(arr[3]),
+filters: dco_decode_list_String(arr[4]),
+expr: dco_decode_opt_String(arr[5]),
+output: dco_decode_list_String(arr[6]),); }
+
+@protected QueryOptions dco_decode_query_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return QueryOptions(immutable: dco_decode_bool(arr[0]),
+timeoutMs: dco_decode_opt_box_autoadd_u_32(arr[1]),
+heapLimitBytes: dco_decode_opt_box_autoadd_u_64(arr[2]),); }
+
+@protected QueryPlan dco_decode_query_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return QueryPlan(rules: dco_decode_list_plan_rule(arr[0]),
+took: dco_decode_f_64(arr[1]),); }
+
+@protected QueryResult dco_decode_query_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return QueryResult(ok: dco_decode_bool(arr[0]),
+headers: dco_decode_list_String(arr[1]),
+rows: dco_decode_list_list_cozo_value(arr[2]),
+took: dco_decode_f_64(arr[3]),
+error: dco_decode_opt_String(arr[4]),); }
+
+@protected (String,CozoValue) dco_decode_record_string_cozo_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_cozo_value(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected RecoveredDb dco_decode_recovered_db(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RecoveredDb(db: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(arr[0]),
+report: dco_decode_recovery_report(arr[1]),); }
+
+@protected RecoveryPolicy dco_decode_recovery_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecoveryPolicy.values[raw as int]; }
+
+@protected RecoveryReport dco_decode_recovery_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RecoveryReport(recovered: dco_decode_bool(arr[0]),
+reason: dco_decode_opt_String(arr[1]),
+corruptPath: dco_decode_opt_String(arr[2]),
+relationsRecovered: dco_decode_list_String(arr[3]),
+rowsRecovered: dco_decode_u_64(arr[4]),
+relationsLost: dco_decode_list_String(arr[5]),
+indicesDropped: dco_decode_list_String(arr[6]),); }
+
+@protected RelationChange dco_decode_relation_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RelationChange(relation: dco_decode_String(arr[0]),
+op: dco_decode_change_op(arr[1]),
+headers: dco_decode_list_String(arr[2]),
+newRows: dco_decode_list_list_cozo_value(arr[3]),
+oldRows: dco_decode_list_list_cozo_value(arr[4]),); }
+
+@protected RelationDiff dco_decode_relation_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RelationDiff(name: dco_decode_String(arr[0]),
+columns: dco_decode_list_schema_mismatch(arr[1]),
+addedIndices: dco_decode_list_String(arr[2]),
+removedIndices: dco_decode_list_String(arr[3]),
+changedIndices: dco_decode_list_String(arr[4]),); }
+
+@protected RelationInfo dco_decode_relation_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return RelationInfo(name: dco_decode_String(arr[0]),
+arity: dco_decode_u_32(arr[1]),
+accessLevel: dco_decode_String(arr[2]),
+nKeys: dco_decode_u_32(arr[3]),
+nNonKeys: dco_decode_u_32(arr[4]),
+nPutTriggers: dco_decode_u_32(arr[5]),
+nRmTriggers: dco_decode_u_32(arr[6]),
+nReplaceTriggers: dco_decode_u_32(arr[7]),
+description: dco_decode_String(arr[8]),); }
+
+@protected RelationIntegrityError dco_decode_relation_integrity_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RelationIntegrityError(relation: dco_decode_String(arr[0]),
+message: dco_decode_String(arr[1]),); }
+
+@protected RelationStats dco_decode_relation_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RelationStats(name: dco_decode_String(arr[0]),
+rows: dco_decode_u_64(arr[1]),
+approxBytes: dco_decode_u_64(arr[2]),
+indices: dco_decode_list_index_summary(arr[3]),
+lastModified: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected ResultEncoding dco_decode_result_encoding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ResultEncoding.values[raw as int]; }
+
+@protected RetryPolicy dco_decode_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RetryPolicy(maxAttempts: dco_decode_u_32(arr[0]),
+initialDelayMs: dco_decode_u_64(arr[1]),
+maxDelayMs: dco_decode_u_64(arr[2]),); }
+
+@protected RowPolicy dco_decode_row_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RowPolicy(relation: dco_decode_String(arr[0]),
+predicate: dco_decode_String(arr[1]),); }
+
+@protected RunningQueryInfo dco_decode_running_query_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RunningQueryInfo(id: dco_decode_String(arr[0]),
+script: dco_decode_String(arr[1]),
+startedAt: dco_decode_f_64(arr[2]),
+elapsed: dco_decode_f_64(arr[3]),); }
+
+@protected SchemaDiff dco_decode_schema_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SchemaDiff(addedRelations: dco_decode_list_String(arr[0]),
+removedRelations: dco_decode_list_String(arr[1]),
+changedRelations: dco_decode_list_relation_diff(arr[2]),); }
+
+@protected SchemaMismatch dco_decode_schema_mismatch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SchemaMismatch(column: dco_decode_String(arr[0]),
+kind: dco_decode_schema_mismatch_kind(arr[1]),
+expected: dco_decode_opt_String(arr[2]),
+actual: dco_decode_opt_String(arr[3]),); }
+
+@protected SchemaMismatchKind dco_decode_schema_mismatch_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SchemaMismatchKind.values[raw as int]; }
+
+@protected ScriptDiagnostic dco_decode_script_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ScriptDiagnostic(message: dco_decode_String(arr[0]),
+severity: dco_decode_diagnostic_severity(arr[1]),
+code: dco_decode_opt_String(arr[2]),
+start: dco_decode_text_position(arr[3]),
+end: dco_decode_text_position(arr[4]),
+label: dco_decode_opt_String(arr[5]),
+hint: dco_decode_opt_String(arr[6]),); }
+
+@protected ScriptWithParams dco_decode_script_with_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ScriptWithParams(script: dco_decode_String(arr[0]),
+params: dco_decode_Map_String_cozo_value_None(arr[1]),); }
+
+@protected SlowQuery dco_decode_slow_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SlowQuery(script: dco_decode_String(arr[0]),
+paramsDigest: dco_decode_String(arr[1]),
+took: dco_decode_f_64(arr[2]),
+rows: dco_decode_u_64(arr[3]),
+finishedAt: dco_decode_f_64(arr[4]),); }
+
+@protected SnippetConfig dco_decode_snippet_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SnippetConfig(open: dco_decode_String(arr[0]),
+close: dco_decode_String(arr[1]),
+maxWords: dco_decode_u_32(arr[2]),); }
+
+@protected SyncChangeset dco_decode_sync_changeset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SyncChangeset(payload: dco_decode_String(arr[0]),
+token: dco_decode_String(arr[1]),
+changes: dco_decode_u_64(arr[2]),); }
+
+@protected SyncReport dco_decode_sync_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SyncReport(applied: dco_decode_u_64(arr[0]),
+skipped: dco_decode_u_64(arr[1]),
+resolved: dco_decode_u_64(arr[2]),); }
+
+@protected TextPosition dco_decode_text_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TextPosition(offset: dco_decode_u_32(arr[0]),
+line: dco_decode_u_32(arr[1]),
+column: dco_decode_u_32(arr[2]),); }
+
+@protected TransactionInfo dco_decode_transaction_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TransactionInfo(id: dco_decode_u_64(arr[0]),
+mode: dco_decode_tx_mode(arr[1]),
+ageSecs: dco_decode_f_64(arr[2]),
+idleSecs: dco_decode_f_64(arr[3]),
+scriptsRun: dco_decode_u_32(arr[4]),
+writeScripts: dco_decode_u_32(arr[5]),); }
+
+@protected TriggerSpec dco_decode_trigger_spec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TriggerSpec(onPut: dco_decode_list_String(arr[0]),
+onRm: dco_decode_list_String(arr[1]),
+onReplace: dco_decode_list_String(arr[2]),); }
+
+@protected TtlSetting dco_decode_ttl_setting(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TtlSetting(relation: dco_decode_String(arr[0]),
+column: dco_decode_String(arr[1]),
+ttlSecs: dco_decode_f_64(arr[2]),); }
+
+@protected TxMode dco_decode_tx_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TxMode.values[raw as int]; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected ValidationResult dco_decode_validation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ValidationResult(valid: dco_decode_bool(arr[0]),
+checked: dco_decode_bool(arr[1]),
+error: dco_decode_opt_box_autoadd_cozo_dart_error(arr[2]),); }
+
+@protected ValidityTime dco_decode_validity_time(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ValidityTime(validFrom: dco_decode_Chrono_Utc(arr[0]),
+isAssert: dco_decode_bool(arr[1]),); }
+
+@protected VectorDistance dco_decode_vector_distance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VectorDistance.values[raw as int]; }
+
+@protected VectorHit dco_decode_vector_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return VectorHit(key: dco_decode_list_cozo_value(arr[0]),
+distance: dco_decode_f_64(arr[1]),
+row: dco_decode_list_cozo_value(arr[2]),); }
+
+@protected VectorIndexInfo dco_decode_vector_index_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return VectorIndexInfo(relation: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+dim: dco_decode_u_32(arr[2]),
+dtype: dco_decode_vector_type(arr[3]),
+fields: dco_decode_list_String(arr[4]),
+distance: dco_decode_vector_distance(arr[5]),
+m: dco_decode_u_32(arr[6]),
+efConstruction: dco_decode_u_32(arr[7]),
+extendCandidates: dco_decode_bool(arr[8]),
+keepPrunedConnections: dco_decode_bool(arr[9]),); }
+
+@protected VectorType dco_decode_vector_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VectorType.values[raw as int]; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected Auditor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AuditorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoCursor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoCursorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoDb sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoDbImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoTx sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoTxImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PreparedQuery sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PreparedQueryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RowPolicies sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RowPoliciesImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SlowLog sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SlowLogImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TenantManager sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TenantManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoCursor sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoCursorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoDb sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoDbImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoTx sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoTxImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PreparedQuery sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PreparedQueryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TenantManager sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TenantManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_64(deserializer);
+        return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_string(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, CozoValue> sse_decode_Map_String_cozo_value_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_cozo_value(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Auditor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AuditorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoCursor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoCursorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoDb sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoDbImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CozoTx sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CozoTxImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PreparedQuery sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PreparedQueryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RowPolicies sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RowPoliciesImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SlowLog sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SlowLogImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TenantManager sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TenantManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<RelationChange> sse_decode_StreamSink_relation_change_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AssetOpenMode sse_decode_asset_open_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AssetOpenMode.values[inner]; }
+
+@protected AuditEntry sse_decode_audit_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_seq = sse_decode_i_64(deserializer);
+var var_at = sse_decode_f_64(deserializer);
+var var_actor = sse_decode_String(deserializer);
+var var_relation = sse_decode_String(deserializer);
+var var_op = sse_decode_cdc_op(deserializer);
+var var_key = sse_decode_list_cozo_value(deserializer);
+var var_old = sse_decode_opt_list_cozo_value(deserializer);
+var var_new_ = sse_decode_opt_list_cozo_value(deserializer);
+return AuditEntry(seq: var_seq, at: var_at, actor: var_actor, relation: var_relation, op: var_op, key: var_key, old: var_old, new_: var_new_); }
+
+@protected AuditQuery sse_decode_audit_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_opt_String(deserializer);
+var var_actor = sse_decode_opt_String(deserializer);
+var var_since = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_until = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_fromSeq = sse_decode_i_64(deserializer);
+var var_limit = sse_decode_u_32(deserializer);
+return AuditQuery(relation: var_relation, actor: var_actor, since: var_since, until: var_until, fromSeq: var_fromSeq, limit: var_limit); }
+
+@protected BackupCompression sse_decode_backup_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return BackupCompression_None();case 1: var var_level = sse_decode_i_32(deserializer);
+return BackupCompression_Zstd(level: var_level); default: throw UnimplementedError(''); }
+             }
+
+@protected BackupInfo sse_decode_backup_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relations = sse_decode_list_String(deserializer);
+var var_rowCounts = sse_decode_list_prim_u_64_strict(deserializer);
+var var_createdAt = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_checksumOk = sse_decode_bool(deserializer);
+return BackupInfo(relations: var_relations, rowCounts: var_rowCounts, createdAt: var_createdAt, checksumOk: var_checksumOk); }
+
+@protected BackupPolicy sse_decode_backup_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_intervalSecs = sse_decode_u_64(deserializer);
+var var_dir = sse_decode_String(deserializer);
+var var_keepN = sse_decode_u_32(deserializer);
+var var_onlyOnIdle = sse_decode_bool(deserializer);
+return BackupPolicy(intervalSecs: var_intervalSecs, dir: var_dir, keepN: var_keepN, onlyOnIdle: var_onlyOnIdle); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Chrono_Utc(deserializer)); }
+
+@protected AuditQuery sse_decode_box_autoadd_audit_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_audit_query(deserializer)); }
+
+@protected BackupCompression sse_decode_box_autoadd_backup_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_backup_compression(deserializer)); }
+
+@protected BackupPolicy sse_decode_box_autoadd_backup_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_backup_policy(deserializer)); }
+
+@protected BusyRetryPolicy sse_decode_box_autoadd_busy_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_busy_retry_policy(deserializer)); }
+
+@protected ColumnDefault sse_decode_box_autoadd_column_default(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_column_default(deserializer)); }
+
+@protected CozoDartError sse_decode_box_autoadd_cozo_dart_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cozo_dart_error(deserializer)); }
+
+@protected CozoValue sse_decode_box_autoadd_cozo_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cozo_value(deserializer)); }
+
+@protected CsvImportConfig sse_decode_box_autoadd_csv_import_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_csv_import_config(deserializer)); }
+
+@protected ErrorSpan sse_decode_box_autoadd_error_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_error_span(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected FtsConfig sse_decode_box_autoadd_fts_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_fts_config(deserializer)); }
+
+@protected HnswConfig sse_decode_box_autoadd_hnsw_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_hnsw_config(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected ImportOptions sse_decode_box_autoadd_import_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_import_options(deserializer)); }
+
+@protected LockHolder sse_decode_box_autoadd_lock_holder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lock_holder(deserializer)); }
+
+@protected LshConfig sse_decode_box_autoadd_lsh_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lsh_config(deserializer)); }
+
+@protected QueryOptions sse_decode_box_autoadd_query_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query_options(deserializer)); }
+
+@protected RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_retry_policy(deserializer)); }
+
+@protected SnippetConfig sse_decode_box_autoadd_snippet_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_snippet_config(deserializer)); }
+
+@protected TriggerSpec sse_decode_box_autoadd_trigger_spec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_trigger_spec(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected ValidityTime sse_decode_box_autoadd_validity_time(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_validity_time(deserializer)); }
+
+@protected BusyRetryPolicy sse_decode_busy_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_initialDelayMs = sse_decode_u_64(deserializer);
+var var_multiplier = sse_decode_f_64(deserializer);
+var var_maxDelayMs = sse_decode_u_64(deserializer);
+return BusyRetryPolicy(maxAttempts: var_maxAttempts, initialDelayMs: var_initialDelayMs, multiplier: var_multiplier, maxDelayMs: var_maxDelayMs); }
+
+@protected CdcEntry sse_decode_cdc_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tx = sse_decode_i_64(deserializer);
+var var_relation = sse_decode_String(deserializer);
+var var_op = sse_decode_cdc_op(deserializer);
+var var_key = sse_decode_list_cozo_value(deserializer);
+var var_at = sse_decode_f_64(deserializer);
+return CdcEntry(tx: var_tx, relation: var_relation, op: var_op, key: var_key, at: var_at); }
+
+@protected CdcOp sse_decode_cdc_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CdcOp.values[inner]; }
+
+@protected ChangeOp sse_decode_change_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChangeOp.values[inner]; }
+
+@protected ColumnDef sse_decode_column_def(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_columnType = sse_decode_String(deserializer);
+var var_nullable = sse_decode_bool(deserializer);
+var var_isKey = sse_decode_bool(deserializer);
+var var_default_ = sse_decode_opt_box_autoadd_column_default(deserializer);
+return ColumnDef(name: var_name, columnType: var_columnType, nullable: var_nullable, isKey: var_isKey, default_: var_default_); }
+
+@protected ColumnDefault sse_decode_column_default(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_cozo_value(deserializer);
+return ColumnDefault_Value(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return ColumnDefault_Expr(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected ColumnInfo sse_decode_column_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_columnType = sse_decode_String(deserializer);
+var var_nullable = sse_decode_bool(deserializer);
+var var_isKey = sse_decode_bool(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+var var_hasDefault = sse_decode_bool(deserializer);
+return ColumnInfo(name: var_name, columnType: var_columnType, nullable: var_nullable, isKey: var_isKey, index: var_index, hasDefault: var_hasDefault); }
+
+@protected CozoDartError sse_decode_cozo_dart_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_error_kind(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_code = sse_decode_opt_String(deserializer);
+var var_span = sse_decode_opt_box_autoadd_error_span(deserializer);
+var var_relation = sse_decode_opt_String(deserializer);
+var var_lockHolder = sse_decode_opt_box_autoadd_lock_holder(deserializer);
+return CozoDartError(kind: var_kind, message: var_message, code: var_code, span: var_span, relation: var_relation, lockHolder: var_lockHolder); }
+
+@protected CozoMetrics sse_decode_cozo_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_since = sse_decode_f_64(deserializer);
+var var_queries = sse_decode_u_64(deserializer);
+var var_writeQueries = sse_decode_u_64(deserializer);
+var var_rowsRead = sse_decode_u_64(deserializer);
+var var_rowsWritten = sse_decode_u_64(deserializer);
+var var_errors = sse_decode_u_64(deserializer);
+var var_errorsByKind = sse_decode_list_error_count(deserializer);
+var var_latencyP50Ms = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_latencyP99Ms = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_latencyMeanMs = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_latencyBuckets = sse_decode_list_latency_bucket(deserializer);
+var var_cacheHits = sse_decode_u_64(deserializer);
+var var_cacheMisses = sse_decode_u_64(deserializer);
+var var_cacheHitRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+return CozoMetrics(since: var_since, queries: var_queries, writeQueries: var_writeQueries, rowsRead: var_rowsRead, rowsWritten: var_rowsWritten, errors: var_errors, errorsByKind: var_errorsByKind, latencyP50Ms: var_latencyP50Ms, latencyP99Ms: var_latencyP99Ms, latencyMeanMs: var_latencyMeanMs, latencyBuckets: var_latencyBuckets, cacheHits: var_cacheHits, cacheMisses: var_cacheMisses, cacheHitRate: var_cacheHitRate); }
+
+@protected CozoValue sse_decode_cozo_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return CozoValue_Null();case 1: var var_field0 = sse_decode_bool(deserializer);
+return CozoValue_Bool(var_field0);case 2: var var_field0 = sse_decode_i_64(deserializer);
+return CozoValue_Int(var_field0);case 3: var var_field0 = sse_decode_f_64(deserializer);
+return CozoValue_Float(var_field0);case 4: var var_field0 = sse_decode_String(deserializer);
+return CozoValue_Str(var_field0);case 5: var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+return CozoValue_Bytes(var_field0);case 6: var var_field0 = sse_decode_String(deserializer);
+return CozoValue_Uuid(var_field0);case 7: var var_field0 = sse_decode_list_cozo_value(deserializer);
+return CozoValue_List(var_field0);case 8: var var_field0 = sse_decode_list_prim_f_32_strict(deserializer);
+return CozoValue_F32Vector(var_field0);case 9: var var_field0 = sse_decode_list_prim_f_64_strict(deserializer);
+return CozoValue_F64Vector(var_field0);case 10: var var_field0 = sse_decode_String(deserializer);
+return CozoValue_Json(var_field0);case 11: var var_timestamp = sse_decode_i_64(deserializer);
+var var_isAssert = sse_decode_bool(deserializer);
+return CozoValue_Validity(timestamp: var_timestamp, isAssert: var_isAssert); default: throw UnimplementedError(''); }
+             }
+
+@protected CrdtMergeReport sse_decode_crdt_merge_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_upserted = sse_decode_u_64(deserializer);
+var var_deleted = sse_decode_u_64(deserializer);
+return CrdtMergeReport(upserted: var_upserted, deleted: var_deleted); }
+
+@protected CsvImportConfig sse_decode_csv_import_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_columnMap = sse_decode_Map_String_String_None(deserializer);
+var var_delimiter = sse_decode_String(deserializer);
+var var_batchSize = sse_decode_u_32(deserializer);
+var var_emptyAsNull = sse_decode_bool(deserializer);
+var var_trim = sse_decode_bool(deserializer);
+return CsvImportConfig(relation: var_relation, columnMap: var_columnMap, delimiter: var_delimiter, batchSize: var_batchSize, emptyAsNull: var_emptyAsNull, trim: var_trim); }
+
+@protected CursorPage sse_decode_cursor_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_headers = sse_decode_list_String(deserializer);
+var var_rows = sse_decode_list_list_cozo_value(deserializer);
+var var_offset = sse_decode_u_64(deserializer);
+var var_last = sse_decode_bool(deserializer);
+return CursorPage(headers: var_headers, rows: var_rows, offset: var_offset, last: var_last); }
+
+@protected DbLocation sse_decode_db_location(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DbLocation.values[inner]; }
+
+@protected DbStats sse_decode_db_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_engine = sse_decode_String(deserializer);
+var var_fileBytes = sse_decode_u_64(deserializer);
+var var_walBytes = sse_decode_u_64(deserializer);
+var var_pageSize = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_pageCount = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_freePages = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_relations = sse_decode_u_32(deserializer);
+return DbStats(engine: var_engine, fileBytes: var_fileBytes, walBytes: var_walBytes, pageSize: var_pageSize, pageCount: var_pageCount, freePages: var_freePages, relations: var_relations); }
+
+@protected DiagnosticSeverity sse_decode_diagnostic_severity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DiagnosticSeverity.values[inner]; }
+
+@protected Embedding sse_decode_embedding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_list_cozo_value(deserializer);
+var var_vector = sse_decode_list_prim_f_32_strict(deserializer);
+return Embedding(key: var_key, vector: var_vector); }
+
+@protected EncryptedColumn sse_decode_encrypted_column(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_column = sse_decode_String(deserializer);
+return EncryptedColumn(relation: var_relation, column: var_column); }
+
+@protected EnsureRelationReport sse_decode_ensure_relation_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_created = sse_decode_bool(deserializer);
+var var_mismatches = sse_decode_list_schema_mismatch(deserializer);
+return EnsureRelationReport(created: var_created, mismatches: var_mismatches); }
+
+@protected ErrorCount sse_decode_error_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_error_kind(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return ErrorCount(kind: var_kind, count: var_count); }
+
+@protected ErrorKind sse_decode_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ErrorKind.values[inner]; }
+
+@protected ErrorSpan sse_decode_error_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+return ErrorSpan(start: var_start, end: var_end); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FtsConfig sse_decode_fts_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tokenizer = sse_decode_fts_tokenizer(deserializer);
+var var_filters = sse_decode_list_fts_filter(deserializer);
+var var_stopwordsLang = sse_decode_opt_String(deserializer);
+return FtsConfig(tokenizer: var_tokenizer, filters: var_filters, stopwordsLang: var_stopwordsLang); }
+
+@protected FtsFilter sse_decode_fts_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return FtsFilter_Lowercase();case 1: return FtsFilter_AlphaNumOnly();case 2: return FtsFilter_AsciiFolding();case 3: var var_limit = sse_decode_u_32(deserializer);
+return FtsFilter_RemoveLong(limit: var_limit);case 4: var var_language = sse_decode_String(deserializer);
+return FtsFilter_Stemmer(language: var_language);case 5: var var_language = sse_decode_String(deserializer);
+return FtsFilter_Stopwords(language: var_language);case 6: var var_words = sse_decode_list_String(deserializer);
+return FtsFilter_StopwordList(words: var_words); default: throw UnimplementedError(''); }
+             }
+
+@protected FtsHit sse_decode_fts_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_list_cozo_value(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_snippet = sse_decode_opt_String(deserializer);
+return FtsHit(key: var_key, score: var_score, snippet: var_snippet); }
+
+@protected FtsTokenizer sse_decode_fts_tokenizer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return FtsTokenizer_Raw();case 1: return FtsTokenizer_Simple();case 2: return FtsTokenizer_Whitespace();case 3: var var_minGram = sse_decode_u_32(deserializer);
+var var_maxGram = sse_decode_u_32(deserializer);
+var var_prefixOnly = sse_decode_bool(deserializer);
+return FtsTokenizer_NGram(minGram: var_minGram, maxGram: var_maxGram, prefixOnly: var_prefixOnly);case 4: var var_kind = sse_decode_String(deserializer);
+var var_useHmm = sse_decode_bool(deserializer);
+return FtsTokenizer_Cangjie(kind: var_kind, useHmm: var_useHmm); default: throw UnimplementedError(''); }
+             }
+
+@protected HnswConfig sse_decode_hnsw_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dim = sse_decode_u_32(deserializer);
+var var_dtype = sse_decode_vector_type(deserializer);
+var var_fields = sse_decode_list_String(deserializer);
+var var_distance = sse_decode_vector_distance(deserializer);
+var var_m = sse_decode_u_32(deserializer);
+var var_efConstruction = sse_decode_u_32(deserializer);
+var var_filter = sse_decode_opt_String(deserializer);
+var var_extendCandidates = sse_decode_bool(deserializer);
+var var_keepPrunedConnections = sse_decode_bool(deserializer);
+return HnswConfig(dim: var_dim, dtype: var_dtype, fields: var_fields, distance: var_distance, m: var_m, efConstruction: var_efConstruction, filter: var_filter, extendCandidates: var_extendCandidates, keepPrunedConnections: var_keepPrunedConnections); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImportConflict sse_decode_import_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_key = sse_decode_list_cozo_value(deserializer);
+var var_local = sse_decode_list_cozo_value(deserializer);
+var var_incoming = sse_decode_list_cozo_value(deserializer);
+return ImportConflict(relation: var_relation, key: var_key, local: var_local, incoming: var_incoming); }
+
+@protected ImportOptions sse_decode_import_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_strategy = sse_decode_import_strategy(deserializer);
+var var_dryRun = sse_decode_bool(deserializer);
+return ImportOptions(strategy: var_strategy, dryRun: var_dryRun); }
+
+@protected ImportReport sse_decode_import_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_inserted = sse_decode_u_64(deserializer);
+var var_updated = sse_decode_u_64(deserializer);
+var var_skipped = sse_decode_u_64(deserializer);
+var var_unchanged = sse_decode_u_64(deserializer);
+var var_deleted = sse_decode_u_64(deserializer);
+var var_conflictCount = sse_decode_u_64(deserializer);
+var var_conflicts = sse_decode_list_import_conflict(deserializer);
+return ImportReport(inserted: var_inserted, updated: var_updated, skipped: var_skipped, unchanged: var_unchanged, deleted: var_deleted, conflictCount: var_conflictCount, conflicts: var_conflicts); }
+
+@protected ImportStrategy sse_decode_import_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ImportStrategy.values[inner]; }
+
+@protected IncrementalBackupInfo sse_decode_incremental_backup_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_full = sse_decode_bool(deserializer);
+var var_relations = sse_decode_list_String(deserializer);
+var var_createdAt = sse_decode_f_64(deserializer);
+return IncrementalBackupInfo(path: var_path, full: var_full, relations: var_relations, createdAt: var_createdAt); }
+
+@protected IndexSummary sse_decode_index_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_indexType = sse_decode_String(deserializer);
+return IndexSummary(name: var_name, indexType: var_indexType); }
+
+@protected IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ok = sse_decode_bool(deserializer);
+var var_storageErrors = sse_decode_list_String(deserializer);
+var var_relationErrors = sse_decode_list_relation_integrity_error(deserializer);
+var var_relationsChecked = sse_decode_u_32(deserializer);
+var var_rowsChecked = sse_decode_u_64(deserializer);
+return IntegrityReport(ok: var_ok, storageErrors: var_storageErrors, relationErrors: var_relationErrors, relationsChecked: var_relationsChecked, rowsChecked: var_rowsChecked); }
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected LatencyBucket sse_decode_latency_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_leMs = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return LatencyBucket(leMs: var_leMs, count: var_count); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AuditEntry> sse_decode_list_audit_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AuditEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_audit_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CdcEntry> sse_decode_list_cdc_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CdcEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cdc_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ColumnDef> sse_decode_list_column_def(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ColumnDef>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_column_def(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ColumnInfo> sse_decode_list_column_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ColumnInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_column_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CozoValue> sse_decode_list_cozo_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CozoValue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cozo_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Embedding> sse_decode_list_embedding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Embedding>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_embedding(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EncryptedColumn> sse_decode_list_encrypted_column(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EncryptedColumn>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_encrypted_column(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ErrorCount> sse_decode_list_error_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ErrorCount>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_error_count(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FtsFilter> sse_decode_list_fts_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FtsFilter>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_fts_filter(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FtsHit> sse_decode_list_fts_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FtsHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_fts_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ImportConflict> sse_decode_list_import_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ImportConflict>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_import_conflict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IndexSummary> sse_decode_list_index_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IndexSummary>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_index_summary(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LatencyBucket> sse_decode_list_latency_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LatencyBucket>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_latency_bucket(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<CozoValue>> sse_decode_list_list_cozo_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<CozoValue>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_cozo_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LshHit> sse_decode_list_lsh_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LshHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lsh_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Migration> sse_decode_list_migration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Migration>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_migration(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PlanRule> sse_decode_list_plan_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PlanRule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_plan_rule(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PlanStep> sse_decode_list_plan_step(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PlanStep>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_plan_step(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat64List(len_); }
+
+@protected Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt64List(len_); }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QueryResult> sse_decode_list_query_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,CozoValue)> sse_decode_list_record_string_cozo_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,CozoValue)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_cozo_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RelationDiff> sse_decode_list_relation_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RelationDiff>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_relation_diff(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RelationInfo> sse_decode_list_relation_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RelationInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_relation_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RelationIntegrityError> sse_decode_list_relation_integrity_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RelationIntegrityError>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_relation_integrity_error(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RowPolicy> sse_decode_list_row_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RowPolicy>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_row_policy(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RunningQueryInfo> sse_decode_list_running_query_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RunningQueryInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_running_query_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SchemaMismatch> sse_decode_list_schema_mismatch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SchemaMismatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_schema_mismatch(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ScriptDiagnostic> sse_decode_list_script_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ScriptDiagnostic>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_script_diagnostic(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ScriptWithParams> sse_decode_list_script_with_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ScriptWithParams>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_script_with_params(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SlowQuery> sse_decode_list_slow_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SlowQuery>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_slow_query(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TransactionInfo> sse_decode_list_transaction_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TransactionInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_transaction_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TtlSetting> sse_decode_list_ttl_setting(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TtlSetting>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ttl_setting(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VectorHit> sse_decode_list_vector_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VectorHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_vector_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VectorIndexInfo> sse_decode_list_vector_index_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VectorIndexInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_vector_index_info(deserializer)); }
+        return ans_;
+         }
+
+@protected LockHolder sse_decode_lock_holder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_since = sse_decode_f_64(deserializer);
+return LockHolder(pid: var_pid, since: var_since); }
+
+@protected LshConfig sse_decode_lsh_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tokenizer = sse_decode_fts_tokenizer(deserializer);
+var var_filters = sse_decode_list_fts_filter(deserializer);
+var var_nGram = sse_decode_u_32(deserializer);
+var var_nPerm = sse_decode_u_32(deserializer);
+var var_targetThreshold = sse_decode_f_64(deserializer);
+var var_falsePositiveWeight = sse_decode_f_64(deserializer);
+var var_falseNegativeWeight = sse_decode_f_64(deserializer);
+return LshConfig(tokenizer: var_tokenizer, filters: var_filters, nGram: var_nGram, nPerm: var_nPerm, targetThreshold: var_targetThreshold, falsePositiveWeight: var_falsePositiveWeight, falseNegativeWeight: var_falseNegativeWeight); }
+
+@protected LshHit sse_decode_lsh_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_list_cozo_value(deserializer);
+var var_row = sse_decode_list_cozo_value(deserializer);
+return LshHit(key: var_key, row: var_row); }
+
+@protected MaintenanceReport sse_decode_maintenance_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_finished = sse_decode_bool(deserializer);
+var var_rowsPurged = sse_decode_u_64(deserializer);
+var var_pagesFreed = sse_decode_u_64(deserializer);
+var var_elapsedMs = sse_decode_u_64(deserializer);
+return MaintenanceReport(finished: var_finished, rowsPurged: var_rowsPurged, pagesFreed: var_pagesFreed, elapsedMs: var_elapsedMs); }
+
+@protected Migration sse_decode_migration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_version = sse_decode_i_64(deserializer);
+var var_script = sse_decode_String(deserializer);
+return Migration(version: var_version, script: var_script); }
+
+@protected MigrationReport sse_decode_migration_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_applied = sse_decode_list_prim_i_64_strict(deserializer);
+var var_currentVersion = sse_decode_opt_box_autoadd_i_64(deserializer);
+return MigrationReport(applied: var_applied, currentVersion: var_currentVersion); }
+
+@protected OnConflict sse_decode_on_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OnConflict.values[inner]; }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Chrono_Utc(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BusyRetryPolicy? sse_decode_opt_box_autoadd_busy_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_busy_retry_policy(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ColumnDefault? sse_decode_opt_box_autoadd_column_default(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_column_default(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CozoDartError? sse_decode_opt_box_autoadd_cozo_dart_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_cozo_dart_error(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ErrorSpan? sse_decode_opt_box_autoadd_error_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_error_span(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LockHolder? sse_decode_opt_box_autoadd_lock_holder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_lock_holder(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SnippetConfig? sse_decode_opt_box_autoadd_snippet_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_snippet_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<CozoValue>? sse_decode_opt_list_cozo_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_cozo_value(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlanRule sse_decode_plan_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stratum = sse_decode_u_32(deserializer);
+var var_ruleIdx = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_steps = sse_decode_list_plan_step(deserializer);
+return PlanRule(stratum: var_stratum, ruleIdx: var_ruleIdx, name: var_name, steps: var_steps); }
+
+@protected PlanStep sse_decode_plan_step(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_atomIdx = sse_decode_u_32(deserializer);
+var var_op = sse_decode_String(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_joinsOn = sse_decode_list_record_string_string(deserializer);
+var var_filters = sse_decode_list_String(deserializer);
+var var_expr = sse_decode_opt_String(deserializer);
+var var_output = sse_decode_list_String(deserializer);
+return PlanStep(atomIdx: var_atomIdx, op: var_op, reference: var_reference, joinsOn: var_joinsOn, filters: var_filters, expr: var_expr, output: var_output); }
+
+@protected QueryOptions sse_decode_query_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_immutable = sse_decode_bool(deserializer);
+var var_timeoutMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_heapLimitBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+return QueryOptions(immutable: var_immutable, timeoutMs: var_timeoutMs, heapLimitBytes: var_heapLimitBytes); }
+
+@protected QueryPlan sse_decode_query_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rules = sse_decode_list_plan_rule(deserializer);
+var var_took = sse_decode_f_64(deserializer);
+return QueryPlan(rules: var_rules, took: var_took); }
+
+@protected QueryResult sse_decode_query_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ok = sse_decode_bool(deserializer);
+var var_headers = sse_decode_list_String(deserializer);
+var var_rows = sse_decode_list_list_cozo_value(deserializer);
+var var_took = sse_decode_f_64(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return QueryResult(ok: var_ok, headers: var_headers, rows: var_rows, took: var_took, error: var_error); }
+
+@protected (String,CozoValue) sse_decode_record_string_cozo_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_cozo_value(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected RecoveredDb sse_decode_recovered_db(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_db = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(deserializer);
+var var_report = sse_decode_recovery_report(deserializer);
+return RecoveredDb(db: var_db, report: var_report); }
+
+@protected RecoveryPolicy sse_decode_recovery_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RecoveryPolicy.values[inner]; }
+
+@protected RecoveryReport sse_decode_recovery_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_recovered = sse_decode_bool(deserializer);
+var var_reason = sse_decode_opt_String(deserializer);
+var var_corruptPath = sse_decode_opt_String(deserializer);
+var var_relationsRecovered = sse_decode_list_String(deserializer);
+var var_rowsRecovered = sse_decode_u_64(deserializer);
+var var_relationsLost = sse_decode_list_String(deserializer);
+var var_indicesDropped = sse_decode_list_String(deserializer);
+return RecoveryReport(recovered: var_recovered, reason: var_reason, corruptPath: var_corruptPath, relationsRecovered: var_relationsRecovered, rowsRecovered: var_rowsRecovered, relationsLost: var_relationsLost, indicesDropped: var_indicesDropped); }
+
+@protected RelationChange sse_decode_relation_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_op = sse_decode_change_op(deserializer);
+var var_headers = sse_decode_list_String(deserializer);
+var var_newRows = sse_decode_list_list_cozo_value(deserializer);
+var var_oldRows = sse_decode_list_list_cozo_value(deserializer);
+return RelationChange(relation: var_relation, op: var_op, headers: var_headers, newRows: var_newRows, oldRows: var_oldRows); }
+
+@protected RelationDiff sse_decode_relation_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_columns = sse_decode_list_schema_mismatch(deserializer);
+var var_addedIndices = sse_decode_list_String(deserializer);
+var var_removedIndices = sse_decode_list_String(deserializer);
+var var_changedIndices = sse_decode_list_String(deserializer);
+return RelationDiff(name: var_name, columns: var_columns, addedIndices: var_addedIndices, removedIndices: var_removedIndices, changedIndices: var_changedIndices); }
+
+@protected RelationInfo sse_decode_relation_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_arity = sse_decode_u_32(deserializer);
+var var_accessLevel = sse_decode_String(deserializer);
+var var_nKeys = sse_decode_u_32(deserializer);
+var var_nNonKeys = sse_decode_u_32(deserializer);
+var var_nPutTriggers = sse_decode_u_32(deserializer);
+var var_nRmTriggers = sse_decode_u_32(deserializer);
+var var_nReplaceTriggers = sse_decode_u_32(deserializer);
+var var_description = sse_decode_String(deserializer);
+return RelationInfo(name: var_name, arity: var_arity, accessLevel: var_accessLevel, nKeys: var_nKeys, nNonKeys: var_nNonKeys, nPutTriggers: var_nPutTriggers, nRmTriggers: var_nRmTriggers, nReplaceTriggers: var_nReplaceTriggers, description: var_description); }
+
+@protected RelationIntegrityError sse_decode_relation_integrity_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return RelationIntegrityError(relation: var_relation, message: var_message); }
+
+@protected RelationStats sse_decode_relation_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_rows = sse_decode_u_64(deserializer);
+var var_approxBytes = sse_decode_u_64(deserializer);
+var var_indices = sse_decode_list_index_summary(deserializer);
+var var_lastModified = sse_decode_opt_box_autoadd_i_64(deserializer);
+return RelationStats(name: var_name, rows: var_rows, approxBytes: var_approxBytes, indices: var_indices, lastModified: var_lastModified); }
+
+@protected ResultEncoding sse_decode_result_encoding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ResultEncoding.values[inner]; }
+
+@protected RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_initialDelayMs = sse_decode_u_64(deserializer);
+var var_maxDelayMs = sse_decode_u_64(deserializer);
+return RetryPolicy(maxAttempts: var_maxAttempts, initialDelayMs: var_initialDelayMs, maxDelayMs: var_maxDelayMs); }
+
+@protected RowPolicy sse_decode_row_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_predicate = sse_decode_String(deserializer);
+return RowPolicy(relation: var_relation, predicate: var_predicate); }
+
+@protected RunningQueryInfo sse_decode_running_query_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_script = sse_decode_String(deserializer);
+var var_startedAt = sse_decode_f_64(deserializer);
+var var_elapsed = sse_decode_f_64(deserializer);
+return RunningQueryInfo(id: var_id, script: var_script, startedAt: var_startedAt, elapsed: var_elapsed); }
+
+@protected SchemaDiff sse_decode_schema_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_addedRelations = sse_decode_list_String(deserializer);
+var var_removedRelations = sse_decode_list_String(deserializer);
+var var_changedRelations = sse_decode_list_relation_diff(deserializer);
+return SchemaDiff(addedRelations: var_addedRelations, removedRelations: var_removedRelations, changedRelations: var_changedRelations); }
+
+@protected SchemaMismatch sse_decode_schema_mismatch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_column = sse_decode_String(deserializer);
+var var_kind = sse_decode_schema_mismatch_kind(deserializer);
+var var_expected = sse_decode_opt_String(deserializer);
+var var_actual = sse_decode_opt_String(deserializer);
+return SchemaMismatch(column: var_column, kind: var_kind, expected: var_expected, actual: var_actual); }
+
+@protected SchemaMismatchKind sse_decode_schema_mismatch_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SchemaMismatchKind.values[inner]; }
+
+@protected ScriptDiagnostic sse_decode_script_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_message = sse_decode_String(deserializer);
+var var_severity = sse_decode_diagnostic_severity(deserializer);
+var var_code = sse_decode_opt_String(deserializer);
+var var_start = sse_decode_text_position(deserializer);
+var var_end = sse_decode_text_position(deserializer);
+var var_label = sse_decode_opt_String(deserializer);
+var var_hint = sse_decode_opt_String(deserializer);
+return ScriptDiagnostic(message: var_message, severity: var_severity, code: var_code, start: var_start, end: var_end, label: var_label, hint: var_hint); }
+
+@protected ScriptWithParams sse_decode_script_with_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_script = sse_decode_String(deserializer);
+var var_params = sse_decode_Map_String_cozo_value_None(deserializer);
+return ScriptWithParams(script: var_script, params: var_params); }
+
+@protected SlowQuery sse_decode_slow_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_script = sse_decode_String(deserializer);
+var var_paramsDigest = sse_decode_String(deserializer);
+var var_took = sse_decode_f_64(deserializer);
+var var_rows = sse_decode_u_64(deserializer);
+var var_finishedAt = sse_decode_f_64(deserializer);
+return SlowQuery(script: var_script, paramsDigest: var_paramsDigest, took: var_took, rows: var_rows, finishedAt: var_finishedAt); }
+
+@protected SnippetConfig sse_decode_snippet_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_open = sse_decode_String(deserializer);
+var var_close = sse_decode_String(deserializer);
+var var_maxWords = sse_decode_u_32(deserializer);
+return SnippetConfig(open: var_open, close: var_close, maxWords: var_maxWords); }
+
+@protected SyncChangeset sse_decode_sync_changeset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_payload = sse_decode_String(deserializer);
+var var_token = sse_decode_String(deserializer);
+var var_changes = sse_decode_u_64(deserializer);
+return SyncChangeset(payload: var_payload, token: var_token, changes: var_changes); }
+
+@protected SyncReport sse_decode_sync_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_applied = sse_decode_u_64(deserializer);
+var var_skipped = sse_decode_u_64(deserializer);
+var var_resolved = sse_decode_u_64(deserializer);
+return SyncReport(applied: var_applied, skipped: var_skipped, resolved: var_resolved); }
+
+@protected TextPosition sse_decode_text_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_offset = sse_decode_u_32(deserializer);
+var var_line = sse_decode_u_32(deserializer);
+var var_column = sse_decode_u_32(deserializer);
+return TextPosition(offset: var_offset, line: var_line, column: var_column); }
+
+@protected TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_64(deserializer);
+var var_mode = sse_decode_tx_mode(deserializer);
+var var_ageSecs = sse_decode_f_64(deserializer);
+var var_idleSecs = sse_decode_f_64(deserializer);
+var var_scriptsRun = sse_decode_u_32(deserializer);
+var var_writeScripts = sse_decode_u_32(deserializer);
+return TransactionInfo(id: var_id, mode: var_mode, ageSecs: var_ageSecs, idleSecs: var_idleSecs, scriptsRun: var_scriptsRun, writeScripts: var_writeScripts); }
+
+@protected TriggerSpec sse_decode_trigger_spec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_onPut = sse_decode_list_String(deserializer);
+var var_onRm = sse_decode_list_String(deserializer);
+var var_onReplace = sse_decode_list_String(deserializer);
+return TriggerSpec(onPut: var_onPut, onRm: var_onRm, onReplace: var_onReplace); }
+
+@protected TtlSetting sse_decode_ttl_setting(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_column = sse_decode_String(deserializer);
+var var_ttlSecs = sse_decode_f_64(deserializer);
+return TtlSetting(relation: var_relation, column: var_column, ttlSecs: var_ttlSecs); }
+
+@protected TxMode sse_decode_tx_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TxMode.values[inner]; }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected ValidationResult sse_decode_validation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_valid = sse_decode_bool(deserializer);
+var var_checked = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_box_autoadd_cozo_dart_error(deserializer);
+return ValidationResult(valid: var_valid, checked: var_checked, error: var_error); }
+
+@protected ValidityTime sse_decode_validity_time(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_validFrom = sse_decode_Chrono_Utc(deserializer);
+var var_isAssert = sse_decode_bool(deserializer);
+return ValidityTime(validFrom: var_validFrom, isAssert: var_isAssert); }
+
+@protected VectorDistance sse_decode_vector_distance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VectorDistance.values[inner]; }
+
+@protected VectorHit sse_decode_vector_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_list_cozo_value(deserializer);
+var var_distance = sse_decode_f_64(deserializer);
+var var_row = sse_decode_list_cozo_value(deserializer);
+return VectorHit(key: var_key, distance: var_distance, row: var_row); }
+
+@protected VectorIndexInfo sse_decode_vector_index_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relation = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_dim = sse_decode_u_32(deserializer);
+var var_dtype = sse_decode_vector_type(deserializer);
+var var_fields = sse_decode_list_String(deserializer);
+var var_distance = sse_decode_vector_distance(deserializer);
+var var_m = sse_decode_u_32(deserializer);
+var var_efConstruction = sse_decode_u_32(deserializer);
+var var_extendCandidates = sse_decode_bool(deserializer);
+var var_keepPrunedConnections = sse_decode_bool(deserializer);
+return VectorIndexInfo(relation: var_relation, name: var_name, dim: var_dim, dtype: var_dtype, fields: var_fields, distance: var_distance, m: var_m, efConstruction: var_efConstruction, extendCandidates: var_extendCandidates, keepPrunedConnections: var_keepPrunedConnections); }
+
+@protected VectorType sse_decode_vector_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VectorType.values[inner]; }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(Auditor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AuditorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(CozoCursor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoCursorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(CozoDb self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoDbImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(CozoTx self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoTxImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(PreparedQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PreparedQueryImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(RowPolicies self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RowPoliciesImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SlowLog self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SlowLogImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(TenantManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TenantManagerImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(CozoCursor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoCursorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(CozoDb self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoDbImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(CozoTx self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoTxImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(PreparedQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PreparedQueryImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(TenantManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TenantManagerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(PlatformInt64Util.from(self.microsecondsSinceEpoch), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(FutureOr<String> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_String_AnyhowException(FutureOr<String> Function(String, String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_String_Output_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs__Output_String_AnyhowException(FutureOr<String> Function() self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs__Output_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_string(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_String_cozo_value_None(Map<String, CozoValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_cozo_value(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(Auditor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AuditorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(CozoCursor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoCursorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(CozoDb self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoDbImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(CozoTx self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CozoTxImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(PreparedQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PreparedQueryImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(RowPolicies self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RowPoliciesImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SlowLog self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SlowLogImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(TenantManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TenantManagerImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_relation_change_Sse(RustStreamSink<RelationChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_relation_change,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_asset_open_mode(AssetOpenMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_audit_entry(AuditEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.seq, serializer);
+sse_encode_f_64(self.at, serializer);
+sse_encode_String(self.actor, serializer);
+sse_encode_String(self.relation, serializer);
+sse_encode_cdc_op(self.op, serializer);
+sse_encode_list_cozo_value(self.key, serializer);
+sse_encode_opt_list_cozo_value(self.old, serializer);
+sse_encode_opt_list_cozo_value(self.new_, serializer);
+ }
+
+@protected void sse_encode_audit_query(AuditQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.relation, serializer);
+sse_encode_opt_String(self.actor, serializer);
+sse_encode_opt_box_autoadd_f_64(self.since, serializer);
+sse_encode_opt_box_autoadd_f_64(self.until, serializer);
+sse_encode_i_64(self.fromSeq, serializer);
+sse_encode_u_32(self.limit, serializer);
+ }
+
+@protected void sse_encode_backup_compression(BackupCompression self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case BackupCompression_None(): sse_encode_i_32(0, serializer); case BackupCompression_Zstd(level: final level): sse_encode_i_32(1, serializer); sse_encode_i_32(level, serializer);
+  } }
+
+@protected void sse_encode_backup_info(BackupInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.relations, serializer);
+sse_encode_list_prim_u_64_strict(self.rowCounts, serializer);
+sse_encode_opt_box_autoadd_f_64(self.createdAt, serializer);
+sse_encode_bool(self.checksumOk, serializer);
+ }
+
+@protected void sse_encode_backup_policy(BackupPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.intervalSecs, serializer);
+sse_encode_String(self.dir, serializer);
+sse_encode_u_32(self.keepN, serializer);
+sse_encode_bool(self.onlyOnIdle, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_Chrono_Utc(DateTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Chrono_Utc(self, serializer); }
+
+@protected void sse_encode_box_autoadd_audit_query(AuditQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_audit_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_backup_compression(BackupCompression self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_backup_compression(self, serializer); }
+
+@protected void sse_encode_box_autoadd_backup_policy(BackupPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_backup_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_busy_retry_policy(BusyRetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_busy_retry_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_column_default(ColumnDefault self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_column_default(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cozo_dart_error(CozoDartError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cozo_dart_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cozo_value(CozoValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cozo_value(self, serializer); }
+
+@protected void sse_encode_box_autoadd_csv_import_config(CsvImportConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_csv_import_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_error_span(ErrorSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_error_span(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_fts_config(FtsConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fts_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_hnsw_config(HnswConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hnsw_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_import_options(ImportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_import_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lock_holder(LockHolder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lock_holder(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lsh_config(LshConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lsh_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_query_options(QueryOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_retry_policy(RetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_retry_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_snippet_config(SnippetConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_snippet_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_trigger_spec(TriggerSpec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_trigger_spec(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_validity_time(ValidityTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_validity_time(self, serializer); }
+
+@protected void sse_encode_busy_retry_policy(BusyRetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxAttempts, serializer);
+sse_encode_u_64(self.initialDelayMs, serializer);
+sse_encode_f_64(self.multiplier, serializer);
+sse_encode_u_64(self.maxDelayMs, serializer);
+ }
+
+@protected void sse_encode_cdc_entry(CdcEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.tx, serializer);
+sse_encode_String(self.relation, serializer);
+sse_encode_cdc_op(self.op, serializer);
+sse_encode_list_cozo_value(self.key, serializer);
+sse_encode_f_64(self.at, serializer);
+ }
+
+@protected void sse_encode_cdc_op(CdcOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_change_op(ChangeOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_column_def(ColumnDef self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.columnType, serializer);
+sse_encode_bool(self.nullable, serializer);
+sse_encode_bool(self.isKey, serializer);
+sse_encode_opt_box_autoadd_column_default(self.default_, serializer);
+ }
+
+@protected void sse_encode_column_default(ColumnDefault self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ColumnDefault_Value(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_cozo_value(field0, serializer);
+case ColumnDefault_Expr(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_column_info(ColumnInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.columnType, serializer);
+sse_encode_bool(self.nullable, serializer);
+sse_encode_bool(self.isKey, serializer);
+sse_encode_u_32(self.index, serializer);
+sse_encode_bool(self.hasDefault, serializer);
+ }
+
+@protected void sse_encode_cozo_dart_error(CozoDartError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_error_kind(self.kind, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_String(self.code, serializer);
+sse_encode_opt_box_autoadd_error_span(self.span, serializer);
+sse_encode_opt_String(self.relation, serializer);
+sse_encode_opt_box_autoadd_lock_holder(self.lockHolder, serializer);
+ }
+
+@protected void sse_encode_cozo_metrics(CozoMetrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.since, serializer);
+sse_encode_u_64(self.queries, serializer);
+sse_encode_u_64(self.writeQueries, serializer);
+sse_encode_u_64(self.rowsRead, serializer);
+sse_encode_u_64(self.rowsWritten, serializer);
+sse_encode_u_64(self.errors, serializer);
+sse_encode_list_error_count(self.errorsByKind, serializer);
+sse_encode_opt_box_autoadd_f_64(self.latencyP50Ms, serializer);
+sse_encode_opt_box_autoadd_f_64(self.latencyP99Ms, serializer);
+sse_encode_opt_box_autoadd_f_64(self.latencyMeanMs, serializer);
+sse_encode_list_latency_bucket(self.latencyBuckets, serializer);
+sse_encode_u_64(self.cacheHits, serializer);
+sse_encode_u_64(self.cacheMisses, serializer);
+sse_encode_opt_box_autoadd_f_64(self.cacheHitRate, serializer);
+ }
+
+@protected void sse_encode_cozo_value(CozoValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case CozoValue_Null(): sse_encode_i_32(0, serializer); case CozoValue_Bool(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_bool(field0, serializer);
+case CozoValue_Int(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_i_64(field0, serializer);
+case CozoValue_Float(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_f_64(field0, serializer);
+case CozoValue_Str(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+case CozoValue_Bytes(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_list_prim_u_8_strict(field0, serializer);
+case CozoValue_Uuid(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_String(field0, serializer);
+case CozoValue_List(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_list_cozo_value(field0, serializer);
+case CozoValue_F32Vector(field0: final field0): sse_encode_i_32(8, serializer); sse_encode_list_prim_f_32_strict(field0, serializer);
+case CozoValue_F64Vector(field0: final field0): sse_encode_i_32(9, serializer); sse_encode_list_prim_f_64_strict(field0, serializer);
+case CozoValue_Json(field0: final field0): sse_encode_i_32(10, serializer); sse_encode_String(field0, serializer);
+case CozoValue_Validity(timestamp: final timestamp,isAssert: final isAssert): sse_encode_i_32(11, serializer); sse_encode_i_64(timestamp, serializer);
+sse_encode_bool(isAssert, serializer);
+  } }
+
+@protected void sse_encode_crdt_merge_report(CrdtMergeReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.upserted, serializer);
+sse_encode_u_64(self.deleted, serializer);
+ }
+
+@protected void sse_encode_csv_import_config(CsvImportConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_Map_String_String_None(self.columnMap, serializer);
+sse_encode_String(self.delimiter, serializer);
+sse_encode_u_32(self.batchSize, serializer);
+sse_encode_bool(self.emptyAsNull, serializer);
+sse_encode_bool(self.trim, serializer);
+ }
+
+@protected void sse_encode_cursor_page(CursorPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.headers, serializer);
+sse_encode_list_list_cozo_value(self.rows, serializer);
+sse_encode_u_64(self.offset, serializer);
+sse_encode_bool(self.last, serializer);
+ }
+
+@protected void sse_encode_db_location(DbLocation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_db_stats(DbStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.engine, serializer);
+sse_encode_u_64(self.fileBytes, serializer);
+sse_encode_u_64(self.walBytes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.pageSize, serializer);
+sse_encode_opt_box_autoadd_u_64(self.pageCount, serializer);
+sse_encode_opt_box_autoadd_u_64(self.freePages, serializer);
+sse_encode_u_32(self.relations, serializer);
+ }
+
+@protected void sse_encode_diagnostic_severity(DiagnosticSeverity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_embedding(Embedding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_cozo_value(self.key, serializer);
+sse_encode_list_prim_f_32_strict(self.vector, serializer);
+ }
+
+@protected void sse_encode_encrypted_column(EncryptedColumn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_String(self.column, serializer);
+ }
+
+@protected void sse_encode_ensure_relation_report(EnsureRelationReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.created, serializer);
+sse_encode_list_schema_mismatch(self.mismatches, serializer);
+ }
+
+@protected void sse_encode_error_count(ErrorCount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_error_kind(self.kind, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_error_kind(ErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_error_span(ErrorSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_fts_config(FtsConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fts_tokenizer(self.tokenizer, serializer);
+sse_encode_list_fts_filter(self.filters, serializer);
+sse_encode_opt_String(self.stopwordsLang, serializer);
+ }
+
+@protected void sse_encode_fts_filter(FtsFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FtsFilter_Lowercase(): sse_encode_i_32(0, serializer); case FtsFilter_AlphaNumOnly(): sse_encode_i_32(1, serializer); case FtsFilter_AsciiFolding(): sse_encode_i_32(2, serializer); case FtsFilter_RemoveLong(limit: final limit): sse_encode_i_32(3, serializer); sse_encode_u_32(limit, serializer);
+case FtsFilter_Stemmer(language: final language): sse_encode_i_32(4, serializer); sse_encode_String(language, serializer);
+case FtsFilter_Stopwords(language: final language): sse_encode_i_32(5, serializer); sse_encode_String(language, serializer);
+case FtsFilter_StopwordList(words: final words): sse_encode_i_32(6, serializer); sse_encode_list_String(words, serializer);
+  } }
+
+@protected void sse_encode_fts_hit(FtsHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_cozo_value(self.key, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_opt_String(self.snippet, serializer);
+ }
+
+@protected void sse_encode_fts_tokenizer(FtsTokenizer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FtsTokenizer_Raw(): sse_encode_i_32(0, serializer); case FtsTokenizer_Simple(): sse_encode_i_32(1, serializer); case FtsTokenizer_Whitespace(): sse_encode_i_32(2, serializer); case FtsTokenizer_NGram(minGram: final minGram,maxGram: final maxGram,prefixOnly: final prefixOnly): sse_encode_i_32(3, serializer); sse_encode_u_32(minGram, serializer);
+sse_encode_u_32(maxGram, serializer);
+sse_encode_bool(prefixOnly, serializer);
+case FtsTokenizer_Cangjie(kind: final kind,useHmm: final useHmm): sse_encode_i_32(4, serializer); sse_encode_String(kind, serializer);
+sse_encode_bool(useHmm, serializer);
+  } }
+
+@protected void sse_encode_hnsw_config(HnswConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.dim, serializer);
+sse_encode_vector_type(self.dtype, serializer);
+sse_encode_list_String(self.fields, serializer);
+sse_encode_vector_distance(self.distance, serializer);
+sse_encode_u_32(self.m, serializer);
+sse_encode_u_32(self.efConstruction, serializer);
+sse_encode_opt_String(self.filter, serializer);
+sse_encode_bool(self.extendCandidates, serializer);
+sse_encode_bool(self.keepPrunedConnections, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_import_conflict(ImportConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_list_cozo_value(self.key, serializer);
+sse_encode_list_cozo_value(self.local, serializer);
+sse_encode_list_cozo_value(self.incoming, serializer);
+ }
+
+@protected void sse_encode_import_options(ImportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_import_strategy(self.strategy, serializer);
+sse_encode_bool(self.dryRun, serializer);
+ }
+
+@protected void sse_encode_import_report(ImportReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.inserted, serializer);
+sse_encode_u_64(self.updated, serializer);
+sse_encode_u_64(self.skipped, serializer);
+sse_encode_u_64(self.unchanged, serializer);
+sse_encode_u_64(self.deleted, serializer);
+sse_encode_u_64(self.conflictCount, serializer);
+sse_encode_list_import_conflict(self.conflicts, serializer);
+ }
+
+@protected void sse_encode_import_strategy(ImportStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_incremental_backup_info(IncrementalBackupInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_bool(self.full, serializer);
+sse_encode_list_String(self.relations, serializer);
+sse_encode_f_64(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_index_summary(IndexSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.indexType, serializer);
+ }
+
+@protected void sse_encode_integrity_report(IntegrityReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.ok, serializer);
+sse_encode_list_String(self.storageErrors, serializer);
+sse_encode_list_relation_integrity_error(self.relationErrors, serializer);
+sse_encode_u_32(self.relationsChecked, serializer);
+sse_encode_u_64(self.rowsChecked, serializer);
+ }
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_latency_bucket(LatencyBucket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.leMs, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_audit_entry(List<AuditEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_audit_entry(item, serializer); } }
+
+@protected void sse_encode_list_cdc_entry(List<CdcEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cdc_entry(item, serializer); } }
+
+@protected void sse_encode_list_column_def(List<ColumnDef> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_column_def(item, serializer); } }
+
+@protected void sse_encode_list_column_info(List<ColumnInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_column_info(item, serializer); } }
+
+@protected void sse_encode_list_cozo_value(List<CozoValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cozo_value(item, serializer); } }
+
+@protected void sse_encode_list_embedding(List<Embedding> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_embedding(item, serializer); } }
+
+@protected void sse_encode_list_encrypted_column(List<EncryptedColumn> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_encrypted_column(item, serializer); } }
+
+@protected void sse_encode_list_error_count(List<ErrorCount> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_error_count(item, serializer); } }
+
+@protected void sse_encode_list_fts_filter(List<FtsFilter> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_fts_filter(item, serializer); } }
+
+@protected void sse_encode_list_fts_hit(List<FtsHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_fts_hit(item, serializer); } }
+
+@protected void sse_encode_list_import_conflict(List<ImportConflict> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_import_conflict(item, serializer); } }
+
+@protected void sse_encode_list_index_summary(List<IndexSummary> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_index_summary(item, serializer); } }
+
+@protected void sse_encode_list_latency_bucket(List<LatencyBucket> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_latency_bucket(item, serializer); } }
+
+@protected void sse_encode_list_list_cozo_value(List<List<CozoValue>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_cozo_value(item, serializer); } }
+
+@protected void sse_encode_list_lsh_hit(List<LshHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lsh_hit(item, serializer); } }
+
+@protected void sse_encode_list_migration(List<Migration> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_migration(item, serializer); } }
+
+@protected void sse_encode_list_plan_rule(List<PlanRule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_plan_rule(item, serializer); } }
+
+@protected void sse_encode_list_plan_step(List<PlanStep> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_plan_step(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self is Float32List ? self : Float32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self); }
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat64List(self); }
+
+@protected void sse_encode_list_prim_i_64_strict(Int64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt64List(self); }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_query_result(List<QueryResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_result(item, serializer); } }
+
+@protected void sse_encode_list_record_string_cozo_value(List<(String,CozoValue)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_cozo_value(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_relation_diff(List<RelationDiff> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_relation_diff(item, serializer); } }
+
+@protected void sse_encode_list_relation_info(List<RelationInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_relation_info(item, serializer); } }
+
+@protected void sse_encode_list_relation_integrity_error(List<RelationIntegrityError> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_relation_integrity_error(item, serializer); } }
+
+@protected void sse_encode_list_row_policy(List<RowPolicy> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_row_policy(item, serializer); } }
+
+@protected void sse_encode_list_running_query_info(List<RunningQueryInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_running_query_info(item, serializer); } }
+
+@protected void sse_encode_list_schema_mismatch(List<SchemaMismatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_schema_mismatch(item, serializer); } }
+
+@protected void sse_encode_list_script_diagnostic(List<ScriptDiagnostic> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_script_diagnostic(item, serializer); } }
+
+@protected void sse_encode_list_script_with_params(List<ScriptWithParams> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_script_with_params(item, serializer); } }
+
+@protected void sse_encode_list_slow_query(List<SlowQuery> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_slow_query(item, serializer); } }
+
+@protected void sse_encode_list_transaction_info(List<TransactionInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_transaction_info(item, serializer); } }
+
+@protected void sse_encode_list_ttl_setting(List<TtlSetting> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ttl_setting(item, serializer); } }
+
+@protected void sse_encode_list_vector_hit(List<VectorHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_vector_hit(item, serializer); } }
+
+@protected void sse_encode_list_vector_index_info(List<VectorIndexInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_vector_index_info(item, serializer); } }
+
+@protected void sse_encode_lock_holder(LockHolder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_f_64(self.since, serializer);
+ }
+
+@protected void sse_encode_lsh_config(LshConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fts_tokenizer(self.tokenizer, serializer);
+sse_encode_list_fts_filter(self.filters, serializer);
+sse_encode_u_32(self.nGram, serializer);
+sse_encode_u_32(self.nPerm, serializer);
+sse_encode_f_64(self.targetThreshold, serializer);
+sse_encode_f_64(self.falsePositiveWeight, serializer);
+sse_encode_f_64(self.falseNegativeWeight, serializer);
+ }
+
+@protected void sse_encode_lsh_hit(LshHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_cozo_value(self.key, serializer);
+sse_encode_list_cozo_value(self.row, serializer);
+ }
+
+@protected void sse_encode_maintenance_report(MaintenanceReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.finished, serializer);
+sse_encode_u_64(self.rowsPurged, serializer);
+sse_encode_u_64(self.pagesFreed, serializer);
+sse_encode_u_64(self.elapsedMs, serializer);
+ }
+
+@protected void sse_encode_migration(Migration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.version, serializer);
+sse_encode_String(self.script, serializer);
+ }
+
+@protected void sse_encode_migration_report(MigrationReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_i_64_strict(self.applied, serializer);
+sse_encode_opt_box_autoadd_i_64(self.currentVersion, serializer);
+ }
+
+@protected void sse_encode_on_conflict(OnConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Chrono_Utc(DateTime? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Chrono_Utc(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_busy_retry_policy(BusyRetryPolicy? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_busy_retry_policy(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_column_default(ColumnDefault? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_column_default(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_cozo_dart_error(CozoDartError? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_cozo_dart_error(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_error_span(ErrorSpan? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_error_span(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_lock_holder(LockHolder? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_lock_holder(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_snippet_config(SnippetConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_snippet_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_cozo_value(List<CozoValue>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_cozo_value(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_plan_rule(PlanRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.stratum, serializer);
+sse_encode_u_32(self.ruleIdx, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_list_plan_step(self.steps, serializer);
+ }
+
+@protected void sse_encode_plan_step(PlanStep self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.atomIdx, serializer);
+sse_encode_String(self.op, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_list_record_string_string(self.joinsOn, serializer);
+sse_encode_list_String(self.filters, serializer);
+sse_encode_opt_String(self.expr, serializer);
+sse_encode_list_String(self.output, serializer);
+ }
+
+@protected void sse_encode_query_options(QueryOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.immutable, serializer);
+sse_encode_opt_box_autoadd_u_32(self.timeoutMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.heapLimitBytes, serializer);
+ }
+
+@protected void sse_encode_query_plan(QueryPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_plan_rule(self.rules, serializer);
+sse_encode_f_64(self.took, serializer);
+ }
+
+@protected void sse_encode_query_result(QueryResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.ok, serializer);
+sse_encode_list_String(self.headers, serializer);
+sse_encode_list_list_cozo_value(self.rows, serializer);
+sse_encode_f_64(self.took, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_record_string_cozo_value((String,CozoValue) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_cozo_value(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_recovered_db(RecoveredDb self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(self.db, serializer);
+sse_encode_recovery_report(self.report, serializer);
+ }
+
+@protected void sse_encode_recovery_policy(RecoveryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_recovery_report(RecoveryReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.recovered, serializer);
+sse_encode_opt_String(self.reason, serializer);
+sse_encode_opt_String(self.corruptPath, serializer);
+sse_encode_list_String(self.relationsRecovered, serializer);
+sse_encode_u_64(self.rowsRecovered, serializer);
+sse_encode_list_String(self.relationsLost, serializer);
+sse_encode_list_String(self.indicesDropped, serializer);
+ }
+
+@protected void sse_encode_relation_change(RelationChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_change_op(self.op, serializer);
+sse_encode_list_String(self.headers, serializer);
+sse_encode_list_list_cozo_value(self.newRows, serializer);
+sse_encode_list_list_cozo_value(self.oldRows, serializer);
+ }
+
+@protected void sse_encode_relation_diff(RelationDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_list_schema_mismatch(self.columns, serializer);
+sse_encode_list_String(self.addedIndices, serializer);
+sse_encode_list_String(self.removedIndices, serializer);
+sse_encode_list_String(self.changedIndices, serializer);
+ }
+
+@protected void sse_encode_relation_info(RelationInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.arity, serializer);
+sse_encode_String(self.accessLevel, serializer);
+sse_encode_u_32(self.nKeys, serializer);
+sse_encode_u_32(self.nNonKeys, serializer);
+sse_encode_u_32(self.nPutTriggers, serializer);
+sse_encode_u_32(self.nRmTriggers, serializer);
+sse_encode_u_32(self.nReplaceTriggers, serializer);
+sse_encode_String(self.description, serializer);
+ }
+
+@protected void sse_encode_relation_integrity_error(RelationIntegrityError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_relation_stats(RelationStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.rows, serializer);
+sse_encode_u_64(self.approxBytes, serializer);
+sse_encode_list_index_summary(self.indices, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastModified, serializer);
+ }
+
+@protected void sse_encode_result_encoding(ResultEncoding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxAttempts, serializer);
+sse_encode_u_64(self.initialDelayMs, serializer);
+sse_encode_u_64(self.maxDelayMs, serializer);
+ }
+
+@protected void sse_encode_row_policy(RowPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_String(self.predicate, serializer);
+ }
+
+@protected void sse_encode_running_query_info(RunningQueryInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.script, serializer);
+sse_encode_f_64(self.startedAt, serializer);
+sse_encode_f_64(self.elapsed, serializer);
+ }
+
+@protected void sse_encode_schema_diff(SchemaDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.addedRelations, serializer);
+sse_encode_list_String(self.removedRelations, serializer);
+sse_encode_list_relation_diff(self.changedRelations, serializer);
+ }
+
+@protected void sse_encode_schema_mismatch(SchemaMismatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.column, serializer);
+sse_encode_schema_mismatch_kind(self.kind, serializer);
+sse_encode_opt_String(self.expected, serializer);
+sse_encode_opt_String(self.actual, serializer);
+ }
+
+@protected void sse_encode_schema_mismatch_kind(SchemaMismatchKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_script_diagnostic(ScriptDiagnostic self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer);
+sse_encode_diagnostic_severity(self.severity, serializer);
+sse_encode_opt_String(self.code, serializer);
+sse_encode_text_position(self.start, serializer);
+sse_encode_text_position(self.end, serializer);
+sse_encode_opt_String(self.label, serializer);
+sse_encode_opt_String(self.hint, serializer);
+ }
+
+@protected void sse_encode_script_with_params(ScriptWithParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.script, serializer);
+sse_encode_Map_String_cozo_value_None(self.params, serializer);
+ }
+
+@protected void sse_encode_slow_query(SlowQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.script, serializer);
+sse_encode_String(self.paramsDigest, serializer);
+sse_encode_f_64(self.took, serializer);
+sse_encode_u_64(self.rows, serializer);
+sse_encode_f_64(self.finishedAt, serializer);
+ }
+
+@protected void sse_encode_snippet_config(SnippetConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.open, serializer);
+sse_encode_String(self.close, serializer);
+sse_encode_u_32(self.maxWords, serializer);
+ }
+
+@protected void sse_encode_sync_changeset(SyncChangeset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.payload, serializer);
+sse_encode_String(self.token, serializer);
+sse_encode_u_64(self.changes, serializer);
+ }
+
+@protected void sse_encode_sync_report(SyncReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.applied, serializer);
+sse_encode_u_64(self.skipped, serializer);
+sse_encode_u_64(self.resolved, serializer);
+ }
+
+@protected void sse_encode_text_position(TextPosition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.offset, serializer);
+sse_encode_u_32(self.line, serializer);
+sse_encode_u_32(self.column, serializer);
+ }
+
+@protected void sse_encode_transaction_info(TransactionInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.id, serializer);
+sse_encode_tx_mode(self.mode, serializer);
+sse_encode_f_64(self.ageSecs, serializer);
+sse_encode_f_64(self.idleSecs, serializer);
+sse_encode_u_32(self.scriptsRun, serializer);
+sse_encode_u_32(self.writeScripts, serializer);
+ }
+
+@protected void sse_encode_trigger_spec(TriggerSpec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.onPut, serializer);
+sse_encode_list_String(self.onRm, serializer);
+sse_encode_list_String(self.onReplace, serializer);
+ }
+
+@protected void sse_encode_ttl_setting(TtlSetting self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_String(self.column, serializer);
+sse_encode_f_64(self.ttlSecs, serializer);
+ }
+
+@protected void sse_encode_tx_mode(TxMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_validation_result(ValidationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.valid, serializer);
+sse_encode_bool(self.checked, serializer);
+sse_encode_opt_box_autoadd_cozo_dart_error(self.error, serializer);
+ }
+
+@protected void sse_encode_validity_time(ValidityTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Chrono_Utc(self.validFrom, serializer);
+sse_encode_bool(self.isAssert, serializer);
+ }
+
+@protected void sse_encode_vector_distance(VectorDistance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_vector_hit(VectorHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_cozo_value(self.key, serializer);
+sse_encode_f_64(self.distance, serializer);
+sse_encode_list_cozo_value(self.row, serializer);
+ }
+
+@protected void sse_encode_vector_index_info(VectorIndexInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.relation, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.dim, serializer);
+sse_encode_vector_type(self.dtype, serializer);
+sse_encode_list_String(self.fields, serializer);
+sse_encode_vector_distance(self.distance, serializer);
+sse_encode_u_32(self.m, serializer);
+sse_encode_u_32(self.efConstruction, serializer);
+sse_encode_bool(self.extendCandidates, serializer);
+sse_encode_bool(self.keepPrunedConnections, serializer);
+ }
+
+@protected void sse_encode_vector_type(VectorType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+                }
+                
+
+            @sealed class AuditorImpl extends RustOpaque implements Auditor {
+                // Not to be used by end users
+                AuditorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                AuditorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Auditor,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Auditor,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_AuditorPtr,
+                );
+
+                
+            }
+            @sealed class CozoCursorImpl extends RustOpaque implements CozoCursor {
+                // Not to be used by end users
+                CozoCursorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CozoCursorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CozoCursor,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CozoCursor,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CozoCursorPtr,
+                );
+
+                
+            }
+            @sealed class CozoDbImpl extends RustOpaque implements CozoDb {
+                // Not to be used by end users
+                CozoDbImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CozoDbImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CozoDb,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CozoDb,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CozoDbPtr,
+                );
+
+                
+            }
+            @sealed class CozoTxImpl extends RustOpaque implements CozoTx {
+                // Not to be used by end users
+                CozoTxImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CozoTxImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CozoTx,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CozoTx,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CozoTxPtr,
+                );
+
+                
+            }
+            @sealed class PreparedQueryImpl extends RustOpaque implements PreparedQuery {
+                // Not to be used by end users
+                PreparedQueryImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PreparedQueryImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PreparedQuery,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PreparedQuery,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PreparedQueryPtr,
+                );
+
+                
+            }
+            @sealed class RowPoliciesImpl extends RustOpaque implements RowPolicies {
+                // Not to be used by end users
+                RowPoliciesImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RowPoliciesImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RowPolicies,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RowPolicies,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RowPoliciesPtr,
+                );
+
+                
+            }
+            @sealed class SlowLogImpl extends RustOpaque implements SlowLog {
+                // Not to be used by end users
+                SlowLogImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SlowLogImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SlowLog,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SlowLog,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SlowLogPtr,
+                );
+
+                
+            }
+            @sealed class TenantManagerImpl extends RustOpaque implements TenantManager {
+                // Not to be used by end users
+                TenantManagerImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                TenantManagerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_TenantManager,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_TenantManager,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_TenantManagerPtr,
+                );
+
+                
+            }
\ No newline at end of file
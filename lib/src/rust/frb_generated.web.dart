@@ -3,195 +3,1418 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/asset.dart';
+import 'api/audit.dart';
+import 'api/auto_backup.dart';
+import 'api/backup.dart';
+import 'api/batch.dart';
+import 'api/busy_retry.dart';
+import 'api/callback.dart';
+import 'api/cdc.dart';
+import 'api/column_crypto.dart';
+import 'api/compression.dart';
+import 'api/crdt.dart';
+import 'api/cursor.dart';
+import 'api/encoding.dart';
+import 'api/error.dart';
+import 'api/explain.dart';
+import 'api/export.dart';
+import 'api/file_lock.dart';
+import 'api/fixed_rule.dart';
+import 'api/format.dart';
+import 'api/import.dart';
+import 'api/incremental.dart';
+import 'api/logging.dart';
+import 'api/maintenance.dart';
+import 'api/metrics.dart';
+import 'api/migration.dart';
+import 'api/paths.dart';
+import 'api/prepared.dart';
+import 'api/query_cache.dart';
+import 'api/recovery.dart';
+import 'api/registry.dart';
+import 'api/retry.dart';
+import 'api/row_policy.dart';
+import 'api/rows.dart';
+import 'api/running.dart';
+import 'api/schema.dart';
+import 'api/search.dart';
 import 'api/simple.dart';
+import 'api/slow_log.dart';
+import 'api/soft_delete.dart';
+import 'api/stats.dart';
+import 'api/storage.dart';
+import 'api/stream.dart';
+import 'api/sync.dart';
+import 'api/temporal.dart';
+import 'api/tenant.dart';
+import 'api/transaction.dart';
+import 'api/triggers.dart';
+import 'api/ttl.dart';
+import 'api/validate.dart';
+import 'api/value.dart';
+import 'api/vector.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CozoDbPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
-
-  @protected
-  CozoDb
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    dynamic raw,
-  );
-
-  @protected
-  CozoDb
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    dynamic raw,
-  );
-
-  @protected
-  CozoDb
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    dynamic raw,
-  );
-
-  @protected
-  String dco_decode_String(dynamic raw);
-
-  @protected
-  bool dco_decode_bool(dynamic raw);
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
-
-  @protected
-  int dco_decode_u_8(dynamic raw);
-
-  @protected
-  void dco_decode_unit(dynamic raw);
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
-
-  @protected
-  CozoDb
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CozoDb
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CozoDb
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    CozoDb self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    CozoDb self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    CozoDb self,
-    SseSerializer serializer,
-  );
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AuditorPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor;
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CozoCursorPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor;
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CozoDbPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb;
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CozoTxPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx;
 
-// Section: wire_class
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PreparedQueryPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery;
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-        ptr,
-      );
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(
-    int ptr,
-  );
-}
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RowPoliciesPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SlowLogPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TenantManagerPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected Auditor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(dynamic raw);
+
+@protected CozoCursor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(dynamic raw);
+
+@protected CozoDb dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(dynamic raw);
+
+@protected CozoTx dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(dynamic raw);
+
+@protected PreparedQuery dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(dynamic raw);
+
+@protected RowPolicies dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(dynamic raw);
+
+@protected SlowLog dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(dynamic raw);
+
+@protected TenantManager dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(dynamic raw);
+
+@protected CozoCursor dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(dynamic raw);
+
+@protected CozoDb dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(dynamic raw);
+
+@protected CozoTx dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(dynamic raw);
+
+@protected PreparedQuery dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(dynamic raw);
+
+@protected TenantManager dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(dynamic raw);
+
+@protected DateTime dco_decode_Chrono_Utc(dynamic raw);
+
+@protected FutureOr<String> Function(String) dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(dynamic raw);
+
+@protected FutureOr<String> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_String_AnyhowException(dynamic raw);
+
+@protected FutureOr<String> Function() dco_decode_DartFn_Inputs__Output_String_AnyhowException(dynamic raw);
+
+@protected Object dco_decode_DartOpaque(dynamic raw);
+
+@protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+@protected Map<String, CozoValue> dco_decode_Map_String_cozo_value_None(dynamic raw);
+
+@protected Auditor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(dynamic raw);
+
+@protected CozoCursor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(dynamic raw);
+
+@protected CozoDb dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(dynamic raw);
+
+@protected CozoTx dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(dynamic raw);
+
+@protected PreparedQuery dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(dynamic raw);
+
+@protected RowPolicies dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(dynamic raw);
+
+@protected SlowLog dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(dynamic raw);
+
+@protected TenantManager dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(dynamic raw);
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+@protected RustStreamSink<RelationChange> dco_decode_StreamSink_relation_change_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected Encoder dco_decode_TraitDef_Encoder(dynamic raw);
+
+@protected AssetOpenMode dco_decode_asset_open_mode(dynamic raw);
+
+@protected AuditEntry dco_decode_audit_entry(dynamic raw);
+
+@protected AuditQuery dco_decode_audit_query(dynamic raw);
+
+@protected BackupCompression dco_decode_backup_compression(dynamic raw);
+
+@protected BackupInfo dco_decode_backup_info(dynamic raw);
+
+@protected BackupPolicy dco_decode_backup_policy(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw);
+
+@protected AuditQuery dco_decode_box_autoadd_audit_query(dynamic raw);
+
+@protected BackupCompression dco_decode_box_autoadd_backup_compression(dynamic raw);
+
+@protected BackupPolicy dco_decode_box_autoadd_backup_policy(dynamic raw);
+
+@protected BusyRetryPolicy dco_decode_box_autoadd_busy_retry_policy(dynamic raw);
+
+@protected ColumnDefault dco_decode_box_autoadd_column_default(dynamic raw);
+
+@protected CozoDartError dco_decode_box_autoadd_cozo_dart_error(dynamic raw);
+
+@protected CozoValue dco_decode_box_autoadd_cozo_value(dynamic raw);
+
+@protected CsvImportConfig dco_decode_box_autoadd_csv_import_config(dynamic raw);
+
+@protected ErrorSpan dco_decode_box_autoadd_error_span(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
+
+@protected FtsConfig dco_decode_box_autoadd_fts_config(dynamic raw);
+
+@protected HnswConfig dco_decode_box_autoadd_hnsw_config(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected ImportOptions dco_decode_box_autoadd_import_options(dynamic raw);
+
+@protected LockHolder dco_decode_box_autoadd_lock_holder(dynamic raw);
+
+@protected LshConfig dco_decode_box_autoadd_lsh_config(dynamic raw);
+
+@protected QueryOptions dco_decode_box_autoadd_query_options(dynamic raw);
+
+@protected RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw);
+
+@protected SnippetConfig dco_decode_box_autoadd_snippet_config(dynamic raw);
+
+@protected TriggerSpec dco_decode_box_autoadd_trigger_spec(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected ValidityTime dco_decode_box_autoadd_validity_time(dynamic raw);
+
+@protected BusyRetryPolicy dco_decode_busy_retry_policy(dynamic raw);
+
+@protected CdcEntry dco_decode_cdc_entry(dynamic raw);
+
+@protected CdcOp dco_decode_cdc_op(dynamic raw);
+
+@protected ChangeOp dco_decode_change_op(dynamic raw);
+
+@protected ColumnDef dco_decode_column_def(dynamic raw);
+
+@protected ColumnDefault dco_decode_column_default(dynamic raw);
+
+@protected ColumnInfo dco_decode_column_info(dynamic raw);
+
+@protected CozoDartError dco_decode_cozo_dart_error(dynamic raw);
+
+@protected CozoMetrics dco_decode_cozo_metrics(dynamic raw);
+
+@protected CozoValue dco_decode_cozo_value(dynamic raw);
+
+@protected CrdtMergeReport dco_decode_crdt_merge_report(dynamic raw);
+
+@protected CsvImportConfig dco_decode_csv_import_config(dynamic raw);
+
+@protected CursorPage dco_decode_cursor_page(dynamic raw);
+
+@protected DbLocation dco_decode_db_location(dynamic raw);
+
+@protected DbStats dco_decode_db_stats(dynamic raw);
+
+@protected DiagnosticSeverity dco_decode_diagnostic_severity(dynamic raw);
+
+@protected Embedding dco_decode_embedding(dynamic raw);
+
+@protected EncryptedColumn dco_decode_encrypted_column(dynamic raw);
+
+@protected EnsureRelationReport dco_decode_ensure_relation_report(dynamic raw);
+
+@protected ErrorCount dco_decode_error_count(dynamic raw);
+
+@protected ErrorKind dco_decode_error_kind(dynamic raw);
+
+@protected ErrorSpan dco_decode_error_span(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FtsConfig dco_decode_fts_config(dynamic raw);
+
+@protected FtsFilter dco_decode_fts_filter(dynamic raw);
+
+@protected FtsHit dco_decode_fts_hit(dynamic raw);
+
+@protected FtsTokenizer dco_decode_fts_tokenizer(dynamic raw);
+
+@protected HnswConfig dco_decode_hnsw_config(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected ImportConflict dco_decode_import_conflict(dynamic raw);
+
+@protected ImportOptions dco_decode_import_options(dynamic raw);
+
+@protected ImportReport dco_decode_import_report(dynamic raw);
+
+@protected ImportStrategy dco_decode_import_strategy(dynamic raw);
+
+@protected IncrementalBackupInfo dco_decode_incremental_backup_info(dynamic raw);
+
+@protected IndexSummary dco_decode_index_summary(dynamic raw);
+
+@protected IntegrityReport dco_decode_integrity_report(dynamic raw);
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
+@protected LatencyBucket dco_decode_latency_bucket(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<AuditEntry> dco_decode_list_audit_entry(dynamic raw);
+
+@protected List<CdcEntry> dco_decode_list_cdc_entry(dynamic raw);
+
+@protected List<ColumnDef> dco_decode_list_column_def(dynamic raw);
+
+@protected List<ColumnInfo> dco_decode_list_column_info(dynamic raw);
+
+@protected List<CozoValue> dco_decode_list_cozo_value(dynamic raw);
+
+@protected List<Embedding> dco_decode_list_embedding(dynamic raw);
+
+@protected List<EncryptedColumn> dco_decode_list_encrypted_column(dynamic raw);
+
+@protected List<ErrorCount> dco_decode_list_error_count(dynamic raw);
+
+@protected List<FtsFilter> dco_decode_list_fts_filter(dynamic raw);
+
+@protected List<FtsHit> dco_decode_list_fts_hit(dynamic raw);
+
+@protected List<ImportConflict> dco_decode_list_import_conflict(dynamic raw);
+
+@protected List<IndexSummary> dco_decode_list_index_summary(dynamic raw);
+
+@protected List<LatencyBucket> dco_decode_list_latency_bucket(dynamic raw);
+
+@protected List<List<CozoValue>> dco_decode_list_list_cozo_value(dynamic raw);
+
+@protected List<LshHit> dco_decode_list_lsh_hit(dynamic raw);
+
+@protected List<Migration> dco_decode_list_migration(dynamic raw);
+
+@protected List<PlanRule> dco_decode_list_plan_rule(dynamic raw);
+
+@protected List<PlanStep> dco_decode_list_plan_step(dynamic raw);
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+@protected Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<QueryResult> dco_decode_list_query_result(dynamic raw);
+
+@protected List<(String,CozoValue)> dco_decode_list_record_string_cozo_value(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected List<RelationDiff> dco_decode_list_relation_diff(dynamic raw);
+
+@protected List<RelationInfo> dco_decode_list_relation_info(dynamic raw);
+
+@protected List<RelationIntegrityError> dco_decode_list_relation_integrity_error(dynamic raw);
+
+@protected List<RowPolicy> dco_decode_list_row_policy(dynamic raw);
+
+@protected List<RunningQueryInfo> dco_decode_list_running_query_info(dynamic raw);
+
+@protected List<SchemaMismatch> dco_decode_list_schema_mismatch(dynamic raw);
+
+@protected List<ScriptDiagnostic> dco_decode_list_script_diagnostic(dynamic raw);
+
+@protected List<ScriptWithParams> dco_decode_list_script_with_params(dynamic raw);
+
+@protected List<SlowQuery> dco_decode_list_slow_query(dynamic raw);
+
+@protected List<TransactionInfo> dco_decode_list_transaction_info(dynamic raw);
+
+@protected List<TtlSetting> dco_decode_list_ttl_setting(dynamic raw);
+
+@protected List<VectorHit> dco_decode_list_vector_hit(dynamic raw);
+
+@protected List<VectorIndexInfo> dco_decode_list_vector_index_info(dynamic raw);
+
+@protected LockHolder dco_decode_lock_holder(dynamic raw);
+
+@protected LshConfig dco_decode_lsh_config(dynamic raw);
+
+@protected LshHit dco_decode_lsh_hit(dynamic raw);
+
+@protected MaintenanceReport dco_decode_maintenance_report(dynamic raw);
+
+@protected Migration dco_decode_migration(dynamic raw);
+
+@protected MigrationReport dco_decode_migration_report(dynamic raw);
+
+@protected OnConflict dco_decode_on_conflict(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw);
+
+@protected BusyRetryPolicy? dco_decode_opt_box_autoadd_busy_retry_policy(dynamic raw);
+
+@protected ColumnDefault? dco_decode_opt_box_autoadd_column_default(dynamic raw);
+
+@protected CozoDartError? dco_decode_opt_box_autoadd_cozo_dart_error(dynamic raw);
+
+@protected ErrorSpan? dco_decode_opt_box_autoadd_error_span(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected LockHolder? dco_decode_opt_box_autoadd_lock_holder(dynamic raw);
+
+@protected SnippetConfig? dco_decode_opt_box_autoadd_snippet_config(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected List<CozoValue>? dco_decode_opt_list_cozo_value(dynamic raw);
+
+@protected PlanRule dco_decode_plan_rule(dynamic raw);
+
+@protected PlanStep dco_decode_plan_step(dynamic raw);
+
+@protected QueryOptions dco_decode_query_options(dynamic raw);
+
+@protected QueryPlan dco_decode_query_plan(dynamic raw);
+
+@protected QueryResult dco_decode_query_result(dynamic raw);
+
+@protected (String,CozoValue) dco_decode_record_string_cozo_value(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected RecoveredDb dco_decode_recovered_db(dynamic raw);
+
+@protected RecoveryPolicy dco_decode_recovery_policy(dynamic raw);
+
+@protected RecoveryReport dco_decode_recovery_report(dynamic raw);
+
+@protected RelationChange dco_decode_relation_change(dynamic raw);
+
+@protected RelationDiff dco_decode_relation_diff(dynamic raw);
+
+@protected RelationInfo dco_decode_relation_info(dynamic raw);
+
+@protected RelationIntegrityError dco_decode_relation_integrity_error(dynamic raw);
+
+@protected RelationStats dco_decode_relation_stats(dynamic raw);
+
+@protected ResultEncoding dco_decode_result_encoding(dynamic raw);
+
+@protected RetryPolicy dco_decode_retry_policy(dynamic raw);
+
+@protected RowPolicy dco_decode_row_policy(dynamic raw);
+
+@protected RunningQueryInfo dco_decode_running_query_info(dynamic raw);
+
+@protected SchemaDiff dco_decode_schema_diff(dynamic raw);
+
+@protected SchemaMismatch dco_decode_schema_mismatch(dynamic raw);
+
+@protected SchemaMismatchKind dco_decode_schema_mismatch_kind(dynamic raw);
+
+@protected ScriptDiagnostic dco_decode_script_diagnostic(dynamic raw);
+
+@protected ScriptWithParams dco_decode_script_with_params(dynamic raw);
+
+@protected SlowQuery dco_decode_slow_query(dynamic raw);
+
+@protected SnippetConfig dco_decode_snippet_config(dynamic raw);
+
+@protected SyncChangeset dco_decode_sync_changeset(dynamic raw);
+
+@protected SyncReport dco_decode_sync_report(dynamic raw);
+
+@protected TextPosition dco_decode_text_position(dynamic raw);
+
+@protected TransactionInfo dco_decode_transaction_info(dynamic raw);
+
+@protected TriggerSpec dco_decode_trigger_spec(dynamic raw);
+
+@protected TtlSetting dco_decode_ttl_setting(dynamic raw);
+
+@protected TxMode dco_decode_tx_mode(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected ValidationResult dco_decode_validation_result(dynamic raw);
+
+@protected ValidityTime dco_decode_validity_time(dynamic raw);
+
+@protected VectorDistance dco_decode_vector_distance(dynamic raw);
+
+@protected VectorHit dco_decode_vector_hit(dynamic raw);
+
+@protected VectorIndexInfo dco_decode_vector_index_info(dynamic raw);
+
+@protected VectorType dco_decode_vector_type(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected Auditor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(SseDeserializer deserializer);
+
+@protected CozoCursor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(SseDeserializer deserializer);
+
+@protected CozoDb sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(SseDeserializer deserializer);
+
+@protected CozoTx sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(SseDeserializer deserializer);
+
+@protected PreparedQuery sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(SseDeserializer deserializer);
+
+@protected RowPolicies sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(SseDeserializer deserializer);
+
+@protected SlowLog sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SseDeserializer deserializer);
+
+@protected TenantManager sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(SseDeserializer deserializer);
+
+@protected CozoCursor sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(SseDeserializer deserializer);
+
+@protected CozoDb sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(SseDeserializer deserializer);
+
+@protected CozoTx sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(SseDeserializer deserializer);
+
+@protected PreparedQuery sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(SseDeserializer deserializer);
+
+@protected TenantManager sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(SseDeserializer deserializer);
+
+@protected DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer);
+
+@protected Map<String, CozoValue> sse_decode_Map_String_cozo_value_None(SseDeserializer deserializer);
+
+@protected Auditor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(SseDeserializer deserializer);
+
+@protected CozoCursor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(SseDeserializer deserializer);
+
+@protected CozoDb sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(SseDeserializer deserializer);
+
+@protected CozoTx sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(SseDeserializer deserializer);
+
+@protected PreparedQuery sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(SseDeserializer deserializer);
+
+@protected RowPolicies sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(SseDeserializer deserializer);
+
+@protected SlowLog sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SseDeserializer deserializer);
+
+@protected TenantManager sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<RelationChange> sse_decode_StreamSink_relation_change_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AssetOpenMode sse_decode_asset_open_mode(SseDeserializer deserializer);
+
+@protected AuditEntry sse_decode_audit_entry(SseDeserializer deserializer);
+
+@protected AuditQuery sse_decode_audit_query(SseDeserializer deserializer);
+
+@protected BackupCompression sse_decode_backup_compression(SseDeserializer deserializer);
+
+@protected BackupInfo sse_decode_backup_info(SseDeserializer deserializer);
+
+@protected BackupPolicy sse_decode_backup_policy(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
+
+@protected AuditQuery sse_decode_box_autoadd_audit_query(SseDeserializer deserializer);
+
+@protected BackupCompression sse_decode_box_autoadd_backup_compression(SseDeserializer deserializer);
+
+@protected BackupPolicy sse_decode_box_autoadd_backup_policy(SseDeserializer deserializer);
+
+@protected BusyRetryPolicy sse_decode_box_autoadd_busy_retry_policy(SseDeserializer deserializer);
+
+@protected ColumnDefault sse_decode_box_autoadd_column_default(SseDeserializer deserializer);
+
+@protected CozoDartError sse_decode_box_autoadd_cozo_dart_error(SseDeserializer deserializer);
+
+@protected CozoValue sse_decode_box_autoadd_cozo_value(SseDeserializer deserializer);
+
+@protected CsvImportConfig sse_decode_box_autoadd_csv_import_config(SseDeserializer deserializer);
+
+@protected ErrorSpan sse_decode_box_autoadd_error_span(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FtsConfig sse_decode_box_autoadd_fts_config(SseDeserializer deserializer);
+
+@protected HnswConfig sse_decode_box_autoadd_hnsw_config(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected ImportOptions sse_decode_box_autoadd_import_options(SseDeserializer deserializer);
+
+@protected LockHolder sse_decode_box_autoadd_lock_holder(SseDeserializer deserializer);
+
+@protected LshConfig sse_decode_box_autoadd_lsh_config(SseDeserializer deserializer);
+
+@protected QueryOptions sse_decode_box_autoadd_query_options(SseDeserializer deserializer);
+
+@protected RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer);
+
+@protected SnippetConfig sse_decode_box_autoadd_snippet_config(SseDeserializer deserializer);
+
+@protected TriggerSpec sse_decode_box_autoadd_trigger_spec(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected ValidityTime sse_decode_box_autoadd_validity_time(SseDeserializer deserializer);
+
+@protected BusyRetryPolicy sse_decode_busy_retry_policy(SseDeserializer deserializer);
+
+@protected CdcEntry sse_decode_cdc_entry(SseDeserializer deserializer);
+
+@protected CdcOp sse_decode_cdc_op(SseDeserializer deserializer);
+
+@protected ChangeOp sse_decode_change_op(SseDeserializer deserializer);
+
+@protected ColumnDef sse_decode_column_def(SseDeserializer deserializer);
+
+@protected ColumnDefault sse_decode_column_default(SseDeserializer deserializer);
+
+@protected ColumnInfo sse_decode_column_info(SseDeserializer deserializer);
+
+@protected CozoDartError sse_decode_cozo_dart_error(SseDeserializer deserializer);
+
+@protected CozoMetrics sse_decode_cozo_metrics(SseDeserializer deserializer);
+
+@protected CozoValue sse_decode_cozo_value(SseDeserializer deserializer);
+
+@protected CrdtMergeReport sse_decode_crdt_merge_report(SseDeserializer deserializer);
+
+@protected CsvImportConfig sse_decode_csv_import_config(SseDeserializer deserializer);
+
+@protected CursorPage sse_decode_cursor_page(SseDeserializer deserializer);
+
+@protected DbLocation sse_decode_db_location(SseDeserializer deserializer);
+
+@protected DbStats sse_decode_db_stats(SseDeserializer deserializer);
+
+@protected DiagnosticSeverity sse_decode_diagnostic_severity(SseDeserializer deserializer);
+
+@protected Embedding sse_decode_embedding(SseDeserializer deserializer);
+
+@protected EncryptedColumn sse_decode_encrypted_column(SseDeserializer deserializer);
+
+@protected EnsureRelationReport sse_decode_ensure_relation_report(SseDeserializer deserializer);
+
+@protected ErrorCount sse_decode_error_count(SseDeserializer deserializer);
+
+@protected ErrorKind sse_decode_error_kind(SseDeserializer deserializer);
+
+@protected ErrorSpan sse_decode_error_span(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FtsConfig sse_decode_fts_config(SseDeserializer deserializer);
+
+@protected FtsFilter sse_decode_fts_filter(SseDeserializer deserializer);
+
+@protected FtsHit sse_decode_fts_hit(SseDeserializer deserializer);
+
+@protected FtsTokenizer sse_decode_fts_tokenizer(SseDeserializer deserializer);
+
+@protected HnswConfig sse_decode_hnsw_config(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected ImportConflict sse_decode_import_conflict(SseDeserializer deserializer);
+
+@protected ImportOptions sse_decode_import_options(SseDeserializer deserializer);
+
+@protected ImportReport sse_decode_import_report(SseDeserializer deserializer);
+
+@protected ImportStrategy sse_decode_import_strategy(SseDeserializer deserializer);
+
+@protected IncrementalBackupInfo sse_decode_incremental_backup_info(SseDeserializer deserializer);
+
+@protected IndexSummary sse_decode_index_summary(SseDeserializer deserializer);
+
+@protected IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+@protected LatencyBucket sse_decode_latency_bucket(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<AuditEntry> sse_decode_list_audit_entry(SseDeserializer deserializer);
+
+@protected List<CdcEntry> sse_decode_list_cdc_entry(SseDeserializer deserializer);
+
+@protected List<ColumnDef> sse_decode_list_column_def(SseDeserializer deserializer);
+
+@protected List<ColumnInfo> sse_decode_list_column_info(SseDeserializer deserializer);
+
+@protected List<CozoValue> sse_decode_list_cozo_value(SseDeserializer deserializer);
+
+@protected List<Embedding> sse_decode_list_embedding(SseDeserializer deserializer);
+
+@protected List<EncryptedColumn> sse_decode_list_encrypted_column(SseDeserializer deserializer);
+
+@protected List<ErrorCount> sse_decode_list_error_count(SseDeserializer deserializer);
+
+@protected List<FtsFilter> sse_decode_list_fts_filter(SseDeserializer deserializer);
+
+@protected List<FtsHit> sse_decode_list_fts_hit(SseDeserializer deserializer);
+
+@protected List<ImportConflict> sse_decode_list_import_conflict(SseDeserializer deserializer);
+
+@protected List<IndexSummary> sse_decode_list_index_summary(SseDeserializer deserializer);
+
+@protected List<LatencyBucket> sse_decode_list_latency_bucket(SseDeserializer deserializer);
+
+@protected List<List<CozoValue>> sse_decode_list_list_cozo_value(SseDeserializer deserializer);
+
+@protected List<LshHit> sse_decode_list_lsh_hit(SseDeserializer deserializer);
+
+@protected List<Migration> sse_decode_list_migration(SseDeserializer deserializer);
+
+@protected List<PlanRule> sse_decode_list_plan_rule(SseDeserializer deserializer);
+
+@protected List<PlanStep> sse_decode_list_plan_step(SseDeserializer deserializer);
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+@protected Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QueryResult> sse_decode_list_query_result(SseDeserializer deserializer);
+
+@protected List<(String,CozoValue)> sse_decode_list_record_string_cozo_value(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<RelationDiff> sse_decode_list_relation_diff(SseDeserializer deserializer);
+
+@protected List<RelationInfo> sse_decode_list_relation_info(SseDeserializer deserializer);
+
+@protected List<RelationIntegrityError> sse_decode_list_relation_integrity_error(SseDeserializer deserializer);
+
+@protected List<RowPolicy> sse_decode_list_row_policy(SseDeserializer deserializer);
+
+@protected List<RunningQueryInfo> sse_decode_list_running_query_info(SseDeserializer deserializer);
+
+@protected List<SchemaMismatch> sse_decode_list_schema_mismatch(SseDeserializer deserializer);
+
+@protected List<ScriptDiagnostic> sse_decode_list_script_diagnostic(SseDeserializer deserializer);
+
+@protected List<ScriptWithParams> sse_decode_list_script_with_params(SseDeserializer deserializer);
+
+@protected List<SlowQuery> sse_decode_list_slow_query(SseDeserializer deserializer);
+
+@protected List<TransactionInfo> sse_decode_list_transaction_info(SseDeserializer deserializer);
+
+@protected List<TtlSetting> sse_decode_list_ttl_setting(SseDeserializer deserializer);
+
+@protected List<VectorHit> sse_decode_list_vector_hit(SseDeserializer deserializer);
+
+@protected List<VectorIndexInfo> sse_decode_list_vector_index_info(SseDeserializer deserializer);
+
+@protected LockHolder sse_decode_lock_holder(SseDeserializer deserializer);
+
+@protected LshConfig sse_decode_lsh_config(SseDeserializer deserializer);
+
+@protected LshHit sse_decode_lsh_hit(SseDeserializer deserializer);
+
+@protected MaintenanceReport sse_decode_maintenance_report(SseDeserializer deserializer);
+
+@protected Migration sse_decode_migration(SseDeserializer deserializer);
+
+@protected MigrationReport sse_decode_migration_report(SseDeserializer deserializer);
+
+@protected OnConflict sse_decode_on_conflict(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
+
+@protected BusyRetryPolicy? sse_decode_opt_box_autoadd_busy_retry_policy(SseDeserializer deserializer);
+
+@protected ColumnDefault? sse_decode_opt_box_autoadd_column_default(SseDeserializer deserializer);
+
+@protected CozoDartError? sse_decode_opt_box_autoadd_cozo_dart_error(SseDeserializer deserializer);
+
+@protected ErrorSpan? sse_decode_opt_box_autoadd_error_span(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LockHolder? sse_decode_opt_box_autoadd_lock_holder(SseDeserializer deserializer);
+
+@protected SnippetConfig? sse_decode_opt_box_autoadd_snippet_config(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected List<CozoValue>? sse_decode_opt_list_cozo_value(SseDeserializer deserializer);
+
+@protected PlanRule sse_decode_plan_rule(SseDeserializer deserializer);
+
+@protected PlanStep sse_decode_plan_step(SseDeserializer deserializer);
+
+@protected QueryOptions sse_decode_query_options(SseDeserializer deserializer);
+
+@protected QueryPlan sse_decode_query_plan(SseDeserializer deserializer);
+
+@protected QueryResult sse_decode_query_result(SseDeserializer deserializer);
+
+@protected (String,CozoValue) sse_decode_record_string_cozo_value(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected RecoveredDb sse_decode_recovered_db(SseDeserializer deserializer);
+
+@protected RecoveryPolicy sse_decode_recovery_policy(SseDeserializer deserializer);
+
+@protected RecoveryReport sse_decode_recovery_report(SseDeserializer deserializer);
+
+@protected RelationChange sse_decode_relation_change(SseDeserializer deserializer);
+
+@protected RelationDiff sse_decode_relation_diff(SseDeserializer deserializer);
+
+@protected RelationInfo sse_decode_relation_info(SseDeserializer deserializer);
+
+@protected RelationIntegrityError sse_decode_relation_integrity_error(SseDeserializer deserializer);
+
+@protected RelationStats sse_decode_relation_stats(SseDeserializer deserializer);
+
+@protected ResultEncoding sse_decode_result_encoding(SseDeserializer deserializer);
+
+@protected RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer);
+
+@protected RowPolicy sse_decode_row_policy(SseDeserializer deserializer);
+
+@protected RunningQueryInfo sse_decode_running_query_info(SseDeserializer deserializer);
+
+@protected SchemaDiff sse_decode_schema_diff(SseDeserializer deserializer);
+
+@protected SchemaMismatch sse_decode_schema_mismatch(SseDeserializer deserializer);
+
+@protected SchemaMismatchKind sse_decode_schema_mismatch_kind(SseDeserializer deserializer);
+
+@protected ScriptDiagnostic sse_decode_script_diagnostic(SseDeserializer deserializer);
+
+@protected ScriptWithParams sse_decode_script_with_params(SseDeserializer deserializer);
+
+@protected SlowQuery sse_decode_slow_query(SseDeserializer deserializer);
+
+@protected SnippetConfig sse_decode_snippet_config(SseDeserializer deserializer);
+
+@protected SyncChangeset sse_decode_sync_changeset(SseDeserializer deserializer);
+
+@protected SyncReport sse_decode_sync_report(SseDeserializer deserializer);
+
+@protected TextPosition sse_decode_text_position(SseDeserializer deserializer);
+
+@protected TransactionInfo sse_decode_transaction_info(SseDeserializer deserializer);
+
+@protected TriggerSpec sse_decode_trigger_spec(SseDeserializer deserializer);
+
+@protected TtlSetting sse_decode_ttl_setting(SseDeserializer deserializer);
+
+@protected TxMode sse_decode_tx_mode(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected ValidationResult sse_decode_validation_result(SseDeserializer deserializer);
+
+@protected ValidityTime sse_decode_validity_time(SseDeserializer deserializer);
+
+@protected VectorDistance sse_decode_vector_distance(SseDeserializer deserializer);
+
+@protected VectorHit sse_decode_vector_hit(SseDeserializer deserializer);
+
+@protected VectorIndexInfo sse_decode_vector_index_info(SseDeserializer deserializer);
+
+@protected VectorType sse_decode_vector_type(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(Auditor self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(CozoCursor self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(CozoDb self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(CozoTx self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(PreparedQuery self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(RowPolicies self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SlowLog self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(TenantManager self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(CozoCursor self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(CozoDb self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(CozoTx self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(PreparedQuery self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(TenantManager self, SseSerializer serializer);
+
+@protected void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(FutureOr<String> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_String_AnyhowException(FutureOr<String> Function(String, String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs__Output_String_AnyhowException(FutureOr<String> Function() self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_cozo_value_None(Map<String, CozoValue> self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(Auditor self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(CozoCursor self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(CozoDb self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(CozoTx self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(PreparedQuery self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(RowPolicies self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(SlowLog self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(TenantManager self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_relation_change_Sse(RustStreamSink<RelationChange> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_asset_open_mode(AssetOpenMode self, SseSerializer serializer);
+
+@protected void sse_encode_audit_entry(AuditEntry self, SseSerializer serializer);
+
+@protected void sse_encode_audit_query(AuditQuery self, SseSerializer serializer);
+
+@protected void sse_encode_backup_compression(BackupCompression self, SseSerializer serializer);
+
+@protected void sse_encode_backup_info(BackupInfo self, SseSerializer serializer);
+
+@protected void sse_encode_backup_policy(BackupPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Chrono_Utc(DateTime self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_audit_query(AuditQuery self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_backup_compression(BackupCompression self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_backup_policy(BackupPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_busy_retry_policy(BusyRetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_column_default(ColumnDefault self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cozo_dart_error(CozoDartError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cozo_value(CozoValue self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_csv_import_config(CsvImportConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_error_span(ErrorSpan self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_fts_config(FtsConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_hnsw_config(HnswConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_import_options(ImportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lock_holder(LockHolder self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lsh_config(LshConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_query_options(QueryOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_retry_policy(RetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_snippet_config(SnippetConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_trigger_spec(TriggerSpec self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_validity_time(ValidityTime self, SseSerializer serializer);
+
+@protected void sse_encode_busy_retry_policy(BusyRetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_cdc_entry(CdcEntry self, SseSerializer serializer);
+
+@protected void sse_encode_cdc_op(CdcOp self, SseSerializer serializer);
+
+@protected void sse_encode_change_op(ChangeOp self, SseSerializer serializer);
+
+@protected void sse_encode_column_def(ColumnDef self, SseSerializer serializer);
+
+@protected void sse_encode_column_default(ColumnDefault self, SseSerializer serializer);
+
+@protected void sse_encode_column_info(ColumnInfo self, SseSerializer serializer);
+
+@protected void sse_encode_cozo_dart_error(CozoDartError self, SseSerializer serializer);
+
+@protected void sse_encode_cozo_metrics(CozoMetrics self, SseSerializer serializer);
+
+@protected void sse_encode_cozo_value(CozoValue self, SseSerializer serializer);
+
+@protected void sse_encode_crdt_merge_report(CrdtMergeReport self, SseSerializer serializer);
+
+@protected void sse_encode_csv_import_config(CsvImportConfig self, SseSerializer serializer);
+
+@protected void sse_encode_cursor_page(CursorPage self, SseSerializer serializer);
+
+@protected void sse_encode_db_location(DbLocation self, SseSerializer serializer);
+
+@protected void sse_encode_db_stats(DbStats self, SseSerializer serializer);
+
+@protected void sse_encode_diagnostic_severity(DiagnosticSeverity self, SseSerializer serializer);
+
+@protected void sse_encode_embedding(Embedding self, SseSerializer serializer);
+
+@protected void sse_encode_encrypted_column(EncryptedColumn self, SseSerializer serializer);
+
+@protected void sse_encode_ensure_relation_report(EnsureRelationReport self, SseSerializer serializer);
+
+@protected void sse_encode_error_count(ErrorCount self, SseSerializer serializer);
+
+@protected void sse_encode_error_kind(ErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_error_span(ErrorSpan self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_fts_config(FtsConfig self, SseSerializer serializer);
+
+@protected void sse_encode_fts_filter(FtsFilter self, SseSerializer serializer);
+
+@protected void sse_encode_fts_hit(FtsHit self, SseSerializer serializer);
+
+@protected void sse_encode_fts_tokenizer(FtsTokenizer self, SseSerializer serializer);
+
+@protected void sse_encode_hnsw_config(HnswConfig self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_import_conflict(ImportConflict self, SseSerializer serializer);
+
+@protected void sse_encode_import_options(ImportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_import_report(ImportReport self, SseSerializer serializer);
+
+@protected void sse_encode_import_strategy(ImportStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_incremental_backup_info(IncrementalBackupInfo self, SseSerializer serializer);
+
+@protected void sse_encode_index_summary(IndexSummary self, SseSerializer serializer);
+
+@protected void sse_encode_integrity_report(IntegrityReport self, SseSerializer serializer);
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_latency_bucket(LatencyBucket self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_audit_entry(List<AuditEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cdc_entry(List<CdcEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_column_def(List<ColumnDef> self, SseSerializer serializer);
+
+@protected void sse_encode_list_column_info(List<ColumnInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cozo_value(List<CozoValue> self, SseSerializer serializer);
+
+@protected void sse_encode_list_embedding(List<Embedding> self, SseSerializer serializer);
+
+@protected void sse_encode_list_encrypted_column(List<EncryptedColumn> self, SseSerializer serializer);
+
+@protected void sse_encode_list_error_count(List<ErrorCount> self, SseSerializer serializer);
+
+@protected void sse_encode_list_fts_filter(List<FtsFilter> self, SseSerializer serializer);
+
+@protected void sse_encode_list_fts_hit(List<FtsHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_import_conflict(List<ImportConflict> self, SseSerializer serializer);
+
+@protected void sse_encode_list_index_summary(List<IndexSummary> self, SseSerializer serializer);
+
+@protected void sse_encode_list_latency_bucket(List<LatencyBucket> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_cozo_value(List<List<CozoValue>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lsh_hit(List<LshHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_migration(List<Migration> self, SseSerializer serializer);
+
+@protected void sse_encode_list_plan_rule(List<PlanRule> self, SseSerializer serializer);
+
+@protected void sse_encode_list_plan_step(List<PlanStep> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_64_strict(Int64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_result(List<QueryResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_cozo_value(List<(String,CozoValue)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_relation_diff(List<RelationDiff> self, SseSerializer serializer);
+
+@protected void sse_encode_list_relation_info(List<RelationInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_relation_integrity_error(List<RelationIntegrityError> self, SseSerializer serializer);
+
+@protected void sse_encode_list_row_policy(List<RowPolicy> self, SseSerializer serializer);
+
+@protected void sse_encode_list_running_query_info(List<RunningQueryInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_schema_mismatch(List<SchemaMismatch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_script_diagnostic(List<ScriptDiagnostic> self, SseSerializer serializer);
+
+@protected void sse_encode_list_script_with_params(List<ScriptWithParams> self, SseSerializer serializer);
+
+@protected void sse_encode_list_slow_query(List<SlowQuery> self, SseSerializer serializer);
+
+@protected void sse_encode_list_transaction_info(List<TransactionInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_ttl_setting(List<TtlSetting> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vector_hit(List<VectorHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vector_index_info(List<VectorIndexInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_lock_holder(LockHolder self, SseSerializer serializer);
+
+@protected void sse_encode_lsh_config(LshConfig self, SseSerializer serializer);
+
+@protected void sse_encode_lsh_hit(LshHit self, SseSerializer serializer);
+
+@protected void sse_encode_maintenance_report(MaintenanceReport self, SseSerializer serializer);
+
+@protected void sse_encode_migration(Migration self, SseSerializer serializer);
+
+@protected void sse_encode_migration_report(MigrationReport self, SseSerializer serializer);
+
+@protected void sse_encode_on_conflict(OnConflict self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Chrono_Utc(DateTime? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_busy_retry_policy(BusyRetryPolicy? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_column_default(ColumnDefault? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_cozo_dart_error(CozoDartError? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_error_span(ErrorSpan? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_lock_holder(LockHolder? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_snippet_config(SnippetConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_cozo_value(List<CozoValue>? self, SseSerializer serializer);
+
+@protected void sse_encode_plan_rule(PlanRule self, SseSerializer serializer);
+
+@protected void sse_encode_plan_step(PlanStep self, SseSerializer serializer);
+
+@protected void sse_encode_query_options(QueryOptions self, SseSerializer serializer);
+
+@protected void sse_encode_query_plan(QueryPlan self, SseSerializer serializer);
+
+@protected void sse_encode_query_result(QueryResult self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_cozo_value((String,CozoValue) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_recovered_db(RecoveredDb self, SseSerializer serializer);
+
+@protected void sse_encode_recovery_policy(RecoveryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_recovery_report(RecoveryReport self, SseSerializer serializer);
+
+@protected void sse_encode_relation_change(RelationChange self, SseSerializer serializer);
+
+@protected void sse_encode_relation_diff(RelationDiff self, SseSerializer serializer);
+
+@protected void sse_encode_relation_info(RelationInfo self, SseSerializer serializer);
+
+@protected void sse_encode_relation_integrity_error(RelationIntegrityError self, SseSerializer serializer);
+
+@protected void sse_encode_relation_stats(RelationStats self, SseSerializer serializer);
+
+@protected void sse_encode_result_encoding(ResultEncoding self, SseSerializer serializer);
+
+@protected void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_row_policy(RowPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_running_query_info(RunningQueryInfo self, SseSerializer serializer);
+
+@protected void sse_encode_schema_diff(SchemaDiff self, SseSerializer serializer);
+
+@protected void sse_encode_schema_mismatch(SchemaMismatch self, SseSerializer serializer);
+
+@protected void sse_encode_schema_mismatch_kind(SchemaMismatchKind self, SseSerializer serializer);
+
+@protected void sse_encode_script_diagnostic(ScriptDiagnostic self, SseSerializer serializer);
+
+@protected void sse_encode_script_with_params(ScriptWithParams self, SseSerializer serializer);
+
+@protected void sse_encode_slow_query(SlowQuery self, SseSerializer serializer);
+
+@protected void sse_encode_snippet_config(SnippetConfig self, SseSerializer serializer);
+
+@protected void sse_encode_sync_changeset(SyncChangeset self, SseSerializer serializer);
+
+@protected void sse_encode_sync_report(SyncReport self, SseSerializer serializer);
+
+@protected void sse_encode_text_position(TextPosition self, SseSerializer serializer);
+
+@protected void sse_encode_transaction_info(TransactionInfo self, SseSerializer serializer);
+
+@protected void sse_encode_trigger_spec(TriggerSpec self, SseSerializer serializer);
+
+@protected void sse_encode_ttl_setting(TtlSetting self, SseSerializer serializer);
+
+@protected void sse_encode_tx_mode(TxMode self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_validation_result(ValidationResult self, SseSerializer serializer);
+
+@protected void sse_encode_validity_time(ValidityTime self, SseSerializer serializer);
+
+@protected void sse_encode_vector_distance(VectorDistance self, SseSerializer serializer);
+
+@protected void sse_encode_vector_hit(VectorHit self, SseSerializer serializer);
+
+@protected void sse_encode_vector_index_info(VectorIndexInfo self, SseSerializer serializer);
+
+@protected void sse_encode_vector_type(VectorType self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuditor(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoCursor(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoDb(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCozoTx(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPreparedQuery(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRowPolicies(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSlowLog(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTenantManager(int ptr);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,31 @@
+//! Build notes for the optional `rocksdb` feature.
+//!
+//! RocksDB is compiled from C++ sources by `cozorocks`, which picks up the
+//! C/C++ toolchain from the `CC_<target>`/`CXX_<target>` environment
+//! variables. cargokit exports these from the Android NDK during a Flutter
+//! build. When running `cargo build --target aarch64-linux-android` by hand,
+//! use `cargo ndk` or export `ANDROID_NDK_HOME` and point `CXX_<target>` at
+//! the NDK clang++ (e.g. `aarch64-linux-android21-clang++`).
+
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=ANDROID_NDK_HOME");
+
+    let rocksdb = env::var_os("CARGO_FEATURE_ROCKSDB").is_some();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if !rocksdb || target_os != "android" {
+        return;
+    }
+
+    let target = env::var("TARGET").unwrap_or_default();
+    let cxx_var = format!("CXX_{}", target.replace('-', "_"));
+    println!("cargo:rerun-if-env-changed={cxx_var}");
+    if env::var_os(&cxx_var).is_none() && env::var_os("ANDROID_NDK_HOME").is_none() {
+        println!(
+            "cargo:warning=Building RocksDB for {target} without {cxx_var} or ANDROID_NDK_HOME; \
+             the C++ compile will likely fail. Build through cargokit/Flutter or `cargo ndk`."
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::path::Path;
+
+use cozo::DbInstance;
+
+use crate::api::backup::{restore_file, TempFile};
+use crate::api::error::{catch_panic, failure, ErrorKind};
+use crate::api::simple::CozoDb;
+
+/// How [`cozo_open_from_asset_bytes`] uses the bundled database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetOpenMode {
+    /// Load the data into a fresh in-memory database. Writes are not saved.
+    Memory,
+    /// Write the asset to `path` if no file exists there yet, then open that
+    /// file with the sqlite engine. Later opens keep the user's changes.
+    CopyOnFirstOpen,
+}
+
+/// Open a database shipped as a Flutter asset.
+///
+/// - `bytes`: contents of a sqlite-engine database file or a backup made with
+///   `cozo_backup`, which use the same format
+/// - `mode`: see [`AssetOpenMode`]
+/// - `path`: destination file for [`AssetOpenMode::CopyOnFirstOpen`], e.g. in
+///   the app documents directory; ignored for [`AssetOpenMode::Memory`]
+///
+/// Returns an opaque CozoDb handle.
+pub fn cozo_open_from_asset_bytes(
+    bytes: Vec<u8>,
+    mode: AssetOpenMode,
+    path: String,
+) -> anyhow::Result<CozoDb> {
+    let (db, engine) = match mode {
+        AssetOpenMode::Memory => {
+            let file = TempFile::private().map_err(|e| {
+                failure(
+                    ErrorKind::Storage,
+                    format!("Failed to open asset database: {}", e),
+                )
+            })?;
+            std::fs::write(file.path(), bytes).map_err(|e| {
+                failure(
+                    ErrorKind::Storage,
+                    format!("Failed to open asset database: {}", e),
+                )
+            })?;
+            let db = DbInstance::new("mem", "", "").map_err(|e| {
+                failure(
+                    ErrorKind::Storage,
+                    format!("Failed to open asset database: {}", e),
+                )
+            })?;
+            catch_panic(|| restore_file(&db, file.path())).map_err(|e| {
+                failure(
+                    ErrorKind::Storage,
+                    format!("Failed to open asset database: {}", e),
+                )
+            })?;
+            (db, "mem")
+        }
+        AssetOpenMode::CopyOnFirstOpen => {
+            if !Path::new(&path).exists() {
+                copy_asset(&bytes, &path)
+                    .map_err(|e| anyhow::anyhow!("Failed to copy asset database: {}", e))?;
+            }
+            let db = DbInstance::new("sqlite", &path, "").map_err(|e| {
+                failure(
+                    ErrorKind::Storage,
+                    format!("Failed to open database: {}", e),
+                )
+            })?;
+            (db, "sqlite")
+        }
+    };
+    let path = if engine == "mem" { "" } else { &path };
+    Ok(CozoDb::from_instance(db, engine, path, false))
+}
+
+/// Write the asset next to `path` and rename it into place, so an interrupted
+/// copy never leaves a truncated database behind.
+fn copy_asset(bytes: &[u8], path: &str) -> std::io::Result<()> {
+    let partial = format!("{}.partial", path);
+    if let Some(dir) = Path::new(path).parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&partial, bytes)?;
+    std::fs::rename(&partial, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_close_db, cozo_open_db};
+    use crate::api::vector::run_write_op;
+    use cozo::ScriptMutability;
+
+    /// A fresh directory, removed on drop.
+    struct Dir(std::path::PathBuf);
+
+    impl Dir {
+        fn new() -> Self {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos();
+            let name = format!("cozo_dart_asset_{}_{}", std::process::id(), nanos);
+            Dir(std::env::temp_dir().join(name))
+        }
+    }
+
+    impl Drop for Dir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Bytes of a backup holding `items` with ids 1 and 2.
+    fn asset() -> Vec<u8> {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        run_write_op(&db, "?[id] <- [[1], [2]] :put items {id}").unwrap();
+        let file = TempFile::private().unwrap();
+        std::fs::remove_file(file.path()).unwrap();
+        db.instance().unwrap().backup_db(file.path()).unwrap();
+        std::fs::read(file.path()).unwrap()
+    }
+
+    fn count(db: &CozoDb) -> usize {
+        db.instance()
+            .unwrap()
+            .run_script(
+                "?[id] := *items{id}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap()
+            .rows
+            .len()
+    }
+
+    #[test]
+    fn memory_mode_loads_a_copy() {
+        let db = cozo_open_from_asset_bytes(asset(), AssetOpenMode::Memory, String::new()).unwrap();
+        assert_eq!(count(&db), 2);
+        run_write_op(&db, "?[id] <- [[3]] :put items {id}").unwrap();
+        assert_eq!(count(&db), 3);
+
+        let bad = cozo_open_from_asset_bytes(b"garbage".to_vec(), AssetOpenMode::Memory, "".into());
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn copies_are_made_only_on_the_first_open() {
+        let dir = Dir::new();
+        let path = dir.0.join("nested").join("app.db");
+        let path = path.to_string_lossy().into_owned();
+
+        let db = cozo_open_from_asset_bytes(asset(), AssetOpenMode::CopyOnFirstOpen, path.clone())
+            .unwrap();
+        assert!(!Path::new(&format!("{}.partial", path)).exists());
+        run_write_op(&db, "?[id] <- [[3]] :put items {id}").unwrap();
+        cozo_close_db(&db);
+
+        let db = cozo_open_from_asset_bytes(asset(), AssetOpenMode::CopyOnFirstOpen, path).unwrap();
+        assert_eq!(count(&db), 3);
+        cozo_close_db(&db);
+    }
+}
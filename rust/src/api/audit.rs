@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+use std::sync::{mpsc, PoisonError};
+use std::thread::JoinHandle;
+
+use cozo::{CallbackOp, DataValue, DbInstance, NamedRows, ScriptMutability};
+use flutter_rust_bridge::DartFnFuture;
+
+use crate::api::cdc::CdcOp;
+use crate::api::error::catch_panic;
+use crate::api::running::epoch_secs;
+use crate::api::schema::{cozo_list_relations, relation_columns};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::value::CozoValue;
+use crate::api::vector::run_write_op;
+
+/// Stored relation the audit entries are appended to:
+/// `{seq => at, actor, relation, op, key, old, new}`.
+const AUDIT_LOG: &str = "cozo_audit";
+
+/// One row changed, as recorded by the audit log.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    /// Position in the log, increasing with every entry.
+    pub seq: i64,
+    /// Unix time in seconds the change was recorded.
+    pub at: f64,
+    /// What the actor provider returned for the change.
+    pub actor: String,
+    pub relation: String,
+    pub op: CdcOp,
+    /// Values of the key columns of the row.
+    pub key: Vec<CozoValue>,
+    /// The row before the change, `None` if it was inserted.
+    pub old: Option<Vec<CozoValue>>,
+    /// The row after the change, `None` if it was removed.
+    pub new: Option<Vec<CozoValue>>,
+}
+
+/// Which entries [`cozo_read_audit`] returns. Unset fields match everything.
+#[derive(Debug, Clone)]
+pub struct AuditQuery {
+    pub relation: Option<String>,
+    pub actor: Option<String>,
+    /// Unix time in seconds of the earliest entry returned.
+    pub since: Option<f64>,
+    /// Unix time in seconds the entries returned are older than.
+    pub until: Option<f64>,
+    /// First `seq` returned; pass the last `seq` read plus one to continue
+    /// where the previous call stopped.
+    pub from_seq: i64,
+    /// Maximum number of entries returned.
+    pub limit: u32,
+}
+
+/// A change to an audited relation, on its way to the writer.
+struct Change {
+    relation: String,
+    key_len: usize,
+    op: CallbackOp,
+    new: NamedRows,
+    old: NamedRows,
+}
+
+/// Key, old row and new row of a row changed.
+type RowChange = (
+    Vec<DataValue>,
+    Option<Vec<DataValue>>,
+    Option<Vec<DataValue>>,
+);
+
+/// The audit log of a database, shared by every handle to it.
+#[derive(Default)]
+pub(crate) struct Auditor {
+    /// Change callbacks on the audited relations, by relation.
+    watches: BTreeMap<String, u32>,
+    /// Appends the entries; ends once every watch is unregistered.
+    writer: Option<JoinHandle<()>>,
+}
+
+impl Auditor {
+    /// Unregister the watches, returning the writer, which ends after
+    /// recording the changes already received.
+    pub(crate) fn stop(&mut self, inner: &DbInstance) -> Option<JoinHandle<()>> {
+        for (_, id) in std::mem::take(&mut self.watches) {
+            inner.unregister_callback(id);
+        }
+        self.writer.take()
+    }
+}
+
+/// Record who changed what and when for every row put into or removed from
+/// `relations`, in the append-only `cozo_audit` relation read with
+/// [`cozo_read_audit`].
+///
+/// - `actor_provider`: returns the user or service to record for a change,
+///   e.g. the id of the signed-in user
+///
+/// Changes are recorded from change callbacks just after they commit, one
+/// actor call per write statement, so the actor is the one current then.
+/// Each entry holds the row before and after the change. Writes that do not
+/// run callbacks, such as `cozo_import_relations` and restores, are not
+/// recorded. The log survives restarts but auditing does not: enable it
+/// again after opening the database. Enabling again replaces the audited
+/// relations and the actor provider.
+pub fn cozo_enable_audit(
+    db: &CozoDb,
+    relations: Vec<String>,
+    actor_provider: impl Fn() -> DartFnFuture<String> + Send + Sync + 'static,
+) -> anyhow::Result<()> {
+    let inner = db.writable()?;
+    let mut key_lens = vec![];
+    for relation in &relations {
+        if relation.contains(':') || relation == AUDIT_LOG {
+            anyhow::bail!("Audit failed: {} cannot be audited", relation);
+        }
+        let columns = relation_columns(&inner, relation)?;
+        key_lens.push(columns.iter().filter(|c| c.is_key).count());
+    }
+    ensure_log(db)?;
+
+    let mut auditor = db.audit.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(writer) = auditor.stop(&inner) {
+        // One writer at a time, so that entries get consecutive positions.
+        let _ = writer.join();
+    }
+    let (sender, changes) = mpsc::channel::<Change>();
+    for (relation, key_len) in relations.into_iter().zip(key_lens) {
+        let (id, receiver) = inner.register_callback(&relation, None);
+        auditor.watches.insert(relation.clone(), id);
+        let sender = sender.clone();
+        std::thread::spawn(move || {
+            for (op, new, old) in receiver {
+                let change = Change {
+                    relation: relation.clone(),
+                    key_len,
+                    op,
+                    new,
+                    old,
+                };
+                if sender.send(change).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    let next = last_seq(&inner)? + 1;
+    auditor.writer = Some(std::thread::spawn(move || {
+        write_entries(inner, next, changes, actor_provider)
+    }));
+    Ok(())
+}
+
+/// Stop auditing. Returns once the changes already made are recorded; the
+/// log itself is kept.
+pub fn cozo_disable_audit(db: &CozoDb) -> anyhow::Result<()> {
+    let inner = db.instance()?;
+    let writer = db
+        .audit
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .stop(&inner);
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+    Ok(())
+}
+
+/// Read audit entries in the order they were recorded.
+///
+/// Returns at most `query.limit` entries matching `query`.
+pub fn cozo_read_audit(db: &CozoDb, query: AuditQuery) -> anyhow::Result<Vec<AuditEntry>> {
+    if !log_exists(db)? {
+        return Ok(vec![]);
+    }
+    // cozo 0.7.6 scans `seq >= $from` on the only key column from after
+    // `$from`, so the bound is exclusive.
+    let mut conditions = vec!["seq > $after".to_string()];
+    let mut params = BTreeMap::from([
+        (
+            "after".to_string(),
+            DataValue::from(query.from_seq.saturating_sub(1)),
+        ),
+        ("limit".to_string(), DataValue::from(i64::from(query.limit))),
+    ]);
+    if let Some(relation) = query.relation {
+        conditions.push("relation == $relation".to_string());
+        params.insert("relation".to_string(), DataValue::from(relation));
+    }
+    if let Some(actor) = query.actor {
+        conditions.push("actor == $actor".to_string());
+        params.insert("actor".to_string(), DataValue::from(actor));
+    }
+    if let Some(since) = query.since {
+        conditions.push("at >= $since".to_string());
+        params.insert("since".to_string(), DataValue::from(since));
+    }
+    if let Some(until) = query.until {
+        conditions.push("at < $until".to_string());
+        params.insert("until".to_string(), DataValue::from(until));
+    }
+    let script = format!(
+        "?[seq, at, actor, relation, op, key, old, new] := *{}{{seq, at, actor, relation, op, key, old, new}}, {}\n:order seq\n:limit $limit",
+        AUDIT_LOG,
+        conditions.join(", ")
+    );
+    let inner = db.instance()?;
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .map(|row| AuditEntry {
+            seq: row[0].get_int().unwrap_or_default(),
+            at: row[1].get_float().unwrap_or_default(),
+            actor: row[2].get_str().unwrap_or_default().to_string(),
+            relation: row[3].get_str().unwrap_or_default().to_string(),
+            op: match row[4].get_str() {
+                Some("rm") => CdcOp::Rm,
+                _ => CdcOp::Put,
+            },
+            key: values(&row[5]).unwrap_or_default(),
+            old: values(&row[6]),
+            new: values(&row[7]),
+        })
+        .collect())
+}
+
+/// Append an entry per row changed, numbering them from `next`, until every
+/// sender is gone.
+fn write_entries(
+    inner: DbInstance,
+    mut next: i64,
+    changes: mpsc::Receiver<Change>,
+    actor_provider: impl Fn() -> DartFnFuture<String>,
+) {
+    let script = format!(
+        "?[seq, at, actor, relation, op, key, old, new] <- $rows :put {} {{seq => at, actor, relation, op, key, old, new}}",
+        AUDIT_LOG
+    );
+    for change in changes {
+        let actor = futures::executor::block_on(actor_provider());
+        let at = epoch_secs();
+        let entries = row_changes(&change);
+        if entries.is_empty() {
+            continue;
+        }
+        let op = match change.op {
+            CallbackOp::Put => "put",
+            CallbackOp::Rm => "rm",
+        };
+        let mut rows = vec![];
+        for (key, old, new) in entries {
+            rows.push(DataValue::List(vec![
+                DataValue::from(next),
+                DataValue::from(at),
+                DataValue::from(actor.as_str()),
+                DataValue::from(change.relation.as_str()),
+                DataValue::from(op),
+                DataValue::List(key),
+                old.map_or(DataValue::Null, DataValue::List),
+                new.map_or(DataValue::Null, DataValue::List),
+            ]));
+            next += 1;
+        }
+        let params = BTreeMap::from([("rows".to_string(), DataValue::List(rows))]);
+        if let Err(err) =
+            catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        {
+            log::error!("Audit of {} failed: {}", change.relation, err);
+        }
+    }
+}
+
+/// Key, old row and new row of every row a change touched. A put lists the
+/// rows written, matched with the ones they replaced; a removal lists the
+/// rows removed.
+fn row_changes(change: &Change) -> Vec<RowChange> {
+    let key = |row: &[DataValue]| row[..change.key_len.min(row.len())].to_vec();
+    match change.op {
+        CallbackOp::Put => {
+            let mut replaced: Vec<&Vec<DataValue>> = change.old.rows.iter().collect();
+            replaced.sort_by_key(|row| key(row));
+            change
+                .new
+                .rows
+                .iter()
+                .map(|row| {
+                    let key = key(row);
+                    let old = replaced
+                        .binary_search_by(|old| old[..key.len().min(old.len())].cmp(&key))
+                        .ok()
+                        .map(|i| replaced[i].clone());
+                    (key, old, Some(row.clone()))
+                })
+                .collect()
+        }
+        CallbackOp::Rm => change
+            .old
+            .rows
+            .iter()
+            .map(|row| (key(row), Some(row.clone()), None))
+            .collect(),
+    }
+}
+
+fn values(value: &DataValue) -> Option<Vec<CozoValue>> {
+    match value {
+        DataValue::List(values) => Some(values.iter().cloned().map(CozoValue::from).collect()),
+        _ => None,
+    }
+}
+
+fn last_seq(inner: &DbInstance) -> anyhow::Result<i64> {
+    let script = format!("?[max(seq)] := *{}{{seq}}", AUDIT_LOG);
+    let rows =
+        catch_panic(|| inner.run_script(&script, Default::default(), ScriptMutability::Immutable))
+            .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .first()
+        .and_then(|row| row[0].get_int())
+        .unwrap_or(0))
+}
+
+fn log_exists(db: &CozoDb) -> anyhow::Result<bool> {
+    Ok(cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == AUDIT_LOG))
+}
+
+fn ensure_log(db: &CozoDb) -> anyhow::Result<()> {
+    if log_exists(db)? {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            ":create {} {{seq: Int => at: Float, actor: String, relation: String, op: String, key: [Any], old: [Any]?, new: [Any]?}}",
+            AUDIT_LOG
+        ),
+    )?;
+    // Protected: scripts cannot drop or replace the log.
+    run_write_op(db, &format!("::access_level protected {}", AUDIT_LOG))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+
+    fn query() -> AuditQuery {
+        AuditQuery {
+            relation: None,
+            actor: None,
+            since: None,
+            until: None,
+            from_seq: 0,
+            limit: 100,
+        }
+    }
+
+    fn actor() -> DartFnFuture<String> {
+        Box::pin(async { "alice".to_string() })
+    }
+
+    #[test]
+    fn changes_are_recorded_with_their_actor_and_rows() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int => body: String}").unwrap();
+        run_write_op(&db, ":create other {id: Int}").unwrap();
+        cozo_enable_audit(&db, vec!["notes".into()], actor).unwrap();
+        run_write_op(&db, "?[id, body] <- [[1, 'a']] :put notes {id => body}").unwrap();
+        run_write_op(&db, "?[id, body] <- [[1, 'b']] :put notes {id => body}").unwrap();
+        run_write_op(&db, "?[id] <- [[1]] :rm notes {id}").unwrap();
+        run_write_op(&db, "?[id] <- [[1]] :put other {id}").unwrap();
+        cozo_disable_audit(&db).unwrap();
+        run_write_op(&db, "?[id, body] <- [[2, 'c']] :put notes {id => body}").unwrap();
+
+        let entries = cozo_read_audit(&db, query()).unwrap();
+        let row = |body: &str| Some(vec![CozoValue::Int(1), CozoValue::Str(body.into())]);
+        let seen: Vec<_> = entries
+            .iter()
+            .map(|e| (e.seq, e.op, e.old.clone(), e.new.clone()))
+            .collect();
+        assert_eq!(
+            seen,
+            [
+                (1, CdcOp::Put, None, row("a")),
+                (2, CdcOp::Put, row("a"), row("b")),
+                (3, CdcOp::Rm, row("b"), None),
+            ]
+        );
+        assert!(entries
+            .iter()
+            .all(|e| e.actor == "alice" && e.relation == "notes" && e.key == [CozoValue::Int(1)]));
+    }
+
+    #[test]
+    fn entries_are_filtered() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int}").unwrap();
+        cozo_enable_audit(&db, vec!["notes".into()], actor).unwrap();
+        run_write_op(&db, "?[id] <- [[1], [2], [3]] :put notes {id}").unwrap();
+        cozo_disable_audit(&db).unwrap();
+
+        let seqs = |query: AuditQuery| -> Vec<i64> {
+            let entries = cozo_read_audit(&db, query).unwrap();
+            entries.iter().map(|e| e.seq).collect()
+        };
+        assert_eq!(
+            seqs(AuditQuery {
+                from_seq: 2,
+                ..query()
+            }),
+            [2, 3]
+        );
+        assert_eq!(
+            seqs(AuditQuery {
+                limit: 1,
+                ..query()
+            }),
+            [1]
+        );
+        let bob = AuditQuery {
+            actor: Some("bob".into()),
+            ..query()
+        };
+        assert!(seqs(bob).is_empty());
+        let other = AuditQuery {
+            relation: Some("other".into()),
+            ..query()
+        };
+        assert!(seqs(other).is_empty());
+        assert!(seqs(AuditQuery {
+            until: Some(0.0),
+            ..query()
+        })
+        .is_empty());
+        assert_eq!(
+            seqs(AuditQuery {
+                since: Some(0.0),
+                ..query()
+            })
+            .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn the_log_cannot_be_audited_or_removed() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_enable_audit(&db, vec![], actor).unwrap();
+        assert!(cozo_enable_audit(&db, vec![AUDIT_LOG.into()], actor).is_err());
+        assert!(run_write_op(&db, &format!("::remove {}", AUDIT_LOG)).is_err());
+        cozo_disable_audit(&db).unwrap();
+    }
+}
@@ -0,0 +1,285 @@
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use cozo::{DbInstance, ScriptMutability};
+use flutter_rust_bridge::frb;
+use serde_json::json;
+
+use crate::api::backup::file_size;
+use crate::api::error::{catch_panic, failure, ErrorKind};
+use crate::api::simple::{live_instance, CozoDb};
+use crate::frb_generated::StreamSink;
+
+/// Backup files written by the scheduler are named `cozo-backup-<ms>.db`.
+const FILE_PREFIX: &str = "cozo-backup-";
+const FILE_SUFFIX: &str = ".db";
+
+/// How often a backup waiting for the database to become idle checks again.
+const IDLE_RETRY: Duration = Duration::from_secs(1);
+
+/// Settings of [`cozo_enable_auto_backup`].
+#[derive(Debug, Clone)]
+pub struct BackupPolicy {
+    /// Seconds between backups, at least 1. The first backup is taken one
+    /// interval after enabling.
+    pub interval_secs: u64,
+    /// Directory the backups are written to, created if missing. Use a
+    /// directory of its own: files named like backups are rotated out.
+    pub dir: String,
+    /// Number of backups kept; older ones are deleted after each backup.
+    /// 0 keeps them all.
+    pub keep_n: u32,
+    /// Wait until no query is running before taking a backup. A backup that
+    /// cannot start before the next one is due is skipped.
+    pub only_on_idle: bool,
+}
+
+/// Back up the database in the background at a fixed interval, keeping the
+/// newest `keep_n` backups.
+///
+/// Each backup is written under a temporary name and renamed when complete,
+/// so the directory never holds a partial backup under a backup name.
+/// Enabling again replaces the previous schedule. The schedule ends with
+/// [`cozo_disable_auto_backup`], when the database is closed, or when the
+/// Dart side cancels its subscription.
+///
+/// Each event is a JSON string, one per scheduled backup:
+/// `{"stage": "done", "path": p, "bytes": n, "removed": [paths], "elapsed": secs}`,
+/// `{"stage": "failed", "error": message, "elapsed": secs}` or
+/// `{"stage": "skipped", "reason": "busy"}`.
+pub fn cozo_enable_auto_backup(
+    db: &CozoDb,
+    policy: BackupPolicy,
+    sink: StreamSink<String>,
+) -> anyhow::Result<()> {
+    db.instance()?;
+    if policy.interval_secs == 0 {
+        anyhow::bail!("Auto backup needs an interval of at least 1 second");
+    }
+    std::fs::create_dir_all(&policy.dir)
+        .map_err(|e| anyhow::anyhow!("Auto backup failed: {}", e))?;
+
+    let (stop, stopped) = mpsc::channel::<()>();
+    let inner = db.shared();
+    std::thread::spawn(move || run_schedule(inner, policy, stopped, sink));
+    // Dropping the previous sender ends the previous schedule.
+    *db.auto_backup
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(stop);
+    Ok(())
+}
+
+/// Stop the schedule started by [`cozo_enable_auto_backup`], if any. A
+/// backup already being written is finished first.
+///
+/// Returns whether a schedule was running.
+#[frb(sync)]
+pub fn cozo_disable_auto_backup(db: &CozoDb) -> bool {
+    db.auto_backup
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+        .is_some()
+}
+
+fn run_schedule(
+    inner: Arc<RwLock<Option<DbInstance>>>,
+    policy: BackupPolicy,
+    stopped: mpsc::Receiver<()>,
+    sink: StreamSink<String>,
+) {
+    let interval = Duration::from_secs(policy.interval_secs);
+    // Waits for `timeout`, returning false once the schedule is stopped.
+    let wait = |timeout| {
+        matches!(
+            stopped.recv_timeout(timeout),
+            Err(RecvTimeoutError::Timeout)
+        )
+    };
+    while wait(interval) {
+        let Ok(db) = live_instance(&inner) else {
+            return;
+        };
+        if policy.only_on_idle {
+            let due = Instant::now() + interval;
+            while !is_idle(&db) {
+                if Instant::now() + IDLE_RETRY >= due {
+                    break;
+                }
+                if !wait(IDLE_RETRY) {
+                    return;
+                }
+            }
+            if !is_idle(&db) {
+                if sink
+                    .add(json!({"stage": "skipped", "reason": "busy"}).to_string())
+                    .is_err()
+                {
+                    return;
+                }
+                continue;
+            }
+        }
+
+        let start = Instant::now();
+        let event = match backup_and_rotate(&db, &policy) {
+            Ok((path, removed)) => json!({
+                "stage": "done",
+                "bytes": file_size(&path),
+                "path": path,
+                "removed": removed,
+                "elapsed": start.elapsed().as_secs_f64(),
+            }),
+            Err(e) => json!({
+                "stage": "failed",
+                "error": e.to_string(),
+                "elapsed": start.elapsed().as_secs_f64(),
+            }),
+        };
+        if sink.add(event.to_string()).is_err() {
+            return;
+        }
+    }
+}
+
+/// Whether no query is running, per `::running`.
+fn is_idle(db: &DbInstance) -> bool {
+    catch_panic(|| db.run_script("::running", Default::default(), ScriptMutability::Immutable))
+        .is_ok_and(|rows| rows.rows.is_empty())
+}
+
+/// Write a new backup, then delete the oldest ones beyond `keep_n`.
+///
+/// Returns the new backup's path and the paths deleted.
+fn backup_and_rotate(
+    db: &DbInstance,
+    policy: &BackupPolicy,
+) -> anyhow::Result<(String, Vec<String>)> {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    let path = Path::new(&policy.dir)
+        .join(format!("{}{}{}", FILE_PREFIX, millis, FILE_SUFFIX))
+        .to_string_lossy()
+        .into_owned();
+    let partial = format!("{}.partial", path);
+    let _ = std::fs::remove_file(&partial);
+    if let Err(e) = catch_panic(|| db.backup_db(&partial)) {
+        let _ = std::fs::remove_file(&partial);
+        return Err(failure(ErrorKind::Storage, format!("Backup failed: {}", e)));
+    }
+    std::fs::rename(&partial, &path)
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))?;
+
+    let mut backups: Vec<String> = std::fs::read_dir(&policy.dir)
+        .map_err(|e| anyhow::anyhow!("Rotating backups failed: {}", e))?
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name.starts_with(FILE_PREFIX) && name.ends_with(FILE_SUFFIX))
+        .collect();
+    // Oldest first: timestamps with fewer digits are older.
+    backups.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    let excess = match policy.keep_n {
+        0 => 0,
+        keep => backups.len().saturating_sub(keep as usize),
+    };
+    let mut removed = vec![];
+    for name in &backups[..excess] {
+        let old = Path::new(&policy.dir).join(name);
+        std::fs::remove_file(&old)
+            .map_err(|e| anyhow::anyhow!("Rotating backups failed: {}", e))?;
+        removed.push(old.to_string_lossy().into_owned());
+    }
+    Ok((path, removed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_close_db, cozo_open_db};
+    use crate::api::vector::run_write_op;
+
+    /// A fresh backup directory, removed on drop.
+    struct Dir(String);
+
+    impl Dir {
+        fn new() -> Self {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_nanos();
+            let name = format!("cozo_dart_auto_{}_{}", std::process::id(), nanos);
+            Dir(std::env::temp_dir()
+                .join(name)
+                .to_string_lossy()
+                .into_owned())
+        }
+    }
+
+    impl Drop for Dir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn policy(dir: &Dir, keep_n: u32) -> BackupPolicy {
+        BackupPolicy {
+            interval_secs: 1,
+            dir: dir.0.clone(),
+            keep_n,
+            only_on_idle: false,
+        }
+    }
+
+    fn sink() -> StreamSink<String> {
+        StreamSink::deserialize("0".into())
+    }
+
+    #[test]
+    fn rotation_keeps_the_newest_backups() {
+        let dir = Dir::new();
+        std::fs::create_dir_all(&dir.0).unwrap();
+        let other = Path::new(&dir.0).join("notes.txt");
+        std::fs::write(&other, "keep me").unwrap();
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        let instance = db.instance().unwrap();
+
+        let mut paths = vec![];
+        for _ in 0..3 {
+            let (path, removed) = backup_and_rotate(&instance, &policy(&dir, 2)).unwrap();
+            paths.push((path, removed));
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(paths[0].1.is_empty() && paths[1].1.is_empty());
+        assert_eq!(paths[2].1, [paths[0].0.clone()]);
+        assert!(!Path::new(&paths[0].0).exists());
+        assert!(Path::new(&paths[1].0).exists() && Path::new(&paths[2].0).exists());
+        assert!(other.exists());
+        assert!(!Path::new(&format!("{}.partial", paths[2].0)).exists());
+
+        let (_, removed) = backup_and_rotate(&instance, &policy(&dir, 0)).unwrap();
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn schedules_are_enabled_and_disabled() {
+        let dir = Dir::new();
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let mut zero = policy(&dir, 1);
+        zero.interval_secs = 0;
+        assert!(cozo_enable_auto_backup(&db, zero, sink()).is_err());
+        assert!(!cozo_disable_auto_backup(&db));
+
+        cozo_enable_auto_backup(&db, policy(&dir, 1), sink()).unwrap();
+        assert!(Path::new(&dir.0).is_dir());
+        assert!(cozo_disable_auto_backup(&db));
+        assert!(!cozo_disable_auto_backup(&db));
+
+        cozo_close_db(&db);
+        assert!(cozo_enable_auto_backup(&db, policy(&dir, 1), sink()).is_err());
+    }
+}
@@ -0,0 +1,766 @@
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use cozo::{DbInstance, ScriptMutability};
+use serde_json::json;
+
+use crate::api::compression::decompressed_backup;
+use crate::api::error::{catch_panic, failure, ErrorKind};
+use crate::api::incremental::{read_manifest, relation_checksum};
+use crate::api::paths::private_cache_dir;
+use crate::api::schema::relation_columns;
+use crate::api::simple::{cozo_open_db, CozoDb};
+use crate::api::storage::sqlite_file_query;
+use crate::frb_generated::StreamSink;
+
+/// How often progress events are sent while a backup or restore runs.
+pub(crate) const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Backup the database to a file path, reporting progress through a stream.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "bytes": n, "relations": n, "elapsed": secs}`,
+/// where `bytes` is the size of the backup file written so far and
+/// `relations` the number of stored relations being copied. The stream closes
+/// after the `done` event, or fails with the backup error.
+pub fn cozo_backup_with_progress(
+    db: &CozoDb,
+    path: String,
+    sink: StreamSink<String>,
+) -> anyhow::Result<()> {
+    let inner = db.instance()?;
+    let relations = relation_count(&inner)?;
+    let start = Instant::now();
+    let event = |stage: &str| {
+        json!({
+            "stage": stage,
+            "bytes": file_size(&path),
+            "relations": relations,
+            "elapsed": start.elapsed().as_secs_f64(),
+        })
+        .to_string()
+    };
+
+    let _ = sink.add(event("started"));
+    with_progress(
+        &sink,
+        || event("progress"),
+        || catch_panic(|| inner.backup_db(&path)),
+    )
+    .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))?;
+    let _ = sink.add(event("done"));
+    Ok(())
+}
+
+/// Restore the database from a backup file, reporting progress through a stream.
+///
+/// Events have the same shape as [`cozo_backup_with_progress`]. `bytes` is the
+/// size of the backup file, and `relations` is the number of stored relations
+/// restored, reported with the `done` event. Cozo restores in one transaction,
+/// so `progress` events only carry the elapsed time. A compressed backup is
+/// decompressed before the `started` event.
+pub fn cozo_restore_with_progress(
+    db: &CozoDb,
+    path: String,
+    sink: StreamSink<String>,
+) -> anyhow::Result<()> {
+    let inner = db.writable()?;
+    let bytes = file_size(&path);
+    let plain = decompressed_backup(&path)?;
+    let path = plain.as_ref().map_or(path.as_str(), TempFile::path);
+    let start = Instant::now();
+    let event = |stage: &str, relations: Option<usize>| {
+        json!({
+            "stage": stage,
+            "bytes": bytes,
+            "relations": relations,
+            "elapsed": start.elapsed().as_secs_f64(),
+        })
+        .to_string()
+    };
+
+    let _ = sink.add(event("started", None));
+    with_progress(
+        &sink,
+        || event("progress", None),
+        || catch_panic(|| restore_file(&inner, path)),
+    )
+    .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))?;
+    let relations = relation_count(&inner)?;
+    let _ = sink.add(event("done", Some(relations)));
+    Ok(())
+}
+
+/// Run `work`, sending a `progress()` event every [`PROGRESS_INTERVAL`] until it finishes.
+pub(crate) fn with_progress<T>(
+    sink: &StreamSink<String>,
+    progress: impl Fn() -> String + Sync,
+    work: impl FnOnce() -> T,
+) -> T {
+    let finished = AtomicBool::new(false);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            while !finished.load(Ordering::Acquire) {
+                std::thread::sleep(PROGRESS_INTERVAL);
+                if finished.load(Ordering::Acquire) || sink.add(progress()).is_err() {
+                    break;
+                }
+            }
+        });
+        let result = work();
+        finished.store(true, Ordering::Release);
+        result
+    })
+}
+
+fn relation_count(inner: &DbInstance) -> anyhow::Result<usize> {
+    let rows = catch_panic(|| {
+        inner.run_script(
+            "::relations",
+            Default::default(),
+            ScriptMutability::Immutable,
+        )
+    })
+    .map_err(|e| anyhow::anyhow!("Failed to list relations: {}", e))?;
+    Ok(rows.rows.len())
+}
+
+pub(crate) fn file_size(path: &str) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+/// Backup the database into memory instead of a file.
+///
+/// Returns the backup file contents, suitable for [`cozo_restore_from_bytes`]
+/// or for handing to a cloud storage SDK.
+pub fn cozo_backup_to_bytes(db: &CozoDb) -> anyhow::Result<Vec<u8>> {
+    let inner = db.instance()?;
+    let file = TempFile::private()
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))?;
+    catch_panic(|| inner.backup_db(file.path()))
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))?;
+    std::fs::read(file.path())
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))
+}
+
+/// Restore the database from backup contents produced by [`cozo_backup_to_bytes`],
+/// or the contents of a compressed backup file.
+///
+/// Like [`crate::api::simple::cozo_restore`], the database must be empty.
+pub fn cozo_restore_from_bytes(db: &CozoDb, data: Vec<u8>) -> anyhow::Result<()> {
+    let inner = db.writable()?;
+    let file = TempFile::private()
+        .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))?;
+    std::fs::write(file.path(), data)
+        .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))?;
+    let plain = decompressed_backup(file.path())?;
+    catch_panic(|| restore_file(&inner, plain.as_ref().unwrap_or(&file).path()))
+        .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))
+}
+
+/// Copy the whole database into a new in-memory database.
+///
+/// Schemas, indices and data are copied, so scratch edits on the clone
+/// (tests, "what-if" flows) never reach the original.
+///
+/// Returns a handle to the clone, which is always writable.
+pub fn cozo_clone_to_mem(db: &CozoDb) -> anyhow::Result<CozoDb> {
+    let inner = db.instance()?;
+    let clone =
+        DbInstance::new("mem", "", "").map_err(|e| anyhow::anyhow!("Clone failed: {}", e))?;
+    copy_into(&inner, &clone).map_err(|e| anyhow::anyhow!("Clone failed: {}", e))?;
+    Ok(CozoDb::from_instance(clone, "mem", "", false))
+}
+
+/// Copy the whole database into a new database on another engine, e.g. to
+/// move from mem to sqlite or from sqlite to rocksdb.
+///
+/// - `dst_engine`, `dst_path`, `dst_options`: as for `cozo_open_db`
+///
+/// Schemas, indices and data are copied. The destination must be empty, so
+/// `dst_path` should not point at an existing database.
+///
+/// Returns a handle to the destination database.
+pub fn cozo_copy_database(
+    src: &CozoDb,
+    dst_engine: String,
+    dst_path: String,
+    dst_options: String,
+) -> anyhow::Result<CozoDb> {
+    let inner = src.instance()?;
+    let dst = cozo_open_db(dst_engine, dst_path, dst_options)?;
+    copy_into(&inner, &dst.writable()?).map_err(|e| anyhow::anyhow!("Copy failed: {}", e))?;
+    Ok(dst)
+}
+
+/// Copy everything in `src` into the empty database `dst` through a
+/// temporary backup file.
+pub(crate) fn copy_into(src: &DbInstance, dst: &DbInstance) -> anyhow::Result<()> {
+    let file = TempFile::private()?;
+    catch_panic(|| src.backup_db(file.path())).map_err(|e| anyhow::anyhow!("{}", e))?;
+    catch_panic(|| restore_file(dst, file.path())).map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+/// Restore the backup file at `path` into the empty database `db`.
+///
+/// Cozo copies the backup's storage but keeps numbering new relations from
+/// its count before the restore, so the next relation created would share
+/// its storage, and rows, with a restored one. The count is reloaded from
+/// the restored storage to prevent that.
+pub(crate) fn restore_file(db: &DbInstance, path: &str) -> Result<(), cozo::Error> {
+    db.restore_backup(path)?;
+    match db {
+        DbInstance::Mem(db) => db.initialize(),
+        #[cfg(not(target_family = "wasm"))]
+        DbInstance::Sqlite(db) => db.initialize(),
+        #[cfg(feature = "rocksdb")]
+        DbInstance::RocksDb(db) => db.initialize(),
+    }
+}
+
+/// A temporary copy of a database, see [`TempFile::private`].
+pub(crate) struct TempFile {
+    path: String,
+    /// Whether the file is overwritten with zeros before it is removed.
+    wipe: bool,
+}
+
+impl TempFile {
+    /// A file for a copy of the data that must not be left readable, such
+    /// as a backup before it is encrypted or compressed: created in the app's private
+    /// cache directory with a random name, readable only by the app's
+    /// user, and overwritten with zeros before it is removed on drop, along
+    /// with SQLite's journal of it.
+    pub(crate) fn private() -> anyhow::Result<Self> {
+        let mut random = [0u8; 16];
+        OsRng.fill_bytes(&mut random);
+        let name: String = random.iter().map(|b| format!("{:02x}", b)).collect();
+        let path = private_cache_dir()?.join(format!("cozo_dart_{}.db", name));
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options
+            .open(&path)
+            .map_err(|e| anyhow::anyhow!("Failed to create {:?}: {}", path, e))?;
+        Ok(TempFile {
+            path: path.to_string_lossy().into_owned(),
+            wipe: true,
+        })
+    }
+
+    pub(crate) fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if !self.wipe {
+            let _ = std::fs::remove_file(&self.path);
+            return;
+        }
+        for path in [self.path.clone(), format!("{}-journal", self.path)] {
+            if let Ok(mut file) = std::fs::OpenOptions::new().write(true).open(&path) {
+                let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+                let zeros = vec![0u8; 64 * 1024];
+                let mut left = len;
+                while left > 0 {
+                    let n = left.min(zeros.len() as u64) as usize;
+                    if file.write_all(&zeros[..n]).is_err() {
+                        break;
+                    }
+                    left -= n as u64;
+                }
+                let _ = file.sync_all();
+            }
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+/// Header identifying a passphrase-protected backup file, followed by the
+/// Argon2 salt, the XChaCha20-Poly1305 nonce and the ciphertext.
+const ENCRYPTED_MAGIC: &[u8] = b"CZDBENC1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// Backup the database to a file encrypted with a passphrase.
+///
+/// The key is derived from `passphrase` with Argon2id and a random salt, and
+/// the backup is sealed with XChaCha20-Poly1305. Restore it with
+/// [`cozo_restore_encrypted`].
+///
+/// Cozo only writes backups to a file, so the plaintext backup is written
+/// first to a file only the app can read, with a random name in its private
+/// cache directory, and overwritten and removed once it has been read,
+/// whether or not the backup succeeds.
+pub fn cozo_backup_encrypted(db: &CozoDb, path: String, passphrase: String) -> anyhow::Result<()> {
+    let inner = db.instance()?;
+    let file = TempFile::private()
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))?;
+    catch_panic(|| inner.backup_db(file.path()))
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))?;
+    let data = std::fs::read(file.path())
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))?;
+    drop(file);
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = backup_cipher(&passphrase, &salt)?
+        .encrypt(&nonce, data.as_slice())
+        .map_err(|_| {
+            failure(
+                ErrorKind::Storage,
+                "Backup failed: could not encrypt backup",
+            )
+        })?;
+
+    let mut out =
+        Vec::with_capacity(ENCRYPTED_MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(ENCRYPTED_MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    std::fs::write(&path, out)
+        .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))
+}
+
+/// Restore the database from a file written by [`cozo_backup_encrypted`].
+///
+/// Fails without touching the database if the passphrase is wrong or the
+/// file has been modified. The decrypted backup goes through a private file
+/// as for [`cozo_backup_encrypted`].
+pub fn cozo_restore_encrypted(db: &CozoDb, path: String, passphrase: String) -> anyhow::Result<()> {
+    let data = std::fs::read(&path)
+        .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))?;
+    let body = data
+        .strip_prefix(ENCRYPTED_MAGIC)
+        .filter(|body| body.len() >= SALT_LEN + NONCE_LEN)
+        .ok_or_else(|| {
+            failure(
+                ErrorKind::Storage,
+                "Restore failed: not an encrypted backup file",
+            )
+        })?;
+    let (salt, rest) = body.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let plain = backup_cipher(&passphrase, salt)?
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| {
+            failure(
+                ErrorKind::Storage,
+                "Restore failed: wrong passphrase or corrupted backup",
+            )
+        })?;
+    let inner = db.writable()?;
+    let file = TempFile::private()
+        .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))?;
+    std::fs::write(file.path(), plain)
+        .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))?;
+    catch_panic(|| restore_file(&inner, file.path()))
+        .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))
+}
+
+fn backup_cipher(passphrase: &str, salt: &[u8]) -> anyhow::Result<XChaCha20Poly1305> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow::anyhow!("Key derivation failed: {}", e))?;
+    Ok(XChaCha20Poly1305::new(&key.into()))
+}
+
+/// What [`cozo_verify_backup`] found in a backup file.
+#[derive(Debug, Clone)]
+pub struct BackupInfo {
+    /// Stored relations in the backup, not counting indices.
+    pub relations: Vec<String>,
+    /// Number of rows of each relation, in the order of `relations`.
+    pub row_counts: Vec<u64>,
+    /// Unix time in seconds the backup was taken, from the manifest of
+    /// `cozo_backup_incremental` if the file is listed in one, else the time
+    /// the file was last modified.
+    pub created_at: Option<f64>,
+    /// Whether the file passed SQLite's integrity check and, for a full
+    /// backup listed in a manifest, every relation matches the checksum
+    /// recorded when it was taken.
+    pub checksum_ok: bool,
+}
+
+/// Check a backup file before offering it for restore, e.g. in a restore
+/// picker.
+///
+/// The file is checked with `PRAGMA integrity_check` and then read in full
+/// into a scratch in-memory database, so this takes about as long as a
+/// restore and needs memory for the whole backup. Files written by
+/// [`cozo_backup_encrypted`] cannot be checked without the passphrase and
+/// are rejected. Compressed backups are decompressed to a temporary file
+/// first.
+///
+/// Fails if the file cannot be read as a backup at all.
+pub fn cozo_verify_backup(path: String) -> anyhow::Result<BackupInfo> {
+    let error = |e: &dyn std::fmt::Display| {
+        failure(
+            ErrorKind::Storage,
+            format!("Backup verification failed: {}", e),
+        )
+    };
+    let mut header = [0u8; ENCRYPTED_MAGIC.len()];
+    let encrypted = std::fs::File::open(&path)
+        .and_then(|mut file| file.read(&mut header))
+        .map_err(|e| error(&e))?
+        == header.len()
+        && header == ENCRYPTED_MAGIC;
+    if encrypted {
+        return Err(error(&"the backup is encrypted"));
+    }
+    let plain = decompressed_backup(&path).map_err(|e| error(&e))?;
+    let plain_path = plain.as_ref().map_or(path.as_str(), TempFile::path);
+    let integrity = sqlite_file_query(plain_path, "Integrity check", "PRAGMA integrity_check")
+        .map_err(|e| error(&e))?;
+    let mut checksum_ok = integrity.iter().all(|row| row == "ok");
+
+    let scratch = DbInstance::new("mem", "", "").map_err(|e| error(&e))?;
+    catch_panic(|| restore_file(&scratch, plain_path)).map_err(|e| error(&e))?;
+    let relations: Vec<String> = catch_panic(|| {
+        scratch.run_script(
+            "::relations",
+            Default::default(),
+            ScriptMutability::Immutable,
+        )
+    })
+    .map_err(|e| error(&e))?
+    .rows
+    .iter()
+    .filter(|row| row[2].get_str() != Some("index"))
+    .filter_map(|row| row[0].get_str().map(String::from))
+    .collect();
+    let mut row_counts = vec![];
+    for relation in &relations {
+        let key = &relation_columns(&scratch, relation)?[0].name;
+        let script = format!("?[count({0})] := *{1}{{{0}}}", key, relation);
+        let count = catch_panic(|| {
+            scratch.run_script(&script, Default::default(), ScriptMutability::Immutable)
+        })
+        .map_err(|e| error(&e))?;
+        row_counts.push(
+            count
+                .rows
+                .first()
+                .and_then(|row| row[0].get_int())
+                .unwrap_or_default() as u64,
+        );
+    }
+
+    // A backup taken by `cozo_backup_incremental` has an entry in the
+    // manifest next to it.
+    let file = Path::new(&path);
+    let entry = file
+        .parent()
+        .and_then(|dir| read_manifest(&dir.to_string_lossy()).ok())
+        .and_then(|manifest| {
+            let name = file.file_name()?.to_string_lossy().into_owned();
+            manifest["backups"]
+                .as_array()?
+                .iter()
+                .find(|entry| entry["file"] == name.as_str())
+                .cloned()
+        });
+    let mut created_at = entry
+        .as_ref()
+        .and_then(|entry| entry["created_at"].as_f64());
+    if let Some(entry) = entry.filter(|entry| entry["full"] == true) {
+        for relation in &relations {
+            let expected = entry["checksums"][relation.as_str()].as_str();
+            if expected != Some(relation_checksum(&scratch, relation)?.as_str()) {
+                checksum_ok = false;
+            }
+        }
+    }
+    if created_at.is_none() {
+        created_at = std::fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs_f64());
+    }
+    Ok(BackupInfo {
+        relations,
+        row_counts,
+        created_at,
+        checksum_ok,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_run_query;
+
+    fn rows(db: &CozoDb, script: &str) -> serde_json::Value {
+        let out = cozo_run_query(db, script.into(), "{}".into(), true).unwrap();
+        serde_json::from_str::<serde_json::Value>(&out).unwrap()["rows"].clone()
+    }
+
+    #[test]
+    fn backup_keys_depend_on_passphrase_and_salt() {
+        let salt = [7u8; SALT_LEN];
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let sealed = backup_cipher("secret", &salt)
+            .unwrap()
+            .encrypt(&nonce, b"backup".as_slice())
+            .unwrap();
+        let open = |passphrase: &str, salt: &[u8]| {
+            backup_cipher(passphrase, salt)
+                .unwrap()
+                .decrypt(&nonce, sealed.as_slice())
+        };
+        assert_eq!(open("secret", &salt).unwrap(), b"backup");
+        assert!(open("Secret", &salt).is_err());
+        assert!(open("secret", &[8u8; SALT_LEN]).is_err());
+        assert!(backup_cipher("secret", &[0u8; 4]).is_err());
+    }
+
+    #[test]
+    fn encrypted_backups_restore_with_the_passphrase_only() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_query(
+            &db,
+            ":create t {id: Int => name: String}".into(),
+            "{}".into(),
+            false,
+        )
+        .unwrap();
+        cozo_run_query(
+            &db,
+            "?[id, name] <- [[1, 'a'], [2, 'b']] :put t {id => name}".into(),
+            "{}".into(),
+            false,
+        )
+        .unwrap();
+        let backup = TempFile::private().unwrap();
+        cozo_backup_encrypted(&db, backup.path().into(), "secret".into()).unwrap();
+        let data = std::fs::read(backup.path()).unwrap();
+        assert!(data.starts_with(ENCRYPTED_MAGIC));
+        assert!(!data.windows(6).any(|w| w == b"SQLite"));
+
+        let restored = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let err = cozo_restore_encrypted(&restored, backup.path().into(), "wrong".into());
+        assert!(err.unwrap_err().to_string().contains("wrong passphrase"));
+        assert_eq!(rows(&restored, "::relations"), serde_json::json!([]));
+
+        cozo_restore_encrypted(&restored, backup.path().into(), "secret".into()).unwrap();
+        assert_eq!(
+            rows(&restored, "?[id, name] := *t{id, name}"),
+            serde_json::json!([[1, "a"], [2, "b"]])
+        );
+    }
+
+    #[test]
+    fn private_files_are_removed_on_drop() {
+        let file = TempFile::private().unwrap();
+        let path = file.path().to_string();
+        assert!(Path::new(&path).starts_with(private_cache_dir().unwrap()));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::write(&path, b"plaintext").unwrap();
+        drop(file);
+        assert!(!Path::new(&path).exists());
+    }
+
+    #[test]
+    fn byte_backups_and_clones_keep_the_data() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_query(
+            &db,
+            "{:create t {id: Int => name: String}} {?[id, name] <- [[1, 'a']] :put t {id => name}}"
+                .into(),
+            "{}".into(),
+            false,
+        )
+        .unwrap();
+        let names = |db: &CozoDb| {
+            cozo_run_query(db, "?[name] := *t{name}".into(), "{}".into(), true).unwrap()
+        };
+
+        let restored = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_restore_from_bytes(&restored, cozo_backup_to_bytes(&db).unwrap()).unwrap();
+        assert!(names(&restored).contains("[[\"a\"]]"));
+        assert!(names(&cozo_clone_to_mem(&db).unwrap()).contains("[[\"a\"]]"));
+    }
+
+    fn sink() -> StreamSink<String> {
+        StreamSink::deserialize("0".into())
+    }
+
+    #[test]
+    fn progress_backups_restore() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_query(
+            &db,
+            "{:create t {id: Int}} {?[id] <- [[1], [2]] :put t {id}}".into(),
+            "{}".into(),
+            false,
+        )
+        .unwrap();
+        let backup = TempFile::private().unwrap();
+        std::fs::remove_file(backup.path()).unwrap();
+        cozo_backup_with_progress(&db, backup.path().into(), sink()).unwrap();
+        assert!(file_size(backup.path()) > 0);
+
+        let restored = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_restore_with_progress(&restored, backup.path().into(), sink()).unwrap();
+        assert_eq!(
+            rows(&restored, "?[id] := *t{id}"),
+            serde_json::json!([[1], [2]])
+        );
+        let again = cozo_restore_with_progress(&restored, backup.path().into(), sink());
+        assert!(again.unwrap_err().to_string().starts_with("Restore failed"));
+        let missing = cozo_backup_with_progress(&db, "/nonexistent/dir/b.db".into(), sink());
+        assert!(missing
+            .unwrap_err()
+            .to_string()
+            .starts_with("Backup failed"));
+    }
+
+    #[test]
+    fn progress_is_sent_until_the_work_finishes() {
+        let sent = std::sync::atomic::AtomicUsize::new(0);
+        let result = with_progress(
+            &sink(),
+            || {
+                sent.fetch_add(1, Ordering::Relaxed);
+                String::new()
+            },
+            || {
+                std::thread::sleep(PROGRESS_INTERVAL * 3);
+                7
+            },
+        );
+        assert_eq!(result, 7);
+        // The first event fails to post, which stops the progress thread.
+        assert_eq!(sent.load(Ordering::Relaxed), 1);
+        assert_eq!(with_progress(&sink(), String::new, || 8), 8);
+    }
+
+    #[test]
+    fn relations_created_after_a_restore_have_their_own_rows() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_query(
+            &db,
+            "{:create t {id: Int}} {?[id] <- [[1], [2]] :put t {id}}".into(),
+            "{}".into(),
+            false,
+        )
+        .unwrap();
+        let restored = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_restore_from_bytes(&restored, cozo_backup_to_bytes(&db).unwrap()).unwrap();
+        for copy in [restored, cozo_clone_to_mem(&db).unwrap()] {
+            cozo_run_query(
+                &copy,
+                "{:create u {id: Int}} {?[id] <- [[9]] :put u {id}}".into(),
+                "{}".into(),
+                false,
+            )
+            .unwrap();
+            assert_eq!(
+                rows(&copy, "?[id] := *t{id}"),
+                serde_json::json!([[1], [2]])
+            );
+            assert_eq!(rows(&copy, "?[id] := *u{id}"), serde_json::json!([[9]]));
+        }
+    }
+
+    #[test]
+    fn clones_are_independent_and_writable() {
+        let db =
+            cozo_open_db("mem".into(), String::new(), r#"{"read_only": true}"#.into()).unwrap();
+        let clone = cozo_clone_to_mem(&db).unwrap();
+        cozo_run_query(&clone, ":create t {id: Int}".into(), "{}".into(), false).unwrap();
+        assert_eq!(rows(&clone, "::relations").as_array().unwrap().len(), 1);
+        assert_eq!(rows(&db, "::relations"), serde_json::json!([]));
+    }
+
+    #[test]
+    fn databases_copy_across_engines() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_query(
+            &db,
+            "{:create t {id: Int => name: String}} {?[id, name] <- [[1, 'a']] :put t {id => name}}
+             {::index create t:by_name {name}}"
+                .into(),
+            "{}".into(),
+            false,
+        )
+        .unwrap();
+        let file = TempFile::private().unwrap();
+        std::fs::remove_file(file.path()).unwrap();
+        let copy =
+            cozo_copy_database(&db, "sqlite".into(), file.path().into(), "{}".into()).unwrap();
+        assert_eq!(
+            rows(&copy, "?[name, id] := *t:by_name{name, id}"),
+            serde_json::json!([["a", 1]])
+        );
+
+        let err = cozo_copy_database(
+            &db,
+            "mem".into(),
+            String::new(),
+            r#"{"read_only": true}"#.into(),
+        );
+        assert!(err.err().unwrap().to_string().contains("read-only"));
+        crate::api::simple::cozo_close_db(&copy);
+        let _ = std::fs::remove_file(format!("{}.lock", file.path()));
+    }
+
+    #[test]
+    fn backups_are_verified() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_query(
+            &db,
+            "{:create t {id: Int}} {?[id] <- [[1], [2]] :put t {id}} {:create u {k: String}}"
+                .into(),
+            "{}".into(),
+            false,
+        )
+        .unwrap();
+        let file = TempFile::private().unwrap();
+        std::fs::remove_file(file.path()).unwrap();
+        db.instance().unwrap().backup_db(file.path()).unwrap();
+        let info = cozo_verify_backup(file.path().into()).unwrap();
+        assert_eq!(info.relations, ["t", "u"]);
+        assert_eq!(info.row_counts, [2, 0]);
+        assert!(info.checksum_ok && info.created_at.is_some());
+
+        let dir = std::env::temp_dir().join(format!("cozo_dart_verify_{}", std::process::id()));
+        let dir = dir.to_string_lossy().into_owned();
+        let full = crate::api::incremental::cozo_backup_incremental(&db, dir.clone()).unwrap();
+        let info = cozo_verify_backup(full.path.clone()).unwrap();
+        assert!(info.checksum_ok);
+        assert_eq!(info.created_at, Some(full.created_at));
+        let manifest = Path::new(&dir).join("manifest.json");
+        let mut json: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&manifest).unwrap()).unwrap();
+        json["backups"][0]["checksums"]["t"] = "0".into();
+        std::fs::write(&manifest, json.to_string()).unwrap();
+        assert!(!cozo_verify_backup(full.path).unwrap().checksum_ok);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let encrypted = TempFile::private().unwrap();
+        cozo_backup_encrypted(&db, encrypted.path().into(), "secret".into()).unwrap();
+        let err = cozo_verify_backup(encrypted.path().into()).unwrap_err();
+        assert!(err.to_string().contains("the backup is encrypted"));
+        std::fs::write(encrypted.path(), b"not a backup").unwrap();
+        assert!(cozo_verify_backup(encrypted.path().into()).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::api::error::{with_context, ErrorKind};
+use crate::api::simple::CozoDb;
+use crate::api::transaction::{cozo_begin_tx, tx_abort, tx_commit};
+use crate::api::value::{params_from_values, CozoValue, QueryResult};
+
+/// One entry of a [`cozo_run_batch`] call.
+#[derive(Debug, Clone)]
+pub struct ScriptWithParams {
+    pub script: String,
+    pub params: HashMap<String, CozoValue>,
+}
+
+/// Run several scripts in one call across the bridge.
+///
+/// - `atomic`: if true, all scripts share one write transaction that is only
+///   committed if every script succeeds. Each script must then be a single
+///   query, without `{ ... }` blocks or `::` system ops.
+///
+/// Returns one [`QueryResult`] per script, in order. Without `atomic`, every
+/// script runs on its own and failures are reported in their entry. With
+/// `atomic`, the first failure aborts the transaction and is returned as an
+/// error naming the failing script.
+pub fn cozo_run_batch(
+    db: &CozoDb,
+    scripts: Vec<ScriptWithParams>,
+    atomic: bool,
+) -> anyhow::Result<Vec<QueryResult>> {
+    if atomic {
+        return run_atomic(db, scripts);
+    }
+    let inner = db.instance()?;
+    Ok(scripts
+        .into_iter()
+        .map(|entry| {
+            let start = Instant::now();
+            let result = params_from_values(entry.params).and_then(|params| {
+                db.run_rows(&inner, &entry.script, params, db.mutability(false))
+            });
+            let took = start.elapsed().as_secs_f64();
+            match result {
+                Ok(rows) => QueryResult::from_rows(rows, took),
+                Err(e) => QueryResult::from_error(e.to_string(), took),
+            }
+        })
+        .collect())
+}
+
+pub(crate) fn run_atomic(
+    db: &CozoDb,
+    scripts: Vec<ScriptWithParams>,
+) -> anyhow::Result<Vec<QueryResult>> {
+    let tx = cozo_begin_tx(db, true)?;
+    let mut results = Vec::with_capacity(scripts.len());
+    for (i, entry) in scripts.into_iter().enumerate() {
+        let start = Instant::now();
+        match params_from_values(entry.params).and_then(|params| tx.run(&entry.script, params)) {
+            Ok(rows) => results.push(QueryResult::from_rows(rows, start.elapsed().as_secs_f64())),
+            Err(e) => {
+                tx_abort(&tx)?;
+                return Err(with_context(
+                    e,
+                    ErrorKind::Transact,
+                    &format!("Batch failed at script {}", i),
+                ));
+            }
+        }
+    }
+    tx_commit(&tx)?;
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    fn entry(script: &str) -> ScriptWithParams {
+        ScriptWithParams {
+            script: script.into(),
+            params: HashMap::new(),
+        }
+    }
+
+    fn ids(db: &CozoDb) -> usize {
+        cozo_run_batch(db, vec![entry("?[id] := *items{id}")], false).unwrap()[0]
+            .rows
+            .len()
+    }
+
+    #[test]
+    fn failures_are_reported_per_script() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        let params = HashMap::from([("id".to_string(), CozoValue::Int(2))]);
+        let scripts = vec![
+            entry("?[id] <- [[1]] :put items {id}"),
+            entry("?[id] <- [[1]"),
+            ScriptWithParams {
+                script: "?[id] <- [[$id]] :put items {id}".into(),
+                params,
+            },
+        ];
+        let results = cozo_run_batch(&db, scripts, false).unwrap();
+        let ok: Vec<_> = results.iter().map(|r| r.ok).collect();
+        assert_eq!(ok, [true, false, true]);
+        assert_eq!(ids(&db), 2);
+    }
+
+    #[test]
+    fn atomic_batches_roll_back_on_failure() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        let scripts = vec![
+            entry("?[id] <- [[1]] :put items {id}"),
+            entry("?[id] := *missing{id}"),
+        ];
+        let err = cozo_run_batch(&db, scripts, true).unwrap_err();
+        assert!(err.to_string().contains("Batch failed at script 1"));
+        assert_eq!(ids(&db), 0);
+
+        let scripts = vec![
+            entry("?[id] <- [[1]] :put items {id}"),
+            entry("?[id] := *items{id}"),
+        ];
+        let results = cozo_run_batch(&db, scripts, true).unwrap();
+        // Later scripts see the writes of earlier ones.
+        assert_eq!(results[1].rows.len(), 1);
+        assert_eq!(ids(&db), 1);
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use flutter_rust_bridge::frb;
+
+use crate::api::simple::CozoDb;
+
+/// How scripts failing with SQLite's "database is locked" are retried, see
+/// [`cozo_set_busy_retry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BusyRetryPolicy {
+    /// Runs of a script in total, including the first; 1 disables retries.
+    pub max_attempts: u32,
+    /// Wait before the first retry.
+    pub initial_delay_ms: u64,
+    /// Factor each wait is multiplied by for the next retry: 2.0 doubles
+    /// it, 1.0 keeps it constant.
+    pub multiplier: f64,
+    /// Upper bound of a single wait.
+    pub max_delay_ms: u64,
+}
+
+impl BusyRetryPolicy {
+    /// The wait before retry `retry`, counting from 0.
+    fn delay(&self, retry: u32) -> Duration {
+        let ms = self.initial_delay_ms as f64 * self.multiplier.powi(retry as i32);
+        Duration::from_millis(ms.min(self.max_delay_ms as f64) as u64)
+    }
+}
+
+/// Run scripts that fail because another connection holds SQLite's write
+/// lock again after a backoff, e.g. while a background isolate with its own
+/// handle is writing, instead of failing the call. `None` turns retrying
+/// off, which is the default.
+///
+/// Applies to `cozo_run_query` and its variants, batches, prepared queries,
+/// the row calls such as `cozo_put_rows`, and system ops the binding runs.
+/// A failed script has written nothing, so running it again is safe; calls
+/// made inside a `CozoTx` are not retried, since the transaction holds its
+/// earlier writes. Takes effect for this handle and the handles sharing its
+/// database, including queries prepared before.
+#[frb(sync)]
+pub fn cozo_set_busy_retry(db: &CozoDb, policy: Option<BusyRetryPolicy>) -> anyhow::Result<()> {
+    if let Some(policy) = &policy {
+        if policy.max_attempts == 0 {
+            anyhow::bail!("Busy retry needs max_attempts of at least 1");
+        }
+        if !policy.multiplier.is_finite() || policy.multiplier < 1.0 {
+            anyhow::bail!("Busy retry needs a multiplier of at least 1.0");
+        }
+    }
+    *db.busy_retry.lock().unwrap_or_else(PoisonError::into_inner) = policy;
+    Ok(())
+}
+
+/// Run `run`, running it again as `policy` says while it fails with a busy
+/// error.
+pub(crate) fn retry_busy<T>(
+    policy: &Mutex<Option<BusyRetryPolicy>>,
+    mut run: impl FnMut() -> Result<T, cozo::Error>,
+) -> Result<T, cozo::Error> {
+    let policy = *policy.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut retry = 0;
+    loop {
+        match run() {
+            Err(err) if is_busy(&err) => match policy {
+                Some(policy) if retry + 1 < policy.max_attempts => {
+                    std::thread::sleep(policy.delay(retry));
+                    retry += 1;
+                }
+                _ => return Err(err),
+            },
+            result => return result,
+        }
+    }
+}
+
+/// Whether `err` is SQLite's `SQLITE_BUSY` or `SQLITE_LOCKED`.
+fn is_busy(err: &cozo::Error) -> bool {
+    err.chain().any(|cause| {
+        let message = cause.to_string();
+        message.contains("database is locked") || message.contains("database table is locked")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY: BusyRetryPolicy = BusyRetryPolicy {
+        max_attempts: 3,
+        initial_delay_ms: 1,
+        multiplier: 10.0,
+        max_delay_ms: 50,
+    };
+
+    /// Runs of a script failing with `error` the first `failures` times
+    /// that `retry_busy` makes under `policy`, and whether it succeeded.
+    fn attempts(policy: Option<BusyRetryPolicy>, failures: u32, error: &str) -> (u32, bool) {
+        let mut runs = 0;
+        let result = retry_busy(&Mutex::new(policy), || {
+            runs += 1;
+            match runs <= failures {
+                true => Err(cozo::Error::msg(error.to_string())),
+                false => Ok(()),
+            }
+        });
+        (runs, result.is_ok())
+    }
+
+    #[test]
+    fn delays_grow_up_to_the_cap() {
+        let delays: Vec<u64> = (0..4).map(|i| POLICY.delay(i).as_millis() as u64).collect();
+        assert_eq!(delays, [1, 10, 50, 50]);
+    }
+
+    #[test]
+    fn only_busy_errors_are_retried() {
+        assert_eq!(attempts(Some(POLICY), 2, "database is locked"), (3, true));
+        assert_eq!(attempts(Some(POLICY), 5, "database is locked"), (3, false));
+        assert_eq!(
+            attempts(Some(POLICY), 1, "database table is locked"),
+            (2, true)
+        );
+        assert_eq!(attempts(Some(POLICY), 1, "no such relation"), (1, false));
+        assert_eq!(attempts(None, 1, "database is locked"), (1, false));
+    }
+
+    #[test]
+    fn policies_are_checked() {
+        let db =
+            crate::api::simple::cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_set_busy_retry(&db, Some(POLICY)).unwrap();
+        assert_eq!(*db.busy_retry.lock().unwrap(), Some(POLICY));
+        let bad = |policy| cozo_set_busy_retry(&db, Some(policy)).is_err();
+        assert!(bad(BusyRetryPolicy {
+            max_attempts: 0,
+            ..POLICY
+        }));
+        assert!(bad(BusyRetryPolicy {
+            multiplier: 0.5,
+            ..POLICY
+        }));
+        assert!(bad(BusyRetryPolicy {
+            multiplier: f64::NAN,
+            ..POLICY
+        }));
+        cozo_set_busy_retry(&db, None).unwrap();
+        assert_eq!(*db.busy_retry.lock().unwrap(), None);
+    }
+}
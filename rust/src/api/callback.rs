@@ -0,0 +1,509 @@
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+use std::time::Instant;
+
+use cozo::{CallbackOp, DbInstance, NamedRows, ScriptMutability};
+use serde_json::json;
+
+use crate::api::column_crypto::{decrypt_rows, ColumnCipher};
+use crate::api::error::catch_panic;
+use crate::api::row_policy::{apply_row_policies, filter_rows, RowPolicies};
+use crate::api::schema::cozo_list_relations;
+use crate::api::simple::{parse_params, query_error, rows_to_json, CozoDb};
+use crate::api::value::CozoValue;
+use crate::frb_generated::StreamSink;
+
+/// Kind of a change to a stored relation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeOp {
+    Put,
+    Rm,
+}
+
+/// Rows a commit wrote to or removed from a stored relation, see
+/// [`cozo_register_callback`].
+#[derive(Debug, Clone)]
+pub struct RelationChange {
+    pub relation: String,
+    pub op: ChangeOp,
+    /// Column names, in the order of the values of each row.
+    pub headers: Vec<String>,
+    /// The rows written by a `Put`; empty for an `Rm`.
+    pub new_rows: Vec<Vec<CozoValue>>,
+    /// The rows a `Put` replaced, or those an `Rm` removed.
+    pub old_rows: Vec<Vec<CozoValue>>,
+}
+
+impl RelationChange {
+    fn new(relation: &str, op: CallbackOp, new: NamedRows, old: NamedRows) -> Self {
+        let values = |rows: NamedRows| {
+            rows.rows
+                .into_iter()
+                .map(|row| row.into_iter().map(CozoValue::from).collect())
+                .collect()
+        };
+        RelationChange {
+            relation: relation.to_string(),
+            op: match op {
+                CallbackOp::Put => ChangeOp::Put,
+                CallbackOp::Rm => ChangeOp::Rm,
+            },
+            headers: if new.headers.is_empty() {
+                old.headers.clone()
+            } else {
+                new.headers.clone()
+            },
+            new_rows: values(new),
+            old_rows: values(old),
+        }
+    }
+}
+
+/// Subscribe to changes committed to a stored relation.
+///
+/// - `relation`: name of the stored relation to watch
+///
+/// Each event is a [`RelationChange`] with typed values, one per commit that
+/// wrote to or removed rows from the relation.
+/// Rows hidden by the relation's row policy are left out, and an event with
+/// no row left is not sent; encrypted columns are decrypted.
+/// The stream stays open until [`cozo_unregister_callbacks`] is called for the
+/// relation or the Dart side cancels its subscription.
+pub fn cozo_register_callback(
+    db: &CozoDb,
+    relation: String,
+    sink: StreamSink<RelationChange>,
+) -> anyhow::Result<()> {
+    let inner = db.instance()?;
+    let (id, receiver) = inner.register_callback(&relation, None);
+    db.callbacks
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(id, relation.clone());
+
+    let callbacks = db.callbacks.clone();
+    let visible = Visible::new(db);
+    std::thread::spawn(move || {
+        for (op, new, old) in receiver {
+            let Some((new, old)) = visible.change(&inner, &relation, new, old) else {
+                continue;
+            };
+            if sink
+                .add(RelationChange::new(&relation, op, new, old))
+                .is_err()
+            {
+                // The Dart stream is gone, so stop the cozo side as well.
+                inner.unregister_callback(id);
+                callbacks
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .remove(&id);
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Subscribe to changes committed to several stored relations through one
+/// stream, e.g. to run a Dart handler whenever an order row is inserted.
+///
+/// - `relations`: names of the stored relations to watch
+///
+/// Each event is a JSON string
+/// `{"relation": name, "op": "Put" | "Rm", "new": [{column: value}], "old": [{column: value}]}`,
+/// with `new` and `old` as for [`cozo_register_callback`] but as one object
+/// per row, keyed by column name, ready to decode into a model class. Events
+/// of one relation arrive in commit order. The stream stays open until
+/// [`cozo_unregister_callbacks`] is called for every relation or the Dart
+/// side cancels its subscription, which stops watching all of them. Row
+/// policies and encrypted columns apply as for [`cozo_register_callback`].
+pub fn cozo_watch_relations(
+    db: &CozoDb,
+    relations: Vec<String>,
+    sink: StreamSink<String>,
+) -> anyhow::Result<()> {
+    let inner = db.instance()?;
+    let mut watches = vec![];
+    {
+        let mut callbacks = db.callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+        for relation in relations {
+            let (id, receiver) = inner.register_callback(&relation, None);
+            callbacks.insert(id, relation.clone());
+            watches.push((id, relation, receiver));
+        }
+    }
+    let ids: Vec<u32> = watches.iter().map(|(id, _, _)| *id).collect();
+    for (_, relation, receiver) in watches {
+        let (inner, sink, ids) = (inner.clone(), sink.clone(), ids.clone());
+        let callbacks = db.callbacks.clone();
+        let visible = Visible::new(db);
+        std::thread::spawn(move || {
+            for (op, new, old) in receiver {
+                let Some((new, old)) = visible.change(&inner, &relation, new, old) else {
+                    continue;
+                };
+                let event = json!({
+                    "relation": relation,
+                    "op": op.as_str(),
+                    "new": row_objects(new),
+                    "old": row_objects(old),
+                });
+                if sink.add(event.to_string()).is_err() {
+                    // The Dart stream is gone: stop watching every relation.
+                    let mut callbacks = callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+                    for id in &ids {
+                        inner.unregister_callback(*id);
+                        callbacks.remove(id);
+                    }
+                    break;
+                }
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Run a read-only query and run it again whenever a stored relation it
+/// reads changes, e.g. to drive a `StreamBuilder` without polling.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Each event is the query result as a JSON string, as returned by
+/// `cozo_run_query`. The first is sent right away, and later ones only when
+/// the rows differ from the last sent; changes committed while the query
+/// runs again are folded into one run. The relations read are those the
+/// script names with `*relation` or `~relation:index`. Each run applies the
+/// row policies in effect at the time. If running the query
+/// again fails, e.g. because a relation was dropped, the last event is
+/// `{"ok": false, "error": message}`. The stream stays open until
+/// [`cozo_unregister_callbacks`] is called for the relations read or the
+/// Dart side cancels its subscription.
+///
+/// Fails without opening the stream if the query fails the first time.
+pub fn cozo_watch_query(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    sink: StreamSink<String>,
+) -> anyhow::Result<()> {
+    watch(db, script, params_json, move |event| {
+        sink.add(event).is_ok()
+    })
+}
+
+/// [`cozo_watch_query`] with every event handed to `emit`, which returns
+/// false once the events are no longer wanted.
+fn watch(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    mut emit: impl FnMut(String) -> bool + Send + 'static,
+) -> anyhow::Result<()> {
+    let params = parse_params(&params_json)?;
+    let relations: Vec<String> = cozo_list_relations(db)?
+        .into_iter()
+        .map(|rel| rel.name)
+        .filter(|name| reads_relation(&script, name))
+        .collect();
+    let inner = db.instance()?;
+    let run = {
+        let (inner, policies, cipher) = (
+            inner.clone(),
+            db.row_policies.clone(),
+            db.column_cipher.clone(),
+        );
+        // The policies in effect each time it runs.
+        move || {
+            let mut params = params.clone();
+            let script = apply_row_policies(&policies, &script, &mut params)?;
+            let start = Instant::now();
+            let mut rows = catch_panic(|| {
+                inner
+                    .run_script(&script, params, ScriptMutability::Immutable)
+                    .map_err(|err| query_error(err, &script))
+            })?;
+            decrypt_rows(&cipher, &mut rows)?;
+            Ok::<_, anyhow::Error>((rows, start.elapsed().as_secs_f64()))
+        }
+    };
+    // Watch before the first run, so no change committed after it is missed.
+    let (changed, changes) = mpsc::channel();
+    let mut ids = vec![];
+    {
+        let mut callbacks = db.callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+        for relation in relations {
+            let (id, receiver) = inner.register_callback(&relation, None);
+            callbacks.insert(id, relation);
+            ids.push(id);
+            let changed = changed.clone();
+            std::thread::spawn(move || {
+                for _ in receiver {
+                    if changed.send(()).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    }
+    drop(changed);
+    let unregister = {
+        let callbacks = db.callbacks.clone();
+        move || {
+            let mut callbacks = callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+            for id in &ids {
+                inner.unregister_callback(*id);
+                callbacks.remove(id);
+            }
+        }
+    };
+
+    let (rows, took) = run().inspect_err(|_| unregister())?;
+    let mut last = (rows.headers.clone(), rows.rows.clone());
+    if !emit(rows_to_json(rows, took)) {
+        unregister();
+        return Ok(());
+    }
+
+    std::thread::spawn(move || {
+        // Ends once every relation is unregistered.
+        while changes.recv().is_ok() {
+            while changes.try_recv().is_ok() {}
+            let stop = match run() {
+                Ok((rows, _)) if (&rows.headers, &rows.rows) == (&last.0, &last.1) => false,
+                Ok((rows, took)) => {
+                    last = (rows.headers.clone(), rows.rows.clone());
+                    !emit(rows_to_json(rows, took))
+                }
+                Err(e) => {
+                    emit(json!({"ok": false, "error": e.to_string()}).to_string());
+                    true
+                }
+            };
+            if stop {
+                // Failed, or the Dart stream is gone: stop watching.
+                unregister();
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Close every change stream registered for `relation` through this handle.
+///
+/// Returns the number of streams closed.
+pub fn cozo_unregister_callbacks(db: &CozoDb, relation: String) -> anyhow::Result<u32> {
+    let inner = db.instance()?;
+    let mut callbacks = db.callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+    let ids: Vec<u32> = callbacks
+        .iter()
+        .filter(|(_, rel)| **rel == relation)
+        .map(|(id, _)| *id)
+        .collect();
+    for id in &ids {
+        callbacks.remove(id);
+        inner.unregister_callback(*id);
+    }
+    Ok(ids.len() as u32)
+}
+
+/// What a change stream may show of a change: the rows the row policy
+/// allows, decrypted.
+struct Visible {
+    policies: Arc<Mutex<RowPolicies>>,
+    cipher: Arc<ColumnCipher>,
+}
+
+impl Visible {
+    fn new(db: &CozoDb) -> Self {
+        Visible {
+            policies: db.row_policies.clone(),
+            cipher: db.column_cipher.clone(),
+        }
+    }
+
+    /// The visible rows written and replaced by a change, or `None` if the
+    /// change had rows and none of them is visible.
+    fn change(
+        &self,
+        inner: &DbInstance,
+        relation: &str,
+        new: NamedRows,
+        old: NamedRows,
+    ) -> Option<(NamedRows, NamedRows)> {
+        let had_rows = !new.rows.is_empty() || !old.rows.is_empty();
+        let (new, old) = (
+            self.rows(inner, relation, new),
+            self.rows(inner, relation, old),
+        );
+        if had_rows && new.rows.is_empty() && old.rows.is_empty() {
+            return None;
+        }
+        Some((new, old))
+    }
+
+    /// The visible `rows`. Rows the policy cannot be checked against are
+    /// left out rather than shown.
+    fn rows(&self, inner: &DbInstance, relation: &str, rows: NamedRows) -> NamedRows {
+        let headers = rows.headers.clone();
+        filter_rows(&self.policies, inner, relation, rows)
+            .and_then(|mut rows| {
+                decrypt_rows(&self.cipher, &mut rows)?;
+                Ok(rows)
+            })
+            .unwrap_or_else(|e| {
+                log::error!("Change event of {} dropped: {}", relation, e);
+                NamedRows::new(headers, vec![])
+            })
+    }
+}
+
+/// Rows as JSON objects keyed by column name.
+fn row_objects(rows: NamedRows) -> Vec<serde_json::Value> {
+    rows.rows
+        .into_iter()
+        .map(|row| {
+            rows.headers
+                .iter()
+                .cloned()
+                .zip(row.into_iter().map(serde_json::Value::from))
+                .collect::<serde_json::Map<_, _>>()
+                .into()
+        })
+        .collect()
+}
+
+/// Whether `script` reads the stored relation `name`, as `*name` or
+/// through one of its indices as `~name:index`.
+pub(crate) fn reads_relation(script: &str, name: &str) -> bool {
+    let is_name = |c: char| c.is_alphanumeric() || c == '_' || c == '.';
+    ["*", "~"].iter().any(|sigil| {
+        let needle = format!("{}{}", sigil, name);
+        script.match_indices(&needle).any(|(i, _)| {
+            let rest = &script[i + needle.len()..];
+            match rest.chars().next() {
+                Some(':') => *sigil == "~",
+                Some(c) => !is_name(c),
+                None => true,
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+    use cozo::DataValue;
+    use std::time::Duration;
+
+    #[test]
+    fn watch_query_sends_the_result_and_its_changes() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int => body: String}").unwrap();
+        let (sent, events) = mpsc::channel();
+        let script = "?[id] := *notes{id, body}, body != 'hidden'".to_string();
+        watch(&db, script, "{}".into(), move |event| {
+            sent.send(event).is_ok()
+        })
+        .unwrap();
+        let next = || events.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(next().contains("\"rows\":[]"));
+
+        run_write_op(&db, "?[id, body] <- [[1, 'a']] :put notes {id => body}").unwrap();
+        assert!(next().contains("\"rows\":[[1]]"));
+        // A change that leaves the result as it was sends nothing.
+        run_write_op(
+            &db,
+            "?[id, body] <- [[2, 'hidden']] :put notes {id => body}",
+        )
+        .unwrap();
+        run_write_op(&db, "?[id, body] <- [[3, 'c']] :put notes {id => body}").unwrap();
+        assert!(next().contains("\"rows\":[[1],[3]]"));
+        drop(events);
+
+        // Once the receiver is gone, the next change stops the watch.
+        run_write_op(&db, "?[id, body] <- [[4, 'd']] :put notes {id => body}").unwrap();
+        assert!(wait_unregistered(&db));
+    }
+
+    #[test]
+    fn failed_first_run_leaves_nothing_registered() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int}").unwrap();
+        let script = "?[id] := *notes{id}, id = $missing".to_string();
+        assert!(watch(&db, script, "{}".into(), |_| true).is_err());
+        assert!(db.callbacks.lock().unwrap().is_empty());
+    }
+
+    fn sink<T>() -> StreamSink<T> {
+        StreamSink::deserialize("0".into())
+    }
+
+    /// Wait until no change stream is left on `db`.
+    fn wait_unregistered(db: &CozoDb) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !db.callbacks.lock().unwrap().is_empty() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        db.callbacks.lock().unwrap().is_empty()
+    }
+
+    #[test]
+    fn relation_changes_carry_typed_rows() {
+        let headers = vec!["id".to_string(), "body".to_string()];
+        let rows = NamedRows::new(headers.clone(), vec![vec![1.into(), "a".into()]]);
+        let removed = RelationChange::new("notes", CallbackOp::Rm, NamedRows::default(), rows);
+        assert_eq!(removed.op, ChangeOp::Rm);
+        assert_eq!(removed.headers, headers);
+        assert!(removed.new_rows.is_empty());
+        assert!(matches!(
+            removed.old_rows[..],
+            [ref row] if matches!(row[..], [CozoValue::Int(1), CozoValue::Str(ref b)] if b == "a")
+        ));
+    }
+
+    #[test]
+    fn callbacks_stop_when_unregistered_or_dropped() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int}").unwrap();
+        cozo_register_callback(&db, "notes".into(), sink()).unwrap();
+        cozo_register_callback(&db, "notes".into(), sink()).unwrap();
+        assert_eq!(cozo_unregister_callbacks(&db, "notes".into()).unwrap(), 2);
+        assert_eq!(cozo_unregister_callbacks(&db, "notes".into()).unwrap(), 0);
+
+        // A stream the Dart side has dropped goes away with the next change.
+        cozo_register_callback(&db, "notes".into(), sink()).unwrap();
+        assert_eq!(db.callbacks.lock().unwrap().len(), 1);
+        run_write_op(&db, "?[id] <- [[1]] :put notes {id}").unwrap();
+        assert!(wait_unregistered(&db));
+    }
+
+    #[test]
+    fn watched_relations_stop_together() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create orders {id: Int}").unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        cozo_watch_relations(&db, vec!["orders".into(), "items".into()], sink()).unwrap();
+        assert_eq!(db.callbacks.lock().unwrap().len(), 2);
+
+        run_write_op(&db, "?[id] <- [[1]] :put orders {id}").unwrap();
+        assert!(wait_unregistered(&db));
+    }
+
+    #[test]
+    fn rows_become_objects_keyed_by_column() {
+        let rows = NamedRows::new(
+            vec!["id".into(), "body".into()],
+            vec![vec![1.into(), "a".into()], vec![2.into(), DataValue::Null]],
+        );
+        assert_eq!(
+            row_objects(rows),
+            [
+                json!({"id": 1, "body": "a"}),
+                json!({"id": 2, "body": null})
+            ]
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+
+use cozo::{DataValue, ScriptMutability};
+
+use crate::api::error::catch_panic;
+use crate::api::running::epoch_secs;
+use crate::api::schema::{cozo_list_relations, relation_columns};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::sync::key_pattern;
+use crate::api::triggers::{cozo_list_triggers, cozo_set_triggers};
+use crate::api::value::{string_literal, CozoValue};
+use crate::api::vector::run_write_op;
+
+/// Stored relation recording captured changes:
+/// `{tx, relation, key => op, at}`.
+const CDC_LOG: &str = "cozo_cdc_log";
+
+/// Stored relation holding the last change id handed out, `{id: 0 => tx}`.
+const CDC_COUNTER: &str = "cozo_cdc_counter";
+
+/// Kind of a captured change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CdcOp {
+    Put,
+    Rm,
+}
+
+/// One row changed, as recorded by change data capture.
+#[derive(Debug, Clone)]
+pub struct CdcEntry {
+    /// Change id, increasing with every write statement to a captured
+    /// relation. Rows written by one statement share it.
+    pub tx: i64,
+    pub relation: String,
+    pub op: CdcOp,
+    /// Values of the key columns of the row.
+    pub key: Vec<CozoValue>,
+    /// Unix time in seconds of the change.
+    pub at: f64,
+}
+
+/// Record every row put into or removed from `relations` in an internal log,
+/// read with [`cozo_cdc_read`] and trimmed with [`cozo_cdc_trim`], e.g. to
+/// feed sync, audit trails or cache invalidation.
+///
+/// Changes are recorded by triggers in the transaction that makes them, so
+/// the log survives restarts and never holds a change that was rolled back.
+/// Writes that do not run triggers, such as `cozo_import_relations` and
+/// restores, are not recorded. Triggers set on the relation later with
+/// `cozo_set_triggers` must keep the capture triggers, which
+/// `cozo_list_triggers` returns along with the others. Enabling a relation
+/// again does nothing.
+pub fn cozo_cdc_enable(db: &CozoDb, relations: Vec<String>) -> anyhow::Result<()> {
+    let inner = db.writable()?;
+    ensure_log(db)?;
+    for relation in relations {
+        if relation.contains(':') || [CDC_LOG, CDC_COUNTER].contains(&relation.as_str()) {
+            anyhow::bail!("Change capture failed: {} cannot be captured", relation);
+        }
+        let columns = relation_columns(&inner, &relation)?;
+        let mut triggers = cozo_list_triggers(db, relation.clone())?;
+        if triggers.on_put.iter().any(|t| is_cdc_trigger(t)) {
+            continue;
+        }
+        let (pattern, key) = key_pattern(&columns);
+        // Triggers cannot chain queries, so one trigger records the rows
+        // under the next id and a second one, run after it, moves the
+        // counter on.
+        let record = |source: &str, op: &str| {
+            format!(
+                "?[tx, relation, key, op, at] := {source}[{pattern}], *{counter}{{id: 0, tx: last}}, tx = last + 1, relation = {name}, key = [{key}], op = '{op}', at = now()\n:put {log} {{tx, relation, key => op, at}}",
+                source = source,
+                pattern = pattern,
+                counter = CDC_COUNTER,
+                name = string_literal(&relation),
+                key = key,
+                op = op,
+                log = CDC_LOG,
+            )
+        };
+        let advance = format!(
+            "?[id, tx] := *{counter}{{id, tx: last}}, id = 0, tx = last + 1\n:put {counter} {{id => tx}}",
+            counter = CDC_COUNTER,
+        );
+        triggers.on_put.push(record("_new", "put"));
+        triggers.on_put.push(advance.clone());
+        triggers.on_rm.push(record("_old", "rm"));
+        triggers.on_rm.push(advance);
+        cozo_set_triggers(db, relation, triggers)?;
+    }
+    Ok(())
+}
+
+/// Stop recording changes to `relation`. Changes already recorded stay in
+/// the log.
+pub fn cozo_cdc_disable(db: &CozoDb, relation: String) -> anyhow::Result<()> {
+    let mut triggers = cozo_list_triggers(db, relation.clone())?;
+    triggers.on_put.retain(|t| !is_cdc_trigger(t));
+    triggers.on_rm.retain(|t| !is_cdc_trigger(t));
+    cozo_set_triggers(db, relation, triggers)
+}
+
+/// Read recorded changes in the order they were made.
+///
+/// - `from_tx`: first change id to return; pass the last `tx` read plus one
+///   to continue where the previous call stopped
+/// - `limit`: maximum number of entries returned
+///
+/// Returns at most `limit` entries with `tx >= from_tx`, every row of a
+/// change id together unless `limit` cuts it short.
+pub fn cozo_cdc_read(db: &CozoDb, from_tx: i64, limit: u32) -> anyhow::Result<Vec<CdcEntry>> {
+    if !log_exists(db)? {
+        return Ok(vec![]);
+    }
+    let script = format!(
+        "?[tx, relation, key, op, at] := *{}{{tx, relation, key, op, at}}, tx >= $from\n:order tx, relation, key\n:limit $limit",
+        CDC_LOG
+    );
+    let params = BTreeMap::from([
+        ("from".to_string(), DataValue::from(from_tx)),
+        ("limit".to_string(), DataValue::from(i64::from(limit))),
+    ]);
+    let inner = db.instance()?;
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .map(|row| CdcEntry {
+            tx: row[0].get_int().unwrap_or_default(),
+            relation: row[1].get_str().unwrap_or_default().to_string(),
+            key: match &row[2] {
+                DataValue::List(key) => key.iter().cloned().map(CozoValue::from).collect(),
+                _ => vec![],
+            },
+            op: match row[3].get_str() {
+                Some("rm") => CdcOp::Rm,
+                _ => CdcOp::Put,
+            },
+            at: row[4].get_float().unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Delete recorded changes with a change id below `before_tx`, e.g. once
+/// every consumer has read them.
+///
+/// Returns the number of entries deleted.
+pub fn cozo_cdc_trim(db: &CozoDb, before_tx: i64) -> anyhow::Result<u64> {
+    trim(db, "tx < $bound", DataValue::from(before_tx))
+}
+
+/// Delete recorded changes older than `max_age_secs` seconds.
+///
+/// Returns the number of entries deleted.
+pub fn cozo_cdc_trim_older_than(db: &CozoDb, max_age_secs: f64) -> anyhow::Result<u64> {
+    trim(
+        db,
+        "at < $bound",
+        DataValue::from(epoch_secs() - max_age_secs),
+    )
+}
+
+fn trim(db: &CozoDb, condition: &str, bound: DataValue) -> anyhow::Result<u64> {
+    if !log_exists(db)? {
+        return Ok(0);
+    }
+    let inner = db.writable()?;
+    let script = format!(
+        "?[tx, relation, key] := *{log}{{tx, relation, key, at}}, {condition}\n:rm {log} {{tx, relation, key}}\n:returning",
+        log = CDC_LOG,
+        condition = condition,
+    );
+    let params = BTreeMap::from([("bound".to_string(), bound)]);
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .iter()
+        .filter(|row| row[0].get_str() == Some("deleted"))
+        .count() as u64)
+}
+
+fn is_cdc_trigger(trigger: &str) -> bool {
+    trigger.contains(&format!(":put {} ", CDC_LOG))
+        || trigger.contains(&format!(":put {} ", CDC_COUNTER))
+}
+
+fn log_exists(db: &CozoDb) -> anyhow::Result<bool> {
+    Ok(cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == CDC_LOG))
+}
+
+fn ensure_log(db: &CozoDb) -> anyhow::Result<()> {
+    if log_exists(db)? {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            "{{\n:create {} {{tx: Int, relation: String, key: Any => op: String, at: Float}}\n}}\n{{\n:create {} {{id: Int => tx: Int}}\n}}\n{{\n?[id, tx] <- [[0, 0]]\n:put {} {{id => tx}}\n}}",
+            CDC_LOG, CDC_COUNTER, CDC_COUNTER
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::transaction::{cozo_begin_tx, tx_abort, tx_run_query};
+
+    fn captured() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int => body: String}").unwrap();
+        cozo_cdc_enable(&db, vec!["notes".into()]).unwrap();
+        db
+    }
+
+    fn summary(entries: &[CdcEntry]) -> Vec<(i64, CdcOp, i64)> {
+        entries
+            .iter()
+            .map(|e| match e.key[..] {
+                [CozoValue::Int(id)] => (e.tx, e.op, id),
+                _ => panic!("unexpected key {:?}", e.key),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn writes_are_recorded_in_order() {
+        let db = captured();
+        run_write_op(
+            &db,
+            "?[id, body] <- [[1, 'a'], [2, 'b']] :put notes {id => body}",
+        )
+        .unwrap();
+        run_write_op(&db, "?[id] <- [[1]] :rm notes {id}").unwrap();
+        // Enabling again does not record changes twice.
+        cozo_cdc_enable(&db, vec!["notes".into()]).unwrap();
+        run_write_op(&db, "?[id, body] <- [[3, 'c']] :put notes {id => body}").unwrap();
+
+        let entries = cozo_cdc_read(&db, 0, 100).unwrap();
+        assert_eq!(
+            summary(&entries),
+            [
+                (1, CdcOp::Put, 1),
+                (1, CdcOp::Put, 2),
+                (2, CdcOp::Rm, 1),
+                (3, CdcOp::Put, 3)
+            ]
+        );
+        assert!(entries.iter().all(|e| e.relation == "notes" && e.at > 0.0));
+        assert_eq!(
+            summary(&cozo_cdc_read(&db, 2, 1).unwrap()),
+            [(2, CdcOp::Rm, 1)]
+        );
+        assert!(cozo_cdc_enable(&db, vec![CDC_LOG.into()]).is_err());
+    }
+
+    #[test]
+    fn rolled_back_writes_are_not_recorded() {
+        let db = captured();
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        let script = "?[id, body] <- [[1, 'a']] :put notes {id => body}";
+        tx_run_query(&tx, script.into(), "{}".into()).unwrap();
+        tx_abort(&tx).unwrap();
+        assert!(cozo_cdc_read(&db, 0, 100).unwrap().is_empty());
+    }
+
+    #[test]
+    fn trim_and_disable() {
+        let db = captured();
+        for id in 1..=3 {
+            let script = format!("?[id, body] <- [[{}, 'x']] :put notes {{id => body}}", id);
+            run_write_op(&db, &script).unwrap();
+        }
+        assert_eq!(cozo_cdc_trim(&db, 3).unwrap(), 2);
+        assert_eq!(
+            summary(&cozo_cdc_read(&db, 0, 100).unwrap()),
+            [(3, CdcOp::Put, 3)]
+        );
+        assert_eq!(cozo_cdc_trim_older_than(&db, 3600.0).unwrap(), 0);
+
+        cozo_cdc_disable(&db, "notes".into()).unwrap();
+        run_write_op(&db, "?[id] <- [[3]] :rm notes {id}").unwrap();
+        assert_eq!(cozo_cdc_read(&db, 0, 100).unwrap().len(), 1);
+        assert!(cozo_list_triggers(&db, "notes".into())
+            .unwrap()
+            .on_rm
+            .is_empty());
+    }
+}
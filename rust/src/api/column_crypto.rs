@@ -0,0 +1,772 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Instant;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability, TransactionPayload};
+use flutter_rust_bridge::frb;
+use serde_json::json;
+
+use crate::api::backup::PROGRESS_INTERVAL;
+use crate::api::error::{catch_panic, failure, ErrorKind};
+use crate::api::schema::{column_names, cozo_list_relations, relation_columns, ColumnInfo};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::transaction::finish_multi;
+use crate::api::value::{string_literal, CozoValue};
+use crate::api::vector::run_write_op;
+use crate::frb_generated::StreamSink;
+
+/// Stored relation listing the encrypted columns: `{relation, column}`.
+const ENCRYPTED_COLUMNS: &str = "cozo_encrypted_columns";
+
+/// Header identifying an encrypted value, followed by the length (u32, little
+/// endian) and bytes of its location, the AES-GCM nonce and the ciphertext of
+/// the value's JSON form. The location, see [`location`], is the associated
+/// data of the ciphertext.
+const ENCRYPTED_MAGIC: &[u8] = b"CZCOLENC2";
+const NONCE_LEN: usize = 12;
+
+/// Values written per statement by [`cozo_rekey`].
+const REKEY_CHUNK_ROWS: usize = 1000;
+
+/// The cipher of the `column_key` open option, swapped by [`cozo_rekey`].
+pub(crate) type ColumnCipher = RwLock<Option<Arc<Aes256Gcm>>>;
+
+/// A column whose values are stored encrypted, see [`cozo_encrypt_column`].
+#[derive(Debug, Clone)]
+pub struct EncryptedColumn {
+    pub relation: String,
+    pub column: String,
+}
+
+/// Encrypt the values of a column with the `column_key` the database was
+/// opened with, so that sensitive fields stay unreadable in the file, its
+/// backups and exports without encrypting the whole database.
+///
+/// - `column`: a non-key `Bytes` or `Any` column, nullable or not
+///
+/// The values already in the column are encrypted now. From then on the
+/// row calls (`cozo_put_rows`, `cozo_upsert_rows`) encrypt the column on
+/// write, and every query result is decrypted on read, wherever the values
+/// end up in it: results of the query calls, in every encoding, rows from
+/// `cozo_get_row`, search hits and change stream events alike. Relation
+/// exports keep the ciphertext, so that they import back as they were.
+/// Scripts writing the column themselves must bind values from
+/// [`cozo_encrypt_value`]: plaintext they write is stored as it is. Nulls
+/// stay null. Each value gets a random nonce, so scripts cannot compare or
+/// filter on encrypted values, only pass them through.
+///
+/// Each value is bound to its relation, column and row key, which are
+/// authenticated with it. Query results are decrypted wherever the value
+/// sits in them, since a script may move it anywhere; rewriting the column
+/// (this call, [`cozo_decrypt_column`], [`cozo_rekey`]) fails on a value
+/// stored in another row or column than the one it was encrypted for.
+///
+/// Returns the number of values encrypted.
+pub fn cozo_encrypt_column(db: &CozoDb, relation: String, column: String) -> anyhow::Result<u64> {
+    let cipher = cipher(db)?;
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let fits = columns.iter().any(|c| {
+        c.name == column && !c.is_key && ["Bytes", "Any"].contains(&c.column_type.as_str())
+    });
+    if !fits {
+        anyhow::bail!("{} has no non-key Bytes or Any column {}", relation, column);
+    }
+    ensure_settings(db)?;
+    let script = format!(
+        "?[relation, column] <- [[$relation, $column]] :put {} {{relation, column}}",
+        ENCRYPTED_COLUMNS
+    );
+    let params = BTreeMap::from([
+        ("relation".to_string(), DataValue::from(relation.as_str())),
+        ("column".to_string(), DataValue::from(column.as_str())),
+    ]);
+    catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    rewrite_column(&inner, &relation, &columns, &column, |key, value| {
+        let location = location(&relation, &column, key)?;
+        match (is_encrypted(value), value) {
+            (true, _) => decrypt(&cipher, value, Some(&location)).map(|_| None),
+            (false, DataValue::Null) => Ok(None),
+            (false, _) => encrypt(&cipher, value, &location).map(Some),
+        }
+    })
+}
+
+/// Store the values of an encrypted column in plaintext again.
+///
+/// Returns the number of values decrypted.
+pub fn cozo_decrypt_column(db: &CozoDb, relation: String, column: String) -> anyhow::Result<u64> {
+    let cipher = cipher(db)?;
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let decrypted =
+        rewrite_column(
+            &inner,
+            &relation,
+            &columns,
+            &column,
+            |key, value| match is_encrypted(value) {
+                true => {
+                    decrypt(&cipher, value, Some(&location(&relation, &column, key)?)).map(Some)
+                }
+                false => Ok(None),
+            },
+        )?;
+    run_write_op(
+        db,
+        &format!(
+            "?[relation, column] <- [[{}, {}]] :rm {} {{relation, column}}",
+            string_literal(&relation),
+            string_literal(&column),
+            ENCRYPTED_COLUMNS
+        ),
+    )?;
+    Ok(decrypted)
+}
+
+/// The encrypted columns of all relations.
+pub fn cozo_list_encrypted_columns(db: &CozoDb) -> anyhow::Result<Vec<EncryptedColumn>> {
+    if !settings_exist(db)? {
+        return Ok(vec![]);
+    }
+    let script = format!(
+        "?[relation, column] := *{}{{relation, column}}",
+        ENCRYPTED_COLUMNS
+    );
+    let inner = db.instance()?;
+    let rows =
+        catch_panic(|| inner.run_script(&script, Default::default(), ScriptMutability::Immutable))
+            .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .map(|row| EncryptedColumn {
+            relation: row[0].get_str().unwrap_or_default().to_string(),
+            column: row[1].get_str().unwrap_or_default().to_string(),
+        })
+        .collect())
+}
+
+/// Re-encrypt every encrypted column with `new_key`, 64 hex digits, and use
+/// it on this handle and the handles sharing its database from then on.
+///
+/// The storage itself is never encrypted (see `cozo_open_db`), so this
+/// rotates the `column_key`. Values are decrypted and encrypted again one at
+/// a time in memory, never written in plaintext, and all of them are
+/// rewritten in one transaction, so if the call fails, e.g. because the
+/// handle's key does not decrypt them, the stored values keep the old key.
+/// Queries decrypting values wait until it finishes; writes of encrypted
+/// columns racing with it may keep the old key, so run it while the app is
+/// not writing them. Open the database with `new_key` afterwards.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "values": n, "total": n, "elapsed": secs}`,
+/// where `values` is the number of values re-encrypted so far out of
+/// `total`. The stream closes after the `done` event, or fails with the
+/// error.
+pub fn cozo_rekey(db: &CozoDb, new_key: String, sink: StreamSink<String>) -> anyhow::Result<()> {
+    rekey(db, &new_key, |event| {
+        let _ = sink.add(event);
+    })
+}
+
+/// [`cozo_rekey`], passing each event to `report`.
+fn rekey(db: &CozoDb, new_key: &str, report: impl Fn(String)) -> anyhow::Result<()> {
+    let new = parse_key(new_key)
+        .ok_or_else(|| anyhow::anyhow!("Rekey failed: the new key must be 64 hex digits"))?;
+    let inner = db.writable()?;
+    let start = Instant::now();
+    let mut slot = db
+        .column_cipher
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    let old = slot.clone().ok_or_else(|| {
+        anyhow::anyhow!("Rekey failed: the database was not opened with a `column_key`")
+    })?;
+
+    let existing: Vec<String> = cozo_list_relations(db)?
+        .into_iter()
+        .map(|rel| rel.name)
+        .collect();
+    // The encrypted values of each column with their keys, and the script
+    // writing them back.
+    let mut work = vec![];
+    for encrypted in cozo_list_encrypted_columns(db)? {
+        if !existing.contains(&encrypted.relation) {
+            continue;
+        }
+        let columns = relation_columns(&inner, &encrypted.relation)?;
+        let keys = column_names(columns.iter().filter(|c| c.is_key));
+        let script = format!(
+            "?[{keys}, {column}] := *{rel}{{{keys}, {column}}}",
+            keys = keys,
+            column = encrypted.column,
+            rel = encrypted.relation,
+        );
+        let rows = catch_panic(|| {
+            inner.run_script(&script, Default::default(), ScriptMutability::Immutable)
+        })
+        .map_err(|err| query_error(err, &script))?;
+        let rows: Vec<Vec<DataValue>> = rows
+            .rows
+            .into_iter()
+            .filter(|row| row.last().is_some_and(is_encrypted))
+            .collect();
+        let update = format!(
+            "?[{keys}, {column}] <- $rows :update {rel} {{{keys} => {column}}}",
+            keys = keys,
+            column = encrypted.column,
+            rel = encrypted.relation,
+        );
+        work.push((encrypted, update, rows));
+    }
+    let total: usize = work.iter().map(|(_, _, rows)| rows.len()).sum();
+    let event = |stage: &str, values: usize| {
+        json!({
+            "stage": stage,
+            "values": values,
+            "total": total,
+            "elapsed": start.elapsed().as_secs_f64(),
+        })
+        .to_string()
+    };
+
+    report(event("started", 0));
+    let tx = inner.multi_transaction(true);
+    let mut done = 0;
+    let mut reported = Instant::now();
+    let rewritten = work.into_iter().try_for_each(|(encrypted, update, rows)| {
+        for chunk in rows.chunks(REKEY_CHUNK_ROWS) {
+            let mut changed = Vec::with_capacity(chunk.len());
+            for row in chunk {
+                let mut row = row.clone();
+                let last = row.len() - 1;
+                let location = location(&encrypted.relation, &encrypted.column, &row[..last])?;
+                let plain = decrypt(&old, &row[last], Some(&location))?;
+                row[last] = encrypt(&new, &plain, &location)?;
+                changed.push(DataValue::List(row));
+            }
+            let params = BTreeMap::from([("rows".to_string(), DataValue::List(changed))]);
+            catch_panic(|| tx.run_script(&update, params))
+                .map_err(|err| query_error(err, &update))?;
+            done += chunk.len();
+            if reported.elapsed() >= PROGRESS_INTERVAL {
+                reported = Instant::now();
+                report(event("progress", done));
+            }
+        }
+        Ok::<_, anyhow::Error>(())
+    });
+    match rewritten {
+        Ok(()) => finish_multi(tx, TransactionPayload::Commit)
+            .map_err(|e| anyhow::anyhow!("Rekey failed: {}", e))?,
+        Err(e) => {
+            let _ = finish_multi(tx, TransactionPayload::Abort);
+            anyhow::bail!("Rekey failed: {}", e);
+        }
+    }
+    *slot = Some(new);
+    report(event("done", done));
+    Ok(())
+}
+
+/// `value` encrypted with the `column_key`, to bind as a parameter in a
+/// script writing it to `column` of `relation`, in the row with the key
+/// columns `key`, in schema order.
+#[frb(sync)]
+pub fn cozo_encrypt_value(
+    db: &CozoDb,
+    relation: String,
+    column: String,
+    key: Vec<CozoValue>,
+    value: CozoValue,
+) -> anyhow::Result<CozoValue> {
+    let key = key
+        .into_iter()
+        .map(DataValue::try_from)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let location = location(&relation, &column, &key)?;
+    Ok(encrypt(&*cipher(db)?, &DataValue::try_from(value)?, &location)?.into())
+}
+
+/// The cipher for the `column_key` open option, 64 hex digits.
+pub(crate) fn cipher_from_options(
+    opts: &serde_json::Value,
+) -> anyhow::Result<Option<Arc<Aes256Gcm>>> {
+    let Some(key) = opts.get("column_key") else {
+        return Ok(None);
+    };
+    key.as_str().and_then(parse_key).map(Some).ok_or_else(|| {
+        failure(
+            ErrorKind::Storage,
+            "Failed to open database: `column_key` must be 64 hex digits",
+        )
+    })
+}
+
+/// Encrypt the encrypted columns of `rows`, full rows of `relation` about
+/// to be written.
+pub(crate) fn encrypt_rows(
+    db: &CozoDb,
+    relation: &str,
+    columns: &[ColumnInfo],
+    rows: &mut [Vec<DataValue>],
+) -> anyhow::Result<()> {
+    let encrypted: Vec<usize> = encrypted_columns(db, relation)?
+        .iter()
+        .filter_map(|name| columns.iter().position(|c| c.name == *name))
+        .collect();
+    if encrypted.is_empty() {
+        return Ok(());
+    }
+    let cipher = cipher(db)?;
+    let keys: Vec<usize> = (0..columns.len()).filter(|&i| columns[i].is_key).collect();
+    for row in rows {
+        let key: Vec<DataValue> = keys.iter().map(|&i| row[i].clone()).collect();
+        for &i in &encrypted {
+            if row[i] != DataValue::Null && !is_encrypted(&row[i]) {
+                let location = location(relation, &columns[i].name, &key)?;
+                row[i] = encrypt(&cipher, &row[i], &location)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decrypt every encrypted value in `rows`, including inside lists. Values
+/// are left encrypted on a handle opened without a `column_key`.
+pub(crate) fn decrypt_rows(cipher: &ColumnCipher, rows: &mut NamedRows) -> anyhow::Result<()> {
+    let cipher = cipher.read().unwrap_or_else(PoisonError::into_inner);
+    let Some(cipher) = cipher.as_deref() else {
+        return Ok(());
+    };
+    fn visit(cipher: &Aes256Gcm, value: &mut DataValue) -> anyhow::Result<()> {
+        match value {
+            DataValue::List(values) => values.iter_mut().try_for_each(|v| visit(cipher, v)),
+            _ if is_encrypted(value) => {
+                *value = decrypt(cipher, value, None)?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+    rows.rows
+        .iter_mut()
+        .flatten()
+        .try_for_each(|value| visit(cipher, value))
+}
+
+fn cipher(db: &CozoDb) -> anyhow::Result<Arc<Aes256Gcm>> {
+    db.column_cipher
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .ok_or_else(|| {
+            anyhow::anyhow!("Column encryption needs the database opened with a `column_key`")
+        })
+}
+
+/// The location a value of `column` in the row of `relation` with the key
+/// columns `key` is bound to.
+fn location(relation: &str, column: &str, key: &[DataValue]) -> anyhow::Result<Vec<u8>> {
+    Ok(serde_json::to_vec(&(relation, column, key))?)
+}
+
+fn encrypt(cipher: &Aes256Gcm, value: &DataValue, location: &[u8]) -> anyhow::Result<DataValue> {
+    let plain = serde_json::to_vec(value)?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let payload = Payload {
+        msg: &plain,
+        aad: location,
+    };
+    let ciphertext = cipher
+        .encrypt(&nonce, payload)
+        .map_err(|_| anyhow::anyhow!("Encryption failed"))?;
+    let mut out = Vec::with_capacity(
+        ENCRYPTED_MAGIC.len() + 4 + location.len() + NONCE_LEN + ciphertext.len(),
+    );
+    out.extend_from_slice(ENCRYPTED_MAGIC);
+    out.extend_from_slice(&(location.len() as u32).to_le_bytes());
+    out.extend_from_slice(location);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(DataValue::Bytes(out))
+}
+
+/// Decrypt `value`, failing if `expected` is given and is not the location
+/// it was encrypted for.
+fn decrypt(
+    cipher: &Aes256Gcm,
+    value: &DataValue,
+    expected: Option<&[u8]>,
+) -> anyhow::Result<DataValue> {
+    let failed = || anyhow::anyhow!("Decryption failed: wrong column_key or corrupted value");
+    let body = match value {
+        DataValue::Bytes(bytes) => bytes.strip_prefix(ENCRYPTED_MAGIC),
+        _ => None,
+    }
+    .ok_or_else(failed)?;
+    let (len, body) = body.split_first_chunk::<4>().ok_or_else(failed)?;
+    let len = u32::from_le_bytes(*len) as usize;
+    if body.len() < len + NONCE_LEN {
+        return Err(failed());
+    }
+    let (location, body) = body.split_at(len);
+    if expected.is_some_and(|expected| expected != location) {
+        anyhow::bail!(
+            "Decryption failed: the value was encrypted for {}",
+            String::from_utf8_lossy(location)
+        );
+    }
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+    let payload = Payload {
+        msg: ciphertext,
+        aad: location,
+    };
+    let plain = cipher
+        .decrypt(Nonce::from_slice(nonce), payload)
+        .map_err(|_| failed())?;
+    serde_json::from_slice(&plain).map_err(|_| failed())
+}
+
+fn is_encrypted(value: &DataValue) -> bool {
+    matches!(value, DataValue::Bytes(bytes) if bytes.starts_with(ENCRYPTED_MAGIC))
+}
+
+/// Replace the values of `column` for which `change`, given the key columns
+/// of the row and the value, returns a new one.
+fn rewrite_column(
+    inner: &DbInstance,
+    relation: &str,
+    columns: &[ColumnInfo],
+    column: &str,
+    change: impl Fn(&[DataValue], &DataValue) -> anyhow::Result<Option<DataValue>>,
+) -> anyhow::Result<u64> {
+    let keys = column_names(columns.iter().filter(|c| c.is_key));
+    let script = format!(
+        "?[{keys}, {column}] := *{rel}{{{keys}, {column}}}",
+        keys = keys,
+        column = column,
+        rel = relation,
+    );
+    let rows =
+        catch_panic(|| inner.run_script(&script, Default::default(), ScriptMutability::Immutable))
+            .map_err(|err| query_error(err, &script))?;
+    let mut changed = vec![];
+    for mut row in rows.rows {
+        let last = row.len() - 1;
+        if let Some(value) = change(&row[..last], &row[last])? {
+            row[last] = value;
+            changed.push(DataValue::List(row));
+        }
+    }
+    if changed.is_empty() {
+        return Ok(0);
+    }
+    let count = changed.len() as u64;
+    let script = format!(
+        "?[{keys}, {column}] <- $rows :update {rel} {{{keys} => {column}}}",
+        keys = keys,
+        column = column,
+        rel = relation,
+    );
+    let params = BTreeMap::from([("rows".to_string(), DataValue::List(changed))]);
+    catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(count)
+}
+
+fn encrypted_columns(db: &CozoDb, relation: &str) -> anyhow::Result<Vec<String>> {
+    if !settings_exist(db)? {
+        return Ok(vec![]);
+    }
+    let script = format!(
+        "?[column] := *{}{{relation: $relation, column}}",
+        ENCRYPTED_COLUMNS
+    );
+    let params = BTreeMap::from([("relation".to_string(), DataValue::from(relation))]);
+    let inner = db.instance()?;
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .filter_map(|row| row[0].get_str().map(String::from))
+        .collect())
+}
+
+/// The cipher for a key of 64 hex digits.
+fn parse_key(hex: &str) -> Option<Arc<Aes256Gcm>> {
+    let hex = hex.as_bytes();
+    if hex.len() != 64 {
+        return None;
+    }
+    let mut key = [0u8; 32];
+    for (byte, pair) in key.iter_mut().zip(hex.chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(Arc::new(Aes256Gcm::new(&key.into())))
+}
+
+fn settings_exist(db: &CozoDb) -> anyhow::Result<bool> {
+    Ok(cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == ENCRYPTED_COLUMNS))
+}
+
+fn ensure_settings(db: &CozoDb) -> anyhow::Result<()> {
+    if settings_exist(db)? {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            ":create {} {{relation: String, column: String}}",
+            ENCRYPTED_COLUMNS
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const NEW_KEY: &str = "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff000102030405060708090a0b0c0d0e0f";
+
+    fn open() -> CozoDb {
+        let options = json!({ "column_key": KEY }).to_string();
+        let db = cozo_open_db("mem".into(), String::new(), options).unwrap();
+        let run = |script: &str| cozo_run_query(&db, script.into(), "{}".into(), false);
+        run(":create people {id: Int => secret: Any?}").unwrap();
+        run("?[id, secret] <- [[1, 'alice'], [2, 42], [3, null]] :put people {id => secret}")
+            .unwrap();
+        db
+    }
+
+    fn raw_secrets(db: &CozoDb) -> Vec<DataValue> {
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(
+                "?[id, secret] := *people{id, secret}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap();
+        rows.rows.into_iter().map(|row| row[1].clone()).collect()
+    }
+
+    fn secrets(db: &CozoDb) -> serde_json::Value {
+        let out = cozo_run_query(
+            db,
+            "?[id, secret] := *people{id, secret}".into(),
+            "{}".into(),
+            true,
+        )
+        .unwrap();
+        serde_json::from_str::<serde_json::Value>(&out).unwrap()["rows"].clone()
+    }
+
+    fn place(id: i64) -> Vec<u8> {
+        location("people", "secret", &[DataValue::from(id)]).unwrap()
+    }
+
+    #[test]
+    fn values_round_trip() {
+        let cipher = parse_key(KEY).unwrap();
+        for value in [
+            DataValue::from("text"),
+            DataValue::from(-7),
+            DataValue::from(1.5),
+            DataValue::Bool(true),
+            DataValue::Bytes(vec![0, 1, 255]),
+            DataValue::List(vec![DataValue::from(1), DataValue::from("two")]),
+        ] {
+            let encrypted = encrypt(&cipher, &value, &place(1)).unwrap();
+            assert!(is_encrypted(&encrypted));
+            assert_eq!(
+                decrypt(&cipher, &encrypted, Some(&place(1))).unwrap(),
+                value
+            );
+            assert_eq!(decrypt(&cipher, &encrypted, None).unwrap(), value);
+        }
+        assert!(!is_encrypted(&DataValue::Null));
+        assert!(!is_encrypted(&DataValue::Bytes(vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn nonces_differ_per_value() {
+        let cipher = parse_key(KEY).unwrap();
+        let value = DataValue::from("same");
+        assert_ne!(
+            encrypt(&cipher, &value, &place(1)).unwrap(),
+            encrypt(&cipher, &value, &place(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn wrong_key_and_tampered_values_fail() {
+        let cipher = parse_key(KEY).unwrap();
+        let encrypted = encrypt(&cipher, &DataValue::from("text"), &place(1)).unwrap();
+        assert!(decrypt(&parse_key(NEW_KEY).unwrap(), &encrypted, None).is_err());
+
+        let DataValue::Bytes(bytes) = encrypted else {
+            unreachable!()
+        };
+        let mut tampered = bytes.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&cipher, &DataValue::Bytes(tampered), None).is_err());
+        assert!(decrypt(&cipher, &DataValue::Bytes(ENCRYPTED_MAGIC.to_vec()), None).is_err());
+        assert!(decrypt(&cipher, &DataValue::from("text"), None).is_err());
+
+        // Rewriting the location, same length, breaks the authentication.
+        let start = ENCRYPTED_MAGIC.len() + 4;
+        let mut relabelled = bytes;
+        relabelled[start..start + place(2).len()].copy_from_slice(&place(2));
+        assert!(decrypt(&cipher, &DataValue::Bytes(relabelled), None).is_err());
+    }
+
+    #[test]
+    fn values_are_bound_to_their_row_and_column() {
+        let cipher = parse_key(KEY).unwrap();
+        let encrypted = encrypt(&cipher, &DataValue::from("text"), &place(1)).unwrap();
+        assert!(decrypt(&cipher, &encrypted, Some(&place(2))).is_err());
+        let other_column = location("people", "other", &[DataValue::from(1)]).unwrap();
+        assert!(decrypt(&cipher, &encrypted, Some(&other_column)).is_err());
+
+        // A ciphertext copied into another row is refused when the column is
+        // rewritten.
+        let db = open();
+        cozo_encrypt_column(&db, "people".into(), "secret".into()).unwrap();
+        let raw = raw_secrets(&db);
+        let params = BTreeMap::from([(
+            "rows".to_string(),
+            DataValue::List(vec![DataValue::List(vec![
+                DataValue::from(2),
+                raw[0].clone(),
+            ])]),
+        )]);
+        db.instance()
+            .unwrap()
+            .run_script(
+                "?[id, secret] <- $rows :put people {id => secret}",
+                params,
+                ScriptMutability::Mutable,
+            )
+            .unwrap();
+        assert!(cozo_decrypt_column(&db, "people".into(), "secret".into()).is_err());
+        assert!(rekey(&db, NEW_KEY, |_| {}).is_err());
+    }
+
+    #[test]
+    fn encrypted_values_bound_by_scripts_read_back() {
+        let db = open();
+        cozo_encrypt_column(&db, "people".into(), "secret".into()).unwrap();
+        let value = cozo_encrypt_value(
+            &db,
+            "people".into(),
+            "secret".into(),
+            vec![CozoValue::Int(4)],
+            CozoValue::Str("dave".into()),
+        )
+        .unwrap();
+        let params = BTreeMap::from([("secret".to_string(), DataValue::try_from(value).unwrap())]);
+        db.instance()
+            .unwrap()
+            .run_script(
+                "?[id, secret] <- [[4, $secret]] :put people {id => secret}",
+                params,
+                ScriptMutability::Mutable,
+            )
+            .unwrap();
+        assert_eq!(
+            secrets(&db),
+            json!([[1, "alice"], [2, 42], [3, null], [4, "dave"]])
+        );
+        assert_eq!(
+            cozo_decrypt_column(&db, "people".into(), "secret".into()).unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn keys_must_be_64_hex_digits() {
+        assert!(parse_key(KEY).is_some());
+        assert!(parse_key(&KEY[..62]).is_none());
+        assert!(parse_key(&KEY.replace('0', "g")).is_none());
+    }
+
+    #[test]
+    fn columns_are_stored_encrypted_and_read_as_plaintext() {
+        let db = open();
+        let plain = json!([[1, "alice"], [2, 42], [3, null]]);
+
+        assert_eq!(
+            cozo_encrypt_column(&db, "people".into(), "secret".into()).unwrap(),
+            2
+        );
+        let raw = raw_secrets(&db);
+        assert!(is_encrypted(&raw[0]) && is_encrypted(&raw[1]));
+        assert_eq!(raw[2], DataValue::Null);
+        assert_eq!(secrets(&db), plain);
+
+        assert_eq!(
+            cozo_decrypt_column(&db, "people".into(), "secret".into()).unwrap(),
+            2
+        );
+        assert!(!raw_secrets(&db).iter().any(is_encrypted));
+        assert_eq!(secrets(&db), plain);
+    }
+
+    #[test]
+    fn rekey_encrypts_with_the_new_key() {
+        let db = open();
+        cozo_encrypt_column(&db, "people".into(), "secret".into()).unwrap();
+        let events = std::sync::Mutex::new(vec![]);
+        rekey(&db, NEW_KEY, |event| events.lock().unwrap().push(event)).unwrap();
+        assert!(events.lock().unwrap().last().unwrap().contains("done"));
+
+        let old = parse_key(KEY).unwrap();
+        let new = parse_key(NEW_KEY).unwrap();
+        let raw = raw_secrets(&db);
+        assert!(decrypt(&old, &raw[0], None).is_err());
+        assert_eq!(
+            decrypt(&new, &raw[0], Some(&place(1))).unwrap(),
+            DataValue::from("alice")
+        );
+        assert_eq!(
+            decrypt(&new, &raw[1], Some(&place(2))).unwrap(),
+            DataValue::from(42)
+        );
+        assert_eq!(secrets(&db), json!([[1, "alice"], [2, 42], [3, null]]));
+    }
+
+    #[test]
+    fn rekey_needs_a_valid_key_and_a_column_key() {
+        let db = open();
+        let err = rekey(&db, "abc", |_| {}).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Rekey failed: the new key must be 64 hex digits"
+        );
+
+        let plain = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let err = rekey(&plain, NEW_KEY, |_| {}).unwrap_err();
+        assert!(err.to_string().contains("not opened with a `column_key`"));
+
+        // Nothing to re-encrypt still switches the handle to the new key.
+        rekey(&db, NEW_KEY, |_| {}).unwrap();
+        cozo_encrypt_column(&db, "people".into(), "secret".into()).unwrap();
+        let new = parse_key(NEW_KEY).unwrap();
+        let raw = raw_secrets(&db);
+        assert_eq!(
+            decrypt(&new, &raw[0], Some(&place(1))).unwrap(),
+            DataValue::from("alice")
+        );
+    }
+}
@@ -0,0 +1,578 @@
+use std::collections::BTreeMap;
+
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability};
+use serde_json::{json, Value};
+
+use crate::api::error::catch_panic;
+use crate::api::import::{coerce_rows, named_rows};
+use crate::api::incremental::scan;
+use crate::api::schema::{
+    column_names, column_spec, cozo_create_relation, cozo_list_relations, relation_columns,
+    ColumnDef, ColumnDefault, ColumnInfo,
+};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::sync::key_pattern;
+use crate::api::triggers::{cozo_set_triggers, TriggerSpec};
+use crate::api::value::{string_literal, CozoValue};
+use crate::api::vector::run_write_op;
+
+/// Prefix of the version columns added to CRDT relations.
+const PREFIX: &str = "cozo_crdt_";
+
+/// Version column holding the time of the latest put of the row.
+const ROW_VERSION: &str = "cozo_crdt_row";
+
+/// Stored relation holding the time each removed row of a CRDT relation was
+/// removed: `{relation, key => version}`.
+const TOMBSTONES: &str = "cozo_crdt_tombstones";
+
+/// Extra column of tombstone rows in a changeset.
+const TOMBSTONE_VERSION: &str = "cozo_crdt_version";
+
+/// Version of the changeset written by [`cozo_crdt_changes`].
+const CHANGESET_FORMAT: u64 = 1;
+
+/// Outcome of [`cozo_crdt_merge`].
+#[derive(Debug, Clone, Default)]
+pub struct CrdtMergeReport {
+    /// Rows inserted or changed by the merge.
+    pub upserted: u64,
+    /// Rows removed by the merge.
+    pub deleted: u64,
+}
+
+/// Create a stored relation whose rows merge without conflicts between
+/// installs, for offline-first apps.
+///
+/// - `columns`: as for `cozo_create_relation`; names must not start with
+///   `cozo_crdt_`
+///
+/// Every value column is a last-writer-wins register: the relation gets a
+/// hidden `cozo_crdt_<column>` version column per value column, plus
+/// `cozo_crdt_row`, which triggers set to the time of each write. Removed
+/// rows leave a tombstone with the time of removal. Merging keeps, per
+/// column, the value written last; a removal wins over puts made before it.
+/// Ties go to the greater value, so every install converges on the same rows.
+///
+/// Write rows with `:put` or `:rm` naming only the app's columns: the
+/// version columns then take their defaults, which is how the triggers tell
+/// writes of the app from those of a merge. `:update` keeps the old
+/// versions and so is not tracked. Queries binding columns by name do not
+/// see the version columns.
+pub fn cozo_create_crdt_relation(
+    db: &CozoDb,
+    name: String,
+    columns: Vec<ColumnDef>,
+) -> anyhow::Result<()> {
+    if let Some(column) = columns.iter().find(|c| c.name.starts_with(PREFIX)) {
+        anyhow::bail!(
+            "Create relation failed: column {} uses the reserved prefix {}",
+            column.name,
+            PREFIX
+        );
+    }
+    ensure_tombstones(db)?;
+    let version = |name: String| ColumnDef {
+        name,
+        column_type: "Float".to_string(),
+        nullable: false,
+        is_key: false,
+        default: Some(ColumnDefault::Value(CozoValue::Float(0.0))),
+    };
+    let mut defs = columns.clone();
+    for column in columns.iter().filter(|c| !c.is_key) {
+        defs.push(version(format!("{}{}", PREFIX, column.name)));
+    }
+    defs.push(version(ROW_VERSION.to_string()));
+    cozo_create_relation(db, name.clone(), defs)?;
+
+    let stored = relation_columns(&db.instance()?, &name)?;
+    let triggers = TriggerSpec {
+        on_put: vec![version_trigger(&name, &stored)],
+        on_rm: vec![tombstone_trigger(&name, &stored)],
+        on_replace: vec![],
+    };
+    cozo_set_triggers(db, name, triggers)
+}
+
+/// Export the rows of CRDT relations written since `since`, with their
+/// versions, and their tombstones, for [`cozo_crdt_merge`] on another
+/// install.
+///
+/// - `relations`: relations created with [`cozo_create_crdt_relation`]
+/// - `since`: Unix time in seconds; 0 exports the full state
+///
+/// Merging is idempotent, so a changeset can be applied any number of times
+/// and in any order. Rows merged from other installs keep the times they
+/// were written there, so a changeset since a given time only carries them
+/// if they were written after it; send the full state to bring a new
+/// install up to date.
+///
+/// Returns a JSON changeset.
+pub fn cozo_crdt_changes(
+    db: &CozoDb,
+    relations: Vec<String>,
+    since: f64,
+) -> anyhow::Result<String> {
+    let inner = db.instance()?;
+    let params = BTreeMap::from([("since".to_string(), DataValue::from(since))]);
+    let mut changeset = json!({"format": CHANGESET_FORMAT, "relations": {}});
+    for relation in relations {
+        let columns = crdt_columns(&inner, &relation)?;
+        let names = column_names(&columns);
+        let rows = query(
+            &inner,
+            &format!(
+                "?[{names}] := *{rel}{{{names}}}, {row} > $since",
+                names = names,
+                rel = relation,
+                row = ROW_VERSION,
+            ),
+            params.clone(),
+        )?;
+        let keys: Vec<&ColumnInfo> = columns.iter().filter(|c| c.is_key).collect();
+        let unpack = keys
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!(", {} = get(cozo_crdt_key, {})", c.name, i))
+            .collect::<String>();
+        let tombstones = query(
+            &inner,
+            &format!(
+                "?[{keys}, {version}] := *{log}{{relation: {name}, key: cozo_crdt_key, version: {version}}}, {version} > $since{unpack}",
+                keys = column_names(keys),
+                version = TOMBSTONE_VERSION,
+                log = TOMBSTONES,
+                name = string_literal(&relation),
+                unpack = unpack,
+            ),
+            params.clone(),
+        )?;
+        changeset["relations"][relation] = json!({
+            "rows": rows_json(rows),
+            "tombstones": rows_json(tombstones),
+        });
+    }
+    Ok(changeset.to_string())
+}
+
+/// Merge a changeset from [`cozo_crdt_changes`] of another install into
+/// the CRDT relations it holds.
+///
+/// Each value column keeps whichever of the local and remote values was
+/// written last, and rows removed on either side after their last put are
+/// removed. Merged rows keep their versions and do not run triggers, and
+/// all relations are merged in one transaction.
+///
+/// Fails without writing anything if a relation of the changeset is not a
+/// CRDT relation here.
+pub fn cozo_crdt_merge(db: &CozoDb, changeset: String) -> anyhow::Result<CrdtMergeReport> {
+    let error = |e: &dyn std::fmt::Display| anyhow::anyhow!("CRDT merge failed: {}", e);
+    let inner = db.writable()?;
+    let changeset: Value = serde_json::from_str(&changeset).map_err(|e| error(&e))?;
+    if changeset["format"].as_u64() != Some(CHANGESET_FORMAT) {
+        return Err(error(&"unsupported changeset format"));
+    }
+    ensure_tombstones(db)?;
+
+    let mut report = CrdtMergeReport::default();
+    let mut writes = BTreeMap::new();
+    let mut tombstones = vec![];
+    let empty = serde_json::Map::new();
+    for (relation, change) in changeset["relations"].as_object().unwrap_or(&empty) {
+        let columns = crdt_columns(&inner, relation)?;
+        let keys = columns.iter().filter(|c| c.is_key).count();
+        let row_version = columns.len() - 1;
+        // Each value column with the position of its version column.
+        let registers: Vec<(usize, usize)> = columns
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_key && !c.name.starts_with(PREFIX))
+            .filter_map(|(i, c)| {
+                let version = format!("{}{}", PREFIX, c.name);
+                let v = columns.iter().position(|c| c.name == version)?;
+                Some((i, v))
+            })
+            .collect();
+
+        let local = scan(&inner, relation, &columns)?.rows;
+        let removed = local_tombstones(&inner, relation)?;
+        let removed_at = |key: &[DataValue]| {
+            removed
+                .binary_search_by(|(k, _)| k.as_slice().cmp(key))
+                .ok()
+                .map(|i| removed[i].1.clone())
+        };
+        let find = |rows: &[Vec<DataValue>], key: &[DataValue]| {
+            rows.binary_search_by(|row| row[..keys].cmp(key)).ok()
+        };
+
+        let remote = NamedRows::from_json(&change["rows"]).map_err(|e| error(&e))?;
+        let mut puts = vec![];
+        for row in coerce_rows(relation, &columns, remote)?.rows {
+            let key = &row[..keys];
+            match find(&local, key) {
+                Some(i) => {
+                    let merged = merge_row(&local[i], &row, &registers, row_version);
+                    if merged != local[i] {
+                        puts.push(merged);
+                    }
+                }
+                None if removed_at(key).is_some_and(|at| at >= row[row_version]) => {}
+                None => puts.push(row),
+            }
+        }
+
+        let mut deletes = vec![];
+        let remote = NamedRows::from_json(&change["tombstones"]).map_err(|e| error(&e))?;
+        let mut expected = columns[..keys].to_vec();
+        expected.push(ColumnInfo {
+            name: TOMBSTONE_VERSION.to_string(),
+            column_type: "Float".to_string(),
+            nullable: false,
+            is_key: false,
+            index: keys as u32,
+            has_default: false,
+        });
+        for mut tombstone in coerce_rows(relation, &expected, remote)?.rows {
+            let at = tombstone.pop().unwrap_or(DataValue::Null);
+            let key = tombstone;
+            // The row as it stands after merging the remote rows.
+            let merged = find(&puts, &key);
+            let current = merged
+                .map(|i| &puts[i])
+                .or_else(|| find(&local, &key).map(|i| &local[i]));
+            if current.is_some_and(|row| at >= row[row_version]) {
+                if let Some(i) = merged {
+                    puts.remove(i);
+                }
+                if find(&local, &key).is_some() {
+                    deletes.push(key.clone());
+                }
+            }
+            if removed_at(&key).is_none_or(|local_at| at > local_at) {
+                tombstones.push(vec![
+                    DataValue::from(relation.as_str()),
+                    DataValue::List(key),
+                    at,
+                ]);
+            }
+        }
+
+        report.upserted += puts.len() as u64;
+        report.deleted += deletes.len() as u64;
+        let headers: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+        if !deletes.is_empty() {
+            let headers = headers[..keys].to_vec();
+            writes.insert(format!("-{}", relation), named_rows(headers, deletes));
+        }
+        if !puts.is_empty() {
+            writes.insert(relation.clone(), named_rows(headers, puts));
+        }
+    }
+    if !tombstones.is_empty() {
+        let headers = ["relation", "key", "version"].map(String::from).to_vec();
+        writes.insert(TOMBSTONES.to_string(), named_rows(headers, tombstones));
+    }
+    if !writes.is_empty() {
+        catch_panic(|| inner.import_relations(writes).map_err(|e| error(&e)))?;
+    }
+    Ok(report)
+}
+
+/// `local` with every register whose remote value was written later, or at
+/// the same time with a greater value, taken from `remote`.
+fn merge_row(
+    local: &[DataValue],
+    remote: &[DataValue],
+    registers: &[(usize, usize)],
+    row_version: usize,
+) -> Vec<DataValue> {
+    let mut merged = local.to_vec();
+    for &(value, version) in registers {
+        if (&remote[version], &remote[value]) > (&local[version], &local[value]) {
+            merged[value] = remote[value].clone();
+            merged[version] = remote[version].clone();
+        }
+    }
+    merged[row_version] = local[row_version].clone().max(remote[row_version].clone());
+    merged
+}
+
+/// The put trigger stamping rows written by the app, recognised by their
+/// defaulted row version: a value column gets the current time as its
+/// version if its value changed, the row version always does.
+fn version_trigger(relation: &str, columns: &[ColumnInfo]) -> String {
+    let is_version = |c: &&ColumnInfo| c.name.starts_with(PREFIX);
+    let values: Vec<&ColumnInfo> = columns
+        .iter()
+        .filter(|c| !c.is_key)
+        .filter(|c| !is_version(c))
+        .collect();
+    let head = column_names(columns);
+    let new_pattern = columns
+        .iter()
+        .map(|c| match (is_version(&c), c.name == ROW_VERSION) {
+            (_, true) => "cozo_crdt_new_row".to_string(),
+            (true, false) => "_".to_string(),
+            (false, false) => c.name.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let old_pattern = columns
+        .iter()
+        .map(|c| match (c.is_key, c.name == ROW_VERSION) {
+            (true, _) => c.name.clone(),
+            (false, true) => "_".to_string(),
+            (false, false) => format!("cozo_crdt_old_{}", c.name),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let missing_pattern = columns
+        .iter()
+        .map(|c| match c.is_key {
+            true => c.name.clone(),
+            false => "_".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let changed = values
+        .iter()
+        .map(|c| {
+            format!(
+                ", {p}{n} = if({n} == cozo_crdt_old_{n}, cozo_crdt_old_{p}{n}, cozo_crdt_now)",
+                p = PREFIX,
+                n = c.name
+            )
+        })
+        .collect::<String>();
+    let fresh = values
+        .iter()
+        .map(|c| format!(", {}{} = cozo_crdt_now", PREFIX, c.name))
+        .collect::<String>();
+    format!(
+        "?[{head}] := _new[{new}], cozo_crdt_new_row == 0.0, _old[{old}], cozo_crdt_now = now(){changed}, {row} = cozo_crdt_now\n?[{head}] := _new[{new}], cozo_crdt_new_row == 0.0, not _old[{missing}], cozo_crdt_now = now(){fresh}, {row} = cozo_crdt_now\n:put {rel} {{{spec}}}",
+        head = head,
+        new = new_pattern,
+        old = old_pattern,
+        missing = missing_pattern,
+        changed = changed,
+        fresh = fresh,
+        row = ROW_VERSION,
+        rel = relation,
+        spec = column_spec(columns),
+    )
+}
+
+/// The rm trigger recording a tombstone for every removed row.
+fn tombstone_trigger(relation: &str, columns: &[ColumnInfo]) -> String {
+    let (pattern, key) = key_pattern(columns);
+    format!(
+        "?[relation, key, version] := _old[{pattern}], relation = {name}, key = [{key}], version = now()\n:put {log} {{relation, key => version}}",
+        pattern = pattern,
+        name = string_literal(relation),
+        key = key,
+        log = TOMBSTONES,
+    )
+}
+
+/// Columns of a relation created by [`cozo_create_crdt_relation`].
+fn crdt_columns(inner: &DbInstance, relation: &str) -> anyhow::Result<Vec<ColumnInfo>> {
+    let columns = relation_columns(inner, relation)?;
+    if columns.last().map(|c| c.name.as_str()) != Some(ROW_VERSION) {
+        anyhow::bail!("{} is not a CRDT relation", relation);
+    }
+    Ok(columns)
+}
+
+/// Removal time of each tombstoned row of `relation`, in key order.
+fn local_tombstones(
+    inner: &DbInstance,
+    relation: &str,
+) -> anyhow::Result<Vec<(Vec<DataValue>, DataValue)>> {
+    let rows = query(
+        inner,
+        &format!(
+            "?[key, version] := *{}{{relation: $relation, key, version}}",
+            TOMBSTONES
+        ),
+        BTreeMap::from([("relation".to_string(), DataValue::from(relation))]),
+    )?;
+    let mut removed: Vec<_> = rows
+        .rows
+        .into_iter()
+        .map(|mut row| {
+            let version = row.pop().unwrap_or(DataValue::Null);
+            match row.pop() {
+                Some(DataValue::List(key)) => (key, version),
+                _ => (vec![], version),
+            }
+        })
+        .collect();
+    removed.sort();
+    Ok(removed)
+}
+
+fn ensure_tombstones(db: &CozoDb) -> anyhow::Result<()> {
+    if cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == TOMBSTONES)
+    {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            ":create {} {{relation: String, key: Any => version: Float}}",
+            TOMBSTONES
+        ),
+    )
+}
+
+fn query(
+    inner: &DbInstance,
+    script: &str,
+    params: BTreeMap<String, DataValue>,
+) -> anyhow::Result<NamedRows> {
+    catch_panic(|| inner.run_script(script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, script))
+}
+
+/// `{"headers", "rows"}` of `rows`.
+fn rows_json(rows: NamedRows) -> Value {
+    let mut value = rows.into_json();
+    if let Some(object) = value.as_object_mut() {
+        object.remove("next");
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install() -> CozoDb {
+        let db =
+            crate::api::simple::cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let column = |name: &str, column_type: &str, is_key| ColumnDef {
+            name: name.into(),
+            column_type: column_type.into(),
+            nullable: false,
+            is_key,
+            default: None,
+        };
+        let columns = vec![
+            column("id", "Int", true),
+            column("title", "String", false),
+            column("done", "Bool", false),
+        ];
+        cozo_create_crdt_relation(&db, "todos".into(), columns).unwrap();
+        db
+    }
+
+    fn write(db: &CozoDb, script: &str) {
+        run_write_op(db, script).unwrap();
+        // Later writes get later versions.
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+
+    fn todos(db: &CozoDb) -> Vec<Vec<DataValue>> {
+        let script = "?[id, title, done] := *todos{id, title, done}";
+        query(&db.instance().unwrap(), script, BTreeMap::new())
+            .unwrap()
+            .rows
+    }
+
+    fn sync(from: &CozoDb, to: &CozoDb) -> CrdtMergeReport {
+        let changes = cozo_crdt_changes(from, vec!["todos".into()], 0.0).unwrap();
+        cozo_crdt_merge(to, changes).unwrap()
+    }
+
+    #[test]
+    fn installs_converge_on_the_last_writes() {
+        let (a, b) = (install(), install());
+        write(&a, "?[id, title, done] <- [[1, 'milk', false], [2, 'eggs', false]] :put todos {id, title, done}");
+        let report = sync(&a, &b);
+        assert_eq!((report.upserted, report.deleted), (2, 0));
+
+        write(
+            &b,
+            "?[id, title, done] <- [[1, 'oat milk', false]] :put todos {id, title, done}",
+        );
+        write(
+            &a,
+            "?[id, title, done] <- [[2, 'eggs', true]] :put todos {id, title, done}",
+        );
+        write(&a, "?[id] <- [[1]] :rm todos {id}");
+        write(
+            &b,
+            "?[id, title, done] <- [[3, 'bread', false]] :put todos {id, title, done}",
+        );
+        sync(&a, &b);
+        sync(&b, &a);
+
+        let expected = vec![
+            vec![
+                DataValue::from(2),
+                DataValue::from("eggs"),
+                DataValue::Bool(true),
+            ],
+            vec![
+                DataValue::from(3),
+                DataValue::from("bread"),
+                DataValue::Bool(false),
+            ],
+        ];
+        assert_eq!(todos(&a), expected);
+        assert_eq!(todos(&b), expected);
+
+        // Merging again changes nothing.
+        let report = sync(&a, &b);
+        assert_eq!((report.upserted, report.deleted), (0, 0));
+        assert_eq!(todos(&b), expected);
+    }
+
+    #[test]
+    fn changes_since_a_time_carry_only_later_writes() {
+        let a = install();
+        write(
+            &a,
+            "?[id, title, done] <- [[1, 'milk', false]] :put todos {id, title, done}",
+        );
+        let since = crate::api::running::epoch_secs();
+        write(
+            &a,
+            "?[id, title, done] <- [[2, 'eggs', false]] :put todos {id, title, done}",
+        );
+        let changes = cozo_crdt_changes(&a, vec!["todos".into()], since).unwrap();
+        let changes: Value = serde_json::from_str(&changes).unwrap();
+        assert_eq!(
+            changes["relations"]["todos"]["rows"]["rows"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn changesets_must_match_the_relations() {
+        let a = install();
+        assert!(cozo_crdt_merge(&a, json!({"format": 99}).to_string()).is_err());
+        run_write_op(&a, ":create plain {id: Int => title: String}").unwrap();
+        assert!(cozo_crdt_changes(&a, vec!["plain".into()], 0.0).is_err());
+        let changes = json!({
+            "format": CHANGESET_FORMAT,
+            "relations": {"plain": {"rows": {"headers": [], "rows": []}, "tombstones": {"headers": [], "rows": []}}},
+        });
+        assert!(cozo_crdt_merge(&a, changes.to_string()).is_err());
+        let column = ColumnDef {
+            name: "cozo_crdt_x".into(),
+            column_type: "Int".into(),
+            nullable: false,
+            is_key: true,
+            default: None,
+        };
+        assert!(cozo_create_crdt_relation(&a, "bad".into(), vec![column]).is_err());
+    }
+}
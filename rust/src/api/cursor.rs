@@ -0,0 +1,156 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use cozo::DataValue;
+use flutter_rust_bridge::frb;
+
+use crate::api::row_policy::{policy_generation, RowPolicies};
+use crate::api::simple::{parse_params, CozoDb};
+use crate::api::value::CozoValue;
+
+/// The result of a query kept on the Rust side and read a page at a time,
+/// e.g. for an infinite-scroll list.
+///
+/// The query runs once, when the cursor is opened, so pages come from one
+/// consistent result however the database changes in between. Its rows stay
+/// in memory until [`cozo_cursor_close`] is called or the cursor is dropped.
+/// Once the row policies or their parameters change, reading more pages
+/// fails, since the rows were read under the old ones.
+#[frb(opaque)]
+pub struct CozoCursor {
+    headers: Vec<String>,
+    row_policies: Arc<Mutex<RowPolicies>>,
+    /// [`policy_generation`] when the query ran.
+    policy_generation: u64,
+    page_size: usize,
+    /// `None` once closed.
+    state: Mutex<Option<CursorState>>,
+    total: u64,
+}
+
+struct CursorState {
+    /// Rows not read yet.
+    rows: std::vec::IntoIter<Vec<DataValue>>,
+    /// Index of the next row in the result.
+    offset: u64,
+}
+
+/// A page of rows read from a [`CozoCursor`].
+#[derive(Debug, Clone)]
+pub struct CursorPage {
+    pub headers: Vec<String>,
+    /// At most the cursor's page size of rows, one value per header.
+    pub rows: Vec<Vec<CozoValue>>,
+    /// Index of the first row of the page in the result.
+    pub offset: u64,
+    /// Whether the page holds the last rows of the result.
+    pub last: bool,
+}
+
+/// Run a read-only query and open a cursor on its result, to read with
+/// [`cozo_cursor_next`] instead of running the query again with a growing
+/// `:offset`.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+/// - `page_size`: maximum number of rows per page
+pub fn cozo_open_cursor(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    page_size: u32,
+) -> anyhow::Result<CozoCursor> {
+    if page_size == 0 {
+        anyhow::bail!("page_size must be greater than zero");
+    }
+    let params = parse_params(&params_json)?;
+    let generation = policy_generation(&db.row_policies);
+    let rows = db.run_rows(&db.instance()?, &script, params, db.mutability(true))?;
+    Ok(CozoCursor {
+        headers: rows.headers,
+        row_policies: db.row_policies.clone(),
+        policy_generation: generation,
+        page_size: page_size as usize,
+        total: rows.rows.len() as u64,
+        state: Mutex::new(Some(CursorState {
+            rows: rows.rows.into_iter(),
+            offset: 0,
+        })),
+    })
+}
+
+/// Read the next page of a cursor. Once the result is exhausted, pages are
+/// empty and `last`.
+pub fn cozo_cursor_next(cursor: &CozoCursor) -> anyhow::Result<CursorPage> {
+    let mut state = cursor.state.lock().unwrap_or_else(PoisonError::into_inner);
+    let state = state
+        .as_mut()
+        .ok_or_else(|| anyhow::anyhow!("Cursor is closed"))?;
+    if policy_generation(&cursor.row_policies) != cursor.policy_generation {
+        anyhow::bail!("Cursor is stale: the row policies changed since it was opened");
+    }
+    let page: Vec<Vec<CozoValue>> = state
+        .rows
+        .by_ref()
+        .take(cursor.page_size)
+        .map(|row| row.into_iter().map(CozoValue::from).collect())
+        .collect();
+    let offset = state.offset;
+    state.offset += page.len() as u64;
+    Ok(CursorPage {
+        headers: cursor.headers.clone(),
+        rows: page,
+        offset,
+        last: state.offset == cursor.total,
+    })
+}
+
+/// Number of rows in the result of a cursor.
+#[frb(sync)]
+pub fn cozo_cursor_len(cursor: &CozoCursor) -> u64 {
+    cursor.total
+}
+
+/// Free the rows of a cursor. Reading it afterwards fails.
+#[frb(sync)]
+pub fn cozo_cursor_close(cursor: &CozoCursor) {
+    cursor
+        .state
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    #[test]
+    fn pages_come_from_the_result_as_it_was_opened() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create t {x: Int}").unwrap();
+        run_write_op(&db, "?[x] <- [[1], [2], [3]] :put t {x}").unwrap();
+        let cursor = cozo_open_cursor(&db, "?[x] := *t{x}".into(), "{}".into(), 2).unwrap();
+        run_write_op(&db, "?[x] <- [[4]] :put t {x}").unwrap();
+        assert_eq!(cozo_cursor_len(&cursor), 3);
+
+        let page = |cursor: &CozoCursor| {
+            let page = cozo_cursor_next(cursor).unwrap();
+            (page.offset, page.rows, page.last)
+        };
+        let x = |x| vec![CozoValue::Int(x)];
+        assert_eq!(page(&cursor), (0, vec![x(1), x(2)], false));
+        assert_eq!(page(&cursor), (2, vec![x(3)], true));
+        assert_eq!(page(&cursor), (3, vec![], true));
+        cozo_cursor_close(&cursor);
+        assert!(cozo_cursor_next(&cursor).is_err());
+    }
+
+    #[test]
+    fn page_size_must_be_positive() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        assert!(cozo_open_cursor(&db, "?[x] <- [[1]]".into(), "{}".into(), 0).is_err());
+        let cursor = cozo_open_cursor(&db, "?[x] <- []".into(), "{}".into(), 5).unwrap();
+        assert!(cozo_cursor_next(&cursor).unwrap().last);
+    }
+}
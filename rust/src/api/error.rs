@@ -0,0 +1,260 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// What went wrong, for branching on errors in Dart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The script does not parse (`parser::*` codes).
+    Parse,
+    /// The script parsed but failed while running.
+    Eval,
+    /// The storage engine, a backup or a database file failed.
+    Storage,
+    /// A write conflicted with the schema, an index or access levels
+    /// (`tx::*` codes).
+    Transact,
+    /// Imported data was malformed, or an import or export failed.
+    ImportExport,
+    /// The handle was closed with `cozo_close_db`.
+    Closed,
+    /// Another process has the database open for writing; see
+    /// `CozoDartError::lock_holder`.
+    Locked,
+    /// The query ran past its timeout.
+    Timeout,
+    /// The query was killed, by `cozo_kill_query` or the heap limit.
+    Cancelled,
+    /// cozo or the binding panicked. The message holds the panic payload.
+    Internal,
+}
+
+impl ErrorKind {
+    /// Classify a failed script by cozo's diagnostic code.
+    pub(crate) fn from_code(code: Option<&str>) -> Self {
+        match code {
+            Some("eval::killed") => ErrorKind::Cancelled,
+            Some("transact::assertion_failure") => ErrorKind::Transact,
+            Some(c) if c.starts_with("parser::") => ErrorKind::Parse,
+            Some(c) if c.starts_with("tx::") => ErrorKind::Transact,
+            Some(c) if c.starts_with("import::") || c.starts_with("deser::") => {
+                ErrorKind::ImportExport
+            }
+            Some(c) if c.starts_with("db::") => ErrorKind::Storage,
+            _ => ErrorKind::Eval,
+        }
+    }
+}
+
+/// Byte offsets into the script of the part an error points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorSpan {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// The process holding a database another process failed to open.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LockHolder {
+    pub pid: u32,
+    /// Unix time in seconds it opened the database.
+    pub since: f64,
+}
+
+/// A failed call, with enough structure for Dart code to branch on.
+///
+/// Its message is the same text the call's error has always carried, e.g.
+/// `Query failed [parser::pest]: ...`, so code matching on messages keeps
+/// working.
+#[derive(Debug, Clone)]
+pub struct CozoDartError {
+    pub kind: ErrorKind,
+    pub message: String,
+    /// cozo's diagnostic code, e.g. `eval::killed`, when there is one.
+    pub code: Option<String>,
+    /// Where in the script the error was found, for script errors.
+    pub span: Option<ErrorSpan>,
+    /// The stored relation the error is about, when cozo names one.
+    pub relation: Option<String>,
+    /// For [`ErrorKind::Locked`], the holder of the lock when it is known.
+    pub lock_holder: Option<LockHolder>,
+}
+
+impl CozoDartError {
+    pub(crate) fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        CozoDartError {
+            kind,
+            message: message.into(),
+            code: None,
+            span: None,
+            relation: None,
+            lock_holder: None,
+        }
+    }
+
+    /// Classify a failed script from cozo's rendered diagnostic.
+    pub(crate) fn from_query(err: cozo::Error, script: &str) -> Self {
+        if let Some(err) = err.downcast_ref::<CozoDartError>() {
+            return err.clone();
+        }
+        let json = cozo::format_error_as_json(err, Some(script));
+        let code = json.get("code").and_then(|c| c.as_str()).map(String::from);
+        let display = json
+            .get("display")
+            .and_then(|d| d.as_str())
+            .unwrap_or("Unknown query error");
+        let message = match &code {
+            Some(code) => format!("Query failed [{}]: {}", code, display),
+            None => format!("Query failed: {}", display),
+        };
+        let span = json
+            .get("labels")
+            .and_then(|labels| labels.get(0))
+            .and_then(|label| label.get("span"))
+            .and_then(|span| {
+                let start = span.get("offset")?.as_u64()? as u32;
+                let len = span.get("length")?.as_u64()? as u32;
+                Some(ErrorSpan {
+                    start,
+                    end: start + len,
+                })
+            });
+        let kind = ErrorKind::from_code(code.as_deref());
+        // Relation errors quote the relation name: "... stored relation 'users'".
+        let relation = code
+            .as_deref()
+            .filter(|c| c.contains("relation") || *c == "tx::insufficient_access_level")
+            .and_then(|_| json.get("message").and_then(|m| m.as_str()))
+            .and_then(|m| m.split('\'').nth(1))
+            .map(String::from);
+        CozoDartError {
+            kind,
+            message,
+            code,
+            span,
+            relation,
+            lock_holder: None,
+        }
+    }
+}
+
+/// An error of `kind` for a call failing outside a script, such as a backup
+/// or a commit.
+pub(crate) fn failure(kind: ErrorKind, message: impl Into<String>) -> anyhow::Error {
+    CozoDartError::new(kind, message).into()
+}
+
+/// `err` with `context` in front of its message, keeping its kind and
+/// details if it is a [`CozoDartError`] and taking `kind` otherwise.
+pub(crate) fn with_context(err: anyhow::Error, kind: ErrorKind, context: &str) -> anyhow::Error {
+    match err.downcast::<CozoDartError>() {
+        Ok(mut err) => {
+            err.message = format!("{}: {}", context, err.message);
+            err.into()
+        }
+        Err(err) => failure(kind, format!("{}: {}", context, err)),
+    }
+}
+
+/// Run `f`, turning a panic into an [`ErrorKind::Internal`] error so the
+/// caller gets an ordinary error instead of a bridge-level panic. Locks in
+/// this crate recover from poisoning, so the handle stays usable afterwards.
+///
+/// Every call into cozo goes through this, either around the call itself,
+/// returning cozo's error, or around the binding code making it.
+pub(crate) fn catch_panic<T, E: From<CozoDartError>>(
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(E::from(CozoDartError::new(
+            ErrorKind::Internal,
+            format!("Internal error: {}", message),
+        )))
+    })
+}
+
+/// Carries the error of a panic caught by [`catch_panic`] through calls
+/// returning cozo's own error; [`CozoDartError::from_query`] recovers it.
+impl From<CozoDartError> for cozo::Error {
+    fn from(err: CozoDartError) -> Self {
+        cozo::Error::msg(err)
+    }
+}
+
+impl fmt::Display for CozoDartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CozoDartError {}
+
+impl From<anyhow::Error> for CozoDartError {
+    /// Recover the structured error. Errors built without one, such as
+    /// argument checks, are reported as [`ErrorKind::Eval`].
+    fn from(err: anyhow::Error) -> Self {
+        err.downcast::<CozoDartError>()
+            .unwrap_or_else(|err| CozoDartError::new(ErrorKind::Eval, err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_in_cozo_calls_become_internal_errors() {
+        let caught: Result<(), cozo::Error> = catch_panic(|| panic!("boom"));
+        let err = CozoDartError::from_query(caught.unwrap_err(), "?[] <- []");
+        assert_eq!(err.kind, ErrorKind::Internal);
+        assert_eq!(err.message, "Internal error: boom");
+
+        let caught: anyhow::Result<()> = catch_panic(|| panic!("{}", "bang"));
+        assert_eq!(
+            CozoDartError::from(caught.unwrap_err()).kind,
+            ErrorKind::Internal
+        );
+    }
+
+    #[test]
+    fn errors_carry_the_kind_of_the_step_that_failed() {
+        use crate::api::batch::{cozo_run_batch, ScriptWithParams};
+        use crate::api::export::cozo_query_to_file;
+        use crate::api::simple::{cozo_open_db, cozo_restore};
+        use crate::api::transaction::{cozo_begin_tx, tx_commit};
+
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let kind = |err: anyhow::Error| CozoDartError::from(err).kind;
+        assert_eq!(
+            kind(cozo_restore(&db, "/nonexistent/backup.db".into()).unwrap_err()),
+            ErrorKind::Storage
+        );
+        let export = cozo_query_to_file(
+            &db,
+            "?[x] <- [[1]]".into(),
+            "{}".into(),
+            "x".into(),
+            "xml".into(),
+        );
+        assert_eq!(kind(export.unwrap_err()), ErrorKind::ImportExport);
+
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        tx_commit(&tx).unwrap();
+        assert_eq!(kind(tx_commit(&tx).unwrap_err()), ErrorKind::Transact);
+
+        // A failed script keeps its own kind inside the batch error.
+        let scripts = vec![ScriptWithParams {
+            script: "?[x] <- [[1]".into(),
+            params: Default::default(),
+        }];
+        let err = CozoDartError::from(cozo_run_batch(&db, scripts, true).unwrap_err());
+        assert_eq!(err.kind, ErrorKind::Parse);
+        assert!(err
+            .message
+            .starts_with("Batch failed at script 0: Query failed"));
+    }
+}
@@ -0,0 +1,207 @@
+use std::time::Instant;
+
+use cozo::{DataValue, NamedRows, ScriptMutability};
+
+use crate::api::error::{catch_panic, CozoDartError};
+use crate::api::simple::CozoDb;
+
+/// Text put before a script to explain it.
+const EXPLAIN_PREFIX: &str = "::explain {\n";
+
+/// Query plan of a script, as reported by `::explain`.
+#[derive(Debug, Clone)]
+pub struct QueryPlan {
+    /// Rules in the order cozo lists them, grouped by stratum.
+    pub rules: Vec<PlanRule>,
+    /// Seconds spent parsing and planning the script, which is not run.
+    pub took: f64,
+}
+
+/// One rule of a [`QueryPlan`]; `?` is the entry rule.
+#[derive(Debug, Clone)]
+pub struct PlanRule {
+    /// Strata are evaluated in increasing order.
+    pub stratum: u32,
+    /// Position of the rule in the plan.
+    pub rule_idx: u32,
+    /// Rule name, with cozo's suffix for its binding pattern, e.g. `r|Mff`.
+    pub name: String,
+    /// Steps in evaluation order, ending with the `out` step.
+    pub steps: Vec<PlanStep>,
+}
+
+/// One step of a [`PlanRule`].
+#[derive(Debug, Clone)]
+pub struct PlanStep {
+    pub atom_idx: u32,
+    /// Operation, e.g. "load_stored", "stored_prefix_join", "unify" or "out".
+    pub op: String,
+    /// Relation or rule the step reads (stored relations start with `:`),
+    /// or the variable a `unify` step binds.
+    pub reference: Option<String>,
+    /// Join keys as (left, right) variable pairs.
+    pub joins_on: Vec<(String, String)>,
+    /// Filters applied by the step.
+    pub filters: Vec<String>,
+    /// Expression of a `unify` step.
+    pub expr: Option<String>,
+    /// Variables the step outputs.
+    pub output: Vec<String>,
+}
+
+/// Explain how cozo would evaluate a script, without running it.
+///
+/// - `script`: a single query; imperative scripts and system ops cannot be
+///   explained
+///
+/// Returns the plan as a tree of rules and their steps.
+pub fn cozo_explain(db: &CozoDb, script: String) -> anyhow::Result<QueryPlan> {
+    let start = Instant::now();
+    let rows = run_explain(db, &script)?;
+    let took = start.elapsed().as_secs_f64();
+
+    let mut rules: Vec<PlanRule> = vec![];
+    for row in rows.rows {
+        let [stratum, rule_idx, rule, atom_idx, op, reference, joins_on, filters, output] =
+            <[DataValue; 9]>::try_from(row)
+                .map_err(|_| anyhow::anyhow!("Unexpected ::explain output"))?;
+        let stratum = stratum.get_int().unwrap_or_default() as u32;
+        let rule_idx = rule_idx.get_int().unwrap_or_default() as u32;
+        let (filters, expr) = match filters {
+            DataValue::List(items) => (strings(items), None),
+            DataValue::Str(s) => (vec![], Some(s.to_string())),
+            _ => (vec![], None),
+        };
+        let joins_on = match serde_json::Value::from(joins_on) {
+            serde_json::Value::Object(map) => map
+                .into_iter()
+                .map(|(left, right)| (left, right.as_str().unwrap_or_default().to_string()))
+                .collect(),
+            _ => vec![],
+        };
+        let step = PlanStep {
+            atom_idx: atom_idx.get_int().unwrap_or_default() as u32,
+            op: op.get_str().unwrap_or_default().to_string(),
+            reference: reference.get_str().map(String::from),
+            joins_on,
+            filters,
+            expr,
+            output: match output {
+                DataValue::List(items) => strings(items),
+                _ => vec![],
+            },
+        };
+        match rules.last_mut() {
+            Some(last) if last.stratum == stratum && last.rule_idx == rule_idx => {
+                last.steps.push(step)
+            }
+            _ => rules.push(PlanRule {
+                stratum,
+                rule_idx,
+                name: rule.get_str().unwrap_or_default().to_string(),
+                steps: vec![step],
+            }),
+        }
+    }
+    Ok(QueryPlan { rules, took })
+}
+
+/// Compile `script` with `::explain`, without running it. Error spans point
+/// into `script` itself.
+pub(crate) fn run_explain(db: &CozoDb, script: &str) -> anyhow::Result<NamedRows> {
+    explain_raw(db, script)?.map_err(|err| {
+        let mut err = CozoDartError::from_query(err, &explain_source(script));
+        if let Some(span) = err.span.as_mut() {
+            span.start = script_offset(span.start as usize, script) as u32;
+            span.end = script_offset(span.end as usize, script) as u32;
+        }
+        err.into()
+    })
+}
+
+/// Compile `script` with `::explain`, keeping cozo's own error. Its spans
+/// point into [`explain_source`]; map them back with [`script_offset`].
+pub(crate) fn explain_raw(
+    db: &CozoDb,
+    script: &str,
+) -> anyhow::Result<Result<NamedRows, cozo::Error>> {
+    let inner = db.instance()?;
+    Ok(catch_panic(|| {
+        inner.run_script(
+            &explain_source(script),
+            Default::default(),
+            ScriptMutability::Immutable,
+        )
+    }))
+}
+
+/// The script actually compiled for `script`.
+pub(crate) fn explain_source(script: &str) -> String {
+    format!("{}{}\n}}", EXPLAIN_PREFIX, script)
+}
+
+/// Map a byte offset into [`explain_source`] back to one into `script`.
+pub(crate) fn script_offset(offset: usize, script: &str) -> usize {
+    offset
+        .saturating_sub(EXPLAIN_PREFIX.len())
+        .min(script.len())
+}
+
+fn strings(items: Vec<DataValue>) -> Vec<String> {
+    items
+        .into_iter()
+        .map(|item| match item {
+            DataValue::Str(s) => s.to_string(),
+            other => serde_json::Value::from(other).to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    #[test]
+    fn plans_list_rules_and_their_steps() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create users {id: Int => name: String}").unwrap();
+        let script = "friend[id] <- [[1]]\n?[name] := friend[id], *users{id, name}, id > 0";
+        let plan = cozo_explain(&db, script.into()).unwrap();
+
+        let entry = plan.rules.iter().find(|r| r.name == "?").unwrap();
+        assert_eq!(entry.steps.last().unwrap().op, "out");
+        let stored = entry
+            .steps
+            .iter()
+            .find(|s| s.reference.as_deref() == Some(":users"))
+            .unwrap();
+        assert!(stored.output.contains(&"name".to_string()));
+        assert!(plan.rules.iter().any(|r| r.name.starts_with("friend")));
+        // Explaining does not run the script.
+        let put = "?[id, name] <- [[1, 'a']] :put users {id => name}";
+        cozo_explain(&db, put.into()).unwrap();
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(
+                "?[id] := *users{id}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap();
+        assert!(rows.rows.is_empty());
+    }
+
+    #[test]
+    fn errors_point_into_the_script() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let script = "?[x] := x = 1,, y";
+        let err = run_explain(&db, script).unwrap_err();
+        let err = err.downcast_ref::<CozoDartError>().unwrap();
+        let span = err.span.as_ref().unwrap();
+        assert!(span.end as usize <= script.len());
+        assert_eq!(&script[span.start as usize..], ", y");
+    }
+}
@@ -0,0 +1,567 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability};
+use serde_json::json;
+
+use crate::api::error::{catch_panic, failure, ErrorKind};
+use crate::api::row_policy::policy_rows;
+use crate::api::schema::{column_names, relation_columns};
+use crate::api::simple::{parse_params, CozoDb};
+
+/// Variable [`cozo_export_filtered`] binds a single key column to.
+const KEY_VAR: &str = "export_key__";
+
+/// Rows per Arrow record batch, in Parquet files and IPC buffers.
+#[cfg(feature = "arrow")]
+const ARROW_BATCH_ROWS: usize = 8192;
+
+/// Export relations to a file, writing one relation at a time so the whole
+/// export is never held in memory as a single string.
+///
+/// - `relations`: names of the stored relations to export
+/// - `path`: destination file, created or truncated
+/// - `format`: only "ndjson" is supported
+///
+/// The NDJSON output has, for each relation, a header line
+/// `{"relation": name, "headers": [..]}` followed by one JSON array per row.
+/// Relations with a row policy only export the rows it allows.
+///
+/// Returns the number of rows written.
+pub fn cozo_export_relations_to_file(
+    db: &CozoDb,
+    relations: Vec<String>,
+    path: String,
+    format: String,
+) -> anyhow::Result<u64> {
+    if format != "ndjson" {
+        return Err(failure(
+            ErrorKind::ImportExport,
+            format!(
+                "Export failed: unsupported format {:?}, expected \"ndjson\"",
+                format
+            ),
+        ));
+    }
+    let inner = db.instance()?;
+    let file = File::create(&path)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    let mut out = BufWriter::new(file);
+    let mut written = 0;
+    for relation in &relations {
+        let rows = export_relation(db, &inner, relation)?;
+        write_line(
+            &mut out,
+            &json!({"relation": relation, "headers": rows.headers}),
+        )?;
+        for row in rows.rows {
+            let row: Vec<serde_json::Value> =
+                row.into_iter().map(serde_json::Value::from).collect();
+            write_line(&mut out, &json!(row))?;
+            written += 1;
+        }
+    }
+    out.flush()
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    Ok(written)
+}
+
+/// The rows of a stored relation as exported, keeping to its row policy.
+/// Encrypted columns stay encrypted, so the rows can be imported again.
+pub(crate) fn export_relation(
+    db: &CozoDb,
+    inner: &DbInstance,
+    relation: &str,
+) -> anyhow::Result<NamedRows> {
+    if let Some(rows) = policy_rows(&db.row_policies, inner, relation)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?
+    {
+        return Ok(rows);
+    }
+    catch_panic(|| inner.export_relations(std::iter::once(relation)))
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?
+        .into_values()
+        .next()
+        .ok_or_else(|| {
+            failure(
+                ErrorKind::ImportExport,
+                format!("Export failed: no relation {}", relation),
+            )
+        })
+}
+
+/// Export the rows of a stored relation that match a filter, e.g. a single
+/// user's data for a GDPR export.
+///
+/// - `relation`: name of the stored relation to export
+/// - `filter_script`: conditions on the relation's columns, in CozoScript,
+///   e.g. `user_id == $uid` or `created_at > $since, status != 'draft'`
+/// - `params_json`: JSON object of named parameters, e.g. '{"uid": 42}'
+///
+/// Every column is bound by name, so the filter can use any of them. It
+/// runs as the body of a read-only query,
+/// `?[cols] := *relation{cols}, <filter_script>`.
+///
+/// Returns a JSON string in the same format as `cozo_export_relations`,
+/// `{"ok": true, "data": {relation: {"headers", "rows"}}}`, holding only the
+/// matching rows. Its `data` can be passed to `cozo_import_relations`.
+pub fn cozo_export_filtered(
+    db: &CozoDb,
+    relation: String,
+    filter_script: String,
+    params_json: String,
+) -> anyhow::Result<String> {
+    let params = parse_params(&params_json)?;
+    let inner = db.instance()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let names = column_names(&columns);
+    let keys: Vec<_> = columns.iter().filter(|c| c.is_key).collect();
+    let (bindings, unify) = match keys[..] {
+        // cozo 0.7 turns `k >= n` on the only key column into a range scan
+        // that skips `n` itself; binding the key through another variable
+        // keeps the filter out of the scan.
+        [key] => {
+            let mut bindings = vec![format!("{}: {}", key.name, KEY_VAR)];
+            bindings.extend(columns.iter().filter(|c| !c.is_key).map(|c| c.name.clone()));
+            (bindings.join(", "), format!(", {} = {}", key.name, KEY_VAR))
+        }
+        _ => (names.clone(), String::new()),
+    };
+    let script = format!(
+        "?[{names}] := *{relation}{{{bindings}}}{unify}, {filter}",
+        names = names,
+        relation = relation,
+        bindings = bindings,
+        unify = unify,
+        filter = filter_script,
+    );
+    let rows = db.run_rows(&inner, &script, params, ScriptMutability::Immutable)?;
+    Ok(json!({"ok": true, "data": { relation: rows.into_json() }}).to_string())
+}
+
+/// Run a read-only query and save its rows to a file.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+/// - `path`: destination file, created or truncated
+/// - `format`: "csv", or "parquet" (requires the `parquet` crate feature)
+///
+/// CSV output has a header row; nulls are empty fields, strings and numbers
+/// are written as-is and other values as JSON. Parquet columns are typed from
+/// their values as in [`cozo_run_query_arrow`]: all-integer columns are
+/// `Int64`, numeric ones `Float64`,
+/// then `Boolean` and `Binary`; anything else is a JSON or plain `Utf8` string.
+///
+/// Returns the number of rows written.
+pub fn cozo_query_to_file(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    path: String,
+    format: String,
+) -> anyhow::Result<u64> {
+    let params = parse_params(&params_json)?;
+    let rows = db.run_rows(
+        &db.instance()?,
+        &script,
+        params,
+        ScriptMutability::Immutable,
+    )?;
+    match format.as_str() {
+        "csv" => write_csv(rows, &path),
+        #[cfg(feature = "parquet")]
+        "parquet" => write_parquet(rows, &path),
+        #[cfg(not(feature = "parquet"))]
+        "parquet" => Err(failure(
+            ErrorKind::ImportExport,
+            "Export failed: parquet output requires building cozo_dart with the `parquet` feature",
+        )),
+        _ => Err(failure(
+            ErrorKind::ImportExport,
+            format!(
+                "Export failed: unsupported format {:?}, expected \"csv\" or \"parquet\"",
+                format
+            ),
+        )),
+    }
+}
+
+/// Run a read-only query and return its rows as an Arrow IPC stream, for
+/// analytics screens that would otherwise decode 100k+ rows of JSON.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Requires the `arrow` crate feature. Columns are typed from their values:
+/// all-integer columns are `Int64`, numeric ones `Float64`, then `Boolean`
+/// and `Binary`; anything else is a JSON or plain `Utf8` string. Every
+/// column is nullable and rows come in batches of 8192.
+///
+/// Returns the bytes of an IPC stream (schema message, then one message per
+/// batch), readable by arrow-dart and other Arrow implementations.
+pub fn cozo_run_query_arrow(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+) -> anyhow::Result<Vec<u8>> {
+    let params = parse_params(&params_json)?;
+    let rows = db.run_rows(
+        &db.instance()?,
+        &script,
+        params,
+        ScriptMutability::Immutable,
+    )?;
+    encode_arrow_ipc(&rows)
+}
+
+#[cfg(feature = "arrow")]
+fn encode_arrow_ipc(rows: &NamedRows) -> anyhow::Result<Vec<u8>> {
+    use arrow_ipc::writer::StreamWriter;
+
+    let (schema, batches) =
+        record_batches(rows).map_err(|e| anyhow::anyhow!("Arrow encoding failed: {}", e))?;
+    let mut writer = StreamWriter::try_new(Vec::new(), &schema)
+        .map_err(|e| anyhow::anyhow!("Arrow encoding failed: {}", e))?;
+    for batch in &batches {
+        writer
+            .write(batch)
+            .map_err(|e| anyhow::anyhow!("Arrow encoding failed: {}", e))?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| anyhow::anyhow!("Arrow encoding failed: {}", e))
+}
+
+#[cfg(not(feature = "arrow"))]
+fn encode_arrow_ipc(_rows: &NamedRows) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("Arrow output requires building cozo_dart with the `arrow` feature")
+}
+
+fn write_csv(rows: NamedRows, path: &str) -> anyhow::Result<u64> {
+    let mut writer = csv::Writer::from_path(path)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    writer
+        .write_record(&rows.headers)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    let mut written = 0;
+    for row in rows.rows {
+        writer
+            .write_record(row.into_iter().map(csv_field))
+            .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+        written += 1;
+    }
+    writer
+        .flush()
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    Ok(written)
+}
+
+fn csv_field(value: DataValue) -> String {
+    match serde_json::Value::from(value) {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn write_parquet(rows: NamedRows, path: &str) -> anyhow::Result<u64> {
+    use parquet::arrow::ArrowWriter;
+
+    let (schema, batches) = record_batches(&rows)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    let file = File::create(path)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    let mut writer = ArrowWriter::try_new(file, schema, None)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    for batch in &batches {
+        writer
+            .write(batch)
+            .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    }
+    writer
+        .close()
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    Ok(rows.rows.len() as u64)
+}
+
+/// Split rows into Arrow record batches, with one typed, nullable field per column.
+#[cfg(feature = "arrow")]
+fn record_batches(
+    rows: &NamedRows,
+) -> Result<(arrow_schema::SchemaRef, Vec<arrow_array::RecordBatch>), arrow_schema::ArrowError> {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::{Field, Schema};
+
+    let types: Vec<_> = (0..rows.headers.len())
+        .map(|i| arrow_type(rows.rows.iter().map(|row| &row[i])))
+        .collect();
+    let fields: Vec<_> = rows
+        .headers
+        .iter()
+        .zip(&types)
+        .map(|(name, ty)| Field::new(name, ty.clone(), true))
+        .collect();
+    let schema = Arc::new(Schema::new(fields));
+    let batches = rows
+        .rows
+        .chunks(ARROW_BATCH_ROWS)
+        .map(|chunk| {
+            let columns = types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| arrow_column(chunk, i, ty))
+                .collect();
+            RecordBatch::try_new(schema.clone(), columns)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((schema, batches))
+}
+
+/// The narrowest Arrow type that holds every non-null value of a column.
+#[cfg(feature = "arrow")]
+fn arrow_type<'a>(values: impl Iterator<Item = &'a DataValue>) -> arrow_schema::DataType {
+    use arrow_schema::DataType;
+
+    let mut current = None;
+    for value in values {
+        let ty = match value {
+            DataValue::Null => continue,
+            DataValue::Bool(_) => DataType::Boolean,
+            DataValue::Num(cozo::Num::Int(_)) => DataType::Int64,
+            DataValue::Num(cozo::Num::Float(_)) => DataType::Float64,
+            DataValue::Bytes(_) => DataType::Binary,
+            _ => return DataType::Utf8,
+        };
+        current = Some(match (current, ty) {
+            (None, ty) => ty,
+            (Some(a), b) if a == b => a,
+            (Some(DataType::Int64), DataType::Float64)
+            | (Some(DataType::Float64), DataType::Int64) => DataType::Float64,
+            _ => return DataType::Utf8,
+        });
+    }
+    current.unwrap_or(DataType::Utf8)
+}
+
+#[cfg(feature = "arrow")]
+fn arrow_column(
+    rows: &[Vec<DataValue>],
+    i: usize,
+    ty: &arrow_schema::DataType,
+) -> arrow_array::ArrayRef {
+    use std::sync::Arc;
+
+    use arrow_array::{BinaryArray, BooleanArray, Float64Array, Int64Array, StringArray};
+    use arrow_schema::DataType;
+
+    let values = rows.iter().map(|row| &row[i]);
+    match ty {
+        DataType::Boolean => Arc::new(values.map(DataValue::get_bool).collect::<BooleanArray>()),
+        DataType::Int64 => Arc::new(values.map(DataValue::get_int).collect::<Int64Array>()),
+        DataType::Float64 => Arc::new(values.map(DataValue::get_float).collect::<Float64Array>()),
+        DataType::Binary => Arc::new(values.map(DataValue::get_bytes).collect::<BinaryArray>()),
+        _ => Arc::new(
+            values
+                .map(|value| match value {
+                    DataValue::Null => None,
+                    DataValue::Str(s) => Some(s.to_string()),
+                    other => Some(serde_json::Value::from(other.clone()).to_string()),
+                })
+                .collect::<StringArray>(),
+        ),
+    }
+}
+
+fn write_line(out: &mut impl Write, value: &serde_json::Value) -> anyhow::Result<()> {
+    serde_json::to_writer(&mut *out, value)
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))?;
+    out.write_all(b"\n")
+        .map_err(|e| failure(ErrorKind::ImportExport, format!("Export failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::backup::TempFile;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    fn with_items() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(
+            &db,
+            ":create items {id: Int => name: String, note: String?}",
+        )
+        .unwrap();
+        run_write_op(
+            &db,
+            "?[id, name, note] <- [[1, 'a', null], [2, 'b, \"c\"', 'x']] \
+             :put items {id => name, note}",
+        )
+        .unwrap();
+        db
+    }
+
+    #[test]
+    fn ndjson_exports_write_a_header_line_per_relation() {
+        let db = with_items();
+        run_write_op(&db, ":create tags {tag: String}").unwrap();
+        let file = TempFile::private().unwrap();
+        let relations = vec!["items".to_string(), "tags".to_string()];
+        let written =
+            cozo_export_relations_to_file(&db, relations, file.path().into(), "ndjson".into())
+                .unwrap();
+        assert_eq!(written, 2);
+        let text = std::fs::read_to_string(file.path()).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                json!({"relation": "items", "headers": ["id", "name", "note"]}),
+                json!([1, "a", null]),
+                json!([2, "b, \"c\"", "x"]),
+                json!({"relation": "tags", "headers": ["tag"]}),
+            ]
+        );
+
+        let export = |relations: Vec<String>, format: &str| {
+            cozo_export_relations_to_file(&db, relations, file.path().into(), format.into())
+        };
+        assert!(export(vec!["items".into()], "json").is_err());
+        assert!(export(vec!["missing".into()], "ndjson").is_err());
+    }
+
+    #[test]
+    fn query_results_are_saved_as_csv() {
+        let db = with_items();
+        let file = TempFile::private().unwrap();
+        let script = "?[id, name, note, tags] := *items{id, name, note}, tags = [id]";
+        let written = cozo_query_to_file(
+            &db,
+            script.into(),
+            "{}".into(),
+            file.path().into(),
+            "csv".into(),
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(
+            std::fs::read_to_string(file.path()).unwrap(),
+            "id,name,note,tags\n1,a,,[1]\n2,\"b, \"\"c\"\"\",x,[2]\n"
+        );
+
+        let to_file = |format: &str| {
+            cozo_query_to_file(
+                &db,
+                script.into(),
+                "{}".into(),
+                file.path().into(),
+                format.into(),
+            )
+        };
+        assert!(to_file("xlsx").is_err());
+        #[cfg(not(feature = "parquet"))]
+        assert!(to_file("parquet").is_err());
+        let put = "?[id, name] <- [[3, 'c']] :put items {id => name}";
+        assert!(cozo_query_to_file(
+            &db,
+            put.into(),
+            "{}".into(),
+            file.path().into(),
+            "csv".into()
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn query_results_are_saved_as_parquet() {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let db = with_items();
+        let file = TempFile::private().unwrap();
+        let script = "?[id, name] := *items{id, name}";
+        let written = cozo_query_to_file(
+            &db,
+            script.into(),
+            "{}".into(),
+            file.path().into(),
+            "parquet".into(),
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+        let reader = SerializedFileReader::new(File::open(file.path()).unwrap()).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 2);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn arrow_columns_are_typed_from_their_values() {
+        use arrow_ipc::reader::StreamReader;
+        use arrow_schema::DataType;
+
+        let db = with_items();
+        let script =
+            "?[id, name, note, score] := *items{id, name, note}, score = if(id == 1, 1, 2.5)";
+        let data = cozo_run_query_arrow(&db, script.into(), "{}".into()).unwrap();
+        let reader = StreamReader::try_new(data.as_slice(), None).unwrap();
+        let types: Vec<_> = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                DataType::Int64,
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Float64
+            ]
+        );
+        let batches: Vec<_> = reader.map(Result::unwrap).collect();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_rows(), 2);
+        assert_eq!(batches[0].column(2).null_count(), 1);
+    }
+
+    #[cfg(not(feature = "arrow"))]
+    #[test]
+    fn arrow_output_needs_the_feature() {
+        let db = with_items();
+        let err = cozo_run_query_arrow(&db, "?[x] <- [[1]]".into(), "{}".into()).unwrap_err();
+        assert!(err.to_string().contains("`arrow` feature"));
+    }
+
+    #[test]
+    fn filtered_exports_keep_only_matching_rows() {
+        let db = with_items();
+        let params = r#"{"min": 2}"#;
+        let export =
+            cozo_export_filtered(&db, "items".into(), "id >= $min".into(), params.into()).unwrap();
+        let export: serde_json::Value = serde_json::from_str(&export).unwrap();
+        assert_eq!(export["ok"], json!(true));
+        assert_eq!(
+            export["data"]["items"]["rows"],
+            json!([[2, "b, \"c\"", "x"]])
+        );
+
+        // Row policies still apply with the key bound through another
+        // variable.
+        crate::api::row_policy::cozo_set_row_policy(&db, "items".into(), "id != 2".into()).unwrap();
+        let export =
+            cozo_export_filtered(&db, "items".into(), "id >= 1".into(), "{}".into()).unwrap();
+        let export: serde_json::Value = serde_json::from_str(&export).unwrap();
+        assert_eq!(export["data"]["items"]["rows"], json!([[1, "a", null]]));
+
+        let filter = "id > 0 :put items {id => name, note}";
+        assert!(cozo_export_filtered(&db, "items".into(), filter.into(), "{}".into()).is_err());
+        let filter = "missing == 1";
+        assert!(cozo_export_filtered(&db, "items".into(), filter.into(), "{}".into()).is_err());
+    }
+}
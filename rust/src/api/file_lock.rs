@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use flutter_rust_bridge::frb;
+use serde_json::json;
+
+use crate::api::error::{failure, CozoDartError, ErrorKind, LockHolder};
+use crate::api::running::epoch_secs;
+
+/// A process's claim on a database file, held by every handle to it and
+/// given back by `cozo_close_db`.
+pub(crate) struct FileLock {
+    key: PathBuf,
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let mut held = held().lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(lock) = held.get_mut(&self.key) {
+            lock.refs -= 1;
+            if lock.refs == 0 {
+                // Closing the file releases the OS lock.
+                held.remove(&self.key);
+            }
+        }
+    }
+}
+
+/// A lock file this process holds, shared by its opens of the database.
+struct HeldLock {
+    _file: File,
+    refs: u32,
+}
+
+fn held() -> &'static Mutex<HashMap<PathBuf, HeldLock>> {
+    static HELD: OnceLock<Mutex<HashMap<PathBuf, HeldLock>>> = OnceLock::new();
+    HELD.get_or_init(Default::default)
+}
+
+/// The lock file of the database at `path`: `<path>.lock`.
+fn lock_path(path: &str) -> PathBuf {
+    PathBuf::from(format!("{}.lock", path))
+}
+
+/// Claim the database at `path` for writing by this process, or fail with an
+/// [`ErrorKind::Locked`] error naming the process holding it.
+///
+/// Opens in the same process share the claim. The lock file records the
+/// holder's pid and since when it holds it; the OS lock on it (`flock` or
+/// `LockFileEx`) goes away with the process, so a crash leaves no stale
+/// claim where file locks work. On file systems without them, a claim whose
+/// process is gone is taken over (on Linux and Android, where that can be
+/// checked), and [`cozo_force_unlock`] clears one otherwise.
+pub(crate) fn lock_database(path: &str) -> anyhow::Result<FileLock> {
+    let lock_path = lock_path(path);
+    let key = std::path::absolute(&lock_path).unwrap_or_else(|_| lock_path.clone());
+    let mut held = held().lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(lock) = held.get_mut(&key) {
+        lock.refs += 1;
+        return Ok(FileLock { key });
+    }
+    let error = |e: std::io::Error| {
+        failure(
+            ErrorKind::Storage,
+            format!("Failed to open database: locking {}: {}", path, e),
+        )
+    };
+    if let Some(parent) = lock_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(error)?;
+    }
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&lock_path)
+        .map_err(error)?;
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => return Err(locked_error(path, read_holder(&mut file))),
+        Err(TryLockError::Error(e)) if e.kind() == std::io::ErrorKind::Unsupported => {
+            // No OS locks here: go by the pid recorded.
+            if let Some(holder) = read_holder(&mut file) {
+                if holder.pid != std::process::id() && process_alive(holder.pid) {
+                    return Err(locked_error(path, Some(holder)));
+                }
+            }
+        }
+        Err(TryLockError::Error(e)) => return Err(error(e)),
+    }
+    let record = json!({"pid": std::process::id(), "since": epoch_secs()}).to_string();
+    file.set_len(0)
+        .and_then(|_| file.seek(SeekFrom::Start(0)))
+        .and_then(|_| file.write_all(record.as_bytes()))
+        .and_then(|_| file.flush())
+        .map_err(error)?;
+    held.insert(
+        key.clone(),
+        HeldLock {
+            _file: file,
+            refs: 1,
+        },
+    );
+    Ok(FileLock { key })
+}
+
+/// Delete the lock file of the database at `path`, e.g. after a crash on a
+/// file system without file locks left a claim `cozo_open_db` keeps
+/// reporting as `Locked`.
+///
+/// Only call it when the process named in the error is really gone: a
+/// process still writing the database keeps doing so, and two writers can
+/// corrupt it. Fails if this process holds the lock; close its handles
+/// instead.
+///
+/// Returns false if there was no lock file.
+#[frb(sync)]
+pub fn cozo_force_unlock(path: String) -> anyhow::Result<bool> {
+    let lock_path = lock_path(&path);
+    let key = std::path::absolute(&lock_path).unwrap_or_else(|_| lock_path.clone());
+    if held()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(&key)
+    {
+        anyhow::bail!("Unlock failed: {} is open in this process", path);
+    }
+    if !lock_path.exists() {
+        return Ok(false);
+    }
+    std::fs::remove_file(&lock_path).map_err(|e| anyhow::anyhow!("Unlock failed: {}", e))?;
+    Ok(true)
+}
+
+/// The holder recorded in a lock file. Unreadable on Windows while another
+/// process holds the lock.
+fn read_holder(file: &mut File) -> Option<LockHolder> {
+    let mut content = String::new();
+    file.seek(SeekFrom::Start(0)).ok()?;
+    file.read_to_string(&mut content).ok()?;
+    let record: serde_json::Value = serde_json::from_str(&content).ok()?;
+    Some(LockHolder {
+        pid: record.get("pid")?.as_u64()? as u32,
+        since: record.get("since")?.as_f64()?,
+    })
+}
+
+fn locked_error(path: &str, holder: Option<LockHolder>) -> anyhow::Error {
+    let message = match &holder {
+        Some(holder) => format!(
+            "Database {} is locked by process {} (since {:.0}); close it there first",
+            path, holder.pid, holder.since
+        ),
+        None => format!(
+            "Database {} is locked by another process; close it there first",
+            path
+        ),
+    };
+    let mut error = CozoDartError::new(ErrorKind::Locked, message);
+    error.lock_holder = holder;
+    error.into()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn process_alive(pid: u32) -> bool {
+    std::path::Path::new("/proc").join(pid.to_string()).exists()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn process_alive(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::backup::TempFile;
+
+    #[test]
+    fn opens_in_this_process_share_the_lock() {
+        let db = TempFile::private().unwrap();
+        let first = lock_database(db.path()).unwrap();
+        let second = lock_database(db.path()).unwrap();
+        let mut file = File::open(lock_path(db.path())).unwrap();
+        assert_eq!(read_holder(&mut file).unwrap().pid, std::process::id());
+
+        drop(first);
+        assert!(cozo_force_unlock(db.path().to_string()).is_err());
+        drop(second);
+        assert!(cozo_force_unlock(db.path().to_string()).unwrap());
+        assert!(!cozo_force_unlock(db.path().to_string()).unwrap());
+    }
+
+    #[test]
+    fn a_lock_held_elsewhere_names_its_holder() {
+        let db = TempFile::private().unwrap();
+        let lock_path = lock_path(db.path());
+        std::fs::write(&lock_path, r#"{"pid": 4242, "since": 1.5}"#).unwrap();
+        // Another open of the file stands in for another process.
+        let other = File::open(&lock_path).unwrap();
+        other.try_lock().unwrap();
+
+        let err = lock_database(db.path()).err().unwrap();
+        let err = err.downcast_ref::<CozoDartError>().unwrap();
+        assert_eq!(err.kind, ErrorKind::Locked);
+        assert_eq!(err.lock_holder.as_ref().map(|h| h.pid), Some(4242));
+        assert!(err.message.contains("4242"), "{}", err.message);
+
+        drop(other);
+        drop(lock_database(db.path()).unwrap());
+        std::fs::remove_file(lock_path).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+
+use cozo::{DataValue, NamedRows, SimpleFixedRule};
+use flutter_rust_bridge::DartFnFuture;
+
+use crate::api::error::catch_panic;
+use crate::api::simple::CozoDb;
+
+/// Register a Dart function as a custom fixed rule usable from CozoScript.
+///
+/// - `name`: rule name, used as `?[..] <~ Name(input[], option: ..)`
+/// - `arity`: number of columns in every row the rule returns
+/// - `rule`: called with a JSON array of the input relations
+///   (`[{"headers", "rows"}, ..]`) and a JSON object of the rule options;
+///   must complete with a JSON object `{"headers", "rows"}` for the output
+///
+/// The calling query blocks while the Dart function runs, so queries using
+/// the rule must not be issued from a `#[frb(sync)]` call.
+pub fn cozo_register_fixed_rule(
+    db: &CozoDb,
+    name: String,
+    arity: usize,
+    rule: impl Fn(String, String) -> DartFnFuture<String> + Send + Sync + 'static,
+) -> anyhow::Result<()> {
+    let rule_name = name.clone();
+    let fixed_rule = SimpleFixedRule::new(arity, move |inputs, options| {
+        let output =
+            futures::executor::block_on(rule(inputs_to_json(inputs), options_to_json(options)));
+        let value: serde_json::Value = serde_json::from_str(&output).map_err(|e| {
+            cozo::Error::msg(format!(
+                "Fixed rule {} returned invalid JSON: {}",
+                rule_name, e
+            ))
+        })?;
+        let rows = NamedRows::from_json(&value)?;
+        if let Some(row) = rows.rows.iter().find(|row| row.len() != arity) {
+            return Err(cozo::Error::msg(format!(
+                "Fixed rule {} returned a row of length {}, expected {}",
+                rule_name,
+                row.len(),
+                arity
+            )));
+        }
+        Ok(rows)
+    });
+    let inner = db.instance()?;
+    catch_panic(|| inner.register_fixed_rule(name, fixed_rule))
+        .map_err(|e| anyhow::anyhow!("Failed to register fixed rule: {}", e))
+}
+
+/// Remove a fixed rule registered with [`cozo_register_fixed_rule`].
+///
+/// Returns whether a rule with that name existed.
+pub fn cozo_unregister_fixed_rule(db: &CozoDb, name: String) -> anyhow::Result<bool> {
+    db.instance()?
+        .unregister_fixed_rule(&name)
+        .map_err(|e| anyhow::anyhow!("Failed to unregister fixed rule: {}", e))
+}
+
+fn inputs_to_json(inputs: Vec<NamedRows>) -> String {
+    serde_json::Value::Array(inputs.into_iter().map(NamedRows::into_json).collect()).to_string()
+}
+
+fn options_to_json(options: BTreeMap<String, DataValue>) -> String {
+    let map: serde_json::Map<String, serde_json::Value> = options
+        .into_iter()
+        .map(|(k, v)| (k, serde_json::Value::from(v)))
+        .collect();
+    serde_json::Value::Object(map).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+
+    /// A rule returning `output`, after checking what it was called with.
+    /// Cozo passes input relations with positional headers.
+    fn rule(output: &'static str) -> impl Fn(String, String) -> DartFnFuture<String> {
+        move |inputs, options| {
+            assert_eq!(
+                inputs,
+                r#"[{"headers":["_0"],"next":null,"rows":[[1],[2]]}]"#
+            );
+            assert_eq!(options, r#"{"n":3}"#);
+            Box::pin(async move { output.to_string() })
+        }
+    }
+
+    fn run(db: &CozoDb, name: &str) -> anyhow::Result<String> {
+        let script = format!("r[x] <- [[1], [2]]\n?[a, b] <~ {}(r[], n: 3)", name);
+        cozo_run_query(db, script, "{}".into(), true)
+    }
+
+    #[test]
+    fn registered_rules_run_in_queries() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let output = r#"{"headers": ["a", "b"], "rows": [[1, "one"]]}"#;
+        cozo_register_fixed_rule(&db, "Echo".into(), 2, rule(output)).unwrap();
+        assert!(run(&db, "Echo").unwrap().contains(r#"[[1,"one"]]"#));
+
+        assert!(cozo_unregister_fixed_rule(&db, "Echo".into()).unwrap());
+        assert!(!cozo_unregister_fixed_rule(&db, "Echo".into()).unwrap());
+        assert!(run(&db, "Echo").is_err());
+    }
+
+    #[test]
+    fn bad_rule_output_fails_the_query() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_register_fixed_rule(&db, "Garbage".into(), 2, rule("not json")).unwrap();
+        let err = run(&db, "Garbage").unwrap_err().to_string();
+        assert!(
+            err.contains("Fixed rule Garbage returned invalid JSON"),
+            "{}",
+            err
+        );
+
+        let short = r#"{"headers": ["a"], "rows": [[1]]}"#;
+        cozo_register_fixed_rule(&db, "Short".into(), 2, rule(short)).unwrap();
+        let err = run(&db, "Short").unwrap_err().to_string();
+        assert!(
+            err.contains("returned a row of length 1, expected 2"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use flutter_rust_bridge::frb;
+
+/// Indentation of one nesting level.
+const INDENT: &str = "    ";
+
+/// Rules longer than this are broken after `:=` and each top-level comma.
+const MAX_WIDTH: usize = 80;
+
+/// Pretty-print a CozoScript script.
+///
+/// Only whitespace outside strings and comments is changed, so the result
+/// means the same as the input:
+///
+/// - blocks (`{ ... }` around a query or an imperative statement) and open
+///   brackets are indented by four spaces per level
+/// - rule bodies continued on the next line are indented one level deeper
+///   than their head
+/// - rules longer than 80 columns get one body atom per line
+/// - `:=`, `<-` and `<~` are surrounded by single spaces, commas are
+///   followed by one, and other runs of spaces are collapsed to one
+/// - trailing spaces are removed and blank lines are collapsed to one
+///
+/// Scripts that do not parse are formatted as far as they can be tokenized;
+/// nothing is validated.
+///
+/// Returns the formatted script, with a final newline if the input had one.
+#[frb(sync)]
+pub fn cozo_format_script(script: String) -> String {
+    let tokens = tokenize(&script);
+    let mut out = String::new();
+    // Open brackets, with whether each `{` is a block.
+    let mut open: Vec<(char, bool)> = vec![];
+    let mut prev: Option<Token> = None;
+    let mut prev_prev: Option<Token> = None;
+    let mut line_start = 0;
+    while line_start < tokens.len() {
+        let line_end = tokens[line_start + 1..]
+            .iter()
+            .position(|t| t.newlines_before > 0)
+            .map_or(tokens.len(), |i| line_start + 1 + i);
+        let line = &tokens[line_start..line_end];
+
+        let closers = line.iter().take_while(|t| is_closer(t.text)).count();
+        let depth = open.len().saturating_sub(closers);
+        let in_block = depth == 0 || open[depth - 1].1;
+        let continues = prev.is_some_and(|t| matches!(t.text, "," | ":=" | "<-" | "<~"))
+            || matches!(line[0].text, "," | "or" | "and");
+        let level = depth + usize::from(in_block && continues && closers == 0);
+
+        if !out.is_empty() {
+            out.push('\n');
+            if line[0].newlines_before > 1 {
+                out.push('\n');
+            }
+        }
+        let rendered = render(line);
+        if level * INDENT.len() + rendered.len() > MAX_WIDTH && in_block {
+            out.push_str(&break_rule(line, level).unwrap_or_else(|| indent(level, &rendered)));
+        } else {
+            out.push_str(&indent(level, &rendered));
+        }
+
+        for token in line {
+            if token.kind != Kind::Comment {
+                let c = token.text.chars().next().unwrap_or_default();
+                if matches!(c, '(' | '[') {
+                    open.push((c, false));
+                } else if c == '{' {
+                    let block = match prev {
+                        None => true,
+                        Some(p) => {
+                            matches!(p.text, "}" | "explain")
+                                || prev_prev.is_some_and(|pp| pp.text == "%")
+                        }
+                    };
+                    open.push((c, block));
+                } else if is_closer(token.text) {
+                    open.pop();
+                }
+                prev_prev = prev;
+                prev = Some(*token);
+            }
+        }
+        line_start = line_end;
+    }
+    if script.ends_with('\n') && !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Word,
+    Punct,
+    Str,
+    Comment,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    kind: Kind,
+    /// Whether whitespace separated this token from the previous one.
+    space_before: bool,
+    newlines_before: usize,
+}
+
+fn tokenize(script: &str) -> Vec<Token<'_>> {
+    let bytes = script.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    let mut space_before = false;
+    let mut newlines_before = 0;
+    while i < bytes.len() {
+        let rest = &script[i..];
+        let c = rest.chars().next().unwrap_or_default();
+        if c.is_whitespace() {
+            space_before = true;
+            newlines_before += usize::from(c == '\n');
+            i += c.len_utf8();
+            continue;
+        }
+        let (len, kind) = if c == '#' {
+            (rest.find('\n').unwrap_or(rest.len()), Kind::Comment)
+        } else if rest.starts_with("/*") {
+            (block_comment_len(rest), Kind::Comment)
+        } else if c == '\'' {
+            (quoted_len(rest), Kind::Str)
+        } else if let Some(len) = raw_string_len(rest) {
+            (len, Kind::Str)
+        } else if c.is_alphanumeric() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+                .unwrap_or(rest.len());
+            (len, Kind::Word)
+        } else if [":=", "<-", "<~"].iter().any(|op| rest.starts_with(op)) {
+            (2, Kind::Punct)
+        } else {
+            (c.len_utf8(), Kind::Punct)
+        };
+        tokens.push(Token {
+            text: &rest[..len],
+            kind,
+            space_before,
+            newlines_before,
+        });
+        i += len;
+        space_before = false;
+        newlines_before = 0;
+    }
+    tokens
+}
+
+/// Length of a string in `'` quotes, with backslash escapes.
+fn quoted_len(rest: &str) -> usize {
+    let mut escaped = false;
+    for (i, c) in rest.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '\'' {
+            return i + 1;
+        }
+    }
+    rest.len()
+}
+
+/// Length of a raw string such as `___"..."___`, if `rest` starts with one.
+/// cozo also reads plain `"..."` strings as raw strings, so they end at the
+/// first `"` and have no escapes.
+fn raw_string_len(rest: &str) -> Option<usize> {
+    let marks = rest.find(|c| c != '_')?;
+    if !rest[marks..].starts_with('"') {
+        return None;
+    }
+    let end = format!("\"{}", &rest[..marks]);
+    let body = marks + 1;
+    Some(
+        rest[body..]
+            .find(&end)
+            .map_or(rest.len(), |i| body + i + end.len()),
+    )
+}
+
+/// Length of a block comment; they nest.
+fn block_comment_len(rest: &str) -> usize {
+    let mut depth = 0;
+    let mut i = 0;
+    while i < rest.len() {
+        if rest[i..].starts_with("/*") {
+            depth += 1;
+            i += 2;
+        } else if rest[i..].starts_with("*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += rest[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    rest.len()
+}
+
+fn is_closer(text: &str) -> bool {
+    matches!(text, ")" | "]" | "}")
+}
+
+/// Join the tokens of one line with normalized spacing.
+fn render(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    let mut prev: Option<&Token> = None;
+    for token in tokens {
+        if let Some(p) = prev {
+            let space = if matches!(p.text, "," | ":=" | "<-" | "<~")
+                || matches!(token.text, ":=" | "<-" | "<~")
+            {
+                true
+            } else if matches!(p.text, "(" | "[") || matches!(token.text, ")" | "]" | ",") {
+                false
+            } else {
+                token.space_before
+            };
+            if space {
+                out.push(' ');
+            }
+        }
+        out.push_str(token.text);
+        prev = Some(token);
+    }
+    out
+}
+
+fn indent(level: usize, line: &str) -> String {
+    format!("{}{}", INDENT.repeat(level), line)
+}
+
+/// Put each top-level body atom of a long rule on its own line, or `None`
+/// if the line has no rule body to break.
+fn break_rule(tokens: &[Token], level: usize) -> Option<String> {
+    let mut depth = 0usize;
+    let mut cuts = vec![];
+    for (i, token) in tokens.iter().enumerate() {
+        match token.text {
+            "(" | "[" | "{" => depth += 1,
+            ")" | "]" | "}" => depth = depth.saturating_sub(1),
+            ":=" if depth == 0 && cuts.is_empty() => cuts.push(i + 1),
+            "," if depth == 0 && !cuts.is_empty() => cuts.push(i + 1),
+            _ => {}
+        }
+    }
+    // Nothing follows the last cut, e.g. the body already starts on the
+    // next line.
+    cuts.retain(|&cut| cut < tokens.len() && tokens[cut].kind != Kind::Comment);
+    if cuts.is_empty() {
+        return None;
+    }
+    let mut lines = vec![];
+    let mut start = 0;
+    for cut in cuts.into_iter().chain([tokens.len()]) {
+        let line_level = if start == 0 { level } else { level + 1 };
+        lines.push(indent(line_level, &render(&tokens[start..cut])));
+        start = cut;
+    }
+    Some(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(script: &str) -> String {
+        cozo_format_script(script.to_string())
+    }
+
+    #[test]
+    fn normalizes_spacing_outside_strings() {
+        assert_eq!(format("?[a,b]:=*t[a ,  b],a>1"), "?[a, b] := *t[a, b], a>1");
+        assert_eq!(format("?[a]  <-  [[1],[2]]\n"), "?[a] <- [[1], [2]]\n");
+    }
+
+    #[test]
+    fn keeps_single_quoted_strings() {
+        assert_eq!(format("?[x]:=x = 'a,b  :=c'"), "?[x] := x = 'a,b  :=c'");
+        // An escaped quote does not end the string.
+        assert_eq!(
+            format(r"?[x]:=x = 'it\'s ,  here'"),
+            r"?[x] := x = 'it\'s ,  here'"
+        );
+    }
+
+    #[test]
+    fn keeps_raw_and_double_quoted_strings() {
+        // Plain `"..."` strings have no escapes, so the backslash is kept and
+        // the string ends at the second quote.
+        assert_eq!(
+            format(r#"?[x]:=x = "a\",y = 'b'"#),
+            r#"?[x] := x = "a\", y = 'b'"#
+        );
+        assert_eq!(
+            format(r#"?[x]:=x = ___"say "hi",  ok"___"#),
+            r#"?[x] := x = ___"say "hi",  ok"___"#
+        );
+    }
+
+    #[test]
+    fn keeps_comments() {
+        assert_eq!(
+            format("?[a] := *t[a]  # a,b  :=  c\n"),
+            "?[a] := *t[a] # a,b  :=  c\n"
+        );
+        assert_eq!(
+            format("/* outer /* inner ,, */ still */ ?[a]:=*t[a]"),
+            "/* outer /* inner ,, */ still */ ?[a] := *t[a]"
+        );
+    }
+
+    #[test]
+    fn breaks_long_rules_outside_strings() {
+        let string = format!("'{}'", "word, ".repeat(20));
+        assert_eq!(
+            format(&format!("?[x] := x = {}", string)),
+            format!("?[x] :=\n    x = {}", string)
+        );
+    }
+
+    #[test]
+    fn formatting_twice_changes_nothing() {
+        let script = "{?[a,b]:=*t{a,b},a='x,  y',b>1\n:limit 10}\n\n\n{::relations}";
+        let once = format(script);
+        assert_eq!(format(&once), once);
+    }
+}
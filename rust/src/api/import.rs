@@ -0,0 +1,574 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cozo::{DataValue, DbInstance, JsonData, NamedRows, ScriptMutability, UuidWrapper};
+
+use crate::api::error::{catch_panic, failure, ErrorKind};
+use crate::api::incremental::scan;
+use crate::api::schema::{
+    column_names, column_spec, cozo_create_relation, relation_columns, ColumnDef, ColumnInfo,
+};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::value::CozoValue;
+
+/// How a CSV file is mapped onto a stored relation.
+#[derive(Debug, Clone)]
+pub struct CsvImportConfig {
+    /// Stored relation to insert into. It must already exist.
+    pub relation: String,
+    /// Relation column name to CSV header name. Columns not listed here are
+    /// read from the CSV column with the same name.
+    pub column_map: HashMap<String, String>,
+    /// Field delimiter, a single character such as "," or ";".
+    pub delimiter: String,
+    /// Number of rows inserted per transaction.
+    pub batch_size: u32,
+    /// Treat empty fields as null even for non-nullable columns, so a missing
+    /// value is rejected instead of being stored as "".
+    pub empty_as_null: bool,
+    /// Trim whitespace around every field before coercion.
+    pub trim: bool,
+}
+
+/// Import a CSV file with a header row into a stored relation.
+///
+/// Fields are coerced to the relation's column types: `Int`, `Float`, `Bool`
+/// ("true"/"false", "1"/"0", "yes"/"no"), `String`, `Uuid` and `Json` are
+/// parsed directly, and any other type (lists, vectors, `Any`) is parsed as
+/// JSON, falling back to a string. Empty fields in nullable columns become
+/// null. Columns with a default may be missing from the CSV.
+///
+/// Rows are inserted in batches of `batch_size`, each in its own transaction,
+/// so a failure leaves earlier batches in place.
+///
+/// Returns the number of rows imported.
+pub fn cozo_import_csv(db: &CozoDb, path: String, config: CsvImportConfig) -> anyhow::Result<u64> {
+    let delimiter = match config.delimiter.as_bytes() {
+        [b] => *b,
+        _ => anyhow::bail!("CSV import failed: delimiter must be a single ASCII character"),
+    };
+    if config.batch_size == 0 {
+        anyhow::bail!("CSV import failed: batch_size must be greater than zero");
+    }
+    let inner = db.writable()?;
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(if config.trim {
+            csv::Trim::All
+        } else {
+            csv::Trim::None
+        })
+        .from_path(&path)
+        .map_err(|e| anyhow::anyhow!("CSV import failed: {}", e))?;
+    let headers = reader
+        .headers()
+        .map_err(|e| anyhow::anyhow!("CSV import failed: {}", e))?
+        .clone();
+
+    // Pair every relation column that is present in the CSV with its field index.
+    let mut mapped = vec![];
+    for column in relation_columns(&inner, &config.relation)? {
+        let source = config.column_map.get(&column.name).unwrap_or(&column.name);
+        match headers.iter().position(|h| h == source) {
+            Some(index) => mapped.push((column, index)),
+            None if column.has_default => {}
+            None => anyhow::bail!(
+                "CSV import failed: no CSV column {:?} for relation column {:?}",
+                source,
+                column.name
+            ),
+        }
+    }
+    let script = put_script(&config.relation, &mapped);
+
+    let mut imported = 0;
+    let mut batch = vec![];
+    for record in reader.records() {
+        let record = record.map_err(|e| anyhow::anyhow!("CSV import failed: {}", e))?;
+        let line = record.position().map_or(0, |p| p.line());
+        let row = mapped
+            .iter()
+            .map(|(column, index)| {
+                let field = record.get(*index).unwrap_or("");
+                coerce(field, column, config.empty_as_null).map_err(|e| {
+                    anyhow::anyhow!(
+                        "CSV import failed at line {}, column {:?}: {}",
+                        line,
+                        column.name,
+                        e
+                    )
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        batch.push(DataValue::List(row));
+        if batch.len() == config.batch_size as usize {
+            imported += insert_batch(&inner, &script, std::mem::take(&mut batch))?;
+        }
+    }
+    if !batch.is_empty() {
+        imported += insert_batch(&inner, &script, batch)?;
+    }
+    Ok(imported)
+}
+
+/// `?[a, b, c] <- $rows :put rel {a, b => c}` for the mapped columns.
+fn put_script(relation: &str, mapped: &[(ColumnInfo, usize)]) -> String {
+    let columns = || mapped.iter().map(|(c, _)| c);
+    format!(
+        "?[{}] <- $rows :put {} {{{}}}",
+        column_names(columns()),
+        relation,
+        column_spec(columns())
+    )
+}
+
+fn insert_batch(inner: &DbInstance, script: &str, rows: Vec<DataValue>) -> anyhow::Result<u64> {
+    let count = rows.len() as u64;
+    let params = BTreeMap::from([("rows".to_string(), DataValue::List(rows))]);
+    catch_panic(|| inner.run_script(script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, script))?;
+    Ok(count)
+}
+
+/// How [`cozo_import_relations_with_options`] treats rows whose key already
+/// exists in the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStrategy {
+    /// Make each imported relation hold exactly the imported rows, deleting
+    /// local rows whose key is not in the import.
+    Replace,
+    /// Write every imported row, overwriting local rows with the same key
+    /// and keeping the others, as `cozo_import_relations` does.
+    Upsert,
+    /// Write only rows whose key does not exist locally, keeping local rows.
+    SkipExisting,
+    /// Fail without writing anything if any imported row has the key of a
+    /// local row with different values.
+    FailOnConflict,
+}
+
+/// Settings of [`cozo_import_relations_with_options`].
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    pub strategy: ImportStrategy,
+    /// Work out what the import would do without writing anything.
+    pub dry_run: bool,
+}
+
+/// Most conflicts listed in an [`ImportReport`].
+const CONFLICT_SAMPLE: usize = 100;
+
+/// What [`cozo_import_relations_with_options`] did, or with `dry_run` would
+/// do, summed over all imported relations.
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    /// Rows whose key did not exist locally.
+    pub inserted: u64,
+    /// Local rows overwritten with different values.
+    pub updated: u64,
+    /// Conflicting rows not written because the local row was kept.
+    pub skipped: u64,
+    /// Rows identical to the local row, which are not written.
+    pub unchanged: u64,
+    /// Local rows deleted by [`ImportStrategy::Replace`].
+    pub deleted: u64,
+    /// Number of imported rows whose key exists locally with different
+    /// values, whatever the strategy did with them.
+    pub conflict_count: u64,
+    /// The first conflicts, in relation and key order, at most 100.
+    pub conflicts: Vec<ImportConflict>,
+}
+
+/// An imported row whose key exists locally with different values.
+#[derive(Debug, Clone)]
+pub struct ImportConflict {
+    pub relation: String,
+    /// Values of the key columns.
+    pub key: Vec<CozoValue>,
+    /// Non-key values of the local row.
+    pub local: Vec<CozoValue>,
+    /// Non-key values of the imported row, converted to the column types.
+    pub incoming: Vec<CozoValue>,
+}
+
+/// Import relations into the database, choosing what happens to rows that
+/// already exist, e.g. to restore a partial export into a live database
+/// without overwriting newer local rows.
+///
+/// - `data_json`: JSON string in the same format as export output
+///
+/// Imported values are converted to the column types before they are
+/// compared with local rows, so a row re-imported from an export of the
+/// same data is unchanged and is not written again. All relations are
+/// written in one transaction, and like `cozo_import_relations` this does
+/// not run triggers. Indices cannot be imported into.
+///
+/// Run with `dry_run` first to show the conflicts for review before
+/// importing: a dry run with [`ImportStrategy::FailOnConflict`] returns the
+/// report instead of failing.
+///
+/// Returns what was written, deleted and left alone.
+pub fn cozo_import_relations_with_options(
+    db: &CozoDb,
+    data_json: String,
+    options: ImportOptions,
+) -> anyhow::Result<ImportReport> {
+    let error = |e: &dyn std::fmt::Display| {
+        failure(ErrorKind::ImportExport, format!("Import failed: {}", e))
+    };
+    let inner = db.writable()?;
+    let data: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&data_json).map_err(|e| error(&e))?;
+
+    let mut writes = BTreeMap::new();
+    let mut report = ImportReport::default();
+    // Keys named in the error of a failed `FailOnConflict` import.
+    let mut shown = vec![];
+    for (relation, rows) in data {
+        if relation.contains(':') {
+            return Err(error(&format!("cannot import into index {}", relation)));
+        }
+        let columns = relation_columns(&inner, &relation)?;
+        let rows = NamedRows::from_json(&rows).map_err(|e| error(&e))?;
+        let incoming = coerce_rows(&relation, &columns, rows)?;
+        let keys = columns.iter().filter(|c| c.is_key).count();
+        // Both sides are in key order, so they are merged in one pass.
+        let mut local = scan(&inner, &relation, &columns)?
+            .rows
+            .into_iter()
+            .peekable();
+        let mut puts = vec![];
+        let mut missing = vec![];
+        for row in incoming.rows {
+            while let Some(old) = local.next_if(|old| old[..keys] < row[..keys]) {
+                missing.push(old);
+            }
+            let Some(old) = local.next_if(|old| old[..keys] == row[..keys]) else {
+                report.inserted += 1;
+                puts.push(row);
+                continue;
+            };
+            if old[keys..] == row[keys..] {
+                report.unchanged += 1;
+                continue;
+            }
+            report.conflict_count += 1;
+            if shown.len() < 10 {
+                shown.push(format!(
+                    "{} {}",
+                    relation,
+                    DataValue::List(row[..keys].to_vec())
+                ));
+            }
+            if report.conflicts.len() < CONFLICT_SAMPLE {
+                let values = |row: &[DataValue]| row.iter().cloned().map(CozoValue::from).collect();
+                report.conflicts.push(ImportConflict {
+                    relation: relation.clone(),
+                    key: values(&row[..keys]),
+                    local: values(&old[keys..]),
+                    incoming: values(&row[keys..]),
+                });
+            }
+            match options.strategy {
+                ImportStrategy::Replace | ImportStrategy::Upsert => {
+                    report.updated += 1;
+                    puts.push(row);
+                }
+                ImportStrategy::SkipExisting => report.skipped += 1,
+                ImportStrategy::FailOnConflict => {}
+            }
+        }
+        missing.extend(local);
+        let deletes: Vec<Vec<DataValue>> = match options.strategy {
+            ImportStrategy::Replace => missing
+                .into_iter()
+                .map(|mut row| {
+                    row.truncate(keys);
+                    row
+                })
+                .collect(),
+            _ => vec![],
+        };
+        report.deleted += deletes.len() as u64;
+        let headers: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+        if !deletes.is_empty() {
+            let headers = headers[..keys].to_vec();
+            writes.insert(format!("-{}", relation), named_rows(headers, deletes));
+        }
+        if !puts.is_empty() {
+            writes.insert(relation, named_rows(headers, puts));
+        }
+    }
+
+    if options.dry_run {
+        return Ok(report);
+    }
+    if options.strategy == ImportStrategy::FailOnConflict && report.conflict_count > 0 {
+        return Err(error(&format!(
+            "{} rows conflict with local rows: {}",
+            report.conflict_count,
+            shown.join(", ")
+        )));
+    }
+    if !writes.is_empty() {
+        // Deletions come first: `-rel` sorts before `rel`.
+        catch_panic(|| inner.import_relations(writes).map_err(|e| error(&e)))?;
+    }
+    Ok(report)
+}
+
+/// `rows` converted to the types of `columns`, deduplicated by key and in
+/// key order, by importing them into a scratch copy of the relation.
+pub(crate) fn coerce_rows(
+    relation: &str,
+    columns: &[ColumnInfo],
+    rows: NamedRows,
+) -> anyhow::Result<NamedRows> {
+    let error = |e: &dyn std::fmt::Display| {
+        failure(ErrorKind::ImportExport, format!("Import failed: {}", e))
+    };
+    let scratch = CozoDb::from_instance(
+        DbInstance::new("mem", "", "").map_err(|e| error(&e))?,
+        "mem",
+        "",
+        false,
+    );
+    let defs = columns.iter().map(ColumnDef::from).collect();
+    cozo_create_relation(&scratch, relation.to_string(), defs)?;
+    let target = scratch.instance()?;
+    catch_panic(|| target.import_relations(BTreeMap::from([(relation.to_string(), rows)])))
+        .map_err(|e| error(&e))?;
+    scan(&target, relation, columns)
+}
+
+pub(crate) fn named_rows(headers: Vec<String>, rows: Vec<Vec<DataValue>>) -> NamedRows {
+    NamedRows {
+        headers,
+        rows,
+        next: None,
+    }
+}
+
+/// Convert a CSV field to a value of the column's type.
+fn coerce(field: &str, column: &ColumnInfo, empty_as_null: bool) -> anyhow::Result<DataValue> {
+    if field.is_empty() && (column.nullable || empty_as_null) {
+        return Ok(DataValue::Null);
+    }
+    Ok(match column.column_type.as_str() {
+        "Int" => DataValue::from(
+            field
+                .parse::<i64>()
+                .map_err(|e| anyhow::anyhow!("invalid Int {:?}: {}", field, e))?,
+        ),
+        "Float" => DataValue::from(
+            field
+                .parse::<f64>()
+                .map_err(|e| anyhow::anyhow!("invalid Float {:?}: {}", field, e))?,
+        ),
+        "Bool" => match field.to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" => DataValue::Bool(true),
+            "false" | "0" | "no" => DataValue::Bool(false),
+            _ => anyhow::bail!("invalid Bool {:?}", field),
+        },
+        "String" => DataValue::from(field),
+        "Uuid" => DataValue::Uuid(UuidWrapper(
+            uuid::Uuid::parse_str(field)
+                .map_err(|e| anyhow::anyhow!("invalid Uuid {:?}: {}", field, e))?,
+        )),
+        "Json" => DataValue::Json(JsonData(
+            serde_json::from_str(field)
+                .map_err(|e| anyhow::anyhow!("invalid Json {:?}: {}", field, e))?,
+        )),
+        _ => serde_json::from_str::<serde_json::Value>(field)
+            .map(DataValue::from)
+            .unwrap_or_else(|_| DataValue::from(field)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::backup::TempFile;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    fn rows(db: &CozoDb, script: &str) -> serde_json::Value {
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(script, Default::default(), ScriptMutability::Immutable)
+            .unwrap();
+        rows.into_json()["rows"].clone()
+    }
+
+    fn csv_config(relation: &str) -> CsvImportConfig {
+        CsvImportConfig {
+            relation: relation.into(),
+            column_map: HashMap::new(),
+            delimiter: ";".into(),
+            batch_size: 2,
+            empty_as_null: false,
+            trim: true,
+        }
+    }
+
+    #[test]
+    fn csv_fields_are_mapped_and_coerced() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(
+            &db,
+            ":create people {id: Int => name: String, active: Bool, score: Float?, \
+             tags: [String], joined: Int default 0}",
+        )
+        .unwrap();
+        let file = TempFile::private().unwrap();
+        let csv = "ID;name;active;score;tags\n\
+                   1; Ann ;yes;1.5;[\"a\"]\n\
+                   2;Bob;0;;[]\n\
+                   3;Cy;TRUE;2;[\"b\", \"c\"]\n";
+        std::fs::write(file.path(), csv).unwrap();
+        let mut config = csv_config("people");
+        config.column_map.insert("id".into(), "ID".into());
+
+        assert_eq!(cozo_import_csv(&db, file.path().into(), config).unwrap(), 3);
+        assert_eq!(
+            rows(&db, "?[id, name, active, score, tags, joined] := *people{id, name, active, score, tags, joined}"),
+            serde_json::json!([
+                [1, "Ann", true, 1.5, ["a"], 0],
+                [2, "Bob", false, null, [], 0],
+                [3, "Cy", true, 2.0, ["b", "c"], 0],
+            ])
+        );
+    }
+
+    #[test]
+    fn csv_errors_name_the_line_and_keep_earlier_batches() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => name: String}").unwrap();
+        let file = TempFile::private().unwrap();
+        std::fs::write(file.path(), "id;name\n1;a\n2;b\nx;c\n").unwrap();
+
+        let err = cozo_import_csv(&db, file.path().into(), csv_config("items")).unwrap_err();
+        assert!(err.to_string().contains("line 4, column \"id\""), "{}", err);
+        assert_eq!(
+            rows(&db, "?[id] := *items{id}"),
+            serde_json::json!([[1], [2]])
+        );
+
+        std::fs::write(file.path(), "id\n3\n").unwrap();
+        let err = cozo_import_csv(&db, file.path().into(), csv_config("items")).unwrap_err();
+        assert!(err.to_string().contains("no CSV column \"name\""));
+        let mut config = csv_config("items");
+        config.delimiter = "::".into();
+        assert!(cozo_import_csv(&db, file.path().into(), config).is_err());
+    }
+
+    /// A database holding rows 1, 2 and 3, and an export changing 2 and
+    /// adding 4.
+    fn with_changes() -> (CozoDb, String) {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => name: String}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, name] <- [[1, 'a'], [2, 'b'], [3, 'c']] :put items {id => name}",
+        )
+        .unwrap();
+        let data = serde_json::json!({"items": {
+            "headers": ["id", "name"],
+            "rows": [[4, "d"], [1, "a"], [2, "x"]],
+        }});
+        (db, data.to_string())
+    }
+
+    fn import(
+        db: &CozoDb,
+        data: &str,
+        strategy: ImportStrategy,
+        dry_run: bool,
+    ) -> anyhow::Result<ImportReport> {
+        let options = ImportOptions { strategy, dry_run };
+        cozo_import_relations_with_options(db, data.into(), options)
+    }
+
+    #[test]
+    fn strategies_decide_what_happens_to_existing_rows() {
+        let expected = [
+            (
+                ImportStrategy::Replace,
+                serde_json::json!([[1, "a"], [2, "x"], [4, "d"]]),
+            ),
+            (
+                ImportStrategy::Upsert,
+                serde_json::json!([[1, "a"], [2, "x"], [3, "c"], [4, "d"]]),
+            ),
+            (
+                ImportStrategy::SkipExisting,
+                serde_json::json!([[1, "a"], [2, "b"], [3, "c"], [4, "d"]]),
+            ),
+        ];
+        for (strategy, rows_after) in expected {
+            let (db, data) = with_changes();
+            import(&db, &data, strategy, false).unwrap();
+            assert_eq!(
+                rows(&db, "?[id, name] := *items{id, name}"),
+                rows_after,
+                "{:?}",
+                strategy
+            );
+        }
+
+        let (db, data) = with_changes();
+        let err = import(&db, &data, ImportStrategy::FailOnConflict, false).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("1 rows conflict with local rows: items [2]"),
+            "{}",
+            err
+        );
+        assert_eq!(
+            rows(&db, "?[id] := *items{id}"),
+            serde_json::json!([[1], [2], [3]])
+        );
+    }
+
+    #[test]
+    fn dry_runs_write_nothing() {
+        let (db, data) = with_changes();
+        for strategy in [ImportStrategy::Replace, ImportStrategy::FailOnConflict] {
+            import(&db, &data, strategy, true).unwrap();
+        }
+        assert_eq!(
+            rows(&db, "?[id, name] := *items{id, name}"),
+            serde_json::json!([[1, "a"], [2, "b"], [3, "c"]])
+        );
+        let data = r#"{"items:by_name": {"headers": [], "rows": []}}"#;
+        assert!(import(&db, data, ImportStrategy::Upsert, true).is_err());
+    }
+
+    #[test]
+    fn reports_count_rows_and_sample_conflicts() {
+        let (db, data) = with_changes();
+        let report = import(&db, &data, ImportStrategy::Replace, true).unwrap();
+        let counts = (
+            report.inserted,
+            report.updated,
+            report.skipped,
+            report.unchanged,
+            report.deleted,
+            report.conflict_count,
+        );
+        assert_eq!(counts, (1, 1, 0, 1, 1, 1));
+        let conflict = &report.conflicts[0];
+        assert_eq!(conflict.relation, "items");
+        assert_eq!(conflict.key, [CozoValue::Int(2)]);
+        assert_eq!(conflict.local, [CozoValue::Str("b".into())]);
+        assert_eq!(conflict.incoming, [CozoValue::Str("x".into())]);
+
+        let report = import(&db, &data, ImportStrategy::SkipExisting, false).unwrap();
+        assert_eq!((report.skipped, report.updated, report.deleted), (1, 0, 0));
+        // Re-importing the same rows changes nothing.
+        let report = import(&db, &data, ImportStrategy::Upsert, false).unwrap();
+        let report_again = import(&db, &data, ImportStrategy::Upsert, false).unwrap();
+        assert_eq!((report.updated, report_again.updated), (1, 0));
+        assert_eq!(report_again.unchanged, 3);
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability};
+use serde_json::{json, Value};
+
+use crate::api::backup::restore_file;
+use crate::api::error::catch_panic;
+use crate::api::running::epoch_secs;
+use crate::api::schema::{
+    column_names, column_spec, cozo_create_relation, cozo_list_relations, relation_columns,
+    ColumnDef, ColumnInfo,
+};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::triggers::{cozo_list_triggers, cozo_set_triggers, TriggerSpec};
+
+/// Name of the manifest listing the backups in a backup directory.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// A backup written by [`cozo_backup_incremental`].
+#[derive(Debug, Clone)]
+pub struct IncrementalBackupInfo {
+    /// Path of the new backup file.
+    pub path: String,
+    /// Whether this is a full backup, starting a new chain.
+    pub full: bool,
+    /// Relations stored in the file: every relation for a full backup, the
+    /// changed ones for an incremental backup.
+    pub relations: Vec<String>,
+    /// Unix time in seconds.
+    pub created_at: f64,
+}
+
+/// Back up the database into `dir`, writing only the relations whose rows
+/// changed since the previous backup in that directory.
+///
+/// - `dir`: a directory of its own for this database's backups, created if
+///   missing, with a `manifest.json` recording every backup in it
+///
+/// The first backup is a full one. Later calls compare a checksum of every
+/// relation's rows with the manifest and write the changed relations, in
+/// full, to a new file. A full backup is written instead when relations or
+/// indices were created, removed or changed since the last full backup, as
+/// increments only carry rows. Checksums are computed by reading every row,
+/// so each call reads the whole database but writes only what changed.
+///
+/// Restore the chain with [`cozo_restore_incremental`]. Files of older
+/// chains can be deleted once a newer full backup exists.
+pub fn cozo_backup_incremental(db: &CozoDb, dir: String) -> anyhow::Result<IncrementalBackupInfo> {
+    let error = |e: &dyn std::fmt::Display| anyhow::anyhow!("Incremental backup failed: {}", e);
+    let inner = db.instance()?;
+    std::fs::create_dir_all(&dir).map_err(|e| error(&e))?;
+    let mut manifest = read_manifest(&dir)?;
+    let schema = schema_signature(db, &inner)?;
+    let checksums: BTreeMap<String, String> = schema
+        .keys()
+        .filter(|name| !name.contains(':'))
+        .map(|name| Ok((name.clone(), relation_checksum(&inner, name)?)))
+        .collect::<anyhow::Result<_>>()?;
+
+    let entries = manifest["backups"].as_array().cloned().unwrap_or_default();
+    let base = entries.iter().rposition(|entry| entry["full"] == true);
+    let full = match base {
+        Some(i) => entries[i]["schema"] != json!(schema),
+        None => true,
+    };
+    let previous: BTreeMap<String, String> = entries
+        .last()
+        .filter(|_| !full)
+        .and_then(|entry| serde_json::from_value(entry["checksums"].clone()).ok())
+        .unwrap_or_default();
+    let changed: Vec<String> = checksums
+        .iter()
+        .filter(|(name, sum)| previous.get(*name) != Some(sum))
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    let created_at = epoch_secs();
+    let file = format!(
+        "{}-{}.db",
+        if full { "full" } else { "incr" },
+        (created_at * 1000.0) as u64
+    );
+    let path = Path::new(&dir).join(&file).to_string_lossy().into_owned();
+    if full {
+        catch_panic(|| inner.backup_db(&path)).map_err(|e| error(&e))?;
+    } else {
+        write_relations(&inner, &changed, &path).map_err(|e| error(&e))?;
+    }
+
+    let mut entries = entries;
+    entries.push(json!({
+        "file": file,
+        "full": full,
+        "created_at": created_at,
+        "relations": changed,
+        "schema": schema,
+        "checksums": checksums,
+    }));
+    manifest["backups"] = Value::Array(entries);
+    write_manifest(&dir, &manifest)?;
+    Ok(IncrementalBackupInfo {
+        path,
+        full,
+        relations: changed,
+        created_at,
+    })
+}
+
+/// Restore the newest backup chain in `dir`: its full backup, then every
+/// later increment in order.
+///
+/// Like `cozo_restore`, the database must be empty. Rows of an increment
+/// replace all rows of their relation, with triggers off, so the result is
+/// the database as it was at the newest backup.
+///
+/// Returns the paths of the files applied, the full backup first.
+pub fn cozo_restore_incremental(db: &CozoDb, dir: String) -> anyhow::Result<Vec<String>> {
+    let error = |e: &dyn std::fmt::Display| anyhow::anyhow!("Incremental restore failed: {}", e);
+    let inner = db.writable()?;
+    let manifest = read_manifest(&dir)?;
+    let entries = manifest["backups"].as_array().cloned().unwrap_or_default();
+    let base = entries
+        .iter()
+        .rposition(|entry| entry["full"] == true)
+        .ok_or_else(|| error(&"no full backup in the manifest"))?;
+
+    let mut applied = vec![];
+    for entry in &entries[base..] {
+        let file = entry["file"].as_str().unwrap_or_default();
+        let path = Path::new(&dir).join(file).to_string_lossy().into_owned();
+        if entry["full"] == true {
+            catch_panic(|| restore_file(&inner, &path)).map_err(|e| error(&e))?;
+        } else {
+            apply_increment(db, &inner, &path).map_err(|e| error(&e))?;
+        }
+        applied.push(path);
+    }
+    Ok(applied)
+}
+
+/// Columns of every stored relation and index, as `name: Type` lists.
+fn schema_signature(db: &CozoDb, inner: &DbInstance) -> anyhow::Result<BTreeMap<String, String>> {
+    cozo_list_relations(db)?
+        .into_iter()
+        .map(|rel| {
+            let columns = relation_columns(inner, &rel.name)?;
+            let signature = columns
+                .iter()
+                .map(|c| {
+                    let key = if c.is_key { "key " } else { "" };
+                    let null = if c.nullable { "?" } else { "" };
+                    format!("{}{}: {}{}", key, c.name, c.column_type, null)
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            Ok((rel.name, signature))
+        })
+        .collect()
+}
+
+/// FNV-1a checksum of all rows of a relation, in key order.
+pub(crate) fn relation_checksum(inner: &DbInstance, relation: &str) -> anyhow::Result<String> {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for row in scan(inner, relation, &relation_columns(inner, relation)?)?.rows {
+        let row = Value::from(DataValue::List(row)).to_string();
+        for byte in row.bytes().chain([b'\n']) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    Ok(format!("{:016x}", hash))
+}
+
+/// All rows of `relation`, in key order.
+pub(crate) fn scan(
+    inner: &DbInstance,
+    relation: &str,
+    columns: &[ColumnInfo],
+) -> anyhow::Result<NamedRows> {
+    let names = column_names(columns);
+    let script = format!("?[{}] := *{}{{{}}}", names, relation, names);
+    catch_panic(|| inner.run_script(&script, Default::default(), ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, &script))
+}
+
+/// Write `relations` to a backup file at `path`, through a scratch database
+/// holding only them.
+fn write_relations(inner: &DbInstance, relations: &[String], path: &str) -> anyhow::Result<()> {
+    let scratch = CozoDb::from_instance(
+        DbInstance::new("mem", "", "").map_err(|e| anyhow::anyhow!("{}", e))?,
+        "mem",
+        "",
+        false,
+    );
+    let target = scratch.instance()?;
+    for relation in relations {
+        let columns = relation_columns(inner, relation)?;
+        let defs = columns.iter().map(ColumnDef::from).collect();
+        cozo_create_relation(&scratch, relation.clone(), defs)?;
+        let rows = scan(inner, relation, &columns)?;
+        catch_panic(|| target.import_relations(BTreeMap::from([(relation.clone(), rows)])))
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+    }
+    catch_panic(|| target.backup_db(path)).map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+/// Replace the rows of every relation in the increment at `path`.
+fn apply_increment(db: &CozoDb, inner: &DbInstance, path: &str) -> anyhow::Result<()> {
+    let increment = DbInstance::new("mem", "", "").map_err(|e| anyhow::anyhow!("{}", e))?;
+    catch_panic(|| restore_file(&increment, path)).map_err(|e| anyhow::anyhow!("{}", e))?;
+    let relations = catch_panic(|| {
+        increment.run_script(
+            "::relations",
+            Default::default(),
+            ScriptMutability::Immutable,
+        )
+    })
+    .map_err(|err| query_error(err, "::relations"))?;
+    for row in relations.rows {
+        let relation = row[0].get_str().unwrap_or_default().to_string();
+        let columns = relation_columns(inner, &relation)?;
+        let rows = scan(&increment, &relation, &columns)?;
+        let triggers = cozo_list_triggers(db, relation.clone())?;
+        cozo_set_triggers(db, relation.clone(), TriggerSpec::default())?;
+        let replaced = replace_rows(inner, &relation, &columns, rows);
+        cozo_set_triggers(db, relation, triggers)?;
+        replaced?;
+    }
+    Ok(())
+}
+
+/// Delete every row of `relation` and write `rows`, in one transaction and
+/// through the query engine so that indices follow.
+fn replace_rows(
+    inner: &DbInstance,
+    relation: &str,
+    columns: &[ColumnInfo],
+    rows: NamedRows,
+) -> anyhow::Result<()> {
+    let keys = column_names(columns.iter().filter(|c| c.is_key));
+    let script = format!(
+        "{{\n?[{keys}] := *{relation}{{{keys}}}\n:rm {relation} {{{keys}}}\n}}\n{{\n?[{names}] <- $rows\n:put {relation} {{{spec}}}\n}}",
+        keys = keys,
+        relation = relation,
+        names = column_names(columns),
+        spec = column_spec(columns),
+    );
+    let params = BTreeMap::from([(
+        "rows".to_string(),
+        DataValue::List(rows.rows.into_iter().map(DataValue::List).collect()),
+    )]);
+    catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(())
+}
+
+pub(crate) fn read_manifest(dir: &str) -> anyhow::Result<Value> {
+    let path = Path::new(dir).join(MANIFEST_FILE);
+    if !path.exists() {
+        return Ok(json!({ "backups": [] }));
+    }
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("Reading backup manifest failed: {}", e))?;
+    serde_json::from_str(&text).map_err(|e| anyhow::anyhow!("Invalid backup manifest: {}", e))
+}
+
+/// Write the manifest under a temporary name and rename it into place, so a
+/// crash never leaves a truncated manifest.
+fn write_manifest(dir: &str, manifest: &Value) -> anyhow::Result<()> {
+    let path = Path::new(dir).join(MANIFEST_FILE);
+    let partial = Path::new(dir).join(format!("{}.partial", MANIFEST_FILE));
+    std::fs::write(&partial, manifest.to_string())
+        .and_then(|_| std::fs::rename(&partial, &path))
+        .map_err(|e| anyhow::anyhow!("Writing backup manifest failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    /// A fresh backup directory, removed on drop.
+    struct Dir(String);
+
+    impl Dir {
+        fn new() -> Self {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos();
+            let name = format!("cozo_dart_incr_{}_{}", std::process::id(), nanos);
+            Dir(std::env::temp_dir()
+                .join(name)
+                .to_string_lossy()
+                .into_owned())
+        }
+    }
+
+    impl Drop for Dir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn names(db: &CozoDb, relation: &str) -> Vec<String> {
+        let script = format!("?[name] := *{}{{name}}", relation);
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(&script, Default::default(), ScriptMutability::Immutable)
+            .unwrap();
+        rows.rows
+            .iter()
+            .map(|row| row[0].get_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn increments_hold_only_changed_relations() {
+        let dir = Dir::new();
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create a {id: Int => name: String}").unwrap();
+        run_write_op(&db, ":create b {id: Int => name: String}").unwrap();
+        run_write_op(&db, "?[id, name] <- [[1, 'a1']] :put a {id => name}").unwrap();
+        run_write_op(&db, "?[id, name] <- [[1, 'b1']] :put b {id => name}").unwrap();
+
+        let first = cozo_backup_incremental(&db, dir.0.clone()).unwrap();
+        assert!(first.full);
+        assert_eq!(first.relations, ["a", "b"]);
+
+        run_write_op(&db, "?[id, name] <- [[2, 'a2']] :put a {id => name}").unwrap();
+        let second = cozo_backup_incremental(&db, dir.0.clone()).unwrap();
+        assert!(!second.full);
+        assert_eq!(second.relations, ["a"]);
+        let third = cozo_backup_incremental(&db, dir.0.clone()).unwrap();
+        assert!(!third.full && third.relations.is_empty());
+        assert_ne!(second.path, third.path);
+
+        run_write_op(&db, "?[id] <- [[1]] :rm b {id}").unwrap();
+        let fourth = cozo_backup_incremental(&db, dir.0.clone()).unwrap();
+        assert_eq!(fourth.relations, ["b"]);
+
+        let restored = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let applied = cozo_restore_incremental(&restored, dir.0.clone()).unwrap();
+        assert_eq!(applied, [first.path, second.path, third.path, fourth.path]);
+        assert_eq!(names(&restored, "a"), ["a1", "a2"]);
+        assert!(names(&restored, "b").is_empty());
+    }
+
+    #[test]
+    fn schema_changes_start_a_new_chain() {
+        let dir = Dir::new();
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create a {id: Int => name: String}").unwrap();
+        cozo_backup_incremental(&db, dir.0.clone()).unwrap();
+        run_write_op(&db, "::index create a:by_name {name}").unwrap();
+        let next = cozo_backup_incremental(&db, dir.0.clone()).unwrap();
+        assert!(next.full);
+
+        let restored = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let applied = cozo_restore_incremental(&restored, dir.0.clone()).unwrap();
+        assert_eq!(applied, [next.path]);
+        assert!(cozo_restore_incremental(&restored, "/nonexistent/backups".into()).is_err());
+    }
+}
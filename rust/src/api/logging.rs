@@ -0,0 +1,105 @@
+use std::sync::{Mutex, PoisonError};
+
+use log::{LevelFilter, Log, Metadata, Record};
+use serde_json::json;
+
+use crate::frb_generated::StreamSink;
+
+/// Global `log` logger that forwards records to the stream opened by
+/// [`cozo_log_stream`]. Installed by `init_app`.
+pub(crate) struct DartLogger {
+    sink: Mutex<Option<StreamSink<String>>>,
+}
+
+pub(crate) static DART_LOGGER: DartLogger = DartLogger {
+    sink: Mutex::new(None),
+};
+
+impl DartLogger {
+    /// Make this the global logger. Nothing is recorded until a stream is opened.
+    pub(crate) fn install() {
+        if log::set_logger(&DART_LOGGER).is_ok() {
+            log::set_max_level(LevelFilter::Off);
+        }
+    }
+}
+
+impl Log for DartLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        // Send outside the lock, in case the bridge itself logs while sending.
+        let Some(sink) = self
+            .sink
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+        else {
+            return;
+        };
+        let event = json!({
+            "level": record.level().as_str(),
+            "target": record.target(),
+            "message": record.args().to_string(),
+        });
+        if sink.add(event.to_string()).is_err() {
+            // The Dart stream is gone: stop formatting records nobody reads.
+            *self.sink.lock().unwrap_or_else(PoisonError::into_inner) = None;
+            log::set_max_level(LevelFilter::Off);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Forward log records from the binding and cozo to Dart.
+///
+/// - `level`: most verbose level to forward: "error", "warn", "info",
+///   "debug" or "trace"
+///
+/// Each event is a JSON string `{"level", "target", "message"}`, e.g.
+/// `{"level": "WARN", "target": "cozo::runtime::db", "message": "..."}`;
+/// print it or hand it to the app's own logger. Opening a new stream
+/// replaces the previous one.
+///
+/// The forwarding logger replaces the platform console logger that the
+/// bridge would otherwise install, so Rust logs only appear while a stream
+/// is open.
+pub fn cozo_log_stream(level: String, sink: StreamSink<String>) -> anyhow::Result<()> {
+    let filter: LevelFilter = level
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Unknown log level {:?}", level))?;
+    *DART_LOGGER
+        .sink
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(sink);
+    log::set_max_level(filter);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_levels_are_rejected() {
+        let err = cozo_log_stream("loud".into(), StreamSink::deserialize("0".into()));
+        assert!(err.unwrap_err().to_string().contains("\"loud\""));
+    }
+
+    #[test]
+    fn a_closed_stream_stops_forwarding() {
+        DartLogger::install();
+        // No Dart side listens in tests, so sending fails like it does once
+        // the Dart stream is gone.
+        cozo_log_stream("DEBUG".into(), StreamSink::deserialize("0".into())).unwrap();
+        log::warn!("nobody reads this");
+        assert_eq!(log::max_level(), LevelFilter::Off);
+        assert!(DART_LOGGER.sink.lock().unwrap().is_none());
+    }
+}
@@ -0,0 +1,164 @@
+use std::time::{Duration, Instant};
+
+use crate::api::simple::CozoDb;
+use crate::api::storage::{sqlite_pragma, sqlite_query};
+use crate::api::ttl::purge_expired;
+
+/// Expired rows deleted per relation in one step.
+const PURGE_CHUNK_ROWS: usize = 500;
+
+/// Free SQLite pages returned to the file system in one step.
+const VACUUM_CHUNK_PAGES: u64 = 256;
+
+/// What [`cozo_begin_maintenance`] did.
+#[derive(Debug, Clone)]
+pub struct MaintenanceReport {
+    /// Whether all the work is done; false if the budget ran out first, in
+    /// which case the next call continues where this one stopped.
+    pub finished: bool,
+    /// Expired rows deleted, see `cozo_set_ttl`.
+    pub rows_purged: u64,
+    /// SQLite pages returned to the file system.
+    pub pages_freed: u64,
+    pub elapsed_ms: u64,
+}
+
+/// Do as much housekeeping as fits in `budget_ms` milliseconds, e.g. from an
+/// iOS `BGProcessingTask` or an Android `WorkManager` job, which the OS
+/// kills once their time is up.
+///
+/// The work is done in small steps, each committed on its own: copying the
+/// write-ahead log into the database file, deleting expired rows (see
+/// `cozo_set_ttl`), then returning free pages to the file system. A step
+/// only starts if the time left is more than twice the longest step so far,
+/// so the call returns before the budget expires rather than in the middle
+/// of a write; report `finished == false` to the OS so that it schedules the
+/// task again.
+///
+/// Free pages are only returned in steps from SQLite files with
+/// `auto_vacuum` set to incremental; other files keep them until
+/// `cozo_compact`, which rewrites the whole file in one go and does not fit
+/// in a budget.
+pub fn cozo_begin_maintenance(db: &CozoDb, budget_ms: u64) -> anyhow::Result<MaintenanceReport> {
+    db.writable()?;
+    let mut budget = Budget::new(Duration::from_millis(budget_ms));
+    let mut report = MaintenanceReport {
+        finished: false,
+        rows_purged: 0,
+        pages_freed: 0,
+        elapsed_ms: 0,
+    };
+    let sqlite = db.engine == "sqlite";
+
+    if sqlite && budget.allows() {
+        budget.step(|| {
+            // PASSIVE: copies what it can without waiting for readers.
+            sqlite_query(db, "Maintenance", "PRAGMA wal_checkpoint(PASSIVE)").map(|_| ())
+        })?;
+    }
+    let mut purged = false;
+    while budget.allows() {
+        let deleted = budget.step(|| purge_expired(db, Some(PURGE_CHUNK_ROWS)))?;
+        report.rows_purged += deleted;
+        if deleted == 0 {
+            purged = true;
+            break;
+        }
+    }
+    let mut vacuumed = !sqlite || sqlite_pragma(db, "Maintenance", "auto_vacuum")? != 2;
+    while purged && !vacuumed && budget.allows() {
+        let free = sqlite_pragma(db, "Maintenance", "freelist_count")?;
+        if free == 0 {
+            vacuumed = true;
+            break;
+        }
+        let pages = free.min(VACUUM_CHUNK_PAGES);
+        budget.step(|| {
+            sqlite_query(
+                db,
+                "Maintenance",
+                &format!("PRAGMA incremental_vacuum({})", pages),
+            )
+        })?;
+        report.pages_freed += pages;
+    }
+
+    report.finished = purged && vacuumed;
+    report.elapsed_ms = budget.start.elapsed().as_millis() as u64;
+    Ok(report)
+}
+
+/// Time left for maintenance, and how long its steps take.
+struct Budget {
+    start: Instant,
+    budget: Duration,
+    longest: Duration,
+}
+
+impl Budget {
+    fn new(budget: Duration) -> Self {
+        Budget {
+            start: Instant::now(),
+            budget,
+            longest: Duration::ZERO,
+        }
+    }
+
+    /// Whether another step is likely to finish within the budget.
+    fn allows(&self) -> bool {
+        let left = self.budget.saturating_sub(self.start.elapsed());
+        !left.is_zero() && left > self.longest * 2
+    }
+
+    fn step<T>(&mut self, f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+        let start = Instant::now();
+        let result = f();
+        self.longest = self.longest.max(start.elapsed());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::backup::TempFile;
+    use crate::api::running::epoch_secs;
+    use crate::api::simple::{cozo_close_db, cozo_open_db};
+    use crate::api::ttl::cozo_set_ttl;
+    use crate::api::vector::run_write_op;
+
+    fn with_expired_rows(db: &CozoDb) {
+        run_write_op(db, ":create cache {id: Int => at: Float}").unwrap();
+        let script = format!(
+            "?[id, at] := id in int_range(1200), at = {}\n:put cache {{id => at}}",
+            epoch_secs() - 60.0
+        );
+        run_write_op(db, &script).unwrap();
+        cozo_set_ttl(db, "cache".into(), "at".into(), 1.0).unwrap();
+    }
+
+    #[test]
+    fn maintenance_purges_within_its_budget() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        with_expired_rows(&db);
+        let report = cozo_begin_maintenance(&db, 0).unwrap();
+        assert!(!report.finished);
+        assert_eq!(report.rows_purged, 0);
+
+        let report = cozo_begin_maintenance(&db, 60_000).unwrap();
+        assert!(report.finished);
+        assert_eq!((report.rows_purged, report.pages_freed), (1200, 0));
+    }
+
+    #[test]
+    fn sqlite_files_are_checkpointed_and_purged() {
+        let file = TempFile::private().unwrap();
+        let db = cozo_open_db("sqlite".into(), file.path().into(), "{}".into()).unwrap();
+        with_expired_rows(&db);
+        let report = cozo_begin_maintenance(&db, 60_000).unwrap();
+        assert!(report.finished);
+        assert_eq!(report.rows_purged, 1200);
+        cozo_close_db(&db);
+        let _ = std::fs::remove_file(format!("{}.lock", file.path()));
+    }
+}
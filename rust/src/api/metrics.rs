@@ -0,0 +1,272 @@
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use flutter_rust_bridge::frb;
+
+use crate::api::error::{CozoDartError, ErrorKind};
+use crate::api::running::epoch_secs;
+use crate::api::simple::CozoDb;
+use crate::api::transaction::WRITE_OPS;
+
+/// Upper bounds of the latency buckets in milliseconds, each twice the last.
+/// Queries slower than the last bound fall in a final open bucket.
+const BUCKET_BOUNDS_MS: [f64; 20] = [
+    0.1, 0.2, 0.4, 0.8, 1.6, 3.2, 6.4, 12.8, 25.6, 51.2, 102.4, 204.8, 409.6, 819.2, 1638.4,
+    3276.8, 6553.6, 13107.2, 26214.4, 52428.8,
+];
+
+/// Number of failed queries of one [`ErrorKind`].
+#[derive(Debug, Clone)]
+pub struct ErrorCount {
+    pub kind: ErrorKind,
+    pub count: u64,
+}
+
+/// Queries that took at most `le_ms` and longer than the previous bucket's.
+#[derive(Debug, Clone)]
+pub struct LatencyBucket {
+    /// `None` for the last bucket, which has no upper bound.
+    pub le_ms: Option<f64>,
+    pub count: u64,
+}
+
+/// Counters of a database since it was opened or [`cozo_reset_metrics`].
+#[derive(Debug, Clone)]
+pub struct CozoMetrics {
+    /// When counting started, in seconds since the Unix epoch.
+    pub since: f64,
+    /// Queries run, failed ones included.
+    pub queries: u64,
+    /// Queries whose script contains a mutation option such as `:put` or
+    /// `:rm`, the same approximation as `TransactionInfo::write_scripts`.
+    pub write_queries: u64,
+    /// Rows returned by queries that did not write.
+    pub rows_read: u64,
+    /// Rows written by `cozo_put_rows`, `cozo_upsert_rows` and
+    /// `cozo_delete_rows`. cozo does not report rows written by a script.
+    pub rows_written: u64,
+    pub errors: u64,
+    /// Failed queries by kind, only kinds seen.
+    pub errors_by_kind: Vec<ErrorCount>,
+    /// Latency percentiles as the upper bound of the bucket they fall in,
+    /// `None` before any query ran or when it falls in the open bucket.
+    pub latency_p50_ms: Option<f64>,
+    pub latency_p99_ms: Option<f64>,
+    pub latency_mean_ms: Option<f64>,
+    pub latency_buckets: Vec<LatencyBucket>,
+    /// Results of `cozo_run_query_cached` served from the cache, and those
+    /// that ran the query while the cache was enabled.
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    /// `cache_hits` over lookups, `None` before any lookup.
+    pub cache_hit_rate: Option<f64>,
+}
+
+/// Counters behind [`cozo_metrics`], shared by every handle to a database.
+#[frb(ignore)]
+pub(crate) struct Metrics {
+    since: f64,
+    queries: u64,
+    write_queries: u64,
+    rows_read: u64,
+    rows_written: u64,
+    errors: Vec<ErrorCount>,
+    /// One count per bound of `BUCKET_BOUNDS_MS`, then the open bucket.
+    buckets: Vec<u64>,
+    total_ms: f64,
+    cache_hits: u64,
+    cache_misses: u64,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics {
+            since: epoch_secs(),
+            queries: 0,
+            write_queries: 0,
+            rows_read: 0,
+            rows_written: 0,
+            errors: vec![],
+            buckets: vec![0; BUCKET_BOUNDS_MS.len() + 1],
+            total_ms: 0.0,
+            cache_hits: 0,
+            cache_misses: 0,
+        }
+    }
+}
+
+impl Metrics {
+    /// Record a finished query, with the rows it returned or how it failed.
+    pub(crate) fn record_query(
+        &mut self,
+        script: &str,
+        took: Duration,
+        result: Result<usize, ErrorKind>,
+    ) {
+        self.queries += 1;
+        let ms = took.as_secs_f64() * 1000.0;
+        self.total_ms += ms;
+        let bucket = BUCKET_BOUNDS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(BUCKET_BOUNDS_MS.len());
+        self.buckets[bucket] += 1;
+        let writes = WRITE_OPS.iter().any(|op| script.contains(op));
+        if writes {
+            self.write_queries += 1;
+        }
+        match result {
+            Ok(rows) if !writes => self.rows_read += rows as u64,
+            Ok(_) => {}
+            Err(kind) => match self.errors.iter_mut().find(|e| e.kind == kind) {
+                Some(entry) => entry.count += 1,
+                None => self.errors.push(ErrorCount { kind, count: 1 }),
+            },
+        }
+    }
+
+    pub(crate) fn record_rows_written(&mut self, rows: u64) {
+        self.rows_written += rows;
+    }
+
+    pub(crate) fn record_cache_lookup(&mut self, hit: bool) {
+        if hit {
+            self.cache_hits += 1;
+        } else {
+            self.cache_misses += 1;
+        }
+    }
+
+    /// Upper bound of the bucket holding the `q` quantile of latencies.
+    fn percentile(&self, q: f64) -> Option<f64> {
+        if self.queries == 0 {
+            return None;
+        }
+        let rank = (q * self.queries as f64).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (i, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return BUCKET_BOUNDS_MS.get(i).copied();
+            }
+        }
+        None
+    }
+}
+
+/// Record how a query went in the metrics of `db`.
+pub(crate) fn record_query<T>(
+    db: &CozoDb,
+    script: &str,
+    took: Duration,
+    result: &anyhow::Result<T>,
+    rows: impl FnOnce(&T) -> usize,
+) {
+    let result = match result {
+        Ok(value) => Ok(rows(value)),
+        Err(e) => Err(error_kind(e)),
+    };
+    db.metrics
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .record_query(script, took, result);
+}
+
+/// The kind [`CozoDartError`] would report for `err`.
+pub(crate) fn error_kind(err: &anyhow::Error) -> ErrorKind {
+    err.downcast_ref::<CozoDartError>()
+        .map_or(ErrorKind::Eval, |err| err.kind)
+}
+
+/// Counters and latency histogram of the queries run on the database, for
+/// feeding app performance dashboards.
+///
+/// Queries run through `cozo_run_query` and the calls built on it, and
+/// through `cozo_run_query_typed`, are counted; counting is in memory and
+/// shared by every handle to the database. See [`CozoMetrics`] for what each
+/// counter covers.
+#[frb(sync)]
+pub fn cozo_metrics(db: &CozoDb) -> CozoMetrics {
+    let metrics = db.metrics.lock().unwrap_or_else(PoisonError::into_inner);
+    let lookups = metrics.cache_hits + metrics.cache_misses;
+    CozoMetrics {
+        since: metrics.since,
+        queries: metrics.queries,
+        write_queries: metrics.write_queries,
+        rows_read: metrics.rows_read,
+        rows_written: metrics.rows_written,
+        errors: metrics.errors.iter().map(|e| e.count).sum(),
+        errors_by_kind: metrics.errors.clone(),
+        latency_p50_ms: metrics.percentile(0.5),
+        latency_p99_ms: metrics.percentile(0.99),
+        latency_mean_ms: (metrics.queries > 0).then(|| metrics.total_ms / metrics.queries as f64),
+        latency_buckets: metrics
+            .buckets
+            .iter()
+            .enumerate()
+            .map(|(i, count)| LatencyBucket {
+                le_ms: BUCKET_BOUNDS_MS.get(i).copied(),
+                count: *count,
+            })
+            .collect(),
+        cache_hits: metrics.cache_hits,
+        cache_misses: metrics.cache_misses,
+        cache_hit_rate: (lookups > 0).then(|| metrics.cache_hits as f64 / lookups as f64),
+    }
+}
+
+/// Set every counter of [`cozo_metrics`] back to zero, starting `since` now.
+#[frb(sync)]
+pub fn cozo_reset_metrics(db: &CozoDb) {
+    *db.metrics.lock().unwrap_or_else(PoisonError::into_inner) = Metrics::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+
+    #[test]
+    fn percentiles_are_bucket_bounds() {
+        let mut metrics = Metrics::default();
+        assert_eq!(metrics.percentile(0.5), None);
+        for _ in 0..98 {
+            metrics.record_query("?[x] <- [[1]]", Duration::from_micros(50), Ok(1));
+        }
+        metrics.record_query("?[x] <- [[1]]", Duration::from_millis(3), Ok(1));
+        metrics.record_query("?[x] <- [[1]]", Duration::from_secs(60), Ok(1));
+        assert_eq!(metrics.percentile(0.5), Some(0.1));
+        assert_eq!(metrics.percentile(0.99), Some(3.2));
+        // The slowest query is in the open bucket.
+        assert_eq!(metrics.percentile(1.0), None);
+        assert_eq!(metrics.rows_read, 100);
+    }
+
+    #[test]
+    fn queries_are_counted_until_reset() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_query(&db, ":create items {id: Int}".into(), "{}".into(), false).unwrap();
+        let put = "?[id] <- [[1], [2]] :put items {id}";
+        cozo_run_query(&db, put.into(), "{}".into(), false).unwrap();
+        cozo_run_query(&db, "?[id] := *items{id}".into(), "{}".into(), true).unwrap();
+        assert!(cozo_run_query(&db, "?[x] <- [[1]".into(), "{}".into(), true).is_err());
+        db.metrics.lock().unwrap().record_cache_lookup(true);
+        db.metrics.lock().unwrap().record_cache_lookup(false);
+
+        let metrics = cozo_metrics(&db);
+        assert_eq!((metrics.queries, metrics.write_queries), (4, 2));
+        assert_eq!(metrics.rows_read, 2);
+        assert_eq!(metrics.errors, 1);
+        assert_eq!(metrics.errors_by_kind[0].kind, ErrorKind::Parse);
+        assert_eq!(metrics.latency_buckets.len(), BUCKET_BOUNDS_MS.len() + 1);
+        assert_eq!(
+            metrics.latency_buckets.iter().map(|b| b.count).sum::<u64>(),
+            4
+        );
+        assert_eq!(metrics.cache_hit_rate, Some(0.5));
+
+        cozo_reset_metrics(&db);
+        let metrics = cozo_metrics(&db);
+        assert_eq!((metrics.queries, metrics.latency_mean_ms), (0, None));
+    }
+}
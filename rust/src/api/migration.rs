@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cozo::{DataValue, DbInstance, ScriptMutability};
+
+use crate::api::error::catch_panic;
+use crate::api::running::epoch_secs;
+use crate::api::schema::cozo_list_relations;
+use crate::api::simple::{query_error, CozoDb};
+
+/// Stored relation recording applied migrations. Cozo treats relations whose
+/// names start with `_` as temporary to a single script, so this cannot be
+/// called `_migrations`.
+const MIGRATIONS_RELATION: &str = "cozo_migrations";
+
+/// One schema change, applied at most once per database.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    /// Unique, ordered version number.
+    pub version: i64,
+    /// CozoScript to run: a single query or a chain of `{ ... }` blocks.
+    pub script: String,
+}
+
+/// Outcome of [`cozo_migrate`].
+#[derive(Debug, Clone)]
+pub struct MigrationReport {
+    /// Versions applied by this call, in order.
+    pub applied: Vec<i64>,
+    /// Highest applied version after this call, `None` if none ever ran.
+    pub current_version: Option<i64>,
+}
+
+/// Run every migration that has not been applied to this database yet.
+///
+/// - `migrations`: the app's full list of migrations, in any order
+///
+/// Applied versions are recorded in the `cozo_migrations` stored relation.
+/// Pending migrations run in version order, each in one transaction together
+/// with its bookkeeping, so a failed migration leaves no trace and can be
+/// retried. Migrations that ran before the failure stay applied, and the
+/// error names the failing version.
+pub fn cozo_migrate(db: &CozoDb, migrations: Vec<Migration>) -> anyhow::Result<MigrationReport> {
+    let inner = db.writable()?;
+    let mut pending = BTreeMap::new();
+    for migration in migrations {
+        let version = migration.version;
+        if pending.insert(version, migration.script).is_some() {
+            anyhow::bail!("Migration failed: duplicate version {}", version);
+        }
+    }
+
+    let mut done = applied_versions(db, &inner)?;
+    let mut applied = vec![];
+    for (version, script) in pending {
+        if done.contains(&version) {
+            continue;
+        }
+        let script = migration_script(&script);
+        let params = BTreeMap::from([
+            ("version".to_string(), DataValue::from(version)),
+            ("applied_at".to_string(), DataValue::from(epoch_secs())),
+        ]);
+        catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable)).map_err(
+            |err| {
+                anyhow::anyhow!(
+                    "Migration {} failed (applied before it: {:?}): {}",
+                    version,
+                    applied,
+                    query_error(err, &script)
+                )
+            },
+        )?;
+        done.insert(version);
+        applied.push(version);
+    }
+    Ok(MigrationReport {
+        applied,
+        current_version: done.last().copied(),
+    })
+}
+
+/// Versions already applied, creating the tracking relation on first use.
+fn applied_versions(db: &CozoDb, inner: &DbInstance) -> anyhow::Result<BTreeSet<i64>> {
+    let exists = cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == MIGRATIONS_RELATION);
+    let script = if exists {
+        format!("?[version] := *{}[version, _]", MIGRATIONS_RELATION)
+    } else {
+        format!(
+            ":create {} {{version: Int => applied_at: Float}}",
+            MIGRATIONS_RELATION
+        )
+    };
+    let rows =
+        catch_panic(|| inner.run_script(&script, Default::default(), ScriptMutability::Mutable))
+            .map_err(|err| query_error(err, &script))?;
+    if !exists {
+        return Ok(BTreeSet::new());
+    }
+    Ok(rows
+        .rows
+        .iter()
+        .filter_map(|row| row[0].get_int())
+        .collect())
+}
+
+/// Chain the migration with the insert of its version, so both commit together.
+fn migration_script(script: &str) -> String {
+    let body = if script.trim_start().starts_with('{') {
+        script.to_string()
+    } else {
+        format!("{{\n{}\n}}", script)
+    };
+    format!(
+        "{}\n{{\n?[version, applied_at] <- [[$version, $applied_at]] :put {} {{version => applied_at}}\n}}",
+        body, MIGRATIONS_RELATION
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+
+    fn migration(version: i64, script: &str) -> Migration {
+        Migration {
+            version,
+            script: script.into(),
+        }
+    }
+
+    fn relations(db: &CozoDb) -> Vec<String> {
+        cozo_list_relations(db)
+            .unwrap()
+            .into_iter()
+            .map(|rel| rel.name)
+            .collect()
+    }
+
+    #[test]
+    fn pending_migrations_run_once_in_order() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let report = cozo_migrate(
+            &db,
+            vec![
+                migration(2, "?[id] <- [[1]] :put items {id}"),
+                migration(1, ":create items {id: Int}"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(report.applied, [1, 2]);
+        assert_eq!(report.current_version, Some(2));
+
+        let report = cozo_migrate(
+            &db,
+            vec![
+                migration(1, ":create items {id: Int}"),
+                migration(2, "?[id] <- [[1]] :put items {id}"),
+                migration(
+                    3,
+                    "{:create tags {id: Int}} {?[id] <- [[7]] :put tags {id}}",
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(report.applied, [3]);
+        assert_eq!(report.current_version, Some(3));
+        assert_eq!(relations(&db), ["cozo_migrations", "items", "tags"]);
+
+        let report = cozo_migrate(&db, vec![]).unwrap();
+        assert!(report.applied.is_empty());
+        assert_eq!(report.current_version, Some(3));
+    }
+
+    #[test]
+    fn failed_migrations_leave_no_trace() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let err = cozo_migrate(
+            &db,
+            vec![
+                migration(1, ":create items {id: Int}"),
+                migration(2, "{:create tags {id: Int}} {?[x] := *missing{x}}"),
+            ],
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.starts_with("Migration 2 failed (applied before it: [1])"),
+            "{}",
+            err
+        );
+        assert_eq!(relations(&db), ["cozo_migrations", "items"]);
+
+        let report = cozo_migrate(&db, vec![migration(2, ":create tags {id: Int}")]).unwrap();
+        assert_eq!(report.applied, [2]);
+
+        let dup = cozo_migrate(&db, vec![migration(4, ""), migration(4, "")]);
+        assert!(dup.unwrap_err().to_string().contains("duplicate version 4"));
+    }
+}
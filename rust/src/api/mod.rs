@@ -1 +1,51 @@
+pub mod asset;
+pub mod audit;
+pub mod auto_backup;
+pub mod backup;
+pub mod batch;
+pub mod busy_retry;
+pub mod callback;
+pub mod cdc;
+pub mod column_crypto;
+pub mod compression;
+pub mod crdt;
+pub mod cursor;
+pub mod encoding;
+pub mod error;
+pub mod explain;
+pub mod export;
+pub mod file_lock;
+pub mod fixed_rule;
+pub mod format;
+pub mod import;
+pub mod incremental;
+pub mod logging;
+pub mod maintenance;
+pub mod metrics;
+pub mod migration;
+pub mod paths;
+pub mod prepared;
+pub mod query_cache;
+pub mod recovery;
+pub mod registry;
+pub mod retry;
+pub mod row_policy;
+pub mod rows;
+pub mod running;
+pub mod schema;
+pub mod search;
 pub mod simple;
+pub mod slow_log;
+pub mod soft_delete;
+pub mod stats;
+pub mod storage;
+pub mod stream;
+pub mod sync;
+pub mod temporal;
+pub mod tenant;
+pub mod transaction;
+pub mod triggers;
+pub mod ttl;
+pub mod validate;
+pub mod value;
+pub mod vector;
@@ -0,0 +1,237 @@
+use std::path::PathBuf;
+
+use crate::api::tenant::valid_id;
+
+/// Which platform directory [`cozo_db_path`] puts a database in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbLocation {
+    /// Private app data that the user does not manage: Application Support
+    /// on iOS and macOS, the app's files directory on Android. The usual
+    /// choice.
+    AppSupport,
+    /// User-visible documents: the Documents folder on iOS (shown in the
+    /// Files app if the app enables file sharing) and desktop. On Android,
+    /// scoped storage keeps apps out of the shared Documents folder, so this
+    /// is the app's files directory, as for `AppSupport`.
+    Documents,
+    /// Data that can be rebuilt: the OS may delete it when space runs low,
+    /// and it is not backed up.
+    Cache,
+}
+
+/// The path to open the database `name` with in `location`, creating the
+/// directory it goes in.
+///
+/// - `name`: letters, digits, `-`, `_` and `.`, not starting with `.`
+/// - `engine`: "sqlite" gives the file `<name>.db`, "rocksdb" the directory
+///   `<name>` (created by `cozo_open_db`), "mem" an empty path
+/// - `base_dir`: the directory to use instead of looking it up, e.g. from
+///   `getApplicationSupportDirectory()` in `path_provider`
+///
+/// Without `base_dir` the directory is derived from the process: `$HOME`
+/// in the app container on iOS, `/data/user/<user>/<package>` on Android,
+/// and the app's directories named after the executable on desktop
+/// (`~/Library/Application Support/<app>`, `%APPDATA%\<app>`,
+/// `$XDG_DATA_HOME/<app>`, ...). That matches `path_provider` on mobile;
+/// on desktop, where `path_provider` uses the bundle or application id,
+/// pass `base_dir` to keep files where the rest of the app keeps them.
+pub fn cozo_db_path(
+    name: String,
+    location: DbLocation,
+    engine: String,
+    base_dir: Option<String>,
+) -> anyhow::Result<String> {
+    if !valid_id(&name) {
+        anyhow::bail!(
+            "Invalid database name {:?}: use letters, digits, `-`, `_` and `.`, not starting with `.`",
+            name
+        );
+    }
+    let file = match engine.as_str() {
+        "mem" => return Ok(String::new()),
+        "sqlite" => format!("{}.db", name),
+        "rocksdb" => name,
+        _ => anyhow::bail!("Unknown engine {}, expected mem, sqlite or rocksdb", engine),
+    };
+    let dir = match base_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => platform_dir(location)?,
+    };
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| anyhow::anyhow!("Failed to create {:?}: {}", dir, e))?;
+    Ok(dir.join(file).to_string_lossy().into_owned())
+}
+
+/// The app's private cache directory, created if missing, for temporary
+/// files that must not be left in the shared temp directory.
+pub(crate) fn private_cache_dir() -> anyhow::Result<PathBuf> {
+    let dir = platform_dir(DbLocation::Cache)?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| anyhow::anyhow!("Failed to create {:?}: {}", dir, e))?;
+    Ok(dir)
+}
+
+#[cfg(not(target_os = "android"))]
+fn home() -> anyhow::Result<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow::anyhow!("Cannot find the home directory, pass base_dir"))
+}
+
+/// Name of the app's own directories on desktop: the executable's name.
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+fn app_name() -> anyhow::Result<String> {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.file_stem()?.to_string_lossy().into_owned()))
+        .ok_or_else(|| anyhow::anyhow!("Cannot find the app name, pass base_dir"))
+}
+
+/// Joined `$var`, or `fallback` under the home directory if it is unset.
+#[cfg(not(any(target_os = "ios", target_os = "android", target_os = "macos")))]
+fn env_dir(var: &str, fallback: &[&str]) -> anyhow::Result<PathBuf> {
+    match std::env::var_os(var) {
+        Some(dir) => Ok(PathBuf::from(dir)),
+        None => Ok(fallback.iter().fold(home()?, |dir, part| dir.join(part))),
+    }
+}
+
+#[cfg(target_os = "ios")]
+fn platform_dir(location: DbLocation) -> anyhow::Result<PathBuf> {
+    // In the sandbox, $HOME is the app's container.
+    let home = home()?;
+    Ok(match location {
+        DbLocation::AppSupport => home.join("Library").join("Application Support"),
+        DbLocation::Documents => home.join("Documents"),
+        DbLocation::Cache => home.join("Library").join("Caches"),
+    })
+}
+
+#[cfg(target_os = "android")]
+fn platform_dir(location: DbLocation) -> anyhow::Result<PathBuf> {
+    let base = android_data_dir()?;
+    Ok(match location {
+        DbLocation::AppSupport | DbLocation::Documents => base.join("files"),
+        DbLocation::Cache => base.join("cache"),
+    })
+}
+
+/// `/data/user/<user>/<package>`, the app's private data directory, which
+/// `/data/data/<package>` links to for the first user only.
+#[cfg(target_os = "android")]
+fn android_data_dir() -> anyhow::Result<PathBuf> {
+    let cmdline = std::fs::read("/proc/self/cmdline")
+        .map_err(|e| anyhow::anyhow!("Cannot find the app package ({}), pass base_dir", e))?;
+    // The process name is the package, or `package:service` in a service
+    // process.
+    let name = String::from_utf8_lossy(cmdline.split(|b| *b == 0).next().unwrap_or_default());
+    let package = name.split(':').next().unwrap_or_default().to_string();
+    if package.is_empty() || package.contains('/') {
+        anyhow::bail!("Cannot find the app package, pass base_dir");
+    }
+    // Apps of Android user n run with uids from n * 100000.
+    let status = std::fs::read_to_string("/proc/self/status").unwrap_or_default();
+    let uid: u32 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("Uid:"))
+        .and_then(|ids| ids.split_whitespace().next())
+        .and_then(|uid| uid.parse().ok())
+        .unwrap_or_default();
+    let dir = std::path::Path::new("/data/user")
+        .join((uid / 100_000).to_string())
+        .join(&package);
+    if dir.is_dir() {
+        return Ok(dir);
+    }
+    Ok(std::path::Path::new("/data/data").join(package))
+}
+
+#[cfg(target_os = "macos")]
+fn platform_dir(location: DbLocation) -> anyhow::Result<PathBuf> {
+    // A sandboxed app's $HOME is its container, so these stay inside it.
+    let library = home()?.join("Library");
+    Ok(match location {
+        DbLocation::AppSupport => library.join("Application Support").join(app_name()?),
+        DbLocation::Documents => home()?.join("Documents"),
+        DbLocation::Cache => library.join("Caches").join(app_name()?),
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn platform_dir(location: DbLocation) -> anyhow::Result<PathBuf> {
+    Ok(match location {
+        DbLocation::AppSupport => env_dir("APPDATA", &["AppData", "Roaming"])?.join(app_name()?),
+        DbLocation::Documents => home()?.join("Documents"),
+        DbLocation::Cache => env_dir("LOCALAPPDATA", &["AppData", "Local"])?
+            .join(app_name()?)
+            .join("cache"),
+    })
+}
+
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+fn platform_dir(location: DbLocation) -> anyhow::Result<PathBuf> {
+    if cfg!(target_family = "wasm") {
+        anyhow::bail!("Only the mem engine is available on the web");
+    }
+    Ok(match location {
+        DbLocation::AppSupport => env_dir("XDG_DATA_HOME", &[".local", "share"])?.join(app_name()?),
+        DbLocation::Documents => home()?.join("Documents"),
+        DbLocation::Cache => env_dir("XDG_CACHE_HOME", &[".cache"])?.join(app_name()?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let name = format!("cozo_dart_paths_{}_{}", std::process::id(), nanos);
+        std::env::temp_dir().join(name).join("nested")
+    }
+
+    #[test]
+    fn paths_go_in_the_base_dir_by_engine() {
+        let dir = base();
+        let path = |engine: &str| {
+            let base_dir = Some(dir.to_string_lossy().into_owned());
+            cozo_db_path(
+                "notes".into(),
+                DbLocation::AppSupport,
+                engine.into(),
+                base_dir,
+            )
+        };
+        assert_eq!(
+            path("sqlite").unwrap(),
+            dir.join("notes.db").to_string_lossy()
+        );
+        assert!(dir.is_dir());
+        assert_eq!(
+            path("rocksdb").unwrap(),
+            dir.join("notes").to_string_lossy()
+        );
+        assert!(!dir.join("notes").exists());
+        assert_eq!(path("mem").unwrap(), "");
+        assert!(path("lmdb").is_err());
+        let _ = std::fs::remove_dir_all(dir.parent().unwrap());
+    }
+
+    #[test]
+    fn names_cannot_leave_the_directory() {
+        for name in ["", "../notes", ".hidden", "a/b", "a\\b"] {
+            let base_dir = Some(std::env::temp_dir().to_string_lossy().into_owned());
+            let path = cozo_db_path(name.into(), DbLocation::Cache, "sqlite".into(), base_dir);
+            assert!(path.is_err(), "{:?}", name);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeSet;
+use std::sync::{Mutex, PoisonError};
+
+use flutter_rust_bridge::frb;
+
+use crate::api::row_policy::{
+    apply_row_policies_cached, is_comment, skip_comment, RewrittenScript,
+};
+use crate::api::simple::{parse_params, record_rows, rows_to_json, CozoDb};
+use crate::api::temporal::skip_string;
+
+/// A CozoScript query kept on the Rust side for repeated runs.
+///
+/// Nothing is parsed ahead of time: cozo 0.7 keeps its parser and compiled
+/// programs `pub(crate)`, so cozo parses the script on every run as it does
+/// for `cozo_run_query`. What the handle saves is sending the script across
+/// the bridge, rewriting it for the row policies, which is done once and
+/// again only after a policy changes, and finding the parameters it uses,
+/// which are checked before each run. Otherwise each run is a
+/// `cozo_run_query`: under the handle's heap limit, and counted in its
+/// metrics and slow query log. It stops working once the database is
+/// closed.
+#[frb(opaque)]
+pub struct PreparedQuery {
+    db: CozoDb,
+    script: String,
+    /// Names of the `$parameters` the script uses.
+    params: BTreeSet<String>,
+    rewritten: Mutex<Option<RewrittenScript>>,
+}
+
+/// Prepare a query for [`cozo_run_prepared`].
+#[frb(sync)]
+pub fn cozo_prepare(db: &CozoDb, script: String) -> anyhow::Result<PreparedQuery> {
+    db.instance()?;
+    Ok(PreparedQuery {
+        db: db.share(),
+        params: script_params(&script),
+        rewritten: Mutex::new(None),
+        script,
+    })
+}
+
+/// Run a prepared query.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+/// - `immutable`: if true, the query is run in read-only mode
+///
+/// Fails without running the query if a parameter the script uses has no
+/// value, from `params_json` or the row policy parameters.
+///
+/// Returns the same JSON result as [`crate::api::simple::cozo_run_query`].
+pub fn cozo_run_prepared(
+    prepared: &PreparedQuery,
+    params_json: String,
+    immutable: bool,
+) -> anyhow::Result<String> {
+    let db = &prepared.db;
+    let mut params = parse_params(&params_json)?;
+    let script = apply_row_policies_cached(
+        &db.row_policies,
+        &prepared.script,
+        &mut prepared
+            .rewritten
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+        &mut params,
+    )?;
+    let missing: Vec<_> = prepared
+        .params
+        .iter()
+        .filter(|name| !params.contains_key(*name))
+        .map(|name| format!("${}", name))
+        .collect();
+    if !missing.is_empty() {
+        anyhow::bail!("Query failed: no value for {}", missing.join(", "));
+    }
+    let (rows, took) = db.with_heap_limit(&prepared.script, || {
+        record_rows(db, &prepared.script, &params_json, |inner| {
+            db.run_rewritten(inner, &script, params, db.mutability(immutable))
+        })
+    })?;
+    Ok(rows_to_json(rows, took))
+}
+
+/// Names of the `$parameters` in `script`, outside strings and comments.
+fn script_params(script: &str) -> BTreeSet<String> {
+    let chars: Vec<char> = script.chars().collect();
+    let is_name = |c: char| c.is_alphanumeric() || c == '_';
+    let mut params = BTreeSet::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' | '"' => {
+                i = skip_string(&chars, i);
+                continue;
+            }
+            '#' | '/' if is_comment(&chars, i) => {
+                i = skip_comment(&chars, i);
+                continue;
+            }
+            '$' => {
+                let end = (i + 1..chars.len())
+                    .find(|j| !is_name(chars[*j]))
+                    .unwrap_or(chars.len());
+                if end > i + 1 {
+                    params.insert(chars[i + 1..end].iter().collect());
+                }
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metrics::cozo_metrics;
+    use crate::api::row_policy::cozo_set_row_policy;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::slow_log::{cozo_configure_slow_log, cozo_slow_queries};
+    use crate::api::vector::run_write_op;
+
+    #[test]
+    fn prepared_runs_are_recorded_like_queries() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_configure_slow_log(&db, Some(0), 10);
+        let prepared = cozo_prepare(&db, "?[x] := x = $x".into()).unwrap();
+        for x in [1, 2] {
+            let out = cozo_run_prepared(&prepared, format!("{{\"x\": {}}}", x), true).unwrap();
+            assert!(out.contains(&format!("[[{}]]", x)), "{}", out);
+        }
+        assert!(cozo_run_prepared(&prepared, "{}".into(), true).is_err());
+        assert_eq!(cozo_metrics(&db).queries, 2);
+        let slow = cozo_slow_queries(&db);
+        assert_eq!(slow.len(), 2);
+        assert!(slow.iter().all(|q| q.script == "?[x] := x = $x"));
+    }
+
+    #[test]
+    fn prepared_writes_return_only_allowed_rows() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int => owner: String}").unwrap();
+        let prepared = cozo_prepare(
+            &db,
+            "?[id, owner] <- $rows :put notes {id => owner} :returning".into(),
+        )
+        .unwrap();
+        let rows = r#"{"rows": [[1, "me"], [2, "them"]]}"#;
+        let out = cozo_run_prepared(&prepared, rows.into(), false).unwrap();
+        assert!(out.contains("\"them\""));
+
+        cozo_set_row_policy(&db, "notes".into(), "owner == 'me'".into()).unwrap();
+        let out = cozo_run_prepared(&prepared, rows.into(), false).unwrap();
+        assert!(
+            out.contains("\"me\"") && !out.contains("\"them\""),
+            "{}",
+            out
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::PoisonError;
+
+use cozo::DbInstance;
+use flutter_rust_bridge::frb;
+
+use crate::api::callback::reads_relation;
+use crate::api::schema::cozo_list_relations;
+use crate::api::simple::{cozo_run_query, CozoDb};
+
+/// Result of a cached query.
+struct Entry {
+    json: String,
+    /// Stored relations the query reads.
+    relations: Vec<String>,
+    /// Value of the cache clock when last returned, for eviction.
+    used: u64,
+}
+
+/// A change callback on a relation read by cached queries.
+struct Watch {
+    id: u32,
+    /// Drains the pending change events, returning whether there were any.
+    drain: Box<dyn Fn() -> bool + Send>,
+}
+
+/// Results of read-only queries, shared by every handle to a database.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct QueryCache {
+    /// Entries kept before evicting the least recently used; 0 disables the
+    /// cache.
+    capacity: usize,
+    /// By hashes of the script and of the parameters JSON.
+    entries: HashMap<(u64, u64), Entry>,
+    watches: HashMap<String, Watch>,
+    clock: u64,
+    /// Incremented whenever a watched relation is written.
+    generation: u64,
+}
+
+impl QueryCache {
+    /// Drop the entries reading a relation written since the last call.
+    fn invalidate(&mut self) {
+        let changed: Vec<&String> = self
+            .watches
+            .iter()
+            .filter(|(_, watch)| (watch.drain)())
+            .map(|(relation, _)| relation)
+            .collect();
+        if changed.is_empty() {
+            return;
+        }
+        self.entries
+            .retain(|_, entry| !entry.relations.iter().any(|r| changed.contains(&r)));
+        self.generation += 1;
+    }
+
+    fn watch(&mut self, inner: &DbInstance, relation: &str) {
+        if self.watches.contains_key(relation) {
+            return;
+        }
+        let (id, receiver) = inner.register_callback(relation, None);
+        let drain = Box::new(move || receiver.try_iter().count() > 0);
+        self.watches
+            .insert(relation.to_string(), Watch { id, drain });
+    }
+
+    fn clear(&mut self, inner: Option<&DbInstance>) {
+        self.entries.clear();
+        for (_, watch) in self.watches.drain() {
+            if let Some(inner) = inner {
+                inner.unregister_callback(watch.id);
+            }
+        }
+    }
+}
+
+/// Keep the results of queries run with [`cozo_run_query_cached`], so
+/// repeated identical reads, e.g. from several widgets, do not run the
+/// query again.
+///
+/// - `capacity`: number of results kept, least recently used dropped first
+///
+/// A result is dropped as soon as a script commits a write to a stored
+/// relation the query reads, i.e. one it names with `*relation` or
+/// `~relation:index`. Writes that do not go through a script, such as
+/// `cozo_import_relations` and restores, are not noticed: call
+/// [`cozo_clear_query_cache`] after them. Calling this again changes the
+/// capacity and keeps the results that fit.
+pub fn cozo_enable_query_cache(db: &CozoDb, capacity: u32) -> anyhow::Result<()> {
+    let inner = db.instance()?;
+    let mut cache = db
+        .query_cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if capacity == 0 {
+        cache.clear(Some(&inner));
+    }
+    cache.capacity = capacity as usize;
+    while cache.entries.len() > cache.capacity {
+        evict(&mut cache);
+    }
+    Ok(())
+}
+
+/// Stop caching query results and drop the cached ones.
+#[frb(sync)]
+pub fn cozo_disable_query_cache(db: &CozoDb) {
+    let inner = db.instance().ok();
+    let mut cache = db
+        .query_cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    cache.clear(inner.as_ref());
+    cache.capacity = 0;
+}
+
+/// Drop every cached query result, keeping the cache enabled.
+#[frb(sync)]
+pub fn cozo_clear_query_cache(db: &CozoDb) {
+    db.query_cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entries
+        .clear();
+}
+
+/// Run a read-only query like `cozo_run_query`, returning the cached result
+/// of the same script with the same `params_json` if there is one.
+///
+/// The script is run immutable. Without [`cozo_enable_query_cache`] every
+/// call runs the query. A cached result is returned exactly as it was
+/// first, including its `took`.
+pub fn cozo_run_query_cached(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+) -> anyhow::Result<String> {
+    let key = (digest(&script), digest(&params_json));
+    let inner = {
+        let mut cache = db
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if cache.capacity == 0 {
+            drop(cache);
+            return cozo_run_query(db, script, params_json, true);
+        }
+        cache.invalidate();
+        cache.clock += 1;
+        let clock = cache.clock;
+        let hit = cache.entries.get_mut(&key).map(|entry| {
+            entry.used = clock;
+            entry.json.clone()
+        });
+        db.metrics
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .record_cache_lookup(hit.is_some());
+        if let Some(json) = hit {
+            return Ok(json);
+        }
+        db.instance()?
+    };
+
+    let relations: Vec<String> = cozo_list_relations(db)?
+        .into_iter()
+        .map(|rel| rel.name)
+        .filter(|name| reads_relation(&script, name))
+        .collect();
+    // Watch before running, so that a write committed meanwhile keeps the
+    // result out of the cache.
+    let generation = {
+        let mut cache = db
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        for relation in &relations {
+            cache.watch(&inner, relation);
+        }
+        cache.invalidate();
+        cache.generation
+    };
+    let json = cozo_run_query(db, script, params_json, true)?;
+
+    let mut cache = db
+        .query_cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    cache.invalidate();
+    if cache.capacity == 0 || cache.generation != generation {
+        return Ok(json);
+    }
+    if cache.entries.len() >= cache.capacity {
+        evict(&mut cache);
+    }
+    let used = cache.clock;
+    cache.entries.insert(
+        key,
+        Entry {
+            json: json.clone(),
+            relations,
+            used,
+        },
+    );
+    Ok(json)
+}
+
+fn evict(cache: &mut QueryCache) {
+    let oldest = cache
+        .entries
+        .iter()
+        .min_by_key(|(_, entry)| entry.used)
+        .map(|(key, _)| *key);
+    if let Some(key) = oldest {
+        cache.entries.remove(&key);
+    }
+}
+
+fn digest(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    fn open() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create a {x: Int}").unwrap();
+        run_write_op(&db, ":create b {x: Int}").unwrap();
+        db
+    }
+
+    fn cached(db: &CozoDb, script: &str) -> String {
+        cozo_run_query_cached(db, script.into(), "{}".into()).unwrap()
+    }
+
+    fn entries(db: &CozoDb) -> usize {
+        db.query_cache.lock().unwrap().entries.len()
+    }
+
+    #[test]
+    fn writes_drop_the_results_reading_the_relation() {
+        let db = open();
+        cozo_enable_query_cache(&db, 10).unwrap();
+        let (read_a, read_b) = ("?[x] := *a{x}", "?[x] := *b{x}");
+        let first = cached(&db, read_a);
+        cached(&db, read_b);
+        assert_eq!(cached(&db, read_a), first);
+        assert_eq!(entries(&db), 2);
+
+        run_write_op(&db, "?[x] <- [[1]] :put a {x}").unwrap();
+        let after = cached(&db, read_a);
+        assert!(after.contains("[[1]]"), "{}", after);
+        assert_eq!(entries(&db), 2);
+
+        cozo_clear_query_cache(&db);
+        assert_eq!(entries(&db), 0);
+        cozo_disable_query_cache(&db);
+        cached(&db, read_a);
+        assert_eq!(entries(&db), 0);
+        assert!(db.query_cache.lock().unwrap().watches.is_empty());
+    }
+
+    #[test]
+    fn least_recently_used_results_are_evicted() {
+        let db = open();
+        cozo_enable_query_cache(&db, 2).unwrap();
+        cached(&db, "?[x] <- [[1]]");
+        cached(&db, "?[x] <- [[2]]");
+        cached(&db, "?[x] <- [[1]]");
+        cached(&db, "?[x] <- [[3]]");
+        let cache = db.query_cache.lock().unwrap();
+        assert_eq!(cache.entries.len(), 2);
+        assert!(cache
+            .entries
+            .contains_key(&(digest("?[x] <- [[1]]"), digest("{}"))));
+        drop(cache);
+
+        cozo_enable_query_cache(&db, 1).unwrap();
+        assert_eq!(entries(&db), 1);
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use cozo::ScriptMutability;
+use flutter_rust_bridge::frb;
+
+use crate::api::error::catch_panic;
+use crate::api::running::epoch_secs;
+use crate::api::schema::{
+    column_names, cozo_create_relation, cozo_list_relations, relation_columns, ColumnDef,
+};
+use crate::api::simple::{cozo_close_db, cozo_open_db, query_error, CozoDb};
+use crate::api::storage::sqlite_file_query;
+use crate::api::triggers::{cozo_list_triggers, cozo_set_triggers};
+
+/// What [`cozo_open_or_recover`] does with a database that cannot be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryPolicy {
+    /// Fail with the corruption error, leaving the files untouched.
+    Fail,
+    /// Copy every relation that can still be read into a fresh database,
+    /// which replaces the corrupt one.
+    Salvage,
+    /// Replace the corrupt database with an empty one.
+    Reset,
+}
+
+/// What [`cozo_open_or_recover`] found and did.
+#[derive(Debug, Clone)]
+pub struct RecoveryReport {
+    /// Whether the database was corrupt and has been replaced.
+    pub recovered: bool,
+    /// Why the database was considered corrupt, `None` if it was not.
+    pub reason: Option<String>,
+    /// Where the corrupt database was moved to, kept for manual inspection.
+    pub corrupt_path: Option<String>,
+    /// Relations copied with all their rows.
+    pub relations_recovered: Vec<String>,
+    pub rows_recovered: u64,
+    /// Relations whose rows could not be read. They exist in the new
+    /// database, but empty.
+    pub relations_lost: Vec<String>,
+    /// Indices that were not recreated, as `relation:index`. Recreate them
+    /// with the calls that made them.
+    pub indices_dropped: Vec<String>,
+}
+
+/// A database opened by [`cozo_open_or_recover`].
+#[frb(non_opaque)]
+pub struct RecoveredDb {
+    pub db: CozoDb,
+    pub report: RecoveryReport,
+}
+
+/// Open a database, replacing it according to `policy` if it is corrupt.
+///
+/// - `engine`, `path`, `options`: as for `cozo_open_db`
+///
+/// A sqlite database is corrupt if `PRAGMA quick_check` finds a problem, and
+/// a rocksdb database if opening it fails with a corruption error. Salvaged relations
+/// keep their columns and triggers, but not column defaults or indices, and
+/// their rows are copied without running triggers. The replacement is built
+/// next to the original and moved into place only when complete; the corrupt
+/// files are renamed to `<path>.corrupt-<unix time>`.
+///
+/// Fails if opening fails for another reason, such as a missing directory
+/// or a locked file, or with the corruption error under
+/// [`RecoveryPolicy::Fail`].
+pub fn cozo_open_or_recover(
+    engine: String,
+    path: String,
+    options: String,
+    policy: RecoveryPolicy,
+) -> anyhow::Result<RecoveredDb> {
+    let mut report = RecoveryReport {
+        recovered: false,
+        reason: None,
+        corrupt_path: None,
+        relations_recovered: vec![],
+        rows_recovered: 0,
+        relations_lost: vec![],
+        indices_dropped: vec![],
+    };
+    // cozo panics instead of failing on some damaged files.
+    let opened = catch_panic(|| cozo_open_db(engine.clone(), path.clone(), options.clone()));
+    let (src, reason) = match opened {
+        Ok(db) => match corruption(&db) {
+            None => return Ok(RecoveredDb { db, report }),
+            Some(reason) => (Some(db), reason),
+        },
+        Err(e) => match open_failure_corruption(&engine, &path, &e) {
+            Some(reason) => (None, reason),
+            None => return Err(e),
+        },
+    };
+    if policy == RecoveryPolicy::Fail {
+        anyhow::bail!("Database is corrupt: {}", reason);
+    }
+    report.recovered = true;
+    report.reason = Some(reason);
+
+    let replacement = format!("{}.recovering", path);
+    remove_files(&engine, &replacement).map_err(|e| anyhow::anyhow!("Recovery failed: {}", e))?;
+    let dst = cozo_open_db(engine.clone(), replacement.clone(), options.clone())?;
+    if let (Some(src), RecoveryPolicy::Salvage) = (&src, policy) {
+        salvage(src, &dst, &mut report)?;
+    }
+    cozo_close_db(&dst);
+    if let Some(src) = &src {
+        cozo_close_db(src);
+    }
+
+    let corrupt = format!("{}.corrupt-{}", path, epoch_secs() as u64);
+    move_files(&engine, &path, &corrupt)
+        .and_then(|_| move_files(&engine, &replacement, &path))
+        .map_err(|e| anyhow::anyhow!("Recovery failed: {}", e))?;
+    report.corrupt_path = Some(corrupt);
+    Ok(RecoveredDb {
+        db: cozo_open_db(engine, path, options)?,
+        report,
+    })
+}
+
+/// Why an open database cannot be trusted, if it cannot.
+fn corruption(db: &CozoDb) -> Option<String> {
+    if db.engine != "sqlite" {
+        return None;
+    }
+    quick_check(&db.path)
+}
+
+/// Whether a database that failed to open is corrupt, as opposed to locked,
+/// missing or misconfigured, and why.
+fn open_failure_corruption(engine: &str, path: &str, error: &anyhow::Error) -> Option<String> {
+    if !Path::new(path).exists() {
+        return None;
+    }
+    match engine {
+        // A file that passes the check failed for another reason.
+        "sqlite" => quick_check(path).map(|reason| format!("{} ({})", error, reason)),
+        "rocksdb" if error.to_string().contains("Corruption") => Some(error.to_string()),
+        _ => None,
+    }
+}
+
+/// Problems found by `PRAGMA quick_check` on a sqlite file, if any.
+fn quick_check(path: &str) -> Option<String> {
+    match sqlite_file_query(path, "Quick check", "PRAGMA quick_check") {
+        Ok(rows) if rows.iter().all(|row| row == "ok") => None,
+        Ok(rows) => Some(rows.join("; ")),
+        Err(e) => Some(e.to_string()),
+    }
+}
+
+/// Copy every readable relation of `src` into the empty database `dst`.
+fn salvage(src: &CozoDb, dst: &CozoDb, report: &mut RecoveryReport) -> anyhow::Result<()> {
+    let relations = catch_panic(|| cozo_list_relations(src)).unwrap_or_default();
+    let (indices, relations): (Vec<_>, Vec<_>) = relations
+        .into_iter()
+        .partition(|rel| rel.access_level == "index");
+    report.indices_dropped = indices.into_iter().map(|rel| rel.name).collect();
+
+    let inner = src.instance()?;
+    let target = dst.instance()?;
+    for relation in relations {
+        let name = relation.name;
+        let Ok(columns) = catch_panic(|| relation_columns(&inner, &name)) else {
+            report.relations_lost.push(name);
+            continue;
+        };
+        let defs = columns.iter().map(ColumnDef::from).collect();
+        cozo_create_relation(dst, name.clone(), defs)
+            .map_err(|e| anyhow::anyhow!("Recovery failed: {}", e))?;
+        if let Ok(triggers) = catch_panic(|| cozo_list_triggers(src, name.clone())) {
+            cozo_set_triggers(dst, name.clone(), triggers)
+                .map_err(|e| anyhow::anyhow!("Recovery failed: {}", e))?;
+        }
+
+        let names = column_names(&columns);
+        let script = format!("?[{}] := *{}{{{}}}", names, name, names);
+        let rows = catch_panic(|| {
+            inner
+                .run_script(&script, Default::default(), ScriptMutability::Immutable)
+                .map_err(|err| query_error(err, &script))
+        });
+        match rows {
+            Ok(rows) => {
+                let count = rows.rows.len() as u64;
+                catch_panic(|| target.import_relations(BTreeMap::from([(name.clone(), rows)])))
+                    .map_err(|e| anyhow::anyhow!("Recovery failed: {}", e))?;
+                report.rows_recovered += count;
+                report.relations_recovered.push(name);
+            }
+            Err(_) => report.relations_lost.push(name),
+        }
+    }
+    Ok(())
+}
+
+/// Files making up a database at `path`: the directory for rocksdb, the
+/// file with its journals for sqlite.
+fn database_files(engine: &str, path: &str) -> Vec<String> {
+    if engine == "sqlite" {
+        ["", "-wal", "-shm", "-journal"]
+            .iter()
+            .map(|suffix| format!("{}{}", path, suffix))
+            .collect()
+    } else {
+        vec![path.to_string()]
+    }
+}
+
+fn move_files(engine: &str, from: &str, to: &str) -> std::io::Result<()> {
+    for (src, dst) in database_files(engine, from)
+        .into_iter()
+        .zip(database_files(engine, to))
+    {
+        if Path::new(&src).exists() {
+            std::fs::rename(src, dst)?;
+        }
+    }
+    Ok(())
+}
+
+fn remove_files(engine: &str, path: &str) -> std::io::Result<()> {
+    for file in database_files(engine, path) {
+        let file = Path::new(&file);
+        if file.is_dir() {
+            std::fs::remove_dir_all(file)?;
+        } else if file.exists() {
+            std::fs::remove_file(file)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::vector::run_write_op;
+
+    /// A fresh directory for a database file, removed on drop.
+    struct Dir(std::path::PathBuf);
+
+    impl Dir {
+        fn new(name: &str) -> Self {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos();
+            let name = format!("cozo_dart_{}_{}_{}", name, std::process::id(), nanos);
+            let dir = std::env::temp_dir().join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            Dir(dir)
+        }
+
+        fn db(&self) -> String {
+            self.0.join("app.db").to_string_lossy().into_owned()
+        }
+    }
+
+    impl Drop for Dir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn open(path: &str, policy: RecoveryPolicy) -> anyhow::Result<RecoveredDb> {
+        cozo_open_or_recover("sqlite".into(), path.into(), "{}".into(), policy)
+    }
+
+    fn count(db: &CozoDb) -> i64 {
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(
+                "?[count(id)] := *items{id}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap();
+        rows.rows[0][0].get_int().unwrap()
+    }
+
+    #[test]
+    fn healthy_databases_are_opened_as_they_are() {
+        let dir = Dir::new("recovery_healthy");
+        let db = cozo_open_db("sqlite".into(), dir.db(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        run_write_op(&db, "?[id] <- [[1], [2]] :put items {id}").unwrap();
+        cozo_close_db(&db);
+
+        let opened = open(&dir.db(), RecoveryPolicy::Reset).unwrap();
+        assert!(!opened.report.recovered);
+        assert_eq!(opened.report.reason, None);
+        assert_eq!(count(&opened.db), 2);
+        cozo_close_db(&opened.db);
+    }
+
+    #[test]
+    fn unreadable_files_are_moved_aside_and_replaced() {
+        let dir = Dir::new("recovery_unreadable");
+        let garbage = b"not a database file".repeat(256);
+        std::fs::write(dir.db(), &garbage).unwrap();
+
+        assert!(open(&dir.db(), RecoveryPolicy::Fail).is_err());
+        assert_eq!(std::fs::read(dir.db()).unwrap(), garbage);
+
+        let opened = open(&dir.db(), RecoveryPolicy::Salvage).unwrap();
+        let report = opened.report;
+        assert!(report.recovered);
+        assert!(report.reason.is_some());
+        assert!(report.relations_recovered.is_empty());
+        let corrupt = report.corrupt_path.unwrap();
+        assert_eq!(std::fs::read(corrupt).unwrap(), garbage);
+        assert!(!Path::new(&format!("{}.recovering", dir.db())).exists());
+        run_write_op(&opened.db, ":create items {id: Int}").unwrap();
+        cozo_close_db(&opened.db);
+    }
+
+    #[test]
+    fn salvage_copies_rows_and_triggers_but_not_indices() {
+        let src = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&src, ":create items {id: Int => name: String}").unwrap();
+        run_write_op(&src, ":create log {id: Int}").unwrap();
+        run_write_op(&src, "::index create items:by_name {name}").unwrap();
+        run_write_op(
+            &src,
+            "::set_triggers items on put { ?[id] := _new[id, _] :put log {id} }",
+        )
+        .unwrap();
+        run_write_op(
+            &src,
+            "?[id, name] <- [[1, 'a'], [2, 'b']] :put items {id => name}",
+        )
+        .unwrap();
+
+        let dst = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let mut report = RecoveryReport {
+            recovered: true,
+            reason: None,
+            corrupt_path: None,
+            relations_recovered: vec![],
+            rows_recovered: 0,
+            relations_lost: vec![],
+            indices_dropped: vec![],
+        };
+        salvage(&src, &dst, &mut report).unwrap();
+        assert_eq!(report.relations_recovered, ["items", "log"]);
+        assert_eq!(report.rows_recovered, 4);
+        assert_eq!(report.indices_dropped, ["items:by_name"]);
+        assert!(report.relations_lost.is_empty());
+        assert_eq!(count(&dst), 2);
+        let triggers = cozo_list_triggers(&dst, "items".into()).unwrap();
+        assert_eq!(triggers.on_put.len(), 1);
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use flutter_rust_bridge::frb;
+
+use crate::api::simple::{cozo_close_db, cozo_open_db, CozoDb};
+
+/// A database opened with [`cozo_open_named`].
+struct NamedDb {
+    db: CozoDb,
+    engine: String,
+    path: String,
+    /// Opens and gets not yet matched by a [`cozo_close_named`].
+    refs: u32,
+}
+
+fn named() -> &'static Mutex<HashMap<String, NamedDb>> {
+    static NAMED: OnceLock<Mutex<HashMap<String, NamedDb>>> = OnceLock::new();
+    NAMED.get_or_init(Default::default)
+}
+
+fn tokens() -> &'static Mutex<HashMap<u64, CozoDb>> {
+    static TOKENS: OnceLock<Mutex<HashMap<u64, CozoDb>>> = OnceLock::new();
+    TOKENS.get_or_init(Default::default)
+}
+
+/// Open a database under a process-wide name, or share it if that name is
+/// already open.
+///
+/// - `name`: registry key, e.g. "main"
+/// - `engine`, `path`, `options`: as for `cozo_open_db`, used only by the
+///   first open of `name`
+///
+/// Every open or [`cozo_get_named`] takes a reference that
+/// [`cozo_close_named`] gives back. All returned handles share one
+/// underlying instance. Opening an existing name with a different engine or
+/// path fails.
+///
+/// Returns a handle to the named database.
+#[frb(sync)]
+pub fn cozo_open_named(
+    name: String,
+    engine: String,
+    path: String,
+    options: String,
+) -> anyhow::Result<CozoDb> {
+    let mut named = named().lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(entry) = named.get_mut(&name) {
+        if entry.engine != engine || entry.path != path {
+            anyhow::bail!(
+                "Database {} is already open with engine {} at {:?}",
+                name,
+                entry.engine,
+                entry.path
+            );
+        }
+        entry.refs += 1;
+        return Ok(entry.db.share());
+    }
+    let db = cozo_open_db(engine.clone(), path.clone(), options)?;
+    let handle = db.share();
+    named.insert(
+        name,
+        NamedDb {
+            db,
+            engine,
+            path,
+            refs: 1,
+        },
+    );
+    Ok(handle)
+}
+
+/// Get another handle to a database opened with [`cozo_open_named`].
+///
+/// Takes a reference like [`cozo_open_named`], to be given back with
+/// [`cozo_close_named`].
+#[frb(sync)]
+pub fn cozo_get_named(name: String) -> anyhow::Result<CozoDb> {
+    let mut named = named().lock().unwrap_or_else(PoisonError::into_inner);
+    let entry = named
+        .get_mut(&name)
+        .ok_or_else(|| anyhow::anyhow!("No database is open under the name {}", name))?;
+    entry.refs += 1;
+    Ok(entry.db.share())
+}
+
+/// Give back a reference taken by [`cozo_open_named`] or [`cozo_get_named`].
+///
+/// When the last reference is given back the database is closed, which
+/// closes every handle to it, and the name becomes free.
+///
+/// Returns the number of references still held.
+#[frb(sync)]
+pub fn cozo_close_named(name: String) -> anyhow::Result<u32> {
+    let mut named = named().lock().unwrap_or_else(PoisonError::into_inner);
+    let entry = named
+        .get_mut(&name)
+        .ok_or_else(|| anyhow::anyhow!("No database is open under the name {}", name))?;
+    entry.refs -= 1;
+    if entry.refs > 0 {
+        return Ok(entry.refs);
+    }
+    if let Some(entry) = named.remove(&name) {
+        cozo_close_db(&entry.db);
+    }
+    Ok(0)
+}
+
+/// Create a token for the database that can be sent to another isolate.
+///
+/// Opaque handles are bound to the isolate that created them, but the token
+/// is a plain integer: pass it through a `SendPort` or isolate arguments and
+/// call [`cozo_attach`] on the other side. Handles attached from the token
+/// share the same instance and can be used concurrently.
+///
+/// The token stays valid until [`cozo_release_token`]. Closing the database
+/// closes every attached handle too.
+#[frb(sync)]
+pub fn cozo_share_token(db: &CozoDb) -> u64 {
+    static NEXT_TOKEN: AtomicU64 = AtomicU64::new(1);
+
+    let token = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed);
+    tokens()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(token, db.share());
+    token
+}
+
+/// Get a handle in the current isolate from a token made by [`cozo_share_token`].
+#[frb(sync)]
+pub fn cozo_attach(token: u64) -> anyhow::Result<CozoDb> {
+    tokens()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&token)
+        .map(CozoDb::share)
+        .ok_or_else(|| anyhow::anyhow!("Unknown database token {}", token))
+}
+
+/// Invalidate a token. Handles already attached from it keep working.
+///
+/// Returns false if the token was unknown or already released.
+#[frb(sync)]
+pub fn cozo_release_token(token: u64) -> bool {
+    tokens()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&token)
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::vector::run_write_op;
+
+    #[test]
+    fn named_databases_are_shared_until_the_last_close() {
+        let name = "registry_test_shared".to_string();
+        let first =
+            cozo_open_named(name.clone(), "mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&first, ":create items {id: Int}").unwrap();
+        let second =
+            cozo_open_named(name.clone(), "mem".into(), String::new(), "{}".into()).unwrap();
+        let third = cozo_get_named(name.clone()).unwrap();
+        run_write_op(&third, "?[id] <- [[1]] :put items {id}").unwrap();
+        assert!(run_write_op(&second, "?[id] <- [[2]] :put items {id}").is_ok());
+
+        assert_eq!(cozo_close_named(name.clone()).unwrap(), 2);
+        assert_eq!(cozo_close_named(name.clone()).unwrap(), 1);
+        assert!(first.instance().is_ok());
+        assert_eq!(cozo_close_named(name.clone()).unwrap(), 0);
+        assert!(first.instance().is_err() && third.instance().is_err());
+        assert!(cozo_get_named(name.clone()).is_err());
+        assert!(cozo_close_named(name).is_err());
+    }
+
+    #[test]
+    fn names_bind_to_one_engine_and_path() {
+        let name = "registry_test_mismatch".to_string();
+        cozo_open_named(name.clone(), "mem".into(), String::new(), "{}".into()).unwrap();
+        let err = cozo_open_named(
+            name.clone(),
+            "sqlite".into(),
+            "other.db".into(),
+            "{}".into(),
+        )
+        .err()
+        .unwrap();
+        assert!(
+            err.to_string().contains("already open with engine mem"),
+            "{}",
+            err
+        );
+        assert_eq!(cozo_close_named(name.clone()).unwrap(), 0);
+
+        let err = cozo_open_named(name.clone(), "nope".into(), String::new(), "{}".into());
+        assert!(err.is_err());
+        assert!(cozo_get_named(name).is_err());
+    }
+
+    #[test]
+    fn tokens_attach_until_released() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        let token = cozo_share_token(&db);
+        assert_ne!(cozo_share_token(&db), token);
+
+        let attached = cozo_attach(token).unwrap();
+        run_write_op(&attached, "?[id] <- [[1]] :put items {id}").unwrap();
+        assert!(cozo_release_token(token));
+        assert!(!cozo_release_token(token));
+        assert!(cozo_attach(token).is_err());
+        assert!(attached.instance().is_ok());
+
+        cozo_close_db(&db);
+        assert!(attached.instance().is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+use crate::api::batch::{run_atomic, ScriptWithParams};
+use crate::api::simple::{cozo_run_query, CozoDb};
+use crate::api::value::QueryResult;
+
+/// Error messages of writes that failed only because another write got in
+/// first, and may succeed when run again: RocksDB's optimistic transaction
+/// conflicts, and SQLite's write lock.
+const CONFLICT_MARKERS: [&str; 5] = [
+    "Resource busy",
+    "Operation failed. Try again",
+    "Deadlock",
+    "database is locked",
+    "database table is locked",
+];
+
+/// How [`cozo_run_with_retry`] and [`cozo_run_tx_with_retry`] retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Runs in total, including the first, at least 1.
+    pub max_attempts: u32,
+    /// Upper bound of the wait before the first retry; it doubles for each
+    /// retry after that.
+    pub initial_delay_ms: u64,
+    /// Upper bound of a single wait.
+    pub max_delay_ms: u64,
+}
+
+impl RetryPolicy {
+    /// The wait before retry `retry`, counting from 0: a random share
+    /// between half and all of the backoff, so that writers that conflicted
+    /// once do not retry in lockstep.
+    fn delay(&self, retry: u32) -> Duration {
+        let backoff = self
+            .initial_delay_ms
+            .saturating_mul(1 << retry.min(32))
+            .min(self.max_delay_ms);
+        let random = RandomState::new().hash_one(retry) % 1000;
+        Duration::from_millis(backoff / 2 + backoff / 2 * random / 1000)
+    }
+}
+
+/// Run a script like `cozo_run_query`, running it again with a jittered
+/// backoff while it fails with a write conflict.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// A conflict means another write committed first (RocksDB) or held the
+/// write lock (SQLite); the failed run wrote nothing, so running it again is
+/// safe. Other errors, including failed `:ensure` checks, are returned right
+/// away. After the last attempt the conflict error is returned.
+pub fn cozo_run_with_retry(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    policy: RetryPolicy,
+) -> anyhow::Result<String> {
+    with_retry(policy, || {
+        cozo_run_query(db, script.clone(), params_json.clone(), false)
+    })
+}
+
+/// Run `scripts` in one write transaction like an atomic `cozo_run_batch`,
+/// running the whole transaction again with a jittered backoff while it
+/// fails with a write conflict, as in [`cozo_run_with_retry`].
+///
+/// Each attempt starts a fresh transaction, so the scripts are run again
+/// against the data as the conflicting write left it. Returns one
+/// [`QueryResult`] per script, from the attempt that committed.
+pub fn cozo_run_tx_with_retry(
+    db: &CozoDb,
+    scripts: Vec<ScriptWithParams>,
+    policy: RetryPolicy,
+) -> anyhow::Result<Vec<QueryResult>> {
+    with_retry(policy, || run_atomic(db, scripts.clone()))
+}
+
+fn with_retry<T>(
+    policy: RetryPolicy,
+    mut run: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    if policy.max_attempts == 0 {
+        anyhow::bail!("Retry needs max_attempts of at least 1");
+    }
+    let mut retry = 0;
+    loop {
+        match run() {
+            Err(err) if retry + 1 < policy.max_attempts && is_conflict(&err) => {
+                std::thread::sleep(policy.delay(retry));
+                retry += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_conflict(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        let message = cause.to_string();
+        CONFLICT_MARKERS
+            .iter()
+            .any(|marker| message.contains(marker))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+
+    const POLICY: RetryPolicy = RetryPolicy {
+        max_attempts: 3,
+        initial_delay_ms: 4,
+        max_delay_ms: 10,
+    };
+
+    #[test]
+    fn delays_are_jittered_within_the_backoff() {
+        for retry in 0..5 {
+            let backoff = (4u64 << retry).min(10);
+            let delay = POLICY.delay(retry).as_millis() as u64;
+            assert!((backoff / 2..=backoff).contains(&delay), "{}", delay);
+        }
+    }
+
+    #[test]
+    fn only_conflicts_are_retried() {
+        let run = |failures: u32, error: &'static str| {
+            let mut runs = 0;
+            let result = with_retry(POLICY, || {
+                runs += 1;
+                match runs <= failures {
+                    true => Err(anyhow::anyhow!(error)),
+                    false => Ok(()),
+                }
+            });
+            (runs, result.is_ok())
+        };
+        assert_eq!(run(2, "Resource busy"), (3, true));
+        assert_eq!(run(3, "database is locked"), (3, false));
+        assert_eq!(run(1, "no such relation"), (1, false));
+        let none = RetryPolicy {
+            max_attempts: 0,
+            ..POLICY
+        };
+        assert!(with_retry(none, || Ok(())).is_err());
+    }
+
+    #[test]
+    fn scripts_run_through_the_retry_calls() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_run_with_retry(&db, ":create t {x: Int}".into(), "{}".into(), POLICY).unwrap();
+        let put = |x: i64| ScriptWithParams {
+            script: "?[x] <- [[$x]] :put t {x}".into(),
+            params: [("x".to_string(), crate::api::value::CozoValue::Int(x))].into(),
+        };
+        let results = cozo_run_tx_with_retry(&db, vec![put(1), put(2)], POLICY).unwrap();
+        assert!(results.iter().all(|r| r.ok));
+        let out = cozo_run_with_retry(&db, "?[x] := *t{x}".into(), "{}".into(), POLICY).unwrap();
+        assert!(out.contains("[[1],[2]]"), "{}", out);
+    }
+}
@@ -0,0 +1,922 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, PoisonError};
+
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability};
+use flutter_rust_bridge::frb;
+
+use crate::api::error::catch_panic;
+use crate::api::query_cache::cozo_clear_query_cache;
+use crate::api::schema::relation_columns;
+use crate::api::simple::{parse_params, query_error, CozoDb};
+use crate::api::temporal::{atom_end, skip_string};
+
+/// Prefix of the rules standing in for relations with a row policy.
+const RULE_PREFIX: &str = "cozo_rls_";
+
+/// A relation's row policy, see [`cozo_set_row_policy`].
+#[derive(Debug, Clone)]
+pub struct RowPolicy {
+    pub relation: String,
+    pub predicate: String,
+}
+
+struct Policy {
+    predicate: String,
+    /// Column names of the relation when the policy was set, keys first.
+    columns: Vec<String>,
+}
+
+/// Row policies and their parameters, shared by every handle to a database.
+#[derive(Default)]
+pub(crate) struct RowPolicies {
+    policies: BTreeMap<String, Policy>,
+    /// Bound in every script reading a relation with a policy, over the
+    /// script's own parameters of the same name.
+    params: BTreeMap<String, DataValue>,
+    /// Incremented whenever `policies` or `params` change, to tell when a
+    /// [`RewrittenScript`] or a result read under them is stale.
+    generation: u64,
+}
+
+/// A script as [`apply_row_policies`] rewrote it, kept to run again.
+pub(crate) struct RewrittenScript {
+    generation: u64,
+    script: String,
+    /// Whether the rewrite changed the script, and so uses the policy
+    /// parameters.
+    rewritten: bool,
+}
+
+/// Only let queries see the rows of `relation` for which `predicate` holds,
+/// e.g. `tenant_id == $current_tenant`, so that a query that forgets the
+/// filter cannot read rows of another account.
+///
+/// - `predicate`: a CozoScript condition over the relation's column names;
+///   parameters it uses are set with [`cozo_set_policy_params`]
+///
+/// Every script run through the query calls (`cozo_run_query` and its
+/// variants, including the encoded, Arrow and file ones, batches,
+/// transactions, prepared queries, streams, cursors and watches) has each
+/// `*relation{..}` and `*relation[..]` atom replaced by a rule reading only
+/// the allowed rows. Scripts the rewrite cannot make safe fail instead:
+/// atoms with an `@` clause, direct reads or searches of the relation's
+/// indices, and imperative scripts. System ops other than `::explain` are
+/// run as they are, as are writes: a policy limits which rows are read, not
+/// which are written. What a write's `:returning` gives back is limited to
+/// the rows the policy allows, besides the `requested` keys of a `:rm`.
+/// Watches apply the policies in effect each time they run again, and a
+/// cursor fails to read more pages once the policies or their parameters
+/// change.
+///
+/// The calls that read rows without a script of the app's also keep to the
+/// policy: `cozo_get_row`, the full-text, LSH and vector searches (which
+/// may then return fewer than `k` hits), both relation exports, and change
+/// streams, whose events leave out the rows the policy hides and are not
+/// sent when no row is left.
+///
+/// Policies are kept in memory for the handle and the handles sharing its
+/// database, not in the database. Setting one again replaces it; set it
+/// again after changing the relation's columns. Cached query results are
+/// dropped.
+pub fn cozo_set_row_policy(db: &CozoDb, relation: String, predicate: String) -> anyhow::Result<()> {
+    if relation.contains(':') {
+        anyhow::bail!(
+            "Row policies apply to stored relations, not to {}",
+            relation
+        );
+    }
+    let columns = relation_columns(&db.instance()?, &relation)?
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    let mut policies = db
+        .row_policies
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    policies
+        .policies
+        .insert(relation, Policy { predicate, columns });
+    policies.generation += 1;
+    drop(policies);
+    cozo_clear_query_cache(db);
+    Ok(())
+}
+
+/// Let queries read every row of `relation` again.
+///
+/// Returns whether the relation had a policy.
+#[frb(sync)]
+pub fn cozo_remove_row_policy(db: &CozoDb, relation: String) -> bool {
+    let mut policies = db
+        .row_policies
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let removed = policies.policies.remove(&relation).is_some();
+    policies.generation += 1;
+    drop(policies);
+    cozo_clear_query_cache(db);
+    removed
+}
+
+/// The row policies in effect, by relation name.
+#[frb(sync)]
+pub fn cozo_list_row_policies(db: &CozoDb) -> Vec<RowPolicy> {
+    db.row_policies
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .policies
+        .iter()
+        .map(|(relation, policy)| RowPolicy {
+            relation: relation.clone(),
+            predicate: policy.predicate.clone(),
+        })
+        .collect()
+}
+
+/// Set the parameters row policies use, e.g. '{"current_tenant": 42}' when
+/// the user signs in, replacing the previous ones.
+///
+/// They are bound in every script reading a relation with a policy and take
+/// precedence over the script's own parameters of the same name, so a query
+/// cannot widen what it reads by passing them itself. Cached query results
+/// are dropped.
+#[frb(sync)]
+pub fn cozo_set_policy_params(db: &CozoDb, params_json: String) -> anyhow::Result<()> {
+    let params = parse_params(&params_json)?;
+    let mut policies = db
+        .row_policies
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    policies.params = params;
+    policies.generation += 1;
+    drop(policies);
+    cozo_clear_query_cache(db);
+    Ok(())
+}
+
+/// `script` rewritten to read only the rows the policies allow, binding
+/// their parameters into `params`.
+pub(crate) fn apply_row_policies(
+    policies: &Mutex<RowPolicies>,
+    script: &str,
+    params: &mut BTreeMap<String, DataValue>,
+) -> anyhow::Result<String> {
+    apply_row_policies_cached(policies, script, &mut None, params)
+}
+
+/// `script` rewritten as by [`apply_row_policies`], reusing `cached` unless
+/// the policies changed since it was rewritten.
+pub(crate) fn apply_row_policies_cached(
+    policies: &Mutex<RowPolicies>,
+    script: &str,
+    cached: &mut Option<RewrittenScript>,
+    params: &mut BTreeMap<String, DataValue>,
+) -> anyhow::Result<String> {
+    let policies = policies.lock().unwrap_or_else(PoisonError::into_inner);
+    let entry = match cached.take() {
+        Some(entry) if entry.generation == policies.generation => entry,
+        _ => {
+            let rewritten = match policies.policies.is_empty() {
+                true => script.to_string(),
+                false => rewrite(script, &policies.policies)?,
+            };
+            RewrittenScript {
+                generation: policies.generation,
+                rewritten: rewritten != script,
+                script: rewritten,
+            }
+        }
+    };
+    if entry.rewritten {
+        params.extend(policies.params.clone());
+    }
+    let script = entry.script.clone();
+    *cached = Some(entry);
+    Ok(script)
+}
+
+/// The condition that limits a rule binding every column of `relation` by
+/// name to the rows its policy allows, e.g. `, (tenant_id == $current_tenant)`,
+/// binding the policy parameters into `params`. Empty if it has no policy.
+///
+/// For the search calls, whose own scripts read the relation's indices,
+/// which scripts passed in by the app may not.
+pub(crate) fn policy_condition(
+    policies: &Mutex<RowPolicies>,
+    relation: &str,
+    params: &mut BTreeMap<String, DataValue>,
+) -> String {
+    let policies = policies.lock().unwrap_or_else(PoisonError::into_inner);
+    match policies.policies.get(relation) {
+        Some(policy) => {
+            params.extend(policies.params.clone());
+            format!(", ({})", policy.predicate)
+        }
+        None => String::new(),
+    }
+}
+
+/// The rows of `relation` its policy allows, with the columns in the order
+/// `export_relations` gives them, or `None` if it has no policy.
+pub(crate) fn policy_rows(
+    policies: &Mutex<RowPolicies>,
+    inner: &DbInstance,
+    relation: &str,
+) -> anyhow::Result<Option<NamedRows>> {
+    let (script, params) = {
+        let policies = policies.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(policy) = policies.policies.get(relation) else {
+            return Ok(None);
+        };
+        let columns = policy.columns.join(", ");
+        let script = format!(
+            "?[{cols}] := *{}[{cols}], ({})",
+            relation,
+            policy.predicate,
+            cols = columns
+        );
+        (script, policies.params.clone())
+    };
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(Some(rows))
+}
+
+/// `rows` of `relation`, such as those of a change event, without the ones
+/// its policy hides.
+pub(crate) fn filter_rows(
+    policies: &Mutex<RowPolicies>,
+    inner: &DbInstance,
+    relation: &str,
+    rows: NamedRows,
+) -> anyhow::Result<NamedRows> {
+    let allowed = {
+        let policies = policies.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(policy) = policies.policies.get(relation) else {
+            return Ok(rows);
+        };
+        allowed_rows(&run_on(inner), policy, &policies.params, &rows.rows)?
+    };
+    let kept = rows
+        .rows
+        .into_iter()
+        .enumerate()
+        .filter(|(n, _)| allowed.contains(n))
+        .map(|(_, row)| row)
+        .collect();
+    Ok(NamedRows::new(rows.headers, kept))
+}
+
+/// The result of `script` without the rows its `:returning` gives back of a
+/// relation with a policy that the policy hides. The `requested` rows of a
+/// `:rm`, which only hold the keys the script passed, are kept.
+///
+/// `run` runs the script checking the rows, e.g. [`run_on`] the database,
+/// or in the transaction `script` ran in, which a script run outside it
+/// would wait for.
+pub(crate) fn filter_returning(
+    policies: &Mutex<RowPolicies>,
+    run: &RunScript,
+    script: &str,
+    rows: NamedRows,
+) -> anyhow::Result<NamedRows> {
+    let policies = policies.lock().unwrap_or_else(PoisonError::into_inner);
+    if policies.policies.is_empty() || rows.headers.first().map(String::as_str) != Some("_kind") {
+        return Ok(rows);
+    }
+    let Some(relation) = returning_relation(script) else {
+        return Ok(rows);
+    };
+    let Some(policy) = policies.policies.get(&relation) else {
+        return Ok(rows);
+    };
+    let positions = policy
+        .columns
+        .iter()
+        .map(|column| {
+            rows.headers
+                .iter()
+                .position(|h| h == column)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{} has a row policy, so its :returning must give every column",
+                        relation
+                    )
+                })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let requested = |row: &[DataValue]| row[0] == DataValue::from("requested");
+    let returned: Vec<Vec<DataValue>> = rows
+        .rows
+        .iter()
+        .filter(|row| !requested(row))
+        .map(|row| positions.iter().map(|p| row[*p].clone()).collect())
+        .collect();
+    let allowed = allowed_rows(run, policy, &policies.params, &returned)?;
+    let mut n = 0;
+    let kept = rows
+        .rows
+        .into_iter()
+        .filter(|row| {
+            if requested(row) {
+                return true;
+            }
+            n += 1;
+            allowed.contains(&(n - 1))
+        })
+        .collect();
+    Ok(NamedRows::new(rows.headers, kept))
+}
+
+/// The generation of the policies and their parameters, which changes
+/// whenever either does.
+pub(crate) fn policy_generation(policies: &Mutex<RowPolicies>) -> u64 {
+    policies
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .generation
+}
+
+/// Runs a read-only script with parameters.
+pub(crate) type RunScript<'a> =
+    dyn Fn(&str, BTreeMap<String, DataValue>) -> anyhow::Result<NamedRows> + 'a;
+
+/// A [`RunScript`] running scripts on `inner`.
+pub(crate) fn run_on(
+    inner: &DbInstance,
+) -> impl Fn(&str, BTreeMap<String, DataValue>) -> anyhow::Result<NamedRows> + '_ {
+    move |script, params| {
+        catch_panic(|| inner.run_script(script, params, ScriptMutability::Immutable))
+            .map_err(|err| query_error(err, script))
+    }
+}
+
+/// Positions in `rows`, each holding the columns of `policy` in order, of
+/// the rows it allows.
+fn allowed_rows(
+    run: &RunScript,
+    policy: &Policy,
+    params: &BTreeMap<String, DataValue>,
+    rows: &[Vec<DataValue>],
+) -> anyhow::Result<BTreeSet<usize>> {
+    if rows.is_empty() {
+        return Ok(BTreeSet::new());
+    }
+    let columns = policy.columns.join(", ");
+    let script = format!(
+        "rows[{cols}, {p}row] <- ${p}rows\n?[{p}row] := rows[{cols}, {p}row], ({})",
+        policy.predicate,
+        cols = columns,
+        p = RULE_PREFIX,
+    );
+    let mut params = params.clone();
+    params.insert(
+        format!("{}rows", RULE_PREFIX),
+        DataValue::List(
+            rows.iter()
+                .enumerate()
+                .map(|(n, row)| {
+                    let mut row = row.clone();
+                    row.push(DataValue::from(n as i64));
+                    DataValue::List(row)
+                })
+                .collect(),
+        ),
+    );
+    Ok(run(&script, params)?
+        .rows
+        .iter()
+        .filter_map(|row| row[0].get_int())
+        .map(|n| n as usize)
+        .collect())
+}
+
+/// The relation written by the last mutation option of `script`, if the
+/// script has a `:returning` option.
+fn returning_relation(script: &str) -> Option<String> {
+    let chars: Vec<char> = script.chars().collect();
+    let is_name = |c: char| c.is_alphanumeric() || c == '_' || c == '.';
+    let read_name = |start: usize| {
+        let end = (start..chars.len())
+            .find(|j| !is_name(chars[*j]))
+            .unwrap_or(chars.len());
+        (end, chars[start..end].iter().collect::<String>())
+    };
+    let mut returning = false;
+    let mut relation = None;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' | '"' => i = skip_string(&chars, i),
+            '#' | '/' if is_comment(&chars, i) => i = skip_comment(&chars, i),
+            ':' => {
+                let (end, option) = read_name(i + 1);
+                match option.as_str() {
+                    "returning" => returning = true,
+                    "put" | "rm" | "insert" | "update" | "delete" | "replace" | "create"
+                    | "ensure" | "ensure_not" => {
+                        let (_, name) = read_name(skip_blank(&chars, end));
+                        relation = Some(name);
+                    }
+                    _ => {}
+                }
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    relation.filter(|_| returning)
+}
+
+fn rewrite(script: &str, policies: &BTreeMap<String, Policy>) -> anyhow::Result<String> {
+    let chars: Vec<char> = script.chars().collect();
+    let start = skip_blank(&chars, 0);
+    let rest: String = chars[start..].iter().collect();
+    if rest.starts_with("::") && !rest.starts_with("::explain") {
+        return Ok(script.to_string());
+    }
+
+    let is_name = |c: char| c.is_alphanumeric() || c == '_' || c == '.';
+    // Atoms to replace: start, end past the closing bracket, replacement.
+    let mut atoms: Vec<(usize, usize, String)> = vec![];
+    let mut used: Vec<&str> = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' | '"' => i = skip_string(&chars, i),
+            '#' | '/' if is_comment(&chars, i) => i = skip_comment(&chars, i),
+            '~' if i > 0 && chars[i - 1] == '<' => i += 1,
+            '*' | '~' => {
+                // Cozo allows whitespace and comments between `~` and the
+                // name of the index searched, but not after `*`.
+                let start = match chars[i] {
+                    '~' => skip_blank(&chars, i + 1),
+                    _ => i + 1,
+                };
+                let mut end = start;
+                while end < chars.len() && is_name(chars[end]) {
+                    end += 1;
+                }
+                let name: String = chars[start..end].iter().collect();
+                let Some((relation, policy)) = policies.get_key_value(&name) else {
+                    i = end.max(i + 1);
+                    continue;
+                };
+                if chars[i] == '~' || chars.get(end) == Some(&':') {
+                    anyhow::bail!(
+                        "{} has a row policy, so its indices cannot be read directly",
+                        name
+                    );
+                }
+                let open = skip_blank(&chars, end);
+                if !matches!(chars.get(open), Some('{' | '[')) {
+                    i = end;
+                    continue;
+                }
+                let Some((close, has_clause)) = atom_end(&chars, open) else {
+                    // Unbalanced: left for the parser to report.
+                    break;
+                };
+                if has_clause {
+                    anyhow::bail!(
+                        "{} has a row policy, so it cannot be read with an `@` clause",
+                        name
+                    );
+                }
+                let args: String = chars[open + 1..close].iter().collect();
+                let args = match chars[open] {
+                    '[' => args,
+                    _ => positional(&args, relation, &policy.columns)?,
+                };
+                let rule = match used.iter().position(|r| r == relation) {
+                    Some(n) => n,
+                    None => {
+                        used.push(relation);
+                        used.len() - 1
+                    }
+                };
+                atoms.push((i, close + 1, format!("{}{}[{}]", RULE_PREFIX, rule, args)));
+                i = close + 1;
+            }
+            _ => i += 1,
+        }
+    }
+    if atoms.is_empty() {
+        return Ok(script.to_string());
+    }
+
+    let rules: String = used
+        .iter()
+        .enumerate()
+        .map(|(n, relation)| {
+            let policy = &policies[*relation];
+            let columns = policy.columns.join(", ");
+            format!(
+                "\n{}{}[{cols}] := *{}[{cols}], ({})\n",
+                RULE_PREFIX,
+                n,
+                relation,
+                policy.predicate,
+                cols = columns
+            )
+        })
+        .collect();
+    // The rules go at the end of a single query, or of each `{ .. }` block
+    // reading a relation with a policy.
+    let inserts = match chars.get(start) {
+        Some('%') => anyhow::bail!("Row policies do not support imperative scripts"),
+        Some('{' | ':') => blocks(&chars)
+            .into_iter()
+            .filter(|(open, close)| atoms.iter().any(|(at, _, _)| open < at && at < close))
+            .map(|(_, close)| close)
+            .collect(),
+        _ => vec![chars.len()],
+    };
+
+    let mut out = String::with_capacity(script.len() + rules.len());
+    let mut atoms = atoms.into_iter().peekable();
+    let mut i = 0;
+    while i <= chars.len() {
+        if inserts.contains(&i) {
+            out.push_str(&rules);
+        }
+        if let Some((_, end, replacement)) = atoms.next_if(|(at, _, _)| *at == i) {
+            out.push_str(&replacement);
+            i = end;
+            continue;
+        }
+        if let Some(c) = chars.get(i) {
+            out.push(*c);
+        }
+        i += 1;
+    }
+    Ok(out)
+}
+
+/// The arguments of a named atom, `a, b: expr`, in column order, with `_`
+/// for the columns it leaves out.
+fn positional(named: &str, relation: &str, columns: &[String]) -> anyhow::Result<String> {
+    let mut args = vec!["_".to_string(); columns.len()];
+    for pair in split_args(named) {
+        let (name, expr) = match pair.split_once(':') {
+            Some((name, expr)) => (name.trim(), expr.trim()),
+            None => (pair.trim(), pair.trim()),
+        };
+        if name.is_empty() {
+            continue;
+        }
+        let column = columns
+            .iter()
+            .position(|c| c == name)
+            .ok_or_else(|| anyhow::anyhow!("{} has no column {}", relation, name))?;
+        args[column] = expr.to_string();
+    }
+    Ok(args.join(", "))
+}
+
+/// `args` split at its top-level commas, without comments.
+fn split_args(args: &str) -> Vec<String> {
+    let chars: Vec<char> = args.chars().collect();
+    let mut parts = vec![String::new()];
+    let mut depth = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' | '"' => {
+                let end = skip_string(&chars, i).min(chars.len());
+                parts.last_mut().unwrap().extend(&chars[i..end]);
+                i = end;
+                continue;
+            }
+            '#' | '/' if is_comment(&chars, i) => {
+                i = skip_comment(&chars, i);
+                continue;
+            }
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(String::new());
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        parts.last_mut().unwrap().push(chars[i]);
+        i += 1;
+    }
+    parts
+}
+
+/// Positions of the brackets of each top-level `{ .. }` block.
+pub(crate) fn blocks(chars: &[char]) -> Vec<(usize, usize)> {
+    let mut blocks = vec![];
+    let mut depth = 0;
+    let mut open = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' | '"' => {
+                i = skip_string(chars, i);
+                continue;
+            }
+            '#' | '/' if is_comment(chars, i) => {
+                i = skip_comment(chars, i);
+                continue;
+            }
+            '{' => {
+                if depth == 0 {
+                    open = i;
+                }
+                depth += 1;
+            }
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    blocks.push((open, i));
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    blocks
+}
+
+pub(crate) fn is_comment(chars: &[char], i: usize) -> bool {
+    chars[i] == '#' || (chars[i] == '/' && chars.get(i + 1) == Some(&'*'))
+}
+
+/// Position just past the comment starting at `start`. Block comments nest,
+/// as in cozo's grammar.
+pub(crate) fn skip_comment(chars: &[char], start: usize) -> usize {
+    let mut i = start + 1;
+    if chars[start] == '#' {
+        while i < chars.len() && chars[i] != '\n' {
+            i += 1;
+        }
+        return i;
+    }
+    let mut depth = 1;
+    i += 1;
+    while i + 1 < chars.len() {
+        match (chars[i], chars[i + 1]) {
+            ('/', '*') => depth += 1,
+            ('*', '/') => depth -= 1,
+            _ => {
+                i += 1;
+                continue;
+            }
+        }
+        i += 2;
+        if depth == 0 {
+            return i;
+        }
+    }
+    chars.len()
+}
+
+/// Position of the first character from `start` that is not whitespace or
+/// in a comment.
+fn skip_blank(chars: &[char], mut start: usize) -> usize {
+    while start < chars.len() {
+        if chars[start].is_whitespace() {
+            start += 1;
+        } else if is_comment(chars, start) {
+            start = skip_comment(chars, start);
+        } else {
+            break;
+        }
+    }
+    start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cursor::{cozo_cursor_len, cozo_cursor_next, cozo_open_cursor};
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+    use crate::api::transaction::{cozo_begin_tx, tx_commit, tx_run_query};
+
+    fn policies() -> BTreeMap<String, Policy> {
+        BTreeMap::from([(
+            "t".to_string(),
+            Policy {
+                predicate: "owner == $me".to_string(),
+                columns: vec!["id".to_string(), "owner".to_string()],
+            },
+        )])
+    }
+
+    const RULE: &str = "\ncozo_rls_0[id, owner] := *t[id, owner], (owner == $me)\n";
+
+    #[test]
+    fn rewrites_named_and_positional_atoms() {
+        assert_eq!(
+            rewrite("?[id] := *t{id}", &policies()).unwrap(),
+            format!("?[id] := cozo_rls_0[id, _]{}", RULE)
+        );
+        assert_eq!(
+            rewrite("?[o] := *t{owner: o, id: 1}", &policies()).unwrap(),
+            format!("?[o] := cozo_rls_0[1, o]{}", RULE)
+        );
+        assert_eq!(
+            rewrite("?[a, b] := *t[a, b], *t[b, a]", &policies()).unwrap(),
+            format!("?[a, b] := cozo_rls_0[a, b], cozo_rls_0[b, a]{}", RULE)
+        );
+    }
+
+    #[test]
+    fn leaves_other_relations_strings_and_comments() {
+        for script in [
+            "?[id] := *u{id}",
+            "?[x] := x = '*t{id}' # *t{id}",
+            "?[x] := x = \"*t[id]\" /* *t{id} */",
+            "::relations",
+            "?[id] := *tt{id}",
+        ] {
+            assert_eq!(rewrite(script, &policies()).unwrap(), script);
+        }
+    }
+
+    #[test]
+    fn puts_rules_in_the_blocks_reading_the_relation() {
+        let script = "{?[id] := *t{id}} {?[a] := *u{a}}";
+        assert_eq!(
+            rewrite(script, &policies()).unwrap(),
+            format!("{{?[id] := cozo_rls_0[id, _]{}}} {{?[a] := *u{{a}}}}", RULE)
+        );
+        assert_eq!(
+            rewrite("::explain {?[id] := *t{id}}", &policies()).unwrap(),
+            format!("::explain {{?[id] := cozo_rls_0[id, _]{}}}", RULE)
+        );
+    }
+
+    #[test]
+    fn rejects_scripts_it_cannot_make_safe() {
+        for (script, error) in [
+            (
+                "?[id] := *t:by_owner{id}",
+                "its indices cannot be read directly",
+            ),
+            (
+                "?[id] := ~t:fts{id | query: 'a'}",
+                "its indices cannot be read directly",
+            ),
+            (
+                "?[id] := ~ t:fts{id | query: 'a'}",
+                "its indices cannot be read directly",
+            ),
+            (
+                "?[id] := ~/*x*/t:fts{id | query: 'a'}",
+                "its indices cannot be read directly",
+            ),
+            (
+                "?[id] := ~/* /* */ */\n# c\n t:fts{id | query: 'a'}",
+                "its indices cannot be read directly",
+            ),
+            (
+                "?[id] := *t{id @ 'NOW'}",
+                "cannot be read with an `@` clause",
+            ),
+            (
+                "%if { ?[id] := *t{id} } %then %return",
+                "imperative scripts",
+            ),
+            ("?[x] := *t{nope: x}", "t has no column nope"),
+        ] {
+            let err = rewrite(script, &policies()).unwrap_err().to_string();
+            assert!(err.contains(error), "{}: {}", script, err);
+        }
+    }
+
+    #[test]
+    fn cached_rewrite_follows_policy_changes() {
+        let policies = Mutex::new(RowPolicies {
+            policies: policies(),
+            params: BTreeMap::from([("me".to_string(), DataValue::from("a"))]),
+            generation: 0,
+        });
+        let mut cached = None;
+        let mut params = BTreeMap::new();
+        let script = "?[id] := *t{id}";
+        let first = apply_row_policies_cached(&policies, script, &mut cached, &mut params);
+        assert!(first.unwrap().contains("cozo_rls_0"));
+        assert_eq!(params.get("me"), Some(&DataValue::from("a")));
+
+        let mut locked = policies.lock().unwrap();
+        locked.policies.clear();
+        locked.generation += 1;
+        drop(locked);
+        let mut params = BTreeMap::new();
+        let second = apply_row_policies_cached(&policies, script, &mut cached, &mut params);
+        assert_eq!(second.unwrap(), script);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn queries_and_change_rows_only_show_allowed_rows() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let run = |script: &str| cozo_run_query(&db, script.into(), "{}".into(), false);
+        run(":create t {id: Int => owner: String}").unwrap();
+        run("?[id, owner] <- [[1, 'a'], [2, 'b']] :put t {id => owner}").unwrap();
+        cozo_set_row_policy(&db, "t".into(), "owner == $me".into()).unwrap();
+        cozo_set_policy_params(&db, r#"{"me": "a"}"#.into()).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&run("?[id] := *t{id}").unwrap()).unwrap();
+        assert_eq!(json["rows"], serde_json::json!([[1]]));
+
+        let rows = NamedRows::new(
+            vec!["id".into(), "owner".into()],
+            vec![
+                vec![DataValue::from(3), DataValue::from("a")],
+                vec![DataValue::from(4), DataValue::from("b")],
+            ],
+        );
+        let inner = db.instance().unwrap();
+        let allowed = filter_rows(&db.row_policies, &inner, "t", rows).unwrap();
+        assert_eq!(
+            allowed.rows,
+            vec![vec![DataValue::from(3), DataValue::from("a")]]
+        );
+    }
+
+    #[test]
+    fn block_comments_nest() {
+        let chars: Vec<char> = "/* a /* b */ c */d".chars().collect();
+        assert_eq!(skip_comment(&chars, 0), chars.len() - 1);
+        let chars: Vec<char> = "# a\nb".chars().collect();
+        assert_eq!(skip_comment(&chars, 0), 3);
+    }
+
+    fn open_with_policy() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let run = |script: &str| cozo_run_query(&db, script.into(), "{}".into(), false);
+        run(":create t {id: Int => owner: String, body: String default ''}").unwrap();
+        run("?[id, owner, body] <- [[1, 'a', 'x'], [2, 'b', 'x']] :put t {id, owner, body}")
+            .unwrap();
+        run("::fts create t:fts {extractor: body, tokenizer: Simple}").unwrap();
+        cozo_set_row_policy(&db, "t".into(), "owner == $me".into()).unwrap();
+        cozo_set_policy_params(&db, r#"{"me": "a"}"#.into()).unwrap();
+        db
+    }
+
+    #[test]
+    fn searches_spelled_with_blanks_are_rejected() {
+        let db = open_with_policy();
+        for script in [
+            "?[id] := ~ t:fts{id | query: 'x', k: 10}",
+            "?[id] := ~/*x*/t:fts{id | query: 'x', k: 10}",
+        ] {
+            let err = cozo_run_query(&db, script.into(), "{}".into(), true).unwrap_err();
+            assert!(
+                err.to_string().contains("indices cannot be read"),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn returning_only_gives_back_allowed_rows() {
+        let db = open_with_policy();
+        let run = |script: &str| {
+            let out = cozo_run_query(&db, script.into(), "{}".into(), false).unwrap();
+            serde_json::from_str::<serde_json::Value>(&out).unwrap()["rows"].clone()
+        };
+        assert_eq!(
+            run("?[id, owner] <- [[3, 'a'], [4, 'b']] :put t {id => owner} :returning"),
+            serde_json::json!([["inserted", 3, "a", ""]])
+        );
+        assert_eq!(
+            run("?[id] <- [[2]] :rm t {id} :returning"),
+            serde_json::json!([["requested", 2, null, null]])
+        );
+    }
+
+    #[test]
+    fn cursors_go_stale_when_policies_change() {
+        let db = open_with_policy();
+        let cursor = cozo_open_cursor(&db, "?[id] := *t{id}".into(), "{}".into(), 1).unwrap();
+        assert_eq!(cozo_cursor_len(&cursor), 1);
+        cozo_cursor_next(&cursor).unwrap();
+        cozo_set_policy_params(&db, r#"{"me": "b"}"#.into()).unwrap();
+        let err = cozo_cursor_next(&cursor).unwrap_err();
+        assert!(err.to_string().contains("stale"), "{}", err);
+    }
+
+    #[test]
+    fn returning_in_a_transaction_only_gives_back_allowed_rows() {
+        let db = open_with_policy();
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        let out = tx_run_query(
+            &tx,
+            "?[id, owner] <- [[3, 'a'], [4, 'b']] :put t {id => owner} :returning".into(),
+            "{}".into(),
+        )
+        .unwrap();
+        tx_commit(&tx).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json["rows"], serde_json::json!([["inserted", 3, "a", ""]]));
+    }
+}
@@ -0,0 +1,370 @@
+use std::collections::BTreeMap;
+use std::sync::PoisonError;
+
+use cozo::{DataValue, ScriptMutability};
+
+use crate::api::busy_retry::retry_busy;
+use crate::api::column_crypto::encrypt_rows;
+use crate::api::error::catch_panic;
+use crate::api::schema::{column_names, column_spec, relation_columns};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::soft_delete::{soft_delete_mode, soft_delete_rows, SoftDelete};
+use crate::api::value::CozoValue;
+
+/// Rows written per storage transaction by [`cozo_put_rows`].
+const PUT_CHUNK_ROWS: usize = 1000;
+
+/// Write rows straight into a stored relation, without building a script.
+///
+/// - `relation`: name of the stored relation
+/// - `rows`: full rows with one value per column, in the relation's column
+///   order (keys first, as listed by `cozo_list_columns`)
+///
+/// Existing rows with the same key are replaced. Rows are written in chunks
+/// of 1000, each with a `:put` script in its own transaction, so triggers,
+/// change callbacks and indices see them as they would any other write.
+///
+/// Returns the number of rows written.
+pub fn cozo_put_rows(
+    db: &CozoDb,
+    relation: String,
+    rows: Vec<Vec<CozoValue>>,
+) -> anyhow::Result<u64> {
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let mut rows = rows_to_data(rows, columns.len())?;
+    encrypt_rows(db, &relation, &columns, &mut rows)?;
+    let script = format!(
+        "?[{}] <- $rows :put {} {{{}}}",
+        column_names(&columns),
+        relation,
+        column_spec(&columns)
+    );
+    for chunk in rows.chunks(PUT_CHUNK_ROWS) {
+        let params = BTreeMap::from([(
+            "rows".to_string(),
+            DataValue::List(chunk.iter().cloned().map(DataValue::List).collect()),
+        )]);
+        retry_busy(&db.busy_retry, || {
+            catch_panic(|| inner.run_script(&script, params.clone(), ScriptMutability::Mutable))
+        })
+        .map_err(|err| query_error(err, &script))?;
+    }
+    Ok(written(db, rows.len() as u64))
+}
+
+/// Delete rows from a stored relation by primary key.
+///
+/// - `keys`: one entry per row, holding the key column values in key order
+///
+/// Keys that do not exist are ignored. All deletions run in one transaction.
+/// For a relation with soft deletes enabled, the rows are marked deleted
+/// instead, see `cozo_enable_soft_delete`.
+///
+/// Returns the number of rows actually deleted.
+pub fn cozo_delete_rows(
+    db: &CozoDb,
+    relation: String,
+    keys: Vec<Vec<CozoValue>>,
+) -> anyhow::Result<u64> {
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    if let Some(mode) = soft_delete_mode(db, &inner, &relation)? {
+        return soft_delete_rows(db, &inner, &relation, &columns, mode, keys)
+            .map(|rows| written(db, rows));
+    }
+    let key_columns: Vec<_> = columns.into_iter().filter(|c| c.is_key).collect();
+    let keys = rows_to_data(keys, key_columns.len())?;
+    let names = column_names(&key_columns);
+    let script = format!(
+        "?[{}] <- $keys :rm {} {{{}}} :returning",
+        names, relation, names
+    );
+    let params = BTreeMap::from([(
+        "keys".to_string(),
+        DataValue::List(keys.into_iter().map(DataValue::List).collect()),
+    )]);
+    let rows = retry_busy(&db.busy_retry, || {
+        catch_panic(|| inner.run_script(&script, params.clone(), ScriptMutability::Mutable))
+    })
+    .map_err(|err| query_error(err, &script))?;
+    // `:returning` lists every requested key, then the rows that were removed.
+    let deleted = rows
+        .rows
+        .iter()
+        .filter(|row| row[0].get_str() == Some("deleted"))
+        .count();
+    Ok(written(db, deleted as u64))
+}
+
+/// What [`cozo_upsert_rows`] does with a row whose key already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnConflict {
+    /// Overwrite the existing row (`:put`).
+    Replace,
+    /// Keep the existing row and drop the new one.
+    Skip,
+    /// Fail the whole call without writing anything (`:insert`).
+    Error,
+}
+
+/// Insert rows into a stored relation, resolving existing keys with `on_conflict`.
+///
+/// - `rows`: full rows with one value per column, in the relation's column
+///   order (keys first)
+///
+/// Unlike [`cozo_put_rows`] this goes through the query engine, so triggers,
+/// callbacks and indices all apply. All rows are written in one transaction.
+///
+/// Returns the number of rows written.
+pub fn cozo_upsert_rows(
+    db: &CozoDb,
+    relation: String,
+    rows: Vec<Vec<CozoValue>>,
+    on_conflict: OnConflict,
+) -> anyhow::Result<u64> {
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let mut rows = rows_to_data(rows, columns.len())?;
+    encrypt_rows(db, &relation, &columns, &mut rows)?;
+    let names = column_names(&columns);
+    let spec = column_spec(&columns);
+    let script = match on_conflict {
+        OnConflict::Replace => format!(
+            "?[{}] <- $rows :put {} {{{}}} :returning",
+            names, relation, spec
+        ),
+        OnConflict::Error => format!(
+            "?[{}] <- $rows :insert {} {{{}}} :returning",
+            names, relation, spec
+        ),
+        OnConflict::Skip => format!(
+            "new[{names}] <- $rows\n?[{names}] := new[{names}], not *{relation}{{{keys}}}\n:put {relation} {{{spec}}} :returning",
+            names = names,
+            relation = relation,
+            keys = column_names(columns.iter().filter(|c| c.is_key)),
+            spec = spec,
+        ),
+    };
+    let params = BTreeMap::from([(
+        "rows".to_string(),
+        DataValue::List(rows.into_iter().map(DataValue::List).collect()),
+    )]);
+    let rows = retry_busy(&db.busy_retry, || {
+        catch_panic(|| inner.run_script(&script, params.clone(), ScriptMutability::Mutable))
+    })
+    .map_err(|err| query_error(err, &script))?;
+    let inserted = rows
+        .rows
+        .iter()
+        .filter(|row| row[0].get_str() == Some("inserted"))
+        .count();
+    Ok(written(db, inserted as u64))
+}
+
+/// Look up one row of a stored relation by its primary key.
+///
+/// - `key`: the key column values, in key order
+///
+/// The key is bound before the relation is scanned, so this is a direct key
+/// lookup rather than a filter over the whole relation.
+///
+/// Returns the full row in column order, or `None` if the key does not exist
+/// or the row is flagged deleted, see `cozo_enable_soft_delete`.
+pub fn cozo_get_row(
+    db: &CozoDb,
+    relation: String,
+    key: Vec<CozoValue>,
+) -> anyhow::Result<Option<Vec<CozoValue>>> {
+    let inner = db.instance()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let key_columns: Vec<_> = columns.iter().filter(|c| c.is_key).collect();
+    if key.len() != key_columns.len() {
+        anyhow::bail!(
+            "Key has {} values, expected {}",
+            key.len(),
+            key_columns.len()
+        );
+    }
+    let key = rows_to_data(vec![key], key_columns.len())?.remove(0);
+    let bindings: Vec<String> = key_columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| format!("{} = $key_{}", c.name, i))
+        .collect();
+    let names = column_names(&columns);
+    let mut script = format!(
+        "?[{}] := {}, *{}{{{}}}",
+        names,
+        bindings.join(", "),
+        relation,
+        names
+    );
+    if let Some(SoftDelete::Flag(flag)) = soft_delete_mode(db, &inner, &relation)? {
+        script.push_str(&format!(", {} == false", flag));
+    }
+    let params: BTreeMap<_, _> = key
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| (format!("key_{}", i), v))
+        .collect();
+    let rows = db.run_rows(&inner, &script, params, ScriptMutability::Immutable)?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .next()
+        .map(|row| row.into_iter().map(CozoValue::from).collect()))
+}
+
+/// Convert typed rows, checking each has exactly `arity` values.
+pub(crate) fn rows_to_data(
+    rows: Vec<Vec<CozoValue>>,
+    arity: usize,
+) -> anyhow::Result<Vec<Vec<DataValue>>> {
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            if row.len() != arity {
+                anyhow::bail!("Row {} has {} values, expected {}", i, row.len(), arity);
+            }
+            row.into_iter()
+                .map(DataValue::try_from)
+                .collect::<anyhow::Result<_>>()
+                .map_err(|e| anyhow::anyhow!("Row {}: {}", i, e))
+        })
+        .collect()
+}
+
+/// Count `rows` as written in the metrics of `db`, returning it.
+fn written(db: &CozoDb, rows: u64) -> u64 {
+    db.metrics
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .record_rows_written(rows);
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+    use crate::api::vector::run_write_op;
+
+    #[test]
+    fn put_rows_runs_triggers_and_updates_indices() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int => body: String}").unwrap();
+        run_write_op(&db, ":create seen {id: Int}").unwrap();
+        run_write_op(
+            &db,
+            "::set_triggers notes on put { ?[id] := _new[id, _] :put seen {id} }",
+        )
+        .unwrap();
+        run_write_op(
+            &db,
+            "::fts create notes:text {extractor: body, tokenizer: Simple, filters: [Lowercase]}",
+        )
+        .unwrap();
+        let rows = vec![vec![
+            CozoValue::Int(1),
+            CozoValue::Str("Hello world".into()),
+        ]];
+        assert_eq!(cozo_put_rows(&db, "notes".into(), rows).unwrap(), 1);
+
+        let run = |script: &str| cozo_run_query(&db, script.into(), "{}".into(), true).unwrap();
+        assert!(run("?[id] := *seen{id}").contains("[[1]]"));
+        assert!(run("?[id] := ~notes:text{id | query: 'hello', k: 10}").contains("[[1]]"));
+    }
+
+    fn pairs() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create pairs {a: Int, b: String => n: Int}").unwrap();
+        run_write_op(
+            &db,
+            "?[a, b, n] <- [[1, 'x', 10], [1, 'y', 11], [2, 'x', 20]] :put pairs {a, b => n}",
+        )
+        .unwrap();
+        db
+    }
+
+    fn key(a: i64, b: &str) -> Vec<CozoValue> {
+        vec![CozoValue::Int(a), CozoValue::Str(b.into())]
+    }
+
+    #[test]
+    fn delete_rows_counts_only_existing_keys() {
+        let db = pairs();
+        let keys = vec![key(1, "x"), key(2, "x"), key(3, "z")];
+        assert_eq!(cozo_delete_rows(&db, "pairs".into(), keys).unwrap(), 2);
+        let left = cozo_run_query(&db, "?[a, b] := *pairs{a, b}".into(), "{}".into(), true);
+        assert!(left.unwrap().contains(r#"[[1,"y"]]"#));
+
+        let err = cozo_delete_rows(&db, "pairs".into(), vec![vec![CozoValue::Int(1)]]);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Row 0 has 1 values, expected 2"
+        );
+        assert!(cozo_delete_rows(&db, "missing".into(), vec![]).is_err());
+    }
+
+    #[test]
+    fn upsert_rows_resolves_conflicts() {
+        let db = pairs();
+        let n_of = |a: i64, b: &str| match cozo_get_row(&db, "pairs".into(), key(a, b)) {
+            Ok(Some(row)) => row[2].clone(),
+            other => panic!("{:?}", other),
+        };
+        let row = |a: i64, b: &str, n: i64| {
+            let mut row = key(a, b);
+            row.push(CozoValue::Int(n));
+            row
+        };
+
+        let skip = vec![row(1, "x", 99), row(3, "x", 30)];
+        assert_eq!(
+            cozo_upsert_rows(&db, "pairs".into(), skip, OnConflict::Skip).unwrap(),
+            1
+        );
+        assert!(matches!(n_of(1, "x"), CozoValue::Int(10)));
+        assert!(matches!(n_of(3, "x"), CozoValue::Int(30)));
+
+        let replace = vec![row(1, "x", 99), row(4, "x", 40)];
+        let written = cozo_upsert_rows(&db, "pairs".into(), replace, OnConflict::Replace);
+        assert_eq!(written.unwrap(), 2);
+        assert!(matches!(n_of(1, "x"), CozoValue::Int(99)));
+
+        let error = vec![row(5, "x", 50), row(2, "x", 0)];
+        assert!(cozo_upsert_rows(&db, "pairs".into(), error, OnConflict::Error).is_err());
+        assert!(cozo_get_row(&db, "pairs".into(), key(5, "x"))
+            .unwrap()
+            .is_none());
+        assert!(matches!(n_of(2, "x"), CozoValue::Int(20)));
+    }
+
+    #[test]
+    fn get_row_looks_up_one_key() {
+        let db = pairs();
+        let row = cozo_get_row(&db, "pairs".into(), key(1, "y"))
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            row.as_slice(),
+            [CozoValue::Int(1), CozoValue::Str(b), CozoValue::Int(11)] if b == "y"
+        ));
+        assert!(cozo_get_row(&db, "pairs".into(), key(9, "y"))
+            .unwrap()
+            .is_none());
+
+        let err = cozo_get_row(&db, "pairs".into(), vec![CozoValue::Int(1)]);
+        assert_eq!(err.unwrap_err().to_string(), "Key has 1 values, expected 2");
+
+        run_write_op(&db, ":create single {id: Int => name: String}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, name] <- [[1, 'a'], [2, 'b']] :put single {id => name}",
+        )
+        .unwrap();
+        let row = cozo_get_row(&db, "single".into(), vec![CozoValue::Int(2)]);
+        assert!(matches!(row.unwrap().unwrap()[1], CozoValue::Str(ref b) if b == "b"));
+    }
+}
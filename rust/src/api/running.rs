@@ -0,0 +1,550 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, Once, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use cozo::{DataValue, DbInstance, ScriptMutability};
+use flutter_rust_bridge::frb;
+
+use crate::api::error::{catch_panic, CozoDartError, ErrorKind};
+use crate::api::row_policy::blocks;
+use crate::api::simple::{run_query, CozoDb};
+use crate::memory::allocated_bytes;
+
+/// Bookkeeping for a query started with [`cozo_run_tracked_query`].
+pub(crate) struct RunningQuery {
+    pub(crate) script: String,
+    /// Start time in seconds since the Unix epoch, comparable with `::running`.
+    pub(crate) started_at: f64,
+    pub(crate) started: Instant,
+}
+
+/// Tracked queries of a handle, by query id.
+pub(crate) type RunningQueries = Mutex<BTreeMap<String, RunningQuery>>;
+
+/// A query currently running under [`cozo_run_tracked_query`].
+#[derive(Debug, Clone)]
+pub struct RunningQueryInfo {
+    /// The caller-chosen query id, usable with [`cozo_kill_query`].
+    pub id: String,
+    /// The first characters of the script.
+    pub script: String,
+    /// Start time in seconds since the Unix epoch.
+    pub started_at: f64,
+    /// Seconds elapsed since the query started.
+    pub elapsed: f64,
+}
+
+/// Number of script characters kept in [`RunningQueryInfo::script`].
+const SCRIPT_SNIPPET_LEN: usize = 200;
+
+/// How often a query run with a heap limit is checked, and signalled again
+/// after hitting it while its evaluation cannot be killed yet.
+const WATCH_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Options for [`cozo_run_query_with_options`].
+#[derive(Debug, Clone)]
+pub struct QueryOptions {
+    /// If true, the query is run in read-only mode.
+    pub immutable: bool,
+    /// Stop the query once it has run for this many milliseconds.
+    pub timeout_ms: Option<u32>,
+    /// Stop the query once the process's Rust heap has grown by this many
+    /// bytes since it started, `None` for no limit. This caps the whole
+    /// process's heap, not the query: allocations made while it runs by
+    /// other queries, handles and threads count too, so they can stop it,
+    /// and memory a query frees again does not.
+    pub heap_limit_bytes: Option<u64>,
+}
+
+/// Read-write query options with no timeout and no heap limit, the ones
+/// `cozo_run_query` uses on a handle opened with default options.
+#[frb(sync)]
+pub fn cozo_default_query_options() -> QueryOptions {
+    QueryOptions {
+        immutable: false,
+        timeout_ms: None,
+        heap_limit_bytes: None,
+    }
+}
+
+/// Removes the registry entry when the tracked query finishes, however it ends.
+struct RunningGuard<'a> {
+    db: &'a CozoDb,
+    query_id: String,
+}
+
+impl Drop for RunningGuard<'_> {
+    fn drop(&mut self) {
+        self.db
+            .running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.query_id);
+    }
+}
+
+/// Run a CozoScript query under a caller-chosen id so it can be cancelled
+/// with [`cozo_kill_query`].
+///
+/// - `query_id`: any string unique among the queries currently running on
+///   this handle, e.g. a UUID generated in Dart
+///
+/// Other arguments and the result are the same as [`cozo_run_query`],
+/// including the handle's heap limit. A killed query fails with an
+/// `eval::killed` error.
+pub fn cozo_run_tracked_query(
+    db: &CozoDb,
+    query_id: String,
+    script: String,
+    params_json: String,
+    immutable: bool,
+) -> anyhow::Result<String> {
+    if db.is_remote() {
+        return run_query(db, &script, &params_json, immutable);
+    }
+    run_tracked(db, Some(query_id), &script, db.heap_limit, || {
+        run_query(db, &script, &params_json, immutable)
+    })
+}
+
+/// Run a CozoScript query with extra execution options.
+///
+/// Other arguments and the result are the same as [`cozo_run_query`], but
+/// `heap_limit_bytes` replaces the handle's heap limit. A query that
+/// exceeds `timeout_ms` fails with a `Query failed [timeout]` error; one
+/// during which the heap grows past `heap_limit_bytes` fails with
+/// `Query failed [heap_limit]`.
+///
+/// The timeout is cozo's own `:timeout` option, added to the script (to
+/// each block of a `{ .. }` chain, each getting the full timeout), so only
+/// this query is stopped. System ops and imperative scripts cannot take a
+/// timeout and fail with an error instead.
+///
+/// Cozo checks for cancellation between evaluation steps, so a single long
+/// step, such as expanding a huge `int_range`, finishes (and allocates)
+/// before the query stops.
+pub fn cozo_run_query_with_options(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    options: QueryOptions,
+) -> anyhow::Result<String> {
+    let script = match options.timeout_ms {
+        Some(ms) => with_timeout(&script, ms)?,
+        None => script,
+    };
+    let start = Instant::now();
+    let run = || run_query(db, &script, &params_json, options.immutable);
+    let result = match options.heap_limit_bytes {
+        Some(limit) if !db.is_remote() => run_tracked(db, None, &script, Some(limit), run),
+        _ => run(),
+    };
+    match result {
+        Err(e)
+            if is_killed(&e)
+                && options
+                    .timeout_ms
+                    .is_some_and(|ms| start.elapsed() >= Duration::from_millis(ms.into())) =>
+        {
+            Err(CozoDartError::new(
+                ErrorKind::Timeout,
+                format!(
+                    "Query failed [timeout]: the query exceeded its {} ms timeout",
+                    options.timeout_ms.unwrap_or_default()
+                ),
+            )
+            .into())
+        }
+        result => result,
+    }
+}
+
+/// Run the query `script` with `run`, tracked under `query_id` if given,
+/// killing it once the heap has grown by more than `heap_limit` bytes while
+/// it runs. Queries run without an id are only watched for the heap limit,
+/// not listed by [`cozo_list_running`].
+pub(crate) fn run_tracked<T>(
+    db: &CozoDb,
+    query_id: Option<String>,
+    script: &str,
+    heap_limit: Option<u64>,
+    run: impl FnOnce() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let started_at = epoch_secs();
+    let _guard = match query_id {
+        Some(query_id) => {
+            let mut running = db.running.lock().unwrap_or_else(PoisonError::into_inner);
+            if running.contains_key(&query_id) {
+                anyhow::bail!("A query with id {} is already running", query_id);
+            }
+            running.insert(
+                query_id.clone(),
+                RunningQuery {
+                    script: script.to_string(),
+                    started_at,
+                    started: Instant::now(),
+                },
+            );
+            Some(RunningGuard { db, query_id })
+        }
+        None => None,
+    };
+    let Some(limit) = heap_limit else {
+        return run();
+    };
+
+    let hit = Arc::new(AtomicBool::new(false));
+    let token = HeapWatch::start(db, started_at, limit as usize, hit.clone())?;
+    let result = run();
+    HeapWatch::stop(token);
+    match result {
+        Err(_) if hit.load(Ordering::Relaxed) => Err(CozoDartError::new(
+            ErrorKind::Cancelled,
+            format!(
+                "Query failed [heap_limit]: the heap grew by more than {} bytes while the query ran",
+                limit
+            ),
+        )
+        .into()),
+        result => result,
+    }
+}
+
+/// A query run with a heap limit, checked by one watcher thread shared by
+/// every such query so that starting one costs no thread.
+struct HeapWatch {
+    token: u64,
+    inner: DbInstance,
+    /// When the query started, to tell its evaluation by.
+    started_at: f64,
+    baseline: usize,
+    limit: usize,
+    /// Set once the limit is hit; the query is then killed as soon as
+    /// [`kill_evaluation`] can attribute its evaluation.
+    hit: Arc<AtomicBool>,
+}
+
+static WATCHES: Mutex<Vec<HeapWatch>> = Mutex::new(Vec::new());
+static WATCHES_CHANGED: Condvar = Condvar::new();
+
+impl HeapWatch {
+    /// Watch a query until [`HeapWatch::stop`] is called with the token.
+    fn start(
+        db: &CozoDb,
+        started_at: f64,
+        limit: usize,
+        hit: Arc<AtomicBool>,
+    ) -> anyhow::Result<u64> {
+        static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+        static WATCHER: Once = Once::new();
+
+        WATCHER.call_once(|| {
+            std::thread::spawn(watch_heap);
+        });
+        let token = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed);
+        let watch = HeapWatch {
+            token,
+            inner: db.instance()?,
+            started_at,
+            baseline: allocated_bytes(),
+            limit,
+            hit,
+        };
+        WATCHES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(watch);
+        WATCHES_CHANGED.notify_one();
+        Ok(token)
+    }
+
+    fn stop(token: u64) {
+        WATCHES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|watch| watch.token != token);
+    }
+}
+
+/// Body of the watcher thread: checks the heap of every watched query each
+/// [`WATCH_INTERVAL`], and sleeps while none is watched.
+fn watch_heap() {
+    let mut watches = WATCHES.lock().unwrap_or_else(PoisonError::into_inner);
+    loop {
+        watches = if watches.is_empty() {
+            WATCHES_CHANGED
+                .wait(watches)
+                .unwrap_or_else(PoisonError::into_inner)
+        } else {
+            WATCHES_CHANGED
+                .wait_timeout(watches, WATCH_INTERVAL)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0
+        };
+
+        let allocated = allocated_bytes();
+        let over: Vec<_> = watches
+            .iter()
+            .filter(|watch| {
+                watch.hit.load(Ordering::Relaxed)
+                    || allocated.saturating_sub(watch.baseline) > watch.limit
+            })
+            .map(|watch| {
+                watch.hit.store(true, Ordering::Relaxed);
+                (watch.inner.clone(), watch.started_at)
+            })
+            .collect();
+        if over.is_empty() {
+            continue;
+        }
+        // Killing runs scripts, so the queries being watched must be able to
+        // start and stop meanwhile.
+        drop(watches);
+        for (inner, started_at) in over {
+            // Retried on the next check until the evaluation can be attributed.
+            let _ = kill_evaluation(&inner, started_at);
+        }
+        watches = WATCHES.lock().unwrap_or_else(PoisonError::into_inner);
+    }
+}
+
+/// `script` with cozo's `:timeout` option set on its query, or on each
+/// block of a chained script.
+fn with_timeout(script: &str, timeout_ms: u32) -> anyhow::Result<String> {
+    let option = format!("\n:timeout {}\n", f64::from(timeout_ms) / 1000.0);
+    let trimmed = script.trim_start();
+    if trimmed.starts_with("::") || trimmed.starts_with('%') {
+        anyhow::bail!("A timeout cannot be set on system ops or imperative scripts");
+    }
+    if !trimmed.starts_with('{') {
+        return Ok(format!("{}{}", script, option));
+    }
+    let chars: Vec<char> = script.chars().collect();
+    let closes: Vec<usize> = blocks(&chars).into_iter().map(|(_, close)| close).collect();
+    let mut out = String::with_capacity(script.len() + closes.len() * option.len());
+    for (i, c) in chars.into_iter().enumerate() {
+        if closes.contains(&i) {
+            out.push_str(&option);
+        }
+        out.push(c);
+    }
+    Ok(out)
+}
+
+/// Whether `err` is cozo's "query killed" error, raised by `::kill` and
+/// by the `:timeout` option.
+fn is_killed(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<CozoDartError>()
+        .is_some_and(|e| e.code.as_deref() == Some("eval::killed"))
+}
+
+/// Abort a query started with [`cozo_run_tracked_query`].
+///
+/// cozo 0.7.6 does not say which caller an evaluation listed by `::running`
+/// belongs to, so the query's evaluation is told by its start time: it is
+/// only killed when it is the only evaluation on the database that started
+/// after the tracked query did. Queries that started earlier, tracked or
+/// not, do not get in the way; while another one that started later is
+/// evaluating too, nothing is killed and false is returned, so call again
+/// once it has finished. A query started in the few milliseconds before
+/// the tracked one begins evaluating would still be taken for it, since
+/// cozo gives no way to tell them apart.
+///
+/// Returns true if the query's evaluation was signalled to stop; false if
+/// the id is unknown, the query has not started evaluating yet or its
+/// evaluation cannot be told apart from others.
+pub fn cozo_kill_query(db: &CozoDb, query_id: String) -> anyhow::Result<bool> {
+    let started_at = match db
+        .running
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&query_id)
+    {
+        Some(query) => query.started_at,
+        None => return Ok(false),
+    };
+    kill_evaluation(&db.instance()?, started_at)
+}
+
+/// Kill the evaluation of a query that started at `started_at`, see
+/// [`cozo_kill_query`].
+fn kill_evaluation(inner: &DbInstance, started_at: f64) -> anyhow::Result<bool> {
+    let listed = catch_panic(|| {
+        inner.run_script("::running", Default::default(), ScriptMutability::Immutable)
+    })
+    .map_err(|e| anyhow::anyhow!("Failed to list running queries: {}", e))?;
+    let candidates: Vec<i64> = listed
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let id = row.first().and_then(DataValue::get_int)?;
+            let started = row
+                .get(1)
+                .and_then(DataValue::get_str)
+                .and_then(|s| s.parse::<f64>().ok())?;
+            (started >= started_at).then_some(id)
+        })
+        .collect();
+    let [id] = candidates[..] else {
+        return Ok(false);
+    };
+    // `::kill` only sets a flag. Running it as a write would wait for the
+    // storage write lock, which the query being killed may hold.
+    catch_panic(|| {
+        inner.run_script(
+            &format!("::kill {}", id),
+            Default::default(),
+            ScriptMutability::Immutable,
+        )
+    })
+    .map_err(|e| anyhow::anyhow!("Failed to kill query: {}", e))?;
+    Ok(true)
+}
+
+/// List the queries currently running under [`cozo_run_tracked_query`],
+/// oldest first.
+#[frb(sync)]
+pub fn cozo_list_running(db: &CozoDb) -> Vec<RunningQueryInfo> {
+    let mut list: Vec<RunningQueryInfo> = db
+        .running
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|(id, q)| RunningQueryInfo {
+            id: id.clone(),
+            script: q.script.chars().take(SCRIPT_SNIPPET_LEN).collect(),
+            started_at: q.started_at,
+            elapsed: q.started.elapsed().as_secs_f64(),
+        })
+        .collect();
+    list.sort_by(|a, b| a.started_at.total_cmp(&b.started_at));
+    list
+}
+
+pub(crate) fn epoch_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+
+    #[test]
+    fn heap_limit_is_off_unless_set() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        assert_eq!(db.heap_limit, None);
+        assert_eq!(cozo_default_query_options().heap_limit_bytes, None);
+        let options = r#"{"heap_limit_bytes": 1024}"#.to_string();
+        let db = cozo_open_db("mem".into(), String::new(), options).unwrap();
+        assert_eq!(db.heap_limit, Some(1024));
+    }
+
+    #[test]
+    fn heap_limit_stops_a_growing_query() {
+        let options = r#"{"heap_limit_bytes": 1048576}"#.to_string();
+        let db = cozo_open_db("mem".into(), String::new(), options).unwrap();
+        let script = "r[n] := n = 0\nr[m] := r[n], m = n + 1, m < 100000000\n?[n] := r[n]";
+        let err = cozo_run_query(&db, script.into(), "{}".into(), true).unwrap_err();
+        assert!(err.to_string().contains("[heap_limit]"), "{}", err);
+        assert!(db.running.lock().unwrap().is_empty());
+    }
+
+    /// A script evaluating for about a second per `steps / 20000` in a debug
+    /// build, one epoch per step.
+    fn counting(steps: u64) -> String {
+        format!(
+            "r[n] := n = 0\nr[m] := r[n], m = n + 1, m < {}\n?[n] := r[n], n = 0",
+            steps
+        )
+    }
+
+    /// Wait until `query_id` is listed as running.
+    fn wait_running(db: &CozoDb, query_id: &str) {
+        let start = Instant::now();
+        while !cozo_list_running(db).iter().any(|q| q.id == query_id) {
+            assert!(start.elapsed() < Duration::from_secs(10), "not started");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn timeouts_are_set_on_each_block() {
+        assert_eq!(
+            with_timeout("?[x] <- [[1]]", 1500).unwrap(),
+            "?[x] <- [[1]]\n:timeout 1.5\n"
+        );
+        assert_eq!(
+            with_timeout("{?[x] <- [[1]]} {?[y] <- [[2]]}", 1000).unwrap(),
+            "{?[x] <- [[1]]\n:timeout 1\n} {?[y] <- [[2]]\n:timeout 1\n}"
+        );
+        assert!(with_timeout("::relations", 1000).is_err());
+        assert!(with_timeout("%return", 1000).is_err());
+    }
+
+    #[test]
+    fn timeout_stops_only_slow_queries() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let options = QueryOptions {
+            immutable: true,
+            timeout_ms: Some(200),
+            ..cozo_default_query_options()
+        };
+        let run =
+            |script: String| cozo_run_query_with_options(&db, script, "{}".into(), options.clone());
+        run("?[x] <- [[1]]".into()).unwrap();
+        let start = Instant::now();
+        let err = run(counting(100000000)).unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(10));
+        let err = err.downcast_ref::<CozoDartError>().unwrap();
+        assert_eq!(err.kind, ErrorKind::Timeout);
+        assert!(err.message.contains("[timeout]"), "{}", err.message);
+    }
+
+    #[test]
+    fn heap_limited_queries_are_not_listed() {
+        let options = r#"{"heap_limit_bytes": 1099511627776}"#.to_string();
+        let db = cozo_open_db("mem".into(), String::new(), options).unwrap();
+        std::thread::scope(|scope| {
+            let untracked = scope.spawn(|| cozo_run_query(&db, counting(20000), "{}".into(), true));
+            let tracked = scope.spawn(|| {
+                cozo_run_tracked_query(&db, "t".into(), counting(20000), "{}".into(), true)
+            });
+            wait_running(&db, "t");
+            let ids: Vec<String> = cozo_list_running(&db).into_iter().map(|q| q.id).collect();
+            assert_eq!(ids, vec!["t".to_string()]);
+            untracked.join().unwrap().unwrap();
+            tracked.join().unwrap().unwrap();
+        });
+        assert!(cozo_list_running(&db).is_empty());
+    }
+
+    #[test]
+    fn kill_stops_the_tracked_query_while_an_earlier_one_runs() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        std::thread::scope(|scope| {
+            let earlier = scope.spawn(|| {
+                cozo_run_tracked_query(&db, "a".into(), counting(20000), "{}".into(), true)
+            });
+            wait_running(&db, "a");
+            std::thread::sleep(Duration::from_millis(50));
+            let killed = scope.spawn(|| {
+                cozo_run_tracked_query(&db, "b".into(), counting(100000000), "{}".into(), true)
+            });
+            wait_running(&db, "b");
+            let start = Instant::now();
+            while !cozo_kill_query(&db, "b".into()).unwrap() {
+                assert!(start.elapsed() < Duration::from_secs(10), "not killed");
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            let err = killed.join().unwrap().unwrap_err();
+            assert!(err.to_string().contains("eval::killed"), "{}", err);
+            earlier.join().unwrap().unwrap();
+        });
+        assert!(!cozo_kill_query(&db, "b".into()).unwrap());
+    }
+}
@@ -0,0 +1,773 @@
+use cozo::{DataValue, DbInstance, ScriptMutability};
+
+use crate::api::backup::{restore_file, TempFile};
+use crate::api::compression::decompressed_backup;
+use crate::api::error::catch_panic;
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::value::{literal, CozoValue};
+use crate::api::vector::run_write_op;
+
+/// A stored relation, as listed by `::relations`.
+#[derive(Debug, Clone)]
+pub struct RelationInfo {
+    pub name: String,
+    /// Total number of columns.
+    pub arity: u32,
+    /// "normal", "protected", "read_only", "hidden", or "index" for indices.
+    pub access_level: String,
+    pub n_keys: u32,
+    pub n_non_keys: u32,
+    pub n_put_triggers: u32,
+    pub n_rm_triggers: u32,
+    pub n_replace_triggers: u32,
+    /// Description set with `::describe`, empty if none.
+    pub description: String,
+}
+
+/// A column of a stored relation, as listed by `::columns`.
+#[derive(Debug, Clone)]
+pub struct ColumnInfo {
+    pub name: String,
+    /// Column type without the nullability marker, e.g. "Int" or "<F32; 128>".
+    pub column_type: String,
+    /// Whether the type was declared with `?`.
+    pub nullable: bool,
+    /// Whether the column is part of the primary key.
+    pub is_key: bool,
+    /// Position in the relation. Key columns come first, so for a key column
+    /// this is also its position within the key.
+    pub index: u32,
+    pub has_default: bool,
+}
+
+/// A column of a relation to create with [`cozo_create_relation`].
+#[derive(Debug, Clone)]
+pub struct ColumnDef {
+    pub name: String,
+    /// Column type without the nullability marker, e.g. "Int", "[String]"
+    /// or "<F32; 128>". Empty for `Any`.
+    pub column_type: String,
+    /// Whether the column accepts null, i.e. the type is declared with `?`.
+    pub nullable: bool,
+    /// Whether the column is part of the primary key. Key columns are placed
+    /// first, in the order given.
+    pub is_key: bool,
+    /// Value used when a write leaves the column out.
+    pub default: Option<ColumnDefault>,
+}
+
+impl From<&ColumnInfo> for ColumnDef {
+    /// The definition of an existing column, without its default: `::columns`
+    /// only reports whether there is one.
+    fn from(column: &ColumnInfo) -> Self {
+        ColumnDef {
+            name: column.name.clone(),
+            column_type: column.column_type.clone(),
+            nullable: column.nullable,
+            is_key: column.is_key,
+            default: None,
+        }
+    }
+}
+
+/// Default of a [`ColumnDef`].
+#[derive(Debug, Clone)]
+pub enum ColumnDefault {
+    /// A fixed value.
+    Value(CozoValue),
+    /// A CozoScript expression evaluated for each written row, e.g. `now()`
+    /// or `rand_uuid_v4()`.
+    Expr(String),
+}
+
+/// Outcome of [`cozo_ensure_relation`].
+#[derive(Debug, Clone)]
+pub struct EnsureRelationReport {
+    /// Whether the relation did not exist and was created by this call.
+    pub created: bool,
+    /// How the existing relation differs from the requested columns; empty
+    /// if it matches or was just created.
+    pub mismatches: Vec<SchemaMismatch>,
+}
+
+/// One difference found by [`cozo_ensure_relation`].
+#[derive(Debug, Clone)]
+pub struct SchemaMismatch {
+    pub column: String,
+    pub kind: SchemaMismatchKind,
+    /// The requested property, e.g. "Int?" for a type mismatch or "key" for
+    /// a key mismatch. `None` for [`SchemaMismatchKind::Unexpected`].
+    pub expected: Option<String>,
+    /// The property found in the database. `None` for
+    /// [`SchemaMismatchKind::Missing`].
+    pub actual: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaMismatchKind {
+    /// A requested column does not exist.
+    Missing,
+    /// The relation has a column that was not requested.
+    Unexpected,
+    /// The column has a different type or nullability.
+    Type,
+    /// The column is a key column but was requested as a value, or the
+    /// other way round.
+    Key,
+    /// The column is at a different position; for key columns this changes
+    /// the key order.
+    Position,
+    /// The column has a default but none was requested, or the other way
+    /// round. Default expressions themselves are not compared.
+    Default,
+}
+
+/// Schema changes from one database to another, see [`cozo_schema_diff`].
+#[derive(Debug, Clone)]
+pub struct SchemaDiff {
+    /// Relations only in the second database.
+    pub added_relations: Vec<String>,
+    /// Relations only in the first database.
+    pub removed_relations: Vec<String>,
+    /// Relations in both whose columns or indices differ.
+    pub changed_relations: Vec<RelationDiff>,
+}
+
+/// How a relation differs between the two databases of a [`SchemaDiff`].
+#[derive(Debug, Clone)]
+pub struct RelationDiff {
+    pub name: String,
+    /// Column differences, with `expected` from the first database and
+    /// `actual` from the second: [`SchemaMismatchKind::Missing`] is a column
+    /// that was removed and [`SchemaMismatchKind::Unexpected`] one that was
+    /// added.
+    pub columns: Vec<SchemaMismatch>,
+    pub added_indices: Vec<String>,
+    pub removed_indices: Vec<String>,
+    /// Indices in both with a different type, columns or configuration.
+    pub changed_indices: Vec<String>,
+}
+
+/// List the stored relations in the database, including indices.
+pub fn cozo_list_relations(db: &CozoDb) -> anyhow::Result<Vec<RelationInfo>> {
+    let rows = system_op(&db.instance()?, "::relations")?;
+    Ok(rows
+        .into_iter()
+        .map(|row| RelationInfo {
+            name: string(&row[0]),
+            arity: count(&row[1]),
+            access_level: string(&row[2]),
+            n_keys: count(&row[3]),
+            n_non_keys: count(&row[4]),
+            n_put_triggers: count(&row[5]),
+            n_rm_triggers: count(&row[6]),
+            n_replace_triggers: count(&row[7]),
+            description: string(&row[8]),
+        })
+        .collect())
+}
+
+/// List the columns of a stored relation, keys first.
+pub fn cozo_list_columns(db: &CozoDb, relation: String) -> anyhow::Result<Vec<ColumnInfo>> {
+    relation_columns(&db.instance()?, &relation)
+}
+
+/// Create a stored relation.
+///
+/// - `name`: relation name; dotted names such as `app.users` are allowed
+/// - `columns`: at least one key column; the rest are value columns
+///
+/// Names and types are checked before the script is built, and default
+/// values are written as escaped literals, so no input is pasted into the
+/// script verbatim apart from [`ColumnDefault::Expr`] expressions.
+///
+/// Fails if a relation with this name already exists.
+pub fn cozo_create_relation(
+    db: &CozoDb,
+    name: String,
+    columns: Vec<ColumnDef>,
+) -> anyhow::Result<()> {
+    let script = create_script(&name, &columns)
+        .map_err(|e| anyhow::anyhow!("Create relation failed: {}", e))?;
+    run_write_op(db, &script)
+}
+
+/// Remove a stored relation with all its rows, indices and triggers.
+pub fn cozo_drop_relation(db: &CozoDb, name: String) -> anyhow::Result<()> {
+    check_relation_name(&name).map_err(|e| anyhow::anyhow!("Drop relation failed: {}", e))?;
+    run_write_op(db, &format!("::remove {}", name))
+}
+
+/// Rename a stored relation, keeping its rows, indices and triggers.
+///
+/// Fails if `new_name` is already taken.
+pub fn cozo_rename_relation(db: &CozoDb, name: String, new_name: String) -> anyhow::Result<()> {
+    check_relation_name(&name)
+        .and_then(|_| check_relation_name(&new_name))
+        .map_err(|e| anyhow::anyhow!("Rename relation failed: {}", e))?;
+    run_write_op(db, &format!("::rename {} -> {}", name, new_name))
+}
+
+/// Create a stored relation unless it exists, and otherwise check that its
+/// columns are the requested ones, e.g. at app startup.
+///
+/// - `columns`: as for [`cozo_create_relation`]
+///
+/// An existing relation is never changed; compare the report against what
+/// the app can work with, or use `cozo_migrate` to change the schema.
+///
+/// Returns whether the relation was created and every difference found.
+pub fn cozo_ensure_relation(
+    db: &CozoDb,
+    name: String,
+    columns: Vec<ColumnDef>,
+) -> anyhow::Result<EnsureRelationReport> {
+    let exists = cozo_list_relations(db)?.iter().any(|rel| rel.name == name);
+    if !exists {
+        cozo_create_relation(db, name, columns)?;
+        return Ok(EnsureRelationReport {
+            created: true,
+            mismatches: vec![],
+        });
+    }
+    let actual = relation_columns(&db.instance()?, &name)?;
+    Ok(EnsureRelationReport {
+        created: false,
+        mismatches: schema_mismatches(&columns, &actual),
+    })
+}
+
+/// Compare the schemas of two databases: relations, their columns and
+/// their indices, e.g. to show what a migration or a restore will change
+/// before applying it.
+///
+/// Differences are reported from `a` to `b`: a relation only in `b` is
+/// added. Rows, triggers and column default expressions are not compared,
+/// only whether a column has a default.
+pub fn cozo_schema_diff(a: &CozoDb, b: &CozoDb) -> anyhow::Result<SchemaDiff> {
+    schema_diff(&a.instance()?, &b.instance()?)
+}
+
+/// Compare the schema of a database with the schema in a backup file, as
+/// [`cozo_schema_diff`] with the backup as `b`: what restoring it would
+/// change.
+///
+/// The backup is read into a scratch in-memory database, so this needs
+/// memory for the whole backup. Compressed backups are read as they are.
+pub fn cozo_schema_diff_backup(db: &CozoDb, path: String) -> anyhow::Result<SchemaDiff> {
+    let inner = db.instance()?;
+    let plain = decompressed_backup(&path)?;
+    let backup = DbInstance::new("mem", "", "").map_err(|e| anyhow::anyhow!("{}", e))?;
+    catch_panic(|| {
+        restore_file(
+            &backup,
+            plain.as_ref().map_or(path.as_str(), TempFile::path),
+        )
+        .map_err(|e| anyhow::anyhow!("Reading backup failed: {}", e))
+    })?;
+    schema_diff(&inner, &backup)
+}
+
+fn schema_diff(a: &DbInstance, b: &DbInstance) -> anyhow::Result<SchemaDiff> {
+    let relations = |inner: &DbInstance| -> anyhow::Result<Vec<String>> {
+        Ok(system_op(inner, "::relations")?
+            .iter()
+            .filter(|row| row[2].get_str() != Some("index"))
+            .map(|row| string(&row[0]))
+            .collect())
+    };
+    let (before, after) = (relations(a)?, relations(b)?);
+    let mut diff = SchemaDiff {
+        added_relations: after
+            .iter()
+            .filter(|name| !before.contains(name))
+            .cloned()
+            .collect(),
+        removed_relations: before
+            .iter()
+            .filter(|name| !after.contains(name))
+            .cloned()
+            .collect(),
+        changed_relations: vec![],
+    };
+    for name in before.iter().filter(|name| after.contains(name)) {
+        // Only whether a column has a default is known, so that is what the
+        // placeholder carries.
+        let expected: Vec<ColumnDef> = relation_columns(a, name)?
+            .iter()
+            .map(|c| ColumnDef {
+                default: c.has_default.then(|| ColumnDefault::Expr(String::new())),
+                ..ColumnDef::from(c)
+            })
+            .collect();
+        let columns = schema_mismatches(&expected, &relation_columns(b, name)?);
+        let (old, new) = (indices(a, name)?, indices(b, name)?);
+        let relation = RelationDiff {
+            name: name.clone(),
+            columns,
+            added_indices: new
+                .iter()
+                .filter(|(index, _)| !old.iter().any(|(o, _)| o == index))
+                .map(|(index, _)| index.clone())
+                .collect(),
+            removed_indices: old
+                .iter()
+                .filter(|(index, _)| !new.iter().any(|(n, _)| n == index))
+                .map(|(index, _)| index.clone())
+                .collect(),
+            changed_indices: old
+                .iter()
+                .filter(|(index, sig)| new.iter().any(|(n, s)| n == index && s != sig))
+                .map(|(index, _)| index.clone())
+                .collect(),
+        };
+        let unchanged = relation.columns.is_empty()
+            && relation.added_indices.is_empty()
+            && relation.removed_indices.is_empty()
+            && relation.changed_indices.is_empty();
+        if !unchanged {
+            diff.changed_relations.push(relation);
+        }
+    }
+    Ok(diff)
+}
+
+/// Indices of `relation` with a signature of their type, configuration and
+/// columns.
+fn indices(inner: &DbInstance, relation: &str) -> anyhow::Result<Vec<(String, String)>> {
+    system_op(inner, &format!("::indices {}", relation))?
+        .into_iter()
+        .map(|row| {
+            let name = string(&row[0]);
+            let columns = relation_columns(inner, &format!("{}:{}", relation, name))?
+                .iter()
+                .map(|c| format!("{}: {}", c.name, type_name(&c.column_type, c.nullable)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let config = serde_json::Value::from(row[3].clone());
+            Ok((
+                name,
+                format!("{} {} [{}]", string(&row[1]), config, columns),
+            ))
+        })
+        .collect()
+}
+
+fn schema_mismatches(expected: &[ColumnDef], actual: &[ColumnInfo]) -> Vec<SchemaMismatch> {
+    let mismatch =
+        |column: &str, kind, expected: Option<String>, actual: Option<String>| SchemaMismatch {
+            column: column.to_string(),
+            kind,
+            expected,
+            actual,
+        };
+    // Columns as they are stored: keys first, both parts in the given order.
+    let (keys, values): (Vec<_>, Vec<_>) = expected.iter().partition(|c| c.is_key);
+    let mut mismatches = vec![];
+    for (index, column) in keys.into_iter().chain(values).enumerate() {
+        let Some(found) = actual.iter().find(|c| c.name == column.name) else {
+            mismatches.push(mismatch(
+                &column.name,
+                SchemaMismatchKind::Missing,
+                Some(type_name(&column.column_type, column.nullable)),
+                None,
+            ));
+            continue;
+        };
+        let (want, got) = (
+            type_name(&column.column_type, column.nullable),
+            type_name(&found.column_type, found.nullable),
+        );
+        if want != got {
+            mismatches.push(mismatch(
+                &column.name,
+                SchemaMismatchKind::Type,
+                Some(want),
+                Some(got),
+            ));
+        }
+        if column.is_key != found.is_key {
+            let part = |is_key| if is_key { "key" } else { "value" }.to_string();
+            mismatches.push(mismatch(
+                &column.name,
+                SchemaMismatchKind::Key,
+                Some(part(column.is_key)),
+                Some(part(found.is_key)),
+            ));
+        }
+        if index as u32 != found.index {
+            mismatches.push(mismatch(
+                &column.name,
+                SchemaMismatchKind::Position,
+                Some(index.to_string()),
+                Some(found.index.to_string()),
+            ));
+        }
+        if column.default.is_some() != found.has_default {
+            let default = |has| if has { "default" } else { "none" }.to_string();
+            mismatches.push(mismatch(
+                &column.name,
+                SchemaMismatchKind::Default,
+                Some(default(column.default.is_some())),
+                Some(default(found.has_default)),
+            ));
+        }
+    }
+    for found in actual {
+        if !expected.iter().any(|c| c.name == found.name) {
+            mismatches.push(mismatch(
+                &found.name,
+                SchemaMismatchKind::Unexpected,
+                None,
+                Some(type_name(&found.column_type, found.nullable)),
+            ));
+        }
+    }
+    mismatches
+}
+
+/// A column type as cozo lists it, e.g. `<F32;128>?`, for comparison.
+fn type_name(column_type: &str, nullable: bool) -> String {
+    let mut name: String = column_type.chars().filter(|c| !c.is_whitespace()).collect();
+    if name.is_empty() {
+        name = "Any".to_string();
+    }
+    // `Any` always admits null.
+    if nullable || name == "Any" {
+        name.push('?');
+    }
+    name
+}
+
+fn create_script(name: &str, columns: &[ColumnDef]) -> anyhow::Result<String> {
+    check_relation_name(name)?;
+    let (keys, values): (Vec<_>, Vec<_>) = columns.iter().partition(|c| c.is_key);
+    if keys.is_empty() {
+        anyhow::bail!("relation {} needs at least one key column", name);
+    }
+    let render = |columns: Vec<&ColumnDef>| -> anyhow::Result<String> {
+        columns
+            .into_iter()
+            .map(column_decl)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map(|decls| decls.join(", "))
+    };
+    let mut spec = render(keys)?;
+    if !values.is_empty() {
+        spec = format!("{} => {}", spec, render(values)?);
+    }
+    Ok(format!(":create {} {{{}}}", name, spec))
+}
+
+/// One column of a `:create` spec, e.g. `age: Int? default null`.
+fn column_decl(column: &ColumnDef) -> anyhow::Result<String> {
+    if !is_ident(&column.name) {
+        anyhow::bail!("invalid column name {:?}", column.name);
+    }
+    let column_type = column.column_type.trim();
+    let valid_type = column_type.chars().all(|c| {
+        c.is_ascii_alphanumeric()
+            || matches!(c, '<' | '>' | '[' | ']' | '(' | ')' | ';' | ',' | ' ')
+    });
+    if !valid_type {
+        anyhow::bail!(
+            "invalid type {:?} for column {}",
+            column.column_type,
+            column.name
+        );
+    }
+    let mut decl = column.name.clone();
+    match (column_type, column.nullable) {
+        ("", false) => {}
+        ("", true) => decl.push_str(": Any?"),
+        (t, nullable) => {
+            decl.push_str(": ");
+            decl.push_str(t);
+            if nullable {
+                decl.push('?');
+            }
+        }
+    }
+    match &column.default {
+        None => {}
+        Some(ColumnDefault::Value(value)) => {
+            let value = literal(value)
+                .map_err(|e| anyhow::anyhow!("default of column {}: {}", column.name, e))?;
+            decl.push_str(&format!(" default {}", value));
+        }
+        Some(ColumnDefault::Expr(expr)) => decl.push_str(&format!(" default {}", expr)),
+    }
+    Ok(decl)
+}
+
+fn check_relation_name(name: &str) -> anyhow::Result<()> {
+    if name.split('.').all(is_ident) {
+        Ok(())
+    } else {
+        anyhow::bail!("invalid relation name {:?}", name)
+    }
+}
+
+/// Whether `name` can be used unquoted as a relation or column name.
+fn is_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(char::is_alphabetic) && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+pub(crate) fn relation_columns(
+    inner: &DbInstance,
+    relation: &str,
+) -> anyhow::Result<Vec<ColumnInfo>> {
+    let rows = system_op(inner, &format!("::columns {}", relation))?;
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let typing = string(&row[3]);
+            let (column_type, nullable) = match typing.strip_suffix('?') {
+                Some(base) => (base.to_string(), true),
+                None => (typing, false),
+            };
+            ColumnInfo {
+                name: string(&row[0]),
+                column_type,
+                nullable,
+                is_key: row[1].get_bool().unwrap_or_default(),
+                index: count(&row[2]),
+                has_default: row[4].get_bool().unwrap_or_default(),
+            }
+        })
+        .collect())
+}
+
+/// Comma-separated names of `columns`, e.g. `a, b, c`.
+pub(crate) fn column_names<'a>(columns: impl IntoIterator<Item = &'a ColumnInfo>) -> String {
+    columns
+        .into_iter()
+        .map(|c| c.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Column spec of a store op such as `:put rel {a, b => c}` for `columns`.
+pub(crate) fn column_spec<'a>(columns: impl IntoIterator<Item = &'a ColumnInfo>) -> String {
+    let (keys, values): (Vec<_>, Vec<_>) = columns.into_iter().partition(|c| c.is_key);
+    if values.is_empty() {
+        column_names(keys)
+    } else {
+        format!("{} => {}", column_names(keys), column_names(values))
+    }
+}
+
+pub(crate) fn system_op(inner: &DbInstance, script: &str) -> anyhow::Result<Vec<Vec<DataValue>>> {
+    catch_panic(|| inner.run_script(script, Default::default(), ScriptMutability::Immutable))
+        .map(|rows| rows.rows)
+        .map_err(|err| query_error(err, script))
+}
+
+fn string(value: &DataValue) -> String {
+    value.get_str().unwrap_or_default().to_string()
+}
+
+fn count(value: &DataValue) -> u32 {
+    value.get_int().unwrap_or_default() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+
+    fn column(name: &str, column_type: &str, is_key: bool) -> ColumnDef {
+        ColumnDef {
+            name: name.into(),
+            column_type: column_type.into(),
+            nullable: false,
+            is_key,
+            default: None,
+        }
+    }
+
+    #[test]
+    fn relations_and_columns_are_listed() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(
+            &db,
+            ":create users {id: Int => name: String, age: Int? default null}",
+        )
+        .unwrap();
+        run_write_op(&db, "::index create users:by_name {name}").unwrap();
+
+        let relations = cozo_list_relations(&db).unwrap();
+        let names: Vec<_> = relations.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["users", "users:by_name"]);
+        let users = &relations[0];
+        assert_eq!((users.arity, users.n_keys, users.n_non_keys), (3, 1, 2));
+        assert_eq!(users.access_level, "normal");
+        assert_eq!(relations[1].access_level, "index");
+
+        let columns = cozo_list_columns(&db, "users".into()).unwrap();
+        let age = &columns[2];
+        assert_eq!(
+            (age.name.as_str(), age.column_type.as_str()),
+            ("age", "Int")
+        );
+        assert!(age.nullable && age.has_default && !age.is_key);
+        assert_eq!(age.index, 2);
+        assert!(columns[0].is_key && !columns[0].nullable);
+        assert!(cozo_list_columns(&db, "missing".into()).is_err());
+    }
+
+    #[test]
+    fn relations_are_created_renamed_and_dropped() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let columns = vec![
+            column("name", "String", false),
+            column("id", "Int", true),
+            ColumnDef {
+                default: Some(ColumnDefault::Value(CozoValue::Str("it's".into()))),
+                ..column("note", "String", false)
+            },
+        ];
+        cozo_create_relation(&db, "app.users".into(), columns.clone()).unwrap();
+        assert!(cozo_create_relation(&db, "app.users".into(), columns).is_err());
+        run_write_op(&db, "?[id, name] <- [[1, 'a']] :put app.users {id, name}").unwrap();
+        let stored = cozo_list_columns(&db, "app.users".into()).unwrap();
+        let names: Vec<_> = stored.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["id", "name", "note"]);
+
+        cozo_rename_relation(&db, "app.users".into(), "people".into()).unwrap();
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(
+                "?[note] := *people{note}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap();
+        assert_eq!(rows.rows[0][0].get_str(), Some("it's"));
+
+        cozo_drop_relation(&db, "people".into()).unwrap();
+        assert!(cozo_list_relations(&db).unwrap().is_empty());
+    }
+
+    #[test]
+    fn unsafe_names_and_types_are_rejected() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let create = |name: &str, columns| cozo_create_relation(&db, name.into(), columns);
+        assert!(create("users {id: Int} :put x", vec![column("id", "Int", true)]).is_err());
+        assert!(create("users", vec![column("id: Int", "Int", true)]).is_err());
+        assert!(create("users", vec![column("id", "Int} ::remove x {", true)]).is_err());
+        assert!(create("users", vec![column("name", "String", false)]).is_err());
+        assert!(cozo_drop_relation(&db, "x; ::remove y".into()).is_err());
+        assert!(cozo_rename_relation(&db, "a".into(), "b -> c".into()).is_err());
+    }
+
+    #[test]
+    fn ensure_relation_creates_once_then_reports_mismatches() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let columns = vec![column("id", "Int", true), column("name", "String", false)];
+        let report = cozo_ensure_relation(&db, "users".into(), columns.clone()).unwrap();
+        assert!(report.created && report.mismatches.is_empty());
+        let report = cozo_ensure_relation(&db, "users".into(), columns).unwrap();
+        assert!(!report.created && report.mismatches.is_empty());
+
+        let wanted = vec![
+            column("id", "String", true),
+            column("email", "String", false),
+        ];
+        let report = cozo_ensure_relation(&db, "users".into(), wanted).unwrap();
+        assert!(!report.created);
+        let found: Vec<_> = report
+            .mismatches
+            .iter()
+            .map(|m| {
+                (
+                    m.column.as_str(),
+                    m.kind,
+                    m.expected.as_deref(),
+                    m.actual.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("id", SchemaMismatchKind::Type, Some("String"), Some("Int")),
+                ("email", SchemaMismatchKind::Missing, Some("String"), None),
+                ("name", SchemaMismatchKind::Unexpected, None, Some("String")),
+            ]
+        );
+        // The relation is left as it was.
+        assert_eq!(
+            cozo_list_columns(&db, "users".into()).unwrap()[0].column_type,
+            "Int"
+        );
+    }
+
+    #[test]
+    fn ensure_relation_reports_key_position_and_default_changes() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create pairs {a: Int, b: Int => c: Int default 0}").unwrap();
+        let wanted = vec![
+            column("b", "Int", true),
+            column("a", "Int", false),
+            column("c", "Int", false),
+        ];
+        let report = cozo_ensure_relation(&db, "pairs".into(), wanted).unwrap();
+        let kinds: Vec<_> = report
+            .mismatches
+            .iter()
+            .map(|m| (m.column.as_str(), m.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("b", SchemaMismatchKind::Position),
+                ("a", SchemaMismatchKind::Key),
+                ("a", SchemaMismatchKind::Position),
+                ("c", SchemaMismatchKind::Default),
+            ]
+        );
+    }
+
+    #[test]
+    fn schema_diff_reports_relation_column_and_index_changes() {
+        let a = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let b = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&a, ":create old {id: Int}").unwrap();
+        run_write_op(&a, ":create users {id: Int => name: String}").unwrap();
+        run_write_op(&a, "::index create users:by_name {name}").unwrap();
+        run_write_op(&b, ":create new {id: Int}").unwrap();
+        run_write_op(&b, ":create users {id: Int => name: String, age: Int}").unwrap();
+        run_write_op(&b, "::index create users:by_age {age}").unwrap();
+
+        let diff = cozo_schema_diff(&a, &b).unwrap();
+        assert_eq!(diff.added_relations, ["new"]);
+        assert_eq!(diff.removed_relations, ["old"]);
+        let users = &diff.changed_relations[0];
+        assert_eq!(users.name, "users");
+        assert_eq!(users.columns.len(), 1);
+        assert_eq!(users.columns[0].column, "age");
+        assert_eq!(users.columns[0].kind, SchemaMismatchKind::Unexpected);
+        assert_eq!(users.added_indices, ["by_age"]);
+        assert_eq!(users.removed_indices, ["by_name"]);
+
+        let same = cozo_schema_diff(&a, &a).unwrap();
+        assert!(same.added_relations.is_empty() && same.changed_relations.is_empty());
+    }
+
+    #[test]
+    fn schema_diff_backup_compares_with_the_file() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create users {id: Int}").unwrap();
+        let backup = TempFile::private().unwrap();
+        db.instance().unwrap().backup_db(backup.path()).unwrap();
+        run_write_op(&db, ":create extra {id: Int}").unwrap();
+
+        let diff = cozo_schema_diff_backup(&db, backup.path().into()).unwrap();
+        assert_eq!(diff.removed_relations, ["extra"]);
+        assert!(diff.added_relations.is_empty() && diff.changed_relations.is_empty());
+        assert!(cozo_schema_diff_backup(&db, "/nonexistent/backup.db".into()).is_err());
+    }
+}
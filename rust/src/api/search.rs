@@ -0,0 +1,674 @@
+use std::collections::BTreeMap;
+
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability};
+
+use crate::api::busy_retry::retry_busy;
+use crate::api::column_crypto::decrypt_rows;
+use crate::api::error::catch_panic;
+use crate::api::row_policy::policy_condition;
+use crate::api::schema::{column_names, relation_columns, system_op};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::value::{string_literal, CozoValue};
+use crate::api::vector::run_write_op;
+
+/// Variables the score and extracted text of a hit are bound to, unlikely
+/// to clash with column names.
+const SCORE_VAR: &str = "score__";
+const TEXT_VAR: &str = "text__";
+
+/// Joins the columns of a multi-column full-text index; see
+/// [`cozo_create_fts_index`].
+const COLUMN_SEPARATOR: &str = "|";
+
+/// Words of context kept before the first match in a snippet, at most; fewer
+/// are kept when the snippet is too short to hold them and the match.
+const SNIPPET_CONTEXT_WORDS: usize = 8;
+
+/// How a full-text index splits text into tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FtsTokenizer {
+    /// The whole text is one token.
+    Raw,
+    /// Split on whitespace and punctuation.
+    Simple,
+    /// Split on whitespace only.
+    Whitespace,
+    /// Every run of `min_gram` to `max_gram` characters, for substring
+    /// matching; with `prefix_only`, only the runs at the start of the text.
+    NGram {
+        min_gram: u32,
+        max_gram: u32,
+        prefix_only: bool,
+    },
+    /// Chinese word segmentation with jieba.
+    ///
+    /// - `kind`: "default", "all", "search" or "unicode"
+    /// - `use_hmm`: also find words missing from the dictionary
+    Cangjie { kind: String, use_hmm: bool },
+}
+
+/// A step applied to every token, in order, after tokenizing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FtsFilter {
+    Lowercase,
+    /// Drop tokens that are not purely alphanumeric.
+    AlphaNumOnly,
+    /// Replace accented Latin characters with their ASCII counterparts.
+    AsciiFolding,
+    /// Drop tokens of `limit` bytes or more.
+    RemoveLong {
+        limit: u32,
+    },
+    /// Reduce words to their stem, e.g. "running" to "run".
+    ///
+    /// - `language`: e.g. "english" or "french"
+    Stemmer {
+        language: String,
+    },
+    /// Drop common words of a language.
+    ///
+    /// - `language`: ISO 639-1 code, e.g. "en" or "de"
+    Stopwords {
+        language: String,
+    },
+    /// Drop the given words.
+    StopwordList {
+        words: Vec<String>,
+    },
+}
+
+/// Tokenization settings of a full-text index.
+#[derive(Debug, Clone)]
+pub struct FtsConfig {
+    pub tokenizer: FtsTokenizer,
+    /// Applied in order; usually starts with `Lowercase`.
+    pub filters: Vec<FtsFilter>,
+    /// If set, a `Stopwords` filter for this language is applied after
+    /// `filters`.
+    pub stopwords_lang: Option<String>,
+}
+
+/// Create a full-text search index over text columns of a stored relation.
+///
+/// - `name`: index name, used as `~relation:name{...}` in queries
+/// - `columns`: the indexed text columns; several columns are indexed as
+///   their values joined with `|`, which the `Simple` tokenizer splits on
+///   but `Whitespace` does not. Rows where every column is null are not
+///   indexed.
+///
+/// Existing rows are indexed before this returns, and later writes keep the
+/// index up to date. Drop the index with `cozo_drop_index`.
+pub fn cozo_create_fts_index(
+    db: &CozoDb,
+    relation: String,
+    name: String,
+    columns: Vec<String>,
+    config: FtsConfig,
+) -> anyhow::Result<()> {
+    let script = format!(
+        "::fts create {}:{} {{ {}, tokenizer: {}{} }}",
+        relation,
+        name,
+        extractor(&columns)?,
+        tokenizer(&config.tokenizer),
+        filters(&config.filters, config.stopwords_lang.as_deref())
+    );
+    run_write_op(db, &script)
+}
+
+/// How [`cozo_fts_search`] builds snippets.
+#[derive(Debug, Clone)]
+pub struct SnippetConfig {
+    /// Put before each matched word, e.g. `<b>`.
+    pub open: String,
+    /// Put after each matched word, e.g. `</b>`.
+    pub close: String,
+    /// Maximum number of words in a snippet.
+    pub max_words: u32,
+}
+
+/// A row found by [`cozo_fts_search`].
+#[derive(Debug, Clone)]
+pub struct FtsHit {
+    /// Key column values of the row, in key order.
+    pub key: Vec<CozoValue>,
+    /// Relevance, higher is better.
+    pub score: f64,
+    /// Indexed text around the first matched word, with matches marked.
+    /// `None` if no snippets were asked for.
+    pub snippet: Option<String>,
+}
+
+/// Search a full-text index.
+///
+/// - `relation`, `index`: the indexed relation and the index name
+/// - `query`: cozo full-text query, e.g. `cat AND dog`, `"exact phrase"`
+///   or `prefix*`
+/// - `limit`, `offset`: the page of hits to return
+/// - `snippets`: if set, each hit gets a snippet of its indexed text
+///
+/// Snippets mark the words that start with a query term, ignoring case.
+/// They are built from the text as stored, so a word the index only matched
+/// after stemming or folding may not be marked.
+///
+/// Returns the hits, best first.
+pub fn cozo_fts_search(
+    db: &CozoDb,
+    relation: String,
+    index: String,
+    query: String,
+    limit: u32,
+    offset: u32,
+    snippets: Option<SnippetConfig>,
+) -> anyhow::Result<Vec<FtsHit>> {
+    let inner = db.instance()?;
+    let config = system_op(&inner, &format!("::indices {}", relation))?
+        .into_iter()
+        .find(|row| row[0].get_str() == Some(index.as_str()) && row[1].get_str() == Some("fts"))
+        .map(|row| serde_json::Value::from(row[3].clone()))
+        .ok_or_else(|| anyhow::anyhow!("No full-text index {}:{}", relation, index))?;
+    let columns = relation_columns(&inner, &relation)?;
+    let n_keys = columns.iter().filter(|c| c.is_key).count();
+    let names = column_names(&columns);
+    let text = match (&snippets, config["extractor"].as_str()) {
+        // Show the columns of a multi-column index separated by spaces.
+        (Some(_), Some(extractor)) => format!(
+            ", {} = {}",
+            TEXT_VAR,
+            extractor.replace(&format!("\"{}\"", COLUMN_SEPARATOR), "' '")
+        ),
+        _ => format!(", {} = null", TEXT_VAR),
+    };
+    let mut params = BTreeMap::new();
+    let policy = policy_condition(&db.row_policies, &relation, &mut params);
+    let script = format!(
+        "?[{names}, {s}, {t}] := ~{relation}:{index}{{{names} | query: $query, k: $k, bind_score: {s}}}{policy}{text}\n:order -{s}\n:limit $limit\n:offset $offset",
+        s = SCORE_VAR,
+        t = TEXT_VAR,
+    );
+    params.extend([
+        ("query".to_string(), DataValue::from(query.as_str())),
+        (
+            "k".to_string(),
+            DataValue::from(i64::from(limit) + i64::from(offset)),
+        ),
+        ("limit".to_string(), DataValue::from(i64::from(limit))),
+        ("offset".to_string(), DataValue::from(i64::from(offset))),
+    ]);
+    let rows = run_search(db, &inner, &script, params)?;
+    let terms = query_terms(&query);
+    Ok(rows
+        .rows
+        .into_iter()
+        .map(|mut row| {
+            let text = row.pop();
+            let score = row.pop().and_then(|s| s.get_float()).unwrap_or_default();
+            let snippet = snippets.as_ref().map(|config| {
+                let text = text.as_ref().and_then(|t| t.get_str()).unwrap_or_default();
+                snippet(text, &terms, config)
+            });
+            row.truncate(n_keys);
+            FtsHit {
+                key: row.into_iter().map(CozoValue::from).collect(),
+                score,
+                snippet,
+            }
+        })
+        .collect())
+}
+
+/// Lowercased words of a full-text query, without its operators.
+fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !matches!(*w, "AND" | "OR" | "NOT" | "NEAR"))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Up to `max_words` words of `text` around the first word matching a term,
+/// with every match marked. Cut-off text is shown as `…`.
+fn snippet(text: &str, terms: &[String], config: &SnippetConfig) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let matches = |word: &str| {
+        let word = word.to_lowercase();
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        terms.iter().any(|t| word.starts_with(t.as_str()))
+    };
+    let max_words = (config.max_words as usize).max(1);
+    let first = words.iter().position(|w| matches(w)).unwrap_or(0);
+    let before = SNIPPET_CONTEXT_WORDS.min((max_words - 1) / 2);
+    let start = first
+        .saturating_sub(before)
+        .min(words.len().saturating_sub(max_words));
+    let end = (start + max_words).min(words.len());
+    let mut out: Vec<String> = words[start..end]
+        .iter()
+        .map(|w| {
+            if matches(w) {
+                format!("{}{}{}", config.open, w, config.close)
+            } else {
+                w.to_string()
+            }
+        })
+        .collect();
+    if start > 0 {
+        out.insert(0, "…".to_string());
+    }
+    if end < words.len() {
+        out.push("…".to_string());
+    }
+    out.join(" ")
+}
+
+/// Settings of a MinHash-LSH index.
+#[derive(Debug, Clone)]
+pub struct LshConfig {
+    pub tokenizer: FtsTokenizer,
+    pub filters: Vec<FtsFilter>,
+    /// Tokens per shingle: 1 compares sets of words, higher values also
+    /// take word order into account.
+    pub n_gram: u32,
+    /// Number of hash permutations; more is more accurate but larger and
+    /// slower. Cozo defaults to 200.
+    pub n_perm: u32,
+    /// Jaccard similarity, between 0 and 1, above which two texts count as
+    /// near duplicates. Cozo defaults to 0.9.
+    pub target_threshold: f64,
+    /// Relative cost of reporting a pair below the threshold; cozo
+    /// defaults to 1.
+    pub false_positive_weight: f64,
+    /// Relative cost of missing a pair above the threshold; cozo defaults
+    /// to 1.
+    pub false_negative_weight: f64,
+}
+
+/// A row found by [`cozo_lsh_search`].
+#[derive(Debug, Clone)]
+pub struct LshHit {
+    /// Key column values of the row, in key order.
+    pub key: Vec<CozoValue>,
+    /// The full row, in column order.
+    pub row: Vec<CozoValue>,
+}
+
+/// Create a MinHash-LSH index for finding near-duplicate texts.
+///
+/// - `name`: index name, used as `~relation:name{...}` in queries
+/// - `columns`: the indexed text columns, combined as for
+///   `cozo_create_fts_index`
+///
+/// Existing rows are indexed before this returns, and later writes keep the
+/// index up to date. Drop the index with `cozo_drop_index`.
+pub fn cozo_create_lsh_index(
+    db: &CozoDb,
+    relation: String,
+    name: String,
+    columns: Vec<String>,
+    config: LshConfig,
+) -> anyhow::Result<()> {
+    let script = format!(
+        "::lsh create {}:{} {{ {}, tokenizer: {}{}, n_gram: {}, n_perm: {}, target_threshold: {:?}, false_positive_weight: {:?}, false_negative_weight: {:?} }}",
+        relation,
+        name,
+        extractor(&columns)?,
+        tokenizer(&config.tokenizer),
+        filters(&config.filters, None),
+        config.n_gram,
+        config.n_perm,
+        config.target_threshold,
+        config.false_positive_weight,
+        config.false_negative_weight
+    );
+    run_write_op(db, &script)
+}
+
+/// Find the rows whose indexed text is a near duplicate of `text`.
+///
+/// - `relation`, `index`: the indexed relation and the index name
+/// - `text`: the text to compare, e.g. a note being edited
+/// - `k`: maximum number of hits; `None` returns every near duplicate
+/// - `filter`: CozoScript expression over the relation's columns; only rows
+///   for which it is true are returned
+///
+/// To find the duplicates of a stored row, pass its text and skip its own
+/// key in the hits. Hits are candidates estimated from the index, in no
+/// particular order; compare the texts to confirm them.
+pub fn cozo_lsh_search(
+    db: &CozoDb,
+    relation: String,
+    index: String,
+    text: String,
+    k: Option<u32>,
+    filter: Option<String>,
+) -> anyhow::Result<Vec<LshHit>> {
+    let inner = db.instance()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let n_keys = columns.iter().filter(|c| c.is_key).count();
+    let names = column_names(&columns);
+    let mut options = vec!["query: $query".to_string()];
+    if let Some(k) = k {
+        options.push(format!("k: {}", k));
+    }
+    if let Some(filter) = filter {
+        options.push(format!("filter: {}", filter));
+    }
+    let mut params = BTreeMap::new();
+    let policy = policy_condition(&db.row_policies, &relation, &mut params);
+    let script = format!(
+        "?[{names}] := ~{relation}:{index}{{{names} | {}}}{policy}",
+        options.join(", ")
+    );
+    params.insert("query".to_string(), DataValue::from(text.as_str()));
+    let rows = run_search(db, &inner, &script, params)?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .map(|row| {
+            let row: Vec<CozoValue> = row.into_iter().map(CozoValue::from).collect();
+            LshHit {
+                key: row[..n_keys].to_vec(),
+                row,
+            }
+        })
+        .collect())
+}
+
+/// Run the script of a search call, which keeps to the relation's row
+/// policy through [`policy_condition`], decrypting encrypted columns of the
+/// hits.
+pub(crate) fn run_search(
+    db: &CozoDb,
+    inner: &DbInstance,
+    script: &str,
+    params: BTreeMap<String, DataValue>,
+) -> anyhow::Result<NamedRows> {
+    let mut rows = retry_busy(&db.busy_retry, || {
+        catch_panic(|| inner.run_script(script, params.clone(), ScriptMutability::Immutable))
+    })
+    .map_err(|err| query_error(err, script))?;
+    decrypt_rows(&db.column_cipher, &mut rows)?;
+    Ok(rows)
+}
+
+/// The `extractor` and `extract_filter` options for `columns`.
+fn extractor(columns: &[String]) -> anyhow::Result<String> {
+    let extractor = match columns {
+        [] => anyhow::bail!("At least one column must be indexed"),
+        [column] => column.clone(),
+        columns => {
+            // cozo stores the extractor as text and drops whitespace from
+            // string literals when reading it back, so the columns cannot be
+            // joined with a space.
+            let parts: Vec<String> = columns.iter().map(|c| format!("{} ~ ''", c)).collect();
+            format!(
+                "concat({})",
+                parts.join(&format!(", '{}', ", COLUMN_SEPARATOR))
+            )
+        }
+    };
+    let not_null: Vec<String> = columns.iter().map(|c| format!("!is_null({})", c)).collect();
+    Ok(format!(
+        "extractor: {}, extract_filter: {}",
+        extractor,
+        not_null.join(" || ")
+    ))
+}
+
+fn tokenizer(tokenizer: &FtsTokenizer) -> String {
+    match tokenizer {
+        FtsTokenizer::Raw => "Raw".to_string(),
+        FtsTokenizer::Simple => "Simple".to_string(),
+        FtsTokenizer::Whitespace => "Whitespace".to_string(),
+        FtsTokenizer::NGram {
+            min_gram,
+            max_gram,
+            prefix_only,
+        } => format!("NGram({}, {}, {})", min_gram, max_gram, prefix_only),
+        FtsTokenizer::Cangjie { kind, use_hmm } => {
+            format!("Cangjie({}, {})", string_literal(kind), use_hmm)
+        }
+    }
+}
+
+fn filters(filters: &[FtsFilter], stopwords_lang: Option<&str>) -> String {
+    let mut filters: Vec<String> = filters
+        .iter()
+        .map(|filter| match filter {
+            FtsFilter::Lowercase => "Lowercase".to_string(),
+            FtsFilter::AlphaNumOnly => "AlphaNumOnly".to_string(),
+            FtsFilter::AsciiFolding => "AsciiFolding".to_string(),
+            FtsFilter::RemoveLong { limit } => format!("RemoveLong({})", limit),
+            FtsFilter::Stemmer { language } => format!("Stemmer({})", string_literal(language)),
+            FtsFilter::Stopwords { language } => format!("Stopwords({})", string_literal(language)),
+            FtsFilter::StopwordList { words } => {
+                let words: Vec<String> = words.iter().map(|w| string_literal(w)).collect();
+                format!("Stopwords([{}])", words.join(", "))
+            }
+        })
+        .collect();
+    if let Some(language) = stopwords_lang {
+        filters.push(format!("Stopwords({})", string_literal(language)));
+    }
+    // cozo rejects an empty filter list.
+    if filters.is_empty() {
+        String::new()
+    } else {
+        format!(", filters: [{}]", filters.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+
+    fn config(tokenizer: FtsTokenizer, filters: Vec<FtsFilter>) -> FtsConfig {
+        FtsConfig {
+            tokenizer,
+            filters,
+            stopwords_lang: None,
+        }
+    }
+
+    /// A database with `notes {id => title, body}` holding three notes.
+    fn notes() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(
+            &db,
+            ":create notes {id: Int => title: String?, body: String?}",
+        )
+        .unwrap();
+        run_write_op(
+            &db,
+            "?[id, title, body] <- [
+                [1, 'Garden', 'The dogs were running in the garden'],
+                [2, 'Kitchen', 'A cat sleeps on the warm kitchen floor'],
+                [3, null, null]
+             ] :put notes {id => title, body}",
+        )
+        .unwrap();
+        db
+    }
+
+    fn ids(hits: &[FtsHit]) -> Vec<i64> {
+        hits.iter()
+            .map(|hit| match hit.key[..] {
+                [CozoValue::Int(id)] => id,
+                _ => panic!("{:?}", hit.key),
+            })
+            .collect()
+    }
+
+    fn search(db: &CozoDb, index: &str, query: &str) -> Vec<i64> {
+        let hits = cozo_fts_search(db, "notes".into(), index.into(), query.into(), 10, 0, None);
+        ids(&hits.unwrap())
+    }
+
+    #[test]
+    fn fts_options_are_written_as_cozo_expects() {
+        let ngram = FtsTokenizer::NGram {
+            min_gram: 2,
+            max_gram: 3,
+            prefix_only: true,
+        };
+        assert_eq!(tokenizer(&ngram), "NGram(2, 3, true)");
+        let cangjie = FtsTokenizer::Cangjie {
+            kind: "search".into(),
+            use_hmm: false,
+        };
+        assert_eq!(tokenizer(&cangjie), "Cangjie('search', false)");
+
+        let list = vec![
+            FtsFilter::Lowercase,
+            FtsFilter::RemoveLong { limit: 20 },
+            FtsFilter::StopwordList {
+                words: vec!["it's".into()],
+            },
+        ];
+        assert_eq!(
+            filters(&list, Some("en")),
+            r#", filters: [Lowercase, RemoveLong(20), Stopwords(['it\'s']), Stopwords('en')]"#
+        );
+        assert_eq!(filters(&[], None), "");
+        assert!(extractor(&[]).is_err());
+    }
+
+    #[test]
+    fn fts_indices_apply_their_config() {
+        let db = notes();
+        let stemmed = config(
+            FtsTokenizer::Simple,
+            vec![
+                FtsFilter::Lowercase,
+                FtsFilter::Stemmer {
+                    language: "english".into(),
+                },
+            ],
+        );
+        let columns = vec!["title".to_string(), "body".to_string()];
+        cozo_create_fts_index(&db, "notes".into(), "text".into(), columns, stemmed).unwrap();
+        assert_eq!(search(&db, "text", "run"), [1]);
+        assert_eq!(search(&db, "text", "kitchen"), [2]);
+
+        let raw = config(FtsTokenizer::Whitespace, vec![]);
+        cozo_create_fts_index(&db, "notes".into(), "raw".into(), vec!["body".into()], raw).unwrap();
+        assert!(search(&db, "raw", "run").is_empty());
+        assert_eq!(search(&db, "raw", "running"), [1]);
+
+        let bad = config(FtsTokenizer::Simple, vec![]);
+        let missing = vec!["missing".to_string()];
+        assert!(cozo_create_fts_index(&db, "notes".into(), "bad".into(), missing, bad).is_err());
+    }
+
+    fn marks() -> SnippetConfig {
+        SnippetConfig {
+            open: "<b>".into(),
+            close: "</b>".into(),
+            max_words: 4,
+        }
+    }
+
+    #[test]
+    fn fts_search_pages_and_marks_snippets() {
+        let db = notes();
+        let simple = config(FtsTokenizer::Simple, vec![FtsFilter::Lowercase]);
+        let columns = vec!["title".to_string(), "body".to_string()];
+        cozo_create_fts_index(&db, "notes".into(), "text".into(), columns, simple).unwrap();
+        let page = |limit, offset| {
+            let hits = cozo_fts_search(
+                &db,
+                "notes".into(),
+                "text".into(),
+                "the".into(),
+                limit,
+                offset,
+                None,
+            );
+            ids(&hits.unwrap())
+        };
+        let all = page(10, 0);
+        assert_eq!(all.len(), 2);
+        assert_eq!(page(1, 0), all[..1]);
+        assert_eq!(page(1, 1), all[1..]);
+        assert!(page(1, 2).is_empty());
+
+        let hits = cozo_fts_search(
+            &db,
+            "notes".into(),
+            "text".into(),
+            "garden".into(),
+            10,
+            0,
+            Some(marks()),
+        )
+        .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].score > 0.0);
+        assert_eq!(
+            hits[0].snippet.as_deref(),
+            Some("<b>Garden</b> The dogs were …")
+        );
+
+        let missing = cozo_fts_search(&db, "notes".into(), "nope".into(), "a".into(), 1, 0, None);
+        assert_eq!(
+            missing.unwrap_err().to_string(),
+            "No full-text index notes:nope"
+        );
+    }
+
+    #[test]
+    fn snippets_centre_on_the_first_match() {
+        let text = "one two three four five six seven eight nine ten eleven twelve";
+        let terms = query_terms("NOT ELEV* AND ten");
+        assert_eq!(terms, ["elev", "ten"]);
+        assert_eq!(
+            snippet(text, &terms, &marks()),
+            "… nine <b>ten</b> <b>eleven</b> twelve"
+        );
+        let wide = SnippetConfig {
+            max_words: 3,
+            ..marks()
+        };
+        assert_eq!(snippet("Ten, then", &terms, &wide), "<b>Ten,</b> then");
+    }
+
+    #[test]
+    fn lsh_search_finds_near_duplicates() {
+        let db = notes();
+        run_write_op(
+            &db,
+            "?[id, title, body] <- [[4, 'Garden', 'The dogs were running in the garden today']]
+             :put notes {id => title, body}",
+        )
+        .unwrap();
+        let lsh = LshConfig {
+            tokenizer: FtsTokenizer::Simple,
+            filters: vec![FtsFilter::Lowercase],
+            n_gram: 1,
+            n_perm: 200,
+            target_threshold: 0.5,
+            false_positive_weight: 1.0,
+            false_negative_weight: 1.0,
+        };
+        cozo_create_lsh_index(&db, "notes".into(), "dup".into(), vec!["body".into()], lsh).unwrap();
+        let search = |k, filter: Option<&str>| {
+            let text = "the dogs were running in the garden";
+            let filter = filter.map(String::from);
+            let hits = cozo_lsh_search(&db, "notes".into(), "dup".into(), text.into(), k, filter);
+            let mut ids: Vec<i64> = hits
+                .unwrap()
+                .iter()
+                .map(|hit| match hit.row[..] {
+                    [CozoValue::Int(id), ..] => id,
+                    _ => panic!("{:?}", hit.row),
+                })
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(search(None, None), [1, 4]);
+        assert_eq!(search(Some(1), None).len(), 1);
+        assert_eq!(search(None, Some("id != 1")), [4]);
+    }
+}
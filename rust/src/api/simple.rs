@@ -1,25 +1,457 @@
-use cozo::DbInstance;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{mpsc, Arc, Mutex, PoisonError, RwLock};
+use std::time::Instant;
+
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability};
 use flutter_rust_bridge::frb;
 
+use crate::api::audit::Auditor;
+use crate::api::backup::{restore_file, TempFile};
+use crate::api::busy_retry::{retry_busy, BusyRetryPolicy};
+use crate::api::column_crypto::{cipher_from_options, decrypt_rows, ColumnCipher};
+use crate::api::compression::decompressed_backup;
+use crate::api::error::{catch_panic, failure, CozoDartError, ErrorKind};
+use crate::api::export::export_relation;
+use crate::api::file_lock::{lock_database, FileLock};
+use crate::api::logging::DartLogger;
+use crate::api::metrics::{record_query, Metrics};
+use crate::api::query_cache::QueryCache;
+use crate::api::row_policy::{apply_row_policies, filter_returning, run_on, RowPolicies};
+use crate::api::running::{run_tracked, RunningQueries};
+use crate::api::slow_log::SlowLog;
+use crate::api::storage::set_journal_mode;
+use crate::api::transaction::TxRegistry;
+use crate::api::value::{params_from_values, CozoValue, QueryResult};
+use crate::remote::RemoteDb;
+
 /// Opaque handle to a CozoDB database instance.
 /// FRB will manage this as a Rust opaque type in Dart.
 #[frb(opaque)]
 pub struct CozoDb {
-    inner: DbInstance,
+    /// `None` once [`cozo_close_db`] has been called. Shared with handles such
+    /// as prepared queries so that closing the database closes them too.
+    inner: Arc<RwLock<Option<DbInstance>>>,
+    /// Relation change callbacks registered through this handle, by id.
+    pub(crate) callbacks: Arc<Mutex<BTreeMap<u32, String>>>,
+    /// Queries started through [`cozo_run_tracked_query`], by caller-chosen id.
+    pub(crate) running: Arc<RunningQueries>,
+    /// Queries slower than the configured threshold, see `cozo_slow_queries`.
+    pub(crate) slow_log: Arc<Mutex<SlowLog>>,
+    /// Counters behind `cozo_metrics`.
+    pub(crate) metrics: Arc<Mutex<Metrics>>,
+    /// Results of `cozo_run_query_cached`, see `cozo_enable_query_cache`.
+    pub(crate) query_cache: Arc<Mutex<QueryCache>>,
+    /// Watches and writer of `cozo_enable_audit`.
+    pub(crate) audit: Arc<Mutex<Auditor>>,
+    /// Set by `cozo_set_row_policy`, applied to every script run.
+    pub(crate) row_policies: Arc<Mutex<RowPolicies>>,
+    /// From the `column_key` open option, see `cozo_encrypt_column`.
+    pub(crate) column_cipher: Arc<ColumnCipher>,
+    /// Transactions begun with `cozo_begin_tx` and not yet finished.
+    pub(crate) transactions: Arc<TxRegistry>,
+    /// Set by `cozo_set_busy_retry`.
+    pub(crate) busy_retry: Arc<Mutex<Option<BusyRetryPolicy>>>,
+    /// This process's claim on the database file, given back on close.
+    pub(crate) file_lock: Arc<Mutex<Option<FileLock>>>,
+    /// Stops the scheduler started by `cozo_enable_auto_backup` when dropped.
+    pub(crate) auto_backup: Mutex<Option<mpsc::Sender<()>>>,
+    /// Set by the `read_only` open option: every script runs immutable and
+    /// direct writes are refused.
+    pub(crate) read_only: bool,
+    /// From the `heap_limit_bytes` open option, applied by `cozo_run_query`.
+    pub(crate) heap_limit: Option<u64>,
+    /// Engine and path the database was opened with, for calls that work on
+    /// the storage files directly.
+    pub(crate) engine: String,
+    pub(crate) path: String,
+    /// Set for handles opened with the "http" engine, which have no local
+    /// instance: queries, export and import go to the server instead.
+    remote: Option<Arc<RemoteDb>>,
+}
+
+impl CozoDb {
+    pub(crate) fn from_instance(db: DbInstance, engine: &str, path: &str, read_only: bool) -> Self {
+        CozoDb {
+            inner: Arc::new(RwLock::new(Some(db))),
+            callbacks: Default::default(),
+            running: Default::default(),
+            slow_log: Default::default(),
+            metrics: Default::default(),
+            query_cache: Default::default(),
+            audit: Default::default(),
+            row_policies: Default::default(),
+            column_cipher: Default::default(),
+            transactions: Default::default(),
+            busy_retry: Default::default(),
+            file_lock: Default::default(),
+            auto_backup: Default::default(),
+            read_only,
+            heap_limit: None,
+            engine: engine.to_string(),
+            path: path.to_string(),
+            remote: None,
+        }
+    }
+
+    fn from_remote(remote: RemoteDb, url: &str, read_only: bool) -> Self {
+        CozoDb {
+            inner: Arc::new(RwLock::new(None)),
+            callbacks: Default::default(),
+            running: Default::default(),
+            slow_log: Default::default(),
+            metrics: Default::default(),
+            query_cache: Default::default(),
+            audit: Default::default(),
+            row_policies: Default::default(),
+            column_cipher: Default::default(),
+            transactions: Default::default(),
+            busy_retry: Default::default(),
+            file_lock: Default::default(),
+            auto_backup: Default::default(),
+            read_only,
+            heap_limit: None,
+            engine: "http".to_string(),
+            path: url.to_string(),
+            remote: Some(Arc::new(remote)),
+        }
+    }
+
+    /// The underlying instance, or a "database closed" error after [`cozo_close_db`].
+    ///
+    /// `DbInstance` is a cheap handle over shared storage, so callers get their own
+    /// clone and the lock is not held while a query runs.
+    pub(crate) fn instance(&self) -> anyhow::Result<DbInstance> {
+        if let Some(remote) = &self.remote {
+            if remote.is_closed() {
+                return Err(closed_error());
+            }
+            anyhow::bail!("This call is not supported on a remote (http) database");
+        }
+        live_instance(&self.inner)
+    }
+
+    /// Like [`CozoDb::instance`], but fails on a read-only handle. Used by
+    /// calls that write without going through a script.
+    pub(crate) fn writable(&self) -> anyhow::Result<DbInstance> {
+        if self.read_only {
+            anyhow::bail!("Database is opened read-only");
+        }
+        self.instance()
+    }
+
+    /// Run a script the way every query call does: with the row policies
+    /// applied to what it reads and returns, retried while SQLite is busy, and with encrypted columns of
+    /// the result decrypted.
+    pub(crate) fn run_rows(
+        &self,
+        inner: &DbInstance,
+        script: &str,
+        mut params: BTreeMap<String, DataValue>,
+        mutability: ScriptMutability,
+    ) -> anyhow::Result<NamedRows> {
+        let script = apply_row_policies(&self.row_policies, script, &mut params)?;
+        self.run_rewritten(inner, &script, params, mutability)
+    }
+
+    /// [`CozoDb::run_rows`] for a script the row policies were already
+    /// applied to, such as a prepared query's.
+    pub(crate) fn run_rewritten(
+        &self,
+        inner: &DbInstance,
+        script: &str,
+        params: BTreeMap<String, DataValue>,
+        mutability: ScriptMutability,
+    ) -> anyhow::Result<NamedRows> {
+        let rows = retry_busy(&self.busy_retry, || {
+            catch_panic(|| inner.run_script(script, params.clone(), mutability))
+        })
+        .map_err(|err| query_error(err, script))?;
+        let mut rows = filter_returning(&self.row_policies, &run_on(inner), script, rows)?;
+        decrypt_rows(&self.column_cipher, &mut rows)?;
+        Ok(rows)
+    }
+
+    /// Run `run` under the handle's heap limit, if it has one.
+    pub(crate) fn with_heap_limit<T>(
+        &self,
+        script: &str,
+        run: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        match self.heap_limit {
+            Some(limit) if !self.is_remote() => run_tracked(self, None, script, Some(limit), run),
+            _ => run(),
+        }
+    }
+
+    /// Whether the handle was opened with the "http" engine.
+    pub(crate) fn is_remote(&self) -> bool {
+        self.remote.is_some()
+    }
+
+    /// Script mutability for a call, forced to immutable on a read-only handle.
+    pub(crate) fn mutability(&self, immutable: bool) -> ScriptMutability {
+        mutability(immutable || self.read_only)
+    }
+
+    /// Another handle on the same database. Closing either closes both, and
+    /// callbacks registered through one are closed with the other.
+    pub(crate) fn share(&self) -> CozoDb {
+        CozoDb {
+            inner: self.inner.clone(),
+            callbacks: self.callbacks.clone(),
+            running: Default::default(),
+            slow_log: self.slow_log.clone(),
+            metrics: self.metrics.clone(),
+            query_cache: self.query_cache.clone(),
+            audit: self.audit.clone(),
+            row_policies: self.row_policies.clone(),
+            column_cipher: self.column_cipher.clone(),
+            transactions: self.transactions.clone(),
+            busy_retry: self.busy_retry.clone(),
+            file_lock: self.file_lock.clone(),
+            auto_backup: Default::default(),
+            read_only: self.read_only,
+            heap_limit: self.heap_limit,
+            engine: self.engine.clone(),
+            path: self.path.clone(),
+            remote: self.remote.clone(),
+        }
+    }
+
+    /// The shared slot behind [`CozoDb::instance`], for handles that outlive a call.
+    pub(crate) fn shared(&self) -> Arc<RwLock<Option<DbInstance>>> {
+        self.inner.clone()
+    }
+}
+
+/// Clone the instance out of a shared slot, or fail if the database was closed.
+pub(crate) fn live_instance(inner: &RwLock<Option<DbInstance>>) -> anyhow::Result<DbInstance> {
+    inner
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .ok_or_else(closed_error)
+}
+
+pub(crate) fn closed_error() -> anyhow::Error {
+    CozoDartError::new(ErrorKind::Closed, "Database is closed").into()
 }
 
 /// Open a new CozoDB database.
 ///
-/// - `engine`: "mem" for in-memory, "sqlite" for persistent SQLite
-/// - `path`: file path for sqlite engine, empty string for mem
-/// - `options`: JSON string of engine options, use "{}" for defaults
+/// - `engine`: "mem" for in-memory, "sqlite" for persistent SQLite,
+///   "rocksdb" for persistent RocksDB (requires the `rocksdb` crate feature),
+///   "http" for a remote cozo-server
+/// - `path`: file path for sqlite, directory for rocksdb, empty string for
+///   mem, server URL such as `https://host:9070` for http
+/// - `options`: JSON string of engine options, use "{}" for defaults.
+///   `{"read_only": true}` opens an existing database for reading only: all
+///   scripts run immutable and writes fail at the binding layer. SQLite takes
+///   its write lock only while writing, so a read-only handle never holds one
+///   and can share a file owned by another process.
+///
+/// `{"heap_limit_bytes": n}` makes `cozo_run_query` stop a query during
+/// which the process's Rust heap grows by more than `n` bytes, so a runaway
+/// join fails with a `Query failed [heap_limit]` error before the OS kills
+/// the app. The limit is off unless set, and not available on the web. It
+/// caps the growth of the whole process's heap, not the query's own
+/// allocations: memory allocated meanwhile by other queries, handles or
+/// threads counts too and can stop an unrelated query, see
+/// `QueryOptions::heap_limit_bytes`.
+///
+/// At-rest encryption is not available: cozo opens its own pooled SQLite
+/// connections with no hook for a SQLCipher key, so an `encryption_key`
+/// option is rejected rather than silently writing plaintext. Sensitive
+/// columns can be encrypted instead: `{"column_key": hex}`, 64 hex digits
+/// of a 256-bit key kept e.g. in the platform keystore, enables
+/// `cozo_encrypt_column` on the handle.
+///
+/// For the sqlite engine, `{"journal_mode": "wal"}` switches the file to
+/// write-ahead logging, so reads no longer wait for a write to commit; the
+/// mode is stored in the file and kept by later opens. "delete", "truncate"
+/// and "persist" switch back to a rollback journal. The per-connection
+/// PRAGMAs `synchronous`, `cache_size`, `mmap_size` and `busy_timeout` are
+/// rejected for the same reason as `encryption_key`: cozo opens its own
+/// connections with no hook to set them.
+///
+/// A writable sqlite or rocksdb handle claims the database for its process
+/// through the lock file `<path>.lock` until it is closed, so that a second
+/// process opening it, such as an Android widget or receiver process, fails
+/// with an `ErrorKind::Locked` error naming the holder's pid instead of
+/// SQLite's "database is locked" on its first write. Opens within one
+/// process share the claim, and read-only handles take none.
+///
+/// A remote handle sends [`cozo_run_query`], [`cozo_export_relations`] and
+/// [`cozo_import_relations`] to the server, authenticating with the `auth`
+/// option (the server's auth token) and giving up after `timeout_secs`
+/// (default 60). Other calls fail on a remote handle.
+///
+/// On the web (wasm builds) only "mem" is available.
 ///
 /// Returns an opaque CozoDb handle.
 #[frb(sync)]
 pub fn cozo_open_db(engine: String, path: String, options: String) -> anyhow::Result<CozoDb> {
-    let db = DbInstance::new_with_str(&engine, &path, &options)
-        .map_err(|e| anyhow::anyhow!("Failed to open database: {}", e))?;
-    Ok(CozoDb { inner: db })
+    if engine == "rocksdb" && !cfg!(feature = "rocksdb") {
+        return Err(failure(ErrorKind::Storage, "Failed to open database: the rocksdb engine requires building cozo_dart with the `rocksdb` feature"));
+    }
+    if cfg!(target_family = "wasm") && engine != "mem" {
+        return Err(failure(
+            ErrorKind::Storage,
+            format!(
+                "Failed to open database: only the mem engine is available on the web, not {}",
+                engine
+            ),
+        ));
+    }
+    let opts: serde_json::Value = if options.trim().is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(&options).map_err(|e| {
+            failure(
+                ErrorKind::Storage,
+                format!("Failed to open database: invalid options JSON: {}", e),
+            )
+        })?
+    };
+    if opts.get("encryption_key").is_some() {
+        return Err(failure(
+            ErrorKind::Storage,
+            "Failed to open database: the `encryption_key` option is not supported, \
+             cozo's storage engines do not support SQLCipher encryption",
+        ));
+    }
+    if let Some(pragma) = CONNECTION_PRAGMAS
+        .iter()
+        .find(|pragma| opts.get(**pragma).is_some())
+    {
+        return Err(failure(
+            ErrorKind::Storage,
+            format!(
+                "Failed to open database: the `{}` option is not supported, \
+             cozo opens its own SQLite connections with default settings",
+                pragma
+            ),
+        ));
+    }
+    let read_only = opts
+        .get("read_only")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if engine == "http" {
+        return Ok(CozoDb::from_remote(
+            RemoteDb::new(&path, &opts)?,
+            &path,
+            read_only,
+        ));
+    }
+    if read_only && engine != "mem" && !std::path::Path::new(&path).exists() {
+        return Err(failure(
+            ErrorKind::Storage,
+            format!(
+                "Failed to open database: {} does not exist, read-only mode cannot create it",
+                path
+            ),
+        ));
+    }
+    let file_lock = match engine.as_str() {
+        "sqlite" | "rocksdb" if !read_only => Some(lock_database(&path)?),
+        _ => None,
+    };
+    if let Some(mode) = opts.get("journal_mode") {
+        let mode = mode.as_str().ok_or_else(|| {
+            failure(
+                ErrorKind::Storage,
+                "Failed to open database: `journal_mode` must be a string",
+            )
+        })?;
+        set_journal_mode(&engine, &path, read_only, mode).map_err(|e| {
+            failure(
+                ErrorKind::Storage,
+                format!("Failed to open database: {}", e),
+            )
+        })?;
+    }
+    let db = DbInstance::new_with_str(&engine, &path, &options).map_err(|e| {
+        failure(
+            ErrorKind::Storage,
+            format!("Failed to open database: {}", e),
+        )
+    })?;
+    let mut db = CozoDb::from_instance(db, &engine, &path, read_only);
+    db.file_lock = Arc::new(Mutex::new(file_lock));
+    if let Some(limit) = opts.get("heap_limit_bytes") {
+        // Enforcing the limit needs a watcher thread, which wasm lacks.
+        if cfg!(target_family = "wasm") && !limit.is_null() {
+            return Err(failure(
+                ErrorKind::Storage,
+                "Failed to open database: `heap_limit_bytes` is not available on the web",
+            ));
+        }
+        db.heap_limit = match limit {
+            serde_json::Value::Null => None,
+            limit => Some(limit.as_u64().ok_or_else(|| {
+                failure(
+                    ErrorKind::Storage,
+                    "Failed to open database: `heap_limit_bytes` must be a number or null",
+                )
+            })?),
+        };
+    }
+    db.column_cipher = Arc::new(RwLock::new(cipher_from_options(&opts)?));
+    Ok(db)
+}
+
+/// SQLite PRAGMAs that only apply to the connection they are set on.
+const CONNECTION_PRAGMAS: [&str; 4] = ["synchronous", "cache_size", "mmap_size", "busy_timeout"];
+
+/// Close the database, releasing the storage (and the SQLite file lock) once
+/// queries already in flight have finished.
+///
+/// Change streams registered through this handle are closed, and automatic
+/// backups and auditing stop. Any later call on the handle fails with a
+/// "Database is closed" error. Closing twice is a no-op.
+#[frb(sync)]
+pub fn cozo_close_db(db: &CozoDb) {
+    if let Some(remote) = &db.remote {
+        remote.close();
+    }
+    db.auto_backup
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    if let Some(inner) = db
+        .inner
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+    {
+        let mut callbacks = db.callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+        for id in callbacks.keys() {
+            inner.unregister_callback(*id);
+        }
+        callbacks.clear();
+        db.audit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .stop(&inner);
+    }
+    db.file_lock
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+}
+
+/// Whether [`cozo_close_db`] has been called on this handle.
+#[frb(sync)]
+pub fn cozo_is_closed(db: &CozoDb) -> bool {
+    match &db.remote {
+        Some(remote) => remote.is_closed(),
+        None => db
+            .inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none(),
+    }
 }
 
 /// Run a CozoScript query.
@@ -29,46 +461,199 @@ pub fn cozo_open_db(engine: String, path: String, options: String) -> anyhow::Re
 /// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
 /// - `immutable`: if true, the query is run in read-only mode
 ///
-/// Returns a JSON string with the query result.
+/// This is not marked `#[frb(sync)]`: FRB runs it on its worker thread pool
+/// and Dart receives a `Future`, so long queries never block the UI isolate.
+///
+/// Returns a JSON string with the query result. Query failures are returned
+/// as errors carrying the error kind (e.g. `parser::pest`) and the rendered
+/// display message, so they surface as Dart exceptions. A panic inside cozo
+/// is returned as an `Internal error` rather than unwinding into the bridge.
+/// A query during which the heap grows past the handle's `heap_limit_bytes`
+/// is stopped, see [`cozo_open_db`].
 pub fn cozo_run_query(
     db: &CozoDb,
     script: String,
     params_json: String,
     immutable: bool,
-) -> String {
-    db.inner.run_script_str(&script, &params_json, immutable)
+) -> anyhow::Result<String> {
+    db.with_heap_limit(&script, || run_query(db, &script, &params_json, immutable))
+}
+
+/// [`cozo_run_query`] without the heap limit.
+pub(crate) fn run_query(
+    db: &CozoDb,
+    script: &str,
+    params_json: &str,
+    immutable: bool,
+) -> anyhow::Result<String> {
+    if let Some(remote) = &db.remote {
+        return remote.query(script, params_json, immutable || db.read_only);
+    }
+    let params = parse_params(params_json)?;
+    let (rows, took) = record_rows(db, script, params_json, |inner| {
+        db.run_rows(inner, script, params, db.mutability(immutable))
+    })?;
+    Ok(rows_to_json(rows, took))
+}
+
+/// Time a query that `run` runs on the local database, and record it in the
+/// handle's metrics and slow query log under `script`, with its parameters
+/// shown as `params_text`.
+///
+/// Returns the rows and the seconds the query took.
+pub(crate) fn record_rows(
+    db: &CozoDb,
+    script: &str,
+    params_text: &str,
+    run: impl FnOnce(&DbInstance) -> anyhow::Result<NamedRows>,
+) -> anyhow::Result<(NamedRows, f64)> {
+    let start = Instant::now();
+    let result = db.instance().and_then(|inner| catch_panic(|| run(&inner)));
+    let took = start.elapsed();
+    record_query(db, script, took, &result, |rows| rows.rows.len());
+    let rows = result?;
+    db.slow_log
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .record(script, params_text, took, rows.rows.len());
+    Ok((rows, took.as_secs_f64()))
+}
+
+/// Run a CozoScript query, failing with a structured [`CozoDartError`].
+///
+/// Arguments and the result are the same as [`cozo_run_query`], and the
+/// error message is the same text, but Dart receives the error's kind,
+/// diagnostic code, script span and relation as fields to branch on.
+pub fn cozo_run_query_checked(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    immutable: bool,
+) -> Result<String, CozoDartError> {
+    cozo_run_query(db, script, params_json, immutable).map_err(CozoDartError::from)
+}
+
+/// Run a CozoScript query with typed parameters and return a typed result.
+///
+/// - `params`: named parameters as [`CozoValue`]s, so bytes, big integers
+///   and vectors are passed without JSON encoding; a `Uint8List` bound as
+///   [`CozoValue::Bytes`] is stored as a `Bytes` value with no base64 step
+///
+/// Other arguments are the same as [`cozo_run_query`], and the query runs
+/// the same way, but the rows come back as [`QueryResult`] with typed
+/// values instead of a JSON string.
+/// This skips the intermediate JSON allocation entirely, which matters for
+/// results with large string or bytes columns.
+/// Failures are reported through `ok` and `error`.
+pub fn cozo_run_query_typed(
+    db: &CozoDb,
+    script: String,
+    params: HashMap<String, CozoValue>,
+    immutable: bool,
+) -> QueryResult {
+    let params = match params_from_values(params) {
+        Ok(params) => params,
+        Err(e) => return QueryResult::from_error(e.to_string(), 0.0),
+    };
+    let params_text = serde_json::Value::Object(
+        params
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone().into()))
+            .collect(),
+    )
+    .to_string();
+    let start = Instant::now();
+    let result = db.with_heap_limit(&script, || {
+        record_rows(db, &script, &params_text, |inner| {
+            db.run_rows(inner, &script, params, db.mutability(immutable))
+        })
+    });
+    match result {
+        Ok((rows, took)) => QueryResult::from_rows(rows, took),
+        Err(e) => QueryResult::from_error(e.to_string(), start.elapsed().as_secs_f64()),
+    }
 }
 
 /// Export relations from the database.
 ///
-/// - `relations_json`: JSON array of relation names, e.g. '["users", "edges"]'
+/// - `relations_json`: JSON array of relation names, e.g. '["users", "edges"]',
+///   or cozo's `{"relations": [..]}`
+///
+/// Relations with a row policy only export the rows it allows, see
+/// `cozo_set_row_policy`.
 ///
 /// Returns JSON string with exported data.
 pub fn cozo_export_relations(db: &CozoDb, relations_json: String) -> String {
-    db.inner.export_relations_str(&relations_json)
+    if let Some(remote) = &db.remote {
+        return remote.export(&relations_json).unwrap_or_else(|e| {
+            serde_json::json!({"ok": false, "message": e.to_string()}).to_string()
+        });
+    }
+    let exported = db.instance().and_then(|inner| {
+        catch_panic(|| {
+            let payload: serde_json::Value =
+                serde_json::from_str(&relations_json).map_err(|e| {
+                    failure(
+                        ErrorKind::ImportExport,
+                        format!("Export failed: invalid relations JSON: {}", e),
+                    )
+                })?;
+            let names = payload.get("relations").unwrap_or(&payload);
+            let relations: Vec<String> = serde_json::from_value(names.clone()).map_err(|e| {
+                failure(
+                    ErrorKind::ImportExport,
+                    format!("Export failed: invalid relations JSON: {}", e),
+                )
+            })?;
+            let mut data = serde_json::Map::new();
+            for relation in relations {
+                let rows = export_relation(db, &inner, &relation)?;
+                data.insert(relation, rows.into_json());
+            }
+            Ok(serde_json::json!({"ok": true, "data": data}).to_string())
+        })
+    });
+    exported
+        .unwrap_or_else(|e| serde_json::json!({"ok": false, "message": e.to_string()}).to_string())
 }
 
 /// Import relations into the database.
 ///
 /// - `data_json`: JSON string in the same format as export output.
 pub fn cozo_import_relations(db: &CozoDb, data_json: String) -> anyhow::Result<()> {
-    db.inner
-        .import_relations_str_with_err(&data_json)
-        .map_err(|e| anyhow::anyhow!("Import failed: {}", e))
+    if let Some(remote) = &db.remote {
+        if db.read_only {
+            anyhow::bail!("Database is opened read-only");
+        }
+        return remote.import(&data_json);
+    }
+    let inner = db.writable()?;
+    catch_panic(|| {
+        inner
+            .import_relations_str_with_err(&data_json)
+            .map_err(|e| failure(ErrorKind::ImportExport, format!("Import failed: {}", e)))
+    })
 }
 
 /// Backup the database to a file path.
 pub fn cozo_backup(db: &CozoDb, path: String) -> anyhow::Result<()> {
-    db.inner
-        .backup_db(&path)
-        .map_err(|e| anyhow::anyhow!("Backup failed: {}", e))
+    let inner = db.instance()?;
+    catch_panic(|| {
+        inner
+            .backup_db(&path)
+            .map_err(|e| failure(ErrorKind::Storage, format!("Backup failed: {}", e)))
+    })
 }
 
-/// Restore the database from a backup file.
+/// Restore the database from a backup file, compressed or not.
 pub fn cozo_restore(db: &CozoDb, path: String) -> anyhow::Result<()> {
-    db.inner
-        .restore_backup(&path)
-        .map_err(|e| anyhow::anyhow!("Restore failed: {}", e))
+    let inner = db.writable()?;
+    let plain = decompressed_backup(&path)?;
+    let path = plain.as_ref().map_or(path.as_str(), TempFile::path);
+    catch_panic(|| {
+        restore_file(&inner, path)
+            .map_err(|e| failure(ErrorKind::Storage, format!("Restore failed: {}", e)))
+    })
 }
 
 /// Import relations from a backup file without fully restoring.
@@ -79,12 +664,249 @@ pub fn cozo_import_from_backup(
 ) -> anyhow::Result<()> {
     let relations: Vec<String> = serde_json::from_str(&relations_json)
         .map_err(|e| anyhow::anyhow!("Invalid relations JSON: {}", e))?;
-    db.inner
-        .import_from_backup(&path, &relations)
-        .map_err(|e| anyhow::anyhow!("Import from backup failed: {}", e))
+    let inner = db.writable()?;
+    let plain = decompressed_backup(&path)?;
+    let path = plain.as_ref().map_or(path.as_str(), TempFile::path);
+    catch_panic(|| {
+        inner.import_from_backup(path, &relations).map_err(|e| {
+            failure(
+                ErrorKind::ImportExport,
+                format!("Import from backup failed: {}", e),
+            )
+        })
+    })
+}
+
+/// Parse a JSON object of named parameters into cozo values.
+pub(crate) fn parse_params(params_json: &str) -> anyhow::Result<BTreeMap<String, DataValue>> {
+    if params_json.is_empty() {
+        return Ok(BTreeMap::new());
+    }
+    let map: BTreeMap<String, serde_json::Value> = serde_json::from_str(params_json)
+        .map_err(|e| anyhow::anyhow!("params argument is not a JSON map: {}", e))?;
+    Ok(map
+        .into_iter()
+        .map(|(k, v)| (k, DataValue::from(v)))
+        .collect())
+}
+
+/// Render successful rows in the same JSON shape as `run_script_str`.
+pub(crate) fn rows_to_json(rows: NamedRows, took: f64) -> String {
+    let mut json = rows.into_json();
+    if let Some(map) = json.as_object_mut() {
+        map.insert("ok".to_string(), serde_json::json!(true));
+        map.insert("took".to_string(), serde_json::json!(took));
+    }
+    json.to_string()
+}
+
+pub(crate) fn mutability(immutable: bool) -> ScriptMutability {
+    if immutable {
+        ScriptMutability::Immutable
+    } else {
+        ScriptMutability::Mutable
+    }
+}
+
+/// Convert a failed query into an error whose message keeps the error kind.
+/// The error wraps a [`CozoDartError`] with the kind, span and relation.
+pub(crate) fn query_error(err: cozo::Error, script: &str) -> anyhow::Error {
+    CozoDartError::from_query(err, script).into()
 }
 
 #[frb(init)]
 pub fn init_app() {
+    // Installed first so that log records can be forwarded to Dart.
+    DartLogger::install();
     flutter_rust_bridge::setup_default_user_utils();
 }
+
+/// Set the number of worker threads that run async (non-`sync`) calls.
+///
+/// - `threads`: pool size, at least 1. Low-end devices can cap parallelism
+///   with a small pool; desktop builds can match the number of cores.
+///
+/// Takes effect immediately. Shrinking the pool lets running calls finish
+/// first; extra threads exit as they become idle.
+///
+/// Returns the previous pool size.
+#[frb(sync)]
+pub fn cozo_configure(threads: u32) -> anyhow::Result<u32> {
+    if threads == 0 {
+        anyhow::bail!("The worker thread pool needs at least 1 thread");
+    }
+    // Clones of the pool share its state, so resizing a clone resizes the
+    // pool behind the bridge handler.
+    let mut pool = crate::frb_generated::FLUTTER_RUST_BRIDGE_HANDLER
+        .thread_pool()
+        .0
+        .clone();
+    let previous = pool.max_count() as u32;
+    pool.set_num_threads(threads as usize);
+    Ok(previous)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::callback::cozo_register_callback;
+    use crate::api::metrics::cozo_metrics;
+    use crate::api::row_policy::cozo_set_row_policy;
+    use crate::api::vector::run_write_op;
+    use crate::frb_generated::StreamSink;
+
+    #[test]
+    fn typed_queries_take_the_query_path() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int => owner: String}").unwrap();
+        cozo_set_row_policy(&db, "notes".into(), "owner == 'me'".into()).unwrap();
+        let params = HashMap::from([(
+            "rows".to_string(),
+            CozoValue::List(vec![
+                CozoValue::List(vec![CozoValue::Int(1), CozoValue::Str("me".into())]),
+                CozoValue::List(vec![CozoValue::Int(2), CozoValue::Str("them".into())]),
+            ]),
+        )]);
+        let script = "?[id, owner] <- $rows :put notes {id => owner} :returning";
+        let result = cozo_run_query_typed(&db, script.into(), params, false);
+        assert!(result.ok, "{:?}", result.error);
+        assert_eq!(result.rows.len(), 1);
+
+        let failed = cozo_run_query_typed(&db, "?[x] <- [[1]".into(), HashMap::new(), true);
+        assert!(failed
+            .error
+            .unwrap()
+            .starts_with("Query failed [parser::pest]"));
+        assert_eq!(cozo_metrics(&db).queries, 2);
+    }
+
+    #[test]
+    fn query_failures_are_errors() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let ok = cozo_run_query(&db, "?[x] <- [[$x]]".into(), r#"{"x": 1}"#.into(), true);
+        assert!(ok.unwrap().contains(r#""rows":[[1]]"#));
+
+        let err = cozo_run_query(&db, "?[x] <- [[1]".into(), "{}".into(), true).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Query failed [parser::pest]"),
+            "{}",
+            err
+        );
+        let err = cozo_run_query(&db, "?[x] := *missing{x}".into(), "{}".into(), true);
+        assert!(err.is_err());
+        let err = cozo_run_query(&db, "?[x] <- [[1]]".into(), "[1]".into(), true);
+        assert!(err.is_err());
+    }
+
+    #[cfg(not(feature = "rocksdb"))]
+    #[test]
+    fn rocksdb_needs_its_feature() {
+        let err = cozo_open_db("rocksdb".into(), "data".into(), "{}".into())
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .contains("requires building cozo_dart with the `rocksdb` feature"));
+        assert!(!std::path::Path::new("data.lock").exists());
+    }
+
+    #[test]
+    fn closed_handles_refuse_calls() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int}").unwrap();
+        let shared = db.share();
+        cozo_register_callback(&db, "notes".into(), StreamSink::deserialize("0".into())).unwrap();
+        assert!(!cozo_is_closed(&db));
+
+        cozo_close_db(&db);
+        cozo_close_db(&db);
+        assert!(cozo_is_closed(&db) && cozo_is_closed(&shared));
+        let err = cozo_run_query(&shared, "?[x] <- [[1]]".into(), "{}".into(), true).unwrap_err();
+        assert_eq!(err.to_string(), "Database is closed");
+        assert!(db.callbacks.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn encryption_keys_are_rejected() {
+        let err = cozo_open_db(
+            "mem".into(),
+            String::new(),
+            r#"{"encryption_key": "k"}"#.into(),
+        )
+        .err()
+        .unwrap();
+        assert!(err
+            .to_string()
+            .contains("`encryption_key` option is not supported"));
+        let err = cozo_open_db(
+            "sqlite".into(),
+            "x.db".into(),
+            r#"{"encryption_key": ""}"#.into(),
+        );
+        assert!(err.is_err());
+        assert!(!std::path::Path::new("x.db").exists());
+    }
+
+    #[test]
+    fn read_only_handles_refuse_writes() {
+        let file = TempFile::private().unwrap();
+        std::fs::remove_file(file.path()).unwrap();
+        let lock = format!("{}.lock", file.path());
+        let missing = cozo_open_db(
+            "sqlite".into(),
+            file.path().into(),
+            r#"{"read_only": true}"#.into(),
+        );
+        assert!(missing
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("read-only mode cannot create it"));
+
+        let db = cozo_open_db("sqlite".into(), file.path().into(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int}").unwrap();
+        run_write_op(&db, "?[id] <- [[1]] :put notes {id}").unwrap();
+        cozo_close_db(&db);
+        std::fs::remove_file(&lock).unwrap();
+
+        let db = cozo_open_db(
+            "sqlite".into(),
+            file.path().into(),
+            r#"{"read_only": true}"#.into(),
+        )
+        .unwrap();
+        assert!(!std::path::Path::new(&lock).exists());
+        let read = cozo_run_query(&db, "?[id] := *notes{id}".into(), "{}".into(), false);
+        assert!(read.unwrap().contains("[[1]]"));
+        let put = "?[id] <- [[2]] :put notes {id}";
+        assert!(cozo_run_query(&db, put.into(), "{}".into(), false).is_err());
+        let err = db.writable().err().unwrap();
+        assert_eq!(err.to_string(), "Database is opened read-only");
+        cozo_close_db(&db);
+    }
+
+    #[test]
+    fn worker_pool_is_resized() {
+        assert!(cozo_configure(0).is_err());
+        let previous = cozo_configure(3).unwrap();
+        assert_eq!(cozo_configure(previous).unwrap(), 3);
+    }
+
+    #[test]
+    fn queries_run_concurrently_on_one_handle() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        std::thread::scope(|scope| {
+            for i in 0..4 {
+                let db = &db;
+                scope.spawn(move || {
+                    let put = format!("?[id] <- [[{}]] :put items {{id}}", i);
+                    cozo_run_query(db, put, "{}".into(), false).unwrap();
+                    cozo_run_query(db, "?[id] := *items{id}".into(), "{}".into(), true).unwrap();
+                });
+            }
+        });
+        let all = cozo_run_query(&db, "?[id] := *items{id}".into(), "{}".into(), true);
+        assert!(all.unwrap().contains("[[0],[1],[2],[3]]"));
+    }
+}
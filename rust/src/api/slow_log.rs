@@ -0,0 +1,154 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use flutter_rust_bridge::frb;
+
+use crate::api::running::epoch_secs;
+use crate::api::simple::CozoDb;
+
+/// Threshold a new handle starts with.
+const DEFAULT_THRESHOLD: Duration = Duration::from_millis(100);
+
+/// Entries a new handle keeps before dropping the oldest.
+const DEFAULT_CAPACITY: usize = 100;
+
+/// A query that ran longer than the slow-query threshold.
+#[derive(Debug, Clone)]
+pub struct SlowQuery {
+    pub script: String,
+    /// Hash of the parameters JSON, so queries with the same parameters can
+    /// be grouped without recording the values. Only comparable within one
+    /// run of the app.
+    pub params_digest: String,
+    /// Seconds the query took.
+    pub took: f64,
+    /// Rows returned.
+    pub rows: u64,
+    /// When the query finished, in seconds since the Unix epoch.
+    pub finished_at: f64,
+}
+
+/// Ring buffer of slow queries, shared by every handle to a database.
+pub(crate) struct SlowLog {
+    /// `None` disables recording.
+    threshold: Option<Duration>,
+    capacity: usize,
+    entries: VecDeque<SlowQuery>,
+}
+
+impl Default for SlowLog {
+    fn default() -> Self {
+        SlowLog {
+            threshold: Some(DEFAULT_THRESHOLD),
+            capacity: DEFAULT_CAPACITY,
+            entries: VecDeque::new(),
+        }
+    }
+}
+
+impl SlowLog {
+    /// Record a finished query if it took at least the threshold.
+    pub(crate) fn record(&mut self, script: &str, params_json: &str, took: Duration, rows: usize) {
+        if self.threshold.is_none_or(|t| took < t) || self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        let mut hasher = DefaultHasher::new();
+        params_json.hash(&mut hasher);
+        self.entries.push_back(SlowQuery {
+            script: script.to_string(),
+            params_digest: format!("{:016x}", hasher.finish()),
+            took: took.as_secs_f64(),
+            rows: rows as u64,
+            finished_at: epoch_secs(),
+        });
+    }
+}
+
+/// Configure the slow-query log of the database.
+///
+/// - `threshold_ms`: queries taking at least this long are recorded; `None`
+///   stops recording. New handles use 100 ms.
+/// - `capacity`: number of entries kept, oldest dropped first. New handles
+///   keep 100.
+///
+/// Queries run through `cozo_run_query` and the calls built on it are
+/// recorded. Existing entries beyond the new capacity are dropped.
+#[frb(sync)]
+pub fn cozo_configure_slow_log(db: &CozoDb, threshold_ms: Option<u32>, capacity: u32) {
+    let mut log = db.slow_log.lock().unwrap_or_else(PoisonError::into_inner);
+    log.threshold = threshold_ms.map(|ms| Duration::from_millis(ms.into()));
+    log.capacity = capacity as usize;
+    while log.entries.len() > log.capacity {
+        log.entries.pop_front();
+    }
+}
+
+/// The recorded slow queries, oldest first.
+#[frb(sync)]
+pub fn cozo_slow_queries(db: &CozoDb) -> Vec<SlowQuery> {
+    let log = db.slow_log.lock().unwrap_or_else(PoisonError::into_inner);
+    log.entries.iter().cloned().collect()
+}
+
+/// Remove every recorded slow query.
+#[frb(sync)]
+pub fn cozo_clear_slow_queries(db: &CozoDb) {
+    db.slow_log
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entries
+        .clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+
+    #[test]
+    fn only_queries_over_the_threshold_are_kept() {
+        let mut log = SlowLog {
+            capacity: 2,
+            ..Default::default()
+        };
+        log.record("fast", "{}", Duration::from_millis(99), 1);
+        assert!(log.entries.is_empty());
+        for (script, params) in [("a", "{}"), ("b", "{}"), ("c", r#"{"x": 1}"#)] {
+            log.record(script, params, DEFAULT_THRESHOLD, 3);
+        }
+        let scripts: Vec<_> = log.entries.iter().map(|e| e.script.as_str()).collect();
+        assert_eq!(scripts, ["b", "c"]);
+        assert_ne!(log.entries[0].params_digest, log.entries[1].params_digest);
+        assert_eq!(log.entries[0].rows, 3);
+
+        log.threshold = None;
+        log.record("d", "{}", Duration::from_secs(10), 0);
+        assert_eq!(log.entries.len(), 2);
+    }
+
+    #[test]
+    fn run_query_records_into_the_configured_log() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_configure_slow_log(&db, Some(0), 10);
+        cozo_run_query(&db, "?[x] <- [[1], [2]]".into(), "{}".into(), true).unwrap();
+        let queries = cozo_slow_queries(&db);
+        assert_eq!(queries.len(), 1);
+        assert_eq!(
+            (queries[0].script.as_str(), queries[0].rows),
+            ("?[x] <- [[1], [2]]", 2)
+        );
+
+        cozo_configure_slow_log(&db, Some(0), 0);
+        assert!(cozo_slow_queries(&db).is_empty());
+        cozo_configure_slow_log(&db, Some(0), 10);
+        cozo_run_query(&db, "?[x] <- [[1]]".into(), "{}".into(), true).unwrap();
+        cozo_clear_slow_queries(&db);
+        assert!(cozo_slow_queries(&db).is_empty());
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use cozo::{DataValue, DbInstance, ScriptMutability};
+
+use crate::api::error::catch_panic;
+use crate::api::rows::rows_to_data;
+use crate::api::schema::{
+    column_names, column_spec, cozo_list_relations, relation_columns, ColumnInfo,
+};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::temporal::{cozo_retract_rows, validity, validity_column};
+use crate::api::value::{string_literal, CozoValue};
+use crate::api::vector::run_write_op;
+
+/// Stored relation holding the soft-delete settings of relations:
+/// `{relation => column}`, with an empty column for time travel relations.
+const SOFT_DELETE_RELATION: &str = "cozo_soft_delete";
+
+/// How rows of a relation with soft deletes enabled are deleted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SoftDelete {
+    /// Set the named `Bool` column to true.
+    Flag(String),
+    /// Retract the row of a time travel relation.
+    Retract,
+}
+
+/// Make `cozo_delete_rows` keep the rows of `relation` and mark them
+/// deleted instead, so that [`cozo_restore_deleted`] can bring them back.
+///
+/// - `flag_column`: a non-key `Bool` column, typically declared with
+///   `default false`, that deleting sets to true; `cozo_get_row` then treats
+///   flagged rows as missing. `None` for a time travel relation, whose rows
+///   are retracted at the current time instead, as with `cozo_retract_rows`.
+///
+/// Only the helpers see the mode: scripts reading the relation must filter
+/// flagged rows themselves, and `:rm` still removes rows. The setting is
+/// stored in the `cozo_soft_delete` relation and survives restarts.
+pub fn cozo_enable_soft_delete(
+    db: &CozoDb,
+    relation: String,
+    flag_column: Option<String>,
+) -> anyhow::Result<()> {
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    match &flag_column {
+        Some(flag) => {
+            let fits = columns
+                .iter()
+                .any(|c| c.name == *flag && !c.is_key && c.column_type == "Bool");
+            if !fits {
+                anyhow::bail!("{} has no non-key Bool column {}", relation, flag);
+            }
+        }
+        None if validity_column(&columns).is_none() => anyhow::bail!(
+            "{} is not a time travel relation, so soft deletes need a flag column",
+            relation
+        ),
+        None => {}
+    }
+    ensure_settings(db)?;
+    let script = format!(
+        "?[relation, column] <- [[$relation, $column]] :put {} {{relation => column}}",
+        SOFT_DELETE_RELATION
+    );
+    let params = BTreeMap::from([
+        ("relation".to_string(), DataValue::from(relation)),
+        (
+            "column".to_string(),
+            DataValue::from(flag_column.unwrap_or_default()),
+        ),
+    ]);
+    catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(())
+}
+
+/// Make `cozo_delete_rows` remove rows of `relation` again. Rows already
+/// marked deleted stay as they are.
+pub fn cozo_disable_soft_delete(db: &CozoDb, relation: String) -> anyhow::Result<()> {
+    if !settings_exist(db)? {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            "?[relation] <- [[{}]] :rm {} {{relation}}",
+            string_literal(&relation),
+            SOFT_DELETE_RELATION
+        ),
+    )
+}
+
+/// Bring back rows of `relation` deleted while soft deletes were enabled.
+///
+/// - `keys`: one entry per row, holding the key column values in key order,
+///   leaving out the `Validity` of a time travel relation
+///
+/// A flagged row has its flag cleared. A retracted row is asserted again
+/// now, with the values it had when it was retracted. Keys of rows that are
+/// not deleted are ignored.
+///
+/// Returns the number of rows restored.
+pub fn cozo_restore_deleted(
+    db: &CozoDb,
+    relation: String,
+    keys: Vec<Vec<CozoValue>>,
+) -> anyhow::Result<u64> {
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    match soft_delete_mode(db, &inner, &relation)? {
+        Some(SoftDelete::Flag(flag)) => set_flag(&inner, &relation, &columns, &flag, keys, false),
+        Some(SoftDelete::Retract) => reassert(&inner, &relation, &columns, keys),
+        None => anyhow::bail!("Soft deletes are not enabled for {}", relation),
+    }
+}
+
+/// Soft-delete the rows at `keys` of `relation` as `mode` says.
+pub(crate) fn soft_delete_rows(
+    db: &CozoDb,
+    inner: &DbInstance,
+    relation: &str,
+    columns: &[ColumnInfo],
+    mode: SoftDelete,
+    keys: Vec<Vec<CozoValue>>,
+) -> anyhow::Result<u64> {
+    match mode {
+        SoftDelete::Flag(flag) => set_flag(inner, relation, columns, &flag, keys, true),
+        SoftDelete::Retract => cozo_retract_rows(db, relation.to_string(), keys, Utc::now()),
+    }
+}
+
+/// The soft-delete setting of `relation`.
+pub(crate) fn soft_delete_mode(
+    db: &CozoDb,
+    inner: &DbInstance,
+    relation: &str,
+) -> anyhow::Result<Option<SoftDelete>> {
+    if !settings_exist(db)? {
+        return Ok(None);
+    }
+    let script = format!(
+        "?[column] := *{}{{relation: $relation, column}}",
+        SOFT_DELETE_RELATION
+    );
+    let params = BTreeMap::from([("relation".to_string(), DataValue::from(relation))]);
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(rows.rows.first().map(|row| match row[0].get_str() {
+        Some("") | None => SoftDelete::Retract,
+        Some(flag) => SoftDelete::Flag(flag.to_string()),
+    }))
+}
+
+/// Set `flag` to `value` on the rows at `keys` where it is not already.
+fn set_flag(
+    inner: &DbInstance,
+    relation: &str,
+    columns: &[ColumnInfo],
+    flag: &str,
+    keys: Vec<Vec<CozoValue>>,
+    value: bool,
+) -> anyhow::Result<u64> {
+    let key_columns = column_names(columns.iter().filter(|c| c.is_key));
+    let keys = rows_to_data(keys, columns.iter().filter(|c| c.is_key).count())?;
+    let script = format!(
+        "keys[{keys}] <- $keys\n?[{keys}, {flag}] := keys[{keys}], *{rel}{{{keys}, {flag}: cozo_was}}, cozo_was != $value, {flag} = $value\n:update {rel} {{{keys} => {flag}}}\n:returning",
+        keys = key_columns,
+        flag = flag,
+        rel = relation,
+    );
+    let params = BTreeMap::from([
+        (
+            "keys".to_string(),
+            DataValue::List(keys.into_iter().map(DataValue::List).collect()),
+        ),
+        ("value".to_string(), DataValue::from(value)),
+    ]);
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .iter()
+        .filter(|row| row[0].get_str() == Some("inserted"))
+        .count() as u64)
+}
+
+/// Assert again, now, the rows at `keys` of a time travel relation whose
+/// latest version is a retraction.
+fn reassert(
+    inner: &DbInstance,
+    relation: &str,
+    columns: &[ColumnInfo],
+    keys: Vec<Vec<CozoValue>>,
+) -> anyhow::Result<u64> {
+    let at = validity_column(columns).unwrap_or_default();
+    let key_columns: Vec<&ColumnInfo> = columns.iter().filter(|c| c.is_key).collect();
+    let keys = rows_to_data(keys, key_columns.len() - 1)?;
+    let names = column_names(columns);
+    let script = format!(
+        "keys[{keys}] <- $keys\n?[{names}] := keys[{keys}], *{rel}{{{names}}}",
+        keys = column_names(key_columns[..key_columns.len() - 1].iter().copied()),
+        names = names,
+        rel = relation,
+    );
+    let params = BTreeMap::from([(
+        "keys".to_string(),
+        DataValue::List(keys.into_iter().map(DataValue::List).collect()),
+    )]);
+    let versions = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, &script))?;
+
+    // Versions of a row sort newest first.
+    let now = validity(Utc::now(), true);
+    let mut restored: Vec<Vec<DataValue>> = vec![];
+    let mut previous: Option<Vec<DataValue>> = None;
+    for row in versions.rows {
+        let key = row[..at].to_vec();
+        if previous.as_ref() == Some(&key) {
+            continue;
+        }
+        previous = Some(key);
+        if matches!(&row[at], DataValue::Validity(v) if !v.is_assert.0) {
+            let mut row = row;
+            row[at] = now.clone();
+            restored.push(row);
+        }
+    }
+    if restored.is_empty() {
+        return Ok(0);
+    }
+    let count = restored.len() as u64;
+    let script = format!(
+        "?[{}] <- $rows :put {} {{{}}}",
+        names,
+        relation,
+        column_spec(columns)
+    );
+    let params = BTreeMap::from([(
+        "rows".to_string(),
+        DataValue::List(restored.into_iter().map(DataValue::List).collect()),
+    )]);
+    catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(count)
+}
+
+fn settings_exist(db: &CozoDb) -> anyhow::Result<bool> {
+    Ok(cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == SOFT_DELETE_RELATION))
+}
+
+fn ensure_settings(db: &CozoDb) -> anyhow::Result<()> {
+    if settings_exist(db)? {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            ":create {} {{relation: String => column: String}}",
+            SOFT_DELETE_RELATION
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::rows::{cozo_delete_rows, cozo_get_row};
+    use crate::api::simple::cozo_open_db;
+
+    fn key(id: i64) -> Vec<CozoValue> {
+        vec![CozoValue::Int(id)]
+    }
+
+    fn open() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(
+            &db,
+            ":create notes {id: Int => body: String, deleted: Bool default false}",
+        )
+        .unwrap();
+        run_write_op(
+            &db,
+            "?[id, body] <- [[1, 'a'], [2, 'b']] :put notes {id => body}",
+        )
+        .unwrap();
+        db
+    }
+
+    fn stored_rows(db: &CozoDb) -> usize {
+        db.instance()
+            .unwrap()
+            .run_script(
+                "?[id] := *notes{id}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap()
+            .rows
+            .len()
+    }
+
+    #[test]
+    fn flagged_rows_are_hidden_and_restored() {
+        let db = open();
+        cozo_enable_soft_delete(&db, "notes".into(), Some("deleted".into())).unwrap();
+        assert_eq!(
+            cozo_delete_rows(&db, "notes".into(), vec![key(1)]).unwrap(),
+            1
+        );
+        assert_eq!(cozo_get_row(&db, "notes".into(), key(1)).unwrap(), None);
+        assert!(cozo_get_row(&db, "notes".into(), key(2)).unwrap().is_some());
+        let stored = db
+            .instance()
+            .unwrap()
+            .run_script(
+                "?[id] := *notes{id, deleted: true}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap();
+        assert_eq!(stored.rows, [[DataValue::from(1)]]);
+
+        let restored = cozo_restore_deleted(&db, "notes".into(), vec![key(1), key(2)]).unwrap();
+        assert_eq!(restored, 1);
+        assert!(cozo_get_row(&db, "notes".into(), key(1)).unwrap().is_some());
+
+        cozo_disable_soft_delete(&db, "notes".into()).unwrap();
+        cozo_delete_rows(&db, "notes".into(), vec![key(1)]).unwrap();
+        assert!(cozo_restore_deleted(&db, "notes".into(), vec![key(1)]).is_err());
+        assert_eq!(stored_rows(&db), 1);
+    }
+
+    #[test]
+    fn time_travel_rows_are_retracted_and_asserted_again() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create docs {id: Int, at: Validity => body: String}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, at, body] <- [[1, 'ASSERT', 'a']] :put docs {id, at => body}",
+        )
+        .unwrap();
+        cozo_enable_soft_delete(&db, "docs".into(), None).unwrap();
+        let now = |db: &CozoDb| {
+            db.instance()
+                .unwrap()
+                .run_script(
+                    "?[id, body] := *docs{id, body @ 'NOW'}",
+                    Default::default(),
+                    ScriptMutability::Immutable,
+                )
+                .unwrap()
+                .rows
+        };
+        assert_eq!(
+            cozo_delete_rows(&db, "docs".into(), vec![key(1)]).unwrap(),
+            1
+        );
+        assert!(now(&db).is_empty());
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert_eq!(
+            cozo_restore_deleted(&db, "docs".into(), vec![key(1)]).unwrap(),
+            1
+        );
+        assert_eq!(now(&db), [[DataValue::from(1), DataValue::from("a")]]);
+    }
+
+    #[test]
+    fn settings_are_checked() {
+        let db = open();
+        assert!(cozo_enable_soft_delete(&db, "notes".into(), Some("body".into())).is_err());
+        assert!(cozo_enable_soft_delete(&db, "notes".into(), Some("id".into())).is_err());
+        assert!(cozo_enable_soft_delete(&db, "notes".into(), None).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use cozo::{DataValue, ScriptMutability, Vector};
+
+use crate::api::backup::file_size;
+use crate::api::error::catch_panic;
+use crate::api::schema::{column_names, cozo_list_relations, relation_columns, system_op};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::storage::sqlite_pragma;
+
+/// Bytes added to every stored row: the relation prefix of its key.
+const ROW_OVERHEAD: u64 = 8;
+
+/// Storage usage of one stored relation, see [`cozo_relation_stats`].
+#[derive(Debug, Clone)]
+pub struct RelationStats {
+    pub name: String,
+    pub rows: u64,
+    /// Estimated size of the rows in storage, from the encoded size of each
+    /// value. Indices and storage-engine overhead are not included.
+    pub approx_bytes: u64,
+    /// Indices on the relation.
+    pub indices: Vec<IndexSummary>,
+    /// Latest `Validity` timestamp in the key, in microseconds, for
+    /// relations keyed by validity (time travel). Cozo keeps no modification
+    /// time for other relations, so this is `None` for them.
+    pub last_modified: Option<i64>,
+}
+
+/// An index of a stored relation, as listed by `::indices`.
+#[derive(Debug, Clone)]
+pub struct IndexSummary {
+    pub name: String,
+    /// "normal", "hnsw", "fts" or "lsh".
+    pub index_type: String,
+}
+
+/// Row count, estimated size and indices of a stored relation, e.g. for a
+/// settings screen showing storage per table.
+///
+/// Scans the whole relation, so the time taken grows with its size.
+pub fn cozo_relation_stats(db: &CozoDb, name: String) -> anyhow::Result<RelationStats> {
+    let inner = db.instance()?;
+    let columns = relation_columns(&inner, &name)?;
+    let names = column_names(&columns);
+    let script = format!("?[{}] := *{}{{{}}}", names, name, names);
+    let rows =
+        catch_panic(|| inner.run_script(&script, Default::default(), ScriptMutability::Immutable))
+            .map_err(|err| query_error(err, &script))?
+            .rows;
+
+    // Time travel relations have a Validity as their last key column.
+    let validity = columns
+        .iter()
+        .rfind(|c| c.is_key)
+        .filter(|c| c.column_type == "Validity")
+        .map(|c| c.index as usize);
+    let last_modified = validity.and_then(|i| {
+        rows.iter()
+            .filter_map(|row| match &row[i] {
+                DataValue::Validity(v) => Some(v.timestamp.0 .0),
+                _ => None,
+            })
+            .max()
+    });
+    let approx_bytes = rows
+        .iter()
+        .map(|row| ROW_OVERHEAD + row.iter().map(encoded_size).sum::<u64>())
+        .sum();
+    let indices = system_op(&inner, &format!("::indices {}", name))?
+        .into_iter()
+        .map(|row| IndexSummary {
+            name: row[0].get_str().unwrap_or_default().to_string(),
+            index_type: row[1].get_str().unwrap_or_default().to_string(),
+        })
+        .collect();
+    Ok(RelationStats {
+        name,
+        rows: rows.len() as u64,
+        approx_bytes,
+        indices,
+        last_modified,
+    })
+}
+
+/// Storage usage of the whole database, see [`cozo_db_stats`].
+#[derive(Debug, Clone)]
+pub struct DbStats {
+    pub engine: String,
+    /// Size of the database file (sqlite) or directory (rocksdb), without
+    /// the write-ahead log. 0 for mem.
+    pub file_bytes: u64,
+    /// Size of the write-ahead log: the `-wal` file for sqlite in WAL mode
+    /// (or the rollback `-journal` during a write), the `.log` files for
+    /// rocksdb. 0 for mem.
+    pub wal_bytes: u64,
+    /// SQLite page size in bytes; `None` for other engines.
+    pub page_size: Option<u64>,
+    /// SQLite pages in the file; `None` for other engines.
+    pub page_count: Option<u64>,
+    /// SQLite pages that are unused, e.g. after deleting rows, and can be
+    /// reclaimed by compacting. `None` for other engines.
+    pub free_pages: Option<u64>,
+    /// Number of stored relations, not counting indices.
+    pub relations: u32,
+}
+
+/// Storage used by the database, e.g. for a "storage used by this app"
+/// screen or to decide when to compact.
+pub fn cozo_db_stats(db: &CozoDb) -> anyhow::Result<DbStats> {
+    let relations = cozo_list_relations(db)?
+        .iter()
+        .filter(|rel| rel.access_level != "index")
+        .count() as u32;
+    let mut stats = DbStats {
+        engine: db.engine.clone(),
+        file_bytes: 0,
+        wal_bytes: 0,
+        page_size: None,
+        page_count: None,
+        free_pages: None,
+        relations,
+    };
+    match db.engine.as_str() {
+        "sqlite" => {
+            let pragma = |name| sqlite_pragma(db, "Database stats", name);
+            stats.page_size = Some(pragma("page_size")?);
+            stats.page_count = Some(pragma("page_count")?);
+            stats.free_pages = Some(pragma("freelist_count")?);
+            stats.file_bytes = file_size(&db.path);
+            stats.wal_bytes =
+                file_size(&format!("{}-wal", db.path)) + file_size(&format!("{}-journal", db.path));
+        }
+        "rocksdb" => add_dir_sizes(std::path::Path::new(&db.path), &mut stats)
+            .map_err(|e| anyhow::anyhow!("Database stats failed: {}", e))?,
+        _ => {}
+    }
+    Ok(stats)
+}
+
+/// Add the files under a rocksdb directory to `file_bytes` and `wal_bytes`.
+fn add_dir_sizes(dir: &std::path::Path, stats: &mut DbStats) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            add_dir_sizes(&entry.path(), stats)?;
+        } else if entry.path().extension().is_some_and(|ext| ext == "log") {
+            stats.wal_bytes += metadata.len();
+        } else {
+            stats.file_bytes += metadata.len();
+        }
+    }
+    Ok(())
+}
+
+/// Approximate size of a value as cozo encodes it in storage.
+fn encoded_size(value: &DataValue) -> u64 {
+    match value {
+        DataValue::Null | DataValue::Bot | DataValue::Bool(_) => 1,
+        DataValue::Num(_) => 9,
+        DataValue::Str(s) => s.len() as u64 + 2,
+        DataValue::Bytes(b) => b.len() as u64 + 2,
+        DataValue::Uuid(_) => 17,
+        DataValue::Regex(r) => r.0.as_str().len() as u64 + 2,
+        DataValue::List(l) => l.iter().map(encoded_size).sum::<u64>() + 2,
+        DataValue::Set(s) => s.iter().map(encoded_size).sum::<u64>() + 2,
+        DataValue::Vec(Vector::F32(a)) => a.len() as u64 * 4 + 5,
+        DataValue::Vec(Vector::F64(a)) => a.len() as u64 * 8 + 5,
+        DataValue::Json(j) => j.0.to_string().len() as u64 + 2,
+        DataValue::Validity(_) => 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::backup::TempFile;
+    use crate::api::simple::{cozo_close_db, cozo_open_db};
+    use crate::api::vector::run_write_op;
+
+    #[test]
+    fn relation_stats_count_rows_and_list_indices() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => name: String}").unwrap();
+        run_write_op(&db, "::index create items:by_name {name}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, name] <- [[1, 'ab'], [2, 'cd']] :put items {id => name}",
+        )
+        .unwrap();
+
+        let stats = cozo_relation_stats(&db, "items".into()).unwrap();
+        assert_eq!(stats.rows, 2);
+        // Per row: the prefix, an Int and a two-byte string.
+        assert_eq!(stats.approx_bytes, 2 * (ROW_OVERHEAD + 9 + 4));
+        assert_eq!(stats.indices.len(), 1);
+        assert_eq!(stats.indices[0].name, "by_name");
+        assert_eq!(stats.indices[0].index_type, "normal");
+        assert_eq!(stats.last_modified, None);
+        assert!(cozo_relation_stats(&db, "missing".into()).is_err());
+    }
+
+    #[test]
+    fn relation_stats_report_the_latest_validity() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create prices {id: Int, at: Validity => price: Int}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, at, price] <- [[1, [10, true], 5], [1, [30, true], 6], [2, [20, true], 7]] \
+             :put prices {id, at => price}",
+        )
+        .unwrap();
+
+        let stats = cozo_relation_stats(&db, "prices".into()).unwrap();
+        assert_eq!(stats.rows, 3);
+        assert_eq!(stats.last_modified, Some(30));
+    }
+
+    #[test]
+    fn db_stats_for_mem_count_relations_only() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => name: String}").unwrap();
+        run_write_op(&db, "::index create items:by_name {name}").unwrap();
+
+        let stats = cozo_db_stats(&db).unwrap();
+        assert_eq!(stats.engine, "mem");
+        assert_eq!(stats.relations, 1);
+        assert_eq!((stats.file_bytes, stats.wal_bytes), (0, 0));
+        assert_eq!(stats.page_size, None);
+    }
+
+    #[test]
+    fn db_stats_for_sqlite_report_pages() {
+        let file = TempFile::private().unwrap();
+        let db = cozo_open_db("sqlite".into(), file.path().into(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => name: String}").unwrap();
+
+        let stats = cozo_db_stats(&db).unwrap();
+        let page_size = stats.page_size.unwrap();
+        assert!(page_size > 0);
+        assert!(stats.page_count.unwrap() > 0);
+        assert!(stats.free_pages.is_some());
+        assert!(stats.file_bytes >= page_size);
+        assert_eq!(stats.relations, 1);
+        cozo_close_db(&db);
+        let _ = std::fs::remove_file(format!("{}.lock", file.path()));
+    }
+}
@@ -0,0 +1,459 @@
+use std::path::Path;
+use std::time::Instant;
+
+use serde_json::json;
+
+use cozo::ScriptMutability;
+
+use crate::api::backup::with_progress;
+use crate::api::error::catch_panic;
+use crate::api::schema::{column_names, cozo_list_relations, relation_columns, system_op};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::stats::cozo_db_stats;
+use crate::api::vector::run_write_op;
+use crate::frb_generated::StreamSink;
+
+/// How long a statement on a separate connection waits for cozo's own
+/// writes to finish before failing with "database is locked".
+#[cfg(not(target_family = "wasm"))]
+const BUSY_TIMEOUT_MS: usize = 5000;
+
+/// Reclaim the space left behind by deleted rows, reporting progress
+/// through a stream.
+///
+/// For the sqlite engine this runs `VACUUM`, which rewrites the whole file
+/// and needs up to twice its size in free disk space while it runs; writes
+/// from other calls wait until it is done. For rocksdb it compacts all
+/// stored data. For mem it does nothing.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "bytes": n, "reclaimed": n | null, "elapsed": secs}`,
+/// where `bytes` is the size of the database files before compacting, and
+/// after compacting in the `done` event, which also carries the bytes
+/// `reclaimed`. The stream closes after the `done` event, or fails with the
+/// compaction error.
+pub fn cozo_compact(db: &CozoDb, sink: StreamSink<String>) -> anyhow::Result<()> {
+    db.writable()?;
+    let before = cozo_db_stats(db)?;
+    let before = before.file_bytes + before.wal_bytes;
+    let start = Instant::now();
+    let event = |stage: &str, bytes: u64, reclaimed: Option<u64>| {
+        json!({
+            "stage": stage,
+            "bytes": bytes,
+            "reclaimed": reclaimed,
+            "elapsed": start.elapsed().as_secs_f64(),
+        })
+        .to_string()
+    };
+
+    let _ = sink.add(event("started", before, None));
+    with_progress(
+        &sink,
+        || event("progress", before, None),
+        || match db.engine.as_str() {
+            "sqlite" => sqlite_query(db, "Compact", "VACUUM").map(|_| ()),
+            _ => {
+                run_write_op(db, "::compact").map_err(|e| anyhow::anyhow!("Compact failed: {}", e))
+            }
+        },
+    )?;
+    let after = cozo_db_stats(db)?;
+    let after = after.file_bytes + after.wal_bytes;
+    let _ = sink.add(event("done", after, Some(before.saturating_sub(after))));
+    Ok(())
+}
+
+/// Write everything committed so far into the database files and flush them
+/// to disk, e.g. from `didChangeAppLifecycleState` when the state becomes
+/// `AppLifecycleState.paused`.
+///
+/// iOS and Android may kill a paused app without warning. Committed data
+/// survives that on its own, but a device losing power or an OS crash can
+/// still drop writes the OS has not flushed yet; checkpointing on pause
+/// narrows that window and leaves a small `-wal` file for the next start.
+///
+/// For the sqlite engine in WAL mode this runs `PRAGMA wal_checkpoint(TRUNCATE)`,
+/// which copies the write-ahead log into the database file and empties it;
+/// then the files are synced to disk. For rocksdb the files in its directory
+/// are synced. For mem it does nothing. It is cheap when little has been
+/// written, so calling it on every pause is fine.
+///
+/// Returns false if a query still reading the database kept part of the log
+/// from being copied; the synced log keeps that part safe, and the next
+/// checkpoint copies it.
+pub fn cozo_checkpoint(db: &CozoDb) -> anyhow::Result<bool> {
+    db.writable()?;
+    let mut complete = true;
+    match db.engine.as_str() {
+        "sqlite" => {
+            let mode = sqlite_query(db, "Checkpoint", "PRAGMA journal_mode")?;
+            if mode.first().map(|m| m.to_ascii_lowercase()).as_deref() == Some("wal") {
+                // The first column is 1 if the checkpoint could not finish.
+                let busy = sqlite_query(db, "Checkpoint", "PRAGMA wal_checkpoint(TRUNCATE)")?;
+                complete = busy.first().map(String::as_str) != Some("1");
+            }
+            for suffix in ["", "-wal"] {
+                sync_file(Path::new(&format!("{}{}", db.path, suffix)))?;
+            }
+        }
+        "rocksdb" => {
+            let entries = std::fs::read_dir(&db.path)
+                .map_err(|e| anyhow::anyhow!("Checkpoint failed: {}", e))?;
+            for entry in entries {
+                let path = entry
+                    .map_err(|e| anyhow::anyhow!("Checkpoint failed: {}", e))?
+                    .path();
+                if path.is_file() {
+                    sync_file(&path)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(complete)
+}
+
+/// Flush `path` to disk if it exists.
+fn sync_file(path: &Path) -> anyhow::Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    // Opened for writing: Windows only flushes files opened that way.
+    std::fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.sync_all())
+        .map_err(|e| anyhow::anyhow!("Checkpoint failed: syncing {:?}: {}", path, e))
+}
+
+/// Outcome of [`cozo_check_integrity`].
+#[derive(Debug, Clone)]
+pub struct IntegrityReport {
+    /// Whether no problem was found.
+    pub ok: bool,
+    /// Problems found by the storage engine's own check, `PRAGMA
+    /// integrity_check` for sqlite. Always empty for mem and rocksdb, which
+    /// have no such check.
+    pub storage_errors: Vec<String>,
+    /// Stored relations whose schema, indices or rows could not be read.
+    pub relation_errors: Vec<RelationIntegrityError>,
+    /// Stored relations scanned, not counting indices.
+    pub relations_checked: u32,
+    /// Rows read while scanning them.
+    pub rows_checked: u64,
+}
+
+/// A stored relation that failed the check in [`cozo_check_integrity`].
+#[derive(Debug, Clone)]
+pub struct RelationIntegrityError {
+    /// The relation, or empty if the list of relations could not be read.
+    pub relation: String,
+    pub message: String,
+}
+
+/// Check that the database can be read in full, e.g. after a crash or
+/// before a backup, instead of finding out from a failing query later.
+///
+/// Runs the storage engine's integrity check, then reads the columns and
+/// indices of every stored relation and scans all of its rows. The scan
+/// reads every row, so this takes about as long as exporting the database.
+///
+/// Fails only if the database is closed; problems are listed in the report.
+pub fn cozo_check_integrity(db: &CozoDb) -> anyhow::Result<IntegrityReport> {
+    let inner = db.instance()?;
+    let mut report = IntegrityReport {
+        ok: true,
+        storage_errors: vec![],
+        relation_errors: vec![],
+        relations_checked: 0,
+        rows_checked: 0,
+    };
+    if db.engine == "sqlite" {
+        match sqlite_query(db, "Integrity check", "PRAGMA integrity_check") {
+            Ok(rows) => report
+                .storage_errors
+                .extend(rows.into_iter().filter(|row| row != "ok")),
+            Err(e) => report.storage_errors.push(e.to_string()),
+        }
+    }
+    let relations = match catch_panic(|| cozo_list_relations(db)) {
+        Ok(relations) => relations,
+        Err(e) => {
+            report.relation_errors.push(RelationIntegrityError {
+                relation: String::new(),
+                message: e.to_string(),
+            });
+            vec![]
+        }
+    };
+    for relation in relations.iter().filter(|r| r.access_level != "index") {
+        let scanned: anyhow::Result<u64> = catch_panic(|| {
+            system_op(&inner, &format!("::indices {}", relation.name))?;
+            let columns = relation_columns(&inner, &relation.name)?;
+            // Binds every column, so each row is decoded in full.
+            let script = format!(
+                "?[count({})] := *{}{{{}}}",
+                columns[0].name,
+                relation.name,
+                column_names(&columns)
+            );
+            let rows = inner
+                .run_script(&script, Default::default(), ScriptMutability::Immutable)
+                .map_err(|err| query_error(err, &script))?;
+            Ok(rows
+                .rows
+                .first()
+                .and_then(|row| row[0].get_int())
+                .unwrap_or_default() as u64)
+        });
+        report.relations_checked += 1;
+        match scanned {
+            Ok(rows) => report.rows_checked += rows,
+            Err(e) => report.relation_errors.push(RelationIntegrityError {
+                relation: relation.name.clone(),
+                message: e.to_string(),
+            }),
+        }
+    }
+    report.ok = report.storage_errors.is_empty() && report.relation_errors.is_empty();
+    Ok(report)
+}
+
+/// Switch the database file at `path` to the `mode` journal, e.g. "wal".
+/// The mode is persistent, so it also applies to cozo's own connections.
+///
+/// Runs before cozo opens the file: SQLite only leaves WAL mode when no
+/// other connection has the file open, and cozo keeps its connections open
+/// in a pool.
+pub(crate) fn set_journal_mode(
+    engine: &str,
+    path: &str,
+    read_only: bool,
+    mode: &str,
+) -> anyhow::Result<()> {
+    let mode = mode.to_ascii_lowercase();
+    if !matches!(mode.as_str(), "delete" | "truncate" | "persist" | "wal") {
+        anyhow::bail!(
+            "unsupported journal_mode {:?}, expected delete, truncate, persist or wal",
+            mode
+        );
+    }
+    if engine != "sqlite" {
+        anyhow::bail!(
+            "journal_mode is only available for the sqlite engine, not {}",
+            engine
+        );
+    }
+    if read_only {
+        anyhow::bail!("journal_mode cannot be changed on a read-only database");
+    }
+    let action = "Setting journal_mode";
+    let set = sqlite_file_query(path, action, &format!("PRAGMA journal_mode={}", mode))?;
+    if set.first().map(|m| m.to_ascii_lowercase()) != Some(mode.clone()) {
+        anyhow::bail!("{} failed: SQLite kept {:?}", action, set.first());
+    }
+    Ok(())
+}
+
+/// Path of the database file, or an error naming `action` if `db` does not
+/// use the sqlite engine.
+pub(crate) fn sqlite_path<'a>(db: &'a CozoDb, action: &str) -> anyhow::Result<&'a str> {
+    db.instance()?;
+    if db.engine != "sqlite" {
+        anyhow::bail!(
+            "{} is only available for the sqlite engine, not {}",
+            action,
+            db.engine
+        );
+    }
+    Ok(&db.path)
+}
+
+/// Run `sql` on a separate connection to the database file and collect the
+/// first column of every row as text.
+///
+/// Cozo keeps its own connections private, so this cannot change their
+/// per-connection settings; it is for statements about the file itself.
+pub(crate) fn sqlite_query(db: &CozoDb, action: &str, sql: &str) -> anyhow::Result<Vec<String>> {
+    sqlite_file_query(sqlite_path(db, action)?, action, sql)
+}
+
+/// Like [`sqlite_query`], for a file that cozo has not opened.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn sqlite_file_query(
+    path: &str,
+    action: &str,
+    sql: &str,
+) -> anyhow::Result<Vec<String>> {
+    use sqlite::State;
+
+    let error = |e: sqlite::Error| anyhow::anyhow!("{} failed: {}", action, e);
+    let mut conn = sqlite::Connection::open(path).map_err(error)?;
+    conn.set_busy_timeout(BUSY_TIMEOUT_MS).map_err(error)?;
+    let mut statement = conn.prepare(sql).map_err(error)?;
+    let mut values = vec![];
+    while statement.next().map_err(error)? == State::Row {
+        values.push(statement.read::<String, _>(0).map_err(error)?);
+    }
+    Ok(values)
+}
+
+#[cfg(target_family = "wasm")]
+pub(crate) fn sqlite_file_query(
+    _path: &str,
+    action: &str,
+    _sql: &str,
+) -> anyhow::Result<Vec<String>> {
+    anyhow::bail!("{} is not available on the web", action)
+}
+
+/// The value of a numeric PRAGMA such as `page_count`.
+pub(crate) fn sqlite_pragma(db: &CozoDb, action: &str, pragma: &str) -> anyhow::Result<u64> {
+    let values = sqlite_query(db, action, &format!("PRAGMA {}", pragma))?;
+    Ok(values
+        .first()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::backup::TempFile;
+    use crate::api::simple::{cozo_close_db, cozo_open_db};
+
+    fn wal_db(file: &TempFile) -> CozoDb {
+        let opts = r#"{"journal_mode": "wal"}"#;
+        let db = cozo_open_db("sqlite".into(), file.path().into(), opts.into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => name: String}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, name] <- [[1, 'a'], [2, 'b']] :put items {id => name}",
+        )
+        .unwrap();
+        db
+    }
+
+    fn close(db: CozoDb, file: &TempFile) {
+        cozo_close_db(&db);
+        let _ = std::fs::remove_file(format!("{}.lock", file.path()));
+    }
+
+    #[test]
+    fn checkpoint_empties_the_wal() {
+        let file = TempFile::private().unwrap();
+        let db = wal_db(&file);
+        let wal = format!("{}-wal", file.path());
+        assert!(std::fs::metadata(&wal).unwrap().len() > 0);
+
+        assert!(cozo_checkpoint(&db).unwrap());
+        assert_eq!(std::fs::metadata(&wal).unwrap().len(), 0);
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(
+                "?[count(id)] := *items{id}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap();
+        assert_eq!(rows.rows[0][0].get_int(), Some(2));
+        close(db, &file);
+    }
+
+    #[test]
+    fn checkpoint_is_a_no_op_for_mem() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        assert!(cozo_checkpoint(&db).unwrap());
+        cozo_close_db(&db);
+        assert!(cozo_checkpoint(&db).is_err());
+    }
+
+    #[test]
+    fn compact_reclaims_deleted_rows() {
+        let file = TempFile::private().unwrap();
+        let db = cozo_open_db("sqlite".into(), file.path().into(), "{}".into()).unwrap();
+        run_write_op(&db, ":create blobs {id: Int => body: String}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, body] := id in int_range(2000), body = concat('row ', to_string(id), \
+             '................................................................') \
+             :put blobs {id => body}",
+        )
+        .unwrap();
+        run_write_op(&db, "?[id] := *blobs{id} :rm blobs {id}").unwrap();
+        assert!(cozo_db_stats(&db).unwrap().free_pages.unwrap() > 0);
+        let before = cozo_db_stats(&db).unwrap().file_bytes;
+
+        // No Dart side listens in tests, so the events are dropped.
+        cozo_compact(&db, StreamSink::deserialize("0".into())).unwrap();
+        let after = cozo_db_stats(&db).unwrap();
+        assert_eq!(after.free_pages, Some(0));
+        assert!(after.file_bytes < before);
+        close(db, &file);
+    }
+
+    #[test]
+    fn compact_needs_a_writable_database() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        cozo_compact(&db, StreamSink::deserialize("0".into())).unwrap();
+        cozo_close_db(&db);
+        assert!(cozo_compact(&db, StreamSink::deserialize("0".into())).is_err());
+    }
+
+    #[test]
+    fn journal_mode_is_kept_by_later_opens() {
+        let file = TempFile::private().unwrap();
+        close(wal_db(&file), &file);
+        let db = cozo_open_db("sqlite".into(), file.path().into(), "{}".into()).unwrap();
+        let mode = sqlite_query(&db, "Test", "PRAGMA journal_mode").unwrap();
+        assert_eq!(mode, ["wal"]);
+        close(db, &file);
+
+        let opts = r#"{"journal_mode": "DELETE"}"#;
+        let db = cozo_open_db("sqlite".into(), file.path().into(), opts.into()).unwrap();
+        let mode = sqlite_query(&db, "Test", "PRAGMA journal_mode").unwrap();
+        assert_eq!(mode, ["delete"]);
+        close(db, &file);
+    }
+
+    #[test]
+    fn unsupported_sqlite_options_are_rejected() {
+        let file = TempFile::private().unwrap();
+        let open = |opts: &str| cozo_open_db("sqlite".into(), file.path().into(), opts.into());
+        assert!(open(r#"{"journal_mode": "off"}"#).is_err());
+        assert!(open(r#"{"journal_mode": 1}"#).is_err());
+        let err = open(r#"{"synchronous": "off"}"#).err().unwrap();
+        assert!(err.to_string().contains("`synchronous`"));
+        let _ = std::fs::remove_file(format!("{}.lock", file.path()));
+
+        let opts = r#"{"journal_mode": "wal"}"#;
+        assert!(cozo_open_db("mem".into(), String::new(), opts.into()).is_err());
+    }
+
+    #[test]
+    fn integrity_check_scans_every_relation() {
+        let file = TempFile::private().unwrap();
+        let db = wal_db(&file);
+        run_write_op(&db, ":create tags {tag: String}").unwrap();
+        run_write_op(&db, "::index create items:by_name {name}").unwrap();
+
+        let report = cozo_check_integrity(&db).unwrap();
+        assert!(report.ok);
+        assert!(report.storage_errors.is_empty());
+        assert!(report.relation_errors.is_empty());
+        assert_eq!((report.relations_checked, report.rows_checked), (2, 2));
+        close(db, &file);
+    }
+
+    #[test]
+    fn integrity_check_needs_an_open_database() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let report = cozo_check_integrity(&db).unwrap();
+        assert!(report.ok);
+        assert_eq!(report.relations_checked, 0);
+        cozo_close_db(&db);
+        assert!(cozo_check_integrity(&db).is_err());
+    }
+}
@@ -0,0 +1,829 @@
+use std::collections::BTreeMap;
+
+use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability, TransactionPayload};
+use flutter_rust_bridge::DartFnFuture;
+use serde_json::{json, Value};
+
+use crate::api::error::{catch_panic, failure, with_context, ErrorKind};
+use crate::api::import::coerce_rows;
+use crate::api::incremental::scan;
+use crate::api::running::epoch_secs;
+use crate::api::schema::{
+    column_names, column_spec, cozo_list_relations, relation_columns, ColumnInfo,
+};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::transaction::finish_multi;
+use crate::api::triggers::{cozo_list_triggers, cozo_set_triggers};
+use crate::api::value::string_literal;
+use crate::api::vector::run_write_op;
+
+/// Stored relation holding the newest change of every row of the synced
+/// relations: `{relation, key => seq, version, deleted}`. `seq` numbers the
+/// changes in the order they were committed here, which export tokens refer
+/// to, and `version` is the time the change was first made, on whichever
+/// install.
+const SYNC_LOG: &str = "cozo_sync_log";
+
+/// Stored relation holding the `seq` of the last recorded change, in its
+/// single row: `{id => seq}`. Every write recording changes takes the next
+/// number inside its own transaction, so a change committed after an export
+/// always gets a higher number than the export's token.
+const SYNC_CLOCK: &str = "cozo_sync_clock";
+
+/// Stored relation holding, for every row received from another install,
+/// the version and values both installs last had, or null values if the
+/// row was removed: `{relation, key => version, row}`. Conflicts are told
+/// apart from plain updates by it.
+const SYNC_BASE: &str = "cozo_sync_base";
+
+/// Version of the payload written by [`cozo_sync_export_changes`].
+const PAYLOAD_FORMAT: u64 = 1;
+
+/// Extra column of payload rows carrying the version of the change.
+const VERSION_COLUMN: &str = "cozo_sync_version";
+
+/// Extra column of payload rows carrying the version the exporting install
+/// last had in common with another one, if any.
+const BASE_COLUMN: &str = "cozo_sync_base_version";
+
+/// Changes exported by [`cozo_sync_export_changes`].
+#[derive(Debug, Clone)]
+pub struct SyncChangeset {
+    /// JSON payload to hand to `cozo_sync_apply_changes` on the other
+    /// install.
+    pub payload: String,
+    /// Token to pass as `since_token` next time, once the other install
+    /// has applied this payload.
+    pub token: String,
+    /// Number of rows put or removed in the payload.
+    pub changes: u64,
+}
+
+/// Outcome of [`cozo_sync_apply_changes`].
+#[derive(Debug, Clone, Default)]
+pub struct SyncReport {
+    /// Remote changes written to this database.
+    pub applied: u64,
+    /// Remote changes not written because the row changed later here, or
+    /// the change had been applied before.
+    pub skipped: u64,
+    /// Conflicting changes settled by the resolver passed to
+    /// [`cozo_sync_apply_changes_with_resolver`].
+    pub resolved: u64,
+}
+
+/// Record changes to stored relations so they can be synced with another
+/// install of the app, with [`cozo_sync_export_changes`] and
+/// [`cozo_sync_apply_changes`].
+///
+/// - `relations`: stored relations to sync; each install must enable the
+///   same ones, with the same columns
+///
+/// Triggers added to each relation record, for every row put or removed,
+/// the time of the change in the `cozo_sync_log` relation, which keeps only
+/// the newest change of each row. Rows already in the relation are recorded
+/// as changed now, so the first export carries them. Triggers set on the
+/// relation later with `cozo_set_triggers` must keep the sync triggers,
+/// which [`cozo_list_triggers`] returns along with the others. Enabling a
+/// relation again does nothing.
+pub fn cozo_sync_enable(db: &CozoDb, relations: Vec<String>) -> anyhow::Result<()> {
+    let inner = db.writable()?;
+    ensure_log(db)?;
+    for relation in relations {
+        if relation.contains(':') || relation == SYNC_LOG {
+            anyhow::bail!("Sync failed: {} cannot be synced", relation);
+        }
+        let columns = relation_columns(&inner, &relation)?;
+        let mut triggers = cozo_list_triggers(db, relation.clone())?;
+        if triggers.on_put.iter().any(|t| is_sync_trigger(t)) {
+            continue;
+        }
+        triggers
+            .on_put
+            .extend([log_trigger(&relation, &columns, "_new", false), tick()]);
+        triggers
+            .on_rm
+            .extend([log_trigger(&relation, &columns, "_old", true), tick()]);
+        cozo_set_triggers(db, relation.clone(), triggers)?;
+
+        let (pattern, key) = key_pattern(&columns);
+        let seed = format!(
+            "{{?[relation, key, seq, version, deleted] := *{rel}[{pattern}], relation = {name}, key = [{key}], not *{log}{{relation, key}}, {next}, version = now(), deleted = false\n:put {log} {{relation, key => seq, version, deleted}}}}\n{{{tick}}}",
+            rel = relation,
+            pattern = pattern,
+            name = string_literal(&relation),
+            key = key,
+            log = SYNC_LOG,
+            next = next_seq(),
+            tick = tick(),
+        );
+        run_write_op(db, &seed)?;
+    }
+    Ok(())
+}
+
+/// Stop recording changes to `relation` and forget the changes recorded and
+/// received so far. Its rows are kept.
+pub fn cozo_sync_disable(db: &CozoDb, relation: String) -> anyhow::Result<()> {
+    let mut triggers = cozo_list_triggers(db, relation.clone())?;
+    triggers.on_put.retain(|t| !is_sync_trigger(t));
+    triggers.on_rm.retain(|t| !is_sync_trigger(t));
+    cozo_set_triggers(db, relation.clone(), triggers)?;
+    if log_exists(db)? {
+        ensure_log(db)?;
+        for log in [SYNC_LOG, SYNC_BASE] {
+            run_write_op(
+                db,
+                &format!(
+                    "?[relation, key] := *{log}{{relation, key}}, relation = {name}\n:rm {log} {{relation, key}}",
+                    log = log,
+                    name = string_literal(&relation),
+                ),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Export the changes to synced relations recorded since `since_token`, for
+/// sending to another install over any transport.
+///
+/// - `since_token`: the token of the last export that the other install
+///   applied, or an empty string for every recorded change
+///
+/// Each changed row is exported once, as it is now: its current values, or
+/// its key if it was removed. Keep one token per install synced with, and
+/// store the new token only once that install has applied the payload; an
+/// older token just exports some changes again, which applying skips.
+pub fn cozo_sync_export_changes(db: &CozoDb, since_token: String) -> anyhow::Result<SyncChangeset> {
+    let inner = db.instance()?;
+    let since = match since_token.as_str() {
+        "" => 0,
+        token => token
+            .parse::<i64>()
+            .map_err(|_| anyhow::anyhow!("Sync failed: invalid token {:?}", token))?,
+    };
+    let mut payload = json!({"format": PAYLOAD_FORMAT, "relations": {}});
+    if !log_exists(db)? {
+        payload["token"] = json!(since_token);
+        return Ok(SyncChangeset {
+            payload: payload.to_string(),
+            token: since_token,
+            changes: 0,
+        });
+    }
+
+    // Changes recorded while exporting are left for the next export.
+    let until = query(
+        &inner,
+        &format!("?[max(seq)] := *{}{{seq}}", SYNC_LOG),
+        BTreeMap::new(),
+    )?
+    .rows
+    .first()
+    .and_then(|row| row[0].get_int())
+    .unwrap_or(since)
+    .max(since);
+    let params = BTreeMap::from([
+        ("since".to_string(), DataValue::from(since)),
+        ("until".to_string(), DataValue::from(until)),
+    ]);
+    let changed = query(
+        &inner,
+        &format!(
+            "?[relation] := *{}{{relation, seq}}, seq > $since, seq <= $until",
+            SYNC_LOG
+        ),
+        params.clone(),
+    )?;
+    let existing: Vec<String> = cozo_list_relations(db)?
+        .into_iter()
+        .map(|rel| rel.name)
+        .collect();
+
+    let mut changes = 0;
+    for row in changed.rows {
+        let relation = row[0].get_str().unwrap_or_default().to_string();
+        if !existing.contains(&relation) {
+            continue;
+        }
+        let columns = relation_columns(&inner, &relation)?;
+        let keys: Vec<&ColumnInfo> = columns.iter().filter(|c| c.is_key).collect();
+        let unpack = keys
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!(", {} = get(cozo_sync_key, {})", c.name, i))
+            .collect::<String>();
+        let entries = |deleted: bool| {
+            format!(
+                "*{log}{{relation: {name}, key: cozo_sync_key, seq: cozo_sync_seq, version: {version}, deleted: {deleted}}}, cozo_sync_seq > $since, cozo_sync_seq <= $until{unpack}",
+                log = SYNC_LOG,
+                name = string_literal(&relation),
+                version = VERSION_COLUMN,
+                deleted = deleted,
+                unpack = unpack,
+            )
+        };
+        let names = column_names(&columns);
+        let put = query(
+            &inner,
+            &format!(
+                "?[{names}, {version}] := {entries}, *{rel}{{{names}}}",
+                names = names,
+                version = VERSION_COLUMN,
+                entries = entries(false),
+                rel = relation,
+            ),
+            params.clone(),
+        )?;
+        let rm = query(
+            &inner,
+            &format!(
+                "?[{keys}, {version}] := {entries}",
+                keys = column_names(keys.iter().copied()),
+                version = VERSION_COLUMN,
+                entries = entries(true),
+            ),
+            params.clone(),
+        )?;
+        let bases = base_rows(&inner, &relation)?;
+        let (put, rm) = (
+            with_base(put, keys.len(), &bases),
+            with_base(rm, keys.len(), &bases),
+        );
+        changes += (put.rows.len() + rm.rows.len()) as u64;
+        payload["relations"][relation] = json!({"put": rows_json(put), "rm": rows_json(rm)});
+    }
+    let token = until.to_string();
+    payload["token"] = json!(token);
+    Ok(SyncChangeset {
+        payload: payload.to_string(),
+        token,
+        changes,
+    })
+}
+
+/// Apply a payload from [`cozo_sync_export_changes`] of another install.
+///
+/// A remote change made to the row as this install last received or sent
+/// it is written as it is. A remote change conflicts with one made here in
+/// the meantime if both installs changed the row since; conflicts are
+/// resolved per row, last writer wins: the remote change is written unless
+/// the row changed here after it, going by the clocks of the installs that
+/// made the changes. Applied changes are recorded as changes of this
+/// install, so they are passed on to installs this one syncs with. They are
+/// written with `:put` and `:rm` like local changes, so the indices and
+/// triggers of the relations see them too. All changes are written in one
+/// transaction.
+///
+/// Fails without writing anything if a relation of the payload is missing
+/// or a row does not fit its columns.
+pub fn cozo_sync_apply_changes(db: &CozoDb, payload: String) -> anyhow::Result<SyncReport> {
+    apply_changes(db, payload, None)
+}
+
+/// Apply a payload from [`cozo_sync_export_changes`] of another install
+/// like [`cozo_sync_apply_changes`], with conflicts settled by `resolve`
+/// instead of by last writer wins.
+///
+/// - `resolve`: called for each conflicting row with a JSON object
+///   `{"relation", "headers", "local", "remote", "base"}`, where `local`
+///   and `remote` are the row here and in the payload and `base` the row
+///   both installs last had, each an array of values in `headers` order or
+///   null if the row is removed or, for `base`, unknown; must complete with
+///   the winning row as such an array, or null to remove it
+///
+/// The winner is recorded as a new change of this install unless it is the
+/// remote row, so it is sent back to the other install on the next export
+/// and replaces the row there without another conflict.
+///
+/// Each call blocks until the Dart function completes, so this must not be
+/// called from a `#[frb(sync)]` call. Fails without writing anything if
+/// `resolve` returns a row that does not fit or changes the key.
+pub fn cozo_sync_apply_changes_with_resolver(
+    db: &CozoDb,
+    payload: String,
+    resolve: impl Fn(String) -> DartFnFuture<String>,
+) -> anyhow::Result<SyncReport> {
+    apply_changes(db, payload, Some(&resolve))
+}
+
+/// How a remote change is applied.
+enum Outcome {
+    Skip,
+    Remote,
+    /// The winner of a conflict, or `None` to remove the row.
+    Resolved(Option<Vec<DataValue>>),
+}
+
+fn apply_changes(
+    db: &CozoDb,
+    payload: String,
+    resolve: Option<&dyn Fn(String) -> DartFnFuture<String>>,
+) -> anyhow::Result<SyncReport> {
+    let error =
+        |e: &dyn std::fmt::Display| failure(ErrorKind::ImportExport, format!("Sync failed: {}", e));
+    let inner = db.writable()?;
+    let payload: Value = serde_json::from_str(&payload).map_err(|e| error(&e))?;
+    if payload["format"].as_u64() != Some(PAYLOAD_FORMAT) {
+        return Err(error(&"unsupported payload format"));
+    }
+    ensure_log(db)?;
+
+    let now = DataValue::from(epoch_secs());
+    let mut report = SyncReport::default();
+    let mut writes = vec![];
+    let mut logged = vec![];
+    let mut agreed = vec![];
+    let empty = serde_json::Map::new();
+    for (relation, change) in payload["relations"].as_object().unwrap_or(&empty) {
+        let columns = relation_columns(&inner, relation)?;
+        let keys = columns.iter().filter(|c| c.is_key).count();
+        let headers: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+        let local = local_versions(&inner, relation)?;
+        let bases = base_rows(&inner, relation)?;
+        let current = match resolve {
+            Some(_) => scan(&inner, relation, &columns)?.rows,
+            None => vec![],
+        };
+        let (mut puts, mut deletes) = (vec![], vec![]);
+        for (part, deleted) in [("put", false), ("rm", true)] {
+            let rows = NamedRows::from_json(&change[part]).map_err(|e| error(&e))?;
+            if rows.rows.is_empty() {
+                continue;
+            }
+            let mut expected: Vec<ColumnInfo> = match deleted {
+                true => columns[..keys].to_vec(),
+                false => columns.clone(),
+            };
+            for (name, nullable) in [(VERSION_COLUMN, false), (BASE_COLUMN, true)] {
+                expected.push(ColumnInfo {
+                    name: name.to_string(),
+                    column_type: "Float".to_string(),
+                    nullable,
+                    is_key: false,
+                    index: expected.len() as u32,
+                    has_default: false,
+                });
+            }
+            for mut row in coerce_rows(relation, &expected, rows)?.rows {
+                let remote_base = row.pop().unwrap_or(DataValue::Null);
+                let version = row.pop().unwrap_or(DataValue::Null);
+                let key = row[..keys].to_vec();
+                let remote = (!deleted).then_some(row);
+                let base = bases
+                    .binary_search_by(|(k, _, _)| k.as_slice().cmp(&key))
+                    .ok()
+                    .map(|i| &bases[i]);
+                let outcome = match local.binary_search_by(|(k, _)| k.as_slice().cmp(&key)) {
+                    Err(_) => Outcome::Remote,
+                    Ok(i) if version == local[i].1 => Outcome::Skip,
+                    // Changed there on top of the row as it is here.
+                    Ok(i) if remote_base == local[i].1 => Outcome::Remote,
+                    // Unchanged here since both last had it.
+                    Ok(i) if base.is_some_and(|(_, v, _)| *v == local[i].1) => Outcome::Remote,
+                    // Unchanged there since both last had it.
+                    Ok(_) if base.is_some_and(|(_, v, _)| *v == version) => Outcome::Skip,
+                    Ok(i) => match resolve {
+                        None if version > local[i].1 => Outcome::Remote,
+                        None => Outcome::Skip,
+                        Some(resolve) => {
+                            let row_here = current
+                                .binary_search_by(|row| row[..keys].cmp(&key))
+                                .ok()
+                                .map(|i| current[i].clone());
+                            let conflict = json!({
+                                "relation": relation,
+                                "headers": headers,
+                                "local": row_json(row_here),
+                                "remote": row_json(remote.clone()),
+                                "base": base.map_or(Value::Null, |(_, _, row)| Value::from(row.clone())),
+                            });
+                            let winner =
+                                resolve_conflict(resolve, conflict, relation, &columns, &key)?;
+                            report.resolved += 1;
+                            match winner == remote {
+                                true => Outcome::Remote,
+                                false => Outcome::Resolved(winner),
+                            }
+                        }
+                    },
+                };
+                let (row, changed) = match outcome {
+                    Outcome::Skip => {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    Outcome::Remote => {
+                        report.applied += 1;
+                        (remote.clone(), version.clone())
+                    }
+                    Outcome::Resolved(winner) => (winner, now.clone()),
+                };
+                logged.push(vec![
+                    DataValue::from(relation.as_str()),
+                    DataValue::List(key.clone()),
+                    changed,
+                    DataValue::from(row.is_none()),
+                ]);
+                agreed.push(vec![
+                    DataValue::from(relation.as_str()),
+                    DataValue::List(key.clone()),
+                    version,
+                    remote.map_or(DataValue::Null, DataValue::List),
+                ]);
+                match row {
+                    Some(row) => puts.push(row),
+                    None => deletes.push(key),
+                }
+            }
+        }
+        let spec = column_spec(&columns);
+        if !deletes.is_empty() {
+            let names = column_names(&columns[..keys]);
+            writes.push((
+                format!("?[{names}] <- $rows :rm {relation} {{{names}}}"),
+                deletes,
+            ));
+        }
+        if !puts.is_empty() {
+            let names = column_names(&columns);
+            writes.push((
+                format!("?[{names}] <- $rows :put {relation} {{{spec}}}"),
+                puts,
+            ));
+        }
+    }
+    if logged.is_empty() {
+        return Ok(report);
+    }
+    // Written after the rows, over what the sync triggers recorded for them.
+    writes.push((
+        format!(
+            "rows[relation, key, version, deleted] <- $rows\n?[relation, key, seq, version, deleted] := rows[relation, key, version, deleted], {next}\n:put {log} {{relation, key => seq, version, deleted}}",
+            next = next_seq(),
+            log = SYNC_LOG,
+        ),
+        logged,
+    ));
+    writes.push((tick(), vec![]));
+    writes.push((
+        format!(
+            "?[relation, key, version, row] <- $rows :put {} {{relation, key => version, row}}",
+            SYNC_BASE
+        ),
+        agreed,
+    ));
+
+    let tx = inner.multi_transaction(true);
+    let written = writes.into_iter().try_for_each(|(script, rows)| {
+        let rows = rows.into_iter().map(DataValue::List).collect();
+        let params = BTreeMap::from([("rows".to_string(), DataValue::List(rows))]);
+        catch_panic(|| {
+            tx.run_script(&script, params)
+                .map_err(|err| query_error(err, &script))
+        })?;
+        Ok::<_, anyhow::Error>(())
+    });
+    match written {
+        Ok(()) => finish_multi(tx, TransactionPayload::Commit)
+            .map_err(|e| with_context(e, ErrorKind::Transact, "Sync failed"))?,
+        Err(e) => {
+            let _ = finish_multi(tx, TransactionPayload::Abort);
+            return Err(with_context(e, ErrorKind::Transact, "Sync failed"));
+        }
+    }
+    Ok(report)
+}
+
+/// Newest recorded version of each row of `relation`, in key order.
+fn local_versions(
+    inner: &DbInstance,
+    relation: &str,
+) -> anyhow::Result<Vec<(Vec<DataValue>, DataValue)>> {
+    let rows = query(
+        inner,
+        &format!(
+            "?[key, version] := *{}{{relation: $relation, key, version}}",
+            SYNC_LOG
+        ),
+        BTreeMap::from([("relation".to_string(), DataValue::from(relation))]),
+    )?;
+    let mut versions: Vec<_> = rows
+        .rows
+        .into_iter()
+        .map(|mut row| {
+            let version = row.pop().unwrap_or(DataValue::Null);
+            match row.pop() {
+                Some(DataValue::List(key)) => (key, version),
+                _ => (vec![], version),
+            }
+        })
+        .collect();
+    versions.sort();
+    Ok(versions)
+}
+
+/// Version and values both installs last had of each row of `relation`
+/// received from another install, in key order.
+fn base_rows(
+    inner: &DbInstance,
+    relation: &str,
+) -> anyhow::Result<Vec<(Vec<DataValue>, DataValue, DataValue)>> {
+    let rows = query(
+        inner,
+        &format!(
+            "?[key, version, row] := *{}{{relation: $relation, key, version, row}}",
+            SYNC_BASE
+        ),
+        BTreeMap::from([("relation".to_string(), DataValue::from(relation))]),
+    )?;
+    let mut bases: Vec<_> = rows
+        .rows
+        .into_iter()
+        .map(|mut row| {
+            let values = row.pop().unwrap_or(DataValue::Null);
+            let version = row.pop().unwrap_or(DataValue::Null);
+            match row.pop() {
+                Some(DataValue::List(key)) => (key, version, values),
+                _ => (vec![], version, values),
+            }
+        })
+        .collect();
+    bases.sort();
+    Ok(bases)
+}
+
+/// `rows`, keyed by their first `keys` columns, with the base version of
+/// each appended.
+fn with_base(
+    mut rows: NamedRows,
+    keys: usize,
+    bases: &[(Vec<DataValue>, DataValue, DataValue)],
+) -> NamedRows {
+    rows.headers.push(BASE_COLUMN.to_string());
+    for row in &mut rows.rows {
+        let base = bases
+            .binary_search_by(|(k, _, _)| k.as_slice().cmp(&row[..keys]))
+            .map_or(DataValue::Null, |i| bases[i].1.clone());
+        row.push(base);
+    }
+    rows
+}
+
+/// Ask `resolve` for the winner of `conflict`, and check that it fits the
+/// row at `key`.
+fn resolve_conflict(
+    resolve: &dyn Fn(String) -> DartFnFuture<String>,
+    conflict: Value,
+    relation: &str,
+    columns: &[ColumnInfo],
+    key: &[DataValue],
+) -> anyhow::Result<Option<Vec<DataValue>>> {
+    let error = |e: &dyn std::fmt::Display| anyhow::anyhow!("Sync failed: resolver: {}", e);
+    let output = futures::executor::block_on(resolve(conflict.to_string()));
+    let winner: Value = serde_json::from_str(&output).map_err(|e| error(&e))?;
+    if winner.is_null() {
+        return Ok(None);
+    }
+    let headers: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+    let rows = NamedRows::from_json(&json!({"headers": headers, "rows": [winner]}))
+        .map_err(|e| error(&e))?;
+    let row = coerce_rows(relation, columns, rows)?
+        .rows
+        .pop()
+        .unwrap_or_default();
+    if row[..key.len()] != *key {
+        return Err(error(&"the winning row has a different key"));
+    }
+    Ok(Some(row))
+}
+
+/// A trigger recording the rows of `source` (`_new` or `_old`) in the log.
+fn log_trigger(relation: &str, columns: &[ColumnInfo], source: &str, deleted: bool) -> String {
+    let (pattern, key) = key_pattern(columns);
+    format!(
+        "?[relation, key, seq, version, deleted] := {source}[{pattern}], relation = {name}, key = [{key}], {next}, version = now(), deleted = {deleted}\n:put {log} {{relation, key => seq, version, deleted}}",
+        source = source,
+        pattern = pattern,
+        name = string_literal(relation),
+        key = key,
+        next = next_seq(),
+        deleted = deleted,
+        log = SYNC_LOG,
+    )
+}
+
+/// Atoms binding `seq` to the number of the change being recorded.
+fn next_seq() -> String {
+    format!(
+        "*{}{{id: 0, seq: cozo_sync_last}}, seq = cozo_sync_last + 1",
+        SYNC_CLOCK
+    )
+}
+
+/// A program advancing the clock past a change recorded with [`next_seq`],
+/// run after it in the same transaction.
+fn tick() -> String {
+    format!(
+        "?[id, seq] := id = 0, {next}\n:put {clock} {{id => seq}}",
+        next = next_seq(),
+        clock = SYNC_CLOCK,
+    )
+}
+
+/// A positional pattern binding the key columns to `k0`, `k1`, ..., and the
+/// list of those variables.
+pub(crate) fn key_pattern(columns: &[ColumnInfo]) -> (String, String) {
+    let vars: Vec<String> = columns
+        .iter()
+        .filter(|c| c.is_key)
+        .enumerate()
+        .map(|(i, _)| format!("k{}", i))
+        .collect();
+    let pattern = columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match c.is_key {
+            true => vars[i].clone(),
+            false => "_".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    (pattern, vars.join(", "))
+}
+
+fn is_sync_trigger(trigger: &str) -> bool {
+    [SYNC_LOG, SYNC_CLOCK]
+        .iter()
+        .any(|log| trigger.contains(&format!(":put {} ", log)))
+}
+
+fn log_exists(db: &CozoDb) -> anyhow::Result<bool> {
+    Ok(cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == SYNC_LOG))
+}
+
+fn ensure_log(db: &CozoDb) -> anyhow::Result<()> {
+    let existing = cozo_list_relations(db)?;
+    let exists = |name: &str| existing.iter().any(|rel| rel.name == name);
+    if !exists(SYNC_LOG) {
+        run_write_op(
+            db,
+            &format!(
+                ":create {} {{relation: String, key: Any => seq: Int, version: Float, deleted: Bool}}",
+                SYNC_LOG
+            ),
+        )?;
+    }
+    if !exists(SYNC_CLOCK) {
+        run_write_op(
+            db,
+            &format!(
+                "{{:create {clock} {{id: Int => seq: Int}}}}\n{{?[id, seq] <- [[0, 0]]\n:put {clock} {{id => seq}}}}",
+                clock = SYNC_CLOCK
+            ),
+        )?;
+    }
+    if !exists(SYNC_BASE) {
+        run_write_op(
+            db,
+            &format!(
+                ":create {} {{relation: String, key: Any => version: Float, row: Any?}}",
+                SYNC_BASE
+            ),
+        )?;
+    }
+    Ok(())
+}
+
+fn query(
+    inner: &DbInstance,
+    script: &str,
+    params: BTreeMap<String, DataValue>,
+) -> anyhow::Result<NamedRows> {
+    catch_panic(|| inner.run_script(script, params, ScriptMutability::Immutable))
+        .map_err(|err| query_error(err, script))
+}
+
+/// The values of `row` as a JSON array, or null.
+fn row_json(row: Option<Vec<DataValue>>) -> Value {
+    row.map_or(Value::Null, |row| Value::from(DataValue::List(row)))
+}
+
+/// `{"headers", "rows"}` of `rows`.
+fn rows_json(rows: NamedRows) -> Value {
+    let mut value = rows.into_json();
+    if let Some(object) = value.as_object_mut() {
+        object.remove("next");
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+
+    fn synced() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create notes {id: Int => body: String}").unwrap();
+        cozo_sync_enable(&db, vec!["notes".into()]).unwrap();
+        db
+    }
+
+    fn rows(db: &CozoDb, script: &str) -> Vec<Vec<DataValue>> {
+        let inner = db.instance().unwrap();
+        query(&inner, script, BTreeMap::new()).unwrap().rows
+    }
+
+    #[test]
+    fn changes_reach_the_other_install() {
+        let (here, there) = (synced(), synced());
+        run_write_op(
+            &here,
+            "?[id, body] <- [[1, 'a'], [2, 'b']] :put notes {id => body}",
+        )
+        .unwrap();
+        let changes = cozo_sync_export_changes(&here, String::new()).unwrap();
+        assert_eq!(changes.changes, 2);
+        let report = cozo_sync_apply_changes(&there, changes.payload.clone()).unwrap();
+        assert_eq!(report.applied, 2);
+        assert_eq!(
+            rows(&there, "?[id, body] := *notes{id, body}"),
+            rows(&here, "?[id, body] := *notes{id, body}")
+        );
+
+        run_write_op(&here, "?[id] <- [[1]] :rm notes {id}").unwrap();
+        let next = cozo_sync_export_changes(&here, changes.token).unwrap();
+        assert_eq!(next.changes, 1);
+        cozo_sync_apply_changes(&there, next.payload.clone()).unwrap();
+        assert_eq!(
+            rows(&there, "?[id] := *notes{id}"),
+            vec![vec![DataValue::from(2)]]
+        );
+        // Applying a payload again changes nothing.
+        let again = cozo_sync_apply_changes(&there, next.payload).unwrap();
+        assert_eq!((again.applied, again.skipped), (0, 1));
+    }
+
+    #[test]
+    fn applied_changes_update_indices_and_run_triggers() {
+        let (here, there) = (synced(), synced());
+        run_write_op(
+            &there,
+            "::fts create notes:text {extractor: body, tokenizer: Simple, filters: [Lowercase]}",
+        )
+        .unwrap();
+        run_write_op(&there, ":create seen {id: Int}").unwrap();
+        let mut triggers = cozo_list_triggers(&there, "notes".into()).unwrap();
+        triggers
+            .on_put
+            .push("?[id] := _new[id, _]\n:put seen {id}".into());
+        cozo_set_triggers(&there, "notes".into(), triggers).unwrap();
+
+        run_write_op(
+            &here,
+            "?[id, body] <- [[1, 'Hello world']] :put notes {id => body}",
+        )
+        .unwrap();
+        let changes = cozo_sync_export_changes(&here, String::new()).unwrap();
+        cozo_sync_apply_changes(&there, changes.payload).unwrap();
+        let found = cozo_run_query(
+            &there,
+            "?[id] := ~notes:text{id | query: 'hello', k: 10}".into(),
+            "{}".into(),
+            true,
+        )
+        .unwrap();
+        assert!(found.contains("[[1]]"), "{}", found);
+        assert_eq!(
+            rows(&there, "?[id] := *seen{id}"),
+            vec![vec![DataValue::from(1)]]
+        );
+        // The applied change keeps the version it was made with.
+        assert_eq!(
+            rows(&there, "?[v] := *cozo_sync_log{version: v}"),
+            rows(&here, "?[v] := *cozo_sync_log{version: v}")
+        );
+    }
+
+    #[test]
+    fn tokens_count_committed_changes() {
+        let db = synced();
+        run_write_op(
+            &db,
+            "?[id, body] <- [[1, 'a'], [2, 'b']] :put notes {id => body}",
+        )
+        .unwrap();
+        let first = cozo_sync_export_changes(&db, String::new()).unwrap();
+        run_write_op(&db, "?[id, body] <- [[3, 'c']] :put notes {id => body}").unwrap();
+        run_write_op(&db, "?[id] <- [[1]] :rm notes {id}").unwrap();
+        let next = cozo_sync_export_changes(&db, first.token.clone()).unwrap();
+        assert_eq!(next.changes, 2);
+        let count = |token: &str| token.parse::<i64>().unwrap();
+        assert_eq!(count(&next.token), count(&first.token) + 2);
+        assert!(cozo_sync_export_changes(&db, "1.5".into()).is_err());
+    }
+}
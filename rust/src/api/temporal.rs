@@ -0,0 +1,422 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use cozo::{DataValue, ScriptMutability, Validity, ValidityTs};
+use flutter_rust_bridge::frb;
+
+use crate::api::column_crypto::decrypt_rows;
+use crate::api::error::catch_panic;
+use crate::api::row_policy::apply_row_policies;
+use crate::api::rows::rows_to_data;
+use crate::api::schema::{
+    column_names, column_spec, cozo_list_relations, relation_columns, ColumnInfo,
+};
+use crate::api::simple::{parse_params, query_error, rows_to_json, CozoDb};
+use crate::api::value::CozoValue;
+
+/// Parameter [`cozo_query_asof`] binds to the time queried.
+const ASOF_PARAM: &str = "cozo_asof";
+
+/// A `Validity` value with its timestamp as a `DateTime`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidityTime {
+    /// Time from which the row is asserted or retracted.
+    pub valid_from: DateTime<Utc>,
+    /// Whether the row is asserted, as opposed to retracted, from then on.
+    pub is_assert: bool,
+}
+
+/// The `Validity` value for `time`, to bind as a parameter or write into a
+/// `Validity` column without converting to microseconds by hand.
+#[frb(sync)]
+pub fn cozo_validity_from_time(time: ValidityTime) -> CozoValue {
+    validity(time.valid_from, time.is_assert).into()
+}
+
+/// The time and assert flag of a `Validity` value read from a result.
+///
+/// Fails for other values, and for timestamps a `DateTime` cannot hold,
+/// such as the ones `'END'` stands for.
+#[frb(sync)]
+pub fn cozo_validity_to_time(value: CozoValue) -> anyhow::Result<ValidityTime> {
+    let CozoValue::Validity {
+        timestamp,
+        is_assert,
+    } = value
+    else {
+        anyhow::bail!("Expected a Validity, got {:?}", value);
+    };
+    let valid_from = DateTime::from_timestamp_micros(timestamp)
+        .ok_or_else(|| anyhow::anyhow!("Validity timestamp {} is out of range", timestamp))?;
+    Ok(ValidityTime {
+        valid_from,
+        is_assert,
+    })
+}
+
+/// Run a read-only query against time travel relations as they were at
+/// `asof`, without writing the `@` clauses by hand.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Every stored relation atom of the script, `*relation{..}` or
+/// `*relation[..]`, that reads a relation with a `Validity` key column and
+/// has no `@` clause of its own gets `@ $cozo_asof`, bound to `asof` in
+/// microseconds. Other atoms are left as they are.
+///
+/// Returns the same JSON result as `cozo_run_query`.
+pub fn cozo_query_asof(
+    db: &CozoDb,
+    script: String,
+    params_json: String,
+    asof: DateTime<Utc>,
+) -> anyhow::Result<String> {
+    let inner = db.instance()?;
+    let mut params = parse_params(&params_json)?;
+    params.insert(
+        ASOF_PARAM.to_string(),
+        DataValue::from(asof.timestamp_micros()),
+    );
+    let mut temporal = vec![];
+    for relation in cozo_list_relations(db)? {
+        if validity_column(&relation_columns(&inner, &relation.name)?).is_some() {
+            temporal.push(relation.name);
+        }
+    }
+    let script = apply_row_policies(&db.row_policies, &script, &mut params)?;
+    let script = with_validity(&script, &temporal, &format!("${}", ASOF_PARAM));
+    let start = Instant::now();
+    let mut rows = catch_panic(|| {
+        inner
+            .run_script(&script, params, ScriptMutability::Immutable)
+            .map_err(|err| query_error(err, &script))
+    })?;
+    decrypt_rows(&db.column_cipher, &mut rows)?;
+    Ok(rows_to_json(rows, start.elapsed().as_secs_f64()))
+}
+
+/// Write rows of a time travel relation, valid from `valid_from` and, if
+/// `valid_to` is given, until then.
+///
+/// - `relation`: a stored relation whose last key column is a `Validity`
+/// - `rows`: rows with one value per column except the `Validity` one, in
+///   the relation's column order (keys first)
+///
+/// Each row is asserted at `valid_from` and, with `valid_to`, retracted at
+/// `valid_to` with the same values, so queries at a time in between see it.
+/// Goes through the query engine, so triggers, callbacks and indices apply.
+/// All rows are written in one transaction.
+///
+/// Returns the number of rows written, counting each retraction.
+pub fn cozo_put_valid(
+    db: &CozoDb,
+    relation: String,
+    rows: Vec<Vec<CozoValue>>,
+    valid_from: DateTime<Utc>,
+    valid_to: Option<DateTime<Utc>>,
+) -> anyhow::Result<u64> {
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let at = temporal_column(&columns, &relation)?;
+    if valid_to.is_some_and(|to| to <= valid_from) {
+        anyhow::bail!("valid_to must be later than valid_from");
+    }
+    let mut data = vec![];
+    for row in rows_to_data(rows, columns.len() - 1)? {
+        for (time, is_assert) in [(Some(valid_from), true), (valid_to, false)] {
+            if let Some(time) = time {
+                let mut row = row.clone();
+                row.insert(at, validity(time, is_assert));
+                data.push(DataValue::List(row));
+            }
+        }
+    }
+    let script = format!(
+        "?[{}] <- $rows :put {} {{{}}}",
+        column_names(&columns),
+        relation,
+        column_spec(&columns)
+    );
+    let written = data.len() as u64;
+    let params = BTreeMap::from([("rows".to_string(), DataValue::List(data))]);
+    catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(written)
+}
+
+/// Retract rows of a time travel relation at `at`, so queries from then on
+/// no longer see them.
+///
+/// - `keys`: one entry per row, holding the key column values except the
+///   `Validity` one, in key order
+///
+/// A retraction keeps the values the row had at `at`; keys without a row
+/// valid at `at` are ignored. Goes through the query engine like
+/// [`cozo_put_valid`], in one transaction.
+///
+/// Returns the number of rows retracted.
+pub fn cozo_retract_rows(
+    db: &CozoDb,
+    relation: String,
+    keys: Vec<Vec<CozoValue>>,
+    at: DateTime<Utc>,
+) -> anyhow::Result<u64> {
+    let inner = db.writable()?;
+    let columns = relation_columns(&inner, &relation)?;
+    let validity_at = temporal_column(&columns, &relation)?;
+    let (keys_but_validity, values): (Vec<&ColumnInfo>, Vec<&ColumnInfo>) = columns
+        .iter()
+        .filter(|c| c.index as usize != validity_at)
+        .partition(|c| c.is_key);
+    let keys = rows_to_data(keys, keys_but_validity.len())?;
+    let script = format!(
+        "keys[{keys}] <- $keys\n?[{names}] := keys[{keys}], *{rel}{{{bound} @ $at}}, {validity} = [$at, false]\n:put {rel} {{{spec}}}\n:returning",
+        keys = column_names(keys_but_validity.iter().copied()),
+        names = column_names(&columns),
+        rel = relation,
+        bound = column_names(keys_but_validity.iter().chain(&values).copied()),
+        validity = columns[validity_at].name,
+        spec = column_spec(&columns),
+    );
+    let params = BTreeMap::from([
+        (
+            "keys".to_string(),
+            DataValue::List(keys.into_iter().map(DataValue::List).collect()),
+        ),
+        ("at".to_string(), DataValue::from(at.timestamp_micros())),
+    ]);
+    let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .iter()
+        .filter(|row| row[0].get_str() == Some("inserted"))
+        .count() as u64)
+}
+
+/// A `Validity` at `time`.
+pub(crate) fn validity(time: DateTime<Utc>, is_assert: bool) -> DataValue {
+    DataValue::Validity(Validity {
+        timestamp: ValidityTs(Reverse(time.timestamp_micros())),
+        is_assert: Reverse(is_assert),
+    })
+}
+
+/// Position of the `Validity` column of a time travel relation, which is
+/// its last key column.
+pub(crate) fn validity_column(columns: &[ColumnInfo]) -> Option<usize> {
+    columns
+        .iter()
+        .rfind(|c| c.is_key)
+        .filter(|c| c.column_type == "Validity")
+        .map(|c| c.index as usize)
+}
+
+fn temporal_column(columns: &[ColumnInfo], relation: &str) -> anyhow::Result<usize> {
+    validity_column(columns).ok_or_else(|| {
+        anyhow::anyhow!(
+            "{} is not a time travel relation: its last key column is not a Validity",
+            relation
+        )
+    })
+}
+
+/// `script` with `@ <at>` added to every atom of a relation in `relations`
+/// that has no `@` clause. Strings and comments are skipped.
+fn with_validity(script: &str, relations: &[String], at: &str) -> String {
+    let chars: Vec<char> = script.chars().collect();
+    let is_name = |c: char| c.is_alphanumeric() || c == '_';
+    let mut out = String::with_capacity(script.len());
+    // Closing brackets to insert the clause before, by position.
+    let mut inserts = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' | '"' => i = skip_string(&chars, i),
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '*' => {
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && is_name(chars[end]) {
+                    end += 1;
+                }
+                let name: String = chars[start..end].iter().collect();
+                let mut open = end;
+                while open < chars.len() && chars[open].is_whitespace() {
+                    open += 1;
+                }
+                if relations.contains(&name) && matches!(chars.get(open), Some('{' | '[')) {
+                    if let Some((close, has_clause)) = atom_end(&chars, open) {
+                        if !has_clause {
+                            inserts.push(close);
+                        }
+                    }
+                }
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    for (i, c) in chars.iter().enumerate() {
+        if inserts.contains(&i) {
+            out.push_str(" @ ");
+            out.push_str(at);
+        }
+        out.push(*c);
+    }
+    out
+}
+
+/// Position of the bracket closing the one at `open`, and whether an `@`
+/// appears directly inside.
+pub(crate) fn atom_end(chars: &[char], open: usize) -> Option<(usize, bool)> {
+    let mut depth = 0;
+    let mut has_clause = false;
+    let mut i = open;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' | '"' => {
+                i = skip_string(chars, i);
+                continue;
+            }
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((i, has_clause));
+                }
+            }
+            '@' if depth == 1 => has_clause = true,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Position just past the string literal starting at `start`. Only
+/// single-quoted strings have escapes.
+pub(crate) fn skip_string(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() && chars[i] != quote {
+        if quote == '\'' && chars[i] == '\\' {
+            i += 1;
+        }
+        i += 1;
+    }
+    i + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    fn open() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(
+            &db,
+            ":create prices {item: String, at: Validity => price: Int}",
+        )
+        .unwrap();
+        db
+    }
+
+    fn price_at(db: &CozoDb, secs: i64) -> serde_json::Value {
+        let script = "?[item, price] := *prices{item, price}";
+        let out = cozo_query_asof(db, script.into(), "{}".into(), time(secs)).unwrap();
+        serde_json::from_str::<serde_json::Value>(&out).unwrap()["rows"].clone()
+    }
+
+    #[test]
+    fn validities_convert_to_and_from_times() {
+        let time = ValidityTime {
+            valid_from: DateTime::from_timestamp_micros(1_700_000_000_123_456).unwrap(),
+            is_assert: false,
+        };
+        let value = cozo_validity_from_time(time.clone());
+        assert_eq!(
+            value,
+            CozoValue::Validity {
+                timestamp: 1_700_000_000_123_456,
+                is_assert: false
+            }
+        );
+        assert_eq!(cozo_validity_to_time(value).unwrap(), time);
+        assert!(cozo_validity_to_time(CozoValue::Int(1)).is_err());
+        let end = CozoValue::Validity {
+            timestamp: i64::MAX,
+            is_assert: true,
+        };
+        assert!(cozo_validity_to_time(end).is_err());
+    }
+
+    #[test]
+    fn clauses_are_added_to_atoms_without_one() {
+        let relations = vec!["prices".to_string()];
+        assert_eq!(
+            with_validity("?[p] := *prices{price: p}, *other{x}", &relations, "$t"),
+            "?[p] := *prices{price: p @ $t}, *other{x}"
+        );
+        assert_eq!(
+            with_validity("?[p] := *prices[i, v, p @ 'NOW']", &relations, "$t"),
+            "?[p] := *prices[i, v, p @ 'NOW']"
+        );
+        assert_eq!(
+            with_validity("?[s] := s = '*prices{x}' # *prices{y}", &relations, "$t"),
+            "?[s] := s = '*prices{x}' # *prices{y}"
+        );
+    }
+
+    #[test]
+    fn rows_are_valid_between_their_times() {
+        let db = open();
+        let row = || vec![vec![CozoValue::Str("tea".into()), CozoValue::Int(3)]];
+        assert_eq!(
+            cozo_put_valid(&db, "prices".into(), row(), time(100), Some(time(200))).unwrap(),
+            2
+        );
+        assert_eq!(price_at(&db, 50), serde_json::json!([]));
+        assert_eq!(price_at(&db, 150), serde_json::json!([["tea", 3]]));
+        assert_eq!(price_at(&db, 250), serde_json::json!([]));
+        assert!(cozo_put_valid(&db, "prices".into(), row(), time(200), Some(time(100))).is_err());
+    }
+
+    #[test]
+    fn retracted_rows_disappear_from_then_on() {
+        let db = open();
+        let rows = vec![
+            vec![CozoValue::Str("tea".into()), CozoValue::Int(3)],
+            vec![CozoValue::Str("milk".into()), CozoValue::Int(2)],
+        ];
+        cozo_put_valid(&db, "prices".into(), rows, time(100), None).unwrap();
+        let keys = vec![
+            vec![CozoValue::Str("tea".into())],
+            vec![CozoValue::Str("coffee".into())],
+        ];
+        assert_eq!(
+            cozo_retract_rows(&db, "prices".into(), keys, time(300)).unwrap(),
+            1
+        );
+        assert_eq!(
+            price_at(&db, 200),
+            serde_json::json!([["milk", 2], ["tea", 3]])
+        );
+        assert_eq!(price_at(&db, 400), serde_json::json!([["milk", 2]]));
+
+        run_write_op(&db, ":create plain {item: String => price: Int}").unwrap();
+        assert!(cozo_retract_rows(&db, "plain".into(), vec![], time(1)).is_err());
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use flutter_rust_bridge::frb;
+
+use crate::api::simple::{cozo_close_db, cozo_open_db, CozoDb};
+
+/// One database per tenant, e.g. per signed-in user, each in its own
+/// directory under a root directory.
+#[frb(opaque)]
+pub struct TenantManager {
+    root: PathBuf,
+    engine: String,
+    options: serde_json::Value,
+    /// Tenants opened through the manager, by id.
+    open: Mutex<HashMap<String, OpenTenant>>,
+}
+
+struct OpenTenant {
+    db: CozoDb,
+    column_key: Option<String>,
+}
+
+/// Manage one isolated database per tenant under `root`, which is created
+/// if missing.
+///
+/// - `engine`: "sqlite" or "rocksdb"
+/// - `options`: JSON string of options for every tenant's database, as for
+///   `cozo_open_db`, without `column_key`
+///
+/// A tenant's database lives in `root/<tenant id>`, so tenants never share
+/// files, and deleting one removes everything it stored.
+#[frb(sync)]
+pub fn cozo_tenant_manager(
+    root: String,
+    engine: String,
+    options: String,
+) -> anyhow::Result<TenantManager> {
+    if engine != "sqlite" && engine != "rocksdb" {
+        anyhow::bail!(
+            "Tenants need a persistent engine, sqlite or rocksdb, not {}",
+            engine
+        );
+    }
+    let options: serde_json::Value = if options.trim().is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(&options)
+            .map_err(|e| anyhow::anyhow!("Invalid tenant options JSON: {}", e))?
+    };
+    if !options.is_object() || options.get("column_key").is_some() {
+        anyhow::bail!("Tenant options must be a JSON object without a `column_key`");
+    }
+    std::fs::create_dir_all(&root)
+        .map_err(|e| anyhow::anyhow!("Failed to create tenant root {}: {}", root, e))?;
+    Ok(TenantManager {
+        root: PathBuf::from(root),
+        engine,
+        options,
+        open: Mutex::new(HashMap::new()),
+    })
+}
+
+/// Open the database of `tenant_id`, creating it on first use, or share it
+/// if the manager already has it open.
+///
+/// - `tenant_id`: letters, digits, `-`, `_` and `.`, not starting with `.`
+/// - `column_key`: the tenant's own key for `cozo_encrypt_column`, 64 hex
+///   digits, or `None` for a tenant without encrypted columns
+///
+/// Opening an open tenant with a different key fails.
+#[frb(sync)]
+pub fn cozo_open_tenant(
+    manager: &TenantManager,
+    tenant_id: String,
+    column_key: Option<String>,
+) -> anyhow::Result<CozoDb> {
+    let dir = tenant_dir(manager, &tenant_id)?;
+    let mut open = manager.open.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(tenant) = open.get(&tenant_id) {
+        if tenant.column_key != column_key {
+            anyhow::bail!("Tenant {} is already open with another key", tenant_id);
+        }
+        return Ok(tenant.db.share());
+    }
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| anyhow::anyhow!("Failed to create tenant {}: {}", tenant_id, e))?;
+    let mut options = manager.options.clone();
+    if let Some(key) = &column_key {
+        options["column_key"] = key.clone().into();
+    }
+    let db = cozo_open_db(
+        manager.engine.clone(),
+        database_path(manager, &dir),
+        options.to_string(),
+    )?;
+    let handle = db.share();
+    open.insert(tenant_id, OpenTenant { db, column_key });
+    Ok(handle)
+}
+
+/// Close the database of `tenant_id`, and every handle the manager gave out
+/// for it, e.g. when the user signs out.
+///
+/// Returns false if the tenant was not open.
+#[frb(sync)]
+pub fn cozo_close_tenant(manager: &TenantManager, tenant_id: String) -> bool {
+    let tenant = manager
+        .open
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&tenant_id);
+    match tenant {
+        Some(tenant) => {
+            cozo_close_db(&tenant.db);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Ids of the tenants with a database under the root, sorted.
+#[frb(sync)]
+pub fn cozo_list_tenants(manager: &TenantManager) -> anyhow::Result<Vec<String>> {
+    let entries = std::fs::read_dir(&manager.root)
+        .map_err(|e| anyhow::anyhow!("Failed to list tenants in {:?}: {}", manager.root, e))?;
+    let mut tenants = vec![];
+    for entry in entries {
+        let entry = entry.map_err(|e| anyhow::anyhow!("Failed to list tenants: {}", e))?;
+        let Ok(id) = entry.file_name().into_string() else {
+            continue;
+        };
+        if valid_id(&id) && Path::new(&database_path(manager, &entry.path())).exists() {
+            tenants.push(id);
+        }
+    }
+    tenants.sort();
+    Ok(tenants)
+}
+
+/// Close the database of `tenant_id` and delete its directory with
+/// everything in it, e.g. when the account is removed from the device.
+///
+/// Handles the manager gave out for the tenant are closed first; a handle
+/// opened on the same files without the manager must be closed by the caller
+/// beforehand.
+///
+/// Returns false if the tenant had no database.
+#[frb(sync)]
+pub fn cozo_delete_tenant(manager: &TenantManager, tenant_id: String) -> anyhow::Result<bool> {
+    let dir = tenant_dir(manager, &tenant_id)?;
+    cozo_close_tenant(manager, tenant_id.clone());
+    if !dir.exists() {
+        return Ok(false);
+    }
+    std::fs::remove_dir_all(&dir)
+        .map_err(|e| anyhow::anyhow!("Failed to delete tenant {}: {}", tenant_id, e))?;
+    Ok(true)
+}
+
+fn tenant_dir(manager: &TenantManager, tenant_id: &str) -> anyhow::Result<PathBuf> {
+    if !valid_id(tenant_id) {
+        anyhow::bail!(
+            "Invalid tenant id {:?}: use letters, digits, `-`, `_` and `.`, not starting with `.`",
+            tenant_id
+        );
+    }
+    Ok(manager.root.join(tenant_id))
+}
+
+/// Whether `id` is usable as a directory name on every platform.
+pub(crate) fn valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && !id.starts_with('.')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// The database file (sqlite) or directory (rocksdb) in a tenant's directory.
+fn database_path(manager: &TenantManager, dir: &Path) -> String {
+    let name = match manager.engine.as_str() {
+        "sqlite" => "cozo.db",
+        _ => "cozo",
+    };
+    dir.join(name).to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_is_closed, cozo_run_query};
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    /// A fresh root directory, removed on drop.
+    struct Root(PathBuf);
+
+    impl Root {
+        fn new(name: &str) -> Self {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos();
+            let name = format!("cozo_dart_{}_{}_{}", name, std::process::id(), nanos);
+            Root(std::env::temp_dir().join(name))
+        }
+
+        fn manager(&self) -> TenantManager {
+            let root = self.0.to_string_lossy().into_owned();
+            cozo_tenant_manager(root, "sqlite".into(), "{}".into()).unwrap()
+        }
+    }
+
+    impl Drop for Root {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn tenants_get_their_own_databases() {
+        let root = Root::new("tenants");
+        let manager = root.manager();
+        let alice = cozo_open_tenant(&manager, "alice".into(), None).unwrap();
+        let bob = cozo_open_tenant(&manager, "bob".into(), Some(KEY.into())).unwrap();
+        cozo_run_query(&alice, ":create t {x: Int}".into(), "{}".into(), false).unwrap();
+        assert!(cozo_run_query(&bob, "?[x] := *t{x}".into(), "{}".into(), true).is_err());
+        assert_eq!(cozo_list_tenants(&manager).unwrap(), ["alice", "bob"]);
+
+        // Opening again shares the database, with the same key only.
+        let again = cozo_open_tenant(&manager, "alice".into(), None).unwrap();
+        cozo_run_query(&again, "?[x] := *t{x}".into(), "{}".into(), true).unwrap();
+        assert!(cozo_open_tenant(&manager, "bob".into(), None).is_err());
+
+        assert!(cozo_close_tenant(&manager, "alice".into()));
+        assert!(cozo_is_closed(&alice) && cozo_is_closed(&again));
+        assert!(!cozo_close_tenant(&manager, "alice".into()));
+
+        assert!(cozo_delete_tenant(&manager, "bob".into()).unwrap());
+        assert!(cozo_is_closed(&bob));
+        assert!(!cozo_delete_tenant(&manager, "bob".into()).unwrap());
+        assert_eq!(cozo_list_tenants(&manager).unwrap(), ["alice"]);
+    }
+
+    #[test]
+    fn ids_and_options_are_checked() {
+        let root = Root::new("tenant_checks");
+        let path = root.0.to_string_lossy().into_owned();
+        assert!(cozo_tenant_manager(path.clone(), "mem".into(), "{}".into()).is_err());
+        let keyed = format!(r#"{{"column_key": "{}"}}"#, KEY);
+        assert!(cozo_tenant_manager(path, "sqlite".into(), keyed).is_err());
+
+        let manager = root.manager();
+        for id in ["", ".hidden", "../up", "a/b", "a b"] {
+            assert!(
+                cozo_open_tenant(&manager, id.into(), None).is_err(),
+                "{}",
+                id
+            );
+        }
+        assert!(valid_id("user-1_v2.db"));
+    }
+}
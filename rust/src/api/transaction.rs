@@ -0,0 +1,594 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock, Weak};
+use std::time::Instant;
+
+use cozo::{DataValue, DbInstance, MultiTransaction, NamedRows, TransactionPayload};
+use flutter_rust_bridge::frb;
+
+use crate::api::column_crypto::{decrypt_rows, ColumnCipher};
+use crate::api::error::{catch_panic, failure, ErrorKind};
+use crate::api::row_policy::{apply_row_policies, filter_returning, RowPolicies};
+use crate::api::simple::{live_instance, parse_params, query_error, rows_to_json, CozoDb};
+
+/// Opaque handle to a multi-statement transaction.
+///
+/// Scripts run through the handle share one storage transaction, which is
+/// applied by [`tx_commit`] or discarded by [`tx_abort`]. Dropping the handle
+/// without committing aborts the transaction.
+#[frb(opaque)]
+pub struct CozoTx {
+    // The lock keeps each request/response pair on the channels together, and
+    // the `Option` is taken once the transaction is committed or aborted.
+    inner: Arc<Mutex<Option<MultiTransaction>>>,
+    /// Scripts run so far and the savepoints between them, locked after
+    /// `inner`.
+    history: Arc<Mutex<TxHistory>>,
+    /// Entry in the database's open transactions, removed once finished.
+    id: u64,
+    registry: Arc<TxRegistry>,
+    /// For starting the transaction over in [`tx_rollback_to`].
+    db: Arc<RwLock<Option<DbInstance>>>,
+    write: bool,
+    row_policies: Arc<Mutex<RowPolicies>>,
+    column_cipher: Arc<ColumnCipher>,
+}
+
+/// What [`tx_rollback_to`] replays and [`cozo_list_transactions`] reports.
+struct TxHistory {
+    /// Every script that succeeded, as run, with a hash of its result.
+    scripts: Vec<(String, BTreeMap<String, DataValue>, u64)>,
+    /// Savepoint names with the number of scripts run before them, oldest
+    /// first.
+    savepoints: Vec<(String, usize)>,
+    /// Scripts run, and those of them that appear to write, including any
+    /// rolled back.
+    runs: u32,
+    write_scripts: u32,
+    last_run: Instant,
+}
+
+/// The open transactions of a database, by id, shared by its handles.
+pub(crate) type TxRegistry = Mutex<BTreeMap<u64, TxEntry>>;
+
+/// An open transaction, as seen from [`cozo_list_transactions`]. Does not
+/// keep the transaction alive.
+pub(crate) struct TxEntry {
+    write: bool,
+    started: Instant,
+    inner: Weak<Mutex<Option<MultiTransaction>>>,
+    history: Weak<Mutex<TxHistory>>,
+}
+
+/// Whether a transaction may write, see [`cozo_begin_tx`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxMode {
+    Read,
+    Write,
+}
+
+/// An open transaction, see [`cozo_list_transactions`].
+#[derive(Debug, Clone)]
+pub struct TransactionInfo {
+    /// Id to pass to [`cozo_abort_transaction`].
+    pub id: u64,
+    pub mode: TxMode,
+    /// Seconds since the transaction began.
+    pub age_secs: f64,
+    /// Seconds since a script last ran in it, or since it began.
+    pub idle_secs: f64,
+    /// Scripts run in it so far.
+    pub scripts_run: u32,
+    /// Scripts run that appear to write: those whose text contains a
+    /// mutation option such as `:put`, `:rm` or `:create`. An
+    /// approximation, since a string literal containing one counts too, and
+    /// not a row count: cozo does not report how many rows a script writes.
+    pub write_scripts: u32,
+}
+
+/// Mutation options of a script, by which [`TransactionInfo::write_scripts`]
+/// counts writing scripts.
+pub(crate) const WRITE_OPS: [&str; 7] = [
+    ":put", ":rm", ":insert", ":update", ":delete", ":create", ":replace",
+];
+
+/// Functions, validity literals and algorithms whose result changes from
+/// one run to the next, by which [`tx_rollback_to`] refuses to run a
+/// script again.
+const NON_DETERMINISTIC: [&str; 13] = [
+    "now(",
+    "rand_float(",
+    "rand_bernoulli(",
+    "rand_int(",
+    "rand_choose(",
+    "rand_vec(",
+    "rand_uuid_v1(",
+    "rand_uuid_v4(",
+    "'NOW'",
+    "'ASSERT'",
+    "\"NOW\"",
+    "\"ASSERT\"",
+    "RandomWalk",
+];
+
+impl CozoTx {
+    /// Run a script in the transaction, failing if it is already finished.
+    pub(crate) fn run(
+        &self,
+        script: &str,
+        mut params: BTreeMap<String, DataValue>,
+    ) -> anyhow::Result<NamedRows> {
+        let script = &apply_row_policies(&self.row_policies, script, &mut params)?;
+        let guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let inner = guard
+            .as_ref()
+            .ok_or_else(|| failure(ErrorKind::Transact, "Transaction is already finished"))?;
+        let rows = catch_panic(|| {
+            inner
+                .run_script(script, params.clone())
+                .map_err(|err| query_error(err, script))
+        })?;
+        let mut history = self.history.lock().unwrap_or_else(PoisonError::into_inner);
+        history.runs += 1;
+        if WRITE_OPS.iter().any(|op| script.contains(op)) {
+            history.write_scripts += 1;
+        }
+        history.last_run = Instant::now();
+        history
+            .scripts
+            .push((script.clone(), params, result_hash(&rows)));
+        drop(history);
+        let run = |script: &str, params| {
+            catch_panic(|| inner.run_script(script, params)).map_err(|err| query_error(err, script))
+        };
+        let mut rows = filter_returning(&self.row_policies, &run, script, rows)?;
+        decrypt_rows(&self.column_cipher, &mut rows)?;
+        Ok(rows)
+    }
+}
+
+/// Hash of a result, by which [`tx_rollback_to`] tells whether a script run
+/// again read the same data.
+fn result_hash(rows: &NamedRows) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    rows.headers.hash(&mut hasher);
+    rows.rows.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl Drop for CozoTx {
+    fn drop(&mut self) {
+        self.registry
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.id);
+    }
+}
+
+/// Begin a multi-statement transaction.
+///
+/// - `write`: if true, the transaction may modify stored relations
+#[frb(sync)]
+pub fn cozo_begin_tx(db: &CozoDb, write: bool) -> anyhow::Result<CozoTx> {
+    let inner = if write {
+        db.writable()?
+    } else {
+        db.instance()?
+    };
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let now = Instant::now();
+    let tx = CozoTx {
+        inner: Arc::new(Mutex::new(Some(inner.multi_transaction(write)))),
+        history: Arc::new(Mutex::new(TxHistory {
+            scripts: vec![],
+            savepoints: vec![],
+            runs: 0,
+            write_scripts: 0,
+            last_run: now,
+        })),
+        id,
+        registry: db.transactions.clone(),
+        db: db.shared(),
+        write,
+        row_policies: db.row_policies.clone(),
+        column_cipher: db.column_cipher.clone(),
+    };
+    db.transactions
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(
+            id,
+            TxEntry {
+                write,
+                started: now,
+                inner: Arc::downgrade(&tx.inner),
+                history: Arc::downgrade(&tx.history),
+            },
+        );
+    Ok(tx)
+}
+
+/// Run a CozoScript query inside the transaction.
+///
+/// - `params_json`: JSON object of named parameters, e.g. '{"name": "Alice"}'
+///
+/// Returns a JSON string with the query result, or an error if the query
+/// fails. A failed query does not end the transaction.
+pub fn tx_run_query(tx: &CozoTx, script: String, params_json: String) -> anyhow::Result<String> {
+    let params = parse_params(&params_json)?;
+    let start = Instant::now();
+    let rows = tx.run(&script, params)?;
+    Ok(rows_to_json(rows, start.elapsed().as_secs_f64()))
+}
+
+/// Commit the transaction. The handle cannot be used afterwards.
+pub fn tx_commit(tx: &CozoTx) -> anyhow::Result<()> {
+    finish(tx, TransactionPayload::Commit)
+        .map_err(|e| failure(ErrorKind::Transact, format!("Commit failed: {}", e)))
+}
+
+/// Abort the transaction, discarding its writes. The handle cannot be used afterwards.
+pub fn tx_abort(tx: &CozoTx) -> anyhow::Result<()> {
+    finish(tx, TransactionPayload::Abort)
+        .map_err(|e| failure(ErrorKind::Transact, format!("Abort failed: {}", e)))
+}
+
+/// Mark the current point of the transaction as `name`, to return to with
+/// [`tx_rollback_to`], e.g. before each step of a wizard or each chunk of an
+/// import.
+///
+/// Savepoints nest: a name used again marks a new point, and rolling back
+/// or releasing it goes to the newest one. The handle keeps every script it
+/// has run, with its parameters, until it is finished, to go back with.
+#[frb(sync)]
+pub fn tx_savepoint(tx: &CozoTx, name: String) -> anyhow::Result<()> {
+    let guard = tx.inner.lock().unwrap_or_else(PoisonError::into_inner);
+    if guard.is_none() {
+        return Err(failure(
+            ErrorKind::Transact,
+            "Transaction is already finished",
+        ));
+    }
+    let mut history = tx.history.lock().unwrap_or_else(PoisonError::into_inner);
+    let at = history.scripts.len();
+    history.savepoints.push((name, at));
+    Ok(())
+}
+
+/// Undo what the transaction wrote since the savepoint `name`, keeping the
+/// savepoint and everything before it. Savepoints set after it are removed.
+///
+/// Cozo transactions cannot undo part of their writes, so this aborts the
+/// storage transaction and runs the scripts from before the savepoint again
+/// in a new one. Savepoints are therefore not isolated: the new transaction
+/// sees what other writers committed in the meantime, and takes as long as
+/// running the scripts did.
+///
+/// - If a script from before the savepoint calls a function whose result
+///   changes on each run, such as `now()`, `rand_uuid_v4()` or the other
+///   `rand_` functions, or uses the `'NOW'` or `'ASSERT'` validity, the
+///   rollback is refused and the transaction is left as it was. The check
+///   looks at the script text, so a string literal containing one counts
+///   too; abort the transaction instead in that case.
+/// - If a script returns something else than it did the first time, e.g.
+///   because another writer committed rows it reads, or if it fails, the
+///   transaction is aborted: the error says so, the handle can no longer be
+///   used, as after [`tx_abort`], and it is no longer listed by
+///   [`cozo_list_transactions`].
+pub fn tx_rollback_to(tx: &CozoTx, name: String) -> anyhow::Result<()> {
+    let mut guard = tx.inner.lock().unwrap_or_else(PoisonError::into_inner);
+    if guard.is_none() {
+        return Err(failure(
+            ErrorKind::Transact,
+            "Transaction is already finished",
+        ));
+    }
+    let mut history = tx.history.lock().unwrap_or_else(PoisonError::into_inner);
+    let Some(savepoint) = history.savepoints.iter().rposition(|(n, _)| *n == name) else {
+        anyhow::bail!("Rollback failed: no savepoint {}", name);
+    };
+    let at = history.savepoints[savepoint].1;
+    for (script, _, _) in &history.scripts[..at] {
+        if let Some(op) = NON_DETERMINISTIC.iter().find(|op| script.contains(*op)) {
+            return Err(failure(
+                ErrorKind::Transact,
+                format!(
+                    "Rollback failed: `{}` uses {}, which gives a new value each time \
+                     it runs, so it cannot run again; abort the transaction instead",
+                    script,
+                    op.trim_end_matches('(')
+                ),
+            ));
+        }
+    }
+    history.savepoints.truncate(savepoint + 1);
+    history.scripts.truncate(at);
+    let old = guard.take().expect("checked above");
+    let restarted = finish_multi(old, TransactionPayload::Abort).and_then(|()| {
+        let inner = live_instance(&tx.db)?.multi_transaction(tx.write);
+        for (script, params, hash) in &history.scripts {
+            let replayed = catch_panic(|| {
+                inner
+                    .run_script(script, params.clone())
+                    .map_err(|err| query_error(err, script))
+            });
+            let failed = match replayed {
+                Ok(rows) if result_hash(&rows) == *hash => continue,
+                Ok(_) => anyhow::anyhow!(
+                    "`{}` read different data than the first time it ran",
+                    script
+                ),
+                Err(e) => e,
+            };
+            let _ = finish_multi(inner, TransactionPayload::Abort);
+            anyhow::bail!("running the transaction again: {}", failed);
+        }
+        Ok(inner)
+    });
+    match restarted {
+        Ok(inner) => {
+            *guard = Some(inner);
+            Ok(())
+        }
+        Err(e) => {
+            // The handle stays finished, as after `tx_abort`.
+            history.scripts.clear();
+            history.savepoints.clear();
+            tx.registry
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(&tx.id);
+            anyhow::bail!("Rollback failed: {}; the transaction was aborted", e)
+        }
+    }
+}
+
+/// Forget the savepoint `name` and the savepoints set after it, keeping
+/// what was written since.
+#[frb(sync)]
+pub fn tx_release(tx: &CozoTx, name: String) -> anyhow::Result<()> {
+    let guard = tx.inner.lock().unwrap_or_else(PoisonError::into_inner);
+    if guard.is_none() {
+        return Err(failure(
+            ErrorKind::Transact,
+            "Transaction is already finished",
+        ));
+    }
+    let mut history = tx.history.lock().unwrap_or_else(PoisonError::into_inner);
+    let savepoint = history
+        .savepoints
+        .iter()
+        .rposition(|(n, _)| *n == name)
+        .ok_or_else(|| anyhow::anyhow!("Release failed: no savepoint {}", name))?;
+    history.savepoints.truncate(savepoint);
+    Ok(())
+}
+
+/// The transactions open on the database, oldest first, e.g. to find a
+/// write transaction a forgotten `CozoTx` keeps open, which makes every
+/// other write wait.
+///
+/// Lists those begun with `cozo_begin_tx` through this handle or the
+/// handles sharing its database that are neither committed, aborted nor
+/// dropped.
+#[frb(sync)]
+pub fn cozo_list_transactions(db: &CozoDb) -> Vec<TransactionInfo> {
+    let registry = db
+        .transactions
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    registry
+        .iter()
+        .filter_map(|(id, entry)| {
+            let history = entry.history.upgrade()?;
+            let history = history.lock().unwrap_or_else(PoisonError::into_inner);
+            Some(TransactionInfo {
+                id: *id,
+                mode: match entry.write {
+                    true => TxMode::Write,
+                    false => TxMode::Read,
+                },
+                age_secs: entry.started.elapsed().as_secs_f64(),
+                idle_secs: history.last_run.elapsed().as_secs_f64(),
+                scripts_run: history.runs,
+                write_scripts: history.write_scripts,
+            })
+        })
+        .collect()
+}
+
+/// Abort the open transaction `id` from [`cozo_list_transactions`],
+/// discarding its writes. Later calls on its `CozoTx` fail as for a
+/// finished transaction.
+///
+/// Waits for a script running in it to finish first. Returns false if no
+/// such transaction is open.
+pub fn cozo_abort_transaction(db: &CozoDb, id: u64) -> anyhow::Result<bool> {
+    let entry = db
+        .transactions
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&id);
+    let Some(inner) = entry.and_then(|entry| entry.inner.upgrade()) else {
+        return Ok(false);
+    };
+    let taken = inner.lock().unwrap_or_else(PoisonError::into_inner).take();
+    match taken {
+        Some(inner) => {
+            finish_multi(inner, TransactionPayload::Abort)
+                .map_err(|e| failure(ErrorKind::Transact, format!("Abort failed: {}", e)))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+// `MultiTransaction::commit` drops the storage result, so the payload is sent
+// through the channels directly to surface commit errors.
+fn finish(tx: &CozoTx, payload: TransactionPayload) -> anyhow::Result<()> {
+    let inner = tx
+        .inner
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+        .ok_or_else(|| failure(ErrorKind::Transact, "Transaction is already finished"))?;
+    tx.registry
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&tx.id);
+    finish_multi(inner, payload)
+}
+
+/// Commit or abort a transaction opened with `multi_transaction`, surfacing
+/// the storage error.
+pub(crate) fn finish_multi(
+    inner: MultiTransaction,
+    payload: TransactionPayload,
+) -> anyhow::Result<()> {
+    inner.sender.send(payload)?;
+    inner
+        .receiver
+        .recv()?
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query};
+    use crate::api::vector::run_write_op;
+
+    fn ids(tx: &CozoTx) -> serde_json::Value {
+        let out = tx_run_query(tx, "?[id] := *items{id}".into(), "{}".into()).unwrap();
+        serde_json::from_str::<serde_json::Value>(&out).unwrap()["rows"].clone()
+    }
+
+    fn open() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => at: Float default 0.0}").unwrap();
+        db
+    }
+
+    fn stored_ids(db: &CozoDb) -> String {
+        cozo_run_query(db, "?[id] := *items{id}".into(), "{}".into(), false).unwrap()
+    }
+
+    #[test]
+    fn commit_applies_and_abort_discards() {
+        let db = open();
+        let put = "?[id] <- [[1]] :put items {id}";
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        tx_run_query(&tx, put.into(), "{}".into()).unwrap();
+        // A failed query leaves the transaction open.
+        assert!(tx_run_query(&tx, "?[x] := *missing{x}".into(), "{}".into()).is_err());
+        assert_eq!(ids(&tx), serde_json::json!([[1]]));
+        tx_commit(&tx).unwrap();
+        assert!(stored_ids(&db).contains("[[1]]"));
+        assert!(tx_run_query(&tx, put.into(), "{}".into()).is_err());
+        assert!(tx_commit(&tx).is_err());
+
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        tx_run_query(&tx, "?[id] <- [[2]] :put items {id}".into(), "{}".into()).unwrap();
+        tx_abort(&tx).unwrap();
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        tx_run_query(&tx, "?[id] <- [[3]] :put items {id}".into(), "{}".into()).unwrap();
+        drop(tx);
+        run_write_op(&db, "?[id] <- [[4]] :put items {id}").unwrap();
+        assert!(stored_ids(&db).contains("[[1],[4]]"));
+    }
+
+    #[test]
+    fn read_transactions_cannot_write() {
+        let db = open();
+        let tx = cozo_begin_tx(&db, false).unwrap();
+        assert!(tx_run_query(&tx, "?[id] <- [[1]] :put items {id}".into(), "{}".into()).is_err());
+        tx_commit(&tx).unwrap();
+        assert!(stored_ids(&db).contains("[]"));
+    }
+
+    #[test]
+    fn open_transactions_are_listed_and_can_be_aborted() {
+        let db = open();
+        let write = cozo_begin_tx(&db, true).unwrap();
+        let put = "?[id] <- [[1]] :put items {id}";
+        tx_run_query(&write, put.into(), "{}".into()).unwrap();
+        ids(&write);
+        // On the mem engine, a read waits for the write transaction.
+        let read = cozo_begin_tx(&db, false).unwrap();
+
+        let listed = cozo_list_transactions(&db);
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].mode, TxMode::Write);
+        assert_eq!((listed[0].scripts_run, listed[0].write_scripts), (2, 1));
+        assert!(listed[0].age_secs >= listed[0].idle_secs);
+        assert_eq!((listed[1].mode, listed[1].scripts_run), (TxMode::Read, 0));
+
+        assert!(cozo_abort_transaction(&db, listed[0].id).unwrap());
+        assert!(!cozo_abort_transaction(&db, listed[0].id).unwrap());
+        assert!(tx_run_query(&write, put.into(), "{}".into()).is_err());
+        assert!(tx_commit(&write).is_err());
+        tx_commit(&read).unwrap();
+        run_write_op(&db, "?[id] <- [[2]] :put items {id}").unwrap();
+        assert!(stored_ids(&db).contains("[[2]]"));
+        drop(write);
+        assert!(cozo_list_transactions(&db).is_empty());
+    }
+
+    #[test]
+    fn rollback_undoes_the_writes_since_the_savepoint() {
+        let db = open();
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        let put = |id: i64| {
+            let script = format!("?[id] <- [[{}]] :put items {{id}}", id);
+            tx_run_query(&tx, script, "{}".into()).unwrap();
+        };
+        put(1);
+        tx_savepoint(&tx, "step".into()).unwrap();
+        put(2);
+        tx_savepoint(&tx, "later".into()).unwrap();
+        put(3);
+        tx_rollback_to(&tx, "step".into()).unwrap();
+        assert_eq!(ids(&tx), serde_json::json!([[1]]));
+        assert!(tx_release(&tx, "later".into()).is_err());
+
+        put(4);
+        tx_rollback_to(&tx, "step".into()).unwrap();
+        assert!(tx_rollback_to(&tx, "missing".into()).is_err());
+        tx_commit(&tx).unwrap();
+        let out = cozo_run_query(&db, "?[id] := *items{id}".into(), "{}".into(), false);
+        assert!(out.unwrap().contains("[[1]]"));
+    }
+
+    #[test]
+    fn rollback_refuses_scripts_that_would_change_on_replay() {
+        let db = open();
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        let script = "?[id, at] <- [[1, now()]] :put items {id => at}";
+        tx_run_query(&tx, script.into(), "{}".into()).unwrap();
+        tx_savepoint(&tx, "step".into()).unwrap();
+        let err = tx_rollback_to(&tx, "step".into()).unwrap_err();
+        assert!(err.to_string().contains("now"));
+        // The transaction is left as it was.
+        assert_eq!(ids(&tx), serde_json::json!([[1]]));
+        tx_commit(&tx).unwrap();
+    }
+
+    #[test]
+    fn rollback_aborts_when_a_replayed_read_changes() {
+        let db = open();
+        let tx = cozo_begin_tx(&db, true).unwrap();
+        assert_eq!(ids(&tx), serde_json::json!([]));
+        tx_savepoint(&tx, "step".into()).unwrap();
+        // As if another writer had committed rows the read returned.
+        tx.history.lock().unwrap().scripts[0].2 ^= 1;
+        let err = tx_rollback_to(&tx, "step".into()).unwrap_err();
+        assert!(err.to_string().contains("read different data"));
+        assert!(tx_run_query(&tx, "?[x] <- [[1]]".into(), "{}".into()).is_err());
+        assert!(cozo_list_transactions(&db).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::api::schema::system_op;
+use crate::api::simple::CozoDb;
+use crate::api::vector::run_write_op;
+
+/// The triggers of a stored relation.
+///
+/// Each trigger is a query script, without surrounding braces, run in the
+/// same transaction as the write that fired it. `_new[...]` holds the
+/// written rows and `_old[...]` the rows they replaced or removed, both with
+/// the relation's columns. Triggers of one kind run in order.
+#[derive(Debug, Clone, Default)]
+pub struct TriggerSpec {
+    /// Run after rows are put.
+    pub on_put: Vec<String>,
+    /// Run after rows are removed.
+    pub on_rm: Vec<String>,
+    /// Run after the relation is replaced with `:replace`.
+    pub on_replace: Vec<String>,
+}
+
+/// Set the triggers of a stored relation, replacing all existing ones.
+pub fn cozo_set_triggers(db: &CozoDb, relation: String, spec: TriggerSpec) -> anyhow::Result<()> {
+    let mut script = format!("::set_triggers {}", relation);
+    for (kind, triggers) in [
+        ("put", &spec.on_put),
+        ("rm", &spec.on_rm),
+        ("replace", &spec.on_replace),
+    ] {
+        for trigger in triggers {
+            script.push_str(&format!("\non {} {{\n{}\n}}", kind, trigger));
+        }
+    }
+    run_write_op(db, &script)
+}
+
+/// List the triggers of a stored relation.
+pub fn cozo_list_triggers(db: &CozoDb, relation: String) -> anyhow::Result<TriggerSpec> {
+    let rows = system_op(&db.instance()?, &format!("::show_triggers {}", relation))?;
+    let mut spec = TriggerSpec::default();
+    // Rows come grouped by kind, each kind in order.
+    for row in rows {
+        let trigger = row[2].get_str().unwrap_or_default().trim().to_string();
+        match row[0].get_str() {
+            Some("put") => spec.on_put.push(trigger),
+            Some("rm") => spec.on_rm.push(trigger),
+            Some("replace") => spec.on_replace.push(trigger),
+            _ => {}
+        }
+    }
+    Ok(spec)
+}
+
+/// Remove every trigger of a stored relation.
+pub fn cozo_clear_triggers(db: &CozoDb, relation: String) -> anyhow::Result<()> {
+    cozo_set_triggers(db, relation, TriggerSpec::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+    use cozo::ScriptMutability;
+
+    fn logged(db: &CozoDb) -> Vec<String> {
+        let rows = db
+            .instance()
+            .unwrap()
+            .run_script(
+                "?[event] := *log{event}",
+                Default::default(),
+                ScriptMutability::Immutable,
+            )
+            .unwrap();
+        rows.rows
+            .iter()
+            .map(|row| row[0].get_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn triggers_run_on_writes_until_cleared() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int => name: String}").unwrap();
+        run_write_op(&db, ":create log {event: String}").unwrap();
+        let spec = TriggerSpec {
+            on_put: vec![
+                "?[event] := _new[_, name], event = concat('put ', name) :put log {event}".into(),
+            ],
+            on_rm: vec![
+                "?[event] := _old[_, name], event = concat('rm ', name) :put log {event}".into(),
+            ],
+            on_replace: vec![],
+        };
+        cozo_set_triggers(&db, "items".into(), spec.clone()).unwrap();
+        let listed = cozo_list_triggers(&db, "items".into()).unwrap();
+        assert_eq!(listed.on_put, spec.on_put);
+        assert_eq!(listed.on_rm, spec.on_rm);
+        assert!(listed.on_replace.is_empty());
+
+        run_write_op(&db, "?[id, name] <- [[1, 'a']] :put items {id => name}").unwrap();
+        run_write_op(&db, "?[id] <- [[1]] :rm items {id}").unwrap();
+        assert_eq!(logged(&db), ["put a", "rm a"]);
+
+        cozo_clear_triggers(&db, "items".into()).unwrap();
+        assert!(cozo_list_triggers(&db, "items".into())
+            .unwrap()
+            .on_put
+            .is_empty());
+        run_write_op(&db, "?[id, name] <- [[2, 'b']] :put items {id => name}").unwrap();
+        assert_eq!(logged(&db).len(), 2);
+    }
+
+    #[test]
+    fn invalid_triggers_are_rejected() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create items {id: Int}").unwrap();
+        let spec = TriggerSpec {
+            on_put: vec!["?[x] := x =".into()],
+            ..Default::default()
+        };
+        assert!(cozo_set_triggers(&db, "items".into(), spec).is_err());
+        assert!(cozo_set_triggers(&db, "missing".into(), TriggerSpec::default()).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::BTreeMap;
+
+use cozo::{DataValue, ScriptMutability};
+
+use crate::api::error::catch_panic;
+use crate::api::running::epoch_secs;
+use crate::api::schema::{column_names, cozo_list_relations, relation_columns};
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::value::string_literal;
+use crate::api::vector::run_write_op;
+
+/// Stored relation holding the expiry settings of relations:
+/// `{relation => column, ttl_secs}`.
+const TTL_RELATION: &str = "cozo_ttl";
+
+/// Expiry setting of a relation, see [`cozo_set_ttl`].
+#[derive(Debug, Clone)]
+pub struct TtlSetting {
+    pub relation: String,
+    /// Column holding the time each row was written or last refreshed.
+    pub column: String,
+    /// Seconds a row lives after that time.
+    pub ttl_secs: f64,
+}
+
+/// Make rows of `relation` expire `ttl_secs` seconds after the time in
+/// `ttl_column`, e.g. for caches, sessions and log tables.
+///
+/// - `ttl_column`: an `Int` or `Float` column holding Unix time in seconds,
+///   typically declared with `default now()`
+///
+/// Expired rows are deleted by [`cozo_purge_expired`], so they stay visible
+/// to queries until it runs. The setting is stored in the `cozo_ttl`
+/// relation and survives restarts; setting it again replaces it.
+pub fn cozo_set_ttl(
+    db: &CozoDb,
+    relation: String,
+    ttl_column: String,
+    ttl_secs: f64,
+) -> anyhow::Result<()> {
+    let inner = db.writable()?;
+    let column = relation_columns(&inner, &relation)?
+        .into_iter()
+        .find(|c| c.name == ttl_column)
+        .ok_or_else(|| anyhow::anyhow!("{} has no column {}", relation, ttl_column))?;
+    if !["Int", "Float"].contains(&column.column_type.as_str()) {
+        anyhow::bail!(
+            "TTL column {} must be an Int or Float, not {}",
+            ttl_column,
+            column.column_type
+        );
+    }
+    if !(ttl_secs.is_finite() && ttl_secs > 0.0) {
+        anyhow::bail!("ttl_secs must be a positive number of seconds");
+    }
+    ensure_settings(db)?;
+    let script = format!(
+        "?[relation, column, ttl_secs] <- [[$relation, $column, $ttl]] :put {} {{relation => column, ttl_secs}}",
+        TTL_RELATION
+    );
+    let params = BTreeMap::from([
+        ("relation".to_string(), DataValue::from(relation)),
+        ("column".to_string(), DataValue::from(ttl_column)),
+        ("ttl".to_string(), DataValue::from(ttl_secs)),
+    ]);
+    catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+        .map_err(|err| query_error(err, &script))?;
+    Ok(())
+}
+
+/// Stop rows of `relation` from expiring. Its rows are kept.
+pub fn cozo_clear_ttl(db: &CozoDb, relation: String) -> anyhow::Result<()> {
+    if !settings_exist(db)? {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            "?[relation] <- [[{}]] :rm {} {{relation}}",
+            string_literal(&relation),
+            TTL_RELATION
+        ),
+    )
+}
+
+/// The expiry settings of all relations.
+pub fn cozo_list_ttl(db: &CozoDb) -> anyhow::Result<Vec<TtlSetting>> {
+    if !settings_exist(db)? {
+        return Ok(vec![]);
+    }
+    let script = format!(
+        "?[relation, column, ttl_secs] := *{}{{relation, column, ttl_secs}}",
+        TTL_RELATION
+    );
+    let inner = db.instance()?;
+    let rows =
+        catch_panic(|| inner.run_script(&script, Default::default(), ScriptMutability::Immutable))
+            .map_err(|err| query_error(err, &script))?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .map(|row| TtlSetting {
+            relation: row[0].get_str().unwrap_or_default().to_string(),
+            column: row[1].get_str().unwrap_or_default().to_string(),
+            ttl_secs: row[2].get_float().unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Delete the expired rows of every relation with a TTL, e.g. on app start
+/// and then periodically.
+///
+/// Rows are removed with `:rm`, so triggers and change callbacks see them,
+/// one transaction per relation. Settings of relations that no longer exist
+/// are skipped.
+///
+/// Returns the number of rows deleted.
+pub fn cozo_purge_expired(db: &CozoDb) -> anyhow::Result<u64> {
+    purge_expired(db, None)
+}
+
+/// [`cozo_purge_expired`], deleting at most `limit` rows per relation.
+pub(crate) fn purge_expired(db: &CozoDb, limit: Option<usize>) -> anyhow::Result<u64> {
+    let inner = db.writable()?;
+    let existing: Vec<String> = cozo_list_relations(db)?
+        .into_iter()
+        .map(|rel| rel.name)
+        .collect();
+    let now = epoch_secs();
+    let mut deleted = 0;
+    for setting in cozo_list_ttl(db)? {
+        if !existing.contains(&setting.relation) {
+            continue;
+        }
+        let columns = relation_columns(&inner, &setting.relation)?;
+        let keys = column_names(columns.iter().filter(|c| c.is_key));
+        let is_key = columns.iter().any(|c| c.is_key && c.name == setting.column);
+        let bindings = match is_key {
+            true => keys.clone(),
+            false => format!("{}, {}", keys, setting.column),
+        };
+        let limit = limit.map_or(String::new(), |n| format!("\n:limit {}", n));
+        let script = format!(
+            "?[{keys}] := *{rel}{{{bindings}}}, {column} < $cutoff{limit}\n:rm {rel} {{{keys}}}\n:returning",
+            keys = keys,
+            rel = setting.relation,
+            bindings = bindings,
+            column = setting.column,
+            limit = limit,
+        );
+        let params = BTreeMap::from([(
+            "cutoff".to_string(),
+            DataValue::from(now - setting.ttl_secs),
+        )]);
+        let rows = catch_panic(|| inner.run_script(&script, params, ScriptMutability::Mutable))
+            .map_err(|err| query_error(err, &script))?;
+        deleted += rows
+            .rows
+            .iter()
+            .filter(|row| row[0].get_str() == Some("deleted"))
+            .count() as u64;
+    }
+    Ok(deleted)
+}
+
+fn settings_exist(db: &CozoDb) -> anyhow::Result<bool> {
+    Ok(cozo_list_relations(db)?
+        .iter()
+        .any(|rel| rel.name == TTL_RELATION))
+}
+
+fn ensure_settings(db: &CozoDb) -> anyhow::Result<()> {
+    if settings_exist(db)? {
+        return Ok(());
+    }
+    run_write_op(
+        db,
+        &format!(
+            ":create {} {{relation: String => column: String, ttl_secs: Float}}",
+            TTL_RELATION
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+
+    fn open() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create sessions {id: Int => at: Float, user: String}").unwrap();
+        let now = epoch_secs();
+        let script = format!(
+            "?[id, at, user] <- [[1, {}, 'a'], [2, {}, 'b'], [3, {}, 'c']] :put sessions {{id => at, user}}",
+            now - 500.0,
+            now - 200.0,
+            now
+        );
+        run_write_op(&db, &script).unwrap();
+        db
+    }
+
+    fn ids(db: &CozoDb, relation: &str) -> Vec<DataValue> {
+        let script = format!("?[id] := *{}{{id}}", relation);
+        let inner = db.instance().unwrap();
+        let rows = inner
+            .run_script(&script, Default::default(), ScriptMutability::Immutable)
+            .unwrap();
+        rows.rows.into_iter().map(|row| row[0].clone()).collect()
+    }
+
+    #[test]
+    fn purge_deletes_the_expired_rows() {
+        let db = open();
+        cozo_set_ttl(&db, "sessions".into(), "at".into(), 100.0).unwrap();
+        assert_eq!(purge_expired(&db, Some(1)).unwrap(), 1);
+        assert_eq!(cozo_purge_expired(&db).unwrap(), 1);
+        assert_eq!(ids(&db, "sessions"), [DataValue::from(3)]);
+        assert_eq!(cozo_purge_expired(&db).unwrap(), 0);
+    }
+
+    #[test]
+    fn settings_are_listed_replaced_and_cleared() {
+        let db = open();
+        assert!(cozo_list_ttl(&db).unwrap().is_empty());
+        cozo_set_ttl(&db, "sessions".into(), "at".into(), 100.0).unwrap();
+        cozo_set_ttl(&db, "sessions".into(), "at".into(), 1000.0).unwrap();
+        let settings = cozo_list_ttl(&db).unwrap();
+        assert_eq!(settings.len(), 1);
+        assert_eq!(
+            (settings[0].column.as_str(), settings[0].ttl_secs),
+            ("at", 1000.0)
+        );
+        assert_eq!(cozo_purge_expired(&db).unwrap(), 0);
+
+        cozo_clear_ttl(&db, "sessions".into()).unwrap();
+        assert!(cozo_list_ttl(&db).unwrap().is_empty());
+        assert_eq!(ids(&db, "sessions").len(), 3);
+    }
+
+    #[test]
+    fn key_columns_and_dropped_relations() {
+        let db = open();
+        run_write_op(&db, ":create events {at: Float, id: Int}").unwrap();
+        let script = format!(
+            "?[at, id] <- [[{}, 1]] :put events {{at, id}}",
+            epoch_secs() - 60.0
+        );
+        run_write_op(&db, &script).unwrap();
+        cozo_set_ttl(&db, "events".into(), "at".into(), 10.0).unwrap();
+        cozo_set_ttl(&db, "sessions".into(), "at".into(), 100.0).unwrap();
+        run_write_op(&db, "::remove sessions").unwrap();
+        assert_eq!(cozo_purge_expired(&db).unwrap(), 1);
+        assert!(ids(&db, "events").is_empty());
+    }
+
+    #[test]
+    fn settings_are_checked() {
+        let db = open();
+        assert!(cozo_set_ttl(&db, "sessions".into(), "missing".into(), 10.0).is_err());
+        assert!(cozo_set_ttl(&db, "sessions".into(), "user".into(), 10.0).is_err());
+        assert!(cozo_set_ttl(&db, "sessions".into(), "at".into(), 0.0).is_err());
+        assert!(cozo_set_ttl(&db, "sessions".into(), "at".into(), f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+use flutter_rust_bridge::frb;
+use serde_json::Value;
+
+use crate::api::error::CozoDartError;
+use crate::api::explain::{explain_raw, explain_source, run_explain, script_offset};
+use crate::api::simple::CozoDb;
+
+/// Outcome of [`cozo_validate_script`].
+#[derive(Debug, Clone)]
+pub struct ValidationResult {
+    /// False if the script failed to parse or compile.
+    pub valid: bool,
+    /// Whether the script could be checked at all. System ops (`::...`) and
+    /// imperative scripts (`{...}` blocks) cannot be compiled without
+    /// running them, so they are reported valid but unchecked.
+    pub checked: bool,
+    /// The parse or compile error, with its span relative to the script.
+    pub error: Option<CozoDartError>,
+}
+
+/// Parse and compile a script without running it, for query editors that
+/// validate as the user types.
+///
+/// Compiling checks the syntax, that the stored relations read exist and
+/// that rules and relations are used with the right arity and columns.
+/// Nothing is read from or written to the database.
+#[frb(sync)]
+pub fn cozo_validate_script(db: &CozoDb, script: String) -> ValidationResult {
+    if !checkable(&script) {
+        return ValidationResult {
+            valid: true,
+            checked: false,
+            error: None,
+        };
+    }
+    match run_explain(db, &script) {
+        Ok(_) => ValidationResult {
+            valid: true,
+            checked: true,
+            error: None,
+        },
+        Err(err) => ValidationResult {
+            valid: false,
+            checked: true,
+            error: Some(CozoDartError::from(err)),
+        },
+    }
+}
+
+/// How serious a [`ScriptDiagnostic`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Advice,
+}
+
+/// A position in a script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextPosition {
+    /// Byte offset into the UTF-8 script.
+    pub offset: u32,
+    /// Zero-based line.
+    pub line: u32,
+    /// Zero-based column in UTF-16 code units, the unit Dart strings and
+    /// text fields index by.
+    pub column: u32,
+}
+
+/// A problem found in a script, positioned for underlining in an editor.
+#[derive(Debug, Clone)]
+pub struct ScriptDiagnostic {
+    /// What is wrong, without the rendered source snippet.
+    pub message: String,
+    pub severity: DiagnosticSeverity,
+    /// cozo's diagnostic code, e.g. `parser::pest`.
+    pub code: Option<String>,
+    /// Start of the part the diagnostic points at.
+    pub start: TextPosition,
+    /// End of that part, exclusive. Equal to `start` where cozo points at a
+    /// position rather than a range, e.g. an unexpected end of input.
+    pub end: TextPosition,
+    /// cozo's note on the pointed-at part, when it has one.
+    pub label: Option<String>,
+    /// Suggestion on how to fix it, when cozo has one.
+    pub hint: Option<String>,
+}
+
+/// Parse and compile a script without running it, and report what is wrong
+/// with it as positioned diagnostics.
+///
+/// Checks the same things as `cozo_validate_script`, and likewise returns
+/// nothing for system ops and imperative scripts. An error pointing at
+/// several parts of the script gives one diagnostic per part; an error
+/// pointing at none is placed at the start of the script.
+///
+/// Returns the diagnostics, empty if the script compiles.
+#[frb(sync)]
+pub fn cozo_diagnose_script(db: &CozoDb, script: String) -> anyhow::Result<Vec<ScriptDiagnostic>> {
+    if !checkable(&script) {
+        return Ok(vec![]);
+    }
+    let err = match explain_raw(db, &script)? {
+        Ok(_) => return Ok(vec![]),
+        Err(err) => err,
+    };
+    let json = cozo::format_error_as_json(err, Some(&explain_source(&script)));
+    let mut diagnostics = vec![];
+    push_diagnostics(&mut diagnostics, &json, &script);
+    Ok(diagnostics)
+}
+
+/// System ops and imperative scripts cannot be wrapped in `::explain`.
+fn checkable(script: &str) -> bool {
+    let trimmed = script.trim_start();
+    !(trimmed.starts_with("::") || trimmed.starts_with('{'))
+}
+
+/// Add the diagnostics of a rendered error and its related errors.
+fn push_diagnostics(out: &mut Vec<ScriptDiagnostic>, json: &Value, script: &str) {
+    let text = |key: &str| json.get(key).and_then(Value::as_str).map(String::from);
+    let mut message = text("message").unwrap_or_else(|| "Unknown query error".to_string());
+    // Parse errors end with the range in cozo's wrapped script, which the
+    // positions below already give relative to the user's script.
+    if let Some(at) = message.rfind(" at ") {
+        let range = &message[at + 4..];
+        if range.contains("..") && range.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            message.truncate(at);
+        }
+    }
+    let severity = match json.get("severity").and_then(Value::as_str) {
+        Some("warning") => DiagnosticSeverity::Warning,
+        Some("advice") => DiagnosticSeverity::Advice,
+        _ => DiagnosticSeverity::Error,
+    };
+    let labels = json
+        .get("labels")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let mut spans: Vec<(usize, usize, Option<String>)> = labels
+        .iter()
+        .filter_map(|label| {
+            let span = label.get("span")?;
+            let offset = span.get("offset")?.as_u64()? as usize;
+            let len = span.get("length")?.as_u64()? as usize;
+            let text = label.get("label").and_then(Value::as_str).map(String::from);
+            Some((offset, offset + len, text))
+        })
+        .collect();
+    if spans.is_empty() {
+        // Nothing to map back: this puts it at the start of the script.
+        spans.push((0, 0, None));
+    }
+    for (start, end, label) in spans {
+        out.push(ScriptDiagnostic {
+            message: message.clone(),
+            severity,
+            code: text("code"),
+            start: position(script, script_offset(start, script)),
+            end: position(script, script_offset(end, script)),
+            label,
+            hint: text("help"),
+        });
+    }
+    for related in json
+        .get("related")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        push_diagnostics(out, related, script);
+    }
+}
+
+fn position(script: &str, mut offset: usize) -> TextPosition {
+    while !script.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &script[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    TextPosition {
+        offset: offset as u32,
+        line: before.matches('\n').count() as u32,
+        column: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::schema::cozo_list_relations;
+    use crate::api::simple::cozo_open_db;
+    use crate::api::vector::run_write_op;
+
+    #[test]
+    fn scripts_are_compiled_without_running() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create users {id: Int => name: String}").unwrap();
+
+        let valid = cozo_validate_script(&db, "?[name] := *users{name}".into());
+        assert!(valid.valid && valid.checked && valid.error.is_none());
+
+        let wrong = cozo_validate_script(&db, "?[name] := *users{nickname}".into());
+        assert!(!wrong.valid && wrong.checked);
+        assert!(wrong.error.is_some());
+
+        let missing = cozo_validate_script(&db, "?[x] := *missing{x}".into());
+        assert!(!missing.valid);
+    }
+
+    #[test]
+    fn system_ops_and_blocks_are_not_checked() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create users {id: Int}").unwrap();
+        for script in ["::remove users", "  { ?[x] <- [[1]] }"] {
+            let result = cozo_validate_script(&db, script.into());
+            assert!(result.valid && !result.checked);
+        }
+        // The system op was not run.
+        assert_eq!(cozo_list_relations(&db).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn diagnostics_are_positioned_in_the_script() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        assert!(cozo_diagnose_script(&db, "?[x] <- [[1]]".into())
+            .unwrap()
+            .is_empty());
+
+        let script = "# é\n?[x] := x = 1,, y";
+        let diagnostics = cozo_diagnose_script(&db, script.into()).unwrap();
+        let first = &diagnostics[0];
+        assert_eq!(first.severity, DiagnosticSeverity::Error);
+        assert!(!first.message.ends_with(".."));
+        assert_eq!(first.start.line, 1);
+        assert_eq!(&script[first.start.offset as usize..], ", y");
+        assert_eq!(first.start.column, 14);
+    }
+
+    #[test]
+    fn columns_count_utf16_units() {
+        let script = "a\n😀b";
+        let at = position(script, script.len() - 1);
+        assert_eq!((at.offset, at.line, at.column), (6, 1, 2));
+        // Offsets inside a character move back to its start.
+        assert_eq!(position(script, 3).offset, 2);
+    }
+}
@@ -0,0 +1,299 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+use cozo::{DataValue, JsonData, NamedRows, Num, UuidWrapper, Validity, ValidityTs, Vector};
+use ndarray::Array1;
+
+/// A single CozoDB value, mirroring cozo's `DataValue` as a real Dart type.
+///
+/// Internal-only variants (regex, set, bottom) are folded into the closest
+/// public one when converting results.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CozoValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    /// Binary blob, a `Uint8List` in Dart. Unlike the JSON API, bytes are
+    /// passed as-is rather than base64 encoded, in both parameters and rows.
+    Bytes(Vec<u8>),
+    Uuid(String),
+    List(Vec<CozoValue>),
+    /// `<F32; N>` vector, a `Float32List` in Dart.
+    F32Vector(Vec<f32>),
+    /// `<F64; N>` vector, a `Float64List` in Dart.
+    F64Vector(Vec<f64>),
+    /// JSON value, encoded as a JSON string.
+    Json(String),
+    /// Validity for time travel: microsecond timestamp and assert/retract flag.
+    Validity {
+        timestamp: i64,
+        is_assert: bool,
+    },
+}
+
+impl From<DataValue> for CozoValue {
+    fn from(value: DataValue) -> Self {
+        match value {
+            DataValue::Null | DataValue::Bot => CozoValue::Null,
+            DataValue::Bool(b) => CozoValue::Bool(b),
+            DataValue::Num(Num::Int(i)) => CozoValue::Int(i),
+            DataValue::Num(Num::Float(f)) => CozoValue::Float(f),
+            DataValue::Str(s) => CozoValue::Str(s.to_string()),
+            DataValue::Bytes(b) => CozoValue::Bytes(b),
+            DataValue::Uuid(u) => CozoValue::Uuid(u.0.to_string()),
+            DataValue::Regex(r) => CozoValue::Str(r.0.as_str().to_string()),
+            DataValue::List(l) => CozoValue::List(l.into_iter().map(CozoValue::from).collect()),
+            DataValue::Set(s) => CozoValue::List(s.into_iter().map(CozoValue::from).collect()),
+            DataValue::Vec(Vector::F32(a)) => CozoValue::F32Vector(a.to_vec()),
+            DataValue::Vec(Vector::F64(a)) => CozoValue::F64Vector(a.to_vec()),
+            DataValue::Json(j) => CozoValue::Json(j.0.to_string()),
+            DataValue::Validity(v) => CozoValue::Validity {
+                timestamp: v.timestamp.0 .0,
+                is_assert: v.is_assert.0,
+            },
+        }
+    }
+}
+
+impl TryFrom<CozoValue> for DataValue {
+    type Error = anyhow::Error;
+
+    fn try_from(value: CozoValue) -> anyhow::Result<Self> {
+        Ok(match value {
+            CozoValue::Null => DataValue::Null,
+            CozoValue::Bool(b) => DataValue::Bool(b),
+            CozoValue::Int(i) => DataValue::from(i),
+            CozoValue::Float(f) => DataValue::from(f),
+            CozoValue::Str(s) => DataValue::from(s),
+            CozoValue::Bytes(b) => DataValue::Bytes(b),
+            CozoValue::Uuid(u) => DataValue::Uuid(UuidWrapper(
+                uuid::Uuid::parse_str(&u)
+                    .map_err(|e| anyhow::anyhow!("Invalid UUID {}: {}", u, e))?,
+            )),
+            CozoValue::List(l) => DataValue::List(
+                l.into_iter()
+                    .map(DataValue::try_from)
+                    .collect::<anyhow::Result<_>>()?,
+            ),
+            CozoValue::F32Vector(v) => DataValue::Vec(Vector::F32(Array1::from(v))),
+            CozoValue::F64Vector(v) => DataValue::Vec(Vector::F64(Array1::from(v))),
+            CozoValue::Json(j) => DataValue::Json(JsonData(
+                serde_json::from_str(&j)
+                    .map_err(|e| anyhow::anyhow!("Invalid JSON value: {}", e))?,
+            )),
+            CozoValue::Validity {
+                timestamp,
+                is_assert,
+            } => DataValue::Validity(Validity {
+                timestamp: ValidityTs(Reverse(timestamp)),
+                is_assert: Reverse(is_assert),
+            }),
+        })
+    }
+}
+
+/// Convert typed parameters from Dart into the map cozo expects.
+pub(crate) fn params_from_values(
+    params: HashMap<String, CozoValue>,
+) -> anyhow::Result<BTreeMap<String, DataValue>> {
+    params
+        .into_iter()
+        .map(|(k, v)| {
+            let v = DataValue::try_from(v)
+                .map_err(|e| anyhow::anyhow!("Invalid parameter {}: {}", k, e))?;
+            Ok((k, v))
+        })
+        .collect()
+}
+
+/// Typed result of a CozoScript query, mirroring cozo's `NamedRows`.
+///
+/// Prefer this over the JSON result for large text or blob columns. Values
+/// are moved out of cozo's rows, so `Bytes` buffers are handed to the bridge
+/// as they are, and the bridge copies each string or blob once into its
+/// message; Dart reads bytes as a view into that message. The JSON path
+/// instead builds a JSON tree, base64-encodes bytes, renders the tree to a
+/// string and parses it again in Dart.
+#[derive(Debug, Clone)]
+pub struct QueryResult {
+    /// Whether the query succeeded.
+    pub ok: bool,
+    /// Column headers.
+    pub headers: Vec<String>,
+    /// Rows of typed values, one entry per header.
+    pub rows: Vec<Vec<CozoValue>>,
+    /// Time taken by the query in seconds.
+    pub took: f64,
+    /// Human-readable error message when `ok` is false.
+    pub error: Option<String>,
+}
+
+impl QueryResult {
+    pub(crate) fn from_rows(rows: NamedRows, took: f64) -> Self {
+        QueryResult {
+            ok: true,
+            headers: rows.headers,
+            rows: rows
+                .rows
+                .into_iter()
+                .map(|row| row.into_iter().map(CozoValue::from).collect())
+                .collect(),
+            took,
+            error: None,
+        }
+    }
+
+    pub(crate) fn from_error(message: String, took: f64) -> Self {
+        QueryResult {
+            ok: false,
+            headers: vec![],
+            rows: vec![],
+            took,
+            error: Some(message),
+        }
+    }
+}
+
+/// A single-quoted CozoScript string literal. Double-quoted literals cannot
+/// be used: cozo reads them as raw strings, without escapes.
+pub(crate) fn string_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('\'');
+    for c in value.chars() {
+        match c {
+            '\'' => out.push_str("\\'"),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+/// `value` as a CozoScript expression, for places such as column defaults
+/// where a parameter cannot be used.
+pub(crate) fn literal(value: &CozoValue) -> anyhow::Result<String> {
+    Ok(match value {
+        CozoValue::Null => "null".to_string(),
+        CozoValue::Bool(b) => b.to_string(),
+        CozoValue::Int(i) => i.to_string(),
+        CozoValue::Float(f) if f.is_nan() => "to_float('NAN')".to_string(),
+        CozoValue::Float(f) if f.is_infinite() => {
+            let name = if *f > 0.0 { "INF" } else { "NEG_INF" };
+            format!("to_float('{}')", name)
+        }
+        CozoValue::Float(f) => format!("{:?}", f),
+        CozoValue::Str(s) => string_literal(s),
+        CozoValue::Bytes(_) => anyhow::bail!("Bytes values cannot be written as a literal"),
+        CozoValue::Uuid(u) => {
+            uuid::Uuid::parse_str(u).map_err(|e| anyhow::anyhow!("Invalid UUID {}: {}", u, e))?;
+            format!("to_uuid({})", string_literal(u))
+        }
+        CozoValue::List(l) => format!(
+            "[{}]",
+            l.iter()
+                .map(literal)
+                .collect::<anyhow::Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        CozoValue::F32Vector(v) => format!("vec({:?})", v),
+        CozoValue::F64Vector(v) => format!("vec({:?}, 'F64')", v),
+        CozoValue::Json(j) => {
+            serde_json::from_str::<serde_json::Value>(j)
+                .map_err(|e| anyhow::anyhow!("Invalid JSON value: {}", e))?;
+            format!("parse_json({})", string_literal(j))
+        }
+        CozoValue::Validity {
+            timestamp,
+            is_assert,
+        } => format!("[{}, {}]", timestamp, is_assert),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::{cozo_open_db, cozo_run_query_typed};
+
+    #[test]
+    fn typed_parameters_round_trip() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        let values = vec![
+            CozoValue::Null,
+            CozoValue::Bool(true),
+            CozoValue::Int(-7),
+            CozoValue::Float(1.5),
+            CozoValue::Str("é'\n".into()),
+            CozoValue::Uuid("5f0b2f0e-7c1a-4d8e-9a63-2b8f0c6d1e4a".into()),
+            CozoValue::List(vec![CozoValue::Int(1), CozoValue::Str("a".into())]),
+            CozoValue::F32Vector(vec![0.5, 1.0]),
+            CozoValue::F64Vector(vec![0.25]),
+            CozoValue::Json(r#"{"a":[1,2]}"#.into()),
+            CozoValue::Validity {
+                timestamp: 42,
+                is_assert: false,
+            },
+        ];
+        for value in values {
+            let params = HashMap::from([("v".to_string(), value.clone())]);
+            let result = cozo_run_query_typed(&db, "?[v] <- [[$v]]".into(), params, true);
+            assert!(result.ok, "{:?}", result.error);
+            assert_eq!(result.rows, [[value]]);
+        }
+    }
+
+    #[test]
+    fn invalid_parameters_name_the_parameter() {
+        let params = HashMap::from([("id".to_string(), CozoValue::Uuid("nope".into()))]);
+        let err = params_from_values(params).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Invalid parameter id: Invalid UUID nope"));
+        let params = HashMap::from([("doc".to_string(), CozoValue::Json("{".into()))]);
+        assert!(params_from_values(params).is_err());
+    }
+
+    #[test]
+    fn bytes_are_stored_as_bytes() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        crate::api::vector::run_write_op(&db, ":create blobs {id: Int => data: Bytes}").unwrap();
+        let data = vec![0u8, 255, 10, 13];
+        let params = HashMap::from([("data".to_string(), CozoValue::Bytes(data.clone()))]);
+        let script = "?[id, data] <- [[1, $data]] :put blobs {id => data}";
+        assert!(cozo_run_query_typed(&db, script.into(), params, false).ok);
+
+        let script = "?[data, n] := *blobs{data}, n = length(data)";
+        let result = cozo_run_query_typed(&db, script.into(), HashMap::new(), true);
+        assert_eq!(result.rows, [[CozoValue::Bytes(data), CozoValue::Int(4)]]);
+    }
+
+    #[test]
+    fn large_values_come_back_whole() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        crate::api::vector::run_write_op(
+            &db,
+            ":create docs {id: Int => text: String, blob: Bytes}",
+        )
+        .unwrap();
+        let text = "é".repeat(1 << 19);
+        let blob: Vec<u8> = (0..1 << 20).map(|i| (i % 251) as u8).collect();
+        let params = HashMap::from([
+            ("text".to_string(), CozoValue::Str(text.clone())),
+            ("blob".to_string(), CozoValue::Bytes(blob.clone())),
+        ]);
+        let script = "?[id, text, blob] <- [[1, $text, $blob]] :put docs {id => text, blob}";
+        assert!(cozo_run_query_typed(&db, script.into(), params, false).ok);
+
+        let script = "?[text, blob] := *docs{text, blob}";
+        let result = cozo_run_query_typed(&db, script.into(), HashMap::new(), true);
+        assert_eq!(
+            result.rows,
+            [[CozoValue::Str(text), CozoValue::Bytes(blob)]]
+        );
+    }
+}
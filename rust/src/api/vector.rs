@@ -0,0 +1,585 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use cozo::{DataValue, Vector};
+use ndarray::Array1;
+use serde_json::{json, Value};
+
+use crate::api::busy_retry::retry_busy;
+use crate::api::error::catch_panic;
+use crate::api::row_policy::policy_condition;
+use crate::api::schema::{column_names, cozo_list_relations, relation_columns, system_op};
+use crate::api::search::run_search;
+use crate::api::simple::{query_error, CozoDb};
+use crate::api::transaction::{cozo_begin_tx, tx_abort, tx_commit};
+use crate::api::value::CozoValue;
+use crate::frb_generated::StreamSink;
+
+/// Variable the distance of a hit is bound to, unlikely to clash with a
+/// column name.
+const DISTANCE_VAR: &str = "distance__";
+
+/// How an HNSW index measures the distance between two vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorDistance {
+    /// Euclidean distance, squared.
+    L2,
+    /// One minus the cosine similarity.
+    Cosine,
+    /// One minus the inner product, for normalized vectors.
+    InnerProduct,
+}
+
+/// Element type of indexed vectors, matching the column type `<F32; dim>`
+/// or `<F64; dim>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorType {
+    F32,
+    F64,
+}
+
+/// Settings of an HNSW vector index.
+#[derive(Debug, Clone)]
+pub struct HnswConfig {
+    /// Length of the indexed vectors.
+    pub dim: u32,
+    pub dtype: VectorType,
+    /// Vector columns to index. Rows with null in a column are skipped.
+    pub fields: Vec<String>,
+    pub distance: VectorDistance,
+    /// Neighbours kept per node; 16 to 50 is typical. Higher is more
+    /// accurate but uses more space and slows down writes.
+    pub m: u32,
+    /// Candidates considered while inserting; higher builds a more accurate
+    /// index more slowly.
+    pub ef_construction: u32,
+    /// CozoScript expression over the columns; only rows for which it is
+    /// true are indexed. `None` indexes every row.
+    pub filter: Option<String>,
+    pub extend_candidates: bool,
+    pub keep_pruned_connections: bool,
+}
+
+/// An HNSW index, as listed by `::indices`.
+#[derive(Debug, Clone)]
+pub struct VectorIndexInfo {
+    /// The indexed relation.
+    pub relation: String,
+    pub name: String,
+    pub dim: u32,
+    pub dtype: VectorType,
+    pub fields: Vec<String>,
+    pub distance: VectorDistance,
+    pub m: u32,
+    pub ef_construction: u32,
+    pub extend_candidates: bool,
+    pub keep_pruned_connections: bool,
+}
+
+/// Create an HNSW index for nearest-neighbour search over vector columns.
+///
+/// - `relation`: the stored relation holding the vectors
+/// - `name`: index name, used as `~relation:name{...}` in queries
+///
+/// Existing rows are indexed before this returns, and later writes keep the
+/// index up to date.
+pub fn cozo_create_hnsw_index(
+    db: &CozoDb,
+    relation: String,
+    name: String,
+    config: HnswConfig,
+) -> anyhow::Result<()> {
+    let mut options = vec![
+        format!("dim: {}", config.dim),
+        format!("m: {}", config.m),
+        format!("ef_construction: {}", config.ef_construction),
+        format!(
+            "dtype: {}",
+            match config.dtype {
+                VectorType::F32 => "F32",
+                VectorType::F64 => "F64",
+            }
+        ),
+        format!("fields: [{}]", config.fields.join(", ")),
+        format!(
+            "distance: {}",
+            match config.distance {
+                VectorDistance::L2 => "L2",
+                VectorDistance::Cosine => "Cosine",
+                VectorDistance::InnerProduct => "IP",
+            }
+        ),
+        format!("extend_candidates: {}", config.extend_candidates),
+        format!(
+            "keep_pruned_connections: {}",
+            config.keep_pruned_connections
+        ),
+    ];
+    if let Some(filter) = &config.filter {
+        options.push(format!("filter: {}", filter));
+    }
+    let script = format!(
+        "::hnsw create {}:{} {{ {} }}",
+        relation,
+        name,
+        options.join(", ")
+    );
+    run_write_op(db, &script)
+}
+
+/// Drop an index of a stored relation. Works for every kind of index.
+pub fn cozo_drop_index(db: &CozoDb, relation: String, name: String) -> anyhow::Result<()> {
+    run_write_op(db, &format!("::index drop {}:{}", relation, name))
+}
+
+/// List HNSW indices.
+///
+/// - `relation`: only list indices of this stored relation; `None` lists
+///   those of every relation
+pub fn cozo_list_vector_indices(
+    db: &CozoDb,
+    relation: Option<String>,
+) -> anyhow::Result<Vec<VectorIndexInfo>> {
+    let inner = db.instance()?;
+    let relations = match relation {
+        Some(relation) => vec![relation],
+        None => cozo_list_relations(db)?
+            .into_iter()
+            .filter(|r| r.access_level != "index")
+            .map(|r| r.name)
+            .collect(),
+    };
+    let mut indices = vec![];
+    for relation in relations {
+        let rows = system_op(&inner, &format!("::indices {}", relation))?;
+        let rows: Vec<_> = rows
+            .into_iter()
+            .filter(|row| row[1].get_str() == Some("hnsw"))
+            .collect();
+        if rows.is_empty() {
+            continue;
+        }
+        // The index lists its fields by column position.
+        let columns = relation_columns(&inner, &relation)?;
+        for row in rows {
+            let config = Value::from(row[3].clone());
+            let int = |key: &str| config[key].as_u64().unwrap_or_default() as u32;
+            indices.push(VectorIndexInfo {
+                relation: relation.clone(),
+                name: row[0].get_str().unwrap_or_default().to_string(),
+                dim: int("vec_dim"),
+                dtype: match config["dtype"].as_str() {
+                    Some("F64") => VectorType::F64,
+                    _ => VectorType::F32,
+                },
+                fields: config["vec_fields"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|i| columns.get(i.as_u64()? as usize))
+                    .map(|c| c.name.clone())
+                    .collect(),
+                distance: match config["distance"].as_str() {
+                    Some("Cosine") => VectorDistance::Cosine,
+                    Some("InnerProduct") => VectorDistance::InnerProduct,
+                    _ => VectorDistance::L2,
+                },
+                m: int("m_neighbours"),
+                ef_construction: int("ef_construction"),
+                extend_candidates: config["extend_candidates"].as_bool().unwrap_or_default(),
+                keep_pruned_connections: config["keep_pruned_connections"]
+                    .as_bool()
+                    .unwrap_or_default(),
+            });
+        }
+    }
+    Ok(indices)
+}
+
+/// Vectors written per script by [`cozo_put_embeddings`].
+const EMBEDDING_CHUNK_ROWS: usize = 1000;
+
+/// A vector to store with [`cozo_put_embeddings`].
+#[derive(Debug, Clone)]
+pub struct Embedding {
+    /// Key column values of the row, in key order.
+    pub key: Vec<CozoValue>,
+    pub vector: Vec<f32>,
+}
+
+/// Write many vectors into one column of a stored relation, in a single
+/// transaction, reporting progress through a stream.
+///
+/// - `column`: the vector column, of type `<F32; dim>` or `<F64; dim>`
+///
+/// Vectors are passed as binary vectors rather than script text, so no
+/// precision is lost in formatting. They are written in chunks of 1000
+/// through the query engine, so HNSW indices, triggers and change callbacks
+/// see them. If `column` is the only non-key column, rows are created or
+/// replaced; otherwise only the vector of existing rows is updated and a
+/// missing key fails the call. Nothing is written unless every chunk
+/// succeeds.
+///
+/// Each event is a JSON string
+/// `{"stage": "started" | "progress" | "done", "written": n, "total": n, "elapsed": secs}`,
+/// with a `progress` event after each chunk. The stream closes after the
+/// `done` event, sent once the transaction is committed, or fails with the error.
+pub fn cozo_put_embeddings(
+    db: &CozoDb,
+    relation: String,
+    column: String,
+    embeddings: Vec<Embedding>,
+    sink: StreamSink<String>,
+) -> anyhow::Result<()> {
+    let columns = relation_columns(&db.writable()?, &relation)?;
+    let target = columns
+        .iter()
+        .find(|c| c.name == column)
+        .ok_or_else(|| anyhow::anyhow!("Put embeddings failed: no column {}", column))?;
+    let f64_column = target.column_type.starts_with("<F64");
+    let keys: Vec<_> = columns.iter().filter(|c| c.is_key).collect();
+    let op = if columns.iter().any(|c| !c.is_key && c.name != column) {
+        "update"
+    } else {
+        "put"
+    };
+    let spec = format!("{} => {}", column_names(keys.iter().copied()), column);
+    let script = format!(
+        "?[{}, {}] <- $rows\n:{} {} {{{}}}",
+        column_names(keys.iter().copied()),
+        column,
+        op,
+        relation,
+        spec
+    );
+
+    let total = embeddings.len();
+    let start = Instant::now();
+    let event = |stage: &str, written: usize| {
+        json!({
+            "stage": stage,
+            "written": written,
+            "total": total,
+            "elapsed": start.elapsed().as_secs_f64(),
+        })
+        .to_string()
+    };
+    let _ = sink.add(event("started", 0));
+    let tx = cozo_begin_tx(db, true)?;
+    let mut written = 0;
+    for chunk in embeddings.chunks(EMBEDDING_CHUNK_ROWS) {
+        let rows = chunk
+            .iter()
+            .map(|e| embedding_row(e, keys.len(), f64_column))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .and_then(|rows| {
+                let params = BTreeMap::from([("rows".to_string(), DataValue::List(rows))]);
+                tx.run(&script, params)
+            });
+        if let Err(e) = rows {
+            tx_abort(&tx)?;
+            anyhow::bail!("Put embeddings failed at row {}: {}", written, e);
+        }
+        written += chunk.len();
+        let _ = sink.add(event("progress", written));
+    }
+    tx_commit(&tx)?;
+    let _ = sink.add(event("done", written));
+    Ok(())
+}
+
+fn embedding_row(
+    embedding: &Embedding,
+    n_keys: usize,
+    f64_column: bool,
+) -> anyhow::Result<DataValue> {
+    if embedding.key.len() != n_keys {
+        anyhow::bail!(
+            "Key has {} values, expected {}",
+            embedding.key.len(),
+            n_keys
+        );
+    }
+    let mut row = embedding
+        .key
+        .iter()
+        .cloned()
+        .map(DataValue::try_from)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let vector = if f64_column {
+        Vector::F64(embedding.vector.iter().copied().map(f64::from).collect())
+    } else {
+        Vector::F32(Array1::from(embedding.vector.clone()))
+    };
+    row.push(DataValue::Vec(vector));
+    Ok(DataValue::List(row))
+}
+
+/// A row found by [`cozo_vector_search`].
+#[derive(Debug, Clone)]
+pub struct VectorHit {
+    /// Key column values of the row, in key order.
+    pub key: Vec<CozoValue>,
+    /// Distance to the query vector, as measured by the index.
+    pub distance: f64,
+    /// The full row, in column order.
+    pub row: Vec<CozoValue>,
+}
+
+/// Find the rows whose vectors are nearest to `query` with an HNSW index.
+///
+/// - `relation`, `index`: the indexed relation and the index name
+/// - `query`: the query vector; converted to `F64` for `F64` indices
+/// - `k`: maximum number of hits
+/// - `ef`: candidates considered while searching; higher is more accurate
+///   and slower, and should be at least `k`
+/// - `filter`: CozoScript expression over the relation's columns; only rows
+///   for which it is true are returned
+///
+/// Returns the hits, nearest first. Rows indexed on several vector fields
+/// can appear once per field.
+pub fn cozo_vector_search(
+    db: &CozoDb,
+    relation: String,
+    index: String,
+    query: Vec<f32>,
+    k: u32,
+    ef: u32,
+    filter: Option<String>,
+) -> anyhow::Result<Vec<VectorHit>> {
+    let inner = db.instance()?;
+    let dtype = cozo_list_vector_indices(db, Some(relation.clone()))?
+        .into_iter()
+        .find(|i| i.name == index)
+        .ok_or_else(|| anyhow::anyhow!("No vector index {}:{}", relation, index))?
+        .dtype;
+    let columns = relation_columns(&inner, &relation)?;
+    let n_keys = columns.iter().filter(|c| c.is_key).count();
+    let names = column_names(&columns);
+    let filter = filter
+        .map(|f| format!(", filter: {}", f))
+        .unwrap_or_default();
+    let mut params = BTreeMap::new();
+    let policy = policy_condition(&db.row_policies, &relation, &mut params);
+    let script = format!(
+        "?[{names}, {d}] := ~{relation}:{index}{{{names} | query: $query, k: $k, ef: $ef, bind_distance: {d}{filter}}}{policy}\n:order {d}",
+        d = DISTANCE_VAR,
+    );
+    let query = match dtype {
+        VectorType::F32 => Vector::F32(Array1::from(query)),
+        VectorType::F64 => Vector::F64(query.into_iter().map(f64::from).collect()),
+    };
+    params.extend([
+        ("query".to_string(), DataValue::Vec(query)),
+        ("k".to_string(), DataValue::from(i64::from(k))),
+        ("ef".to_string(), DataValue::from(i64::from(ef))),
+    ]);
+    let rows = run_search(db, &inner, &script, params)?;
+    Ok(rows
+        .rows
+        .into_iter()
+        .map(|mut row| {
+            let distance = row.pop().and_then(|d| d.get_float()).unwrap_or_default();
+            let row: Vec<CozoValue> = row.into_iter().map(CozoValue::from).collect();
+            VectorHit {
+                key: row[..n_keys].to_vec(),
+                distance,
+                row,
+            }
+        })
+        .collect())
+}
+
+/// Run a system op that writes, such as creating an index.
+pub(crate) fn run_write_op(db: &CozoDb, script: &str) -> anyhow::Result<()> {
+    let inner = db.instance()?;
+    retry_busy(&db.busy_retry, || {
+        catch_panic(|| inner.run_script(script, Default::default(), db.mutability(false)))
+    })
+    .map_err(|err| query_error(err, script))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::cozo_open_db;
+
+    fn config(distance: VectorDistance) -> HnswConfig {
+        HnswConfig {
+            dim: 2,
+            dtype: VectorType::F32,
+            fields: vec!["v".into()],
+            distance,
+            m: 16,
+            ef_construction: 20,
+            filter: None,
+            extend_candidates: false,
+            keep_pruned_connections: true,
+        }
+    }
+
+    /// A database with `docs {id => tag, v}` and an L2 index `docs:vec`.
+    fn docs() -> CozoDb {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create docs {id: Int => tag: String, v: <F32; 2>}").unwrap();
+        run_write_op(
+            &db,
+            "?[id, tag, v] <- [[1, 'a', vec([0, 0])], [2, 'b', vec([1, 0])], [3, 'a', vec([5, 5])]]
+             :put docs {id => tag, v}",
+        )
+        .unwrap();
+        cozo_create_hnsw_index(&db, "docs".into(), "vec".into(), config(VectorDistance::L2))
+            .unwrap();
+        db
+    }
+
+    #[test]
+    fn hnsw_indices_are_created_listed_and_dropped() {
+        let db = docs();
+        let mut cosine = config(VectorDistance::Cosine);
+        cosine.m = 32;
+        cozo_create_hnsw_index(&db, "docs".into(), "cos".into(), cosine).unwrap();
+        let mut ip = config(VectorDistance::InnerProduct);
+        ip.dtype = VectorType::F64;
+        run_write_op(&db, ":create other {id: Int => w: <F64; 2>}").unwrap();
+        cozo_create_hnsw_index(&db, "other".into(), "ip".into(), ip.clone()).unwrap_err();
+        ip.fields = vec!["w".into()];
+        cozo_create_hnsw_index(&db, "other".into(), "ip".into(), ip).unwrap();
+
+        let mut indices = cozo_list_vector_indices(&db, None).unwrap();
+        indices.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(indices.len(), 3);
+        let cos = &indices[0];
+        assert_eq!((cos.relation.as_str(), cos.name.as_str()), ("docs", "cos"));
+        assert_eq!((cos.dim, cos.m, cos.ef_construction), (2, 32, 20));
+        assert_eq!(cos.distance, VectorDistance::Cosine);
+        assert_eq!(cos.fields, ["v"]);
+        assert!(cos.keep_pruned_connections && !cos.extend_candidates);
+        let ip = &indices[1];
+        assert_eq!((ip.relation.as_str(), ip.dtype), ("other", VectorType::F64));
+        assert_eq!(ip.distance, VectorDistance::InnerProduct);
+        assert_eq!(indices[2].distance, VectorDistance::L2);
+
+        cozo_drop_index(&db, "docs".into(), "cos".into()).unwrap();
+        let left = cozo_list_vector_indices(&db, Some("docs".into())).unwrap();
+        assert_eq!(left.len(), 1);
+        assert!(cozo_drop_index(&db, "docs".into(), "cos".into()).is_err());
+
+        let mut bad = config(VectorDistance::L2);
+        bad.fields = vec!["missing".into()];
+        assert!(cozo_create_hnsw_index(&db, "docs".into(), "bad".into(), bad).is_err());
+    }
+
+    fn ids(hits: &[VectorHit]) -> Vec<i64> {
+        hits.iter()
+            .map(|hit| match hit.key[..] {
+                [CozoValue::Int(id)] => id,
+                _ => panic!("{:?}", hit.key),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn vector_search_returns_nearest_first() {
+        let db = docs();
+        let search = |k, filter: Option<&str>| {
+            let filter = filter.map(String::from);
+            cozo_vector_search(
+                &db,
+                "docs".into(),
+                "vec".into(),
+                vec![0.9, 0.0],
+                k,
+                10,
+                filter,
+            )
+        };
+        let hits = search(3, None).unwrap();
+        assert_eq!(ids(&hits), [2, 1, 3]);
+        assert!((hits[0].distance - 0.01).abs() < 1e-6);
+        assert!(matches!(hits[0].row[1], CozoValue::Str(ref tag) if tag == "b"));
+
+        assert_eq!(ids(&search(1, None).unwrap()), [2]);
+        assert_eq!(ids(&search(3, Some("tag == 'a'")).unwrap()), [1, 3]);
+        let missing =
+            cozo_vector_search(&db, "docs".into(), "nope".into(), vec![0.0; 2], 1, 1, None);
+        assert_eq!(
+            missing.unwrap_err().to_string(),
+            "No vector index docs:nope"
+        );
+    }
+
+    fn embedding(id: i64, vector: Vec<f32>) -> Embedding {
+        Embedding {
+            key: vec![CozoValue::Int(id)],
+            vector,
+        }
+    }
+
+    fn sink() -> StreamSink<String> {
+        StreamSink::deserialize("0".into())
+    }
+
+    fn count(db: &CozoDb, relation: &str) -> usize {
+        let script = format!("?[id] := *{}{{id}}", relation);
+        let inner = db.instance().unwrap();
+        let rows = inner.run_script(
+            &script,
+            Default::default(),
+            cozo::ScriptMutability::Immutable,
+        );
+        rows.unwrap().rows.len()
+    }
+
+    #[test]
+    fn put_embeddings_writes_all_chunks_or_nothing() {
+        let db = cozo_open_db("mem".into(), String::new(), "{}".into()).unwrap();
+        run_write_op(&db, ":create vecs {id: Int => v: <F64; 2>}").unwrap();
+        let mut embeddings: Vec<_> = (0..1200)
+            .map(|i| embedding(i, vec![i as f32, 0.5]))
+            .collect();
+        cozo_put_embeddings(&db, "vecs".into(), "v".into(), embeddings.clone(), sink()).unwrap();
+        assert_eq!(count(&db, "vecs"), 1200);
+
+        run_write_op(&db, ":create fresh {id: Int => v: <F32; 2>}").unwrap();
+        embeddings.push(Embedding {
+            key: vec![],
+            vector: vec![0.0; 2],
+        });
+        let err = cozo_put_embeddings(&db, "fresh".into(), "v".into(), embeddings, sink());
+        let err = err.unwrap_err().to_string();
+        assert!(
+            err.starts_with("Put embeddings failed at row 1000: Key has 0 values"),
+            "{}",
+            err
+        );
+        assert_eq!(count(&db, "fresh"), 0);
+    }
+
+    #[test]
+    fn put_embeddings_updates_rows_with_other_columns() {
+        let db = docs();
+        let update = vec![embedding(3, vec![0.9, 0.1])];
+        cozo_put_embeddings(&db, "docs".into(), "v".into(), update, sink()).unwrap();
+        let hits = cozo_vector_search(
+            &db,
+            "docs".into(),
+            "vec".into(),
+            vec![0.9, 0.1],
+            1,
+            10,
+            None,
+        );
+        assert_eq!(ids(&hits.unwrap()), [3]);
+
+        let missing = vec![embedding(9, vec![0.0, 0.0])];
+        assert!(cozo_put_embeddings(&db, "docs".into(), "v".into(), missing, sink()).is_err());
+        assert_eq!(count(&db, "docs"), 3);
+        let column = cozo_put_embeddings(&db, "docs".into(), "w".into(), vec![], sink());
+        assert_eq!(
+            column.unwrap_err().to_string(),
+            "Put embeddings failed: no column w"
+        );
+    }
+}
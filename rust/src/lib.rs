@@ -1,2 +1,4 @@
 pub mod api;
 mod frb_generated;
+mod memory;
+mod remote;
@@ -0,0 +1,236 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde_json::json;
+
+use crate::api::simple::closed_error;
+
+/// Seconds a request to the server may take when no `timeout_secs` option is given.
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
+/// Connection to a cozo-server, behind handles opened with the "http" engine.
+pub(crate) struct RemoteDb {
+    /// Server base URL without a trailing slash, e.g. `https://host:9070`.
+    url: String,
+    /// Sent as the `x-cozo-auth` header.
+    auth: Option<String>,
+    timeout_secs: u64,
+    closed: AtomicBool,
+}
+
+impl RemoteDb {
+    /// Connection settings from the `auth` and `timeout_secs` open options.
+    /// Nothing is sent until the first call.
+    pub(crate) fn new(url: &str, opts: &serde_json::Value) -> anyhow::Result<Self> {
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            anyhow::bail!(
+                "Failed to open database: the http engine needs an http:// or https:// URL, got {:?}",
+                url
+            );
+        }
+        Ok(RemoteDb {
+            url: url.trim_end_matches('/').to_string(),
+            auth: opts.get("auth").and_then(|v| v.as_str()).map(String::from),
+            timeout_secs: opts
+                .get("timeout_secs")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(DEFAULT_TIMEOUT_SECS),
+            closed: AtomicBool::new(false),
+        })
+    }
+
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Relaxed)
+    }
+
+    /// Run a script on the server. Returns the result JSON as the server sent it,
+    /// which has the same shape as a local result.
+    pub(crate) fn query(
+        &self,
+        script: &str,
+        params_json: &str,
+        immutable: bool,
+    ) -> anyhow::Result<String> {
+        let params: serde_json::Value = if params_json.is_empty() {
+            json!({})
+        } else {
+            serde_json::from_str(params_json)
+                .map_err(|e| anyhow::anyhow!("params argument is not a JSON map: {}", e))?
+        };
+        let body = json!({"script": script, "params": params, "immutable": immutable});
+        let (reply, text) = self.send(minreq::post(self.endpoint("/text-query")), Some(body))?;
+        if reply.get("ok").and_then(|v| v.as_bool()) == Some(true) {
+            return Ok(text);
+        }
+        let display = reply
+            .get("display")
+            .or_else(|| reply.get("message"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown query error");
+        match reply.get("code").and_then(|v| v.as_str()) {
+            Some(code) => Err(anyhow::anyhow!("Query failed [{}]: {}", code, display)),
+            None => Err(anyhow::anyhow!("Query failed: {}", display)),
+        }
+    }
+
+    /// Export relations from the server, in the same JSON shape as a local export.
+    pub(crate) fn export(&self, relations_json: &str) -> anyhow::Result<String> {
+        let relations: Vec<String> = serde_json::from_str(relations_json)
+            .map_err(|e| anyhow::anyhow!("Invalid relations JSON: {}", e))?;
+        let path = format!("/export/{}", relations.join(","));
+        let (_, text) = self.send(minreq::get(self.endpoint(&path)), None)?;
+        Ok(text)
+    }
+
+    /// Import relations on the server from data in the export format.
+    pub(crate) fn import(&self, data_json: &str) -> anyhow::Result<()> {
+        let data: serde_json::Value = serde_json::from_str(data_json)
+            .map_err(|e| anyhow::anyhow!("Import failed: invalid data JSON: {}", e))?;
+        let (reply, _) = self.send(minreq::put(self.endpoint("/import")), Some(data))?;
+        if reply.get("ok").and_then(|v| v.as_bool()) == Some(true) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "Import failed: {}",
+                reply
+                    .get("message")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown server error")
+            ))
+        }
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}{}", self.url, path)
+    }
+
+    /// Send a request, returning the reply both parsed and as text.
+    fn send(
+        &self,
+        request: minreq::Request,
+        body: Option<serde_json::Value>,
+    ) -> anyhow::Result<(serde_json::Value, String)> {
+        if self.is_closed() {
+            return Err(closed_error());
+        }
+        let mut request = request.with_timeout(self.timeout_secs);
+        if let Some(auth) = &self.auth {
+            request = request.with_header("x-cozo-auth", auth.as_str());
+        }
+        if let Some(body) = body {
+            request = request
+                .with_header("content-type", "application/json")
+                .with_body(body.to_string());
+        }
+        let response = request
+            .send()
+            .map_err(|e| anyhow::anyhow!("Request to {} failed: {}", self.url, e))?;
+        let text = response
+            .as_str()
+            .map_err(|e| anyhow::anyhow!("Invalid response from {}: {}", self.url, e))?
+            .to_string();
+        let reply = serde_json::from_str(&text).map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid response from {} (HTTP {}): {}",
+                self.url,
+                response.status_code,
+                text
+            )
+        })?;
+        Ok((reply, text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// Serve one request with `reply`, sending back the request as received.
+    fn serve(reply: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let (sent, received) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                reply.len(),
+                reply
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            sent.send(request).unwrap();
+        });
+        (url, received)
+    }
+
+    #[test]
+    fn queries_are_posted_with_auth() {
+        let reply = r#"{"ok":true,"headers":["x"],"rows":[[1]]}"#;
+        let (url, request) = serve(reply);
+        let remote = RemoteDb::new(&url, &json!({"auth": "secret"})).unwrap();
+        assert_eq!(remote.query("?[x] <- [[1]]", "", true).unwrap(), reply);
+
+        let request = request.recv().unwrap();
+        assert!(
+            request.starts_with("POST /text-query HTTP/1.1\r\n"),
+            "{}",
+            request
+        );
+        assert!(request.contains("x-cozo-auth: secret\r\n"));
+        let body: serde_json::Value =
+            serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({"script": "?[x] <- [[1]]", "params": {}, "immutable": true})
+        );
+    }
+
+    #[test]
+    fn server_errors_keep_their_code() {
+        let (url, _request) = serve(r#"{"ok":false,"code":"eval::unbound","display":"unbound x"}"#);
+        let remote = RemoteDb::new(&url, &json!({})).unwrap();
+        let err = remote.query("?[x] := y", "{}", false).unwrap_err();
+        assert_eq!(err.to_string(), "Query failed [eval::unbound]: unbound x");
+
+        let (url, _request) = serve("not json");
+        let err = RemoteDb::new(&url, &json!({}))
+            .unwrap()
+            .import("{}")
+            .unwrap_err();
+        assert!(err.to_string().contains("(HTTP 200): not json"), "{}", err);
+    }
+
+    #[test]
+    fn closed_and_invalid_connections_fail() {
+        assert!(RemoteDb::new("ftp://host", &json!({})).is_err());
+        let remote = RemoteDb::new("http://127.0.0.1:9/", &json!({"timeout_secs": 1})).unwrap();
+        assert!(remote.query("?[x] <- [[1]]", "not json", true).is_err());
+        remote.close();
+        assert!(remote.is_closed());
+        let err = remote.export(r#"["a"]"#).unwrap_err();
+        assert_eq!(err.to_string(), closed_error().to_string());
+    }
+}